@@ -0,0 +1,14 @@
+CREATE TABLE random_tables(
+    table_id UUID PRIMARY KEY,
+    version UUID NOT NULL,
+    created TIMESTAMP WITH TIME ZONE NOT NULL,
+    updated TIMESTAMP WITH TIME ZONE NOT NULL,
+
+    world_id UUID NOT NULL REFERENCES worlds (world_id) ON DELETE CASCADE,
+    name TEXT NOT NULL,
+    description TEXT NOT NULL,
+    url_slug TEXT NOT NULL,
+    entries JSONB NOT NULL
+);
+
+ALTER TABLE random_tables ADD CONSTRAINT random_tables_world_url_slug_key UNIQUE (world_id, url_slug);
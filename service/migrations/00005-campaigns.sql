@@ -0,0 +1,39 @@
+CREATE TABLE campaigns(
+    campaign_id UUID PRIMARY KEY,
+    version UUID NOT NULL,
+    created TIMESTAMP WITH TIME ZONE NOT NULL,
+    updated TIMESTAMP WITH TIME ZONE NOT NULL,
+
+    world_id UUID NOT NULL REFERENCES worlds (world_id) ON DELETE CASCADE,
+    name TEXT NOT NULL,
+    description TEXT NOT NULL,
+    players UUID[] NOT NULL
+);
+
+CREATE INDEX campaigns_world_id_idx ON campaigns(world_id);
+
+CREATE TABLE sessions(
+    session_id UUID PRIMARY KEY,
+    version UUID NOT NULL,
+    created TIMESTAMP WITH TIME ZONE NOT NULL,
+    updated TIMESTAMP WITH TIME ZONE NOT NULL,
+
+    campaign_id UUID NOT NULL REFERENCES campaigns (campaign_id) ON DELETE CASCADE,
+    session_date DATE NOT NULL,
+    summary TEXT NOT NULL,
+    entities TEXT[] NOT NULL
+);
+
+CREATE INDEX sessions_campaign_id_idx ON sessions(campaign_id);
+CREATE INDEX sessions_entities_idx ON sessions USING GIN (entities);
+
+CREATE TABLE session_notes(
+    session_id UUID NOT NULL REFERENCES sessions (session_id) ON DELETE CASCADE,
+    author_id UUID NOT NULL REFERENCES users (user_id) ON DELETE CASCADE,
+    created TIMESTAMP WITH TIME ZONE NOT NULL,
+    updated TIMESTAMP WITH TIME ZONE NOT NULL,
+
+    notes TEXT NOT NULL,
+
+    PRIMARY KEY (session_id, author_id)
+);
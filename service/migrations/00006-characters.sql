@@ -0,0 +1,14 @@
+CREATE TABLE characters(
+    character_id UUID PRIMARY KEY,
+    version UUID NOT NULL,
+    created TIMESTAMP WITH TIME ZONE NOT NULL,
+    updated TIMESTAMP WITH TIME ZONE NOT NULL,
+
+    world_id UUID NOT NULL REFERENCES worlds (world_id) ON DELETE CASCADE,
+    name TEXT NOT NULL,
+    description TEXT NOT NULL,
+    controlled_by UUID NULL REFERENCES users (user_id) ON DELETE SET NULL
+);
+
+CREATE INDEX characters_world_id_idx ON characters(world_id);
+CREATE INDEX characters_controlled_by_idx ON characters(controlled_by);
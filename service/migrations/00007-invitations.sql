@@ -0,0 +1,27 @@
+CREATE TABLE world_members(
+    world_id UUID NOT NULL REFERENCES worlds (world_id) ON DELETE CASCADE,
+    user_id UUID NOT NULL REFERENCES users (user_id) ON DELETE CASCADE,
+    created TIMESTAMP WITH TIME ZONE NOT NULL,
+
+    role TEXT NOT NULL,
+
+    PRIMARY KEY (world_id, user_id)
+);
+
+CREATE INDEX world_members_user_id_idx ON world_members(user_id);
+
+CREATE TABLE invitations(
+    invitation_id UUID PRIMARY KEY,
+    version UUID NOT NULL,
+    created TIMESTAMP WITH TIME ZONE NOT NULL,
+    updated TIMESTAMP WITH TIME ZONE NOT NULL,
+
+    world_id UUID NOT NULL REFERENCES worlds (world_id) ON DELETE CASCADE,
+    token_hash TEXT NOT NULL UNIQUE,
+    role TEXT NOT NULL,
+    expires TIMESTAMP WITH TIME ZONE NULL,
+    max_uses INTEGER NULL,
+    uses INTEGER NOT NULL
+);
+
+CREATE INDEX invitations_world_id_idx ON invitations(world_id);
@@ -0,0 +1,9 @@
+CREATE TABLE world_stars(
+    world_id UUID NOT NULL REFERENCES worlds (world_id) ON DELETE CASCADE,
+    user_id UUID NOT NULL REFERENCES users (user_id) ON DELETE CASCADE,
+    created TIMESTAMP WITH TIME ZONE NOT NULL,
+
+    PRIMARY KEY (world_id, user_id)
+);
+
+CREATE INDEX world_stars_user_id_idx ON world_stars(user_id);
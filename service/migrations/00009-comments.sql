@@ -0,0 +1,15 @@
+CREATE TABLE comments(
+    comment_id UUID PRIMARY KEY,
+    version UUID NOT NULL,
+    created TIMESTAMP WITH TIME ZONE NOT NULL,
+    updated TIMESTAMP WITH TIME ZONE NOT NULL,
+
+    world_id UUID NOT NULL REFERENCES worlds (world_id) ON DELETE CASCADE,
+    entity TEXT NULL,
+    parent_id UUID NULL REFERENCES comments (comment_id) ON DELETE CASCADE,
+    author_id UUID NOT NULL REFERENCES users (user_id) ON DELETE CASCADE,
+    body TEXT NOT NULL
+);
+
+CREATE INDEX comments_world_id_entity_idx ON comments(world_id, entity);
+CREATE INDEX comments_parent_id_idx ON comments(parent_id);
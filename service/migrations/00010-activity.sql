@@ -0,0 +1,12 @@
+CREATE TABLE activity(
+    activity_id UUID PRIMARY KEY,
+    created TIMESTAMP WITH TIME ZONE NOT NULL,
+
+    world_id UUID NOT NULL REFERENCES worlds (world_id) ON DELETE CASCADE,
+    user_id UUID NOT NULL REFERENCES users (user_id) ON DELETE CASCADE,
+    event TEXT NOT NULL,
+    target TEXT NULL
+);
+
+CREATE INDEX activity_world_id_idx ON activity(world_id, created DESC);
+CREATE INDEX activity_user_id_idx ON activity(user_id, created DESC);
@@ -0,0 +1,15 @@
+CREATE TABLE user_follows(
+    follower_id UUID NOT NULL REFERENCES users (user_id) ON DELETE CASCADE,
+    user_id UUID NOT NULL REFERENCES users (user_id) ON DELETE CASCADE,
+    created TIMESTAMP WITH TIME ZONE NOT NULL,
+
+    PRIMARY KEY (follower_id, user_id)
+);
+
+CREATE TABLE world_follows(
+    follower_id UUID NOT NULL REFERENCES users (user_id) ON DELETE CASCADE,
+    world_id UUID NOT NULL REFERENCES worlds (world_id) ON DELETE CASCADE,
+    created TIMESTAMP WITH TIME ZONE NOT NULL,
+
+    PRIMARY KEY (follower_id, world_id)
+);
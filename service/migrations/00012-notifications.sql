@@ -0,0 +1,20 @@
+CREATE TABLE notifications(
+    notification_id UUID PRIMARY KEY,
+    created TIMESTAMP WITH TIME ZONE NOT NULL,
+    user_id UUID NOT NULL REFERENCES users (user_id) ON DELETE CASCADE,
+    actor_id UUID NOT NULL REFERENCES users (user_id) ON DELETE CASCADE,
+    kind TEXT NOT NULL,
+    world_id UUID NOT NULL REFERENCES worlds (world_id) ON DELETE CASCADE,
+    target TEXT NULL,
+    read TIMESTAMP WITH TIME ZONE NULL
+);
+
+CREATE INDEX notifications_user_id_created_idx ON notifications(user_id, created DESC);
+
+CREATE TABLE notification_preferences(
+    user_id UUID NOT NULL REFERENCES users (user_id) ON DELETE CASCADE,
+    kind TEXT NOT NULL,
+    enabled BOOLEAN NOT NULL,
+
+    PRIMARY KEY (user_id, kind)
+);
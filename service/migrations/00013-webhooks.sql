@@ -0,0 +1,31 @@
+CREATE TABLE webhooks(
+    webhook_id UUID PRIMARY KEY,
+    version UUID NOT NULL,
+    created TIMESTAMP WITH TIME ZONE NOT NULL,
+    updated TIMESTAMP WITH TIME ZONE NOT NULL,
+    world_id UUID NOT NULL REFERENCES worlds (world_id) ON DELETE CASCADE,
+    url TEXT NOT NULL,
+    secret TEXT NOT NULL
+);
+
+CREATE INDEX webhooks_world_id_idx ON webhooks(world_id);
+
+CREATE TABLE webhook_deliveries(
+    delivery_id UUID PRIMARY KEY,
+    created TIMESTAMP WITH TIME ZONE NOT NULL,
+    webhook_id UUID NOT NULL REFERENCES webhooks (webhook_id) ON DELETE CASCADE,
+    event TEXT NOT NULL,
+    payload TEXT NOT NULL,
+    status TEXT NOT NULL
+);
+
+CREATE INDEX webhook_deliveries_webhook_id_idx ON webhook_deliveries(webhook_id, created DESC);
+
+CREATE TABLE webhook_delivery_attempts(
+    delivery_id UUID NOT NULL REFERENCES webhook_deliveries (delivery_id) ON DELETE CASCADE,
+    attempted TIMESTAMP WITH TIME ZONE NOT NULL,
+    response_status INTEGER NULL,
+    error TEXT NULL
+);
+
+CREATE INDEX webhook_delivery_attempts_delivery_id_idx ON webhook_delivery_attempts(delivery_id, attempted);
@@ -0,0 +1,10 @@
+CREATE TABLE world_events(
+    event_id BIGSERIAL PRIMARY KEY,
+    created TIMESTAMP WITH TIME ZONE NOT NULL,
+    world_id UUID NOT NULL REFERENCES worlds (world_id) ON DELETE CASCADE,
+    entity_type TEXT NOT NULL,
+    entity_id UUID NOT NULL,
+    version UUID NULL
+);
+
+CREATE INDEX world_events_world_id_idx ON world_events(world_id, event_id);
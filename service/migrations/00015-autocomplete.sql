@@ -0,0 +1,4 @@
+CREATE EXTENSION IF NOT EXISTS pg_trgm;
+
+CREATE INDEX random_tables_name_trgm_idx ON random_tables USING GIN (name gin_trgm_ops);
+CREATE INDEX campaigns_name_trgm_idx ON campaigns USING GIN (name gin_trgm_ops);
@@ -0,0 +1,15 @@
+CREATE TABLE world_connections(
+    connection_id UUID PRIMARY KEY,
+    version UUID NOT NULL,
+    created TIMESTAMP WITH TIME ZONE NOT NULL,
+    updated TIMESTAMP WITH TIME ZONE NOT NULL,
+    from_world_id UUID NOT NULL REFERENCES worlds (world_id) ON DELETE CASCADE,
+    to_world_id UUID NOT NULL REFERENCES worlds (world_id) ON DELETE CASCADE,
+    kind TEXT NOT NULL,
+    description TEXT NOT NULL,
+    CONSTRAINT world_connections_different_worlds CHECK (from_world_id <> to_world_id)
+);
+
+ALTER TABLE world_connections ADD CONSTRAINT world_connections_worlds_key UNIQUE (from_world_id, to_world_id);
+
+CREATE INDEX world_connections_to_world_id_idx ON world_connections(to_world_id);
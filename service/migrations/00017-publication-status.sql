@@ -0,0 +1,8 @@
+ALTER TABLE worlds ADD COLUMN status TEXT NOT NULL DEFAULT 'published';
+ALTER TABLE worlds ADD COLUMN publish_at TIMESTAMP WITH TIME ZONE NULL;
+
+ALTER TABLE random_tables ADD COLUMN status TEXT NOT NULL DEFAULT 'published';
+ALTER TABLE random_tables ADD COLUMN publish_at TIMESTAMP WITH TIME ZONE NULL;
+
+ALTER TABLE campaigns ADD COLUMN status TEXT NOT NULL DEFAULT 'published';
+ALTER TABLE campaigns ADD COLUMN publish_at TIMESTAMP WITH TIME ZONE NULL;
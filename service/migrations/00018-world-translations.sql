@@ -0,0 +1,2 @@
+ALTER TABLE worlds ADD COLUMN language TEXT NOT NULL DEFAULT 'en';
+ALTER TABLE worlds ADD COLUMN translations JSONB NOT NULL DEFAULT '{}';
@@ -0,0 +1,16 @@
+CREATE TABLE attachments(
+    attachment_id UUID PRIMARY KEY,
+    version UUID NOT NULL,
+    created TIMESTAMP WITH TIME ZONE NOT NULL,
+    updated TIMESTAMP WITH TIME ZONE NOT NULL,
+    world_id UUID NOT NULL REFERENCES worlds (world_id) ON DELETE CASCADE,
+    owner_id UUID NOT NULL REFERENCES users (user_id) ON DELETE CASCADE,
+    filename TEXT NOT NULL,
+    content_type TEXT NOT NULL,
+    size BIGINT NOT NULL,
+    hash TEXT NOT NULL,
+    has_thumbnail BOOLEAN NOT NULL
+);
+
+CREATE INDEX attachments_world_id_idx ON attachments(world_id, created DESC);
+CREATE INDEX attachments_owner_id_idx ON attachments(owner_id);
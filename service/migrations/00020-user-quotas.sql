@@ -0,0 +1,6 @@
+CREATE TABLE user_quotas(
+    user_id UUID NOT NULL REFERENCES users (user_id) ON DELETE CASCADE,
+    resource TEXT NOT NULL,
+    quota BIGINT NOT NULL,
+    PRIMARY KEY (user_id, resource)
+);
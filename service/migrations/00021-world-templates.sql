@@ -0,0 +1,13 @@
+CREATE TABLE world_templates(
+    template_id UUID PRIMARY KEY,
+    version UUID NOT NULL,
+    created TIMESTAMP WITH TIME ZONE NOT NULL,
+    updated TIMESTAMP WITH TIME ZONE NOT NULL,
+
+    owner_id UUID NOT NULL REFERENCES users (user_id) ON DELETE CASCADE,
+    name TEXT NOT NULL,
+    description TEXT NOT NULL,
+    content JSONB NOT NULL
+);
+
+CREATE INDEX world_templates_owner_id_idx ON world_templates(owner_id);
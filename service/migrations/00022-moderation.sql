@@ -0,0 +1,34 @@
+ALTER TABLE users ADD COLUMN admin BOOLEAN NOT NULL DEFAULT FALSE;
+ALTER TABLE users ADD COLUMN suspended BOOLEAN NOT NULL DEFAULT FALSE;
+
+ALTER TABLE worlds ADD COLUMN hidden BOOLEAN NOT NULL DEFAULT FALSE;
+ALTER TABLE comments ADD COLUMN hidden BOOLEAN NOT NULL DEFAULT FALSE;
+
+CREATE TABLE reports(
+    report_id UUID PRIMARY KEY,
+    version UUID NOT NULL,
+    created TIMESTAMP WITH TIME ZONE NOT NULL,
+    updated TIMESTAMP WITH TIME ZONE NOT NULL,
+
+    reporter_id UUID NOT NULL REFERENCES users (user_id) ON DELETE CASCADE,
+    target_type TEXT NOT NULL,
+    target_id UUID NOT NULL,
+    reason TEXT NOT NULL,
+    status TEXT NOT NULL
+);
+
+CREATE INDEX reports_status_created_idx ON reports(status, created);
+
+CREATE TABLE moderation_actions(
+    action_id UUID PRIMARY KEY,
+    created TIMESTAMP WITH TIME ZONE NOT NULL,
+
+    moderator_id UUID NULL REFERENCES users (user_id) ON DELETE SET NULL,
+    report_id UUID NULL REFERENCES reports (report_id) ON DELETE SET NULL,
+    action TEXT NOT NULL,
+    target_type TEXT NOT NULL,
+    target_id UUID NOT NULL,
+    note TEXT NOT NULL
+);
+
+CREATE INDEX moderation_actions_created_idx ON moderation_actions(created);
@@ -0,0 +1,5 @@
+ALTER TABLE webhook_deliveries ADD COLUMN next_attempt TIMESTAMP WITH TIME ZONE NULL;
+
+UPDATE webhook_deliveries SET next_attempt = created WHERE status = 'pending';
+
+CREATE INDEX webhook_deliveries_next_attempt_idx ON webhook_deliveries(next_attempt) WHERE status = 'pending';
@@ -0,0 +1 @@
+CREATE INDEX world_events_created_idx ON world_events(created);
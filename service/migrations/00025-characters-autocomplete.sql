@@ -0,0 +1 @@
+CREATE INDEX characters_name_trgm_idx ON characters USING GIN (name gin_trgm_ops);
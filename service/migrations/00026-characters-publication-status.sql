@@ -0,0 +1,2 @@
+ALTER TABLE characters ADD COLUMN status TEXT NOT NULL DEFAULT 'published';
+ALTER TABLE characters ADD COLUMN publish_at TIMESTAMP WITH TIME ZONE NULL;
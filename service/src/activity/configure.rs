@@ -0,0 +1,44 @@
+use super::{repository::ActivityRepository, ActivityService};
+use crate::infrastructure::database::Database;
+use std::sync::Arc;
+
+/// Application Configuration for the Activity module
+pub struct ActivityConfig {
+    pub activity_service: ActivityService,
+}
+
+impl ActivityConfig {
+    /// Create the Configuration for the Activity Module
+    ///
+    /// # Returns
+    /// The Activity Config object
+    pub fn new(database: Database) -> Self {
+        let repository = ActivityRepository::new(database);
+        Self {
+            activity_service: ActivityService::new(repository),
+        }
+    }
+
+    /// Generate the configuration callback needed for the HTTP Server to actually add the Activity
+    /// endpoints to the server
+    ///
+    /// # Returns
+    /// The callback to provide to the HTTP Server to configure up the Activity endpoints
+    pub fn configure(&self) -> Arc<dyn Fn(rocket::Rocket) -> rocket::Rocket + Send + Sync> {
+        let activity_service = self.activity_service.clone();
+        Arc::new(move |config| {
+            config.manage(activity_service.clone()).mount(
+                "/",
+                rocket::routes![
+                    super::endpoints::feed,
+                    super::endpoints::follow_user,
+                    super::endpoints::follow_world,
+                    super::endpoints::unfollow_user,
+                    super::endpoints::unfollow_world,
+                    super::endpoints::user_activity,
+                    super::endpoints::world_activity
+                ],
+            )
+        })
+    }
+}
@@ -0,0 +1,13 @@
+pub(crate) mod errors;
+mod feed;
+mod follow_user;
+mod follow_world;
+pub(crate) mod model;
+mod user_activity;
+mod world_activity;
+
+pub use feed::*;
+pub use follow_user::*;
+pub use follow_world::*;
+pub use user_activity::*;
+pub use world_activity::*;
@@ -0,0 +1,41 @@
+use crate::http::problem::{ProblemType, ValidationType};
+
+/// Problem Types that can happen when working with activity and follows
+#[derive(Debug, thiserror::Error)]
+pub enum ActivityProblemType {
+    /// The user tried to follow themselves
+    #[error("Users can not follow themselves")]
+    FollowingSelf,
+}
+
+impl ProblemType for ActivityProblemType {
+    /// Generate a Type value for the `ProblemType` values.
+    ///
+    /// These are used in the `type` field in the RFC-7807 Problem Response
+    fn error_code(&self) -> &'static str {
+        match self {
+            ActivityProblemType::FollowingSelf => {
+                "tag:multiverse,2020:activity/problems/following_self"
+            }
+        }
+    }
+}
+
+/// Validation errors that are specific to activity
+#[derive(Debug, thiserror::Error)]
+pub enum ActivityValidation {
+    /// The feed cursor was not one that we generated
+    #[error("The feed cursor was malformed")]
+    InvalidCursor,
+}
+
+impl ValidationType for ActivityValidation {
+    /// Generate a Type value for the `ValidationType` values.
+    fn error_code(&self) -> &'static str {
+        match self {
+            ActivityValidation::InvalidCursor => {
+                "tag:multiverse,2020:activity/problems/validation_error/invalid_cursor"
+            }
+        }
+    }
+}
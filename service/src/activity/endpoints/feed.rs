@@ -0,0 +1,57 @@
+use super::{errors::ActivityValidation, model::FeedResponse};
+use crate::{
+    activity::{ActivityService, FeedCursor},
+    authorization::Authorizer,
+    http::problem::{Problem, ValidationProblem},
+    model::Pagination,
+};
+use rocket::{get, http::RawStr, State};
+
+/// The most entries that a client may ask for in a single page
+const MAX_COUNT: u64 = 100;
+
+/// Handler to get the feed of recent Activity from everything that the current user follows
+///
+/// Pagination is by cursor rather than offset, so that activity recorded between requests doesn't
+/// shift entries from one page to the next
+///
+/// # Parameters
+/// - `activity_service` - The activity service to use
+/// - `authorizer` - The authorizer to prove whose feed to get
+/// - `cursor` - The cursor returned by the previous page, if any
+/// - `count` - The maximum number of entries to return. This is capped at `MAX_COUNT`
+///
+/// # Returns
+/// The requested page of the feed
+#[tracing::instrument(name = "GET /feed", skip(activity_service))]
+#[get("/feed?<cursor>&<count>")]
+pub fn feed(
+    activity_service: State<ActivityService>,
+    authorizer: Authorizer,
+    cursor: Option<Result<FeedCursor, &RawStr>>,
+    count: Option<u64>,
+) -> Result<FeedResponse, Problem> {
+    let follower = authorizer
+        .authorize()
+        .authorized()
+        .finish()?
+        .expect("No authenticated user");
+
+    let cursor = match cursor {
+        Some(Ok(cursor)) => Some(cursor),
+        Some(Err(_)) => {
+            let mut problem = ValidationProblem::new();
+            problem.with_field_error("cursor", ActivityValidation::InvalidCursor);
+            return Err(problem.build());
+        }
+        None => None,
+    };
+    let count = count
+        .unwrap_or_else(|| Pagination::default().count)
+        .min(MAX_COUNT);
+
+    let feed = activity_service.feed(&follower, cursor.as_ref(), count);
+    tracing::debug!(feed = ?feed, "Found feed");
+
+    Ok(FeedResponse { feed, count })
+}
@@ -0,0 +1,84 @@
+use super::errors::ActivityProblemType;
+use crate::{
+    activity::{ActivityService, FollowUserError},
+    authorization::Authorizer,
+    http::problem::Problem,
+    users::{endpoints::errors::UserProblemType, UserID, UsersService},
+};
+use rocket::{delete, http::Status, put, Response, State};
+
+/// Handler to follow a User on behalf of the current user
+///
+/// # Parameters
+/// - `activity_service` - The activity service to use
+/// - `users_service` - The users service to use
+/// - `id` - The ID of the user to follow
+/// - `authorizer` - The authorizer to prove who is doing the following
+///
+/// # Returns
+/// An empty response if the user was followed, or a Problem if they weren't
+#[tracing::instrument(name = "PUT /users/{id}/follow", skip(activity_service, users_service))]
+#[put("/users/<id>/follow")]
+pub fn follow_user(
+    activity_service: State<ActivityService>,
+    users_service: State<UsersService>,
+    id: UserID,
+    authorizer: Authorizer,
+) -> Result<Response<'static>, Problem> {
+    let follower = authorizer
+        .authorize()
+        .authorized()
+        .finish()?
+        .expect("No authenticated user");
+
+    let user = users_service
+        .find_user_by_id(&id)
+        .ok_or_else(|| Problem::new(UserProblemType::UnknownUserID, Status::NotFound))?;
+
+    activity_service
+        .follow_user(&follower, &user.identity.id)
+        .map_err(|e| match e {
+            FollowUserError::FollowingSelf => Problem::new(
+                ActivityProblemType::FollowingSelf,
+                Status::UnprocessableEntity,
+            ),
+        })?;
+
+    Ok(Response::build().status(Status::NoContent).finalize())
+}
+
+/// Handler to stop following a User on behalf of the current user
+///
+/// # Parameters
+/// - `activity_service` - The activity service to use
+/// - `users_service` - The users service to use
+/// - `id` - The ID of the user to stop following
+/// - `authorizer` - The authorizer to prove who is doing the following
+///
+/// # Returns
+/// An empty response if the user is no longer followed, or a Problem if something went wrong
+#[tracing::instrument(
+    name = "DELETE /users/{id}/follow",
+    skip(activity_service, users_service)
+)]
+#[delete("/users/<id>/follow")]
+pub fn unfollow_user(
+    activity_service: State<ActivityService>,
+    users_service: State<UsersService>,
+    id: UserID,
+    authorizer: Authorizer,
+) -> Result<Response<'static>, Problem> {
+    let follower = authorizer
+        .authorize()
+        .authorized()
+        .finish()?
+        .expect("No authenticated user");
+
+    let user = users_service
+        .find_user_by_id(&id)
+        .ok_or_else(|| Problem::new(UserProblemType::UnknownUserID, Status::NotFound))?;
+
+    activity_service.unfollow_user(&follower, &user.identity.id);
+
+    Ok(Response::build().status(Status::NoContent).finalize())
+}
@@ -0,0 +1,80 @@
+use crate::{
+    activity::ActivityService,
+    authorization::Authorizer,
+    http::problem::Problem,
+    worlds::{
+        endpoints::{errors::WorldProblemType, world_owner::authorize_world_viewer},
+        WorldID, WorldsService,
+    },
+};
+use rocket::{delete, http::Status, put, Response, State};
+
+/// Handler to follow a World on behalf of the current user
+///
+/// # Parameters
+/// - `activity_service` - The activity service to use
+/// - `worlds_service` - The worlds service to use
+/// - `id` - The ID of the world to follow
+/// - `authorizer` - The authorizer to prove who is doing the following
+///
+/// # Returns
+/// An empty response if the world was followed, or a Problem if it wasn't
+#[tracing::instrument(
+    name = "PUT /worlds/{id}/follow",
+    skip(activity_service, worlds_service)
+)]
+#[put("/worlds/<id>/follow")]
+pub fn follow_world(
+    activity_service: State<ActivityService>,
+    worlds_service: State<WorldsService>,
+    id: WorldID,
+    authorizer: Authorizer,
+) -> Result<Response<'static>, Problem> {
+    let follower = authorizer
+        .authorize()
+        .authorized()
+        .finish()?
+        .expect("No authenticated user");
+
+    let world = authorize_world_viewer(&worlds_service, &authorizer, &id)?;
+
+    activity_service.follow_world(&follower, &world.identity.id);
+
+    Ok(Response::build().status(Status::NoContent).finalize())
+}
+
+/// Handler to stop following a World on behalf of the current user
+///
+/// # Parameters
+/// - `activity_service` - The activity service to use
+/// - `worlds_service` - The worlds service to use
+/// - `id` - The ID of the world to stop following
+/// - `authorizer` - The authorizer to prove who is doing the following
+///
+/// # Returns
+/// An empty response if the world is no longer followed, or a Problem if something went wrong
+#[tracing::instrument(
+    name = "DELETE /worlds/{id}/follow",
+    skip(activity_service, worlds_service)
+)]
+#[delete("/worlds/<id>/follow")]
+pub fn unfollow_world(
+    activity_service: State<ActivityService>,
+    worlds_service: State<WorldsService>,
+    id: WorldID,
+    authorizer: Authorizer,
+) -> Result<Response<'static>, Problem> {
+    let follower = authorizer
+        .authorize()
+        .authorized()
+        .finish()?
+        .expect("No authenticated user");
+
+    let world = worlds_service
+        .find_world_by_id(id)
+        .ok_or_else(|| Problem::new(WorldProblemType::UnknownWorldID, Status::NotFound))?;
+
+    activity_service.unfollow_world(&follower, &world.identity.id);
+
+    Ok(Response::build().status(Status::NoContent).finalize())
+}
@@ -0,0 +1,6 @@
+mod activity_entries;
+mod activity_response;
+mod feed_response;
+
+pub use activity_response::*;
+pub use feed_response::*;
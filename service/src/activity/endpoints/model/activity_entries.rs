@@ -0,0 +1,75 @@
+use crate::{
+    activity::ActivityModel,
+    http::link::{Link, LinkRel},
+    users::{endpoints::model::UserLink, UserID, UserModel, UsersService},
+    worlds::endpoints::model::WorldLink,
+};
+use itertools::*;
+use rocket::{Request, State};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+
+/// Render a list of activity into the JSON entries and the links to go with them
+///
+/// # Parameters
+/// - `req` - The request being responded to
+/// - `activity` - The activity to render
+///
+/// # Returns
+/// The JSON for each entry, and the links for all of the entries
+pub(super) fn render_activity(
+    req: &Request,
+    activity: &[ActivityModel],
+) -> (Vec<Value>, Vec<Link>) {
+    let users_service = req.guard::<State<UsersService>>().unwrap();
+
+    let user_ids: Vec<&UserID> = activity
+        .iter()
+        .map(|activity| &activity.user)
+        .unique()
+        .collect();
+    let users: HashMap<UserID, UserModel> = if user_ids.is_empty() {
+        HashMap::new()
+    } else {
+        users_service
+            .find_users_by_id(&user_ids[..])
+            .into_iter()
+            .map(|user| (user.identity.id.clone(), user))
+            .collect()
+    };
+
+    let entries: Vec<Value> = activity
+        .iter()
+        .map(|activity| {
+            let world: String = WorldLink::new(activity.world.clone()).into();
+            json!({
+              "event": activity.event.to_string(),
+              "world": world,
+              "target": activity.target,
+              "created": activity.created
+            })
+        })
+        .collect();
+
+    let mut links: Vec<Link> = activity
+        .iter()
+        .enumerate()
+        .map(|(index, activity)| {
+            Link::new(WorldLink::new(activity.world.clone()), LinkRel::RELATED)
+                .anchor(format!("#/entries/{}", index))
+        })
+        .collect();
+    let user_links: Vec<Link> = activity
+        .iter()
+        .enumerate()
+        .filter_map(|(index, activity)| users.get(&activity.user).map(|user| (index, user)))
+        .map(|(index, user)| {
+            Link::new(UserLink::new(user.identity.id.clone()), LinkRel::AUTHOR)
+                .anchor(format!("#/entries/{}", index))
+                .title(user.data.display_name.clone())
+        })
+        .collect();
+    links.extend(user_links);
+
+    (entries, links)
+}
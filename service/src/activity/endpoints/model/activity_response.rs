@@ -0,0 +1,36 @@
+use super::activity_entries::render_activity;
+use crate::{activity::ActivityModel, http::link::Links, model::Page};
+use rocket::{
+    http::hyper::header::{CacheControl, CacheDirective},
+    response, Request,
+};
+use rocket_contrib::json::Json;
+use serde_json::json;
+
+/// API Model representing a page of Activity
+#[derive(Debug)]
+pub struct ActivityResponse(pub Page<ActivityModel>);
+
+impl<'r> response::Responder<'r> for ActivityResponse {
+    fn respond_to(self, req: &Request) -> response::Result<'r> {
+        let (entries, links) = render_activity(req, &self.0.entries);
+
+        let response_body = json!({
+          "entries": entries,
+          "pagination": {
+            "offset": self.0.offset,
+            "count": self.0.count,
+            "total": self.0.total
+          }
+        });
+
+        response::Response::build()
+            .merge(Json(response_body).respond_to(req).unwrap())
+            .header(CacheControl(vec![
+                CacheDirective::Private,
+                CacheDirective::MaxAge(60),
+            ]))
+            .header(Links(links))
+            .ok()
+    }
+}
@@ -0,0 +1,49 @@
+use super::activity_entries::render_activity;
+use crate::{
+    activity::FeedPage,
+    http::link::{Link, LinkRel, Links},
+};
+use rocket::{
+    http::hyper::header::{CacheControl, CacheDirective},
+    response, Request,
+};
+use rocket_contrib::json::Json;
+use serde_json::json;
+
+/// API Model representing a page of a user's feed
+#[derive(Debug)]
+pub struct FeedResponse {
+    /// The page of the feed
+    pub feed: FeedPage,
+
+    /// The number of entries that were requested, for generating the link to the next page
+    pub count: u64,
+}
+
+impl<'r> response::Responder<'r> for FeedResponse {
+    fn respond_to(self, req: &Request) -> response::Result<'r> {
+        let (entries, mut links) = render_activity(req, &self.feed.entries);
+
+        let next = self.feed.next.map(|next| next.to_string());
+        if let Some(next) = &next {
+            links.push(Link::new(
+                format!("/feed?cursor={}&count={}", next, self.count),
+                LinkRel::NEXT,
+            ));
+        }
+
+        let response_body = json!({
+          "entries": entries,
+          "next": next
+        });
+
+        response::Response::build()
+            .merge(Json(response_body).respond_to(req).unwrap())
+            .header(CacheControl(vec![
+                CacheDirective::Private,
+                CacheDirective::MaxAge(60),
+            ]))
+            .header(Links(links))
+            .ok()
+    }
+}
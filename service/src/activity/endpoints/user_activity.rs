@@ -0,0 +1,49 @@
+use super::model::ActivityResponse;
+use crate::{
+    activity::{ActivityFilters, ActivityService},
+    authorization::Authorizer,
+    http::{pagination::PaginationRequest, problem::Problem},
+    users::{endpoints::errors::UserProblemType, UserID, UsersService},
+};
+use rocket::{get, http::Status, State};
+
+/// Handler to get the feed of recent Activity performed by a User
+///
+/// # Parameters
+/// - `activity_service` - The activity service to use
+/// - `users_service` - The users service to use
+/// - `id` - The ID of the user to get the activity of
+/// - `pagination` - The pagination controls to apply
+/// - `authorizer` - The authorizer to identify the current user. Activity in worlds that they can't see is
+///   not included
+///
+/// # Returns
+/// The recent activity performed by the user
+#[tracing::instrument(
+    name = "GET /users/{id}/activity",
+    skip(activity_service, users_service)
+)]
+#[get("/users/<id>/activity?<pagination..>")]
+pub fn user_activity(
+    activity_service: State<ActivityService>,
+    users_service: State<UsersService>,
+    id: UserID,
+    pagination: PaginationRequest,
+    authorizer: Authorizer,
+) -> Result<ActivityResponse, Problem> {
+    users_service
+        .find_user_by_id(&id)
+        .ok_or_else(|| Problem::new(UserProblemType::UnknownUserID, Status::NotFound))?;
+
+    let activity = activity_service.search_activity(
+        &ActivityFilters {
+            user: Some(id),
+            viewer: authorizer.authorize().finish()?,
+            ..Default::default()
+        },
+        &pagination,
+    );
+    tracing::debug!(activity = ?activity, "Found activity");
+
+    Ok(ActivityResponse(activity))
+}
@@ -0,0 +1,46 @@
+use super::model::ActivityResponse;
+use crate::{
+    activity::{ActivityFilters, ActivityService},
+    authorization::Authorizer,
+    http::{pagination::PaginationRequest, problem::Problem},
+    worlds::{endpoints::world_owner::authorize_world_viewer, WorldID, WorldsService},
+};
+use rocket::{get, State};
+
+/// Handler to get the feed of recent Activity within a World
+///
+/// # Parameters
+/// - `activity_service` - The activity service to use
+/// - `worlds_service` - The worlds service to use
+/// - `world` - The ID of the world to get the activity of
+/// - `pagination` - The pagination controls to apply
+/// - `authorizer` - The authorizer to identify the current user
+///
+/// # Returns
+/// The recent activity within the world
+#[tracing::instrument(
+    name = "GET /worlds/{id}/activity",
+    skip(activity_service, worlds_service)
+)]
+#[get("/worlds/<world>/activity?<pagination..>")]
+pub fn world_activity(
+    activity_service: State<ActivityService>,
+    worlds_service: State<WorldsService>,
+    world: WorldID,
+    pagination: PaginationRequest,
+    authorizer: Authorizer,
+) -> Result<ActivityResponse, Problem> {
+    authorize_world_viewer(&worlds_service, &authorizer, &world)?;
+
+    let activity = activity_service.search_activity(
+        &ActivityFilters {
+            world: Some(world),
+            viewer: authorizer.authorize().finish()?,
+            ..Default::default()
+        },
+        &pagination,
+    );
+    tracing::debug!(activity = ?activity, "Found activity");
+
+    Ok(ActivityResponse(activity))
+}
@@ -0,0 +1,11 @@
+mod activity;
+mod activity_event;
+mod activity_id;
+mod feed;
+mod feed_cursor;
+
+pub use activity::*;
+pub use activity_event::*;
+pub use activity_id::*;
+pub use feed::*;
+pub use feed_cursor::*;
@@ -0,0 +1,44 @@
+use super::{ActivityEvent, ActivityID};
+use crate::{users::UserID, worlds::WorldID};
+use chrono::{DateTime, Utc};
+
+/// Data to represent a single thing that happened within a world
+#[derive(Debug, Clone)]
+pub struct ActivityData {
+    /// The ID of the world that the activity happened in
+    pub world: WorldID,
+
+    /// The ID of the user that performed the activity. If `None` when recording then the owner of the
+    /// world is recorded as having performed it
+    pub user: Option<UserID>,
+
+    /// What happened
+    pub event: ActivityEvent,
+
+    /// A link to the thing that the activity was about, if it wasn't the world itself
+    pub target: Option<String>,
+}
+
+/// Model representation of an activity that has been recorded.
+///
+/// Activity is never updated once recorded, so this doesn't have the full `Identity` of other models
+#[derive(Debug, Clone)]
+pub struct ActivityModel {
+    /// The ID of the activity
+    pub id: ActivityID,
+
+    /// When the activity happened
+    pub created: DateTime<Utc>,
+
+    /// The ID of the world that the activity happened in
+    pub world: WorldID,
+
+    /// The ID of the user that performed the activity
+    pub user: UserID,
+
+    /// What happened
+    pub event: ActivityEvent,
+
+    /// A link to the thing that the activity was about, if it wasn't the world itself
+    pub target: Option<String>,
+}
@@ -0,0 +1,47 @@
+/// The kinds of activity that can happen within a world
+#[derive(Debug, PartialEq, Clone, strum_macros::EnumString, strum_macros::Display)]
+#[strum(serialize_all = "snake_case")]
+pub enum ActivityEvent {
+    /// The world was created
+    WorldCreated,
+    /// The details of the world were changed
+    WorldUpdated,
+    /// A random table was added to the world
+    TableCreated,
+    /// A campaign was added to the world
+    CampaignCreated,
+    /// A session was added to a campaign in the world
+    SessionCreated,
+    /// A character was added to the world
+    CharacterCreated,
+    /// A user joined the world as a member
+    MemberJoined,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_event_round_trip() {
+        for event in &[
+            ActivityEvent::WorldCreated,
+            ActivityEvent::WorldUpdated,
+            ActivityEvent::TableCreated,
+            ActivityEvent::CampaignCreated,
+            ActivityEvent::SessionCreated,
+            ActivityEvent::CharacterCreated,
+            ActivityEvent::MemberJoined,
+        ] {
+            let serialized = event.to_string();
+            assert_eq!(serialized.parse::<ActivityEvent>().as_ref(), Ok(event));
+        }
+    }
+
+    #[test]
+    fn test_event_names() {
+        assert_eq!(ActivityEvent::WorldCreated.to_string(), "world_created");
+        assert_eq!(ActivityEvent::WorldUpdated.to_string(), "world_updated");
+        assert_eq!(ActivityEvent::MemberJoined.to_string(), "member_joined");
+    }
+}
@@ -0,0 +1,40 @@
+use bytes::BytesMut;
+use postgres_types::{accepts, to_sql_checked, FromSql, IsNull, ToSql, Type};
+use std::fmt::{Display, Formatter};
+use uuid::Uuid;
+
+/// The ID of a recorded Activity
+#[derive(Debug, PartialEq, Clone, FromSql)]
+pub struct ActivityID(Uuid);
+
+impl ActivityID {
+    /// Wrap a UUID as an Activity ID object
+    ///
+    /// # Parameters
+    /// - `uuid` - The UUID to wrap
+    ///
+    /// # The Activity ID
+    pub fn new(uuid: Uuid) -> Self {
+        ActivityID(uuid)
+    }
+}
+
+impl ToSql for ActivityID {
+    accepts!(UUID);
+
+    to_sql_checked!();
+
+    fn to_sql(
+        &self,
+        t: &Type,
+        w: &mut BytesMut,
+    ) -> Result<IsNull, Box<dyn std::error::Error + Sync + Send>> {
+        self.0.to_sql(t, w)
+    }
+}
+
+impl Display for ActivityID {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
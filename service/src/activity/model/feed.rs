@@ -0,0 +1,11 @@
+use super::{ActivityModel, FeedCursor};
+
+/// A page of activity from a feed, using cursor-based pagination instead of offsets
+#[derive(Debug)]
+pub struct FeedPage {
+    /// The activity on this page, most recent first
+    pub entries: Vec<ActivityModel>,
+
+    /// The cursor to use to get the next page, if there is one
+    pub next: Option<FeedCursor>,
+}
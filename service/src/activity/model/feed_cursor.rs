@@ -0,0 +1,98 @@
+use super::ActivityID;
+use chrono::{DateTime, Utc};
+use rocket::{http::RawStr, request::FromFormValue};
+use std::{
+    fmt::{Display, Formatter},
+    str::FromStr,
+};
+use uuid::Uuid;
+
+/// Cursor into a feed of activity, identifying the last entry that was already seen.
+///
+/// Clients treat this as an opaque string. It is the hex encoding of the creation time and ID of
+/// the entry, so that new activity being recorded never shifts the position of later pages.
+#[derive(Debug, PartialEq, Clone)]
+pub struct FeedCursor {
+    /// When the last seen activity happened
+    pub created: DateTime<Utc>,
+
+    /// The ID of the last seen activity
+    pub id: ActivityID,
+}
+
+/// Error when a feed cursor couldn't be parsed
+#[derive(Debug, PartialEq, thiserror::Error)]
+#[error("The feed cursor was malformed")]
+pub struct FeedCursorParseError;
+
+impl Display for FeedCursor {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let raw = format!("{}|{}", self.created.to_rfc3339(), self.id);
+        write!(f, "{}", hex::encode(raw))
+    }
+}
+
+impl FromStr for FeedCursor {
+    type Err = FeedCursorParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let decoded = hex::decode(s).map_err(|_| FeedCursorParseError)?;
+        let raw = String::from_utf8(decoded).map_err(|_| FeedCursorParseError)?;
+        let mut parts = raw.splitn(2, '|');
+
+        let created = parts
+            .next()
+            .and_then(|created| DateTime::parse_from_rfc3339(created).ok())
+            .map(|created| created.with_timezone(&Utc))
+            .ok_or(FeedCursorParseError)?;
+        let id = parts
+            .next()
+            .and_then(|id| Uuid::parse_str(id).ok())
+            .map(ActivityID::new)
+            .ok_or(FeedCursorParseError)?;
+
+        Ok(FeedCursor { created, id })
+    }
+}
+
+impl<'v> FromFormValue<'v> for FeedCursor {
+    type Error = &'v RawStr;
+
+    fn from_form_value(form_value: &'v RawStr) -> Result<Self, Self::Error> {
+        form_value.as_str().parse().map_err(|e| {
+            tracing::warn!(e = ?e, value = ?form_value, "Failed to parse feed cursor");
+            form_value
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_round_trip() {
+        let cursor = FeedCursor {
+            created: Utc.ymd(2020, 7, 1).and_hms_milli(12, 34, 56, 789),
+            id: ActivityID::new(Uuid::parse_str("d6c124d5-1060-48ef-aeb8-e591a8261c2b").unwrap()),
+        };
+
+        let encoded = cursor.to_string();
+        assert_eq!(encoded.parse(), Ok(cursor));
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert_eq!("".parse::<FeedCursor>(), Err(FeedCursorParseError));
+        assert_eq!("not hex".parse::<FeedCursor>(), Err(FeedCursorParseError));
+        assert_eq!(
+            hex::encode("2020-07-01T12:34:56Z").parse::<FeedCursor>(),
+            Err(FeedCursorParseError)
+        );
+        assert_eq!(
+            hex::encode("yesterday|d6c124d5-1060-48ef-aeb8-e591a8261c2b").parse::<FeedCursor>(),
+            Err(FeedCursorParseError)
+        );
+    }
+}
@@ -0,0 +1,27 @@
+mod feed;
+mod find;
+mod follows;
+mod parse;
+mod record;
+
+use crate::infrastructure::database::Database;
+
+/// Repository used to access Activity data from the database
+#[derive(Clone)]
+pub(super) struct ActivityRepository {
+    /// The database with which to access activity records
+    database: Database,
+}
+
+impl ActivityRepository {
+    /// Create a new Activity Repository to work with
+    ///
+    /// # Parameters
+    /// - `database` - The database connection to use
+    ///
+    /// # Returns
+    /// The Activity Repository to use
+    pub(super) fn new(database: Database) -> Self {
+        Self { database }
+    }
+}
@@ -0,0 +1,61 @@
+use super::ActivityRepository;
+use crate::{
+    activity::{ActivityModel, FeedCursor},
+    users::UserID,
+    worlds::visible_world_sql,
+};
+
+impl ActivityRepository {
+    /// Find the activity for the users and worlds that a user follows, with the most recent first.
+    ///
+    /// Activity is only included if it happened in a world that the follower is able to see, so that
+    /// following a user doesn't reveal what they do in worlds that aren't yet published.
+    ///
+    /// # Parameters
+    /// - `follower` - The ID of the user whose feed is wanted
+    /// - `after` - The cursor for the last entry already seen, if any
+    /// - `count` - The maximum number of entries to return
+    ///
+    /// # Returns
+    /// The activity that was found
+    #[tracing::instrument(skip(self))]
+    pub fn find_feed(
+        &self,
+        follower: &UserID,
+        after: Option<&FeedCursor>,
+        count: u64,
+    ) -> Vec<ActivityModel> {
+        let mut binds: Vec<&(dyn postgres::types::ToSql + Sync)> = vec![follower];
+        let mut where_clause = format!(
+            "(activity.world_id IN (SELECT world_id FROM world_follows WHERE follower_id = $1)
+            OR activity.user_id IN (SELECT user_id FROM user_follows WHERE follower_id = $1))
+            AND {}",
+            visible_world_sql("worlds", Some(1))
+        );
+
+        if let Some(after) = after {
+            binds.push(&after.created);
+            binds.push(&after.id);
+            where_clause = format!(
+                "{} AND (activity.created, activity.activity_id) < (${}, ${})",
+                where_clause,
+                binds.len() - 1,
+                binds.len()
+            );
+        }
+
+        let mut connection = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+
+        let query = format!(
+            "SELECT activity.* FROM activity JOIN worlds ON worlds.world_id = activity.world_id WHERE {} ORDER BY activity.created DESC, activity.activity_id DESC LIMIT {}",
+            where_clause, count
+        );
+        connection
+            .query(query.as_str(), &binds[..])
+            .map(|rows| rows.iter().map(|row| self.parse_row(row)).collect())
+            .expect("Failed to select feed")
+    }
+}
@@ -0,0 +1,84 @@
+use super::ActivityRepository;
+use crate::{
+    activity::{ActivityFilters, ActivityModel},
+    model::{Page, Pagination},
+    worlds::visible_world_sql,
+};
+
+impl ActivityRepository {
+    /// Search for the activity that matches the given filters, with the most recent first.
+    ///
+    /// Activity is only included if it happened in a world that the viewer is able to see.
+    ///
+    /// # Parameters
+    /// - `filters` - The filters to apply to the search
+    /// - `pagination` - The pagination details for which page of results are wanted
+    ///
+    /// # Returns
+    /// The requested page of results
+    #[tracing::instrument(skip(self))]
+    pub fn search_activity(
+        &self,
+        filters: &ActivityFilters,
+        pagination: &Pagination,
+    ) -> Page<ActivityModel> {
+        // Collect together the Where clauses and Binds
+        let mut where_clauses: Vec<String> = vec![];
+        let mut binds: Vec<&(dyn postgres::types::ToSql + Sync)> = vec![];
+
+        if let Some(world) = &filters.world {
+            binds.push(world);
+            where_clauses.push(format!("activity.world_id = ${}", binds.len()));
+        }
+        if let Some(user) = &filters.user {
+            binds.push(user);
+            where_clauses.push(format!("activity.user_id = ${}", binds.len()));
+        }
+        match &filters.viewer {
+            None => where_clauses.push(visible_world_sql("worlds", None)),
+            Some(viewer) => {
+                binds.push(viewer);
+                where_clauses.push(visible_world_sql("worlds", Some(binds.len())));
+            }
+        }
+        let from_clause = format!(
+            "FROM activity JOIN worlds ON worlds.world_id = activity.world_id WHERE {}",
+            where_clauses.join(" AND ")
+        );
+
+        let mut connection = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+
+        let select_query = format!(
+            "SELECT activity.* {} ORDER BY activity.created DESC, activity.activity_id ASC OFFSET {} LIMIT {}",
+            from_clause, pagination.offset, pagination.count
+        );
+        let records: Vec<ActivityModel> = connection
+            .query(select_query.as_str(), &binds[..])
+            .map(|rows| rows.iter().map(|row| self.parse_row(row)).collect())
+            .expect("Failed to select activity");
+
+        let num_records = records.len() as u64;
+        let total = if records.is_empty() || num_records == pagination.count {
+            // If we got zero records back, or exactly the number we asked for, then we don't know
+            // how many there are in the database
+            let count_query = format!("SELECT COUNT(*)::INTEGER AS c {}", from_clause);
+            let count: i32 = connection
+                .query_one(count_query.as_str(), &binds[..])
+                .map(|row| row.get("c"))
+                .expect("Failed to count activity");
+            count as u64
+        } else {
+            pagination.offset + num_records
+        };
+
+        Page {
+            entries: records,
+            offset: pagination.offset,
+            count: pagination.count,
+            total,
+        }
+    }
+}
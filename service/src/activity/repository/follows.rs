@@ -0,0 +1,87 @@
+use super::ActivityRepository;
+use crate::{users::UserID, worlds::WorldID};
+use chrono::Utc;
+
+impl ActivityRepository {
+    /// Record that a user is following another user. Following a user that is already followed does
+    /// nothing
+    ///
+    /// # Parameters
+    /// - `follower` - The ID of the user that is doing the following
+    /// - `user` - The ID of the user being followed
+    #[tracing::instrument(skip(self))]
+    pub fn follow_user(&self, follower: &UserID, user: &UserID) {
+        let mut connection = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+
+        connection
+            .execute(
+                "INSERT INTO user_follows(follower_id, user_id, created) VALUES($1, $2, $3) ON CONFLICT DO NOTHING",
+                &[follower, user, &Utc::now()],
+            )
+            .expect("Failed to follow user");
+    }
+
+    /// Stop a user from following another user, if they were
+    ///
+    /// # Parameters
+    /// - `follower` - The ID of the user that is doing the following
+    /// - `user` - The ID of the user to stop following
+    #[tracing::instrument(skip(self))]
+    pub fn unfollow_user(&self, follower: &UserID, user: &UserID) {
+        let mut connection = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+
+        connection
+            .execute(
+                "DELETE FROM user_follows WHERE follower_id = $1 AND user_id = $2",
+                &[follower, user],
+            )
+            .expect("Failed to unfollow user");
+    }
+
+    /// Record that a user is following a world. Following a world that is already followed does
+    /// nothing
+    ///
+    /// # Parameters
+    /// - `follower` - The ID of the user that is doing the following
+    /// - `world` - The ID of the world being followed
+    #[tracing::instrument(skip(self))]
+    pub fn follow_world(&self, follower: &UserID, world: &WorldID) {
+        let mut connection = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+
+        connection
+            .execute(
+                "INSERT INTO world_follows(follower_id, world_id, created) VALUES($1, $2, $3) ON CONFLICT DO NOTHING",
+                &[follower, world, &Utc::now()],
+            )
+            .expect("Failed to follow world");
+    }
+
+    /// Stop a user from following a world, if they were
+    ///
+    /// # Parameters
+    /// - `follower` - The ID of the user that is doing the following
+    /// - `world` - The ID of the world to stop following
+    #[tracing::instrument(skip(self))]
+    pub fn unfollow_world(&self, follower: &UserID, world: &WorldID) {
+        let mut connection = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+
+        connection
+            .execute(
+                "DELETE FROM world_follows WHERE follower_id = $1 AND world_id = $2",
+                &[follower, world],
+            )
+            .expect("Failed to unfollow world");
+    }
+}
@@ -0,0 +1,25 @@
+use super::ActivityRepository;
+use crate::activity::model::*;
+use postgres::row::Row;
+
+impl ActivityRepository {
+    /// Parse a row from the database into an Activity Model
+    ///
+    /// # Parameters
+    /// - `row` - The row to parse
+    ///
+    /// # Returns
+    /// The activity that the row represented
+    pub(super) fn parse_row(&self, row: &Row) -> ActivityModel {
+        let event: String = row.get("event");
+
+        ActivityModel {
+            id: row.get("activity_id"),
+            created: row.get("created"),
+            world: row.get("world_id"),
+            user: row.get("user_id"),
+            event: event.parse().expect("Unknown activity event in database"),
+            target: row.get("target"),
+        }
+    }
+}
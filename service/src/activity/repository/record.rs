@@ -0,0 +1,39 @@
+use super::ActivityRepository;
+use crate::activity::ActivityData;
+use chrono::Utc;
+use uuid::Uuid;
+
+impl ActivityRepository {
+    /// Record some activity in the database
+    ///
+    /// # Parameters
+    /// - `activity` - The activity to record
+    ///
+    /// # Errors
+    /// Any errors that occurred recording the activity
+    #[tracing::instrument(skip(self))]
+    pub fn record(&self, activity: &ActivityData) -> Result<(), postgres::Error> {
+        let id = Uuid::new_v4();
+        let now = Utc::now();
+
+        let mut connection = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+
+        connection.execute(
+            "INSERT INTO activity(activity_id, created, world_id, user_id, event, target)
+            SELECT $1, $2, world_id, COALESCE($3, owner_id), $4, $5 FROM worlds WHERE world_id = $6",
+            &[
+                &id,
+                &now,
+                &activity.user,
+                &activity.event.to_string(),
+                &activity.target,
+                &activity.world,
+            ],
+        )?;
+
+        Ok(())
+    }
+}
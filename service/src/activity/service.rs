@@ -0,0 +1,24 @@
+mod feed;
+mod find;
+mod follows;
+mod record;
+
+use super::repository::ActivityRepository;
+pub use find::ActivityFilters;
+pub use follows::FollowUserError;
+
+/// Service Layer for dealing with the Activity within worlds
+#[derive(Clone)]
+pub struct ActivityService {
+    repository: ActivityRepository,
+}
+
+impl ActivityService {
+    /// Create a new instance of the Activity Service
+    ///
+    /// # Returns
+    /// The Activity Service ready to use
+    pub(super) fn new(repository: ActivityRepository) -> Self {
+        Self { repository }
+    }
+}
@@ -0,0 +1,35 @@
+use super::ActivityService;
+use crate::{
+    activity::{FeedCursor, FeedPage},
+    users::UserID,
+};
+
+impl ActivityService {
+    /// Get a page of the feed of activity from everything that a user follows, most recent first
+    ///
+    /// # Parameters
+    /// - `follower` - The ID of the user whose feed is wanted
+    /// - `after` - The cursor for the last entry already seen, if any
+    /// - `count` - The maximum number of entries to return
+    ///
+    /// # Returns
+    /// The requested page of the feed
+    pub fn feed(&self, follower: &UserID, after: Option<&FeedCursor>, count: u64) -> FeedPage {
+        // Ask for one more than we need so that we know whether there is another page
+        let mut entries = self
+            .repository
+            .find_feed(follower, after, count.saturating_add(1));
+
+        let next = if entries.len() as u64 > count {
+            entries.truncate(count as usize);
+            entries.last().map(|last| FeedCursor {
+                created: last.created,
+                id: last.id.clone(),
+            })
+        } else {
+            None
+        };
+
+        FeedPage { entries, next }
+    }
+}
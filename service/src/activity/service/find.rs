@@ -0,0 +1,39 @@
+use super::ActivityService;
+use crate::{
+    activity::ActivityModel,
+    model::{Page, Pagination},
+    users::UserID,
+    worlds::WorldID,
+};
+
+/// Filters to apply when searching for activity
+#[derive(Debug, Default)]
+pub struct ActivityFilters {
+    /// Only include activity within this world
+    pub world: Option<WorldID>,
+
+    /// Only include activity performed by this user
+    pub user: Option<UserID>,
+
+    /// The user doing the search. Activity within worlds is only included if this user is able to see
+    /// the world
+    pub viewer: Option<UserID>,
+}
+
+impl ActivityService {
+    /// Search for activity that matches the given criteria, most recent first
+    ///
+    /// # Parameters
+    /// - `filters` - The filters to apply to the results
+    /// - `pagination` - The pagination details for which page of results are wanted
+    ///
+    /// # Returns
+    /// The requested page of results
+    pub fn search_activity(
+        &self,
+        filters: &ActivityFilters,
+        pagination: &Pagination,
+    ) -> Page<ActivityModel> {
+        self.repository.search_activity(filters, pagination)
+    }
+}
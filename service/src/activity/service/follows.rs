@@ -0,0 +1,56 @@
+use super::ActivityService;
+use crate::{users::UserID, worlds::WorldID};
+
+/// Errors that can occur when following a user
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum FollowUserError {
+    /// The user tried to follow themselves
+    #[error("Users can not follow themselves")]
+    FollowingSelf,
+}
+
+impl ActivityService {
+    /// Have one user follow another, so that their activity appears in the follower's feed
+    ///
+    /// # Parameters
+    /// - `follower` - The ID of the user that is doing the following
+    /// - `user` - The ID of the user to follow
+    ///
+    /// # Errors
+    /// If the user is trying to follow themselves
+    pub fn follow_user(&self, follower: &UserID, user: &UserID) -> Result<(), FollowUserError> {
+        if follower == user {
+            return Err(FollowUserError::FollowingSelf);
+        }
+
+        self.repository.follow_user(follower, user);
+        Ok(())
+    }
+
+    /// Stop one user from following another
+    ///
+    /// # Parameters
+    /// - `follower` - The ID of the user that is doing the following
+    /// - `user` - The ID of the user to stop following
+    pub fn unfollow_user(&self, follower: &UserID, user: &UserID) {
+        self.repository.unfollow_user(follower, user);
+    }
+
+    /// Have a user follow a world, so that activity within it appears in their feed
+    ///
+    /// # Parameters
+    /// - `follower` - The ID of the user that is doing the following
+    /// - `world` - The ID of the world to follow
+    pub fn follow_world(&self, follower: &UserID, world: &WorldID) {
+        self.repository.follow_world(follower, world);
+    }
+
+    /// Stop a user from following a world
+    ///
+    /// # Parameters
+    /// - `follower` - The ID of the user that is doing the following
+    /// - `world` - The ID of the world to stop following
+    pub fn unfollow_world(&self, follower: &UserID, world: &WorldID) {
+        self.repository.unfollow_world(follower, world);
+    }
+}
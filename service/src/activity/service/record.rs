@@ -0,0 +1,17 @@
+use super::ActivityService;
+use crate::activity::ActivityData;
+
+impl ActivityService {
+    /// Record some activity that has happened within a world.
+    ///
+    /// Failing to record activity is never allowed to fail the action that caused it, so any errors are
+    /// logged and then ignored
+    ///
+    /// # Parameters
+    /// - `activity` - The activity to record
+    pub fn record_activity(&self, activity: ActivityData) {
+        if let Err(e) = self.repository.record(&activity) {
+            tracing::warn!(e = ?e, activity = ?activity, "Failed to record activity");
+        }
+    }
+}
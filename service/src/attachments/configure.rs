@@ -0,0 +1,62 @@
+use super::{repository::AttachmentRepository, AttachmentsService};
+use crate::{
+    infrastructure::{blobs::SharedBlobStore, database::Database},
+    quotas::QuotasService,
+};
+use std::sync::Arc;
+
+/// Application Configuration for the Attachments module
+pub struct AttachmentsConfig {
+    pub attachments_service: AttachmentsService,
+}
+
+impl AttachmentsConfig {
+    /// Create the Configuration for the Attachments Module
+    ///
+    /// # Parameters
+    /// - `database` - The database connection to use
+    /// - `blobs` - The blob store to keep the uploaded files in
+    /// - `max_size` - The largest file, in bytes, that can be uploaded
+    /// - `quotas_service` - The quotas service to limit how much users can upload
+    ///
+    /// # Returns
+    /// The Attachments Config object
+    pub fn new(
+        database: Database,
+        blobs: SharedBlobStore,
+        max_size: u64,
+        quotas_service: QuotasService,
+    ) -> Self {
+        let repository = AttachmentRepository::new(database);
+        Self {
+            attachments_service: AttachmentsService::new(
+                repository,
+                blobs,
+                max_size,
+                quotas_service,
+            ),
+        }
+    }
+
+    /// Generate the configuration callback needed for the HTTP Server to actually add the Attachments
+    /// endpoints to the server
+    ///
+    /// # Returns
+    /// The callback to provide to the HTTP Server to configure up the Attachments endpoints
+    pub fn configure(&self) -> Arc<dyn Fn(rocket::Rocket) -> rocket::Rocket + Send + Sync> {
+        let attachments_service = self.attachments_service.clone();
+        Arc::new(move |config| {
+            config.manage(attachments_service.clone()).mount(
+                "/",
+                rocket::routes![
+                    super::endpoints::create_attachment,
+                    super::endpoints::delete_attachment,
+                    super::endpoints::get_attachment,
+                    super::endpoints::get_attachment_content,
+                    super::endpoints::get_attachment_thumbnail,
+                    super::endpoints::list_attachments
+                ],
+            )
+        })
+    }
+}
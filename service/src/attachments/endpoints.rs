@@ -0,0 +1,15 @@
+mod create_attachment;
+mod delete_attachment;
+mod errors;
+mod get_attachment;
+mod get_attachment_content;
+mod get_attachment_thumbnail;
+mod list_attachments;
+pub(crate) mod model;
+
+pub use create_attachment::*;
+pub use delete_attachment::*;
+pub use get_attachment::*;
+pub use get_attachment_content::*;
+pub use get_attachment_thumbnail::*;
+pub use list_attachments::*;
@@ -0,0 +1,110 @@
+use super::{errors::AttachmentProblemType, model::AttachmentResponse};
+use crate::{
+    attachments::{AttachmentsService, CreateAttachmentError},
+    authorization::Authorizer,
+    http::{problem::Problem, upload::read_upload},
+    worlds::{
+        endpoints::{
+            errors::WorldProblemType,
+            world_owner::{authorize_world_viewer, is_world_editor},
+        },
+        WorldID, WorldsService,
+    },
+};
+use rocket::{
+    http::{ContentType, Status},
+    post, Data, State,
+};
+
+/// The name to give an uploaded file if the client didn't provide one
+const DEFAULT_FILENAME: &str = "attachment";
+
+/// Handler to attach a file to a World.
+///
+/// The file is uploaded as the `file` field of a `multipart/form-data` request. Only editors of the
+/// world may attach files to it
+///
+/// # Parameters
+/// - `attachments_service` - The attachments service to use
+/// - `worlds_service` - The worlds service to use
+/// - `world` - The ID of the world to attach the file to
+/// - `content_type` - The content type of the request
+/// - `data` - The body of the request
+/// - `authorizer` - The authorizer to prove who is uploading the file
+///
+/// # Returns
+/// The newly created attachment details, or a Problem if the upload failed
+#[tracing::instrument(
+    name = "POST /worlds/{id}/attachments",
+    skip(attachments_service, worlds_service, data)
+)]
+#[post("/worlds/<world>/attachments", data = "<data>")]
+pub fn create_attachment(
+    attachments_service: State<AttachmentsService>,
+    worlds_service: State<WorldsService>,
+    world: WorldID,
+    content_type: &ContentType,
+    data: Data,
+    authorizer: Authorizer,
+) -> Result<AttachmentResponse, Problem> {
+    let owner = authorizer
+        .authorize()
+        .authorized()
+        .finish()?
+        .expect("No authenticated user");
+
+    let world = authorize_world_viewer(&worlds_service, &authorizer, &world)?;
+    if !is_world_editor(&worlds_service, &authorizer, &world) {
+        // The owner is always an editor, so this rejects everyone else as Forbidden
+        authorizer
+            .authorize()
+            .same_user(&world.data.owner)
+            .finish()?;
+    }
+
+    let upload = read_upload(content_type, data, "file", attachments_service.max_size())?;
+
+    // Only keep the final part of the filename, in case the client sent a full path
+    let filename = upload
+        .filename
+        .as_ref()
+        .and_then(|filename| filename.rsplit(|c: char| c == '/' || c == '\\').next())
+        .filter(|filename| !filename.is_empty())
+        .unwrap_or(DEFAULT_FILENAME)
+        .to_owned();
+
+    let attachment = attachments_service.create_attachment(
+        world.identity.id,
+        owner,
+        filename,
+        &upload.content,
+    )?;
+    Ok(AttachmentResponse(attachment))
+}
+
+impl From<CreateAttachmentError> for Problem {
+    fn from(e: CreateAttachmentError) -> Self {
+        match e {
+            CreateAttachmentError::UnknownWorld => {
+                Problem::new(WorldProblemType::UnknownWorldID, Status::NotFound)
+            }
+            CreateAttachmentError::UnsupportedContentType => Problem::new(
+                AttachmentProblemType::UnsupportedContentType,
+                Status::UnsupportedMediaType,
+            ),
+            CreateAttachmentError::QuotaExceeded(e) => Problem::new(
+                AttachmentProblemType::QuotaExceeded,
+                Status::PayloadTooLarge,
+            )
+            .with_extra("used", e.used)
+            .with_extra("quota", e.limit),
+            _ => {
+                tracing::warn!(error = ?e, "An unexpected error occurred");
+                Problem::new(
+                    AttachmentProblemType::UnknownError,
+                    Status::InternalServerError,
+                )
+            }
+        }
+    }
+}
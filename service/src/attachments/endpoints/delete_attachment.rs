@@ -0,0 +1,57 @@
+use super::errors::AttachmentProblemType;
+use crate::{
+    attachments::{AttachmentID, AttachmentsService},
+    authorization::Authorizer,
+    http::problem::Problem,
+    worlds::{endpoints::errors::WorldProblemType, WorldID, WorldsService},
+};
+use rocket::{delete, http::Status, Response, State};
+
+/// Handler to delete an Attachment from a World, along with the stored file.
+///
+/// Attachments may be deleted either by the user that uploaded them or by the owner of the world that
+/// they are on.
+///
+/// # Parameters
+/// - `attachments_service` - The attachments service to use
+/// - `worlds_service` - The worlds service to use
+/// - `world` - The ID of the world
+/// - `id` - The ID of the attachment to delete
+/// - `authorizer` - The authorizer to prove we're allowed to delete the attachment
+///
+/// # Returns
+/// An empty response if the attachment was deleted, or a Problem if the deletion failed
+#[tracing::instrument(
+    name = "DELETE /worlds/{id}/attachments/{id}",
+    skip(attachments_service, worlds_service)
+)]
+#[delete("/worlds/<world>/attachments/<id>")]
+pub fn delete_attachment(
+    attachments_service: State<AttachmentsService>,
+    worlds_service: State<WorldsService>,
+    world: WorldID,
+    id: AttachmentID,
+    authorizer: Authorizer,
+) -> Result<Response<'static>, Problem> {
+    let attachment = attachments_service
+        .find_attachment_by_id(&id)
+        .filter(|attachment| attachment.data.world == world)
+        .ok_or_else(|| Problem::new(AttachmentProblemType::UnknownAttachment, Status::NotFound))?;
+    let world = worlds_service
+        .find_world_by_id(world)
+        .ok_or_else(|| Problem::new(WorldProblemType::UnknownWorldID, Status::NotFound))?;
+
+    authorizer
+        .authorize()
+        .one_of_users(&[attachment.data.owner, world.data.owner])
+        .finish()?;
+
+    if attachments_service.delete_attachment(&id) {
+        Ok(Response::build().status(Status::NoContent).finalize())
+    } else {
+        Err(Problem::new(
+            AttachmentProblemType::UnknownAttachment,
+            Status::NotFound,
+        ))
+    }
+}
@@ -0,0 +1,43 @@
+use crate::http::problem::ProblemType;
+
+/// Problem Types that can happen when working with attachments
+#[derive(Debug, thiserror::Error)]
+pub enum AttachmentProblemType {
+    /// An unknown error occurred
+    #[error("An unknown error occurred")]
+    UnknownError,
+
+    /// The attachment that was looked up was not found
+    #[error("The requested attachment was unknown")]
+    UnknownAttachment,
+
+    /// The uploaded file was not a type that can be attached
+    #[error("The uploaded file was not a supported type")]
+    UnsupportedContentType,
+
+    /// The uploaded file would take the user over their quota
+    #[error("The upload quota has been exceeded")]
+    QuotaExceeded,
+}
+
+impl ProblemType for AttachmentProblemType {
+    /// Generate a Type value for the `ProblemType` values.
+    ///
+    /// These are used in the `type` field in the RFC-7807 Problem Response
+    fn error_code(&self) -> &'static str {
+        match self {
+            AttachmentProblemType::UnknownError => {
+                "tag:multiverse,2020:attachments/problems/unknown_error"
+            }
+            AttachmentProblemType::UnknownAttachment => {
+                "tag:multiverse,2020:attachments/problems/unknown_attachment"
+            }
+            AttachmentProblemType::UnsupportedContentType => {
+                "tag:multiverse,2020:attachments/problems/unsupported_content_type"
+            }
+            AttachmentProblemType::QuotaExceeded => {
+                "tag:multiverse,2020:attachments/problems/quota_exceeded"
+            }
+        }
+    }
+}
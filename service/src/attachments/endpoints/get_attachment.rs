@@ -0,0 +1,70 @@
+use super::{errors::AttachmentProblemType, model::AttachmentResponse};
+use crate::{
+    attachments::{AttachmentID, AttachmentModel, AttachmentsService},
+    authorization::Authorizer,
+    http::problem::Problem,
+    worlds::{endpoints::world_owner::authorize_world_viewer, WorldID, WorldsService},
+};
+use rocket::{get, http::Status, State};
+
+/// Look up an attachment on a world that the current user is allowed to see
+///
+/// # Parameters
+/// - `attachments_service` - The attachments service to use
+/// - `worlds_service` - The worlds service to use
+/// - `authorizer` - The authorizer to identify the current user
+/// - `world` - The ID of the world
+/// - `id` - The ID of the attachment
+///
+/// # Returns
+/// The attachment
+///
+/// # Errors
+/// A Problem if the world or attachment doesn't exist, or the current user can't see the world
+pub(super) fn find_attachment(
+    attachments_service: &AttachmentsService,
+    worlds_service: &WorldsService,
+    authorizer: &Authorizer,
+    world: &WorldID,
+    id: &AttachmentID,
+) -> Result<AttachmentModel, Problem> {
+    authorize_world_viewer(worlds_service, authorizer, world)?;
+
+    attachments_service
+        .find_attachment_by_id(id)
+        .filter(|attachment| &attachment.data.world == world)
+        .ok_or_else(|| Problem::new(AttachmentProblemType::UnknownAttachment, Status::NotFound))
+}
+
+/// Handler to get the details of an Attachment on a World
+///
+/// # Parameters
+/// - `attachments_service` - The attachments service to use
+/// - `worlds_service` - The worlds service to use
+/// - `world` - The ID of the world
+/// - `id` - The ID of the attachment
+/// - `authorizer` - The authorizer to identify the current user
+///
+/// # Returns
+/// The details of the attachment
+#[tracing::instrument(
+    name = "GET /worlds/{id}/attachments/{id}",
+    skip(attachments_service, worlds_service)
+)]
+#[get("/worlds/<world>/attachments/<id>")]
+pub fn get_attachment(
+    attachments_service: State<AttachmentsService>,
+    worlds_service: State<WorldsService>,
+    world: WorldID,
+    id: AttachmentID,
+    authorizer: Authorizer,
+) -> Result<AttachmentResponse, Problem> {
+    find_attachment(
+        &attachments_service,
+        &worlds_service,
+        &authorizer,
+        &world,
+        &id,
+    )
+    .map(AttachmentResponse)
+}
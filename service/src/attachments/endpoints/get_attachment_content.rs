@@ -0,0 +1,58 @@
+use super::{errors::AttachmentProblemType, get_attachment::find_attachment};
+use crate::{
+    attachments::{AttachmentID, AttachmentsService},
+    authorization::Authorizer,
+    http::{blob::BlobResponse, problem::Problem},
+    worlds::{WorldID, WorldsService},
+};
+use rocket::{get, http::Status, State};
+
+/// Handler to download the file of an Attachment on a World.
+///
+/// The file is served with a strong ETag of its hash, and supports conditional and range requests
+///
+/// # Parameters
+/// - `attachments_service` - The attachments service to use
+/// - `worlds_service` - The worlds service to use
+/// - `world` - The ID of the world
+/// - `id` - The ID of the attachment
+/// - `authorizer` - The authorizer to identify the current user
+///
+/// # Returns
+/// The contents of the file
+#[tracing::instrument(
+    name = "GET /worlds/{id}/attachments/{id}/content",
+    skip(attachments_service, worlds_service)
+)]
+#[get("/worlds/<world>/attachments/<id>/content")]
+pub fn get_attachment_content(
+    attachments_service: State<AttachmentsService>,
+    worlds_service: State<WorldsService>,
+    world: WorldID,
+    id: AttachmentID,
+    authorizer: Authorizer,
+) -> Result<BlobResponse, Problem> {
+    let attachment = find_attachment(
+        &attachments_service,
+        &worlds_service,
+        &authorizer,
+        &world,
+        &id,
+    )?;
+
+    let body = attachments_service
+        .load_content(&attachment)
+        .ok_or_else(|| {
+            Problem::new(
+                AttachmentProblemType::UnknownError,
+                Status::InternalServerError,
+            )
+        })?;
+
+    Ok(BlobResponse {
+        body,
+        content_type: attachment.data.content_type,
+        etag: attachment.data.hash,
+        filename: Some(attachment.data.filename),
+    })
+}
@@ -0,0 +1,53 @@
+use super::{errors::AttachmentProblemType, get_attachment::find_attachment};
+use crate::{
+    attachments::{AttachmentID, AttachmentsService},
+    authorization::Authorizer,
+    http::{blob::BlobResponse, problem::Problem},
+    worlds::{WorldID, WorldsService},
+};
+use rocket::{get, http::Status, State};
+
+/// Handler to download the thumbnail of an Attachment on a World.
+///
+/// Thumbnails only exist for attachments that are images
+///
+/// # Parameters
+/// - `attachments_service` - The attachments service to use
+/// - `worlds_service` - The worlds service to use
+/// - `world` - The ID of the world
+/// - `id` - The ID of the attachment
+/// - `authorizer` - The authorizer to identify the current user
+///
+/// # Returns
+/// The thumbnail, as a PNG
+#[tracing::instrument(
+    name = "GET /worlds/{id}/attachments/{id}/thumbnail",
+    skip(attachments_service, worlds_service)
+)]
+#[get("/worlds/<world>/attachments/<id>/thumbnail")]
+pub fn get_attachment_thumbnail(
+    attachments_service: State<AttachmentsService>,
+    worlds_service: State<WorldsService>,
+    world: WorldID,
+    id: AttachmentID,
+    authorizer: Authorizer,
+) -> Result<BlobResponse, Problem> {
+    let attachment = find_attachment(
+        &attachments_service,
+        &worlds_service,
+        &authorizer,
+        &world,
+        &id,
+    )?;
+
+    let body = attachments_service
+        .load_thumbnail(&attachment)
+        .ok_or_else(|| Problem::new(AttachmentProblemType::UnknownAttachment, Status::NotFound))?;
+
+    Ok(BlobResponse {
+        body,
+        content_type: "image/png".to_owned(),
+        etag: format!("{}-thumbnail", attachment.data.hash),
+        filename: None,
+    })
+}
@@ -0,0 +1,39 @@
+use super::model::AttachmentsResponse;
+use crate::{
+    attachments::AttachmentsService,
+    authorization::Authorizer,
+    http::{pagination::PaginationRequest, problem::Problem},
+    worlds::{endpoints::world_owner::authorize_world_viewer, WorldID, WorldsService},
+};
+use rocket::{get, State};
+
+/// Handler to list the Attachments on a World, newest first
+///
+/// # Parameters
+/// - `attachments_service` - The attachments service to use
+/// - `worlds_service` - The worlds service to use
+/// - `world` - The ID of the world to list the attachments of
+/// - `pagination` - The pagination controls to apply
+/// - `authorizer` - The authorizer to identify the current user
+///
+/// # Returns
+/// The details of the attachments
+#[tracing::instrument(
+    name = "GET /worlds/{id}/attachments",
+    skip(attachments_service, worlds_service)
+)]
+#[get("/worlds/<world>/attachments?<pagination..>")]
+pub fn list_attachments(
+    attachments_service: State<AttachmentsService>,
+    worlds_service: State<WorldsService>,
+    world: WorldID,
+    pagination: PaginationRequest,
+    authorizer: Authorizer,
+) -> Result<AttachmentsResponse, Problem> {
+    authorize_world_viewer(&worlds_service, &authorizer, &world)?;
+
+    let attachments = attachments_service.search_attachments(&world, &pagination);
+    tracing::debug!(attachments = ?attachments, "Found attachments");
+
+    Ok(AttachmentsResponse(attachments))
+}
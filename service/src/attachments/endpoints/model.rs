@@ -0,0 +1,7 @@
+mod attachment_link;
+mod attachment_response;
+mod attachments_response;
+
+pub use attachment_link::*;
+pub use attachment_response::*;
+pub use attachments_response::*;
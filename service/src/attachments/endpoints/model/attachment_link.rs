@@ -0,0 +1,19 @@
+use crate::{attachments::AttachmentID, worlds::WorldID};
+
+/// Representation of a link to an attachment
+pub struct AttachmentLink(WorldID, AttachmentID);
+
+impl AttachmentLink {
+    pub fn new(world_id: WorldID, attachment_id: AttachmentID) -> Self {
+        Self(world_id, attachment_id)
+    }
+}
+
+impl From<AttachmentLink> for String {
+    fn from(attachment_link: AttachmentLink) -> String {
+        format!(
+            "/worlds/{}/attachments/{}",
+            attachment_link.0, attachment_link.1
+        )
+    }
+}
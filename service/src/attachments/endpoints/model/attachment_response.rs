@@ -0,0 +1,62 @@
+use super::AttachmentLink;
+use crate::{
+    attachments::AttachmentModel,
+    http::link::{Link, LinkRel, Links},
+    users::{endpoints::model::UserLink, UsersService},
+    worlds::endpoints::model::WorldLink,
+};
+use rocket::{
+    http::hyper::header::{CacheControl, CacheDirective, ETag, EntityTag},
+    response, Request, State,
+};
+use rocket_contrib::json::Json;
+use serde_json::{json, Value};
+
+/// API Model representing an Attachment
+#[derive(Debug)]
+pub struct AttachmentResponse(pub AttachmentModel);
+
+pub(super) fn to_json(attachment: &AttachmentModel) -> Value {
+    json!({
+      "filename": attachment.data.filename,
+      "content_type": attachment.data.content_type,
+      "size": attachment.data.size,
+      "created": attachment.identity.created,
+      "updated": attachment.identity.updated
+    })
+}
+
+impl<'r> response::Responder<'r> for AttachmentResponse {
+    fn respond_to(self, req: &Request) -> response::Result<'r> {
+        let users_service = req.guard::<State<UsersService>>().unwrap();
+        let user = users_service.find_user_by_id(&self.0.data.owner);
+        let user_name = user.map(|u| u.data.display_name).unwrap_or_default();
+
+        let etag = self.0.identity.version.to_string();
+        let self_link: String =
+            AttachmentLink::new(self.0.data.world.clone(), self.0.identity.id.clone()).into();
+
+        let mut links = vec![
+            Link::new(self_link.clone(), LinkRel::SELF),
+            Link::new(UserLink::new(self.0.data.owner.clone()), LinkRel::AUTHOR).title(user_name),
+            Link::new(WorldLink::new(self.0.data.world.clone()), LinkRel::RELATED),
+            Link::new(format!("{}/content", self_link), LinkRel::ENCLOSURE),
+        ];
+        if self.0.data.has_thumbnail {
+            links.push(Link::new(
+                format!("{}/thumbnail", self_link),
+                LinkRel::PREVIEW,
+            ));
+        }
+
+        response::Response::build()
+            .merge(Json(to_json(&self.0)).respond_to(req).unwrap())
+            .header(CacheControl(vec![
+                CacheDirective::Private,
+                CacheDirective::MaxAge(3600),
+            ]))
+            .header(ETag(EntityTag::new(false, etag)))
+            .header(Links(links))
+            .ok()
+    }
+}
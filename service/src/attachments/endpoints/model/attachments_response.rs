@@ -0,0 +1,98 @@
+use super::{to_json, AttachmentLink};
+use crate::{
+    attachments::AttachmentModel,
+    http::link::{Link, LinkRel, Links},
+    model::Page,
+    users::{endpoints::model::UserLink, UserID, UserModel, UsersService},
+};
+use itertools::*;
+use rocket::{
+    http::hyper::header::{CacheControl, CacheDirective},
+    response, Request, State,
+};
+use rocket_contrib::json::Json;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+
+/// API Model representing a page of Attachments
+#[derive(Debug)]
+pub struct AttachmentsResponse(pub Page<AttachmentModel>);
+
+impl<'r> response::Responder<'r> for AttachmentsResponse {
+    fn respond_to(self, req: &Request) -> response::Result<'r> {
+        let users_service = req.guard::<State<UsersService>>().unwrap();
+
+        let user_ids: Vec<&UserID> = self
+            .0
+            .entries
+            .iter()
+            .map(|attachment| &attachment.data.owner)
+            .unique()
+            .collect();
+        let users: HashMap<UserID, UserModel> = if user_ids.is_empty() {
+            HashMap::new()
+        } else {
+            users_service
+                .find_users_by_id(&user_ids[..])
+                .into_iter()
+                .map(|user| (user.identity.id.clone(), user))
+                .collect()
+        };
+
+        let attachments: Vec<Value> = self
+            .0
+            .entries
+            .iter()
+            .map(|attachment| to_json(&attachment))
+            .collect();
+
+        let response_body = json!({
+          "entries": attachments,
+          "pagination": {
+            "offset": self.0.offset,
+            "count": self.0.count,
+            "total": self.0.total
+          }
+        });
+
+        let mut attachment_links: Vec<Link> = self
+            .0
+            .entries
+            .iter()
+            .map(|attachment| {
+                AttachmentLink::new(
+                    attachment.data.world.clone(),
+                    attachment.identity.id.clone(),
+                )
+            })
+            .enumerate()
+            .map(|(index, link)| {
+                Link::new(link, LinkRel::ITEM).anchor(format!("#/entries/{}", index))
+            })
+            .collect();
+        let user_links: Vec<Link> = self
+            .0
+            .entries
+            .iter()
+            .enumerate()
+            .filter_map(|(index, attachment)| {
+                users.get(&attachment.data.owner).map(|user| (index, user))
+            })
+            .map(|(index, user)| {
+                Link::new(UserLink::new(user.identity.id.clone()), LinkRel::AUTHOR)
+                    .anchor(format!("#/entries/{}", index))
+                    .title(user.data.display_name.clone())
+            })
+            .collect();
+        attachment_links.extend(user_links);
+
+        response::Response::build()
+            .merge(Json(response_body).respond_to(req).unwrap())
+            .header(CacheControl(vec![
+                CacheDirective::Private,
+                CacheDirective::MaxAge(3600),
+            ]))
+            .header(Links(attachment_links))
+            .ok()
+    }
+}
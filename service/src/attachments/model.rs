@@ -0,0 +1,5 @@
+mod attachment;
+mod attachment_id;
+
+pub use attachment::*;
+pub use attachment_id::*;
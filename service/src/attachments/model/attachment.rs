@@ -0,0 +1,30 @@
+use super::AttachmentID;
+use crate::{model::Model, users::UserID, worlds::WorldID};
+
+/// Data to represent a file that has been attached to a world, such as artwork or a handout
+#[derive(Debug, Clone)]
+pub struct AttachmentData {
+    /// The ID of the world that the file is attached to
+    pub world: WorldID,
+
+    /// The ID of the user that uploaded the file
+    pub owner: UserID,
+
+    /// The name of the file
+    pub filename: String,
+
+    /// The content type of the file, as determined from the contents of the file itself
+    pub content_type: String,
+
+    /// The size of the file, in bytes
+    pub size: u64,
+
+    /// The SHA-256 hash of the contents of the file, in hex
+    pub hash: String,
+
+    /// Whether a thumbnail was generated for the file
+    pub has_thumbnail: bool,
+}
+
+/// Model representation of an attachment
+pub type AttachmentModel = Model<AttachmentID, AttachmentData>;
@@ -0,0 +1,67 @@
+use bytes::BytesMut;
+use postgres_types::{accepts, to_sql_checked, FromSql, IsNull, ToSql, Type};
+use rocket::{http::RawStr, request};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use uuid::Uuid;
+
+/// The ID of an Attachment
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, FromSql)]
+pub struct AttachmentID(Uuid);
+
+impl AttachmentID {
+    /// Wrap a UUID as an Attachment ID object
+    ///
+    /// # Parameters
+    /// - `uuid` - The UUID to wrap
+    ///
+    /// # The Attachment ID
+    pub fn new(uuid: Uuid) -> Self {
+        AttachmentID(uuid)
+    }
+}
+
+impl Default for AttachmentID {
+    fn default() -> Self {
+        Self(Uuid::new_v4())
+    }
+}
+
+impl ToSql for AttachmentID {
+    accepts!(UUID);
+
+    to_sql_checked!();
+
+    fn to_sql(
+        &self,
+        t: &Type,
+        w: &mut BytesMut,
+    ) -> Result<IsNull, Box<dyn std::error::Error + Sync + Send>> {
+        self.0.to_sql(t, w)
+    }
+}
+
+impl<'r> request::FromParam<'r> for AttachmentID {
+    type Error = &'r RawStr;
+
+    fn from_param(param: &'r RawStr) -> Result<Self, Self::Error> {
+        param
+            .percent_decode()
+            .map(|cow| cow.into_owned())
+            .map_err(|_| param)
+            .and_then(|attachment_id| Uuid::parse_str(&attachment_id).map_err(|_| param))
+            .map(|attachment_id| AttachmentID::new(attachment_id))
+    }
+}
+
+impl Display for AttachmentID {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<AttachmentID> for Uuid {
+    fn from(attachment_id: AttachmentID) -> Self {
+        attachment_id.0
+    }
+}
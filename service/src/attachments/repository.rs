@@ -0,0 +1,28 @@
+mod create;
+mod delete;
+mod errors;
+mod find;
+mod parse;
+
+use crate::infrastructure::database::Database;
+pub use errors::*;
+
+/// Repository used to access Attachment data from the database
+#[derive(Clone)]
+pub(super) struct AttachmentRepository {
+    /// The database with which to access attachment records
+    database: Database,
+}
+
+impl AttachmentRepository {
+    /// Create a new Attachment Repository to work with
+    ///
+    /// # Parameters
+    /// - `database` - The database connection to use
+    ///
+    /// # Returns
+    /// The Attachment Repository to use
+    pub(super) fn new(database: Database) -> Self {
+        Self { database }
+    }
+}
@@ -0,0 +1,72 @@
+use super::{AttachmentRepository, SaveAttachmentError};
+use crate::{
+    attachments::model::*,
+    events::{publish_change, EntityType, WorldChange},
+    quotas::{enforce_quota, QuotaAllowance},
+};
+use chrono::Utc;
+use uuid::Uuid;
+
+impl AttachmentRepository {
+    /// Create a new attachment in the database, enforcing the upload quota of the owner in the same
+    /// transaction
+    ///
+    /// # Parameters
+    /// - `id` - The ID to give the new attachment
+    /// - `attachment` - The attachment to create
+    /// - `quota` - The allowance for the owner to upload the file
+    ///
+    /// # Returns
+    /// The newly created attachment
+    ///
+    /// # Errors
+    /// Any errors that occurred creating the new attachment
+    #[tracing::instrument(skip(self))]
+    pub fn create(
+        &self,
+        id: AttachmentID,
+        attachment: AttachmentData,
+        quota: &QuotaAllowance,
+    ) -> Result<AttachmentModel, SaveAttachmentError> {
+        let now = Utc::now();
+        let version = Uuid::new_v4();
+        let size = attachment.size as i64;
+
+        let mut connection = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+        let mut transaction = connection.transaction()?;
+
+        enforce_quota(&mut transaction, quota)?;
+
+        let new_attachment = transaction.query_one("INSERT INTO attachments(attachment_id, version, created, updated, world_id, owner_id, filename, content_type, size, hash, has_thumbnail) VALUES($1, $2, $3, $3, $4, $5, $6, $7, $8, $9, $10) RETURNING *",
+            &[
+                &id,
+                &version,
+                &now,
+                &attachment.world,
+                &attachment.owner,
+                &attachment.filename,
+                &attachment.content_type,
+                &size,
+                &attachment.hash,
+                &attachment.has_thumbnail,
+            ])
+            .map(|row| self.parse_row(&row))?;
+
+        transaction.commit()?;
+
+        publish_change(
+            &mut connection,
+            WorldChange {
+                world: new_attachment.data.world.clone(),
+                entity_type: EntityType::Attachment,
+                entity_id: new_attachment.identity.id.clone().into(),
+                version: Some(new_attachment.identity.version),
+            },
+        );
+
+        Ok(new_attachment)
+    }
+}
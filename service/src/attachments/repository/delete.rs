@@ -0,0 +1,47 @@
+use super::AttachmentRepository;
+use crate::{
+    attachments::AttachmentID,
+    events::{publish_change, EntityType, WorldChange},
+    worlds::WorldID,
+};
+
+impl AttachmentRepository {
+    /// Delete an attachment from the database
+    ///
+    /// # Parameters
+    /// - `id` - The ID of the attachment to delete
+    ///
+    /// # Returns
+    /// True if the attachment was deleted. False if it didn't exist
+    #[tracing::instrument(skip(self))]
+    pub fn delete(&self, id: &AttachmentID) -> bool {
+        let mut connection = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+
+        let world: Option<WorldID> = connection
+            .query_opt(
+                "DELETE FROM attachments WHERE attachment_id = $1 RETURNING world_id",
+                &[id],
+            )
+            .expect("Failed to delete attachment")
+            .map(|row| row.get("world_id"));
+
+        match world {
+            Some(world) => {
+                publish_change(
+                    &mut connection,
+                    WorldChange {
+                        world,
+                        entity_type: EntityType::Attachment,
+                        entity_id: id.clone().into(),
+                        version: None,
+                    },
+                );
+                true
+            }
+            None => false,
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use crate::quotas::QuotaExceededError;
+use postgres::error::{DbError, SqlState};
+
+/// Errors that can occur when saving an attachment record
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum SaveAttachmentError {
+    /// An unknown error occurred
+    #[error("An unknown error occurred")]
+    UnknownError,
+
+    /// The world that the attachment is on does not exist
+    #[error("The desired world does not exist")]
+    UnknownWorld,
+
+    /// Storing the file would take the user over their quota
+    #[error("The upload quota has been exceeded")]
+    QuotaExceeded(#[from] QuotaExceededError),
+}
+
+impl From<postgres::Error> for SaveAttachmentError {
+    /// Convert a database error into a SaveAttachmentError.
+    ///
+    /// The conversion returns an `UnknownWorld` iff the error is a `FOREIGN_KEY_VIOLATION` and the constraint
+    /// is `attachments_world_id_fkey`. Otherwise it returns an `UnknownError`
+    ///
+    /// # Parameters
+    /// - `e` - The error to convert
+    ///
+    /// # Returns
+    /// The new error code
+    fn from(e: postgres::Error) -> Self {
+        if e.code() == Some(&SqlState::FOREIGN_KEY_VIOLATION) {
+            let db_error: Option<DbError> = e
+                .into_source()
+                .and_then(|e| e.downcast_ref::<DbError>().cloned());
+
+            match db_error.as_ref().and_then(|e| e.constraint()) {
+                Some("attachments_world_id_fkey") => SaveAttachmentError::UnknownWorld,
+                constraint => {
+                    tracing::warn!(
+                        "Unexpected foreign key constraint violation error: {:?}",
+                        constraint
+                    );
+                    SaveAttachmentError::UnknownError
+                }
+            }
+        } else {
+            tracing::warn!("Unexpected database error: {:?}", e);
+            SaveAttachmentError::UnknownError
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use super::AttachmentRepository;
+use crate::{
+    attachments::{AttachmentID, AttachmentModel},
+    model::{Page, Pagination},
+    worlds::WorldID,
+};
+
+impl AttachmentRepository {
+    /// Find an attachment in the database by its unique ID
+    ///
+    /// # Parameters
+    /// - `id` - The ID of the attachment
+    ///
+    /// # Returns
+    /// The attachment, if it exists
+    #[tracing::instrument(skip(self))]
+    pub fn find_attachment_by_id(&self, id: &AttachmentID) -> Option<AttachmentModel> {
+        let mut connection = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+        connection
+            .query_opt("SELECT * FROM attachments WHERE attachment_id = $1", &[id])
+            .expect("Failed to query for attachment by ID")
+            .map(|row| self.parse_row(&row))
+    }
+
+    /// Search for the attachments on a world, with the newest first
+    ///
+    /// # Parameters
+    /// - `world` - The ID of the world
+    /// - `pagination` - The pagination details for which page of results are wanted
+    ///
+    /// # Returns
+    /// The requested page of results
+    #[tracing::instrument(skip(self))]
+    pub fn search_attachments(
+        &self,
+        world: &WorldID,
+        pagination: &Pagination,
+    ) -> Page<AttachmentModel> {
+        let mut connection = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+
+        let select_query = format!(
+            "SELECT * FROM attachments WHERE world_id = $1 ORDER BY created DESC, attachment_id ASC OFFSET {} LIMIT {}",
+            pagination.offset, pagination.count
+        );
+        let records: Vec<AttachmentModel> = connection
+            .query(select_query.as_str(), &[world])
+            .map(|rows| rows.iter().map(|row| self.parse_row(row)).collect())
+            .expect("Failed to select attachments");
+
+        let num_records = records.len() as u64;
+        let total = if records.is_empty() || num_records == pagination.count {
+            // If we got zero records back, or exactly the number we asked for, then we don't know
+            // how many there are in the database
+            let count: i32 = connection
+                .query_one(
+                    "SELECT COUNT(*)::INTEGER AS c FROM attachments WHERE world_id = $1",
+                    &[world],
+                )
+                .map(|row| row.get("c"))
+                .expect("Failed to count attachments");
+            count as u64
+        } else {
+            pagination.offset + num_records
+        };
+
+        Page {
+            entries: records,
+            offset: pagination.offset,
+            count: pagination.count,
+            total,
+        }
+    }
+}
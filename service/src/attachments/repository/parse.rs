@@ -0,0 +1,34 @@
+use super::AttachmentRepository;
+use crate::{attachments::model::*, model::Identity};
+use postgres::row::Row;
+
+impl AttachmentRepository {
+    /// Parse a row from the database into an Attachment Model
+    ///
+    /// # Parameters
+    /// - `row` - The row to parse
+    ///
+    /// # Returns
+    /// The attachment that the row represented
+    pub(super) fn parse_row(&self, row: &Row) -> AttachmentModel {
+        let size: i64 = row.get("size");
+
+        AttachmentModel {
+            identity: Identity {
+                id: row.get("attachment_id"),
+                version: row.get("version"),
+                created: row.get("created"),
+                updated: row.get("updated"),
+            },
+            data: AttachmentData {
+                world: row.get("world_id"),
+                owner: row.get("owner_id"),
+                filename: row.get("filename"),
+                content_type: row.get("content_type"),
+                size: size as u64,
+                hash: row.get("hash"),
+                has_thumbnail: row.get("has_thumbnail"),
+            },
+        }
+    }
+}
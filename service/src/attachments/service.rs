@@ -0,0 +1,45 @@
+mod content;
+mod create_attachment;
+mod delete;
+mod find;
+mod sniff;
+mod thumbnail;
+
+use super::repository::AttachmentRepository;
+use crate::{infrastructure::blobs::SharedBlobStore, quotas::QuotasService};
+pub use create_attachment::CreateAttachmentError;
+
+/// Service Layer for dealing with Attachments
+#[derive(Clone)]
+pub struct AttachmentsService {
+    repository: AttachmentRepository,
+    blobs: SharedBlobStore,
+    /// The largest file, in bytes, that can be uploaded
+    max_size: u64,
+    quotas: QuotasService,
+}
+
+impl AttachmentsService {
+    /// Create a new instance of the Attachments Service
+    ///
+    /// # Returns
+    /// The Attachments Service ready to use
+    pub(super) fn new(
+        repository: AttachmentRepository,
+        blobs: SharedBlobStore,
+        max_size: u64,
+        quotas: QuotasService,
+    ) -> Self {
+        Self {
+            repository,
+            blobs,
+            max_size,
+            quotas,
+        }
+    }
+
+    /// Get the largest file, in bytes, that can be uploaded
+    pub fn max_size(&self) -> u64 {
+        self.max_size
+    }
+}
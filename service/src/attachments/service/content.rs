@@ -0,0 +1,42 @@
+use super::{
+    create_attachment::{content_key, thumbnail_key},
+    AttachmentsService,
+};
+use crate::attachments::AttachmentModel;
+
+impl AttachmentsService {
+    /// Load the contents of the file for an attachment
+    ///
+    /// # Parameters
+    /// - `attachment` - The attachment to load the file for
+    ///
+    /// # Returns
+    /// The contents of the file, or `None` if it couldn't be loaded
+    pub fn load_content(&self, attachment: &AttachmentModel) -> Option<Vec<u8>> {
+        self.load_blob(&content_key(&attachment.identity.id))
+    }
+
+    /// Load the thumbnail for an attachment
+    ///
+    /// # Parameters
+    /// - `attachment` - The attachment to load the thumbnail for
+    ///
+    /// # Returns
+    /// The thumbnail as a PNG, or `None` if the attachment has no thumbnail
+    pub fn load_thumbnail(&self, attachment: &AttachmentModel) -> Option<Vec<u8>> {
+        if attachment.data.has_thumbnail {
+            self.load_blob(&thumbnail_key(&attachment.identity.id))
+        } else {
+            None
+        }
+    }
+
+    /// Load a blob from the blob store, logging any errors
+    fn load_blob(&self, key: &str) -> Option<Vec<u8>> {
+        self.blobs
+            .get(key)
+            .map_err(|e| tracing::error!(e = ?e, key = key, "Failed to load attachment blob"))
+            .ok()
+            .flatten()
+    }
+}
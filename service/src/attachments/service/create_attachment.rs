@@ -0,0 +1,121 @@
+use super::{sniff::sniff_content_type, thumbnail::generate_thumbnail};
+use crate::{
+    attachments::{repository::SaveAttachmentError, *},
+    quotas::{QuotaExceededError, QuotaResource},
+    users::UserID,
+    worlds::WorldID,
+};
+use sha2::{Digest, Sha256};
+
+/// Errors that can occur when creating a new attachment
+#[derive(Debug, thiserror::Error)]
+pub enum CreateAttachmentError {
+    /// An unknown error occurred
+    #[error("An unknown error occurred")]
+    UnknownError,
+
+    /// The world that the attachment is on does not exist
+    #[error("The desired world does not exist")]
+    UnknownWorld,
+
+    /// The contents of the file are not a type that can be uploaded
+    #[error("The file is not a supported type")]
+    UnsupportedContentType,
+
+    /// Storing the file would take the user over their quota
+    #[error("The upload quota has been exceeded")]
+    QuotaExceeded(#[from] QuotaExceededError),
+}
+
+/// Get the key of the blob that stores the contents of an attachment
+pub(super) fn content_key(id: &AttachmentID) -> String {
+    format!("attachments/{}", id)
+}
+
+/// Get the key of the blob that stores the thumbnail of an attachment
+pub(super) fn thumbnail_key(id: &AttachmentID) -> String {
+    format!("attachments/{}-thumbnail", id)
+}
+
+impl AttachmentsService {
+    /// Attach a new file to a world.
+    ///
+    /// The content type is determined from the contents of the file, and a thumbnail is generated if the
+    /// file is an image
+    ///
+    /// # Parameters
+    /// - `world` - The world to attach the file to
+    /// - `owner` - The user that is uploading the file
+    /// - `filename` - The name of the file
+    /// - `content` - The contents of the file
+    ///
+    /// # Returns
+    /// The newly created attachment
+    ///
+    /// # Errors
+    /// Any errors that occurred creating the new attachment
+    #[tracing::instrument(skip(self, content))]
+    pub fn create_attachment(
+        &self,
+        world: WorldID,
+        owner: UserID,
+        filename: String,
+        content: &[u8],
+    ) -> Result<AttachmentModel, CreateAttachmentError> {
+        let content_type =
+            sniff_content_type(content).ok_or(CreateAttachmentError::UnsupportedContentType)?;
+
+        let quota = self.quotas.check_quota(
+            &owner,
+            QuotaResource::AttachmentBytes,
+            content.len() as u64,
+        )?;
+
+        let thumbnail = if content_type.starts_with("image/") {
+            generate_thumbnail(content)
+        } else {
+            None
+        };
+
+        let id = AttachmentID::default();
+        let attachment = self.repository.create(
+            id.clone(),
+            AttachmentData {
+                world,
+                owner,
+                filename,
+                content_type: content_type.to_owned(),
+                size: content.len() as u64,
+                hash: hex::encode(Sha256::digest(content)),
+                has_thumbnail: thumbnail.is_some(),
+            },
+            &quota,
+        )?;
+        tracing::debug!(attachment = ?attachment, "Created attachment");
+
+        let stored = self
+            .blobs
+            .put(&content_key(&id), content)
+            .and_then(|_| match &thumbnail {
+                Some(thumbnail) => self.blobs.put(&thumbnail_key(&id), thumbnail),
+                None => Ok(()),
+            });
+        if let Err(e) = stored {
+            tracing::error!(e = ?e, "Failed to store attachment");
+            self.delete_attachment(&id);
+            return Err(CreateAttachmentError::UnknownError);
+        }
+
+        Ok(attachment)
+    }
+}
+
+impl From<SaveAttachmentError> for CreateAttachmentError {
+    fn from(e: SaveAttachmentError) -> Self {
+        match e {
+            SaveAttachmentError::UnknownWorld => CreateAttachmentError::UnknownWorld,
+            SaveAttachmentError::QuotaExceeded(e) => CreateAttachmentError::QuotaExceeded(e),
+            _ => CreateAttachmentError::UnknownError,
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use super::{
+    create_attachment::{content_key, thumbnail_key},
+    AttachmentsService,
+};
+use crate::attachments::AttachmentID;
+
+impl AttachmentsService {
+    /// Delete an attachment, along with the stored file and thumbnail
+    ///
+    /// # Parameters
+    /// - `id` - The ID of the attachment to delete
+    ///
+    /// # Returns
+    /// True if the attachment was deleted. False if it didn't exist
+    pub fn delete_attachment(&self, id: &AttachmentID) -> bool {
+        let deleted = self.repository.delete(id);
+
+        for key in &[content_key(id), thumbnail_key(id)] {
+            if let Err(e) = self.blobs.delete(key) {
+                tracing::warn!(e = ?e, key = ?key, "Failed to delete attachment blob");
+            }
+        }
+
+        deleted
+    }
+}
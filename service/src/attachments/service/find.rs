@@ -0,0 +1,35 @@
+use super::AttachmentsService;
+use crate::{
+    attachments::{AttachmentID, AttachmentModel},
+    model::{Page, Pagination},
+    worlds::WorldID,
+};
+
+impl AttachmentsService {
+    /// Look up an attachment by it's unique ID
+    ///
+    /// # Parameters
+    /// - `id` - The ID of the Attachment to find
+    ///
+    /// # Returns
+    /// The Attachment, or `None` if it wasn't found
+    pub fn find_attachment_by_id(&self, id: &AttachmentID) -> Option<AttachmentModel> {
+        self.repository.find_attachment_by_id(id)
+    }
+
+    /// Get the attachments on a world, newest first
+    ///
+    /// # Parameters
+    /// - `world` - The ID of the world
+    /// - `pagination` - The pagination details for which page of results are wanted
+    ///
+    /// # Returns
+    /// The requested page of results
+    pub fn search_attachments(
+        &self,
+        world: &WorldID,
+        pagination: &Pagination,
+    ) -> Page<AttachmentModel> {
+        self.repository.search_attachments(world, pagination)
+    }
+}
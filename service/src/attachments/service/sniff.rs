@@ -0,0 +1,74 @@
+/// The signatures of the binary file types that can be uploaded, and the content types that they map to
+const SIGNATURES: &[(&[u8], &str)] = &[
+    (b"\x89PNG\r\n\x1a\n", "image/png"),
+    (b"\xff\xd8\xff", "image/jpeg"),
+    (b"GIF87a", "image/gif"),
+    (b"GIF89a", "image/gif"),
+    (b"%PDF-", "application/pdf"),
+];
+
+/// Determine the content type of a file from its contents.
+///
+/// The content type that the client claimed is never trusted, since it is the contents that browsers
+/// will act on when the file is served back
+///
+/// # Parameters
+/// - `content` - The contents of the file
+///
+/// # Returns
+/// The content type of the file, or `None` if it isn't a type that can be uploaded
+pub(super) fn sniff_content_type(content: &[u8]) -> Option<&'static str> {
+    if let Some((_, content_type)) = SIGNATURES
+        .iter()
+        .find(|(signature, _)| content.starts_with(signature))
+    {
+        return Some(content_type);
+    }
+
+    if content.len() >= 12 && &content[0..4] == b"RIFF" && &content[8..12] == b"WEBP" {
+        return Some("image/webp");
+    }
+
+    match std::str::from_utf8(content) {
+        Ok(text) if !text.contains('\0') => Some("text/plain; charset=utf-8"),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sniff_images() {
+        assert_eq!(
+            sniff_content_type(b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR"),
+            Some("image/png")
+        );
+        assert_eq!(
+            sniff_content_type(b"\xff\xd8\xff\xe0\0\x10JFIF"),
+            Some("image/jpeg")
+        );
+        assert_eq!(sniff_content_type(b"GIF89a\x01\0"), Some("image/gif"));
+        assert_eq!(
+            sniff_content_type(b"RIFF\x24\0\0\0WEBPVP8 "),
+            Some("image/webp")
+        );
+    }
+
+    #[test]
+    fn test_sniff_documents() {
+        assert_eq!(sniff_content_type(b"%PDF-1.7\n"), Some("application/pdf"));
+        assert_eq!(
+            sniff_content_type("Notes on the Dragon's Lair ✓".as_bytes()),
+            Some("text/plain; charset=utf-8")
+        );
+    }
+
+    #[test]
+    fn test_sniff_unsupported() {
+        assert_eq!(sniff_content_type(b"MZ\x90\0\x03\0\0\0"), None);
+        assert_eq!(sniff_content_type(b"text\0with a nul"), None);
+        assert_eq!(sniff_content_type(b"RIFF\x24\0\0\0WAVE"), None);
+    }
+}
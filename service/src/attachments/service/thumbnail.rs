@@ -0,0 +1,79 @@
+use image::{io::Reader, ImageOutputFormat};
+use std::io::Cursor;
+
+/// The largest width or height of a thumbnail, in pixels
+const THUMBNAIL_SIZE: u32 = 256;
+
+/// The largest image, in pixels, that will be decoded to generate a thumbnail
+const MAX_THUMBNAIL_SOURCE_PIXELS: u64 = 40_000_000;
+
+/// Generate a thumbnail of an image, scaled to fit within a square while keeping the aspect ratio.
+///
+/// The dimensions of the image are read from its header before it is decoded, so that images too large
+/// to decode safely are never expanded into memory.
+///
+/// # Parameters
+/// - `content` - The contents of the image file
+///
+/// # Returns
+/// The thumbnail as a PNG, or `None` if the content couldn't be decoded as an image or was too large
+pub(super) fn generate_thumbnail(content: &[u8]) -> Option<Vec<u8>> {
+    let (width, height) = Reader::new(Cursor::new(content))
+        .with_guessed_format()
+        .map_err(|e| tracing::warn!(e = ?e, "Failed to guess image format for thumbnail"))
+        .ok()?
+        .into_dimensions()
+        .map_err(|e| tracing::warn!(e = ?e, "Failed to read image dimensions for thumbnail"))
+        .ok()?;
+    if u64::from(width) * u64::from(height) > MAX_THUMBNAIL_SOURCE_PIXELS {
+        tracing::warn!(
+            width = width,
+            height = height,
+            "Image too large to generate thumbnail"
+        );
+        return None;
+    }
+
+    let image = image::load_from_memory(content)
+        .map_err(|e| tracing::warn!(e = ?e, "Failed to decode image for thumbnail"))
+        .ok()?;
+
+    let mut thumbnail = vec![];
+    image
+        .thumbnail(THUMBNAIL_SIZE, THUMBNAIL_SIZE)
+        .write_to(&mut thumbnail, ImageOutputFormat::Png)
+        .map_err(|e| tracing::warn!(e = ?e, "Failed to encode thumbnail"))
+        .ok()?;
+
+    Some(thumbnail)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{DynamicImage, GenericImageView};
+
+    #[test]
+    fn test_generate_thumbnail() {
+        let mut original = vec![];
+        DynamicImage::new_rgb8(600, 300)
+            .write_to(&mut original, ImageOutputFormat::Png)
+            .unwrap();
+
+        let thumbnail = generate_thumbnail(&original).unwrap();
+        let decoded = image::load_from_memory(&thumbnail).unwrap();
+        assert_eq!(decoded.dimensions(), (256, 128));
+    }
+
+    #[test]
+    fn test_generate_thumbnail_too_large() {
+        // A GIF header claiming to be 65535x65535 pixels, with no image data
+        let header = b"GIF89a\xff\xff\xff\xff\x00\x00\x00\x3b";
+        assert_eq!(generate_thumbnail(header), None);
+    }
+
+    #[test]
+    fn test_generate_thumbnail_not_image() {
+        assert_eq!(generate_thumbnail(b"%PDF-1.7\n"), None);
+    }
+}
@@ -41,6 +41,8 @@ pub fn register_user(
                 email_address: email_address.clone(),
                 avatar_url,
                 password: password.clone(),
+                admin: false,
+                suspended: false,
             };
             tracing::debug!(user = ?user, "Registering user");
             let authenticated_user = authentication_service.register_user(user)?;
@@ -13,6 +13,10 @@ pub enum AuthenticateError {
     /// The password was incorrect
     #[error("The password was incorrect")]
     InvalidPassword,
+
+    /// The user has been suspended by a moderator
+    #[error("The user has been suspended")]
+    Suspended,
 }
 
 impl AuthenticationService {
@@ -43,6 +47,11 @@ impl AuthenticationService {
             Err(AuthenticateError::InvalidPassword)?
         }
 
+        // Suspended users are not allowed to sign in at all
+        if user.data.suspended {
+            Err(AuthenticateError::Suspended)?
+        }
+
         // Call the Authorization Service to create a new Token for the User
         let authorization = self.authorization_service.generate_authorization(&user);
 
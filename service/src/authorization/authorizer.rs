@@ -1,7 +1,7 @@
 use super::{AuthorizationDetails, AuthorizationService, Token};
 use crate::{
     http::problem::{Problem, ProblemType},
-    users::UserID,
+    users::{UserID, UsersService},
 };
 use rocket::{
     http::Status,
@@ -75,6 +75,51 @@ impl Authorizing {
         }
     }
 
+    /// Check if the current request is authorized for any one of the given User IDs
+    ///
+    /// # Parameters
+    /// - `self` - Consumes self
+    /// - `user_ids` - The User IDs to look for
+    ///
+    /// # Returns
+    /// A new DSL in the correct status after this test
+    pub fn one_of_users(self, user_ids: &[UserID]) -> Self {
+        Self {
+            result: self.result.and_then(|_| {
+                self.authorization
+                    .clone()
+                    .filter(|d| user_ids.contains(&d.user_id))
+                    .and(Some(()))
+                    .ok_or(())
+            }),
+            ..self
+        }
+    }
+
+    /// Check if the current request is authorized for a user that passes the given test
+    ///
+    /// # Parameters
+    /// - `self` - Consumes self
+    /// - `test` - The test that the authorized User ID must pass
+    ///
+    /// # Returns
+    /// A new DSL in the correct status after this test
+    pub fn user_matching<F>(self, test: F) -> Self
+    where
+        F: FnOnce(&UserID) -> bool,
+    {
+        Self {
+            result: self.result.and_then(|_| {
+                self.authorization
+                    .clone()
+                    .filter(|d| test(&d.user_id))
+                    .and(Some(()))
+                    .ok_or(())
+            }),
+            ..self
+        }
+    }
+
     /// Finish authorization, returning the result
     ///
     /// # Parameters
@@ -99,38 +144,54 @@ impl<'a, 'r> FromRequest<'a, 'r> for Authorizer {
     /// This will read a Token from the `Authorization` header, ensure that it starts with the string "Bearer "
     /// and then pass the rest on to the `AuthorizationService` to decode.
     ///
-    /// If any of this fails then we return a successful `Authorizer` with no token. This will then fail
-    /// all the checks.
+    /// If any of this fails, or the user that the token is for has been suspended, then we return a
+    /// successful `Authorizer` with no token. This will then fail all the checks.
     /// If instead this succeeds then we return a successful `Authorizer` with the decoded token, so that we
     /// can perform checks against it.
     ///
+    /// The decoded token is cached on the request, so that using the `Authorizer` more than once while
+    /// handling a request only decodes it and looks up the user the first time.
+    ///
     /// # Parameters
     /// - `request` - The HTTP Request to load the token from
     ///
     /// # Returns
     /// The authorizer
     fn from_request(request: &'a Request<'r>) -> Outcome<Self, Self::Error> {
-        let authorization_service = request.guard::<State<AuthorizationService>>().unwrap();
-
-        let token = request
-            .headers()
-            .get_one("Authorization")
-            .filter(|token| {
-                tracing::trace!(token = ?token, "Read authorization header");
-                token.starts_with("Bearer ")
-            })
-            .map(|token| token[7..].to_owned())
-            .map(|token| Token::new(token))
-            .and_then(|token| {
-                let authorization = authorization_service.parse_token(&token);
-
-                tracing::debug!(token = ?token, authorization = ?authorization, "Read authorization token");
-                authorization.ok()
-            });
+        let token = request.local_cache(|| {
+            let authorization_service = request.guard::<State<AuthorizationService>>().unwrap();
+            let users_service = request.guard::<State<UsersService>>().unwrap();
+
+            request
+                .headers()
+                .get_one("Authorization")
+                .filter(|token| {
+                    tracing::trace!(token = ?token, "Read authorization header");
+                    token.starts_with("Bearer ")
+                })
+                .map(|token| token[7..].to_owned())
+                .map(|token| Token::new(token))
+                .and_then(|token| {
+                    let authorization = authorization_service.parse_token(&token);
+
+                    tracing::debug!(token = ?token, authorization = ?authorization, "Read authorization token");
+                    authorization.ok()
+                })
+                .filter(|authorization| {
+                    let suspended = users_service
+                        .find_user_by_id(&authorization.user_id)
+                        .map(|user| user.data.suspended)
+                        .unwrap_or(false);
+                    if suspended {
+                        tracing::warn!(authorization = ?authorization, "Rejecting token for suspended user");
+                    }
+                    !suspended
+                })
+        });
         tracing::debug!(token = ?token, "Read authorization details");
 
         Outcome::Success(Self {
-            authorization: token,
+            authorization: token.clone(),
         })
     }
 }
@@ -0,0 +1,35 @@
+use super::{repository::AutocompleteRepository, AutocompleteService};
+use crate::infrastructure::database::Database;
+use std::sync::Arc;
+
+/// Application Configuration for the Autocomplete module
+pub struct AutocompleteConfig {
+    pub autocomplete_service: AutocompleteService,
+}
+
+impl AutocompleteConfig {
+    /// Create the Configuration for the Autocomplete Module
+    ///
+    /// # Returns
+    /// The Autocomplete Config object
+    pub fn new(database: Database) -> Self {
+        let repository = AutocompleteRepository::new(database);
+        Self {
+            autocomplete_service: AutocompleteService::new(repository),
+        }
+    }
+
+    /// Generate the configuration callback needed for the HTTP Server to actually add the Autocomplete
+    /// endpoints to the server
+    ///
+    /// # Returns
+    /// The callback to provide to the HTTP Server to configure up the Autocomplete endpoints
+    pub fn configure(&self) -> Arc<dyn Fn(rocket::Rocket) -> rocket::Rocket + Send + Sync> {
+        let autocomplete_service = self.autocomplete_service.clone();
+        Arc::new(move |config| {
+            config
+                .manage(autocomplete_service.clone())
+                .mount("/", rocket::routes![super::endpoints::autocomplete])
+        })
+    }
+}
@@ -0,0 +1,5 @@
+mod autocomplete;
+mod errors;
+pub(crate) mod model;
+
+pub use autocomplete::*;
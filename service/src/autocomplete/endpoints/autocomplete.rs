@@ -0,0 +1,84 @@
+use super::{errors::AutocompleteValidation, model::SuggestionsResponse};
+use crate::{
+    authorization::Authorizer,
+    autocomplete::{AutocompleteService, EntityKind},
+    http::problem::{GenericValidation, Problem, ValidationProblem},
+    worlds::{
+        endpoints::world_owner::{authorize_world_viewer, is_world_editor},
+        WorldID, WorldsService,
+    },
+};
+use rocket::{get, State};
+use strum::IntoEnumIterator;
+
+/// The number of suggestions to return if the client doesn't say
+const DEFAULT_COUNT: u64 = 10;
+
+/// The most suggestions that a client may ask for
+const MAX_COUNT: u64 = 50;
+
+/// Handler to suggest entities within a World whose names match some text, for mentioning them
+///
+/// # Parameters
+/// - `autocomplete_service` - The autocomplete service to use
+/// - `worlds_service` - The worlds service to use
+/// - `world` - The ID of the world to search within
+/// - `q` - The text to match entity names against
+/// - `types` - A comma-separated list of the kinds of entity to suggest. Defaults to all of them
+/// - `count` - The maximum number of suggestions to return
+/// - `authorizer` - The authorizer to identify the current user. Only editors are suggested unpublished
+///   entities
+///
+/// # Returns
+/// The suggested entities, best match first
+#[tracing::instrument(
+    name = "GET /worlds/{id}/autocomplete",
+    skip(autocomplete_service, worlds_service)
+)]
+#[get("/worlds/<world>/autocomplete?<q>&<types>&<count>")]
+pub fn autocomplete(
+    autocomplete_service: State<AutocompleteService>,
+    worlds_service: State<WorldsService>,
+    world: WorldID,
+    q: Option<String>,
+    types: Option<String>,
+    count: Option<u64>,
+    authorizer: Authorizer,
+) -> Result<SuggestionsResponse, Problem> {
+    let mut problem = ValidationProblem::new();
+    let mut valid = true;
+
+    let query = q.as_ref().map(|q| q.trim()).filter(|q| !q.is_empty());
+    if query.is_none() {
+        problem.with_field_error("q", GenericValidation::Missing);
+        valid = false;
+    }
+
+    let kinds: Vec<EntityKind> = match types.as_ref().filter(|types| !types.trim().is_empty()) {
+        None => EntityKind::iter().collect(),
+        Some(types) => {
+            let parsed: Result<Vec<EntityKind>, _> =
+                types.split(',').map(|kind| kind.trim().parse()).collect();
+            parsed.unwrap_or_else(|_| {
+                problem.with_field_error("types", AutocompleteValidation::UnknownType);
+                valid = false;
+                vec![]
+            })
+        }
+    };
+
+    if !valid {
+        tracing::warn!("Validation error autocompleting");
+        return Err(problem.build());
+    }
+
+    let found_world = authorize_world_viewer(&worlds_service, &authorizer, &world)?;
+    let editor = is_world_editor(&worlds_service, &authorizer, &found_world);
+
+    let count = count.unwrap_or(DEFAULT_COUNT).min(MAX_COUNT);
+    let suggestions =
+        autocomplete_service.autocomplete(&world, query.unwrap(), &kinds, count, editor);
+    tracing::debug!(suggestions = ?suggestions, "Found suggestions");
+
+    Ok(SuggestionsResponse { world, suggestions })
+}
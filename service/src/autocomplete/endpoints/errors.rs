@@ -0,0 +1,20 @@
+use crate::http::problem::ValidationType;
+
+/// Validation errors that are specific to autocomplete
+#[derive(Debug, thiserror::Error)]
+pub enum AutocompleteValidation {
+    /// One of the requested types was not a kind of entity that can be suggested
+    #[error("The requested entity type was unknown")]
+    UnknownType,
+}
+
+impl ValidationType for AutocompleteValidation {
+    /// Generate a Type value for the `ValidationType` values.
+    fn error_code(&self) -> &'static str {
+        match self {
+            AutocompleteValidation::UnknownType => {
+                "tag:multiverse,2020:autocomplete/problems/validation_error/unknown_type"
+            }
+        }
+    }
+}
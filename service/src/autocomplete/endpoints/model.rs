@@ -0,0 +1,3 @@
+mod suggestions_response;
+
+pub use suggestions_response::*;
@@ -0,0 +1,82 @@
+use crate::{
+    autocomplete::{EntityKind, Suggestion},
+    campaigns::{endpoints::model::CampaignLink, CampaignID},
+    characters::{endpoints::model::CharacterLink, CharacterID},
+    http::link::{Link, LinkRel, Links},
+    tables::endpoints::model::TableLink,
+    worlds::WorldID,
+};
+use rocket::{
+    http::hyper::header::{CacheControl, CacheDirective},
+    response, Request,
+};
+use rocket_contrib::json::Json;
+use serde_json::{json, Value};
+
+/// API Model representing the entities suggested for an autocomplete query
+#[derive(Debug)]
+pub struct SuggestionsResponse {
+    /// The ID of the world that was searched
+    pub world: WorldID,
+
+    /// The suggested entities, best match first
+    pub suggestions: Vec<Suggestion>,
+}
+
+/// Generate the link to a suggested entity
+///
+/// # Parameters
+/// - `world` - The ID of the world that the entity belongs to
+/// - `suggestion` - The suggested entity
+///
+/// # Returns
+/// The link to the entity
+fn link(world: &WorldID, suggestion: &Suggestion) -> String {
+    match suggestion.kind {
+        EntityKind::Table => TableLink::new(
+            world.clone(),
+            suggestion
+                .url_slug
+                .clone()
+                .expect("Random tables always have a URL Slug"),
+        )
+        .into(),
+        EntityKind::Campaign => CampaignLink::new(CampaignID::new(suggestion.id)).into(),
+        EntityKind::Character => CharacterLink::new(CharacterID::new(suggestion.id)).into(),
+    }
+}
+
+impl<'r> response::Responder<'r> for SuggestionsResponse {
+    fn respond_to(self, req: &Request) -> response::Result<'r> {
+        let entries: Vec<Value> = self
+            .suggestions
+            .iter()
+            .map(|suggestion| {
+                json!({
+                  "type": suggestion.kind.to_string(),
+                  "name": suggestion.name
+                })
+            })
+            .collect();
+
+        let links: Vec<Link> = self
+            .suggestions
+            .iter()
+            .enumerate()
+            .map(|(index, suggestion)| {
+                Link::new(link(&self.world, suggestion), LinkRel::ITEM)
+                    .anchor(format!("#/entries/{}", index))
+                    .title(suggestion.name.clone())
+            })
+            .collect();
+
+        response::Response::build()
+            .merge(Json(json!({ "entries": entries })).respond_to(req).unwrap())
+            .header(CacheControl(vec![
+                CacheDirective::Private,
+                CacheDirective::MaxAge(60),
+            ]))
+            .header(Links(links))
+            .ok()
+    }
+}
@@ -0,0 +1,5 @@
+mod entity_kind;
+mod suggestion;
+
+pub use entity_kind::*;
+pub use suggestion::*;
@@ -0,0 +1,26 @@
+use strum_macros::{Display, EnumIter, EnumString};
+
+/// The kinds of entity within a world that can be suggested
+#[derive(Debug, PartialEq, Clone, Display, EnumString, EnumIter)]
+#[strum(serialize_all = "snake_case")]
+pub enum EntityKind {
+    /// A random table
+    Table,
+    /// A campaign
+    Campaign,
+    /// A character
+    Character,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!("table".parse(), Ok(EntityKind::Table));
+        assert_eq!("campaign".parse(), Ok(EntityKind::Campaign));
+        assert_eq!("character".parse(), Ok(EntityKind::Character));
+        assert!("session".parse::<EntityKind>().is_err());
+    }
+}
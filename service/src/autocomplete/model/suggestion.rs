@@ -0,0 +1,16 @@
+use super::EntityKind;
+use crate::worlds::UrlSlug;
+use uuid::Uuid;
+
+/// A single entity that matches an autocomplete query
+#[derive(Debug)]
+pub struct Suggestion {
+    /// The kind of entity
+    pub kind: EntityKind,
+    /// The ID of the entity
+    pub id: Uuid,
+    /// The name of the entity
+    pub name: String,
+    /// The URL Slug of the entity, for those kinds that have one
+    pub url_slug: Option<UrlSlug>,
+}
@@ -0,0 +1,23 @@
+mod suggest;
+
+use crate::infrastructure::database::Database;
+
+/// Repository used to look up entities to suggest from the database
+#[derive(Clone)]
+pub(super) struct AutocompleteRepository {
+    /// The database with which to look up entities
+    database: Database,
+}
+
+impl AutocompleteRepository {
+    /// Create a new Autocomplete Repository to work with
+    ///
+    /// # Parameters
+    /// - `database` - The database connection to use
+    ///
+    /// # Returns
+    /// The Autocomplete Repository to use
+    pub(super) fn new(database: Database) -> Self {
+        Self { database }
+    }
+}
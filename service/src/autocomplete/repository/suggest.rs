@@ -0,0 +1,106 @@
+use super::AutocompleteRepository;
+use crate::{
+    autocomplete::{EntityKind, Suggestion},
+    model::published_sql,
+    worlds::WorldID,
+};
+
+impl AutocompleteRepository {
+    /// Find the entities within a world whose names best match the given query.
+    ///
+    /// Entities whose names start with the query are ranked first, followed by those containing a word
+    /// that is similar to it, most similar first
+    ///
+    /// # Parameters
+    /// - `world` - The ID of the world to search within
+    /// - `query` - The text to match names against
+    /// - `kinds` - The kinds of entity to search for
+    /// - `count` - The maximum number of entities to return
+    /// - `include_unpublished` - Whether to include entities that aren't yet published
+    ///
+    /// # Returns
+    /// The matching entities, best match first
+    #[tracing::instrument(skip(self))]
+    pub fn suggest(
+        &self,
+        world: &WorldID,
+        query: &str,
+        kinds: &[EntityKind],
+        count: u64,
+        include_unpublished: bool,
+    ) -> Vec<Suggestion> {
+        if kinds.is_empty() {
+            return vec![];
+        }
+
+        let published_clause = |table: &str| {
+            if include_unpublished {
+                "".to_owned()
+            } else {
+                format!(" AND {}", published_sql(table))
+            }
+        };
+        let entities: Vec<String> = kinds
+            .iter()
+            .map(|kind| match kind {
+                EntityKind::Table => format!("SELECT 'table' AS kind, table_id AS id, name, url_slug FROM random_tables WHERE world_id = $1{}", published_clause("random_tables")),
+                EntityKind::Campaign => format!("SELECT 'campaign' AS kind, campaign_id AS id, name, NULL::TEXT AS url_slug FROM campaigns WHERE world_id = $1{}", published_clause("campaigns")),
+                EntityKind::Character => format!("SELECT 'character' AS kind, character_id AS id, name, NULL::TEXT AS url_slug FROM characters WHERE world_id = $1{}", published_clause("characters")),
+            })
+            .collect();
+
+        let sql = format!(
+            "SELECT * FROM ({}) entities WHERE name ILIKE $2 OR $3 <% name ORDER BY name ILIKE $2 DESC, word_similarity($3, name) DESC, name ASC LIMIT {}",
+            entities.join(" UNION ALL "),
+            count
+        );
+        let prefix = format!("{}%", escape_like(query));
+
+        let mut connection = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+
+        connection
+            .query(sql.as_str(), &[world, &prefix, &query])
+            .map(|rows| {
+                rows.iter()
+                    .map(|row| {
+                        let kind: String = row.get("kind");
+                        Suggestion {
+                            kind: kind.parse().expect("Invalid entity kind"),
+                            id: row.get("id"),
+                            name: row.get("name"),
+                            url_slug: row.get("url_slug"),
+                        }
+                    })
+                    .collect()
+            })
+            .expect("Failed to query for suggestions")
+    }
+}
+
+/// Escape a string so that it can be used as a literal within an `ILIKE` pattern
+///
+/// # Parameters
+/// - `value` - The value to escape
+///
+/// # Returns
+/// The escaped value
+fn escape_like(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escape_like() {
+        assert_eq!(escape_like("Tavern"), "Tavern");
+        assert_eq!(escape_like("100%_done\\"), "100\\%\\_done\\\\");
+    }
+}
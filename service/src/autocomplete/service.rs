@@ -0,0 +1,41 @@
+use super::{repository::AutocompleteRepository, EntityKind, Suggestion};
+use crate::worlds::WorldID;
+
+/// Service Layer for suggesting entities to mention
+#[derive(Clone)]
+pub struct AutocompleteService {
+    repository: AutocompleteRepository,
+}
+
+impl AutocompleteService {
+    /// Create a new instance of the Autocomplete Service
+    ///
+    /// # Returns
+    /// The Autocomplete Service ready to use
+    pub(super) fn new(repository: AutocompleteRepository) -> Self {
+        Self { repository }
+    }
+
+    /// Suggest the entities within a world that best match some text
+    ///
+    /// # Parameters
+    /// - `world` - The ID of the world to search within
+    /// - `query` - The text to match names against
+    /// - `kinds` - The kinds of entity to search for
+    /// - `count` - The maximum number of entities to return
+    /// - `include_unpublished` - Whether to include entities that aren't yet published
+    ///
+    /// # Returns
+    /// The matching entities, best match first
+    pub fn autocomplete(
+        &self,
+        world: &WorldID,
+        query: &str,
+        kinds: &[EntityKind],
+        count: u64,
+        include_unpublished: bool,
+    ) -> Vec<Suggestion> {
+        self.repository
+            .suggest(world, query, kinds, count, include_unpublished)
+    }
+}
@@ -0,0 +1,63 @@
+use super::{repository::CampaignRepository, CampaignsService};
+use crate::{
+    activity::ActivityService, infrastructure::database::Database, quotas::QuotasService,
+    webhooks::WebhooksService,
+};
+use std::sync::Arc;
+
+/// Application Configuration for the Campaigns module
+pub struct CampaignsConfig {
+    pub campaigns_service: CampaignsService,
+}
+
+impl CampaignsConfig {
+    /// Create the Configuration for the Campaigns Module
+    ///
+    /// # Parameters
+    /// - `database` - The database connection to use
+    /// - `activity_service` - The activity service to record activity with
+    /// - `webhooks_service` - The webhooks service to dispatch events with
+    /// - `quotas_service` - The quotas service to limit how many campaigns users can have
+    ///
+    /// # Returns
+    /// The Campaigns Config object
+    pub fn new(
+        database: Database,
+        activity_service: ActivityService,
+        webhooks_service: WebhooksService,
+        quotas_service: QuotasService,
+    ) -> Self {
+        let repository = CampaignRepository::new(database);
+        Self {
+            campaigns_service: CampaignsService::new(
+                repository,
+                activity_service,
+                webhooks_service,
+                quotas_service,
+            ),
+        }
+    }
+
+    /// Generate the configuration callback needed for the HTTP Server to actually add the Campaigns
+    /// endpoints to the server
+    ///
+    /// # Returns
+    /// The callback to provide to the HTTP Server to configure up the Campaigns endpoints
+    pub fn configure(&self) -> Arc<dyn Fn(rocket::Rocket) -> rocket::Rocket + Send + Sync> {
+        let campaigns_service = self.campaigns_service.clone();
+        Arc::new(move |config| {
+            config.manage(campaigns_service.clone()).mount(
+                "/",
+                rocket::routes![
+                    super::endpoints::create_campaign,
+                    super::endpoints::create_session,
+                    super::endpoints::get_campaign,
+                    super::endpoints::get_session,
+                    super::endpoints::save_session_notes,
+                    super::endpoints::search_sessions,
+                    super::endpoints::update_campaign_status
+                ],
+            )
+        })
+    }
+}
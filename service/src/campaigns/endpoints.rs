@@ -0,0 +1,18 @@
+mod campaign_viewer;
+mod create_campaign;
+mod create_session;
+mod errors;
+mod get_campaign;
+mod get_session;
+pub(crate) mod model;
+mod save_session_notes;
+mod search_sessions;
+mod update_campaign_status;
+
+pub use create_campaign::*;
+pub use create_session::*;
+pub use get_campaign::*;
+pub use get_session::*;
+pub use save_session_notes::*;
+pub use search_sessions::*;
+pub use update_campaign_status::*;
@@ -0,0 +1,49 @@
+use super::errors::CampaignProblemType;
+use crate::{
+    authorization::Authorizer,
+    campaigns::{CampaignID, CampaignModel, CampaignsService},
+    http::problem::Problem,
+    worlds::{
+        endpoints::world_owner::{authorize_world_viewer, is_world_editor},
+        WorldsService,
+    },
+};
+use rocket::http::Status;
+
+/// Look up a campaign and ensure that the current user is allowed to see it.
+///
+/// Campaigns are only visible if the world they are run in is visible. Campaigns that aren't yet published
+/// are only visible to the editors of the world.
+///
+/// # Parameters
+/// - `campaigns_service` - The campaigns service to use
+/// - `worlds_service` - The worlds service to use
+/// - `authorizer` - The authorizer to identify the current user
+/// - `id` - The ID of the campaign
+///
+/// # Returns
+/// The campaign, if it exists and the current user can see it
+///
+/// # Errors
+/// A Problem if the campaign doesn't exist or the current user can't see it
+pub(super) fn authorize_campaign_viewer(
+    campaigns_service: &CampaignsService,
+    worlds_service: &WorldsService,
+    authorizer: &Authorizer,
+    id: &CampaignID,
+) -> Result<CampaignModel, Problem> {
+    let unknown_campaign = || Problem::new(CampaignProblemType::UnknownCampaign, Status::NotFound);
+
+    let campaign = campaigns_service
+        .find_campaign_by_id(id)
+        .ok_or_else(unknown_campaign)?;
+    let world = authorize_world_viewer(worlds_service, authorizer, &campaign.data.world)
+        .map_err(|_| unknown_campaign())?;
+
+    if campaign.data.publication.is_visible() || is_world_editor(worlds_service, authorizer, &world)
+    {
+        Ok(campaign)
+    } else {
+        Err(unknown_campaign())
+    }
+}
@@ -0,0 +1,158 @@
+use super::{
+    errors::{CampaignProblemType, CampaignValidation},
+    model::CampaignResponse,
+};
+use crate::{
+    authorization::Authorizer,
+    campaigns::{CampaignData, CampaignsService, CreateCampaignError},
+    http::{
+        problem::{GenericValidation, Problem, ValidationProblem},
+        publication::PublicationRequest,
+    },
+    users::{UserID, UsersService},
+    worlds::{
+        endpoints::{errors::WorldProblemType, world_owner::authorize_world_editor},
+        WorldID, WorldsService,
+    },
+};
+use rocket::{http::Status, post, State};
+use rocket_contrib::json::Json;
+use serde::Deserialize;
+use uuid::Uuid;
+
+/// Handler to create a new Campaign in a World
+///
+/// # Parameters
+/// - `campaigns_service` - The campaigns service to use
+/// - `worlds_service` - The worlds service to use
+/// - `users_service` - The users service to use
+/// - `world` - The ID of the world to create the campaign in
+/// - `body` - The details of the campaign to create
+/// - `authorizer` - The authorizer to prove we're allowed to create the campaign
+///
+/// # Returns
+/// The newly created campaign details, or a Problem if the creation failed
+#[tracing::instrument(
+    name = "POST /worlds/{id}/campaigns",
+    skip(campaigns_service, worlds_service, users_service)
+)]
+#[post("/worlds/<world>/campaigns", data = "<body>")]
+pub fn create_campaign(
+    campaigns_service: State<CampaignsService>,
+    worlds_service: State<WorldsService>,
+    users_service: State<UsersService>,
+    world: WorldID,
+    body: Json<CreateCampaignRequest>,
+    authorizer: Authorizer,
+) -> Result<CampaignResponse, Problem> {
+    authorize_world_editor(&worlds_service, &authorizer, &world)?;
+
+    let mut problem = ValidationProblem::new();
+    let mut valid = true;
+
+    let name = body.name();
+    if name == None {
+        problem.with_field_error("name", GenericValidation::Missing);
+        valid = false;
+    }
+
+    let mut players: Vec<UserID> = vec![];
+    for (index, player) in body.players.iter().enumerate() {
+        let user = parse_user_id(player)
+            .filter(|user_id| users_service.find_user_by_id(user_id).is_some());
+        match user {
+            Some(user_id) => {
+                if !players.contains(&user_id) {
+                    players.push(user_id);
+                }
+            }
+            None => {
+                problem.with_field_error(
+                    format!("players/{}", index),
+                    CampaignValidation::UnknownUser,
+                );
+                valid = false;
+            }
+        }
+    }
+
+    let publication = body.publication.build(&mut problem);
+    if publication.is_none() {
+        valid = false;
+    }
+
+    if !valid {
+        tracing::warn!("Validation error creating campaign");
+        return Err(problem.build());
+    }
+
+    let campaign = CampaignData {
+        world,
+        name: name.unwrap(),
+        description: body.description().unwrap_or_default(),
+        players,
+        publication: publication.unwrap(),
+    };
+    tracing::debug!(campaign = ?campaign, "Creating new campaign");
+
+    let new_campaign = campaigns_service.create_campaign(campaign)?;
+    Ok(CampaignResponse(new_campaign))
+}
+
+/// Parse a reference to a user, either as a bare User ID or as a link to the user
+///
+/// # Parameters
+/// - `value` - The value to parse
+///
+/// # Returns
+/// The User ID, if the value was valid
+fn parse_user_id(value: &str) -> Option<UserID> {
+    Uuid::parse_str(value.trim().trim_start_matches("/users/"))
+        .ok()
+        .map(UserID::new)
+}
+
+/// Incoming details representing a request to create a new campaign
+#[derive(Debug, Deserialize)]
+pub struct CreateCampaignRequest {
+    /// The name of the campaign
+    name: Option<String>,
+    /// The description of the campaign. May be omitted
+    description: Option<String>,
+    /// The users that are playing in the campaign, either as User IDs or links to the users
+    #[serde(default)]
+    players: Vec<String>,
+    /// The publication state of the campaign. If omitted then the campaign is published immediately
+    #[serde(flatten)]
+    publication: PublicationRequest,
+}
+
+impl CreateCampaignRequest {
+    /// Get the name to use for the campaign
+    fn name(&self) -> Option<String> {
+        self.name.clone().filter(|v| !v.trim().is_empty())
+    }
+
+    /// Get the description to use for the campaign
+    fn description(&self) -> Option<String> {
+        self.description.clone().filter(|v| !v.trim().is_empty())
+    }
+}
+
+impl From<CreateCampaignError> for Problem {
+    fn from(e: CreateCampaignError) -> Self {
+        match e {
+            CreateCampaignError::UnknownWorld => {
+                Problem::new(WorldProblemType::UnknownWorldID, Status::NotFound)
+            }
+            CreateCampaignError::QuotaExceeded(e) => e.into(),
+            _ => {
+                tracing::warn!(error = ?e, "An unexpected error occurred");
+                Problem::new(
+                    CampaignProblemType::UnknownError,
+                    Status::InternalServerError,
+                )
+            }
+        }
+    }
+}
@@ -0,0 +1,136 @@
+use super::{
+    errors::{CampaignProblemType, CampaignValidation},
+    model::SessionResponse,
+};
+use crate::{
+    authorization::Authorizer,
+    campaigns::{CampaignID, CampaignsService, CreateSessionError, SessionData},
+    http::problem::{GenericValidation, Problem, ValidationProblem},
+    worlds::{endpoints::world_owner::authorize_world_editor, WorldsService},
+};
+use chrono::NaiveDate;
+use rocket::{http::Status, post, State};
+use rocket_contrib::json::Json;
+use serde::Deserialize;
+
+/// Handler to record a new Session of a Campaign
+///
+/// # Parameters
+/// - `campaigns_service` - The campaigns service to use
+/// - `worlds_service` - The worlds service to use
+/// - `campaign` - The ID of the campaign that the session belongs to
+/// - `body` - The details of the session to create
+/// - `authorizer` - The authorizer to prove we're allowed to create the session
+///
+/// # Returns
+/// The newly created session details, or a Problem if the creation failed
+#[tracing::instrument(
+    name = "POST /campaigns/{id}/sessions",
+    skip(campaigns_service, worlds_service)
+)]
+#[post("/campaigns/<campaign>/sessions", data = "<body>")]
+pub fn create_session(
+    campaigns_service: State<CampaignsService>,
+    worlds_service: State<WorldsService>,
+    campaign: CampaignID,
+    body: Json<CreateSessionRequest>,
+    authorizer: Authorizer,
+) -> Result<SessionResponse, Problem> {
+    let campaign = campaigns_service
+        .find_campaign_by_id(&campaign)
+        .ok_or_else(|| Problem::new(CampaignProblemType::UnknownCampaign, Status::NotFound))?;
+
+    authorize_world_editor(&worlds_service, &authorizer, &campaign.data.world)?;
+
+    let mut problem = ValidationProblem::new();
+    let mut valid = true;
+
+    let date = body.date();
+    match &date {
+        None => {
+            problem.with_field_error("date", GenericValidation::Missing);
+            valid = false;
+        }
+        Some(Err(_)) => {
+            problem.with_field_error("date", CampaignValidation::InvalidDate);
+            valid = false;
+        }
+        Some(Ok(_)) => {}
+    }
+
+    let entities = body.entities();
+    for (index, entity) in entities.iter().enumerate() {
+        if entity.is_none() {
+            problem.with_field_error(format!("entities/{}", index), GenericValidation::Missing);
+            valid = false;
+        }
+    }
+
+    if !valid {
+        tracing::warn!("Validation error creating session");
+        return Err(problem.build());
+    }
+
+    let session = SessionData {
+        campaign: campaign.identity.id,
+        date: date.unwrap().unwrap(),
+        summary: body.summary().unwrap_or_default(),
+        entities: entities.into_iter().map(Option::unwrap).collect(),
+    };
+    tracing::debug!(session = ?session, "Creating new session");
+
+    let new_session = campaigns_service.create_session(session)?;
+    Ok(SessionResponse(new_session, vec![]))
+}
+
+/// Incoming details representing a request to record a new session
+#[derive(Debug, Deserialize)]
+pub struct CreateSessionRequest {
+    /// The date that the session was played on, in the format YYYY-MM-DD
+    date: Option<String>,
+    /// The summary of the session, in Markdown. May be omitted
+    summary: Option<String>,
+    /// References to the entities that appeared in the session
+    #[serde(default)]
+    entities: Vec<String>,
+}
+
+impl CreateSessionRequest {
+    /// Get the date that the session was played on
+    fn date(&self) -> Option<Result<NaiveDate, chrono::ParseError>> {
+        self.date
+            .clone()
+            .filter(|v| !v.trim().is_empty())
+            .map(|v| NaiveDate::parse_from_str(v.trim(), "%Y-%m-%d"))
+    }
+
+    /// Get the summary of the session
+    fn summary(&self) -> Option<String> {
+        self.summary.clone().filter(|v| !v.trim().is_empty())
+    }
+
+    /// Get the entities that appeared in the session, with `None` for any that were blank
+    fn entities(&self) -> Vec<Option<String>> {
+        self.entities
+            .iter()
+            .map(|v| Some(v.trim().to_owned()).filter(|v| !v.is_empty()))
+            .collect()
+    }
+}
+
+impl From<CreateSessionError> for Problem {
+    fn from(e: CreateSessionError) -> Self {
+        match e {
+            CreateSessionError::UnknownCampaign => {
+                Problem::new(CampaignProblemType::UnknownCampaign, Status::NotFound)
+            }
+            _ => {
+                tracing::warn!(error = ?e, "An unexpected error occurred");
+                Problem::new(
+                    CampaignProblemType::UnknownError,
+                    Status::InternalServerError,
+                )
+            }
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use crate::http::problem::{ProblemType, ValidationType};
+
+/// Problem Types that can happen when working with campaigns
+#[derive(Debug, thiserror::Error)]
+pub enum CampaignProblemType {
+    /// An unknown error occurred
+    #[error("An unknown error occurred")]
+    UnknownError,
+
+    /// The campaign that was looked up was not found
+    #[error("The requested campaign was unknown")]
+    UnknownCampaign,
+
+    /// The session that was looked up was not found
+    #[error("The requested session was unknown")]
+    UnknownSession,
+}
+
+impl ProblemType for CampaignProblemType {
+    /// Generate a Type value for the `ProblemType` values.
+    ///
+    /// These are used in the `type` field in the RFC-7807 Problem Response
+    fn error_code(&self) -> &'static str {
+        match self {
+            CampaignProblemType::UnknownError => {
+                "tag:multiverse,2020:campaigns/problems/unknown_error"
+            }
+            CampaignProblemType::UnknownCampaign => {
+                "tag:multiverse,2020:campaigns/problems/unknown_campaign"
+            }
+            CampaignProblemType::UnknownSession => {
+                "tag:multiverse,2020:campaigns/problems/unknown_session"
+            }
+        }
+    }
+}
+
+/// Validation errors that are specific to campaigns
+#[derive(Debug, thiserror::Error)]
+pub enum CampaignValidation {
+    /// A player was not a known user
+    #[error("The user was unknown")]
+    UnknownUser,
+
+    /// The date of a session was not a valid date
+    #[error("The date must be in the format YYYY-MM-DD")]
+    InvalidDate,
+}
+
+impl ValidationType for CampaignValidation {
+    /// Generate a Type value for the `ValidationType` values.
+    fn error_code(&self) -> &'static str {
+        match self {
+            CampaignValidation::UnknownUser => {
+                "tag:multiverse,2020:campaigns/problems/validation_error/unknown_user"
+            }
+            CampaignValidation::InvalidDate => {
+                "tag:multiverse,2020:campaigns/problems/validation_error/invalid_date"
+            }
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use super::{campaign_viewer::authorize_campaign_viewer, model::CampaignResponse};
+use crate::{
+    authorization::Authorizer,
+    campaigns::{CampaignID, CampaignsService},
+    http::problem::Problem,
+    worlds::WorldsService,
+};
+use rocket::{get, State};
+
+/// Handler to get an existing Campaign by ID
+///
+/// # Parameters
+/// - `campaigns_service` - The campaigns service to use
+/// - `worlds_service` - The worlds service to use
+/// - `id` - The ID of the campaign
+/// - `authorizer` - The authorizer to identify the current user. Only editors can see unpublished campaigns
+///
+/// # Returns
+/// The details of the campaign
+#[tracing::instrument(name = "GET /campaigns/{id}", skip(campaigns_service, worlds_service))]
+#[get("/campaigns/<id>")]
+pub fn get_campaign(
+    campaigns_service: State<CampaignsService>,
+    worlds_service: State<WorldsService>,
+    id: CampaignID,
+    authorizer: Authorizer,
+) -> Result<CampaignResponse, Problem> {
+    tracing::debug!("Looking up campaign");
+    authorize_campaign_viewer(&campaigns_service, &worlds_service, &authorizer, &id)
+        .map(CampaignResponse)
+}
@@ -0,0 +1,48 @@
+use super::{
+    campaign_viewer::authorize_campaign_viewer, errors::CampaignProblemType, model::SessionResponse,
+};
+use crate::{
+    authorization::Authorizer,
+    campaigns::{CampaignsService, SessionID},
+    http::problem::Problem,
+    worlds::WorldsService,
+};
+use rocket::{get, http::Status, State};
+
+/// Handler to get an existing Session by ID, including all of the notes that players have written about it
+///
+/// # Parameters
+/// - `campaigns_service` - The campaigns service to use
+/// - `worlds_service` - The worlds service to use
+/// - `id` - The ID of the session
+/// - `authorizer` - The authorizer to identify the current user. Sessions of unpublished campaigns are
+///   only visible to editors
+///
+/// # Returns
+/// The details of the session
+#[tracing::instrument(name = "GET /sessions/{id}", skip(campaigns_service, worlds_service))]
+#[get("/sessions/<id>")]
+pub fn get_session(
+    campaigns_service: State<CampaignsService>,
+    worlds_service: State<WorldsService>,
+    id: SessionID,
+    authorizer: Authorizer,
+) -> Result<SessionResponse, Problem> {
+    tracing::debug!("Looking up session");
+    let unknown_session = || Problem::new(CampaignProblemType::UnknownSession, Status::NotFound);
+
+    let session = campaigns_service
+        .find_session_by_id(&id)
+        .ok_or_else(unknown_session)?;
+    authorize_campaign_viewer(
+        &campaigns_service,
+        &worlds_service,
+        &authorizer,
+        &session.data.campaign,
+    )
+    .map_err(|_| unknown_session())?;
+
+    let notes = campaigns_service.find_session_notes(&id);
+
+    Ok(SessionResponse(session, notes))
+}
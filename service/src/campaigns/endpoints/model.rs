@@ -0,0 +1,11 @@
+mod campaign_link;
+mod campaign_response;
+mod session_link;
+mod session_response;
+mod sessions_response;
+
+pub use campaign_link::*;
+pub use campaign_response::*;
+pub use session_link::*;
+pub use session_response::*;
+pub use sessions_response::*;
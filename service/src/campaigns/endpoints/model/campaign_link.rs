@@ -0,0 +1,56 @@
+use crate::campaigns::CampaignID;
+use rocket::{http::RawStr, request::FromFormValue};
+use std::ops::Deref;
+use uuid::Uuid;
+
+/// Representation of a link to a campaign
+#[derive(Debug)]
+pub struct CampaignLink(CampaignID);
+
+impl CampaignLink {
+    pub fn new(campaign_id: CampaignID) -> Self {
+        Self(campaign_id)
+    }
+}
+
+impl From<CampaignLink> for String {
+    fn from(campaign_link: CampaignLink) -> String {
+        format!("/campaigns/{}", campaign_link.0)
+    }
+}
+
+impl Deref for CampaignLink {
+    type Target = CampaignID;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<CampaignLink> for CampaignID {
+    fn from(campaign_link: CampaignLink) -> Self {
+        campaign_link.0.clone()
+    }
+}
+
+impl<'v> FromFormValue<'v> for CampaignLink {
+    type Error = &'v RawStr;
+
+    fn from_form_value(form_value: &'v RawStr) -> Result<Self, Self::Error> {
+        form_value
+            .percent_decode()
+            .map_err(|e| {
+                tracing::warn!(e = ?e, value = ?form_value, "Failed to decode parameter");
+                form_value
+            })
+            .map(|param| param.trim_start_matches("/campaigns/").to_owned())
+            .and_then(|campaign_id| {
+                Uuid::parse_str(&campaign_id).map_err(|e| {
+                    tracing::warn!(e = ?e, value = ?form_value, "Failed to parse Campaign ID");
+                    form_value
+                })
+            })
+            .map(|campaign_id| CampaignID::new(campaign_id))
+            .map(|campaign_id| CampaignLink(campaign_id))
+    }
+}
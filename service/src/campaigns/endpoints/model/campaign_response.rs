@@ -0,0 +1,54 @@
+use super::CampaignLink;
+use crate::{
+    campaigns::CampaignModel,
+    http::{
+        link::{Link, LinkRel, Links},
+        publication::add_publication_json,
+    },
+    users::endpoints::model::UserLink,
+    worlds::endpoints::model::WorldLink,
+};
+use rocket::{
+    http::hyper::header::{CacheControl, CacheDirective, ETag, EntityTag},
+    response, Request,
+};
+use rocket_contrib::json::Json;
+use serde_json::json;
+
+/// API Model representing a Campaign
+#[derive(Debug)]
+pub struct CampaignResponse(pub CampaignModel);
+
+impl<'r> response::Responder<'r> for CampaignResponse {
+    fn respond_to(self, req: &Request) -> response::Result<'r> {
+        let etag = self.0.identity.version.to_string();
+
+        let players: Vec<String> = self
+            .0
+            .data
+            .players
+            .iter()
+            .map(|player| UserLink::new(player.clone()).into())
+            .collect();
+
+        let mut response_body = json!({
+          "name": self.0.data.name,
+          "description": self.0.data.description,
+          "players": players
+        });
+        add_publication_json(&mut response_body, &self.0.data.publication);
+
+        response::Response::build()
+            .merge(Json(response_body).respond_to(req).unwrap())
+            .header(CacheControl(vec![
+                CacheDirective::Private,
+                CacheDirective::MaxAge(3600),
+            ]))
+            .header(ETag(EntityTag::new(false, etag)))
+            .header(Links(vec![
+                Link::new(CampaignLink::new(self.0.identity.id), LinkRel::SELF),
+                Link::new(WorldLink::new(self.0.data.world), LinkRel::RELATED),
+            ]))
+            .ok()
+    }
+}
@@ -0,0 +1,16 @@
+use crate::campaigns::SessionID;
+
+/// Representation of a link to a session
+pub struct SessionLink(SessionID);
+
+impl SessionLink {
+    pub fn new(session_id: SessionID) -> Self {
+        Self(session_id)
+    }
+}
+
+impl From<SessionLink> for String {
+    fn from(session_link: SessionLink) -> String {
+        format!("/sessions/{}", session_link.0)
+    }
+}
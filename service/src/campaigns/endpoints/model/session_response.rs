@@ -0,0 +1,73 @@
+use super::{CampaignLink, SessionLink};
+use crate::{
+    campaigns::{SessionModel, SessionNote},
+    http::link::{Link, LinkRel, Links},
+    users::{endpoints::model::UserLink, UserID, UsersService},
+};
+use rocket::{
+    http::hyper::header::{CacheControl, CacheDirective, ETag, EntityTag},
+    response, Request, State,
+};
+use rocket_contrib::json::Json;
+use serde_json::{json, Value};
+
+/// API Model representing a Session of a Campaign, including the notes that players have written about it
+#[derive(Debug)]
+pub struct SessionResponse(pub SessionModel, pub Vec<SessionNote>);
+
+pub(super) fn to_json(session: &SessionModel) -> Value {
+    json!({
+      "date": session.data.date.format("%Y-%m-%d").to_string(),
+      "summary": session.data.summary,
+      "entities": session.data.entities
+    })
+}
+
+impl<'r> response::Responder<'r> for SessionResponse {
+    fn respond_to(self, req: &Request) -> response::Result<'r> {
+        let users_service = req.guard::<State<UsersService>>().unwrap();
+
+        let etag = self.0.identity.version.to_string();
+
+        let notes: Vec<Value> = self
+            .1
+            .iter()
+            .map(|note| {
+                json!({
+                  "author": String::from(UserLink::new(note.author.clone())),
+                  "updated": note.updated,
+                  "notes": note.notes
+                })
+            })
+            .collect();
+        let mut response_body = to_json(&self.0);
+        response_body["notes"] = Value::from(notes);
+
+        let author_ids: Vec<&UserID> = self.1.iter().map(|note| &note.author).collect();
+        let authors = users_service.find_users_by_id(&author_ids[..]);
+
+        let mut links = vec![
+            Link::new(SessionLink::new(self.0.identity.id), LinkRel::SELF),
+            Link::new(CampaignLink::new(self.0.data.campaign), LinkRel::RELATED),
+        ];
+        for (index, note) in self.1.iter().enumerate() {
+            if let Some(author) = authors.iter().find(|user| user.identity.id == note.author) {
+                links.push(
+                    Link::new(UserLink::new(note.author.clone()), LinkRel::AUTHOR)
+                        .anchor(format!("#/notes/{}", index))
+                        .title(author.data.display_name.clone()),
+                );
+            }
+        }
+
+        response::Response::build()
+            .merge(Json(response_body).respond_to(req).unwrap())
+            .header(CacheControl(vec![
+                CacheDirective::Private,
+                CacheDirective::MaxAge(3600),
+            ]))
+            .header(ETag(EntityTag::new(false, etag)))
+            .header(Links(links))
+            .ok()
+    }
+}
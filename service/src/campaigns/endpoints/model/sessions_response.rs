@@ -0,0 +1,56 @@
+use super::{to_json, SessionLink};
+use crate::{
+    campaigns::SessionModel,
+    http::link::{Link, LinkRel, Links},
+    model::Page,
+};
+use rocket::{
+    http::hyper::header::{CacheControl, CacheDirective},
+    response, Request,
+};
+use rocket_contrib::json::Json;
+use serde_json::{json, Value};
+
+/// API Model representing a page of Sessions
+#[derive(Debug)]
+pub struct SessionsResponse(pub Page<SessionModel>);
+
+impl<'r> response::Responder<'r> for SessionsResponse {
+    fn respond_to(self, req: &Request) -> response::Result<'r> {
+        let sessions: Vec<Value> = self
+            .0
+            .entries
+            .iter()
+            .map(|session| to_json(&session))
+            .collect();
+
+        let response_body = json!({
+          "entries": sessions,
+          "pagination": {
+            "offset": self.0.offset,
+            "count": self.0.count,
+            "total": self.0.total
+          }
+        });
+
+        let session_links: Vec<Link> = self
+            .0
+            .entries
+            .iter()
+            .map(|session| SessionLink::new(session.identity.id.clone()))
+            .enumerate()
+            .map(|(index, link)| {
+                Link::new(link, LinkRel::ITEM).anchor(format!("#/entries/{}", index))
+            })
+            .collect();
+
+        response::Response::build()
+            .merge(Json(response_body).respond_to(req).unwrap())
+            .header(CacheControl(vec![
+                CacheDirective::Private,
+                CacheDirective::MaxAge(3600),
+            ]))
+            .header(Links(session_links))
+            .ok()
+    }
+}
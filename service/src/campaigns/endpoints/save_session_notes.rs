@@ -0,0 +1,87 @@
+use super::{errors::CampaignProblemType, model::SessionResponse};
+use crate::{
+    authorization::Authorizer,
+    campaigns::{CampaignsService, SaveSessionNotesError, SessionID},
+    http::problem::{GenericValidation, Problem, ValidationProblem},
+};
+use rocket::{http::Status, put, State};
+use rocket_contrib::json::Json;
+use serde::Deserialize;
+
+/// Handler to save the notes that the current player has written about a Session.
+///
+/// Only players of the campaign may write notes, and each player has a single set of notes per session
+/// that is replaced every time they are saved.
+///
+/// # Parameters
+/// - `campaigns_service` - The campaigns service to use
+/// - `id` - The ID of the session
+/// - `body` - The notes to save
+/// - `authorizer` - The authorizer to prove we're allowed to write notes about the session
+///
+/// # Returns
+/// The details of the session, including the newly saved notes
+#[tracing::instrument(name = "PUT /sessions/{id}/notes", skip(campaigns_service))]
+#[put("/sessions/<id>/notes", data = "<body>")]
+pub fn save_session_notes(
+    campaigns_service: State<CampaignsService>,
+    id: SessionID,
+    body: Json<SaveSessionNotesRequest>,
+    authorizer: Authorizer,
+) -> Result<SessionResponse, Problem> {
+    let session = campaigns_service
+        .find_session_by_id(&id)
+        .ok_or_else(|| Problem::new(CampaignProblemType::UnknownSession, Status::NotFound))?;
+    let campaign = campaigns_service
+        .find_campaign_by_id(&session.data.campaign)
+        .ok_or_else(|| Problem::new(CampaignProblemType::UnknownSession, Status::NotFound))?;
+
+    let author = authorizer
+        .authorize()
+        .one_of_users(&campaign.data.players)
+        .finish()?
+        .expect("No authenticated user");
+
+    let notes = body.notes().ok_or_else(|| {
+        tracing::warn!("Validation error saving session notes");
+        let mut problem = ValidationProblem::new();
+        problem.with_field_error("notes", GenericValidation::Missing);
+        problem.build()
+    })?;
+
+    campaigns_service.save_session_notes(&id, &author, &notes)?;
+
+    let notes = campaigns_service.find_session_notes(&id);
+    Ok(SessionResponse(session, notes))
+}
+
+/// Incoming details representing the notes a player has written about a session
+#[derive(Debug, Deserialize)]
+pub struct SaveSessionNotesRequest {
+    /// The notes, in Markdown
+    notes: Option<String>,
+}
+
+impl SaveSessionNotesRequest {
+    /// Get the notes to save
+    fn notes(&self) -> Option<String> {
+        self.notes.clone().filter(|v| !v.trim().is_empty())
+    }
+}
+
+impl From<SaveSessionNotesError> for Problem {
+    fn from(e: SaveSessionNotesError) -> Self {
+        match e {
+            SaveSessionNotesError::UnknownSession => {
+                Problem::new(CampaignProblemType::UnknownSession, Status::NotFound)
+            }
+            _ => {
+                tracing::warn!(error = ?e, "An unexpected error occurred");
+                Problem::new(
+                    CampaignProblemType::UnknownError,
+                    Status::InternalServerError,
+                )
+            }
+        }
+    }
+}
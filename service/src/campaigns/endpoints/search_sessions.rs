@@ -0,0 +1,47 @@
+use super::model::{CampaignLink, SessionsResponse};
+use crate::{
+    authorization::Authorizer,
+    campaigns::{CampaignsService, SessionsFilters},
+    http::{pagination::PaginationRequest, problem::Problem},
+};
+use rocket::{get, State};
+
+/// Handler to search the recorded sessions
+///
+/// Filtering by entity allows for finding every session that a given entity appeared in.
+///
+/// # Parameters
+/// - `campaigns_service` - The campaigns service to use
+/// - `campaign` - The campaign to filter against
+/// - `entity` - The entity reference to filter against
+/// - `pagination` - The pagination controls to apply
+/// - `authorizer` - The authorizer to identify the current user. Sessions of unpublished campaigns are
+///   only included for editors
+///
+/// # Returns
+/// The details of the sessions that matched the search, with the most recently played first
+#[tracing::instrument(name = "GET /sessions", skip(campaigns_service))]
+#[get("/sessions?<campaign>&<entity>&<pagination..>")]
+pub fn search_sessions(
+    campaigns_service: State<CampaignsService>,
+    campaign: Option<CampaignLink>,
+    entity: Option<String>,
+    pagination: PaginationRequest,
+    authorizer: Authorizer,
+) -> Result<SessionsResponse, Problem> {
+    tracing::debug!("Searching sessions");
+    let viewer = authorizer.authorize().finish()?;
+
+    let sessions = campaigns_service.search_sessions(
+        &SessionsFilters {
+            campaign: campaign.map(|link| link.into()),
+            entity,
+            viewer,
+        },
+        &pagination,
+    );
+
+    tracing::debug!(sessions = ?sessions, "Found sessions");
+
+    Ok(SessionsResponse(sessions))
+}
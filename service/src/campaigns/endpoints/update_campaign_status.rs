@@ -0,0 +1,53 @@
+use super::{errors::CampaignProblemType, model::CampaignResponse};
+use crate::{
+    authorization::Authorizer,
+    campaigns::{CampaignID, CampaignsService},
+    http::{
+        problem::{Problem, ValidationProblem},
+        publication::PublicationRequest,
+    },
+    worlds::{endpoints::world_owner::authorize_world_editor, WorldsService},
+};
+use rocket::{http::Status, put, State};
+use rocket_contrib::json::Json;
+
+/// Handler to change the publication status of a Campaign
+///
+/// # Parameters
+/// - `campaigns_service` - The campaigns service to use
+/// - `worlds_service` - The worlds service to use
+/// - `id` - The ID of the campaign
+/// - `body` - The new publication status of the campaign
+/// - `authorizer` - The authorizer to prove we're allowed to change the campaign
+///
+/// # Returns
+/// The updated campaign details, or a Problem if the update failed
+#[tracing::instrument(
+    name = "PUT /campaigns/{id}/status",
+    skip(campaigns_service, worlds_service)
+)]
+#[put("/campaigns/<id>/status", data = "<body>")]
+pub fn update_campaign_status(
+    campaigns_service: State<CampaignsService>,
+    worlds_service: State<WorldsService>,
+    id: CampaignID,
+    body: Json<PublicationRequest>,
+    authorizer: Authorizer,
+) -> Result<CampaignResponse, Problem> {
+    let campaign = campaigns_service
+        .find_campaign_by_id(&id)
+        .ok_or_else(|| Problem::new(CampaignProblemType::UnknownCampaign, Status::NotFound))?;
+
+    authorize_world_editor(&worlds_service, &authorizer, &campaign.data.world)?;
+
+    let mut problem = ValidationProblem::new();
+    let publication = body.build(&mut problem).ok_or_else(|| {
+        tracing::warn!("Validation error updating campaign status");
+        problem.build()
+    })?;
+
+    campaigns_service
+        .update_publication(&id, publication)
+        .map(CampaignResponse)
+        .ok_or_else(|| Problem::new(CampaignProblemType::UnknownCampaign, Status::NotFound))
+}
@@ -0,0 +1,11 @@
+mod campaign;
+mod campaign_id;
+mod session;
+mod session_id;
+mod session_note;
+
+pub use campaign::*;
+pub use campaign_id::*;
+pub use session::*;
+pub use session_id::*;
+pub use session_note::*;
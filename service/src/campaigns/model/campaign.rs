@@ -0,0 +1,28 @@
+use super::CampaignID;
+use crate::{
+    model::{Model, Publication},
+    users::UserID,
+    worlds::WorldID,
+};
+
+/// Data to represent a campaign that is run in a world
+#[derive(Debug, Clone)]
+pub struct CampaignData {
+    /// The ID of the world that the campaign is run in
+    pub world: WorldID,
+
+    /// The name of the campaign
+    pub name: String,
+
+    /// A description of the campaign
+    pub description: String,
+
+    /// The IDs of the users that are playing in the campaign
+    pub players: Vec<UserID>,
+
+    /// Whether the campaign is a draft, published or scheduled for publication
+    pub publication: Publication,
+}
+
+/// Model representation of a campaign
+pub type CampaignModel = Model<CampaignID, CampaignData>;
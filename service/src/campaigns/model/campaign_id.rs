@@ -0,0 +1,67 @@
+use bytes::BytesMut;
+use postgres_types::{accepts, to_sql_checked, FromSql, IsNull, ToSql, Type};
+use rocket::{http::RawStr, request};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use uuid::Uuid;
+
+/// The ID of a Campaign
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, FromSql)]
+pub struct CampaignID(Uuid);
+
+impl CampaignID {
+    /// Wrap a UUID as a Campaign ID object
+    ///
+    /// # Parameters
+    /// - `uuid` - The UUID to wrap
+    ///
+    /// # The Campaign ID
+    pub fn new(uuid: Uuid) -> Self {
+        CampaignID(uuid)
+    }
+}
+
+impl Default for CampaignID {
+    fn default() -> Self {
+        Self(Uuid::new_v4())
+    }
+}
+
+impl ToSql for CampaignID {
+    accepts!(UUID);
+
+    to_sql_checked!();
+
+    fn to_sql(
+        &self,
+        t: &Type,
+        w: &mut BytesMut,
+    ) -> Result<IsNull, Box<dyn std::error::Error + Sync + Send>> {
+        self.0.to_sql(t, w)
+    }
+}
+
+impl<'r> request::FromParam<'r> for CampaignID {
+    type Error = &'r RawStr;
+
+    fn from_param(param: &'r RawStr) -> Result<Self, Self::Error> {
+        param
+            .percent_decode()
+            .map(|cow| cow.into_owned())
+            .map_err(|_| param)
+            .and_then(|campaign_id| Uuid::parse_str(&campaign_id).map_err(|_| param))
+            .map(|campaign_id| CampaignID::new(campaign_id))
+    }
+}
+
+impl Display for CampaignID {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<CampaignID> for Uuid {
+    fn from(campaign_id: CampaignID) -> Self {
+        campaign_id.0
+    }
+}
@@ -0,0 +1,22 @@
+use super::{CampaignID, SessionID};
+use crate::model::Model;
+use chrono::NaiveDate;
+
+/// Data to represent a single session of a campaign
+#[derive(Debug, Clone)]
+pub struct SessionData {
+    /// The ID of the campaign that the session belongs to
+    pub campaign: CampaignID,
+
+    /// The date that the session was played on
+    pub date: NaiveDate,
+
+    /// The summary of the session, in Markdown
+    pub summary: String,
+
+    /// References to the entities that appeared in the session
+    pub entities: Vec<String>,
+}
+
+/// Model representation of a session
+pub type SessionModel = Model<SessionID, SessionData>;
@@ -0,0 +1,67 @@
+use bytes::BytesMut;
+use postgres_types::{accepts, to_sql_checked, FromSql, IsNull, ToSql, Type};
+use rocket::{http::RawStr, request};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use uuid::Uuid;
+
+/// The ID of a Session
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, FromSql)]
+pub struct SessionID(Uuid);
+
+impl SessionID {
+    /// Wrap a UUID as a Session ID object
+    ///
+    /// # Parameters
+    /// - `uuid` - The UUID to wrap
+    ///
+    /// # The Session ID
+    pub fn new(uuid: Uuid) -> Self {
+        SessionID(uuid)
+    }
+}
+
+impl Default for SessionID {
+    fn default() -> Self {
+        Self(Uuid::new_v4())
+    }
+}
+
+impl ToSql for SessionID {
+    accepts!(UUID);
+
+    to_sql_checked!();
+
+    fn to_sql(
+        &self,
+        t: &Type,
+        w: &mut BytesMut,
+    ) -> Result<IsNull, Box<dyn std::error::Error + Sync + Send>> {
+        self.0.to_sql(t, w)
+    }
+}
+
+impl<'r> request::FromParam<'r> for SessionID {
+    type Error = &'r RawStr;
+
+    fn from_param(param: &'r RawStr) -> Result<Self, Self::Error> {
+        param
+            .percent_decode()
+            .map(|cow| cow.into_owned())
+            .map_err(|_| param)
+            .and_then(|session_id| Uuid::parse_str(&session_id).map_err(|_| param))
+            .map(|session_id| SessionID::new(session_id))
+    }
+}
+
+impl Display for SessionID {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<SessionID> for Uuid {
+    fn from(session_id: SessionID) -> Self {
+        session_id.0
+    }
+}
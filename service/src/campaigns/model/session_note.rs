@@ -0,0 +1,15 @@
+use crate::users::UserID;
+use chrono::{DateTime, Utc};
+
+/// The notes that a single player has written about a session
+#[derive(Debug, Clone)]
+pub struct SessionNote {
+    /// The ID of the player that wrote the notes
+    pub author: UserID,
+
+    /// When the notes were last updated
+    pub updated: DateTime<Utc>,
+
+    /// The notes themselves, in Markdown
+    pub notes: String,
+}
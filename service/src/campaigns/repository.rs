@@ -0,0 +1,33 @@
+mod create_campaign;
+mod create_session;
+mod errors;
+mod find_campaign;
+mod find_sessions;
+mod notes;
+mod parse;
+mod publication;
+
+use crate::infrastructure::database::Database;
+pub(crate) use create_campaign::insert_campaign;
+pub(crate) use create_session::insert_session;
+pub use errors::*;
+
+/// Repository used to access Campaign and Session data from the database
+#[derive(Clone)]
+pub(super) struct CampaignRepository {
+    /// The database with which to access campaign records
+    database: Database,
+}
+
+impl CampaignRepository {
+    /// Create a new Campaign Repository to work with
+    ///
+    /// # Parameters
+    /// - `database` - The database connection to use
+    ///
+    /// # Returns
+    /// The Campaign Repository to use
+    pub(super) fn new(database: Database) -> Self {
+        Self { database }
+    }
+}
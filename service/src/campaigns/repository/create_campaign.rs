@@ -0,0 +1,90 @@
+use super::{CampaignRepository, SaveCampaignError};
+use crate::{
+    campaigns::model::*,
+    events::{publish_change, EntityType, WorldChange},
+    quotas::{enforce_quota, QuotaAllowance},
+};
+use chrono::Utc;
+use uuid::Uuid;
+
+impl CampaignRepository {
+    /// Create a new campaign in the database, enforcing the quota of campaigns that the owner of the world
+    /// has in the same transaction
+    ///
+    /// # Parameters
+    /// - `campaign` - The campaign to create
+    /// - `quota` - The allowance for the owner of the world to create another campaign, if the world exists
+    ///
+    /// # Returns
+    /// The newly created campaign
+    ///
+    /// # Errors
+    /// Any errors that occurred creating the new campaign
+    #[tracing::instrument(skip(self))]
+    pub fn create_campaign(
+        &self,
+        campaign: CampaignData,
+        quota: Option<&QuotaAllowance>,
+    ) -> Result<CampaignModel, SaveCampaignError> {
+        let mut connection = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+        let mut transaction = connection.transaction()?;
+
+        if let Some(quota) = quota {
+            enforce_quota(&mut transaction, quota)?;
+        }
+
+        let new_campaign = insert_campaign(&mut transaction, &CampaignID::default(), &campaign)?;
+
+        transaction.commit()?;
+
+        publish_change(
+            &mut connection,
+            WorldChange {
+                world: new_campaign.data.world.clone(),
+                entity_type: EntityType::Campaign,
+                entity_id: new_campaign.identity.id.clone().into(),
+                version: Some(new_campaign.identity.version),
+            },
+        );
+
+        Ok(new_campaign)
+    }
+}
+
+/// Insert a new campaign using an existing transaction, without enforcing any quota or publishing the
+/// change
+///
+/// # Parameters
+/// - `transaction` - The transaction to insert the campaign in
+/// - `id` - The ID to give the new campaign
+/// - `campaign` - The campaign to create
+///
+/// # Returns
+/// The newly created campaign
+///
+/// # Errors
+/// Any errors that occurred creating the new campaign
+pub(crate) fn insert_campaign(
+    transaction: &mut postgres::Transaction<'_>,
+    id: &CampaignID,
+    campaign: &CampaignData,
+) -> Result<CampaignModel, SaveCampaignError> {
+    let new_campaign = transaction.query_one("INSERT INTO campaigns(campaign_id, version, created, updated, world_id, name, description, players, status, publish_at) VALUES($1, $2, $3, $3, $4, $5, $6, $7, $8, $9) RETURNING *",
+        &[
+            id,
+            &Uuid::new_v4(),
+            &Utc::now(),
+            &campaign.world,
+            &campaign.name,
+            &campaign.description,
+            &campaign.players,
+            &campaign.publication.stored_status().to_string(),
+            &campaign.publication.publish_at(),
+        ])
+        .map(|row| CampaignRepository::parse_campaign_row(&row))?;
+
+    Ok(new_campaign)
+}
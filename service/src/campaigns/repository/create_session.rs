@@ -0,0 +1,81 @@
+use super::{CampaignRepository, SaveSessionError};
+use crate::{
+    campaigns::model::*,
+    events::{publish_change, EntityType, WorldChange},
+    worlds::WorldID,
+};
+use chrono::Utc;
+use uuid::Uuid;
+
+impl CampaignRepository {
+    /// Create a new session of a campaign in the database
+    ///
+    /// # Parameters
+    /// - `session` - The session to create
+    ///
+    /// # Returns
+    /// The newly created session
+    ///
+    /// # Errors
+    /// Any errors that occurred creating the new session
+    #[tracing::instrument(skip(self))]
+    pub fn create_session(&self, session: SessionData) -> Result<SessionModel, SaveSessionError> {
+        let mut connection = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+        let mut transaction = connection.transaction()?;
+
+        let new_session = insert_session(&mut transaction, &session)?;
+
+        transaction.commit()?;
+
+        let world: WorldID = connection
+            .query_one(
+                "SELECT world_id FROM campaigns WHERE campaign_id = $1",
+                &[&new_session.data.campaign],
+            )?
+            .get("world_id");
+        publish_change(
+            &mut connection,
+            WorldChange {
+                world,
+                entity_type: EntityType::Session,
+                entity_id: new_session.identity.id.clone().into(),
+                version: Some(new_session.identity.version),
+            },
+        );
+
+        Ok(new_session)
+    }
+}
+
+/// Insert a new session using an existing transaction, without publishing the change
+///
+/// # Parameters
+/// - `transaction` - The transaction to insert the session in
+/// - `session` - The session to create
+///
+/// # Returns
+/// The newly created session
+///
+/// # Errors
+/// Any errors that occurred creating the new session
+pub(crate) fn insert_session(
+    transaction: &mut postgres::Transaction<'_>,
+    session: &SessionData,
+) -> Result<SessionModel, SaveSessionError> {
+    let new_session = transaction.query_one("INSERT INTO sessions(session_id, version, created, updated, campaign_id, session_date, summary, entities) VALUES($1, $2, $3, $3, $4, $5, $6, $7) RETURNING *",
+        &[
+            &SessionID::default(),
+            &Uuid::new_v4(),
+            &Utc::now(),
+            &session.campaign,
+            &session.date,
+            &session.summary,
+            &session.entities,
+        ])
+        .map(|row| CampaignRepository::parse_session_row(&row))?;
+
+    Ok(new_session)
+}
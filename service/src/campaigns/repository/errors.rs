@@ -0,0 +1,111 @@
+use crate::quotas::QuotaExceededError;
+use postgres::error::{DbError, SqlState};
+
+/// Errors that can occur when saving a campaign record
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum SaveCampaignError {
+    /// An unknown error occurred
+    #[error("An unknown error occurred")]
+    UnknownError,
+
+    /// The world that the campaign belongs to does not exist
+    #[error("The desired world does not exist")]
+    UnknownWorld,
+
+    /// The owner of the world has reached their quota of campaigns
+    #[error("The owner of the world has reached their quota of campaigns")]
+    QuotaExceeded(#[from] QuotaExceededError),
+}
+
+/// Errors that can occur when saving a session record, or the notes for a session
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum SaveSessionError {
+    /// An unknown error occurred
+    #[error("An unknown error occurred")]
+    UnknownError,
+
+    /// The campaign that the session belongs to does not exist
+    #[error("The desired campaign does not exist")]
+    UnknownCampaign,
+
+    /// The session that the notes belong to does not exist
+    #[error("The desired session does not exist")]
+    UnknownSession,
+}
+
+/// Extract the name of the foreign key constraint that was violated, if that is what the error was
+///
+/// # Parameters
+/// - `e` - The error to inspect
+///
+/// # Returns
+/// The name of the constraint, if the error was a foreign key violation
+fn foreign_key_constraint(e: postgres::Error) -> Option<String> {
+    if e.code() == Some(&SqlState::FOREIGN_KEY_VIOLATION) {
+        let db_error: Option<DbError> = e
+            .into_source()
+            .and_then(|e| e.downcast_ref::<DbError>().cloned());
+
+        db_error.and_then(|e| e.constraint().map(|c| c.to_owned()))
+    } else {
+        tracing::warn!("Unexpected database error: {:?}", e);
+        None
+    }
+}
+
+impl From<postgres::Error> for SaveCampaignError {
+    /// Convert a database error into a SaveCampaignError.
+    ///
+    /// The conversion returns an `UnknownWorld` iff the error is a `FOREIGN_KEY_VIOLATION` and the constraint
+    /// is `campaigns_world_id_fkey`. Otherwise it returns an `UnknownError`
+    ///
+    /// # Parameters
+    /// - `e` - The error to convert
+    ///
+    /// # Returns
+    /// The new error code
+    fn from(e: postgres::Error) -> Self {
+        foreign_key_constraint(e)
+            .map(|constraint| match constraint.as_ref() {
+                "campaigns_world_id_fkey" => SaveCampaignError::UnknownWorld,
+                _ => {
+                    tracing::warn!(
+                        "Unexpected foreign key constraint violation error: {:?}",
+                        constraint
+                    );
+                    SaveCampaignError::UnknownError
+                }
+            })
+            .unwrap_or(SaveCampaignError::UnknownError)
+    }
+}
+
+impl From<postgres::Error> for SaveSessionError {
+    /// Convert a database error into a SaveSessionError.
+    ///
+    /// The conversion returns an `UnknownCampaign` iff the error is a `FOREIGN_KEY_VIOLATION` and the
+    /// constraint is `sessions_campaign_id_fkey`, and an `UnknownSession` iff the error is a
+    /// `FOREIGN_KEY_VIOLATION` and the constraint is `session_notes_session_id_fkey`. Otherwise it returns
+    /// an `UnknownError`
+    ///
+    /// # Parameters
+    /// - `e` - The error to convert
+    ///
+    /// # Returns
+    /// The new error code
+    fn from(e: postgres::Error) -> Self {
+        foreign_key_constraint(e)
+            .map(|constraint| match constraint.as_ref() {
+                "sessions_campaign_id_fkey" => SaveSessionError::UnknownCampaign,
+                "session_notes_session_id_fkey" => SaveSessionError::UnknownSession,
+                _ => {
+                    tracing::warn!(
+                        "Unexpected foreign key constraint violation error: {:?}",
+                        constraint
+                    );
+                    SaveSessionError::UnknownError
+                }
+            })
+            .unwrap_or(SaveSessionError::UnknownError)
+    }
+}
@@ -0,0 +1,63 @@
+use super::CampaignRepository;
+use crate::{
+    campaigns::{CampaignID, CampaignModel},
+    model::published_sql,
+    worlds::WorldID,
+};
+
+impl CampaignRepository {
+    /// Find a campaign in the database by its unique ID
+    ///
+    /// # Parameters
+    /// - `id` - The ID of the campaign
+    ///
+    /// # Returns
+    /// The campaign, if it exists
+    #[tracing::instrument(skip(self))]
+    pub fn find_campaign_by_id(&self, id: &CampaignID) -> Option<CampaignModel> {
+        let mut connection = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+        connection
+            .query_opt("SELECT * FROM campaigns WHERE campaign_id = $1", &[id])
+            .expect("Failed to query for campaign by ID")
+            .map(|row| Self::parse_campaign_row(&row))
+    }
+
+    /// Find all of the campaigns that are run in a world, ordered by name
+    ///
+    /// # Parameters
+    /// - `world` - The ID of the world
+    /// - `include_unpublished` - Whether to include campaigns that aren't yet published
+    ///
+    /// # Returns
+    /// The campaigns in the world
+    #[tracing::instrument(skip(self))]
+    pub fn find_campaigns_in_world(
+        &self,
+        world: &WorldID,
+        include_unpublished: bool,
+    ) -> Vec<CampaignModel> {
+        let published_clause = if include_unpublished {
+            "".to_owned()
+        } else {
+            format!(" AND {}", published_sql("campaigns"))
+        };
+        let query = format!(
+            "SELECT * FROM campaigns WHERE world_id = $1{} ORDER BY name ASC, campaign_id ASC",
+            published_clause
+        );
+
+        let mut connection = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+        connection
+            .query(query.as_str(), &[world])
+            .expect("Failed to query for campaigns in world")
+            .iter()
+            .map(Self::parse_campaign_row)
+            .collect()
+    }
+}
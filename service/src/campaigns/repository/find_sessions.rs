@@ -0,0 +1,138 @@
+use super::CampaignRepository;
+use crate::{
+    campaigns::{CampaignID, SessionID, SessionModel, SessionsFilters},
+    model::{published_sql, Page, Pagination},
+    worlds::{editable_world_sql, visible_world_sql},
+};
+
+impl CampaignRepository {
+    /// Find a session in the database by its unique ID
+    ///
+    /// # Parameters
+    /// - `id` - The ID of the session
+    ///
+    /// # Returns
+    /// The session, if it exists
+    #[tracing::instrument(skip(self))]
+    pub fn find_session_by_id(&self, id: &SessionID) -> Option<SessionModel> {
+        let mut connection = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+        connection
+            .query_opt("SELECT * FROM sessions WHERE session_id = $1", &[id])
+            .expect("Failed to query for session by ID")
+            .map(|row| Self::parse_session_row(&row))
+    }
+
+    /// Find every session of a campaign, with the earliest played first
+    ///
+    /// # Parameters
+    /// - `campaign` - The ID of the campaign
+    ///
+    /// # Returns
+    /// The sessions of the campaign
+    #[tracing::instrument(skip(self))]
+    pub fn find_campaign_sessions(&self, campaign: &CampaignID) -> Vec<SessionModel> {
+        let mut connection = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+        connection
+            .query(
+                "SELECT * FROM sessions WHERE campaign_id = $1 ORDER BY session_date ASC, created ASC, session_id ASC",
+                &[campaign],
+            )
+            .expect("Failed to query for sessions of campaign")
+            .iter()
+            .map(Self::parse_session_row)
+            .collect()
+    }
+
+    /// Search for the sessions that match the given filters, with the most recently played first
+    ///
+    /// # Parameters
+    /// - `filters` - The filters to apply to the search
+    /// - `pagination` - The pagination details for which page of results are wanted
+    ///
+    /// # Returns
+    /// The requested page of results
+    #[tracing::instrument(skip(self))]
+    pub fn search_sessions(
+        &self,
+        filters: &SessionsFilters,
+        pagination: &Pagination,
+    ) -> Page<SessionModel> {
+        // Collect together the Where clauses and Binds
+        let mut where_clauses: Vec<String> = vec![];
+        let mut binds: Vec<&(dyn postgres::types::ToSql + Sync)> = vec![];
+
+        if let Some(campaign) = &filters.campaign {
+            binds.push(campaign);
+            where_clauses.push(format!("campaign_id = ${}", binds.len()));
+        }
+        if let Some(entity) = &filters.entity {
+            binds.push(entity);
+            where_clauses.push(format!("${} = ANY(entities)", binds.len()));
+        }
+        let visible_clause = match &filters.viewer {
+            None => format!(
+                "{} AND {}",
+                published_sql("campaigns"),
+                visible_world_sql("worlds", None)
+            ),
+            Some(viewer) => {
+                binds.push(viewer);
+                format!(
+                    "({} AND {}) OR {}",
+                    published_sql("campaigns"),
+                    visible_world_sql("worlds", None),
+                    editable_world_sql("worlds", binds.len())
+                )
+            }
+        };
+        where_clauses.push(format!(
+            "campaign_id IN (SELECT campaigns.campaign_id FROM campaigns JOIN worlds ON worlds.world_id = campaigns.world_id WHERE {})",
+            visible_clause
+        ));
+        let where_clause = format!("WHERE {}", where_clauses.join(" AND "));
+
+        let mut connection = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+
+        let select_query = format!(
+            "SELECT * FROM sessions {} ORDER BY session_date DESC, created DESC, session_id ASC OFFSET {} LIMIT {}",
+            where_clause, pagination.offset, pagination.count
+        );
+        let records: Vec<SessionModel> = connection
+            .query(select_query.as_str(), &binds[..])
+            .map(|rows| rows.iter().map(Self::parse_session_row).collect())
+            .expect("Failed to select sessions");
+
+        let num_records = records.len() as u64;
+        let total = if records.is_empty() || num_records == pagination.count {
+            // If we got zero records back, or exactly the number we asked for, then we don't know
+            // how many there are in the database
+            let count_query = format!(
+                "SELECT COUNT(*)::INTEGER AS c FROM sessions {}",
+                where_clause
+            );
+            let count: i32 = connection
+                .query_one(count_query.as_str(), &binds[..])
+                .map(|row| row.get("c"))
+                .expect("Failed to count sessions");
+            count as u64
+        } else {
+            pagination.offset + num_records
+        };
+
+        Page {
+            entries: records,
+            offset: pagination.offset,
+            count: pagination.count,
+            total,
+        }
+    }
+}
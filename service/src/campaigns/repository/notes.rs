@@ -0,0 +1,68 @@
+use super::{CampaignRepository, SaveSessionError};
+use crate::{
+    campaigns::{SessionID, SessionNote},
+    users::UserID,
+};
+use chrono::Utc;
+
+impl CampaignRepository {
+    /// Find all of the notes that players have written about a session
+    ///
+    /// # Parameters
+    /// - `session` - The ID of the session
+    ///
+    /// # Returns
+    /// The notes for the session, oldest first
+    #[tracing::instrument(skip(self))]
+    pub fn find_session_notes(&self, session: &SessionID) -> Vec<SessionNote> {
+        let mut connection = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+        connection
+            .query(
+                "SELECT * FROM session_notes WHERE session_id = $1 ORDER BY created ASC, author_id ASC",
+                &[session],
+            )
+            .map(|rows| rows.iter().map(|row| self.parse_note_row(row)).collect())
+            .expect("Failed to query for session notes")
+    }
+
+    /// Save the notes that a player has written about a session, replacing any that they had already written
+    ///
+    /// # Parameters
+    /// - `session` - The ID of the session
+    /// - `author` - The ID of the player that wrote the notes
+    /// - `notes` - The notes themselves
+    ///
+    /// # Returns
+    /// The saved notes
+    ///
+    /// # Errors
+    /// Any errors that occurred saving the notes
+    #[tracing::instrument(skip(self))]
+    pub fn save_session_notes(
+        &self,
+        session: &SessionID,
+        author: &UserID,
+        notes: &str,
+    ) -> Result<SessionNote, SaveSessionError> {
+        let now = Utc::now();
+
+        let mut connection = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+
+        let note = connection.query_one("INSERT INTO session_notes(session_id, author_id, created, updated, notes) VALUES($1, $2, $3, $3, $4) ON CONFLICT (session_id, author_id) DO UPDATE SET updated = EXCLUDED.updated, notes = EXCLUDED.notes RETURNING *",
+            &[
+                session,
+                author,
+                &now,
+                &notes,
+            ])
+            .map(|row| self.parse_note_row(&row))?;
+
+        Ok(note)
+    }
+}
@@ -0,0 +1,72 @@
+use super::CampaignRepository;
+use crate::{
+    campaigns::model::*,
+    model::{Identity, Publication},
+};
+use postgres::row::Row;
+
+impl CampaignRepository {
+    /// Parse a row from the database into a Campaign Model
+    ///
+    /// # Parameters
+    /// - `row` - The row to parse
+    ///
+    /// # Returns
+    /// The campaign that the row represented
+    pub(super) fn parse_campaign_row(row: &Row) -> CampaignModel {
+        CampaignModel {
+            identity: Identity {
+                id: row.get("campaign_id"),
+                version: row.get("version"),
+                created: row.get("created"),
+                updated: row.get("updated"),
+            },
+            data: CampaignData {
+                world: row.get("world_id"),
+                name: row.get("name"),
+                description: row.get("description"),
+                players: row.get("players"),
+                publication: Publication::from_stored(row.get("status"), row.get("publish_at")),
+            },
+        }
+    }
+
+    /// Parse a row from the database into a Session Model
+    ///
+    /// # Parameters
+    /// - `row` - The row to parse
+    ///
+    /// # Returns
+    /// The session that the row represented
+    pub(super) fn parse_session_row(row: &Row) -> SessionModel {
+        SessionModel {
+            identity: Identity {
+                id: row.get("session_id"),
+                version: row.get("version"),
+                created: row.get("created"),
+                updated: row.get("updated"),
+            },
+            data: SessionData {
+                campaign: row.get("campaign_id"),
+                date: row.get("session_date"),
+                summary: row.get("summary"),
+                entities: row.get("entities"),
+            },
+        }
+    }
+
+    /// Parse a row from the database into the notes for a session
+    ///
+    /// # Parameters
+    /// - `row` - The row to parse
+    ///
+    /// # Returns
+    /// The notes that the row represented
+    pub(super) fn parse_note_row(&self, row: &Row) -> SessionNote {
+        SessionNote {
+            author: row.get("author_id"),
+            updated: row.get("updated"),
+            notes: row.get("notes"),
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use super::CampaignRepository;
+use crate::{
+    campaigns::model::*,
+    events::{publish_change, EntityType, WorldChange},
+    model::Publication,
+};
+use chrono::Utc;
+use uuid::Uuid;
+
+impl CampaignRepository {
+    /// Change the publication state of a campaign
+    ///
+    /// # Parameters
+    /// - `id` - The ID of the campaign to update
+    /// - `publication` - The new publication state of the campaign
+    ///
+    /// # Returns
+    /// The updated campaign, or `None` if the campaign didn't exist
+    #[tracing::instrument(skip(self))]
+    pub fn update_publication(
+        &self,
+        id: &CampaignID,
+        publication: &Publication,
+    ) -> Option<CampaignModel> {
+        let mut connection = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+
+        let updated_campaign = connection
+            .query_opt(
+                "UPDATE campaigns SET version = $2, updated = $3, status = $4, publish_at = $5 WHERE campaign_id = $1 RETURNING *",
+                &[
+                    id,
+                    &Uuid::new_v4(),
+                    &Utc::now(),
+                    &publication.stored_status().to_string(),
+                    &publication.publish_at(),
+                ],
+            )
+            .expect("Failed to update campaign publication")
+            .map(|row| Self::parse_campaign_row(&row))?;
+
+        publish_change(
+            &mut connection,
+            WorldChange {
+                world: updated_campaign.data.world.clone(),
+                entity_type: EntityType::Campaign,
+                entity_id: updated_campaign.identity.id.clone().into(),
+                version: Some(updated_campaign.identity.version),
+            },
+        );
+
+        Some(updated_campaign)
+    }
+}
@@ -0,0 +1,41 @@
+mod create_campaign;
+mod create_session;
+mod find;
+mod notes;
+mod publication;
+
+use super::repository::CampaignRepository;
+use crate::{activity::ActivityService, quotas::QuotasService, webhooks::WebhooksService};
+pub use create_campaign::CreateCampaignError;
+pub use create_session::CreateSessionError;
+pub use find::SessionsFilters;
+pub use notes::SaveSessionNotesError;
+
+/// Service Layer for dealing with Campaigns and their Sessions
+#[derive(Clone)]
+pub struct CampaignsService {
+    repository: CampaignRepository,
+    activity: ActivityService,
+    webhooks: WebhooksService,
+    quotas: QuotasService,
+}
+
+impl CampaignsService {
+    /// Create a new instance of the Campaigns Service
+    ///
+    /// # Returns
+    /// The Campaigns Service ready to use
+    pub(super) fn new(
+        repository: CampaignRepository,
+        activity: ActivityService,
+        webhooks: WebhooksService,
+        quotas: QuotasService,
+    ) -> Self {
+        Self {
+            repository,
+            activity,
+            webhooks,
+            quotas,
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use crate::{
+    activity::{ActivityData, ActivityEvent},
+    campaigns::{endpoints::model::CampaignLink, repository::SaveCampaignError, *},
+    quotas::{QuotaExceededError, QuotaResource},
+    webhooks::{WebhookEvent, WebhookEventKind},
+};
+
+/// Errors that can occur when creating a new campaign
+#[derive(Debug, thiserror::Error)]
+pub enum CreateCampaignError {
+    /// An unknown error occurred
+    #[error("An unknown error occurred")]
+    UnknownError,
+
+    #[error("The desired world does not exist")]
+    UnknownWorld,
+
+    #[error("The owner of the world has reached their quota of campaigns")]
+    QuotaExceeded(#[from] QuotaExceededError),
+}
+
+impl CampaignsService {
+    /// Create a new campaign in the system
+    ///
+    /// # Parameters
+    /// - `campaign` - The campaign to create
+    ///
+    /// # Returns
+    /// The newly created campaign
+    ///
+    /// # Errors
+    /// Any errors that occurred creating the new campaign
+    pub fn create_campaign(
+        &self,
+        campaign: CampaignData,
+    ) -> Result<CampaignModel, CreateCampaignError> {
+        tracing::debug!(campaign = ?campaign, "Creating campaign");
+
+        let quota = self
+            .quotas
+            .check_world_quota(&campaign.world, QuotaResource::Campaigns, 1)?;
+
+        let new_campaign = self.repository.create_campaign(campaign, quota.as_ref())?;
+        tracing::debug!(campaign = ?new_campaign, "Created campaign");
+
+        self.campaign_created(&new_campaign);
+
+        Ok(new_campaign)
+    }
+
+    /// Record the activity and dispatch the webhook event for a campaign that has just been created
+    pub(crate) fn campaign_created(&self, new_campaign: &CampaignModel) {
+        self.activity.record_activity(ActivityData {
+            world: new_campaign.data.world.clone(),
+            user: None,
+            event: ActivityEvent::CampaignCreated,
+            target: Some(CampaignLink::new(new_campaign.identity.id.clone()).into()),
+        });
+        self.webhooks.dispatch(WebhookEvent {
+            world: new_campaign.data.world.clone(),
+            kind: WebhookEventKind::CampaignCreated,
+            target: CampaignLink::new(new_campaign.identity.id.clone()).into(),
+        });
+    }
+}
+
+impl From<SaveCampaignError> for CreateCampaignError {
+    fn from(e: SaveCampaignError) -> Self {
+        match e {
+            SaveCampaignError::UnknownWorld => CreateCampaignError::UnknownWorld,
+            SaveCampaignError::QuotaExceeded(e) => CreateCampaignError::QuotaExceeded(e),
+            _ => CreateCampaignError::UnknownError,
+        }
+    }
+}
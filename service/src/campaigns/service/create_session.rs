@@ -0,0 +1,74 @@
+use crate::{
+    activity::{ActivityData, ActivityEvent},
+    campaigns::{endpoints::model::SessionLink, repository::SaveSessionError, *},
+    webhooks::{WebhookEvent, WebhookEventKind},
+    worlds::WorldID,
+};
+
+/// Errors that can occur when creating a new session
+#[derive(Debug, thiserror::Error)]
+pub enum CreateSessionError {
+    /// An unknown error occurred
+    #[error("An unknown error occurred")]
+    UnknownError,
+
+    #[error("The desired campaign does not exist")]
+    UnknownCampaign,
+}
+
+impl CampaignsService {
+    /// Create a new session of a campaign
+    ///
+    /// # Parameters
+    /// - `session` - The session to create
+    ///
+    /// # Returns
+    /// The newly created session
+    ///
+    /// # Errors
+    /// Any errors that occurred creating the new session
+    pub fn create_session(&self, session: SessionData) -> Result<SessionModel, CreateSessionError> {
+        tracing::debug!(session = ?session, "Creating session");
+
+        let new_session = self.repository.create_session(session)?;
+        tracing::debug!(session = ?new_session, "Created session");
+
+        if let Some(campaign) = self
+            .repository
+            .find_campaign_by_id(&new_session.data.campaign)
+        {
+            self.session_created(&new_session, &campaign.data.world);
+        }
+
+        Ok(new_session)
+    }
+
+    /// Record the activity and dispatch the webhook event for a session that has just been created
+    ///
+    /// # Parameters
+    /// - `new_session` - The session that was created
+    /// - `world` - The world that the campaign of the session belongs to
+    pub(crate) fn session_created(&self, new_session: &SessionModel, world: &WorldID) {
+        let target: String = SessionLink::new(new_session.identity.id.clone()).into();
+        self.activity.record_activity(ActivityData {
+            world: world.clone(),
+            user: None,
+            event: ActivityEvent::SessionCreated,
+            target: Some(target.clone()),
+        });
+        self.webhooks.dispatch(WebhookEvent {
+            world: world.clone(),
+            kind: WebhookEventKind::SessionCreated,
+            target,
+        });
+    }
+}
+
+impl From<SaveSessionError> for CreateSessionError {
+    fn from(e: SaveSessionError) -> Self {
+        match e {
+            SaveSessionError::UnknownCampaign => CreateSessionError::UnknownCampaign,
+            _ => CreateSessionError::UnknownError,
+        }
+    }
+}
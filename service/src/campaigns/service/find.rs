@@ -0,0 +1,100 @@
+use super::CampaignsService;
+use crate::{
+    campaigns::{CampaignID, CampaignModel, SessionID, SessionModel, SessionNote},
+    model::{Page, Pagination},
+    users::UserID,
+    worlds::WorldID,
+};
+
+/// Filters to apply when searching for sessions
+#[derive(Debug, Default)]
+pub struct SessionsFilters {
+    /// Only include sessions of this campaign
+    pub campaign: Option<CampaignID>,
+
+    /// Only include sessions that this entity appeared in
+    pub entity: Option<String>,
+
+    /// The user doing the search. Sessions of campaigns that aren't published are only included if this
+    /// user is an editor of the world
+    pub viewer: Option<UserID>,
+}
+
+impl CampaignsService {
+    /// Look up a campaign by it's unique ID
+    ///
+    /// # Parameters
+    /// - `id` - The ID of the Campaign to find
+    ///
+    /// # Returns
+    /// The Campaign, or `None` if it wasn't found
+    pub fn find_campaign_by_id(&self, id: &CampaignID) -> Option<CampaignModel> {
+        self.repository.find_campaign_by_id(id)
+    }
+
+    /// Find all of the campaigns that are run in a world
+    ///
+    /// # Parameters
+    /// - `world` - The ID of the World
+    /// - `include_unpublished` - Whether to include campaigns that aren't yet published
+    ///
+    /// # Returns
+    /// The campaigns, ordered by name
+    pub fn find_campaigns_in_world(
+        &self,
+        world: &WorldID,
+        include_unpublished: bool,
+    ) -> Vec<CampaignModel> {
+        self.repository
+            .find_campaigns_in_world(world, include_unpublished)
+    }
+
+    /// Look up a session by it's unique ID
+    ///
+    /// # Parameters
+    /// - `id` - The ID of the Session to find
+    ///
+    /// # Returns
+    /// The Session, or `None` if it wasn't found
+    pub fn find_session_by_id(&self, id: &SessionID) -> Option<SessionModel> {
+        self.repository.find_session_by_id(id)
+    }
+
+    /// Find every session of a campaign
+    ///
+    /// # Parameters
+    /// - `campaign` - The ID of the Campaign
+    ///
+    /// # Returns
+    /// The sessions, with the earliest played first
+    pub fn find_campaign_sessions(&self, campaign: &CampaignID) -> Vec<SessionModel> {
+        self.repository.find_campaign_sessions(campaign)
+    }
+
+    /// Find all of the notes that players have written about a session
+    ///
+    /// # Parameters
+    /// - `id` - The ID of the Session
+    ///
+    /// # Returns
+    /// The notes about the session
+    pub fn find_session_notes(&self, id: &SessionID) -> Vec<SessionNote> {
+        self.repository.find_session_notes(id)
+    }
+
+    /// Search for sessions that match the given criteria
+    ///
+    /// # Parameters
+    /// - `filters` - The filters to apply to the search
+    /// - `pagination` - The pagination controls to apply
+    ///
+    /// # Returns
+    /// The page of sessions that matched, with the most recently played first
+    pub fn search_sessions(
+        &self,
+        filters: &SessionsFilters,
+        pagination: &Pagination,
+    ) -> Page<SessionModel> {
+        self.repository.search_sessions(filters, pagination)
+    }
+}
@@ -0,0 +1,50 @@
+use crate::{
+    campaigns::{repository::SaveSessionError, *},
+    users::UserID,
+};
+
+/// Errors that can occur when saving the notes for a session
+#[derive(Debug, thiserror::Error)]
+pub enum SaveSessionNotesError {
+    /// An unknown error occurred
+    #[error("An unknown error occurred")]
+    UnknownError,
+
+    #[error("The desired session does not exist")]
+    UnknownSession,
+}
+
+impl CampaignsService {
+    /// Save the notes that a player has written about a session, replacing any that they had already written
+    ///
+    /// # Parameters
+    /// - `session` - The ID of the session
+    /// - `author` - The ID of the player that wrote the notes
+    /// - `notes` - The notes themselves
+    ///
+    /// # Returns
+    /// The saved notes
+    ///
+    /// # Errors
+    /// Any errors that occurred saving the notes
+    pub fn save_session_notes(
+        &self,
+        session: &SessionID,
+        author: &UserID,
+        notes: &str,
+    ) -> Result<SessionNote, SaveSessionNotesError> {
+        let note = self.repository.save_session_notes(session, author, notes)?;
+        tracing::debug!(note = ?note, "Saved session notes");
+
+        Ok(note)
+    }
+}
+
+impl From<SaveSessionError> for SaveSessionNotesError {
+    fn from(e: SaveSessionError) -> Self {
+        match e {
+            SaveSessionError::UnknownSession => SaveSessionNotesError::UnknownSession,
+            _ => SaveSessionNotesError::UnknownError,
+        }
+    }
+}
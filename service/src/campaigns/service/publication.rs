@@ -0,0 +1,26 @@
+use super::CampaignsService;
+use crate::{
+    campaigns::{CampaignID, CampaignModel},
+    model::Publication,
+};
+
+impl CampaignsService {
+    /// Change the publication state of a campaign
+    ///
+    /// # Parameters
+    /// - `campaign` - The ID of the campaign to update
+    /// - `publication` - The new publication state of the campaign
+    ///
+    /// # Returns
+    /// The updated campaign, or `None` if the campaign didn't exist
+    pub fn update_publication(
+        &self,
+        campaign: &CampaignID,
+        publication: Publication,
+    ) -> Option<CampaignModel> {
+        let updated = self.repository.update_publication(campaign, &publication);
+        tracing::debug!(campaign = ?updated, "Updated campaign publication");
+
+        updated
+    }
+}
@@ -0,0 +1,64 @@
+use super::{repository::CharacterRepository, CharactersService};
+use crate::{
+    activity::ActivityService, infrastructure::database::Database, quotas::QuotasService,
+    webhooks::WebhooksService,
+};
+use std::sync::Arc;
+
+/// Application Configuration for the Characters module
+pub struct CharactersConfig {
+    pub characters_service: CharactersService,
+}
+
+impl CharactersConfig {
+    /// Create the Configuration for the Characters Module
+    ///
+    /// # Parameters
+    /// - `database` - The database connection to use
+    /// - `activity_service` - The activity service to record activity with
+    /// - `webhooks_service` - The webhooks service to dispatch events with
+    /// - `quotas_service` - The quotas service to limit how many characters users can have
+    ///
+    /// # Returns
+    /// The Characters Config object
+    pub fn new(
+        database: Database,
+        activity_service: ActivityService,
+        webhooks_service: WebhooksService,
+        quotas_service: QuotasService,
+    ) -> Self {
+        let repository = CharacterRepository::new(database);
+        Self {
+            characters_service: CharactersService::new(
+                repository,
+                activity_service,
+                webhooks_service,
+                quotas_service,
+            ),
+        }
+    }
+
+    /// Generate the configuration callback needed for the HTTP Server to actually add the Characters
+    /// endpoints to the server
+    ///
+    /// # Returns
+    /// The callback to provide to the HTTP Server to configure up the Characters endpoints
+    pub fn configure(&self) -> Arc<dyn Fn(rocket::Rocket) -> rocket::Rocket + Send + Sync> {
+        let characters_service = self.characters_service.clone();
+        Arc::new(move |config| {
+            config.manage(characters_service.clone()).mount(
+                "/",
+                rocket::routes![
+                    super::endpoints::create_character,
+                    super::endpoints::delete_character,
+                    super::endpoints::export_characters_csv,
+                    super::endpoints::get_character,
+                    super::endpoints::import_characters_csv,
+                    super::endpoints::list_user_characters,
+                    super::endpoints::search_characters,
+                    super::endpoints::update_character
+                ],
+            )
+        })
+    }
+}
@@ -0,0 +1,20 @@
+mod character_controller;
+mod create_character;
+mod delete_character;
+mod errors;
+mod export_characters_csv;
+mod get_character;
+mod import_characters_csv;
+mod list_user_characters;
+pub(crate) mod model;
+mod search_characters;
+mod update_character;
+
+pub use create_character::*;
+pub use delete_character::*;
+pub use export_characters_csv::*;
+pub use get_character::*;
+pub use import_characters_csv::*;
+pub use list_user_characters::*;
+pub use search_characters::*;
+pub use update_character::*;
@@ -0,0 +1,110 @@
+use super::errors::CharacterProblemType;
+use crate::{
+    authorization::Authorizer,
+    characters::{CharacterID, CharacterModel, CharactersService},
+    http::problem::Problem,
+    worlds::{endpoints::world_owner::authorize_world_viewer, WorldModel, WorldsService},
+};
+use rocket::http::Status;
+
+/// Look up a character and ensure that the current user is allowed to see it.
+///
+/// Published characters are visible to anyone that can see the world they belong to, and characters
+/// that aren't yet published only to the editors of the world. They are always visible to the user that
+/// controls them, even if the character or the world isn't yet published.
+///
+/// # Parameters
+/// - `characters_service` - The characters service to use
+/// - `worlds_service` - The worlds service to use
+/// - `authorizer` - The authorizer to identify the current user
+/// - `id` - The ID of the character
+///
+/// # Returns
+/// The character and the world it belongs to, if it exists and the current user can see it
+///
+/// # Errors
+/// A Problem if the character doesn't exist or the current user can't see it
+pub(super) fn authorize_character_viewer(
+    characters_service: &CharactersService,
+    worlds_service: &WorldsService,
+    authorizer: &Authorizer,
+    id: &CharacterID,
+) -> Result<(CharacterModel, WorldModel), Problem> {
+    let unknown_character =
+        || Problem::new(CharacterProblemType::UnknownCharacter, Status::NotFound);
+
+    let character = characters_service
+        .find_character_by_id(id)
+        .ok_or_else(unknown_character)?;
+
+    let viewer = authorizer.authorize().finish()?;
+    let controller = viewer.is_some() && viewer == character.data.controlled_by;
+    let world = if controller {
+        worlds_service.find_world_by_id(character.data.world.clone())
+    } else {
+        authorize_world_viewer(worlds_service, authorizer, &character.data.world).ok()
+    }
+    .ok_or_else(unknown_character)?;
+
+    if !controller
+        && !character.data.publication.is_visible()
+        && !worlds_service.is_editor(&world, viewer.as_ref())
+    {
+        return Err(unknown_character());
+    }
+
+    Ok((character, world))
+}
+
+/// Ensure that the current user is allowed to change a character.
+///
+/// This is allowed for the owner of the world, for members of the world with the Editor role, and for the
+/// user that controls the character.
+///
+/// # Parameters
+/// - `worlds_service` - The worlds service to use
+/// - `authorizer` - The authorizer to identify the current user
+/// - `character` - The character to change
+/// - `world` - The world that the character belongs to
+///
+/// # Errors
+/// A Problem if the current user isn't allowed to change the character
+pub(super) fn authorize_character_controller(
+    worlds_service: &WorldsService,
+    authorizer: &Authorizer,
+    character: &CharacterModel,
+    world: &WorldModel,
+) -> Result<(), Problem> {
+    authorizer
+        .authorize()
+        .user_matching(|user| {
+            character.data.controlled_by.as_ref() == Some(user)
+                || worlds_service.is_editor(world, Some(user))
+        })
+        .finish()?;
+
+    Ok(())
+}
+
+/// Ensure that the current user is an editor of the world that a character belongs to, and so has full
+/// control over the character including who controls it
+///
+/// # Parameters
+/// - `worlds_service` - The worlds service to use
+/// - `authorizer` - The authorizer to identify the current user
+/// - `world` - The world that the character belongs to
+///
+/// # Errors
+/// A Problem if the current user isn't an editor of the world
+pub(super) fn authorize_character_owner(
+    worlds_service: &WorldsService,
+    authorizer: &Authorizer,
+    world: &WorldModel,
+) -> Result<(), Problem> {
+    authorizer
+        .authorize()
+        .user_matching(|user| worlds_service.is_editor(world, Some(user)))
+        .finish()?;
+
+    Ok(())
+}
@@ -0,0 +1,69 @@
+use super::{
+    character_controller::authorize_character_owner,
+    errors::{unknown_controller, CharacterProblemType},
+    model::{CharacterRequest, CharacterResponse},
+};
+use crate::{
+    authorization::Authorizer,
+    characters::{CharactersService, CreateCharacterError},
+    http::problem::Problem,
+    worlds::{
+        endpoints::{errors::WorldProblemType, world_owner::authorize_world_viewer},
+        WorldID, WorldsService,
+    },
+};
+use rocket::{http::Status, post, State};
+use rocket_contrib::json::Json;
+
+/// Handler to create a new Character in a World
+///
+/// # Parameters
+/// - `characters_service` - The characters service to use
+/// - `worlds_service` - The worlds service to use
+/// - `world` - The ID of the world to create the character in
+/// - `body` - The details of the character to create
+/// - `authorizer` - The authorizer to prove we're allowed to create the character. Only editors of the
+///   world can do this
+///
+/// # Returns
+/// The newly created character details, or a Problem if the creation failed
+#[tracing::instrument(
+    name = "POST /worlds/{id}/characters",
+    skip(characters_service, worlds_service)
+)]
+#[post("/worlds/<world>/characters", data = "<body>")]
+pub fn create_character(
+    characters_service: State<CharactersService>,
+    worlds_service: State<WorldsService>,
+    world: WorldID,
+    body: Json<CharacterRequest>,
+    authorizer: Authorizer,
+) -> Result<CharacterResponse, Problem> {
+    let world_model = authorize_world_viewer(&worlds_service, &authorizer, &world)?;
+    authorize_character_owner(&worlds_service, &authorizer, &world_model)?;
+
+    let character = body.build(world)?;
+    tracing::debug!(character = ?character, "Creating new character");
+
+    let new_character = characters_service.create_character(character)?;
+    Ok(CharacterResponse(new_character))
+}
+
+impl From<CreateCharacterError> for Problem {
+    fn from(e: CreateCharacterError) -> Self {
+        match e {
+            CreateCharacterError::UnknownWorld => {
+                Problem::new(WorldProblemType::UnknownWorldID, Status::NotFound)
+            }
+            CreateCharacterError::UnknownUser => unknown_controller(),
+            CreateCharacterError::QuotaExceeded(e) => e.into(),
+            _ => {
+                tracing::warn!(error = ?e, "An unexpected error occurred");
+                Problem::new(
+                    CharacterProblemType::UnknownError,
+                    Status::InternalServerError,
+                )
+            }
+        }
+    }
+}
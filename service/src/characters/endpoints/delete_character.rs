@@ -0,0 +1,46 @@
+use super::{
+    character_controller::{authorize_character_owner, authorize_character_viewer},
+    errors::CharacterProblemType,
+};
+use crate::{
+    authorization::Authorizer,
+    characters::{CharacterID, CharactersService},
+    http::problem::Problem,
+    worlds::WorldsService,
+};
+use rocket::{delete, http::Status, Response, State};
+
+/// Handler to delete an existing Character. Only the editors of the world can do this
+///
+/// # Parameters
+/// - `characters_service` - The characters service to use
+/// - `worlds_service` - The worlds service to use
+/// - `id` - The ID of the character to delete
+/// - `authorizer` - The authorizer to prove we're allowed to delete the character
+///
+/// # Returns
+/// An empty response if the character was deleted, or a Problem if the deletion failed
+#[tracing::instrument(
+    name = "DELETE /characters/{id}",
+    skip(characters_service, worlds_service)
+)]
+#[delete("/characters/<id>")]
+pub fn delete_character(
+    characters_service: State<CharactersService>,
+    worlds_service: State<WorldsService>,
+    id: CharacterID,
+    authorizer: Authorizer,
+) -> Result<Response<'static>, Problem> {
+    let (_, world) =
+        authorize_character_viewer(&characters_service, &worlds_service, &authorizer, &id)?;
+    authorize_character_owner(&worlds_service, &authorizer, &world)?;
+
+    if characters_service.delete_character(&id) {
+        Ok(Response::build().status(Status::NoContent).finalize())
+    } else {
+        Err(Problem::new(
+            CharacterProblemType::UnknownCharacter,
+            Status::NotFound,
+        ))
+    }
+}
@@ -0,0 +1,58 @@
+use crate::http::problem::{Problem, ProblemType, ValidationProblem, ValidationType};
+
+/// Problem Types that can happen when working with characters
+#[derive(Debug, thiserror::Error)]
+pub enum CharacterProblemType {
+    /// An unknown error occurred
+    #[error("An unknown error occurred")]
+    UnknownError,
+
+    /// The character that was looked up was not found
+    #[error("The requested character was unknown")]
+    UnknownCharacter,
+}
+
+impl ProblemType for CharacterProblemType {
+    /// Generate a Type value for the `ProblemType` values.
+    ///
+    /// These are used in the `type` field in the RFC-7807 Problem Response
+    fn error_code(&self) -> &'static str {
+        match self {
+            CharacterProblemType::UnknownError => {
+                "tag:multiverse,2020:characters/problems/unknown_error"
+            }
+            CharacterProblemType::UnknownCharacter => {
+                "tag:multiverse,2020:characters/problems/unknown_character"
+            }
+        }
+    }
+}
+
+/// Validation errors that are specific to characters
+#[derive(Debug, thiserror::Error)]
+pub enum CharacterValidation {
+    /// The user to control the character was not a known user
+    #[error("The user was unknown")]
+    UnknownUser,
+}
+
+impl ValidationType for CharacterValidation {
+    /// Generate a Type value for the `ValidationType` values.
+    fn error_code(&self) -> &'static str {
+        match self {
+            CharacterValidation::UnknownUser => {
+                "tag:multiverse,2020:characters/problems/validation_error/unknown_user"
+            }
+        }
+    }
+}
+
+/// Build the Problem to return when the user to control a character was not a known user
+///
+/// # Returns
+/// The Problem
+pub(super) fn unknown_controller() -> Problem {
+    let mut problem = ValidationProblem::new();
+    problem.with_field_error("controlled_by", CharacterValidation::UnknownUser);
+    problem.build()
+}
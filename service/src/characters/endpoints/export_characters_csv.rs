@@ -0,0 +1,58 @@
+use super::{model::CharacterCsvRecord, search_characters::build_filters};
+use crate::{
+    authorization::Authorizer,
+    characters::{CharacterSortField, CharactersService},
+    http::{
+        csv::{write_csv, CsvResponse},
+        problem::Problem,
+        sorts::SortFieldsRequest,
+    },
+    model::fetch_all_pages,
+    users::endpoints::model::UserLink,
+    worlds::{WorldID, WorldsService},
+};
+use rocket::{get, State};
+
+/// Handler to download every Character in a World that matches a search as a CSV file.
+///
+/// This takes the same filters and sorts as `GET /worlds/{id}/characters`, but returns every matching
+/// character instead of a single page of them
+///
+/// # Parameters
+/// - `characters_service` - The characters service to use
+/// - `worlds_service` - The worlds service to use
+/// - `world` - The ID of the world to search the characters of
+/// - `controlled_by` - The user controlling the characters to filter against
+/// - `sort` - The sort ordering to apply
+/// - `authorizer` - The authorizer to identify the current user
+///
+/// # Returns
+/// The CSV file of the characters that matched the search
+#[tracing::instrument(
+    name = "GET /worlds/{id}/characters.csv",
+    skip(characters_service, worlds_service)
+)]
+#[get("/worlds/<world>/characters.csv?<controlled_by>&<sort>")]
+pub fn export_characters_csv(
+    characters_service: State<CharactersService>,
+    worlds_service: State<WorldsService>,
+    world: WorldID,
+    controlled_by: Option<UserLink>,
+    sort: SortFieldsRequest<CharacterSortField>,
+    authorizer: Authorizer,
+) -> Result<CsvResponse, Problem> {
+    let (world, filters) = build_filters(&worlds_service, &authorizer, &world, controlled_by)?;
+
+    let records: Vec<CharacterCsvRecord> = fetch_all_pages(|pagination| {
+        characters_service.search_characters(&filters, &sort, pagination)
+    })
+    .iter()
+    .map(CharacterCsvRecord::from)
+    .collect();
+    tracing::debug!(characters = records.len(), "Exporting characters");
+
+    Ok(CsvResponse {
+        filename: format!("{}-characters.csv", world.data.url_slug),
+        body: write_csv(&records),
+    })
+}
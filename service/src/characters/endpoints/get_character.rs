@@ -0,0 +1,34 @@
+use super::{character_controller::authorize_character_viewer, model::CharacterResponse};
+use crate::{
+    authorization::Authorizer,
+    characters::{CharacterID, CharactersService},
+    http::problem::Problem,
+    worlds::WorldsService,
+};
+use rocket::{get, State};
+
+/// Handler to get an existing Character by ID
+///
+/// # Parameters
+/// - `characters_service` - The characters service to use
+/// - `worlds_service` - The worlds service to use
+/// - `id` - The ID of the character
+/// - `authorizer` - The authorizer to identify the current user
+///
+/// # Returns
+/// The details of the character
+#[tracing::instrument(
+    name = "GET /characters/{id}",
+    skip(characters_service, worlds_service)
+)]
+#[get("/characters/<id>")]
+pub fn get_character(
+    characters_service: State<CharactersService>,
+    worlds_service: State<WorldsService>,
+    id: CharacterID,
+    authorizer: Authorizer,
+) -> Result<CharacterResponse, Problem> {
+    tracing::debug!("Looking up character");
+    authorize_character_viewer(&characters_service, &worlds_service, &authorizer, &id)
+        .map(|(character, _)| CharacterResponse(character))
+}
@@ -0,0 +1,101 @@
+use super::{
+    errors::unknown_controller,
+    model::{CharacterCsvRecord, CharacterLink, CharacterRequest},
+};
+use crate::{
+    authorization::Authorizer,
+    characters::{CharacterData, CharactersService},
+    http::{
+        csv::{invalid_rows_problem, read_csv, CsvImportResponse, CsvRowReport, CsvRowStatus},
+        problem::Problem,
+    },
+    users::UsersService,
+    worlds::{endpoints::world_owner::authorize_world_editor, WorldID, WorldsService},
+};
+use rocket::{post, Data, State};
+
+/// Handler to create Characters in a World from the rows of a CSV file.
+///
+/// Every row is validated in the same way as creating a single character, and nothing is created unless
+/// every row is valid. The characters are all created together, so if any of them can't be, including
+/// because they would take the owner of the world over their quota, then none of them are. In a dry run
+/// nothing is created at all, and the validation of every row is reported back instead
+///
+/// # Parameters
+/// - `characters_service` - The characters service to use
+/// - `worlds_service` - The worlds service to use
+/// - `users_service` - The users service to check that the users controlling the characters exist
+/// - `world` - The ID of the world to create the characters in
+/// - `dry_run` - Whether to only validate the file, without creating anything
+/// - `body` - The CSV file, in the same format as is downloaded from `GET /worlds/{id}/characters.csv`
+/// - `authorizer` - The authorizer to prove we're allowed to create characters
+///
+/// # Returns
+/// The report of every row in the file, or a Problem if the import failed
+#[tracing::instrument(
+    name = "POST /worlds/{id}/characters.csv",
+    skip(characters_service, worlds_service, users_service, body)
+)]
+#[post("/worlds/<world>/characters.csv?<dry_run>", data = "<body>")]
+pub fn import_characters_csv(
+    characters_service: State<CharactersService>,
+    worlds_service: State<WorldsService>,
+    users_service: State<UsersService>,
+    world: WorldID,
+    dry_run: Option<bool>,
+    body: Data,
+    authorizer: Authorizer,
+) -> Result<CsvImportResponse, Problem> {
+    authorize_world_editor(&worlds_service, &authorizer, &world)?;
+    let dry_run = dry_run.unwrap_or(false);
+
+    let records: Vec<(u64, CharacterCsvRecord)> = read_csv(body)?;
+
+    let mut characters = vec![];
+    let mut rows = vec![];
+    for (line, record) in records {
+        let character = CharacterRequest::from(record)
+            .build(world.clone())
+            .and_then(|character| match &character.controlled_by {
+                Some(user) if users_service.find_user_by_id(user).is_none() => {
+                    Err(unknown_controller())
+                }
+                _ => Ok(character),
+            });
+
+        match character {
+            Ok(character) => {
+                characters.push((line, character));
+                rows.push(CsvRowReport {
+                    line,
+                    status: CsvRowStatus::Valid,
+                });
+            }
+            Err(mut problem) => rows.push(CsvRowReport {
+                line,
+                status: CsvRowStatus::Invalid(problem.extra.remove("fields").unwrap_or_default()),
+            }),
+        }
+    }
+
+    if dry_run {
+        return Ok(CsvImportResponse { dry_run, rows });
+    }
+    if characters.len() < rows.len() {
+        tracing::warn!("Validation error importing characters");
+        return Err(invalid_rows_problem(&rows));
+    }
+
+    let (lines, characters): (Vec<u64>, Vec<CharacterData>) = characters.into_iter().unzip();
+    let rows = characters_service
+        .create_characters(&world, characters)?
+        .into_iter()
+        .zip(lines)
+        .map(|(new_character, line)| CsvRowReport {
+            line,
+            status: CsvRowStatus::Created(CharacterLink::new(new_character.identity.id).into()),
+        })
+        .collect();
+
+    Ok(CsvImportResponse { dry_run, rows })
+}
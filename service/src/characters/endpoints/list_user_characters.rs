@@ -0,0 +1,44 @@
+use super::model::CharactersResponse;
+use crate::{
+    authorization::Authorizer,
+    characters::CharactersService,
+    http::{pagination::PaginationRequest, problem::Problem},
+    users::{endpoints::errors::UserProblemType, UserID, UsersService},
+};
+use rocket::{get, http::Status, State};
+
+/// Handler to list the characters that a user controls, across every world
+///
+/// # Parameters
+/// - `characters_service` - The characters service to use
+/// - `users_service` - The users service to use
+/// - `id` - The ID of the user
+/// - `pagination` - The pagination controls to apply
+/// - `authorizer` - The authorizer to identify the current user. Characters in worlds that they can't see
+///   are not included
+///
+/// # Returns
+/// The details of the characters that the user controls, ordered by name
+#[tracing::instrument(
+    name = "GET /users/{id}/characters",
+    skip(characters_service, users_service)
+)]
+#[get("/users/<id>/characters?<pagination..>")]
+pub fn list_user_characters(
+    characters_service: State<CharactersService>,
+    users_service: State<UsersService>,
+    id: UserID,
+    pagination: PaginationRequest,
+    authorizer: Authorizer,
+) -> Result<CharactersResponse, Problem> {
+    users_service
+        .find_user_by_id(&id)
+        .ok_or_else(|| Problem::new(UserProblemType::UnknownUserID, Status::NotFound))?;
+
+    let viewer = authorizer.authorize().finish()?;
+    let characters =
+        characters_service.find_controlled_characters(&id, viewer.as_ref(), &pagination);
+    tracing::debug!(characters = ?characters, "Found characters");
+
+    Ok(CharactersResponse(characters))
+}
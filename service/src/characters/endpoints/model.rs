@@ -0,0 +1,11 @@
+mod character_csv;
+mod character_link;
+mod character_request;
+mod character_response;
+mod characters_response;
+
+pub use character_csv::*;
+pub use character_link::*;
+pub use character_request::*;
+pub use character_response::*;
+pub use characters_response::*;
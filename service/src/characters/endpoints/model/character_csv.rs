@@ -0,0 +1,38 @@
+use crate::characters::CharacterModel;
+use chrono::SecondsFormat;
+use serde::{Deserialize, Serialize};
+
+/// API Model representing a single Character as a row of a CSV file
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct CharacterCsvRecord {
+    /// The name of the character
+    pub name: Option<String>,
+    /// The description of the character
+    pub description: Option<String>,
+    /// The ID of the user that controls the character, if any
+    pub controlled_by: Option<String>,
+    /// The publication status of the character
+    pub status: Option<String>,
+    /// When the character is to be published, if it is scheduled
+    pub publish_at: Option<String>,
+}
+
+impl From<&CharacterModel> for CharacterCsvRecord {
+    fn from(character: &CharacterModel) -> Self {
+        Self {
+            name: Some(character.data.name.clone()),
+            description: Some(character.data.description.clone()),
+            controlled_by: character
+                .data
+                .controlled_by
+                .as_ref()
+                .map(ToString::to_string),
+            status: Some(character.data.publication.status().to_string()),
+            publish_at: character
+                .data
+                .publication
+                .publish_at()
+                .map(|publish_at| publish_at.to_rfc3339_opts(SecondsFormat::Secs, true)),
+        }
+    }
+}
@@ -0,0 +1,17 @@
+use crate::characters::CharacterID;
+
+/// Representation of a link to a character
+#[derive(Debug)]
+pub struct CharacterLink(CharacterID);
+
+impl CharacterLink {
+    pub fn new(character_id: CharacterID) -> Self {
+        Self(character_id)
+    }
+}
+
+impl From<CharacterLink> for String {
+    fn from(character_link: CharacterLink) -> String {
+        format!("/characters/{}", character_link.0)
+    }
+}
@@ -0,0 +1,97 @@
+use super::CharacterCsvRecord;
+use crate::{
+    characters::{endpoints::errors::CharacterValidation, CharacterData},
+    http::{
+        problem::{GenericValidation, Problem, ValidationProblem},
+        publication::PublicationRequest,
+    },
+    users::UserID,
+    worlds::WorldID,
+};
+use serde::Deserialize;
+use uuid::Uuid;
+
+/// Incoming details representing a character to create or replace
+#[derive(Debug, Deserialize)]
+pub struct CharacterRequest {
+    /// The name of the character
+    name: Option<String>,
+    /// The description of the character. May be omitted
+    description: Option<String>,
+    /// The user that controls the character, either as a User ID or a link to the user. May be omitted if
+    /// the character isn't controlled by any player
+    controlled_by: Option<String>,
+    /// The publication state of the character. If omitted then the character is published immediately
+    #[serde(flatten)]
+    publication: PublicationRequest,
+}
+
+impl From<CharacterCsvRecord> for CharacterRequest {
+    fn from(record: CharacterCsvRecord) -> Self {
+        Self {
+            name: record.name,
+            description: record.description,
+            controlled_by: record.controlled_by,
+            publication: PublicationRequest::new(record.status, record.publish_at),
+        }
+    }
+}
+
+impl CharacterRequest {
+    /// Build the details of the character from the request, validating that everything is present and correct
+    ///
+    /// # Parameters
+    /// - `world` - The ID of the world that the character belongs to
+    ///
+    /// # Returns
+    /// The details of the character
+    ///
+    /// # Errors
+    /// A Problem describing every validation error in the request
+    pub fn build(&self, world: WorldID) -> Result<CharacterData, Problem> {
+        let mut problem = ValidationProblem::new();
+        let mut valid = true;
+
+        let name = self.name.clone().filter(|v| !v.trim().is_empty());
+        if name == None {
+            problem.with_field_error("name", GenericValidation::Missing);
+            valid = false;
+        }
+
+        let controlled_by = match self.controlled_by.as_ref().filter(|v| !v.trim().is_empty()) {
+            None => None,
+            Some(value) => {
+                let user = Uuid::parse_str(value.trim().trim_start_matches("/users/"))
+                    .ok()
+                    .map(UserID::new);
+                if user.is_none() {
+                    problem.with_field_error("controlled_by", CharacterValidation::UnknownUser);
+                    valid = false;
+                }
+                user
+            }
+        };
+
+        let publication = self.publication.build(&mut problem);
+        if publication.is_none() {
+            valid = false;
+        }
+
+        if !valid {
+            tracing::warn!("Validation error building character");
+            return Err(problem.build());
+        }
+
+        Ok(CharacterData {
+            world,
+            name: name.unwrap(),
+            description: self
+                .description
+                .clone()
+                .filter(|v| !v.trim().is_empty())
+                .unwrap_or_default(),
+            controlled_by,
+            publication: publication.unwrap(),
+        })
+    }
+}
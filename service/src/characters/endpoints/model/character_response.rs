@@ -0,0 +1,63 @@
+use super::CharacterLink;
+use crate::{
+    characters::CharacterModel,
+    http::{
+        link::{Link, LinkRel, Links},
+        publication::add_publication_json,
+    },
+    users::endpoints::model::UserLink,
+    worlds::endpoints::model::WorldLink,
+};
+use rocket::{
+    http::hyper::header::{CacheControl, CacheDirective, ETag, EntityTag},
+    response, Request,
+};
+use rocket_contrib::json::Json;
+use serde_json::{json, Value};
+
+/// Convert a character into the JSON representation of it
+///
+/// # Parameters
+/// - `character` - The character to convert
+///
+/// # Returns
+/// The JSON representation of the character
+pub(super) fn to_json(character: &CharacterModel) -> Value {
+    let controlled_by: Option<String> = character
+        .data
+        .controlled_by
+        .clone()
+        .map(|user| UserLink::new(user).into());
+
+    let mut value = json!({
+      "name": character.data.name,
+      "description": character.data.description,
+      "controlled_by": controlled_by
+    });
+    add_publication_json(&mut value, &character.data.publication);
+
+    value
+}
+
+/// API Model representing a Character
+#[derive(Debug)]
+pub struct CharacterResponse(pub CharacterModel);
+
+impl<'r> response::Responder<'r> for CharacterResponse {
+    fn respond_to(self, req: &Request) -> response::Result<'r> {
+        let etag = self.0.identity.version.to_string();
+
+        response::Response::build()
+            .merge(Json(to_json(&self.0)).respond_to(req).unwrap())
+            .header(CacheControl(vec![
+                CacheDirective::Private,
+                CacheDirective::MaxAge(3600),
+            ]))
+            .header(ETag(EntityTag::new(false, etag)))
+            .header(Links(vec![
+                Link::new(CharacterLink::new(self.0.identity.id), LinkRel::SELF),
+                Link::new(WorldLink::new(self.0.data.world), LinkRel::RELATED),
+            ]))
+            .ok()
+    }
+}
@@ -0,0 +1,51 @@
+use super::{character_response::to_json, CharacterLink};
+use crate::{
+    characters::CharacterModel,
+    http::link::{Link, LinkRel, Links},
+    model::Page,
+};
+use rocket::{
+    http::hyper::header::{CacheControl, CacheDirective},
+    response, Request,
+};
+use rocket_contrib::json::Json;
+use serde_json::{json, Value};
+
+/// API Model representing a page of Characters
+#[derive(Debug)]
+pub struct CharactersResponse(pub Page<CharacterModel>);
+
+impl<'r> response::Responder<'r> for CharactersResponse {
+    fn respond_to(self, req: &Request) -> response::Result<'r> {
+        let characters: Vec<Value> = self.0.entries.iter().map(to_json).collect();
+
+        let response_body = json!({
+          "entries": characters,
+          "pagination": {
+            "offset": self.0.offset,
+            "count": self.0.count,
+            "total": self.0.total
+          }
+        });
+
+        let character_links: Vec<Link> = self
+            .0
+            .entries
+            .iter()
+            .map(|character| CharacterLink::new(character.identity.id.clone()))
+            .enumerate()
+            .map(|(index, link)| {
+                Link::new(link, LinkRel::ITEM).anchor(format!("#/entries/{}", index))
+            })
+            .collect();
+
+        response::Response::build()
+            .merge(Json(response_body).respond_to(req).unwrap())
+            .header(CacheControl(vec![
+                CacheDirective::Private,
+                CacheDirective::MaxAge(3600),
+            ]))
+            .header(Links(character_links))
+            .ok()
+    }
+}
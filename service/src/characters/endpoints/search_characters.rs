@@ -0,0 +1,80 @@
+use super::model::CharactersResponse;
+use crate::{
+    authorization::Authorizer,
+    characters::{CharacterSortField, CharactersFilters, CharactersService},
+    http::{pagination::PaginationRequest, problem::Problem, sorts::SortFieldsRequest},
+    users::endpoints::model::UserLink,
+    worlds::{
+        endpoints::world_owner::{authorize_world_viewer, is_world_editor},
+        WorldID, WorldModel, WorldsService,
+    },
+};
+use rocket::{get, State};
+
+/// Handler to search the Characters in a World
+///
+/// # Parameters
+/// - `characters_service` - The characters service to use
+/// - `worlds_service` - The worlds service to use
+/// - `world` - The ID of the world to search the characters of
+/// - `controlled_by` - The user controlling the characters to filter against
+/// - `sort` - The sort ordering to apply
+/// - `pagination` - The pagination controls to apply
+/// - `authorizer` - The authorizer to identify the current user. Only editors can see unpublished
+///   characters, apart from those that the current user controls
+///
+/// # Returns
+/// The details of the characters that matched the search
+#[tracing::instrument(
+    name = "GET /worlds/{id}/characters",
+    skip(characters_service, worlds_service)
+)]
+#[get("/worlds/<world>/characters?<controlled_by>&<sort>&<pagination..>")]
+pub fn search_characters(
+    characters_service: State<CharactersService>,
+    worlds_service: State<WorldsService>,
+    world: WorldID,
+    controlled_by: Option<UserLink>,
+    sort: SortFieldsRequest<CharacterSortField>,
+    pagination: PaginationRequest,
+    authorizer: Authorizer,
+) -> Result<CharactersResponse, Problem> {
+    let (_, filters) = build_filters(&worlds_service, &authorizer, &world, controlled_by)?;
+
+    let characters = characters_service.search_characters(&filters, &sort, &pagination);
+    tracing::debug!(characters = ?characters, "Found characters");
+
+    Ok(CharactersResponse(characters))
+}
+
+/// Build the filters to search the characters in a world with, making sure that the current user can
+/// see the world
+///
+/// # Parameters
+/// - `worlds_service` - The worlds service to use
+/// - `authorizer` - The authorizer to identify the current user
+/// - `world` - The ID of the world to search the characters of
+/// - `controlled_by` - The user controlling the characters to filter against
+///
+/// # Returns
+/// The world and the filters to search with
+///
+/// # Errors
+/// A Problem if the world can't be seen
+pub(super) fn build_filters(
+    worlds_service: &WorldsService,
+    authorizer: &Authorizer,
+    world: &WorldID,
+    controlled_by: Option<UserLink>,
+) -> Result<(WorldModel, CharactersFilters), Problem> {
+    let world = authorize_world_viewer(worlds_service, authorizer, world)?;
+
+    let filters = CharactersFilters {
+        world: world.identity.id.clone(),
+        controlled_by: controlled_by.map(|link| link.into()),
+        include_unpublished: is_world_editor(worlds_service, authorizer, &world),
+        viewer: authorizer.authorize().finish()?,
+    };
+
+    Ok((world, filters))
+}
@@ -0,0 +1,75 @@
+use super::{
+    character_controller::{
+        authorize_character_controller, authorize_character_owner, authorize_character_viewer,
+    },
+    errors::{unknown_controller, CharacterProblemType},
+    model::{CharacterRequest, CharacterResponse},
+};
+use crate::{
+    authorization::Authorizer,
+    characters::{CharacterID, CharactersService, UpdateCharacterError},
+    http::problem::Problem,
+    worlds::WorldsService,
+};
+use rocket::{http::Status, put, State};
+use rocket_contrib::json::Json;
+
+/// Handler to replace an existing Character
+///
+/// The user that controls the character can change its details, but only the editors of the world can
+/// change who controls it or whether it is published.
+///
+/// # Parameters
+/// - `characters_service` - The characters service to use
+/// - `worlds_service` - The worlds service to use
+/// - `id` - The ID of the character to replace
+/// - `body` - The new details of the character
+/// - `authorizer` - The authorizer to prove we're allowed to change the character
+///
+/// # Returns
+/// The updated character details, or a Problem if the update failed
+#[tracing::instrument(
+    name = "PUT /characters/{id}",
+    skip(characters_service, worlds_service)
+)]
+#[put("/characters/<id>", data = "<body>")]
+pub fn update_character(
+    characters_service: State<CharactersService>,
+    worlds_service: State<WorldsService>,
+    id: CharacterID,
+    body: Json<CharacterRequest>,
+    authorizer: Authorizer,
+) -> Result<CharacterResponse, Problem> {
+    let (existing, world) =
+        authorize_character_viewer(&characters_service, &worlds_service, &authorizer, &id)?;
+    authorize_character_controller(&worlds_service, &authorizer, &existing, &world)?;
+
+    let character = body.build(existing.data.world.clone())?;
+    if character.controlled_by != existing.data.controlled_by
+        || character.publication != existing.data.publication
+    {
+        authorize_character_owner(&worlds_service, &authorizer, &world)?;
+    }
+    tracing::debug!(character = ?character, "Updating character");
+
+    let updated = characters_service.update_character(&id, character)?;
+    Ok(CharacterResponse(updated))
+}
+
+impl From<UpdateCharacterError> for Problem {
+    fn from(e: UpdateCharacterError) -> Self {
+        match e {
+            UpdateCharacterError::UnknownCharacter => {
+                Problem::new(CharacterProblemType::UnknownCharacter, Status::NotFound)
+            }
+            UpdateCharacterError::UnknownUser => unknown_controller(),
+            _ => {
+                tracing::warn!(error = ?e, "An unexpected error occurred");
+                Problem::new(
+                    CharacterProblemType::UnknownError,
+                    Status::InternalServerError,
+                )
+            }
+        }
+    }
+}
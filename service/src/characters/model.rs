@@ -0,0 +1,7 @@
+mod character;
+mod character_id;
+mod sorts;
+
+pub use character::*;
+pub use character_id::*;
+pub use sorts::*;
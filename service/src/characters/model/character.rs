@@ -0,0 +1,29 @@
+use super::CharacterID;
+use crate::{
+    model::{Model, Publication},
+    users::UserID,
+    worlds::WorldID,
+};
+
+/// Data to represent a character in a world
+#[derive(Debug, Clone)]
+pub struct CharacterData {
+    /// The ID of the world that the character belongs to
+    pub world: WorldID,
+
+    /// The name of the character
+    pub name: String,
+
+    /// A description of the character
+    pub description: String,
+
+    /// The ID of the user that controls the character, if any. This user is able to edit the character
+    /// even though they don't own the world
+    pub controlled_by: Option<UserID>,
+
+    /// The publication state of the character
+    pub publication: Publication,
+}
+
+/// Model representation of a character
+pub type CharacterModel = Model<CharacterID, CharacterData>;
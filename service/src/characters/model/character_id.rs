@@ -0,0 +1,67 @@
+use bytes::BytesMut;
+use postgres_types::{accepts, to_sql_checked, FromSql, IsNull, ToSql, Type};
+use rocket::{http::RawStr, request};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use uuid::Uuid;
+
+/// The ID of a Character
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, FromSql)]
+pub struct CharacterID(Uuid);
+
+impl CharacterID {
+    /// Wrap a UUID as a Character ID object
+    ///
+    /// # Parameters
+    /// - `uuid` - The UUID to wrap
+    ///
+    /// # The Character ID
+    pub fn new(uuid: Uuid) -> Self {
+        CharacterID(uuid)
+    }
+}
+
+impl Default for CharacterID {
+    fn default() -> Self {
+        Self(Uuid::new_v4())
+    }
+}
+
+impl ToSql for CharacterID {
+    accepts!(UUID);
+
+    to_sql_checked!();
+
+    fn to_sql(
+        &self,
+        t: &Type,
+        w: &mut BytesMut,
+    ) -> Result<IsNull, Box<dyn std::error::Error + Sync + Send>> {
+        self.0.to_sql(t, w)
+    }
+}
+
+impl<'r> request::FromParam<'r> for CharacterID {
+    type Error = &'r RawStr;
+
+    fn from_param(param: &'r RawStr) -> Result<Self, Self::Error> {
+        param
+            .percent_decode()
+            .map(|cow| cow.into_owned())
+            .map_err(|_| param)
+            .and_then(|character_id| Uuid::parse_str(&character_id).map_err(|_| param))
+            .map(|character_id| CharacterID::new(character_id))
+    }
+}
+
+impl Display for CharacterID {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<CharacterID> for Uuid {
+    fn from(character_id: CharacterID) -> Self {
+        character_id.0
+    }
+}
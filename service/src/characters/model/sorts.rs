@@ -0,0 +1,10 @@
+/// Fields by which the results of searching for characters can be sorted
+#[derive(Debug, strum_macros::EnumVariantNames, strum_macros::EnumString)]
+pub enum CharacterSortField {
+    /// Sort by the name of the character
+    Name,
+    /// Sort by when the character was created
+    Created,
+    /// Sort by when the character was last updated
+    Updated,
+}
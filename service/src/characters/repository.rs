@@ -0,0 +1,29 @@
+mod create;
+mod delete;
+mod errors;
+mod find;
+mod parse;
+mod update;
+
+use crate::infrastructure::database::Database;
+pub use errors::*;
+
+/// Repository used to access Character data from the database
+#[derive(Clone)]
+pub(super) struct CharacterRepository {
+    /// The database with which to access character records
+    database: Database,
+}
+
+impl CharacterRepository {
+    /// Create a new Character Repository to work with
+    ///
+    /// # Parameters
+    /// - `database` - The database connection to use
+    ///
+    /// # Returns
+    /// The Character Repository to use
+    pub(super) fn new(database: Database) -> Self {
+        Self { database }
+    }
+}
@@ -0,0 +1,99 @@
+use super::{CharacterRepository, SaveCharacterError};
+use crate::{
+    characters::model::*,
+    events::{publish_change, EntityType, WorldChange},
+    quotas::{enforce_quota, QuotaAllowance},
+};
+use chrono::Utc;
+use uuid::Uuid;
+
+impl CharacterRepository {
+    /// Create a new character in the database, enforcing the quota of characters that the owner of the
+    /// world has in the same transaction
+    ///
+    /// # Parameters
+    /// - `character` - The character to create
+    /// - `quota` - The allowance for the owner of the world to create another character, if the world
+    ///   exists
+    ///
+    /// # Returns
+    /// The newly created character
+    ///
+    /// # Errors
+    /// Any errors that occurred creating the new character
+    pub fn create(
+        &self,
+        character: CharacterData,
+        quota: Option<&QuotaAllowance>,
+    ) -> Result<CharacterModel, SaveCharacterError> {
+        self.create_many(vec![character], quota)
+            .map(|mut characters| characters.remove(0))
+    }
+
+    /// Create several new characters in the database in a single transaction, so that either all of them
+    /// are created or none are. The quota of characters that the owner of the world has is enforced for
+    /// all of them together in the same transaction
+    ///
+    /// # Parameters
+    /// - `characters` - The characters to create
+    /// - `quota` - The allowance for the owner of the world to create this many characters, if the world
+    ///   exists
+    ///
+    /// # Returns
+    /// The newly created characters, in the same order
+    ///
+    /// # Errors
+    /// Any errors that occurred creating the new characters
+    #[tracing::instrument(skip(self))]
+    pub fn create_many(
+        &self,
+        characters: Vec<CharacterData>,
+        quota: Option<&QuotaAllowance>,
+    ) -> Result<Vec<CharacterModel>, SaveCharacterError> {
+        let now = Utc::now();
+
+        let mut connection = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+        let mut transaction = connection.transaction()?;
+
+        if let Some(quota) = quota {
+            enforce_quota(&mut transaction, quota)?;
+        }
+
+        let mut new_characters = vec![];
+        for character in characters {
+            let new_character = transaction.query_one("INSERT INTO characters(character_id, version, created, updated, world_id, name, description, controlled_by, status, publish_at) VALUES($1, $2, $3, $3, $4, $5, $6, $7, $8, $9) RETURNING *",
+                &[
+                    &CharacterID::default(),
+                    &Uuid::new_v4(),
+                    &now,
+                    &character.world,
+                    &character.name,
+                    &character.description,
+                    &character.controlled_by,
+                    &character.publication.stored_status().to_string(),
+                    &character.publication.publish_at(),
+                ])
+                .map(|row| self.parse_row(&row))?;
+            new_characters.push(new_character);
+        }
+
+        transaction.commit()?;
+
+        for new_character in &new_characters {
+            publish_change(
+                &mut connection,
+                WorldChange {
+                    world: new_character.data.world.clone(),
+                    entity_type: EntityType::Character,
+                    entity_id: new_character.identity.id.clone().into(),
+                    version: Some(new_character.identity.version),
+                },
+            );
+        }
+
+        Ok(new_characters)
+    }
+}
@@ -0,0 +1,44 @@
+use super::CharacterRepository;
+use crate::{
+    characters::{CharacterID, CharacterModel},
+    events::{publish_change, EntityType, WorldChange},
+};
+
+impl CharacterRepository {
+    /// Delete the character with the given ID
+    ///
+    /// # Parameters
+    /// - `id` - The ID of the character to delete
+    ///
+    /// # Returns
+    /// The character that was deleted, or `None` if it didn't exist
+    #[tracing::instrument(skip(self))]
+    pub fn delete(&self, id: &CharacterID) -> Option<CharacterModel> {
+        let mut connection = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+
+        let deleted = connection
+            .query_opt(
+                "DELETE FROM characters WHERE character_id = $1 RETURNING *",
+                &[id],
+            )
+            .expect("Failed to delete character")
+            .map(|row| self.parse_row(&row));
+
+        if let Some(character) = &deleted {
+            publish_change(
+                &mut connection,
+                WorldChange {
+                    world: character.data.world.clone(),
+                    entity_type: EntityType::Character,
+                    entity_id: character.identity.id.clone().into(),
+                    version: None,
+                },
+            );
+        }
+
+        deleted
+    }
+}
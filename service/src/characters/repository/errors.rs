@@ -0,0 +1,62 @@
+use crate::quotas::QuotaExceededError;
+use postgres::error::{DbError, SqlState};
+
+/// Errors that can occur when saving a character record
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum SaveCharacterError {
+    /// An unknown error occurred
+    #[error("An unknown error occurred")]
+    UnknownError,
+
+    /// The world that the character belongs to does not exist
+    #[error("The desired world does not exist")]
+    UnknownWorld,
+
+    /// The user that controls the character does not exist
+    #[error("The desired user does not exist")]
+    UnknownUser,
+
+    /// The character being updated does not exist
+    #[error("The desired character does not exist")]
+    UnknownCharacter,
+
+    /// The owner of the world has reached their quota of characters
+    #[error("The owner of the world has reached their quota of characters")]
+    QuotaExceeded(#[from] QuotaExceededError),
+}
+
+impl From<postgres::Error> for SaveCharacterError {
+    /// Convert a database error into a SaveCharacterError.
+    ///
+    /// The conversion returns an `UnknownWorld` iff the error is a `FOREIGN_KEY_VIOLATION` and the constraint
+    /// is `characters_world_id_fkey`, and an `UnknownUser` iff the error is a `FOREIGN_KEY_VIOLATION` and the
+    /// constraint is `characters_controlled_by_fkey`. Otherwise it returns an `UnknownError`
+    ///
+    /// # Parameters
+    /// - `e` - The error to convert
+    ///
+    /// # Returns
+    /// The new error code
+    fn from(e: postgres::Error) -> Self {
+        if e.code() == Some(&SqlState::FOREIGN_KEY_VIOLATION) {
+            let db_error: Option<DbError> = e
+                .into_source()
+                .and_then(|e| e.downcast_ref::<DbError>().cloned());
+
+            match db_error.as_ref().and_then(|e| e.constraint()) {
+                Some("characters_world_id_fkey") => SaveCharacterError::UnknownWorld,
+                Some("characters_controlled_by_fkey") => SaveCharacterError::UnknownUser,
+                constraint => {
+                    tracing::warn!(
+                        "Unexpected foreign key constraint violation error: {:?}",
+                        constraint
+                    );
+                    SaveCharacterError::UnknownError
+                }
+            }
+        } else {
+            tracing::warn!("Unexpected database error: {:?}", e);
+            SaveCharacterError::UnknownError
+        }
+    }
+}
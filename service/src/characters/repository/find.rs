@@ -0,0 +1,275 @@
+use super::CharacterRepository;
+use crate::{
+    characters::{CharacterID, CharacterModel, CharacterSortField, CharactersFilters},
+    model::{published_sql, Page, Pagination, SortDirection, SortFields},
+    users::UserID,
+    worlds::{editable_world_sql, visible_world_sql, WorldID},
+};
+
+impl CharacterRepository {
+    /// Find a character in the database by its unique ID
+    ///
+    /// # Parameters
+    /// - `id` - The ID of the character
+    ///
+    /// # Returns
+    /// The character, if it exists
+    #[tracing::instrument(skip(self))]
+    pub fn find_character_by_id(&self, id: &CharacterID) -> Option<CharacterModel> {
+        let mut connection = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+        connection
+            .query_opt("SELECT * FROM characters WHERE character_id = $1", &[id])
+            .expect("Failed to query for character by ID")
+            .map(|row| self.parse_row(&row))
+    }
+
+    /// List the characters that belong to a world, ordered by name
+    ///
+    /// # Parameters
+    /// - `world` - The ID of the world to list the characters for
+    /// - `include_unpublished` - Whether to include characters that aren't yet published
+    /// - `pagination` - The pagination details for which page of results are wanted
+    ///
+    /// # Returns
+    /// The requested page of results
+    #[tracing::instrument(skip(self))]
+    pub fn list_characters(
+        &self,
+        world: &WorldID,
+        include_unpublished: bool,
+        pagination: &Pagination,
+    ) -> Page<CharacterModel> {
+        let mut connection = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+
+        let where_clause = if include_unpublished {
+            "WHERE world_id = $1".to_owned()
+        } else {
+            format!("WHERE world_id = $1 AND {}", published_sql("characters"))
+        };
+
+        let select_query = format!(
+            "SELECT * FROM characters {} ORDER BY name ASC, character_id ASC OFFSET {} LIMIT {}",
+            where_clause, pagination.offset, pagination.count
+        );
+        let records: Vec<CharacterModel> = connection
+            .query(select_query.as_str(), &[world])
+            .map(|rows| rows.iter().map(|row| self.parse_row(row)).collect())
+            .expect("Failed to select characters for world");
+
+        let num_records = records.len() as u64;
+        let total = if records.is_empty() || num_records == pagination.count {
+            // If we got zero records back, or exactly the number we asked for, then we don't know
+            // how many there are in the database
+            let count_query = format!(
+                "SELECT COUNT(*)::INTEGER AS c FROM characters {}",
+                where_clause
+            );
+            let count: i32 = connection
+                .query_one(count_query.as_str(), &[world])
+                .map(|row| row.get("c"))
+                .expect("Failed to count characters for world");
+            count as u64
+        } else {
+            pagination.offset + num_records
+        };
+
+        Page {
+            entries: records,
+            offset: pagination.offset,
+            count: pagination.count,
+            total,
+        }
+    }
+
+    /// Search the characters in a world.
+    ///
+    /// Characters that aren't published are only included if the filters allow it, or if the viewer is
+    /// the user that controls them
+    ///
+    /// # Parameters
+    /// - `filters` - The filters to apply
+    /// - `sorts` - The sorts to apply
+    /// - `pagination` - The pagination details for which page of results are wanted
+    ///
+    /// # Returns
+    /// The requested page of results
+    #[tracing::instrument(skip(self))]
+    pub fn search_characters(
+        &self,
+        filters: &CharactersFilters,
+        sorts: &SortFields<CharacterSortField>,
+        pagination: &Pagination,
+    ) -> Page<CharacterModel> {
+        // Collect together the Where clauses and Binds
+        let mut binds: Vec<&(dyn postgres::types::ToSql + Sync)> = vec![&filters.world];
+        let mut where_clauses: Vec<String> = vec!["characters.world_id = $1".to_owned()];
+
+        if let Some(controlled_by) = &filters.controlled_by {
+            binds.push(controlled_by);
+            where_clauses.push(format!("characters.controlled_by = ${}", binds.len()));
+        }
+        if !filters.include_unpublished {
+            match &filters.viewer {
+                Some(viewer) => {
+                    binds.push(viewer);
+                    where_clauses.push(format!(
+                        "({} OR characters.controlled_by = ${})",
+                        published_sql("characters"),
+                        binds.len()
+                    ));
+                }
+                None => where_clauses.push(published_sql("characters")),
+            }
+        }
+        let where_clause = format!("WHERE {}", where_clauses.join(" AND "));
+
+        // Collect together the Sort clauses
+        let mut sort_clauses: Vec<String> = sorts
+            .iter()
+            .map(|sort| {
+                let sort_field = match &sort.field {
+                    CharacterSortField::Name => "characters.name",
+                    CharacterSortField::Created => "characters.created",
+                    CharacterSortField::Updated => "characters.updated",
+                };
+                let sort_direction = match (&sort.field, &sort.direction) {
+                    (_, SortDirection::Ascending) => "ASC",
+                    (_, SortDirection::Descending) => "DESC",
+                    (CharacterSortField::Created, SortDirection::Default) => "DESC",
+                    (CharacterSortField::Updated, SortDirection::Default) => "DESC",
+                    (_, SortDirection::Default) => "ASC",
+                };
+
+                format!("{} {}", sort_field, sort_direction)
+            })
+            .collect();
+        sort_clauses.push("characters.name ASC".to_owned());
+        sort_clauses.push("characters.character_id ASC".to_owned());
+        let sort_clause = format!("ORDER BY {}", sort_clauses.join(", "));
+
+        let mut connection = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+
+        // Build and run the actual SELECT to get the matching records
+        let select_query = format!(
+            "SELECT characters.* FROM characters {} {} OFFSET {} LIMIT {}",
+            where_clause, sort_clause, pagination.offset, pagination.count
+        );
+        let records: Vec<CharacterModel> = connection
+            .query(select_query.as_str(), &binds[..])
+            .map(|rows| rows.iter().map(|row| self.parse_row(row)).collect())
+            .expect("Failed to select characters matching query");
+
+        let num_records = records.len() as u64;
+        let total = if records.is_empty() || num_records == pagination.count {
+            // If we got zero records back, or exactly the number we asked for, then we don't know
+            // how many there are in the database
+            let count_query = format!(
+                "SELECT COUNT(*)::INTEGER AS c FROM characters {}",
+                where_clause
+            );
+            let count: i32 = connection
+                .query_one(count_query.as_str(), &binds[..])
+                .map(|row| row.get("c"))
+                .expect("Failed to count characters matching query");
+            count as u64
+        } else {
+            pagination.offset + num_records
+        };
+
+        Page {
+            entries: records,
+            offset: pagination.offset,
+            count: pagination.count,
+            total,
+        }
+    }
+
+    /// Find the characters that a user controls, across every world, ordered by name.
+    ///
+    /// Characters are only included if they are published and the viewer is able to see the world that
+    /// they belong to, if the viewer is an editor of that world, or if the viewer is the user that controls
+    /// them.
+    ///
+    /// # Parameters
+    /// - `user` - The ID of the user that controls the characters
+    /// - `viewer` - The ID of the user doing the viewing, if there is one
+    /// - `pagination` - The pagination details for which page of results are wanted
+    ///
+    /// # Returns
+    /// The requested page of results
+    #[tracing::instrument(skip(self))]
+    pub fn find_controlled_characters(
+        &self,
+        user: &UserID,
+        viewer: Option<&UserID>,
+        pagination: &Pagination,
+    ) -> Page<CharacterModel> {
+        let mut binds: Vec<&(dyn postgres::types::ToSql + Sync)> = vec![user];
+
+        let visible_clause = match viewer {
+            None => format!(
+                "{} AND {}",
+                published_sql("characters"),
+                visible_world_sql("worlds", None)
+            ),
+            Some(viewer) => {
+                binds.push(viewer);
+                format!(
+                    "(({} AND {}) OR {} OR characters.controlled_by = ${})",
+                    published_sql("characters"),
+                    visible_world_sql("worlds", Some(binds.len())),
+                    editable_world_sql("worlds", binds.len()),
+                    binds.len()
+                )
+            }
+        };
+        let from_clause = format!(
+            "FROM characters JOIN worlds ON worlds.world_id = characters.world_id WHERE characters.controlled_by = $1 AND {}",
+            visible_clause
+        );
+
+        let mut connection = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+
+        let select_query = format!(
+            "SELECT characters.* {} ORDER BY characters.name ASC, characters.character_id ASC OFFSET {} LIMIT {}",
+            from_clause, pagination.offset, pagination.count
+        );
+        let records: Vec<CharacterModel> = connection
+            .query(select_query.as_str(), &binds[..])
+            .map(|rows| rows.iter().map(|row| self.parse_row(row)).collect())
+            .expect("Failed to select characters");
+
+        let num_records = records.len() as u64;
+        let total = if records.is_empty() || num_records == pagination.count {
+            // If we got zero records back, or exactly the number we asked for, then we don't know
+            // how many there are in the database
+            let count_query = format!("SELECT COUNT(*)::INTEGER AS c {}", from_clause);
+            let count: i32 = connection
+                .query_one(count_query.as_str(), &binds[..])
+                .map(|row| row.get("c"))
+                .expect("Failed to count characters");
+            count as u64
+        } else {
+            pagination.offset + num_records
+        };
+
+        Page {
+            entries: records,
+            offset: pagination.offset,
+            count: pagination.count,
+            total,
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use super::CharacterRepository;
+use crate::{
+    characters::model::*,
+    model::{Identity, Publication},
+};
+use postgres::row::Row;
+
+impl CharacterRepository {
+    /// Parse a row from the database into a Character Model
+    ///
+    /// # Parameters
+    /// - `row` - The row to parse
+    ///
+    /// # Returns
+    /// The character that the row represented
+    pub(super) fn parse_row(&self, row: &Row) -> CharacterModel {
+        CharacterModel {
+            identity: Identity {
+                id: row.get("character_id"),
+                version: row.get("version"),
+                created: row.get("created"),
+                updated: row.get("updated"),
+            },
+            data: CharacterData {
+                world: row.get("world_id"),
+                name: row.get("name"),
+                description: row.get("description"),
+                controlled_by: row.get("controlled_by"),
+                publication: Publication::from_stored(row.get("status"), row.get("publish_at")),
+            },
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use super::{CharacterRepository, SaveCharacterError};
+use crate::{
+    characters::model::*,
+    events::{publish_change, EntityType, WorldChange},
+};
+use chrono::Utc;
+use uuid::Uuid;
+
+impl CharacterRepository {
+    /// Update the given character in the database
+    ///
+    /// # Parameters
+    /// - `id` - The ID of the character to update
+    /// - `character` - The details to update in the database
+    ///
+    /// # Returns
+    /// The updated character
+    ///
+    /// # Errors
+    /// Any errors that occurred updating the character
+    #[tracing::instrument(skip(self))]
+    pub fn update(
+        &self,
+        id: &CharacterID,
+        character: CharacterData,
+    ) -> Result<CharacterModel, SaveCharacterError> {
+        let now = Utc::now();
+        let version = Uuid::new_v4();
+
+        let mut connection = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+
+        let updated_character = connection.query_opt("UPDATE characters SET version = $2, updated = $3, name = $4, description = $5, controlled_by = $6, status = $7, publish_at = $8 WHERE character_id = $1 RETURNING *",
+            &[
+                &id,
+                &version,
+                &now,
+                &character.name,
+                &character.description,
+                &character.controlled_by,
+                &character.publication.stored_status().to_string(),
+                &character.publication.publish_at(),
+            ])?
+            .map(|row| self.parse_row(&row))
+            .ok_or(SaveCharacterError::UnknownCharacter)?;
+
+        publish_change(
+            &mut connection,
+            WorldChange {
+                world: updated_character.data.world.clone(),
+                entity_type: EntityType::Character,
+                entity_id: updated_character.identity.id.clone().into(),
+                version: Some(updated_character.identity.version),
+            },
+        );
+
+        Ok(updated_character)
+    }
+}
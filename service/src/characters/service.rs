@@ -0,0 +1,39 @@
+mod create;
+mod delete;
+mod find;
+mod update;
+
+use super::repository::CharacterRepository;
+use crate::{activity::ActivityService, quotas::QuotasService, webhooks::WebhooksService};
+pub use create::CreateCharacterError;
+pub use find::CharactersFilters;
+pub use update::UpdateCharacterError;
+
+/// Service Layer for dealing with Characters
+#[derive(Clone)]
+pub struct CharactersService {
+    repository: CharacterRepository,
+    activity: ActivityService,
+    webhooks: WebhooksService,
+    quotas: QuotasService,
+}
+
+impl CharactersService {
+    /// Create a new instance of the Characters Service
+    ///
+    /// # Returns
+    /// The Characters Service ready to use
+    pub(super) fn new(
+        repository: CharacterRepository,
+        activity: ActivityService,
+        webhooks: WebhooksService,
+        quotas: QuotasService,
+    ) -> Self {
+        Self {
+            repository,
+            activity,
+            webhooks,
+            quotas,
+        }
+    }
+}
@@ -0,0 +1,116 @@
+use crate::{
+    activity::{ActivityData, ActivityEvent},
+    characters::{endpoints::model::CharacterLink, repository::SaveCharacterError, *},
+    quotas::{QuotaExceededError, QuotaResource},
+    webhooks::{WebhookEvent, WebhookEventKind},
+    worlds::WorldID,
+};
+
+/// Errors that can occur when creating a new character
+#[derive(Debug, thiserror::Error)]
+pub enum CreateCharacterError {
+    /// An unknown error occurred
+    #[error("An unknown error occurred")]
+    UnknownError,
+
+    #[error("The desired world does not exist")]
+    UnknownWorld,
+
+    #[error("The user to control the character does not exist")]
+    UnknownUser,
+
+    #[error("The owner of the world has reached their quota of characters")]
+    QuotaExceeded(#[from] QuotaExceededError),
+}
+
+impl CharactersService {
+    /// Create a new character in the system
+    ///
+    /// # Parameters
+    /// - `character` - The character to create
+    ///
+    /// # Returns
+    /// The newly created character
+    ///
+    /// # Errors
+    /// Any errors that occurred creating the new character
+    pub fn create_character(
+        &self,
+        character: CharacterData,
+    ) -> Result<CharacterModel, CreateCharacterError> {
+        tracing::debug!(character = ?character, "Creating character");
+
+        let quota =
+            self.quotas
+                .check_world_quota(&character.world, QuotaResource::Characters, 1)?;
+
+        let new_character = self.repository.create(character, quota.as_ref())?;
+        tracing::debug!(character = ?new_character, "Created character");
+
+        self.character_created(&new_character);
+
+        Ok(new_character)
+    }
+
+    /// Create several new characters in a world at once. Either every character is created or, if any of
+    /// them can't be, none of them are
+    ///
+    /// # Parameters
+    /// - `world` - The ID of the world that every character belongs to
+    /// - `characters` - The characters to create
+    ///
+    /// # Returns
+    /// The newly created characters, in the same order
+    ///
+    /// # Errors
+    /// Any errors that occurred creating the new characters
+    pub fn create_characters(
+        &self,
+        world: &WorldID,
+        characters: Vec<CharacterData>,
+    ) -> Result<Vec<CharacterModel>, CreateCharacterError> {
+        tracing::debug!(world = ?world, characters = characters.len(), "Creating characters");
+
+        let quota = self.quotas.check_world_quota(
+            world,
+            QuotaResource::Characters,
+            characters.len() as u64,
+        )?;
+
+        let new_characters = self.repository.create_many(characters, quota.as_ref())?;
+        tracing::debug!(characters = ?new_characters, "Created characters");
+
+        for new_character in &new_characters {
+            self.character_created(new_character);
+        }
+
+        Ok(new_characters)
+    }
+
+    /// Record the activity and dispatch the webhook event for a character that has just been created
+    fn character_created(&self, new_character: &CharacterModel) {
+        let target: String = CharacterLink::new(new_character.identity.id.clone()).into();
+        self.activity.record_activity(ActivityData {
+            world: new_character.data.world.clone(),
+            user: None,
+            event: ActivityEvent::CharacterCreated,
+            target: Some(target.clone()),
+        });
+        self.webhooks.dispatch(WebhookEvent {
+            world: new_character.data.world.clone(),
+            kind: WebhookEventKind::CharacterCreated,
+            target,
+        });
+    }
+}
+
+impl From<SaveCharacterError> for CreateCharacterError {
+    fn from(e: SaveCharacterError) -> Self {
+        match e {
+            SaveCharacterError::UnknownWorld => CreateCharacterError::UnknownWorld,
+            SaveCharacterError::UnknownUser => CreateCharacterError::UnknownUser,
+            SaveCharacterError::QuotaExceeded(e) => CreateCharacterError::QuotaExceeded(e),
+            _ => CreateCharacterError::UnknownError,
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use super::CharactersService;
+use crate::{
+    characters::{endpoints::model::CharacterLink, CharacterID},
+    webhooks::{WebhookEvent, WebhookEventKind},
+};
+
+impl CharactersService {
+    /// Delete the character with the given ID
+    ///
+    /// # Parameters
+    /// - `id` - The ID of the character to delete
+    ///
+    /// # Returns
+    /// `true` if the character was deleted, or `false` if it didn't exist
+    pub fn delete_character(&self, id: &CharacterID) -> bool {
+        let deleted = self.repository.delete(id);
+        tracing::debug!(character = ?deleted, "Deleted character");
+
+        match deleted {
+            Some(character) => {
+                self.webhooks.dispatch(WebhookEvent {
+                    world: character.data.world,
+                    kind: WebhookEventKind::CharacterDeleted,
+                    target: CharacterLink::new(character.identity.id).into(),
+                });
+                true
+            }
+            None => false,
+        }
+    }
+}
@@ -0,0 +1,96 @@
+use super::CharactersService;
+use crate::{
+    characters::{CharacterID, CharacterModel, CharacterSortField},
+    model::{Page, Pagination, SortFields},
+    users::UserID,
+    worlds::WorldID,
+};
+
+/// Filters to apply when searching for the characters in a world
+#[derive(Debug)]
+pub struct CharactersFilters {
+    /// The world to search within
+    pub world: WorldID,
+
+    /// Only include characters controlled by this user
+    pub controlled_by: Option<UserID>,
+
+    /// Whether characters that aren't published are included. This should only be set for editors of the
+    /// world
+    pub include_unpublished: bool,
+
+    /// The user that is doing the search. Characters that this user controls are always included, even
+    /// if they aren't published
+    pub viewer: Option<UserID>,
+}
+
+impl CharactersService {
+    /// Look up a character by it's unique ID
+    ///
+    /// # Parameters
+    /// - `id` - The ID of the Character to find
+    ///
+    /// # Returns
+    /// The Character, or `None` if it wasn't found
+    pub fn find_character_by_id(&self, id: &CharacterID) -> Option<CharacterModel> {
+        self.repository.find_character_by_id(id)
+    }
+
+    /// List the characters that belong to a world
+    ///
+    /// # Parameters
+    /// - `world` - The ID of the world to list the characters for
+    /// - `include_unpublished` - Whether to include characters that aren't yet published
+    /// - `pagination` - The pagination details for which page of results are wanted
+    ///
+    /// # Returns
+    /// The requested page of characters, ordered by name
+    pub fn list_characters(
+        &self,
+        world: &WorldID,
+        include_unpublished: bool,
+        pagination: &Pagination,
+    ) -> Page<CharacterModel> {
+        self.repository
+            .list_characters(world, include_unpublished, pagination)
+    }
+
+    /// Search the characters in a world
+    ///
+    /// # Parameters
+    /// - `filters` - The filters to apply
+    /// - `sorts` - The sorts to apply
+    /// - `pagination` - The pagination details for which page of results are wanted
+    ///
+    /// # Returns
+    /// The requested page of results
+    pub fn search_characters(
+        &self,
+        filters: &CharactersFilters,
+        sorts: &SortFields<CharacterSortField>,
+        pagination: &Pagination,
+    ) -> Page<CharacterModel> {
+        self.repository
+            .search_characters(filters, sorts, pagination)
+    }
+
+    /// Find the characters that a user controls, across every world
+    ///
+    /// # Parameters
+    /// - `user` - The ID of the user that controls the characters
+    /// - `viewer` - The ID of the user doing the viewing. Characters in worlds that this user can't see are
+    ///   not included, unless this is the user that controls them
+    /// - `pagination` - The pagination details for which page of results are wanted
+    ///
+    /// # Returns
+    /// The requested page of characters, ordered by name
+    pub fn find_controlled_characters(
+        &self,
+        user: &UserID,
+        viewer: Option<&UserID>,
+        pagination: &Pagination,
+    ) -> Page<CharacterModel> {
+        self.repository
+            .find_controlled_characters(user, viewer, pagination)
+    }
+}
@@ -0,0 +1,60 @@
+use crate::{
+    characters::{endpoints::model::CharacterLink, repository::SaveCharacterError, *},
+    webhooks::{WebhookEvent, WebhookEventKind},
+};
+
+/// Errors that can occur when updating a character
+#[derive(Debug, thiserror::Error)]
+pub enum UpdateCharacterError {
+    /// An unknown error occurred
+    #[error("An unknown error occurred")]
+    UnknownError,
+
+    #[error("The character does not exist")]
+    UnknownCharacter,
+
+    #[error("The user to control the character does not exist")]
+    UnknownUser,
+}
+
+impl CharactersService {
+    /// Update an existing character in the system
+    ///
+    /// # Parameters
+    /// - `id` - The ID of the character to update
+    /// - `character` - The new details of the character
+    ///
+    /// # Returns
+    /// The updated character
+    ///
+    /// # Errors
+    /// Any errors that occurred updating the character
+    pub fn update_character(
+        &self,
+        id: &CharacterID,
+        character: CharacterData,
+    ) -> Result<CharacterModel, UpdateCharacterError> {
+        tracing::debug!(id = ?id, character = ?character, "Updating character");
+
+        let updated = self.repository.update(id, character)?;
+        tracing::debug!(character = ?updated, "Updated character");
+
+        self.webhooks.dispatch(WebhookEvent {
+            world: updated.data.world.clone(),
+            kind: WebhookEventKind::CharacterUpdated,
+            target: CharacterLink::new(updated.identity.id.clone()).into(),
+        });
+
+        Ok(updated)
+    }
+}
+
+impl From<SaveCharacterError> for UpdateCharacterError {
+    fn from(e: SaveCharacterError) -> Self {
+        match e {
+            SaveCharacterError::UnknownCharacter => UpdateCharacterError::UnknownCharacter,
+            SaveCharacterError::UnknownUser => UpdateCharacterError::UnknownUser,
+            _ => UpdateCharacterError::UnknownError,
+        }
+    }
+}
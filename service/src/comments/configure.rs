@@ -0,0 +1,47 @@
+use super::{repository::CommentRepository, CommentsService};
+use crate::{infrastructure::database::Database, notifications::NotificationsService};
+use std::sync::Arc;
+
+/// Application Configuration for the Comments module
+pub struct CommentsConfig {
+    pub comments_service: CommentsService,
+}
+
+impl CommentsConfig {
+    /// Create the Configuration for the Comments Module
+    ///
+    /// # Parameters
+    /// - `database` - The database connection to use
+    /// - `notifications_service` - The notifications service to notify users with
+    ///
+    /// # Returns
+    /// The Comments Config object
+    pub fn new(database: Database, notifications_service: NotificationsService) -> Self {
+        let repository = CommentRepository::new(database);
+        Self {
+            comments_service: CommentsService::new(repository, notifications_service),
+        }
+    }
+
+    /// Generate the configuration callback needed for the HTTP Server to actually add the Comments
+    /// endpoints to the server
+    ///
+    /// # Returns
+    /// The callback to provide to the HTTP Server to configure up the Comments endpoints
+    pub fn configure(&self) -> Arc<dyn Fn(rocket::Rocket) -> rocket::Rocket + Send + Sync> {
+        let comments_service = self.comments_service.clone();
+        Arc::new(move |config| {
+            config.manage(comments_service.clone()).mount(
+                "/",
+                rocket::routes![
+                    super::endpoints::create_comment,
+                    super::endpoints::delete_comment,
+                    super::endpoints::get_comment,
+                    super::endpoints::list_comments,
+                    super::endpoints::list_replies,
+                    super::endpoints::update_comment
+                ],
+            )
+        })
+    }
+}
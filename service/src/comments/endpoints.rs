@@ -0,0 +1,16 @@
+mod comment_viewer;
+mod create_comment;
+mod delete_comment;
+mod errors;
+mod get_comment;
+mod list_comments;
+mod list_replies;
+pub(crate) mod model;
+mod update_comment;
+
+pub use create_comment::*;
+pub use delete_comment::*;
+pub use get_comment::*;
+pub use list_comments::*;
+pub use list_replies::*;
+pub use update_comment::*;
@@ -0,0 +1,58 @@
+use super::errors::CommentProblemType;
+use crate::{
+    authorization::Authorizer,
+    comments::{CommentID, CommentModel, CommentsService},
+    http::problem::Problem,
+    moderation::ModerationService,
+    worlds::{endpoints::world_owner::authorize_world_viewer, WorldModel, WorldsService},
+};
+use rocket::http::Status;
+
+/// Look up a comment and ensure that the current user is allowed to see it.
+///
+/// Comments are only visible to users that can see the world they were made in, and comments that have
+/// been hidden by a moderator are only visible to editors of the world and to moderators. To everyone
+/// else they appear not to exist at all.
+///
+/// # Parameters
+/// - `comments_service` - The comments service to use
+/// - `worlds_service` - The worlds service to use
+/// - `moderation_service` - The moderation service to use
+/// - `authorizer` - The authorizer to identify the current user
+/// - `id` - The ID of the comment
+///
+/// # Returns
+/// The comment and the world it was made in, if it exists and the current user can see it
+///
+/// # Errors
+/// A Problem if the comment doesn't exist or the current user can't see it
+pub(super) fn authorize_comment_viewer(
+    comments_service: &CommentsService,
+    worlds_service: &WorldsService,
+    moderation_service: &ModerationService,
+    authorizer: &Authorizer,
+    id: &CommentID,
+) -> Result<(CommentModel, WorldModel), Problem> {
+    let unknown_comment = || Problem::new(CommentProblemType::UnknownComment, Status::NotFound);
+
+    let comment = comments_service
+        .find_comment_by_id(id)
+        .ok_or_else(unknown_comment)?;
+
+    let viewer = authorizer.authorize().finish()?;
+    let world = authorize_world_viewer(worlds_service, authorizer, &comment.data.world)
+        .map_err(|_| unknown_comment())?;
+
+    if comment.data.hidden {
+        let can_see_hidden = worlds_service.is_editor(&world, viewer.as_ref())
+            || viewer
+                .as_ref()
+                .map(|viewer| moderation_service.is_moderator(viewer))
+                .unwrap_or(false);
+        if !can_see_hidden {
+            return Err(unknown_comment());
+        }
+    }
+
+    Ok((comment, world))
+}
@@ -0,0 +1,147 @@
+use super::{
+    errors::{CommentProblemType, CommentValidation},
+    model::CommentResponse,
+};
+use crate::{
+    authorization::Authorizer,
+    comments::{CommentData, CommentID, CommentsService, CreateCommentError},
+    http::problem::{GenericValidation, Problem, ValidationProblem},
+    worlds::{
+        endpoints::{errors::WorldProblemType, world_owner::authorize_world_viewer},
+        WorldID, WorldsService,
+    },
+};
+use rocket::{http::Status, post, State};
+use rocket_contrib::json::Json;
+use serde::Deserialize;
+use uuid::Uuid;
+
+/// Handler to comment on a World, or on an entity within a World
+///
+/// # Parameters
+/// - `comments_service` - The comments service to use
+/// - `worlds_service` - The worlds service to use
+/// - `world` - The ID of the world to comment on
+/// - `body` - The details of the comment
+/// - `authorizer` - The authorizer to prove who is writing the comment
+///
+/// # Returns
+/// The newly created comment details, or a Problem if the creation failed
+#[tracing::instrument(
+    name = "POST /worlds/{id}/comments",
+    skip(comments_service, worlds_service)
+)]
+#[post("/worlds/<world>/comments", data = "<body>")]
+pub fn create_comment(
+    comments_service: State<CommentsService>,
+    worlds_service: State<WorldsService>,
+    world: WorldID,
+    body: Json<CreateCommentRequest>,
+    authorizer: Authorizer,
+) -> Result<CommentResponse, Problem> {
+    let author = authorizer
+        .authorize()
+        .authorized()
+        .finish()?
+        .expect("No authenticated user");
+
+    let world = authorize_world_viewer(&worlds_service, &authorizer, &world)?;
+
+    let mut problem = ValidationProblem::new();
+    let mut valid = true;
+
+    let comment_body = body.body();
+    if comment_body == None {
+        problem.with_field_error("body", GenericValidation::Missing);
+        valid = false;
+    }
+
+    let entity = body.entity();
+
+    // A reply must be to a visible comment on the same thing as the reply itself
+    let parent = match &body.parent {
+        None => None,
+        Some(parent) => {
+            let parent = Uuid::parse_str(parent.trim_start_matches("/comments/"))
+                .ok()
+                .map(|parent| CommentID::new(parent))
+                .and_then(|parent| comments_service.find_comment_by_id(&parent))
+                .filter(|parent| {
+                    parent.data.world == world.identity.id
+                        && parent.data.entity == entity
+                        && !parent.data.hidden
+                });
+            if parent.is_none() {
+                problem.with_field_error("parent", CommentValidation::UnknownParent);
+                valid = false;
+            }
+            parent.map(|parent| parent.identity.id)
+        }
+    };
+
+    if !valid {
+        tracing::warn!("Validation error creating comment");
+        return Err(problem.build());
+    }
+
+    let comment = CommentData {
+        world: world.identity.id,
+        entity,
+        parent,
+        author,
+        body: comment_body.unwrap(),
+        hidden: false,
+    };
+    tracing::debug!(comment = ?comment, "Creating new comment");
+
+    let new_comment = comments_service.create_comment(comment)?;
+    Ok(CommentResponse(new_comment))
+}
+
+/// Incoming details representing a comment to create
+#[derive(Debug, Deserialize)]
+pub struct CreateCommentRequest {
+    /// The body of the comment, in Markdown
+    body: Option<String>,
+    /// The entity within the world that the comment is on. May be omitted to comment on the world itself
+    entity: Option<String>,
+    /// A link to the comment that this is a reply to. May be omitted for a top-level comment
+    parent: Option<String>,
+}
+
+impl CreateCommentRequest {
+    /// Get the body to use for the comment
+    fn body(&self) -> Option<String> {
+        self.body.clone().filter(|v| !v.trim().is_empty())
+    }
+
+    /// Get the entity that the comment is on
+    fn entity(&self) -> Option<String> {
+        self.entity
+            .clone()
+            .map(|v| v.trim().to_owned())
+            .filter(|v| !v.is_empty())
+    }
+}
+
+impl From<CreateCommentError> for Problem {
+    fn from(e: CreateCommentError) -> Self {
+        match e {
+            CreateCommentError::UnknownWorld => {
+                Problem::new(WorldProblemType::UnknownWorldID, Status::NotFound)
+            }
+            CreateCommentError::UnknownParent => {
+                let mut problem = ValidationProblem::new();
+                problem.with_field_error("parent", CommentValidation::UnknownParent);
+                problem.build()
+            }
+            _ => {
+                tracing::warn!(error = ?e, "An unexpected error occurred");
+                Problem::new(
+                    CommentProblemType::UnknownError,
+                    Status::InternalServerError,
+                )
+            }
+        }
+    }
+}
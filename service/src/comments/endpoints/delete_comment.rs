@@ -0,0 +1,50 @@
+use super::errors::CommentProblemType;
+use crate::{
+    authorization::Authorizer,
+    comments::{CommentID, CommentsService},
+    http::problem::Problem,
+    worlds::{endpoints::errors::WorldProblemType, WorldsService},
+};
+use rocket::{delete, http::Status, Response, State};
+
+/// Handler to delete an existing Comment, along with all of the replies to it.
+///
+/// Comments may be deleted either by their author or by the owner of the world that they are on.
+///
+/// # Parameters
+/// - `comments_service` - The comments service to use
+/// - `worlds_service` - The worlds service to use
+/// - `id` - The ID of the comment to delete
+/// - `authorizer` - The authorizer to prove we're allowed to delete the comment
+///
+/// # Returns
+/// An empty response if the comment was deleted, or a Problem if the deletion failed
+#[tracing::instrument(name = "DELETE /comments/{id}", skip(comments_service, worlds_service))]
+#[delete("/comments/<id>")]
+pub fn delete_comment(
+    comments_service: State<CommentsService>,
+    worlds_service: State<WorldsService>,
+    id: CommentID,
+    authorizer: Authorizer,
+) -> Result<Response<'static>, Problem> {
+    let comment = comments_service
+        .find_comment_by_id(&id)
+        .ok_or_else(|| Problem::new(CommentProblemType::UnknownComment, Status::NotFound))?;
+    let world = worlds_service
+        .find_world_by_id(comment.data.world.clone())
+        .ok_or_else(|| Problem::new(WorldProblemType::UnknownWorldID, Status::NotFound))?;
+
+    authorizer
+        .authorize()
+        .one_of_users(&[comment.data.author, world.data.owner])
+        .finish()?;
+
+    if comments_service.delete_comment(&id) {
+        Ok(Response::build().status(Status::NoContent).finalize())
+    } else {
+        Err(Problem::new(
+            CommentProblemType::UnknownComment,
+            Status::NotFound,
+        ))
+    }
+}
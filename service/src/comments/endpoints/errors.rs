@@ -0,0 +1,48 @@
+use crate::http::problem::{ProblemType, ValidationType};
+
+/// Problem Types that can happen when working with comments
+#[derive(Debug, thiserror::Error)]
+pub enum CommentProblemType {
+    /// An unknown error occurred
+    #[error("An unknown error occurred")]
+    UnknownError,
+
+    /// The comment that was looked up was not found
+    #[error("The requested comment was unknown")]
+    UnknownComment,
+}
+
+impl ProblemType for CommentProblemType {
+    /// Generate a Type value for the `ProblemType` values.
+    ///
+    /// These are used in the `type` field in the RFC-7807 Problem Response
+    fn error_code(&self) -> &'static str {
+        match self {
+            CommentProblemType::UnknownError => {
+                "tag:multiverse,2020:comments/problems/unknown_error"
+            }
+            CommentProblemType::UnknownComment => {
+                "tag:multiverse,2020:comments/problems/unknown_comment"
+            }
+        }
+    }
+}
+
+/// Validation errors that are specific to comments
+#[derive(Debug, thiserror::Error)]
+pub enum CommentValidation {
+    /// The comment being replied to does not exist, or is on a different world or entity
+    #[error("The parent comment was unknown")]
+    UnknownParent,
+}
+
+impl ValidationType for CommentValidation {
+    /// Generate a Type value for the `ValidationType` values.
+    fn error_code(&self) -> &'static str {
+        match self {
+            CommentValidation::UnknownParent => {
+                "tag:multiverse,2020:comments/problems/validation_error/unknown_parent"
+            }
+        }
+    }
+}
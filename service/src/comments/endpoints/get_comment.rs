@@ -0,0 +1,42 @@
+use super::{comment_viewer::authorize_comment_viewer, model::CommentResponse};
+use crate::{
+    authorization::Authorizer,
+    comments::{CommentID, CommentsService},
+    http::problem::Problem,
+    moderation::ModerationService,
+    worlds::WorldsService,
+};
+use rocket::{get, State};
+
+/// Handler to get an existing Comment by ID
+///
+/// # Parameters
+/// - `comments_service` - The comments service to use
+/// - `worlds_service` - The worlds service to use
+/// - `moderation_service` - The moderation service to use
+/// - `id` - The ID of the comment
+/// - `authorizer` - The authorizer to identify the current user
+///
+/// # Returns
+/// The details of the comment
+#[tracing::instrument(
+    name = "GET /comments/{id}",
+    skip(comments_service, worlds_service, moderation_service)
+)]
+#[get("/comments/<id>")]
+pub fn get_comment(
+    comments_service: State<CommentsService>,
+    worlds_service: State<WorldsService>,
+    moderation_service: State<ModerationService>,
+    id: CommentID,
+    authorizer: Authorizer,
+) -> Result<CommentResponse, Problem> {
+    authorize_comment_viewer(
+        &comments_service,
+        &worlds_service,
+        &moderation_service,
+        &authorizer,
+        &id,
+    )
+    .map(|(comment, _)| CommentResponse(comment))
+}
@@ -0,0 +1,49 @@
+use super::model::CommentsResponse;
+use crate::{
+    authorization::Authorizer,
+    comments::{CommentsFilters, CommentsService},
+    http::{pagination::PaginationRequest, problem::Problem},
+    worlds::{endpoints::world_owner::authorize_world_viewer, WorldID, WorldsService},
+};
+use rocket::{get, State};
+
+/// Handler to list the top-level Comments on a World, or on an entity within a World
+///
+/// # Parameters
+/// - `comments_service` - The comments service to use
+/// - `worlds_service` - The worlds service to use
+/// - `world` - The ID of the world to list the comments of
+/// - `entity` - The entity within the world to list the comments of. If omitted then the comments on
+///   the world itself are listed
+/// - `pagination` - The pagination controls to apply
+/// - `authorizer` - The authorizer to identify the current user
+///
+/// # Returns
+/// The details of the comments
+#[tracing::instrument(
+    name = "GET /worlds/{id}/comments",
+    skip(comments_service, worlds_service)
+)]
+#[get("/worlds/<world>/comments?<entity>&<pagination..>")]
+pub fn list_comments(
+    comments_service: State<CommentsService>,
+    worlds_service: State<WorldsService>,
+    world: WorldID,
+    entity: Option<String>,
+    pagination: PaginationRequest,
+    authorizer: Authorizer,
+) -> Result<CommentsResponse, Problem> {
+    authorize_world_viewer(&worlds_service, &authorizer, &world)?;
+
+    let comments = comments_service.search_comments(
+        &CommentsFilters {
+            world,
+            entity: entity.filter(|v| !v.trim().is_empty()),
+            parent: None,
+        },
+        &pagination,
+    );
+    tracing::debug!(comments = ?comments, "Found comments");
+
+    Ok(CommentsResponse(comments))
+}
@@ -0,0 +1,55 @@
+use super::{comment_viewer::authorize_comment_viewer, model::CommentsResponse};
+use crate::{
+    authorization::Authorizer,
+    comments::{CommentID, CommentsFilters, CommentsService},
+    http::{pagination::PaginationRequest, problem::Problem},
+    moderation::ModerationService,
+    worlds::WorldsService,
+};
+use rocket::{get, State};
+
+/// Handler to list the direct replies to a Comment
+///
+/// # Parameters
+/// - `comments_service` - The comments service to use
+/// - `worlds_service` - The worlds service to use
+/// - `moderation_service` - The moderation service to use
+/// - `id` - The ID of the comment to list the replies to
+/// - `pagination` - The pagination controls to apply
+/// - `authorizer` - The authorizer to identify the current user
+///
+/// # Returns
+/// The details of the replies
+#[tracing::instrument(
+    name = "GET /comments/{id}/replies",
+    skip(comments_service, worlds_service, moderation_service)
+)]
+#[get("/comments/<id>/replies?<pagination..>")]
+pub fn list_replies(
+    comments_service: State<CommentsService>,
+    worlds_service: State<WorldsService>,
+    moderation_service: State<ModerationService>,
+    id: CommentID,
+    pagination: PaginationRequest,
+    authorizer: Authorizer,
+) -> Result<CommentsResponse, Problem> {
+    let (comment, _) = authorize_comment_viewer(
+        &comments_service,
+        &worlds_service,
+        &moderation_service,
+        &authorizer,
+        &id,
+    )?;
+
+    let replies = comments_service.search_comments(
+        &CommentsFilters {
+            world: comment.data.world,
+            entity: comment.data.entity,
+            parent: Some(comment.identity.id),
+        },
+        &pagination,
+    );
+    tracing::debug!(replies = ?replies, "Found replies");
+
+    Ok(CommentsResponse(replies))
+}
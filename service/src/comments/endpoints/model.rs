@@ -0,0 +1,7 @@
+mod comment_link;
+mod comment_response;
+mod comments_response;
+
+pub use comment_link::*;
+pub use comment_response::*;
+pub use comments_response::*;
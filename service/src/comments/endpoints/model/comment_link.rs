@@ -0,0 +1,16 @@
+use crate::comments::CommentID;
+
+/// Representation of a link to a comment
+pub struct CommentLink(CommentID);
+
+impl CommentLink {
+    pub fn new(comment_id: CommentID) -> Self {
+        Self(comment_id)
+    }
+}
+
+impl From<CommentLink> for String {
+    fn from(comment_link: CommentLink) -> String {
+        format!("/comments/{}", comment_link.0)
+    }
+}
@@ -0,0 +1,61 @@
+use super::CommentLink;
+use crate::{
+    comments::CommentModel,
+    http::{
+        link::{Link, LinkRel, Links},
+        markdown,
+    },
+    users::{endpoints::model::UserLink, UsersService},
+    worlds::endpoints::model::WorldLink,
+};
+use rocket::{
+    http::hyper::header::{CacheControl, CacheDirective, ETag, EntityTag},
+    response, Request, State,
+};
+use rocket_contrib::json::Json;
+use serde_json::{json, Value};
+
+/// API Model representing a Comment
+#[derive(Debug)]
+pub struct CommentResponse(pub CommentModel);
+
+pub(super) fn to_json(comment: &CommentModel) -> Value {
+    let parent: Option<String> = comment
+        .data
+        .parent
+        .clone()
+        .map(|parent| CommentLink::new(parent).into());
+
+    json!({
+      "body": comment.data.body,
+      "html": markdown::render(&comment.data.body),
+      "entity": comment.data.entity,
+      "parent": parent,
+      "created": comment.identity.created,
+      "updated": comment.identity.updated
+    })
+}
+
+impl<'r> response::Responder<'r> for CommentResponse {
+    fn respond_to(self, req: &Request) -> response::Result<'r> {
+        let users_service = req.guard::<State<UsersService>>().unwrap();
+        let user = users_service.find_user_by_id(&self.0.data.author);
+        let user_name = user.map(|u| u.data.display_name).unwrap_or_default();
+
+        let etag = self.0.identity.version.to_string();
+
+        response::Response::build()
+            .merge(Json(to_json(&self.0)).respond_to(req).unwrap())
+            .header(CacheControl(vec![
+                CacheDirective::Private,
+                CacheDirective::MaxAge(3600),
+            ]))
+            .header(ETag(EntityTag::new(false, etag)))
+            .header(Links(vec![
+                Link::new(CommentLink::new(self.0.identity.id), LinkRel::SELF),
+                Link::new(UserLink::new(self.0.data.author), LinkRel::AUTHOR).title(user_name),
+                Link::new(WorldLink::new(self.0.data.world), LinkRel::RELATED),
+            ]))
+            .ok()
+    }
+}
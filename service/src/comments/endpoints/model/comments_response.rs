@@ -0,0 +1,93 @@
+use super::{to_json, CommentLink};
+use crate::{
+    comments::CommentModel,
+    http::link::{Link, LinkRel, Links},
+    model::Page,
+    users::{endpoints::model::UserLink, UserID, UserModel, UsersService},
+};
+use itertools::*;
+use rocket::{
+    http::hyper::header::{CacheControl, CacheDirective},
+    response, Request, State,
+};
+use rocket_contrib::json::Json;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+
+/// API Model representing a page of Comments
+#[derive(Debug)]
+pub struct CommentsResponse(pub Page<CommentModel>);
+
+impl<'r> response::Responder<'r> for CommentsResponse {
+    fn respond_to(self, req: &Request) -> response::Result<'r> {
+        let users_service = req.guard::<State<UsersService>>().unwrap();
+
+        let user_ids: Vec<&UserID> = self
+            .0
+            .entries
+            .iter()
+            .map(|comment| &comment.data.author)
+            .unique()
+            .collect();
+        let users: HashMap<UserID, UserModel> = if user_ids.is_empty() {
+            HashMap::new()
+        } else {
+            users_service
+                .find_users_by_id(&user_ids[..])
+                .into_iter()
+                .map(|user| (user.identity.id.clone(), user))
+                .collect()
+        };
+
+        let comments: Vec<Value> = self
+            .0
+            .entries
+            .iter()
+            .map(|comment| to_json(&comment))
+            .collect();
+
+        let response_body = json!({
+          "entries": comments,
+          "pagination": {
+            "offset": self.0.offset,
+            "count": self.0.count,
+            "total": self.0.total
+          }
+        });
+
+        let mut comment_links: Vec<Link> = self
+            .0
+            .entries
+            .iter()
+            .map(|comment| CommentLink::new(comment.identity.id.clone()))
+            .enumerate()
+            .map(|(index, link)| {
+                Link::new(link, LinkRel::ITEM).anchor(format!("#/entries/{}", index))
+            })
+            .collect();
+        let user_links: Vec<Link> = self
+            .0
+            .entries
+            .iter()
+            .enumerate()
+            .filter_map(|(index, comment)| {
+                users.get(&comment.data.author).map(|user| (index, user))
+            })
+            .map(|(index, user)| {
+                Link::new(UserLink::new(user.identity.id.clone()), LinkRel::AUTHOR)
+                    .anchor(format!("#/entries/{}", index))
+                    .title(user.data.display_name.clone())
+            })
+            .collect();
+        comment_links.extend(user_links);
+
+        response::Response::build()
+            .merge(Json(response_body).respond_to(req).unwrap())
+            .header(CacheControl(vec![
+                CacheDirective::Private,
+                CacheDirective::MaxAge(3600),
+            ]))
+            .header(Links(comment_links))
+            .ok()
+    }
+}
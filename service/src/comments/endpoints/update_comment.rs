@@ -0,0 +1,78 @@
+use super::{errors::CommentProblemType, model::CommentResponse};
+use crate::{
+    authorization::Authorizer,
+    comments::{CommentID, CommentsService, UpdateCommentError},
+    http::problem::{GenericValidation, Problem, ValidationProblem},
+};
+use rocket::{http::Status, put, State};
+use rocket_contrib::json::Json;
+use serde::Deserialize;
+
+/// Handler to edit an existing Comment. Only the author of the comment may edit it
+///
+/// # Parameters
+/// - `comments_service` - The comments service to use
+/// - `id` - The ID of the comment to edit
+/// - `body` - The new body of the comment
+/// - `authorizer` - The authorizer to prove we're allowed to edit the comment
+///
+/// # Returns
+/// The updated comment details, or a Problem if the update failed
+#[tracing::instrument(name = "PUT /comments/{id}", skip(comments_service))]
+#[put("/comments/<id>", data = "<body>")]
+pub fn update_comment(
+    comments_service: State<CommentsService>,
+    id: CommentID,
+    body: Json<UpdateCommentRequest>,
+    authorizer: Authorizer,
+) -> Result<CommentResponse, Problem> {
+    let comment = comments_service
+        .find_comment_by_id(&id)
+        .ok_or_else(|| Problem::new(CommentProblemType::UnknownComment, Status::NotFound))?;
+
+    authorizer
+        .authorize()
+        .same_user(&comment.data.author)
+        .finish()?;
+
+    let comment_body = body.body().ok_or_else(|| {
+        tracing::warn!("Validation error updating comment");
+        let mut problem = ValidationProblem::new();
+        problem.with_field_error("body", GenericValidation::Missing);
+        problem.build()
+    })?;
+
+    let updated = comments_service.update_comment(&id, &comment_body)?;
+    Ok(CommentResponse(updated))
+}
+
+/// Incoming details representing the new body of a comment
+#[derive(Debug, Deserialize)]
+pub struct UpdateCommentRequest {
+    /// The body of the comment, in Markdown
+    body: Option<String>,
+}
+
+impl UpdateCommentRequest {
+    /// Get the body to use for the comment
+    fn body(&self) -> Option<String> {
+        self.body.clone().filter(|v| !v.trim().is_empty())
+    }
+}
+
+impl From<UpdateCommentError> for Problem {
+    fn from(e: UpdateCommentError) -> Self {
+        match e {
+            UpdateCommentError::UnknownComment => {
+                Problem::new(CommentProblemType::UnknownComment, Status::NotFound)
+            }
+            _ => {
+                tracing::warn!(error = ?e, "An unexpected error occurred");
+                Problem::new(
+                    CommentProblemType::UnknownError,
+                    Status::InternalServerError,
+                )
+            }
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use super::CommentID;
+use crate::{model::Model, users::UserID, worlds::WorldID};
+
+/// Data to represent a comment on a world, or on an entity within a world
+#[derive(Debug, Clone)]
+pub struct CommentData {
+    /// The ID of the world that the comment is on
+    pub world: WorldID,
+
+    /// The entity within the world that the comment is on, if it isn't on the world itself
+    pub entity: Option<String>,
+
+    /// The comment that this is a reply to, if it is a reply
+    pub parent: Option<CommentID>,
+
+    /// The ID of the user that wrote the comment
+    pub author: UserID,
+
+    /// The body of the comment, in Markdown
+    pub body: String,
+
+    /// Whether the comment has been hidden by a moderator. Hidden comments are only visible to editors
+    /// of the world and to moderators
+    pub hidden: bool,
+}
+
+/// Model representation of a comment
+pub type CommentModel = Model<CommentID, CommentData>;
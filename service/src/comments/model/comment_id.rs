@@ -0,0 +1,67 @@
+use bytes::BytesMut;
+use postgres_types::{accepts, to_sql_checked, FromSql, IsNull, ToSql, Type};
+use rocket::{http::RawStr, request};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use uuid::Uuid;
+
+/// The ID of a Comment
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, FromSql)]
+pub struct CommentID(Uuid);
+
+impl CommentID {
+    /// Wrap a UUID as a Comment ID object
+    ///
+    /// # Parameters
+    /// - `uuid` - The UUID to wrap
+    ///
+    /// # The Comment ID
+    pub fn new(uuid: Uuid) -> Self {
+        CommentID(uuid)
+    }
+}
+
+impl Default for CommentID {
+    fn default() -> Self {
+        Self(Uuid::new_v4())
+    }
+}
+
+impl ToSql for CommentID {
+    accepts!(UUID);
+
+    to_sql_checked!();
+
+    fn to_sql(
+        &self,
+        t: &Type,
+        w: &mut BytesMut,
+    ) -> Result<IsNull, Box<dyn std::error::Error + Sync + Send>> {
+        self.0.to_sql(t, w)
+    }
+}
+
+impl<'r> request::FromParam<'r> for CommentID {
+    type Error = &'r RawStr;
+
+    fn from_param(param: &'r RawStr) -> Result<Self, Self::Error> {
+        param
+            .percent_decode()
+            .map(|cow| cow.into_owned())
+            .map_err(|_| param)
+            .and_then(|comment_id| Uuid::parse_str(&comment_id).map_err(|_| param))
+            .map(|comment_id| CommentID::new(comment_id))
+    }
+}
+
+impl Display for CommentID {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<CommentID> for Uuid {
+    fn from(comment_id: CommentID) -> Self {
+        comment_id.0
+    }
+}
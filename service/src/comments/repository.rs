@@ -0,0 +1,29 @@
+mod create;
+mod delete;
+mod errors;
+mod find;
+mod parse;
+mod update;
+
+use crate::infrastructure::database::Database;
+pub use errors::*;
+
+/// Repository used to access Comment data from the database
+#[derive(Clone)]
+pub(super) struct CommentRepository {
+    /// The database with which to access comment records
+    database: Database,
+}
+
+impl CommentRepository {
+    /// Create a new Comment Repository to work with
+    ///
+    /// # Parameters
+    /// - `database` - The database connection to use
+    ///
+    /// # Returns
+    /// The Comment Repository to use
+    pub(super) fn new(database: Database) -> Self {
+        Self { database }
+    }
+}
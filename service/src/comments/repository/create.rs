@@ -0,0 +1,56 @@
+use super::{CommentRepository, SaveCommentError};
+use crate::{
+    comments::model::*,
+    events::{publish_change, EntityType, WorldChange},
+};
+use chrono::Utc;
+use uuid::Uuid;
+
+impl CommentRepository {
+    /// Create a new comment in the database
+    ///
+    /// # Parameters
+    /// - `comment` - The comment to create
+    ///
+    /// # Returns
+    /// The newly created comment
+    ///
+    /// # Errors
+    /// Any errors that occurred creating the new comment
+    #[tracing::instrument(skip(self))]
+    pub fn create(&self, comment: CommentData) -> Result<CommentModel, SaveCommentError> {
+        let id = CommentID::default();
+        let now = Utc::now();
+        let version = Uuid::new_v4();
+
+        let mut connection = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+
+        let new_comment = connection.query_one("INSERT INTO comments(comment_id, version, created, updated, world_id, entity, parent_id, author_id, body) VALUES($1, $2, $3, $3, $4, $5, $6, $7, $8) RETURNING *",
+            &[
+                &id,
+                &version,
+                &now,
+                &comment.world,
+                &comment.entity,
+                &comment.parent,
+                &comment.author,
+                &comment.body,
+            ])
+            .map(|row| self.parse_row(&row))?;
+
+        publish_change(
+            &mut connection,
+            WorldChange {
+                world: new_comment.data.world.clone(),
+                entity_type: EntityType::Comment,
+                entity_id: new_comment.identity.id.clone().into(),
+                version: Some(new_comment.identity.version),
+            },
+        );
+
+        Ok(new_comment)
+    }
+}
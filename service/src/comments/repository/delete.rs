@@ -0,0 +1,47 @@
+use super::CommentRepository;
+use crate::{
+    comments::CommentID,
+    events::{publish_change, EntityType, WorldChange},
+    worlds::WorldID,
+};
+
+impl CommentRepository {
+    /// Delete a comment from the database, along with all of the replies to it
+    ///
+    /// # Parameters
+    /// - `id` - The ID of the comment to delete
+    ///
+    /// # Returns
+    /// True if the comment was deleted. False if it didn't exist
+    #[tracing::instrument(skip(self))]
+    pub fn delete(&self, id: &CommentID) -> bool {
+        let mut connection = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+
+        let world: Option<WorldID> = connection
+            .query_opt(
+                "DELETE FROM comments WHERE comment_id = $1 RETURNING world_id",
+                &[id],
+            )
+            .expect("Failed to delete comment")
+            .map(|row| row.get("world_id"));
+
+        match world {
+            Some(world) => {
+                publish_change(
+                    &mut connection,
+                    WorldChange {
+                        world,
+                        entity_type: EntityType::Comment,
+                        entity_id: id.clone().into(),
+                        version: None,
+                    },
+                );
+                true
+            }
+            None => false,
+        }
+    }
+}
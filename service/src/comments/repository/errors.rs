@@ -0,0 +1,57 @@
+use postgres::error::{DbError, SqlState};
+
+/// Errors that can occur when saving a comment record
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum SaveCommentError {
+    /// An unknown error occurred
+    #[error("An unknown error occurred")]
+    UnknownError,
+
+    /// The world that the comment is on does not exist
+    #[error("The desired world does not exist")]
+    UnknownWorld,
+
+    /// The comment that this is a reply to does not exist
+    #[error("The parent comment does not exist")]
+    UnknownParent,
+
+    /// The comment being updated does not exist
+    #[error("The comment does not exist")]
+    UnknownComment,
+}
+
+impl From<postgres::Error> for SaveCommentError {
+    /// Convert a database error into a SaveCommentError.
+    ///
+    /// The conversion returns an `UnknownWorld` iff the error is a `FOREIGN_KEY_VIOLATION` and the constraint
+    /// is `comments_world_id_fkey`, and an `UnknownParent` iff the error is a `FOREIGN_KEY_VIOLATION` and the
+    /// constraint is `comments_parent_id_fkey`. Otherwise it returns an `UnknownError`
+    ///
+    /// # Parameters
+    /// - `e` - The error to convert
+    ///
+    /// # Returns
+    /// The new error code
+    fn from(e: postgres::Error) -> Self {
+        if e.code() == Some(&SqlState::FOREIGN_KEY_VIOLATION) {
+            let db_error: Option<DbError> = e
+                .into_source()
+                .and_then(|e| e.downcast_ref::<DbError>().cloned());
+
+            match db_error.as_ref().and_then(|e| e.constraint()) {
+                Some("comments_world_id_fkey") => SaveCommentError::UnknownWorld,
+                Some("comments_parent_id_fkey") => SaveCommentError::UnknownParent,
+                constraint => {
+                    tracing::warn!(
+                        "Unexpected foreign key constraint violation error: {:?}",
+                        constraint
+                    );
+                    SaveCommentError::UnknownError
+                }
+            }
+        } else {
+            tracing::warn!("Unexpected database error: {:?}", e);
+            SaveCommentError::UnknownError
+        }
+    }
+}
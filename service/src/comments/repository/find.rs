@@ -0,0 +1,104 @@
+use super::CommentRepository;
+use crate::{
+    comments::{CommentID, CommentModel, CommentsFilters},
+    model::{Page, Pagination},
+};
+
+impl CommentRepository {
+    /// Find a comment in the database by its unique ID
+    ///
+    /// # Parameters
+    /// - `id` - The ID of the comment
+    ///
+    /// # Returns
+    /// The comment, if it exists
+    #[tracing::instrument(skip(self))]
+    pub fn find_comment_by_id(&self, id: &CommentID) -> Option<CommentModel> {
+        let mut connection = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+        connection
+            .query_opt("SELECT * FROM comments WHERE comment_id = $1", &[id])
+            .expect("Failed to query for comment by ID")
+            .map(|row| self.parse_row(&row))
+    }
+
+    /// Search for the comments that match the given filters, with the oldest first so that they read as
+    /// a conversation. Comments that have been hidden by a moderator are never included
+    ///
+    /// # Parameters
+    /// - `filters` - The filters to apply to the search
+    /// - `pagination` - The pagination details for which page of results are wanted
+    ///
+    /// # Returns
+    /// The requested page of results
+    #[tracing::instrument(skip(self))]
+    pub fn search_comments(
+        &self,
+        filters: &CommentsFilters,
+        pagination: &Pagination,
+    ) -> Page<CommentModel> {
+        // Collect together the Where clauses and Binds
+        let mut where_clauses: Vec<String> = vec![];
+        let mut binds: Vec<&(dyn postgres::types::ToSql + Sync)> = vec![];
+
+        binds.push(&filters.world);
+        where_clauses.push(format!("world_id = ${}", binds.len()));
+
+        match &filters.entity {
+            Some(entity) => {
+                binds.push(entity);
+                where_clauses.push(format!("entity = ${}", binds.len()));
+            }
+            None => where_clauses.push("entity IS NULL".to_owned()),
+        }
+        match &filters.parent {
+            Some(parent) => {
+                binds.push(parent);
+                where_clauses.push(format!("parent_id = ${}", binds.len()));
+            }
+            None => where_clauses.push("parent_id IS NULL".to_owned()),
+        }
+        where_clauses.push("NOT hidden".to_owned());
+        let where_clause = format!("WHERE {}", where_clauses.join(" AND "));
+
+        let mut connection = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+
+        let select_query = format!(
+            "SELECT * FROM comments {} ORDER BY created ASC, comment_id ASC OFFSET {} LIMIT {}",
+            where_clause, pagination.offset, pagination.count
+        );
+        let records: Vec<CommentModel> = connection
+            .query(select_query.as_str(), &binds[..])
+            .map(|rows| rows.iter().map(|row| self.parse_row(row)).collect())
+            .expect("Failed to select comments");
+
+        let num_records = records.len() as u64;
+        let total = if records.is_empty() || num_records == pagination.count {
+            // If we got zero records back, or exactly the number we asked for, then we don't know
+            // how many there are in the database
+            let count_query = format!(
+                "SELECT COUNT(*)::INTEGER AS c FROM comments {}",
+                where_clause
+            );
+            let count: i32 = connection
+                .query_one(count_query.as_str(), &binds[..])
+                .map(|row| row.get("c"))
+                .expect("Failed to count comments");
+            count as u64
+        } else {
+            pagination.offset + num_records
+        };
+
+        Page {
+            entries: records,
+            offset: pagination.offset,
+            count: pagination.count,
+            total,
+        }
+    }
+}
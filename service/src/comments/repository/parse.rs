@@ -0,0 +1,31 @@
+use super::CommentRepository;
+use crate::{comments::model::*, model::Identity};
+use postgres::row::Row;
+
+impl CommentRepository {
+    /// Parse a row from the database into a Comment Model
+    ///
+    /// # Parameters
+    /// - `row` - The row to parse
+    ///
+    /// # Returns
+    /// The comment that the row represented
+    pub(super) fn parse_row(&self, row: &Row) -> CommentModel {
+        CommentModel {
+            identity: Identity {
+                id: row.get("comment_id"),
+                version: row.get("version"),
+                created: row.get("created"),
+                updated: row.get("updated"),
+            },
+            data: CommentData {
+                world: row.get("world_id"),
+                entity: row.get("entity"),
+                parent: row.get("parent_id"),
+                author: row.get("author_id"),
+                body: row.get("body"),
+                hidden: row.get("hidden"),
+            },
+        }
+    }
+}
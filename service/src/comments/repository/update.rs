@@ -0,0 +1,51 @@
+use super::{CommentRepository, SaveCommentError};
+use crate::{
+    comments::model::*,
+    events::{publish_change, EntityType, WorldChange},
+};
+use chrono::Utc;
+use uuid::Uuid;
+
+impl CommentRepository {
+    /// Update the body of the given comment in the database
+    ///
+    /// # Parameters
+    /// - `id` - The ID of the comment to update
+    /// - `body` - The new body of the comment
+    ///
+    /// # Returns
+    /// The updated comment
+    ///
+    /// # Errors
+    /// Any errors that occurred updating the comment
+    #[tracing::instrument(skip(self))]
+    pub fn update(&self, id: &CommentID, body: &str) -> Result<CommentModel, SaveCommentError> {
+        let now = Utc::now();
+        let version = Uuid::new_v4();
+
+        let mut connection = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+
+        let updated_comment = connection
+            .query_opt(
+                "UPDATE comments SET version = $2, updated = $3, body = $4 WHERE comment_id = $1 RETURNING *",
+                &[&id, &version, &now, &body],
+            )?
+            .map(|row| self.parse_row(&row))
+            .ok_or(SaveCommentError::UnknownComment)?;
+
+        publish_change(
+            &mut connection,
+            WorldChange {
+                world: updated_comment.data.world.clone(),
+                entity_type: EntityType::Comment,
+                entity_id: updated_comment.identity.id.clone().into(),
+                version: Some(updated_comment.identity.version),
+            },
+        );
+
+        Ok(updated_comment)
+    }
+}
@@ -0,0 +1,30 @@
+mod create_comment;
+mod delete;
+mod find;
+mod update;
+
+use super::repository::CommentRepository;
+use crate::notifications::NotificationsService;
+pub use create_comment::CreateCommentError;
+pub use find::CommentsFilters;
+pub use update::UpdateCommentError;
+
+/// Service Layer for dealing with Comments
+#[derive(Clone)]
+pub struct CommentsService {
+    repository: CommentRepository,
+    notifications: NotificationsService,
+}
+
+impl CommentsService {
+    /// Create a new instance of the Comments Service
+    ///
+    /// # Returns
+    /// The Comments Service ready to use
+    pub(super) fn new(repository: CommentRepository, notifications: NotificationsService) -> Self {
+        Self {
+            repository,
+            notifications,
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use crate::{
+    comments::{endpoints::model::CommentLink, repository::SaveCommentError, *},
+    notifications::{NotificationData, NotificationKind},
+};
+
+/// Errors that can occur when creating a new comment
+#[derive(Debug, thiserror::Error)]
+pub enum CreateCommentError {
+    /// An unknown error occurred
+    #[error("An unknown error occurred")]
+    UnknownError,
+
+    /// The world that the comment is on does not exist
+    #[error("The desired world does not exist")]
+    UnknownWorld,
+
+    /// The comment that this is a reply to does not exist
+    #[error("The parent comment does not exist")]
+    UnknownParent,
+}
+
+impl CommentsService {
+    /// Create a new comment. The owner of the world is notified of the new comment, and if it is a
+    /// reply then the author of the parent comment is notified as well
+    ///
+    /// # Parameters
+    /// - `comment` - The comment to create
+    ///
+    /// # Returns
+    /// The newly created comment
+    ///
+    /// # Errors
+    /// Any errors that occurred creating the new comment
+    pub fn create_comment(&self, comment: CommentData) -> Result<CommentModel, CreateCommentError> {
+        tracing::debug!(comment = ?comment, "Creating comment");
+        let new_comment = self.repository.create(comment)?;
+        tracing::debug!(comment = ?new_comment, "Created comment");
+
+        let target: String = CommentLink::new(new_comment.identity.id.clone()).into();
+        self.notifications.notify(NotificationData {
+            user: None,
+            actor: new_comment.data.author.clone(),
+            kind: NotificationKind::Comment,
+            world: new_comment.data.world.clone(),
+            target: Some(target.clone()),
+        });
+        if let Some(parent) = new_comment
+            .data
+            .parent
+            .as_ref()
+            .and_then(|parent| self.repository.find_comment_by_id(parent))
+        {
+            self.notifications.notify(NotificationData {
+                user: Some(parent.data.author),
+                actor: new_comment.data.author.clone(),
+                kind: NotificationKind::Reply,
+                world: new_comment.data.world.clone(),
+                target: Some(target),
+            });
+        }
+
+        Ok(new_comment)
+    }
+}
+
+impl From<SaveCommentError> for CreateCommentError {
+    fn from(e: SaveCommentError) -> Self {
+        match e {
+            SaveCommentError::UnknownWorld => CreateCommentError::UnknownWorld,
+            SaveCommentError::UnknownParent => CreateCommentError::UnknownParent,
+            _ => CreateCommentError::UnknownError,
+        }
+    }
+}
@@ -0,0 +1,15 @@
+use super::CommentsService;
+use crate::comments::CommentID;
+
+impl CommentsService {
+    /// Delete a comment, along with all of the replies to it
+    ///
+    /// # Parameters
+    /// - `id` - The ID of the comment to delete
+    ///
+    /// # Returns
+    /// True if the comment was deleted. False if it didn't exist
+    pub fn delete_comment(&self, id: &CommentID) -> bool {
+        self.repository.delete(id)
+    }
+}
@@ -0,0 +1,49 @@
+use super::CommentsService;
+use crate::{
+    comments::{CommentID, CommentModel},
+    model::{Page, Pagination},
+    worlds::WorldID,
+};
+
+/// Filters to apply when searching for comments
+#[derive(Debug)]
+pub struct CommentsFilters {
+    /// Only include comments on this world
+    pub world: WorldID,
+
+    /// Only include comments on this entity within the world. If `None` then only comments on the world
+    /// itself are included
+    pub entity: Option<String>,
+
+    /// Only include replies to this comment. If `None` then only top-level comments are included
+    pub parent: Option<CommentID>,
+}
+
+impl CommentsService {
+    /// Look up a comment by it's unique ID
+    ///
+    /// # Parameters
+    /// - `id` - The ID of the Comment to find
+    ///
+    /// # Returns
+    /// The Comment, or `None` if it wasn't found
+    pub fn find_comment_by_id(&self, id: &CommentID) -> Option<CommentModel> {
+        self.repository.find_comment_by_id(id)
+    }
+
+    /// Search for comments that match the given criteria, oldest first
+    ///
+    /// # Parameters
+    /// - `filters` - The filters to apply to the results
+    /// - `pagination` - The pagination details for which page of results are wanted
+    ///
+    /// # Returns
+    /// The requested page of results
+    pub fn search_comments(
+        &self,
+        filters: &CommentsFilters,
+        pagination: &Pagination,
+    ) -> Page<CommentModel> {
+        self.repository.search_comments(filters, pagination)
+    }
+}
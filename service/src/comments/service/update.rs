@@ -0,0 +1,46 @@
+use crate::comments::{repository::SaveCommentError, *};
+
+/// Errors that can occur when updating a comment
+#[derive(Debug, thiserror::Error)]
+pub enum UpdateCommentError {
+    /// An unknown error occurred
+    #[error("An unknown error occurred")]
+    UnknownError,
+
+    /// The comment being updated does not exist
+    #[error("The comment does not exist")]
+    UnknownComment,
+}
+
+impl CommentsService {
+    /// Replace the body of an existing comment
+    ///
+    /// # Parameters
+    /// - `id` - The ID of the comment to update
+    /// - `body` - The new body of the comment
+    ///
+    /// # Returns
+    /// The updated comment
+    ///
+    /// # Errors
+    /// Any errors that occurred updating the comment
+    pub fn update_comment(
+        &self,
+        id: &CommentID,
+        body: &str,
+    ) -> Result<CommentModel, UpdateCommentError> {
+        let updated_comment = self.repository.update(id, body)?;
+        tracing::debug!(comment = ?updated_comment, "Updated comment");
+
+        Ok(updated_comment)
+    }
+}
+
+impl From<SaveCommentError> for UpdateCommentError {
+    fn from(e: SaveCommentError) -> Self {
+        match e {
+            SaveCommentError::UnknownComment => UpdateCommentError::UnknownComment,
+            _ => UpdateCommentError::UnknownError,
+        }
+    }
+}
@@ -0,0 +1,10 @@
+pub(crate) mod configure;
+pub(crate) mod endpoints;
+mod model;
+mod publish;
+mod repository;
+mod service;
+
+pub use model::*;
+pub(crate) use publish::*;
+pub use service::*;
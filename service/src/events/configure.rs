@@ -0,0 +1,42 @@
+use super::{repository::WorldEventsRepository, WorldEventsService};
+use crate::infrastructure::database::Database;
+use std::sync::Arc;
+
+/// Application Configuration for the World Events module
+pub struct WorldEventsConfig {
+    pub events_service: WorldEventsService,
+}
+
+impl WorldEventsConfig {
+    /// Create the Configuration for the World Events Module
+    ///
+    /// This starts listening for events straight away, so that they can be passed on to clients
+    ///
+    /// # Parameters
+    /// - `database` - The database connection to use
+    /// - `max_streams` - The maximum number of event streams that can be open at once
+    ///
+    /// # Returns
+    /// The World Events Config object
+    pub fn new(database: Database, max_streams: usize) -> Self {
+        let repository = WorldEventsRepository::new(database);
+        let events_service = WorldEventsService::new(repository, max_streams);
+        events_service.start_listening();
+
+        Self { events_service }
+    }
+
+    /// Generate the configuration callback needed for the HTTP Server to actually add the World Events
+    /// endpoints to the server
+    ///
+    /// # Returns
+    /// The callback to provide to the HTTP Server to configure up the World Events endpoints
+    pub fn configure(&self) -> Arc<dyn Fn(rocket::Rocket) -> rocket::Rocket + Send + Sync> {
+        let events_service = self.events_service.clone();
+        Arc::new(move |config| {
+            config
+                .manage(events_service.clone())
+                .mount("/", rocket::routes![super::endpoints::world_events])
+        })
+    }
+}
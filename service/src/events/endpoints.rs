@@ -0,0 +1,5 @@
+mod errors;
+pub(crate) mod model;
+mod world_events;
+
+pub use world_events::*;
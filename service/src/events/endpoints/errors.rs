@@ -0,0 +1,22 @@
+use crate::http::problem::ProblemType;
+
+/// Problem Types that can happen when working with world events
+#[derive(Debug, thiserror::Error)]
+pub enum WorldEventsProblemType {
+    /// Too many event streams are already open
+    #[error("Too many event streams are already open")]
+    TooManyStreams,
+}
+
+impl ProblemType for WorldEventsProblemType {
+    /// Generate a Type value for the `ProblemType` values.
+    ///
+    /// These are used in the `type` field in the RFC-7807 Problem Response
+    fn error_code(&self) -> &'static str {
+        match self {
+            WorldEventsProblemType::TooManyStreams => {
+                "tag:multiverse,2020:events/problems/too_many_streams"
+            }
+        }
+    }
+}
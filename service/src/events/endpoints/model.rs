@@ -0,0 +1,5 @@
+mod event_stream;
+mod last_event_id;
+
+pub use event_stream::*;
+pub use last_event_id::*;
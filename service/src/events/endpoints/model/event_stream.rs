@@ -0,0 +1,176 @@
+use crate::events::{Subscription, WorldEvent};
+use rocket::{
+    http::{
+        hyper::header::{CacheControl, CacheDirective},
+        ContentType,
+    },
+    response, Request,
+};
+use serde_json::json;
+use std::{
+    io::{Error, ErrorKind, Read},
+    time::Duration,
+};
+
+/// The largest chunk that is written to the client at once
+const CHUNK_SIZE: u64 = 4096;
+
+/// How long to go without any events before sending something anyway, so that proxies and clients
+/// don't decide that the connection has died
+const KEEP_ALIVE: Duration = Duration::from_secs(30);
+
+/// How long clients should wait before reconnecting if the stream is lost, in milliseconds
+const RETRY_MILLIS: u64 = 3000;
+
+/// Responder to stream the events happening within a world to the client as Server-Sent Events
+#[derive(Debug)]
+pub struct EventStream(pub Subscription);
+
+impl<'r> response::Responder<'r> for EventStream {
+    fn respond_to(self, _: &Request) -> response::Result<'r> {
+        response::Response::build()
+            .header(ContentType::new("text", "event-stream"))
+            .header(CacheControl(vec![CacheDirective::NoCache]))
+            .chunked_body(
+                EventStreamReader {
+                    subscription: self.0,
+                    frame: format!("retry: {}\n", RETRY_MILLIS).into_bytes(),
+                    position: 0,
+                    flushed: false,
+                },
+                CHUNK_SIZE,
+            )
+            .ok()
+    }
+}
+
+/// Reader that produces the body of the event stream, blocking until there is something to send
+struct EventStreamReader {
+    /// The subscription to read events from
+    subscription: Subscription,
+    /// The frame currently being sent
+    frame: Vec<u8>,
+    /// How much of the current frame has been sent so far
+    position: usize,
+    /// Whether the server has been told to flush the current frame to the client
+    flushed: bool,
+}
+
+impl Read for EventStreamReader {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        if self.position >= self.frame.len() {
+            // The HTTP server otherwise buffers the response until a whole chunk has been read, so
+            // signal that the frame should be flushed to the client before waiting for the next one
+            if !self.flushed {
+                self.flushed = true;
+                return Err(Error::new(ErrorKind::WouldBlock, "End of frame"));
+            }
+
+            let frame = match self.subscription.next_event(KEEP_ALIVE) {
+                Ok(Some(event)) => format_event(&event),
+                Ok(None) => ":\n".to_owned(),
+                Err(_) => return Ok(0),
+            };
+            self.frame = frame.into_bytes();
+            self.position = 0;
+            self.flushed = false;
+        }
+
+        let remaining = &self.frame[self.position..];
+        let count = remaining.len().min(buf.len());
+        buf[..count].copy_from_slice(&remaining[..count]);
+        self.position += count;
+
+        Ok(count)
+    }
+}
+
+/// Format a single event as a Server-Sent Event
+///
+/// # Parameters
+/// - `event` - The event to format
+///
+/// # Returns
+/// The formatted event
+fn format_event(event: &WorldEvent) -> String {
+    let data = json!({
+      "type": event.change.entity_type.to_string(),
+      "id": event.change.entity_id,
+      "version": event.change.version
+    });
+
+    format!("id: {}\nevent: change\ndata: {}\n\n", event.id, data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        events::{EntityType, EventID, SubscriptionPermit, WorldChange},
+        worlds::WorldID,
+    };
+    use chrono::Utc;
+    use std::sync::{mpsc::channel, Arc};
+    use uuid::Uuid;
+
+    #[test]
+    fn test_format_event() {
+        let event = WorldEvent {
+            id: EventID(42),
+            created: Utc::now(),
+            change: WorldChange {
+                world: WorldID::default(),
+                entity_type: EntityType::Table,
+                entity_id: Uuid::parse_str("d6c124d5-1060-48ef-aeb8-e591a8261c2b").unwrap(),
+                version: None,
+            },
+        };
+
+        assert_eq!(
+            format_event(&event),
+            "id: 42\nevent: change\ndata: {\"id\":\"d6c124d5-1060-48ef-aeb8-e591a8261c2b\",\"type\":\"table\",\"version\":null}\n\n"
+        );
+    }
+
+    #[test]
+    fn test_read_stream() {
+        let (sender, receiver) = channel();
+        let mut reader = EventStreamReader {
+            subscription: Subscription {
+                backlog: vec![WorldEvent {
+                    id: EventID(7),
+                    created: Utc::now(),
+                    change: WorldChange {
+                        world: WorldID::default(),
+                        entity_type: EntityType::Comment,
+                        entity_id: Uuid::new_v4(),
+                        version: Some(Uuid::new_v4()),
+                    },
+                }],
+                receiver,
+                last_seen: Some(EventID(6)),
+                _permit: SubscriptionPermit::acquire(&Arc::default(), 1).unwrap(),
+            },
+            frame: b"retry: 3000\n".to_vec(),
+            position: 0,
+            flushed: false,
+        };
+        drop(sender);
+
+        // Read the whole stream, noting where the reader asked for a flush
+        let mut body = String::new();
+        let mut buf = [0; 16];
+        loop {
+            match reader.read(&mut buf) {
+                Ok(0) => break,
+                Ok(count) => body.push_str(std::str::from_utf8(&buf[..count]).unwrap()),
+                Err(e) if e.kind() == ErrorKind::WouldBlock => body.push('|'),
+                Err(e) => panic!("Unexpected error: {}", e),
+            }
+        }
+
+        assert!(body.starts_with("retry: 3000\n|id: 7\nevent: change\ndata: {"));
+        assert!(body.contains("\"type\":\"comment\""));
+        assert!(body.ends_with("}\n\n|"));
+    }
+}
@@ -0,0 +1,31 @@
+use crate::events::EventID;
+use rocket::request::{FromRequest, Outcome, Request};
+
+/// The ID of the last event that a client has seen, as provided in the `Last-Event-ID` header when
+/// an event stream is reconnected
+#[derive(Debug)]
+pub struct LastEventID(pub Option<EventID>);
+
+impl<'a, 'r> FromRequest<'a, 'r> for LastEventID {
+    /// No error is ever returned
+    type Error = ();
+
+    /// Load the Last Event ID from the HTTP Request
+    ///
+    /// If the header is missing or isn't a valid Event ID then it is treated as absent, and the client
+    /// will only receive events from now on.
+    ///
+    /// # Parameters
+    /// - `request` - The HTTP Request to load the header from
+    ///
+    /// # Returns
+    /// The last event ID
+    fn from_request(request: &'a Request<'r>) -> Outcome<Self, Self::Error> {
+        let last_event_id = request
+            .headers()
+            .get_one("Last-Event-ID")
+            .and_then(|value| value.parse().ok());
+
+        Outcome::Success(LastEventID(last_event_id))
+    }
+}
@@ -0,0 +1,67 @@
+use super::{
+    errors::WorldEventsProblemType,
+    model::{EventStream, LastEventID},
+};
+use crate::{
+    authorization::Authorizer,
+    events::{Audience, SubscribeError, WorldEventsService},
+    http::problem::Problem,
+    moderation::ModerationService,
+    worlds::{endpoints::world_owner::authorize_world_viewer, WorldID, WorldsService},
+};
+use rocket::{get, http::Status, State};
+
+/// Handler to stream the changes happening within a World as Server-Sent Events.
+///
+/// If the client provides a `Last-Event-ID` header then every change since that event is sent first,
+/// so that nothing is missed when reconnecting. Only changes to things that the current user is allowed
+/// to see are sent
+///
+/// # Parameters
+/// - `events_service` - The world events service to use
+/// - `worlds_service` - The worlds service to use
+/// - `moderation_service` - The moderation service to use
+/// - `world` - The ID of the world to watch
+/// - `last_event_id` - The ID of the last event that the client has seen
+/// - `authorizer` - The authorizer to identify the current user
+///
+/// # Returns
+/// The stream of events, or a Problem if the world doesn't exist or too many streams are already open
+#[tracing::instrument(
+    name = "GET /worlds/{id}/events",
+    skip(events_service, worlds_service, moderation_service)
+)]
+#[get("/worlds/<world>/events")]
+pub fn world_events(
+    events_service: State<WorldEventsService>,
+    worlds_service: State<WorldsService>,
+    moderation_service: State<ModerationService>,
+    world: WorldID,
+    last_event_id: LastEventID,
+    authorizer: Authorizer,
+) -> Result<EventStream, Problem> {
+    let viewer = authorizer.authorize().finish()?;
+    let world = authorize_world_viewer(&worlds_service, &authorizer, &world)?;
+
+    let audience = Audience {
+        editor: worlds_service.is_editor(&world, viewer.as_ref()),
+        moderator: viewer
+            .as_ref()
+            .map(|viewer| moderation_service.is_moderator(viewer))
+            .unwrap_or(false),
+    };
+
+    let subscription = events_service.subscribe(&world.identity.id, audience, last_event_id.0)?;
+    Ok(EventStream(subscription))
+}
+
+impl From<SubscribeError> for Problem {
+    fn from(e: SubscribeError) -> Self {
+        match e {
+            SubscribeError::TooManySubscriptions => Problem::new(
+                WorldEventsProblemType::TooManyStreams,
+                Status::ServiceUnavailable,
+            ),
+        }
+    }
+}
@@ -0,0 +1,11 @@
+mod entity_type;
+mod event_id;
+mod subscription;
+mod visibility;
+mod world_event;
+
+pub use entity_type::*;
+pub use event_id::*;
+pub use subscription::*;
+pub use visibility::*;
+pub use world_event::*;
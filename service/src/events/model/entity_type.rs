@@ -0,0 +1,19 @@
+use strum_macros::{Display, EnumString};
+
+/// The types of entity within a world that changes are published for
+#[derive(Debug, PartialEq, Clone, Display, EnumString)]
+#[strum(serialize_all = "snake_case")]
+pub enum EntityType {
+    /// A random table
+    Table,
+    /// A campaign
+    Campaign,
+    /// A session of a campaign
+    Session,
+    /// A comment
+    Comment,
+    /// An attached file
+    Attachment,
+    /// A character
+    Character,
+}
@@ -0,0 +1,47 @@
+use std::{
+    fmt::{Display, Formatter},
+    num::ParseIntError,
+    str::FromStr,
+};
+
+/// The ID of a World Event.
+///
+/// These are allocated in increasing order, so that a client can resume from the last one it saw
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+pub struct EventID(pub(crate) i64);
+
+impl Display for EventID {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromStr for EventID {
+    type Err = ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.trim().parse().map(EventID)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_valid() {
+        assert_eq!("42".parse(), Ok(EventID(42)));
+        assert_eq!(" 42 ".parse(), Ok(EventID(42)));
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert!("".parse::<EventID>().is_err());
+        assert!("abc".parse::<EventID>().is_err());
+    }
+
+    #[test]
+    fn test_ordering() {
+        assert!(EventID(1) < EventID(2));
+    }
+}
@@ -0,0 +1,189 @@
+use super::{EventID, WorldEvent};
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc::Receiver,
+        Arc,
+    },
+    time::Duration,
+};
+
+/// A permit to hold one of a limited number of subscriptions open. The permit is given back when it is
+/// dropped
+#[derive(Debug)]
+pub struct SubscriptionPermit {
+    /// The number of permits currently held
+    held: Arc<AtomicUsize>,
+}
+
+impl SubscriptionPermit {
+    /// Try to acquire a permit
+    ///
+    /// # Parameters
+    /// - `held` - The number of permits currently held
+    /// - `limit` - The maximum number of permits that can be held at once
+    ///
+    /// # Returns
+    /// The permit, or `None` if the limit has already been reached
+    pub(crate) fn acquire(held: &Arc<AtomicUsize>, limit: usize) -> Option<Self> {
+        held.fetch_update(Ordering::SeqCst, Ordering::SeqCst, |count| {
+            if count < limit {
+                Some(count + 1)
+            } else {
+                None
+            }
+        })
+        .ok()
+        .map(|_| Self { held: held.clone() })
+    }
+}
+
+impl Drop for SubscriptionPermit {
+    fn drop(&mut self) {
+        self.held.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// A subscription to the events happening within a single world
+#[derive(Debug)]
+pub struct Subscription {
+    /// Events that happened before subscribing that the subscriber has not yet seen, oldest first
+    pub(crate) backlog: Vec<WorldEvent>,
+    /// The receiver for events that happen from now on
+    pub(crate) receiver: Receiver<WorldEvent>,
+    /// The ID of the last event that the subscriber has seen
+    pub(crate) last_seen: Option<EventID>,
+    /// The permit allowing this subscription to be open
+    pub(crate) _permit: SubscriptionPermit,
+}
+
+impl Subscription {
+    /// Wait for the next event that the subscriber has not yet seen.
+    ///
+    /// Events from the backlog are returned first, and after that events as they happen. Events that
+    /// arrive live but that were already returned as part of the backlog are skipped.
+    ///
+    /// # Parameters
+    /// - `timeout` - How long to wait for an event to happen
+    ///
+    /// # Returns
+    /// - `Ok(Some(event))` - The next event
+    /// - `Ok(None)` - No event happened within the timeout
+    /// - `Err(())` - No more events will ever happen
+    pub fn next_event(&mut self, timeout: Duration) -> Result<Option<WorldEvent>, ()> {
+        let event = if self.backlog.is_empty() {
+            loop {
+                match self.receiver.recv_timeout(timeout) {
+                    Ok(event) if Some(event.id) <= self.last_seen => continue,
+                    Ok(event) => break event,
+                    Err(std::sync::mpsc::RecvTimeoutError::Timeout) => return Ok(None),
+                    Err(std::sync::mpsc::RecvTimeoutError::Disconnected) => return Err(()),
+                }
+            }
+        } else {
+            self.backlog.remove(0)
+        };
+
+        self.last_seen = Some(event.id);
+        Ok(Some(event))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        events::{EntityType, WorldChange},
+        worlds::WorldID,
+    };
+    use chrono::Utc;
+    use std::sync::mpsc::channel;
+    use uuid::Uuid;
+
+    fn permit() -> SubscriptionPermit {
+        SubscriptionPermit::acquire(&Arc::default(), 1).unwrap()
+    }
+
+    fn event(id: i64) -> WorldEvent {
+        WorldEvent {
+            id: EventID(id),
+            created: Utc::now(),
+            change: WorldChange {
+                world: WorldID::default(),
+                entity_type: EntityType::Table,
+                entity_id: Uuid::new_v4(),
+                version: Some(Uuid::new_v4()),
+            },
+        }
+    }
+
+    #[test]
+    fn test_backlog_then_live() {
+        let (sender, receiver) = channel();
+        let mut subscription = Subscription {
+            backlog: vec![event(1), event(2)],
+            receiver,
+            last_seen: None,
+            _permit: permit(),
+        };
+
+        sender.send(event(2)).unwrap();
+        sender.send(event(3)).unwrap();
+
+        let timeout = Duration::from_millis(10);
+        assert_eq!(
+            subscription.next_event(timeout).unwrap().unwrap().id,
+            EventID(1)
+        );
+        assert_eq!(
+            subscription.next_event(timeout).unwrap().unwrap().id,
+            EventID(2)
+        );
+        assert_eq!(
+            subscription.next_event(timeout).unwrap().unwrap().id,
+            EventID(3)
+        );
+        assert!(subscription.next_event(timeout).unwrap().is_none());
+
+        drop(sender);
+        assert!(subscription.next_event(timeout).is_err());
+    }
+
+    #[test]
+    fn test_skip_already_seen() {
+        let (sender, receiver) = channel();
+        let mut subscription = Subscription {
+            backlog: vec![],
+            receiver,
+            last_seen: Some(EventID(5)),
+            _permit: permit(),
+        };
+
+        sender.send(event(4)).unwrap();
+        sender.send(event(5)).unwrap();
+        sender.send(event(6)).unwrap();
+
+        let timeout = Duration::from_millis(10);
+        assert_eq!(
+            subscription.next_event(timeout).unwrap().unwrap().id,
+            EventID(6)
+        );
+    }
+
+    #[test]
+    fn test_permits() {
+        let held = Arc::default();
+
+        let first = SubscriptionPermit::acquire(&held, 2).unwrap();
+        let second = SubscriptionPermit::acquire(&held, 2).unwrap();
+        assert!(SubscriptionPermit::acquire(&held, 2).is_none());
+
+        drop(first);
+        let third = SubscriptionPermit::acquire(&held, 2);
+        assert!(third.is_some());
+
+        drop(second);
+        drop(third);
+        assert_eq!(held.load(Ordering::SeqCst), 0);
+    }
+}
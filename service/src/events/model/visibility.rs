@@ -0,0 +1,94 @@
+/// Who a subscription to the events of a world is for, which decides which events they may see
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Audience {
+    /// Whether the subscriber is an editor of the world, and so can see everything within it
+    pub editor: bool,
+    /// Whether the subscriber is a moderator, and so can see content that has been hidden
+    pub moderator: bool,
+}
+
+/// Whether the entity that an event is about can currently be seen by those who aren't editors of
+/// the world
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EntityVisibility {
+    /// Whether the entity, or whatever it belongs to, has been published
+    pub published: bool,
+    /// Whether the entity has been hidden by a moderator
+    pub hidden: bool,
+}
+
+impl Default for EntityVisibility {
+    fn default() -> Self {
+        Self {
+            published: true,
+            hidden: false,
+        }
+    }
+}
+
+impl EntityVisibility {
+    /// Check if events about the entity may be sent to the given audience.
+    ///
+    /// This follows the same rules as reading the entity directly: editors see everything, everyone
+    /// else only sees published entities, and hidden entities are only seen by moderators
+    ///
+    /// # Parameters
+    /// - `audience` - Who the events would be sent to
+    ///
+    /// # Returns
+    /// `true` if the events may be sent
+    pub fn is_visible_to(&self, audience: &Audience) -> bool {
+        audience.editor || (self.published && (!self.hidden || audience.moderator))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VIEWER: Audience = Audience {
+        editor: false,
+        moderator: false,
+    };
+    const EDITOR: Audience = Audience {
+        editor: true,
+        moderator: false,
+    };
+    const MODERATOR: Audience = Audience {
+        editor: false,
+        moderator: true,
+    };
+
+    #[test]
+    fn test_published() {
+        let visibility = EntityVisibility::default();
+
+        assert!(visibility.is_visible_to(&VIEWER));
+        assert!(visibility.is_visible_to(&EDITOR));
+        assert!(visibility.is_visible_to(&MODERATOR));
+    }
+
+    #[test]
+    fn test_unpublished() {
+        let visibility = EntityVisibility {
+            published: false,
+            hidden: false,
+        };
+
+        assert!(!visibility.is_visible_to(&VIEWER));
+        assert!(visibility.is_visible_to(&EDITOR));
+        assert!(!visibility.is_visible_to(&MODERATOR));
+    }
+
+    #[test]
+    fn test_hidden() {
+        let visibility = EntityVisibility {
+            published: true,
+            hidden: true,
+        };
+
+        assert!(!visibility.is_visible_to(&VIEWER));
+        assert!(visibility.is_visible_to(&EDITOR));
+        assert!(visibility.is_visible_to(&MODERATOR));
+    }
+}
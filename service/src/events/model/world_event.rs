@@ -0,0 +1,28 @@
+use super::{EntityType, EventID};
+use crate::worlds::WorldID;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+/// Details of a change to some entity within a world
+#[derive(Debug, Clone)]
+pub struct WorldChange {
+    /// The world that the entity belongs to
+    pub world: WorldID,
+    /// The type of entity that changed
+    pub entity_type: EntityType,
+    /// The ID of the entity that changed
+    pub entity_id: Uuid,
+    /// The new version of the entity, or `None` if it was deleted
+    pub version: Option<Uuid>,
+}
+
+/// A change to some entity within a world that has been published
+#[derive(Debug, Clone)]
+pub struct WorldEvent {
+    /// The ID of the event
+    pub id: EventID,
+    /// When the event happened
+    pub created: DateTime<Utc>,
+    /// The change that happened
+    pub change: WorldChange,
+}
@@ -0,0 +1,36 @@
+use super::WorldChange;
+use chrono::Utc;
+
+/// The Postgres channel that world events are announced on
+pub(super) const CHANNEL: &str = "world_events";
+
+/// Publish a change to some entity within a world, so that anyone watching the world is told about it.
+///
+/// The event is recorded so that clients can resume from where they left off, and then announced with
+/// `NOTIFY` so that every server instance can push it out to the clients watching the world.
+/// Failing to publish an event is never allowed to fail the change that caused it.
+///
+/// # Parameters
+/// - `connection` - The database connection that the change was made on
+/// - `change` - The change to publish
+pub(crate) fn publish_change(connection: &mut postgres::Client, change: WorldChange) {
+    let result = connection
+        .query_one(
+            "INSERT INTO world_events(created, world_id, entity_type, entity_id, version) VALUES($1, $2, $3, $4, $5) RETURNING event_id",
+            &[
+                &Utc::now(),
+                &change.world,
+                &change.entity_type.to_string(),
+                &change.entity_id,
+                &change.version,
+            ],
+        )
+        .and_then(|row| {
+            let event_id: i64 = row.get("event_id");
+            connection.execute("SELECT pg_notify($1, $2)", &[&CHANNEL, &event_id.to_string()])
+        });
+
+    if let Err(e) = result {
+        tracing::warn!(error = ?e, change = ?change, "Failed to publish world change");
+    }
+}
@@ -0,0 +1,27 @@
+mod cleanup;
+mod find;
+mod listen;
+mod parse;
+mod visibility;
+
+use crate::infrastructure::database::Database;
+
+/// Repository used to access World Events from the database
+#[derive(Clone)]
+pub(super) struct WorldEventsRepository {
+    /// The database with which to access world event records
+    database: Database,
+}
+
+impl WorldEventsRepository {
+    /// Create a new World Events Repository to work with
+    ///
+    /// # Parameters
+    /// - `database` - The database connection to use
+    ///
+    /// # Returns
+    /// The World Events Repository to use
+    pub(super) fn new(database: Database) -> Self {
+        Self { database }
+    }
+}
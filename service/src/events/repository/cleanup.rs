@@ -0,0 +1,23 @@
+use super::WorldEventsRepository;
+use chrono::{DateTime, Utc};
+
+impl WorldEventsRepository {
+    /// Delete all of the world events that happened before the given time
+    ///
+    /// # Parameters
+    /// - `before` - The time to delete events from before
+    ///
+    /// # Returns
+    /// The number of events that were deleted
+    #[tracing::instrument(skip(self))]
+    pub fn delete_events_before(&self, before: &DateTime<Utc>) -> u64 {
+        let mut connection = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+
+        connection
+            .execute("DELETE FROM world_events WHERE created < $1", &[before])
+            .expect("Failed to delete old world events")
+    }
+}
@@ -0,0 +1,60 @@
+use super::WorldEventsRepository;
+use crate::{
+    events::{EventID, WorldEvent},
+    worlds::WorldID,
+};
+
+impl WorldEventsRepository {
+    /// Find the world event with the given ID
+    ///
+    /// # Parameters
+    /// - `id` - The ID of the event
+    ///
+    /// # Returns
+    /// The event, if it exists
+    #[tracing::instrument(skip(self))]
+    pub fn find_event_by_id(&self, id: &EventID) -> Option<WorldEvent> {
+        let mut connection = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+
+        connection
+            .query_opt("SELECT * FROM world_events WHERE event_id = $1", &[&id.0])
+            .expect("Failed to query for world event")
+            .map(|row| self.parse_row(&row))
+    }
+
+    /// Find the events for a world that happened after the given one.
+    ///
+    /// If more than `limit` events have happened since then, only the most recent of them are
+    /// returned, so that there is never a gap between these and the events that happen next
+    ///
+    /// # Parameters
+    /// - `world` - The ID of the world
+    /// - `after` - The ID of the last event that has already been seen
+    /// - `limit` - The most events to return
+    ///
+    /// # Returns
+    /// The events, oldest first
+    #[tracing::instrument(skip(self))]
+    pub fn find_events_after(
+        &self,
+        world: &WorldID,
+        after: &EventID,
+        limit: i64,
+    ) -> Vec<WorldEvent> {
+        let mut connection = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+
+        connection
+            .query(
+                "SELECT * FROM (SELECT * FROM world_events WHERE world_id = $1 AND event_id > $2 ORDER BY event_id DESC LIMIT $3) e ORDER BY event_id ASC",
+                &[world, &after.0, &limit],
+            )
+            .map(|rows| rows.iter().map(|row| self.parse_row(row)).collect())
+            .expect("Failed to query for world events")
+    }
+}
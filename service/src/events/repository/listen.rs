@@ -0,0 +1,67 @@
+use super::WorldEventsRepository;
+use crate::{
+    events::{publish::CHANNEL, EventID},
+    infrastructure::database::DatabaseError,
+};
+use fallible_iterator::FallibleIterator;
+use std::time::Duration;
+
+impl WorldEventsRepository {
+    /// Listen for world events being announced by any server instance.
+    ///
+    /// This uses a dedicated connection rather than one from the pool, since it is held for as long as
+    /// the service is running. It blocks the calling thread for as long as the callback returns `true`, and so should be
+    /// run on a thread of its own. The callback is also called with `None` periodically when nothing
+    /// has happened, so that it can decide whether to keep listening.
+    ///
+    /// # Parameters
+    /// - `poll` - How long to wait for an event before calling the callback with `None`
+    /// - `callback` - The callback to call with the ID of each event that is announced
+    ///
+    /// # Errors
+    /// If the connection to the database fails in any way
+    pub fn listen<F>(&self, poll: Duration, mut callback: F) -> Result<(), ListenError>
+    where
+        F: FnMut(Option<EventID>) -> bool,
+    {
+        let mut connection = self.database.connect()?;
+
+        connection.batch_execute(&format!("LISTEN {}", CHANNEL))?;
+        tracing::debug!(channel = CHANNEL, "Listening for world events");
+
+        loop {
+            let mut notifications = connection.notifications();
+            let mut iter = notifications.timeout_iter(poll);
+
+            let next = match iter.next()? {
+                None => None,
+                Some(notification) => match notification.payload().parse() {
+                    Ok(id) => Some(id),
+                    Err(e) => {
+                        tracing::warn!(error = ?e, payload = notification.payload(), "Invalid world event notification");
+                        continue;
+                    }
+                },
+            };
+
+            if !callback(next) {
+                break;
+            }
+        }
+
+        connection.batch_execute("UNLISTEN *")?;
+        Ok(())
+    }
+}
+
+/// Errors that can happen when listening for world events
+#[derive(Debug, thiserror::Error)]
+pub enum ListenError {
+    /// The connection to listen on couldn't be opened
+    #[error("Failed to connect to the database: {0}")]
+    Connect(#[from] DatabaseError),
+
+    /// The connection failed while listening
+    #[error("Error while listening: {0}")]
+    Listen(#[from] postgres::Error),
+}
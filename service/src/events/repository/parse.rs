@@ -0,0 +1,27 @@
+use super::WorldEventsRepository;
+use crate::events::{EventID, WorldChange, WorldEvent};
+use postgres::Row;
+
+impl WorldEventsRepository {
+    /// Parse a row from the database into a World Event
+    ///
+    /// # Parameters
+    /// - `row` - The row to parse
+    ///
+    /// # Returns
+    /// The world event
+    pub(super) fn parse_row(&self, row: &Row) -> WorldEvent {
+        let entity_type: String = row.get("entity_type");
+
+        WorldEvent {
+            id: EventID(row.get("event_id")),
+            created: row.get("created"),
+            change: WorldChange {
+                world: row.get("world_id"),
+                entity_type: entity_type.parse().expect("Invalid entity type"),
+                entity_id: row.get("entity_id"),
+                version: row.get("version"),
+            },
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use super::WorldEventsRepository;
+use crate::{
+    events::{EntityType, EntityVisibility, WorldChange},
+    model::published_sql,
+};
+
+impl WorldEventsRepository {
+    /// Find whether the entity that a change was made to can currently be seen by those who aren't
+    /// editors of the world.
+    ///
+    /// Entities that no longer exist, and types of entity that are always visible within a world, are
+    /// treated as visible, so that everyone watching the world is told when they are removed
+    ///
+    /// # Parameters
+    /// - `change` - The change to check
+    ///
+    /// # Returns
+    /// The visibility of the entity
+    #[tracing::instrument(skip(self))]
+    pub fn find_entity_visibility(&self, change: &WorldChange) -> EntityVisibility {
+        let sql = match change.entity_type {
+            EntityType::Table => format!(
+                "SELECT {} AS published, FALSE AS hidden FROM random_tables WHERE table_id = $1",
+                published_sql("random_tables")
+            ),
+            EntityType::Campaign => format!(
+                "SELECT {} AS published, FALSE AS hidden FROM campaigns WHERE campaign_id = $1",
+                published_sql("campaigns")
+            ),
+            EntityType::Session => format!(
+                "SELECT {} AS published, FALSE AS hidden FROM sessions JOIN campaigns ON campaigns.campaign_id = sessions.campaign_id WHERE sessions.session_id = $1",
+                published_sql("campaigns")
+            ),
+            EntityType::Character => format!(
+                "SELECT {} AS published, FALSE AS hidden FROM characters WHERE character_id = $1",
+                published_sql("characters")
+            ),
+            EntityType::Comment => {
+                "SELECT TRUE AS published, hidden FROM comments WHERE comment_id = $1".to_owned()
+            }
+            EntityType::Attachment => return EntityVisibility::default(),
+        };
+
+        let mut connection = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+
+        connection
+            .query_opt(sql.as_str(), &[&change.entity_id])
+            .expect("Failed to query for entity visibility")
+            .map(|row| EntityVisibility {
+                published: row.get("published"),
+                hidden: row.get("hidden"),
+            })
+            .unwrap_or_default()
+    }
+}
@@ -0,0 +1,47 @@
+mod listen;
+mod subscribe;
+
+use super::{repository::WorldEventsRepository, Audience, WorldEvent};
+use crate::worlds::WorldID;
+use std::sync::{atomic::AtomicUsize, mpsc::Sender, Arc, Mutex};
+pub use subscribe::SubscribeError;
+
+/// A single client that is watching a world for events
+struct Subscriber {
+    /// The world being watched
+    world: WorldID,
+    /// Who the client is, which decides which events they may see
+    audience: Audience,
+    /// The means to send events to the client
+    sender: Sender<WorldEvent>,
+}
+
+/// Service Layer for dealing with World Events
+#[derive(Clone)]
+pub struct WorldEventsService {
+    repository: WorldEventsRepository,
+    subscribers: Arc<Mutex<Vec<Subscriber>>>,
+    /// The number of subscriptions that are currently open
+    open_subscriptions: Arc<AtomicUsize>,
+    /// The maximum number of subscriptions that can be open at once
+    max_subscriptions: usize,
+}
+
+impl WorldEventsService {
+    /// Create a new instance of the World Events Service
+    ///
+    /// # Parameters
+    /// - `repository` - The repository to access world events with
+    /// - `max_subscriptions` - The maximum number of subscriptions that can be open at once
+    ///
+    /// # Returns
+    /// The World Events Service ready to use
+    pub(super) fn new(repository: WorldEventsRepository, max_subscriptions: usize) -> Self {
+        Self {
+            repository,
+            subscribers: Arc::new(Mutex::new(vec![])),
+            open_subscriptions: Arc::new(AtomicUsize::new(0)),
+            max_subscriptions,
+        }
+    }
+}
@@ -0,0 +1,108 @@
+use super::{Subscriber, WorldEventsService};
+use crate::events::{repository::WorldEventsRepository, EventID};
+use chrono::Utc;
+use std::{
+    sync::{Arc, Mutex, Weak},
+    time::{Duration, Instant},
+};
+
+/// How often to check whether the service is still in use when no events are happening
+const POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// How long to wait before listening again after losing the database connection
+const RECONNECT_DELAY: Duration = Duration::from_secs(5);
+
+/// How long world events are kept for, so that clients can catch up on what they missed
+const RETENTION: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+
+/// How often to delete the world events that are older than the retention period
+const CLEANUP_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+impl WorldEventsService {
+    /// Start listening for world events on a background thread, passing each one on to the
+    /// subscribers watching the world that it happened in. The same thread periodically deletes the
+    /// events that are older than the retention period.
+    ///
+    /// The thread stops once the service, and every clone of it, has been dropped
+    pub(crate) fn start_listening(&self) {
+        let repository = self.repository.clone();
+        let subscribers = Arc::downgrade(&self.subscribers);
+
+        std::thread::spawn(move || {
+            let mut last_cleanup: Option<Instant> = None;
+
+            loop {
+                let result = repository.listen(POLL_INTERVAL, |id| match subscribers.upgrade() {
+                    None => false,
+                    Some(subscribers) => {
+                        if let Some(id) = id {
+                            broadcast(&repository, &subscribers, &id);
+                        }
+                        if last_cleanup.map_or(true, |last| last.elapsed() >= CLEANUP_INTERVAL) {
+                            cleanup(&repository);
+                            last_cleanup = Some(Instant::now());
+                        }
+                        true
+                    }
+                });
+
+                match result {
+                    Ok(_) => break,
+                    Err(e) => {
+                        tracing::warn!(error = ?e, "Lost connection listening for world events");
+                        std::thread::sleep(RECONNECT_DELAY);
+                        if Weak::strong_count(&subscribers) == 0 {
+                            break;
+                        }
+                    }
+                }
+            }
+        });
+    }
+}
+
+/// Send the event with the given ID to every subscriber watching the world that it happened in that
+/// is allowed to see it, and forget about any subscribers that have gone away
+///
+/// # Parameters
+/// - `repository` - The repository to load the event from
+/// - `subscribers` - The subscribers to send the event to
+/// - `id` - The ID of the event
+fn broadcast(
+    repository: &WorldEventsRepository,
+    subscribers: &Mutex<Vec<Subscriber>>,
+    id: &EventID,
+) {
+    let event = match repository.find_event_by_id(id) {
+        Some(event) => event,
+        None => return,
+    };
+    tracing::debug!(event = ?event, "Broadcasting world event");
+
+    // Only look up whether the entity is visible if there is someone that needs to know
+    let mut visibility = None;
+
+    let mut subscribers = subscribers.lock().unwrap();
+    subscribers.retain(|subscriber| {
+        if subscriber.world != event.change.world {
+            return true;
+        }
+
+        let visible = subscriber.audience.editor
+            || visibility
+                .get_or_insert_with(|| repository.find_entity_visibility(&event.change))
+                .is_visible_to(&subscriber.audience);
+
+        !visible || subscriber.sender.send(event.clone()).is_ok()
+    });
+}
+
+/// Delete the world events that are older than the retention period
+///
+/// # Parameters
+/// - `repository` - The repository to delete the events from
+fn cleanup(repository: &WorldEventsRepository) {
+    let before = Utc::now() - chrono::Duration::from_std(RETENTION).unwrap();
+    let deleted = repository.delete_events_before(&before);
+    tracing::debug!(deleted = deleted, before = ?before, "Deleted old world events");
+}
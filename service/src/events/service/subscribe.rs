@@ -0,0 +1,84 @@
+use super::{Subscriber, WorldEventsService};
+use crate::{
+    events::{Audience, EventID, Subscription, SubscriptionPermit},
+    worlds::WorldID,
+};
+use std::sync::mpsc::channel;
+
+/// The most events that are sent to a subscriber that is catching up on what they missed. If more
+/// than this have happened then only the most recent are sent
+const MAX_BACKLOG: i64 = 1000;
+
+/// Errors that can occur when subscribing to the events happening within a world
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum SubscribeError {
+    /// The maximum number of subscriptions are already open
+    #[error("Too many subscriptions are already open")]
+    TooManySubscriptions,
+}
+
+impl WorldEventsService {
+    /// Subscribe to the events happening within a world.
+    ///
+    /// Only events about entities that the audience is allowed to see are included, both in the
+    /// backlog and as they happen
+    ///
+    /// # Parameters
+    /// - `world` - The ID of the world to watch
+    /// - `audience` - Who the subscription is for
+    /// - `last_event` - The ID of the last event that the subscriber has already seen, if any
+    ///
+    /// # Returns
+    /// The subscription, including any events since the last one that was seen
+    ///
+    /// # Errors
+    /// If the maximum number of subscriptions are already open
+    pub fn subscribe(
+        &self,
+        world: &WorldID,
+        audience: Audience,
+        last_event: Option<EventID>,
+    ) -> Result<Subscription, SubscribeError> {
+        let permit = SubscriptionPermit::acquire(&self.open_subscriptions, self.max_subscriptions)
+            .ok_or_else(|| {
+                tracing::warn!(
+                    limit = self.max_subscriptions,
+                    "Too many subscriptions open"
+                );
+                SubscribeError::TooManySubscriptions
+            })?;
+
+        let (sender, receiver) = channel();
+
+        // Subscribe before loading the backlog, so that nothing can be missed in between. Anything
+        // received twice is skipped by the subscription.
+        self.subscribers.lock().unwrap().push(Subscriber {
+            world: world.clone(),
+            audience,
+            sender,
+        });
+
+        let backlog = last_event
+            .map(|last_event| {
+                self.repository
+                    .find_events_after(world, &last_event, MAX_BACKLOG)
+            })
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|event| {
+                audience.editor
+                    || self
+                        .repository
+                        .find_entity_visibility(&event.change)
+                        .is_visible_to(&audience)
+            })
+            .collect();
+
+        Ok(Subscription {
+            backlog,
+            receiver,
+            last_seen: last_event,
+            _permit: permit,
+        })
+    }
+}
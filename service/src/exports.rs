@@ -0,0 +1,7 @@
+pub(crate) mod configure;
+pub(crate) mod endpoints;
+mod model;
+mod service;
+
+pub use model::*;
+pub use service::*;
@@ -0,0 +1,54 @@
+use super::ExportsService;
+use crate::{
+    attachments::AttachmentsService, campaigns::CampaignsService, characters::CharactersService,
+    tables::TablesService,
+};
+use std::sync::Arc;
+
+/// Application Configuration for the Exports module
+pub struct ExportsConfig {
+    pub exports_service: ExportsService,
+}
+
+impl ExportsConfig {
+    /// Create the Configuration for the Exports Module
+    ///
+    /// # Parameters
+    /// - `tables_service` - The tables service to read random tables with
+    /// - `campaigns_service` - The campaigns service to read campaigns and sessions with
+    /// - `characters_service` - The characters service to read characters with
+    /// - `attachments_service` - The attachments service to read attached files with
+    ///
+    /// # Returns
+    /// The Exports Config object
+    pub fn new(
+        tables_service: TablesService,
+        campaigns_service: CampaignsService,
+        characters_service: CharactersService,
+        attachments_service: AttachmentsService,
+    ) -> Self {
+        Self {
+            exports_service: ExportsService::new(
+                tables_service,
+                campaigns_service,
+                characters_service,
+                attachments_service,
+            ),
+        }
+    }
+
+    /// Generate the configuration callback needed for the HTTP Server to actually add the Exports
+    /// endpoints to the server
+    ///
+    /// # Returns
+    /// The callback to provide to the HTTP Server to configure up the Exports endpoints
+    pub fn configure(&self) -> Arc<dyn Fn(rocket::Rocket) -> rocket::Rocket + Send + Sync> {
+        let exports_service = self.exports_service.clone();
+        Arc::new(move |config| {
+            config.manage(exports_service.clone()).mount(
+                "/",
+                rocket::routes![super::endpoints::export_epub, super::endpoints::export_site],
+            )
+        })
+    }
+}
@@ -0,0 +1,7 @@
+mod errors;
+mod export_epub;
+mod export_site;
+pub(crate) mod model;
+
+pub use export_epub::*;
+pub use export_site::*;
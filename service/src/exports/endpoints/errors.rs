@@ -0,0 +1,20 @@
+use crate::http::problem::ValidationType;
+
+/// Validation errors that are specific to exports
+#[derive(Debug, thiserror::Error)]
+pub enum ExportValidation {
+    /// One of the requested sections was not a section that can be exported
+    #[error("The requested section was unknown")]
+    UnknownSection,
+}
+
+impl ValidationType for ExportValidation {
+    /// Generate a Type value for the `ValidationType` values.
+    fn error_code(&self) -> &'static str {
+        match self {
+            ExportValidation::UnknownSection => {
+                "tag:multiverse,2020:exports/problems/validation_error/unknown_section"
+            }
+        }
+    }
+}
@@ -0,0 +1,64 @@
+use super::{errors::ExportValidation, model::ArchiveResponse};
+use crate::{
+    authorization::Authorizer,
+    exports::{ExportSection, ExportsService},
+    http::problem::{Problem, ValidationProblem},
+    worlds::{endpoints::world_owner::authorize_world_viewer, WorldID, WorldsService},
+};
+use rocket::{get, http::ContentType, State};
+use strum::IntoEnumIterator;
+
+/// Handler to export a World as an EPUB 3 book, for reading on e-readers.
+///
+/// The book contains a chapter for each published piece of content in the world, grouped into sections.
+/// The sections to include, and the order they appear in the table of contents, can be chosen
+///
+/// # Parameters
+/// - `exports_service` - The exports service to use
+/// - `worlds_service` - The worlds service to use
+/// - `world` - The ID of the world to export
+/// - `contents` - A comma-separated list of the sections to include, in order. Defaults to all of them
+/// - `authorizer` - The authorizer to identify the current user
+///
+/// # Returns
+/// The EPUB file, or a Problem if the world doesn't exist or the request was invalid
+#[tracing::instrument(
+    name = "GET /worlds/{id}/export/epub",
+    skip(exports_service, worlds_service)
+)]
+#[get("/worlds/<world>/export/epub?<contents>")]
+pub fn export_epub(
+    exports_service: State<ExportsService>,
+    worlds_service: State<WorldsService>,
+    world: WorldID,
+    contents: Option<String>,
+    authorizer: Authorizer,
+) -> Result<ArchiveResponse, Problem> {
+    let sections: Vec<ExportSection> = match contents
+        .as_ref()
+        .filter(|contents| !contents.trim().is_empty())
+    {
+        None => ExportSection::iter().collect(),
+        Some(contents) => contents
+            .split(',')
+            .map(|section| section.trim().parse())
+            .collect::<Result<_, _>>()
+            .map_err(|_| {
+                tracing::warn!("Validation error exporting EPUB");
+                let mut problem = ValidationProblem::new();
+                problem.with_field_error("contents", ExportValidation::UnknownSection);
+                problem.build()
+            })?,
+    };
+
+    let world = authorize_world_viewer(&worlds_service, &authorizer, &world)?;
+    let filename = format!("{}.epub", world.data.url_slug);
+
+    let body = exports_service.export_epub(world, &sections);
+
+    Ok(ArchiveResponse {
+        filename,
+        content_type: ContentType::new("application", "epub+zip"),
+        body,
+    })
+}
@@ -0,0 +1,44 @@
+use super::model::ArchiveResponse;
+use crate::{
+    authorization::Authorizer,
+    exports::ExportsService,
+    http::problem::Problem,
+    worlds::{endpoints::world_owner::authorize_world_viewer, WorldID, WorldsService},
+};
+use rocket::{get, http::ContentType, State};
+
+/// Handler to export a World as a standalone website.
+///
+/// The world, its random tables and its campaigns are rendered to HTML pages, with wiki links between
+/// them resolved, and returned as a Zip archive. Only published content is included
+///
+/// # Parameters
+/// - `exports_service` - The exports service to use
+/// - `worlds_service` - The worlds service to use
+/// - `world` - The ID of the world to export
+/// - `authorizer` - The authorizer to identify the current user
+///
+/// # Returns
+/// The Zip archive of the website, or a Problem if the world doesn't exist
+#[tracing::instrument(
+    name = "GET /worlds/{id}/export/site",
+    skip(exports_service, worlds_service)
+)]
+#[get("/worlds/<world>/export/site")]
+pub fn export_site(
+    exports_service: State<ExportsService>,
+    worlds_service: State<WorldsService>,
+    world: WorldID,
+    authorizer: Authorizer,
+) -> Result<ArchiveResponse, Problem> {
+    let world = authorize_world_viewer(&worlds_service, &authorizer, &world)?;
+    let filename = format!("{}.zip", world.data.url_slug);
+
+    let body = exports_service.export_site(world);
+
+    Ok(ArchiveResponse {
+        filename,
+        content_type: ContentType::new("application", "zip"),
+        body,
+    })
+}
@@ -0,0 +1,3 @@
+mod archive_response;
+
+pub use archive_response::*;
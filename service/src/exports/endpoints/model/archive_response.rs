@@ -0,0 +1,36 @@
+use rocket::{
+    http::{
+        hyper::header::{CacheControl, CacheDirective},
+        ContentType,
+    },
+    response, Request,
+};
+use std::io::Cursor;
+
+/// API Model representing an exported archive that is downloaded as a file
+#[derive(Debug)]
+pub struct ArchiveResponse {
+    /// The name of the file that the archive should be saved as
+    pub filename: String,
+    /// The content type of the archive
+    pub content_type: ContentType,
+    /// The bytes of the archive
+    pub body: Vec<u8>,
+}
+
+impl<'r> response::Responder<'r> for ArchiveResponse {
+    fn respond_to(self, _: &Request) -> response::Result<'r> {
+        response::Response::build()
+            .header(self.content_type)
+            .raw_header(
+                "Content-Disposition",
+                format!("attachment; filename=\"{}\"", self.filename),
+            )
+            .header(CacheControl(vec![
+                CacheDirective::Private,
+                CacheDirective::NoCache,
+            ]))
+            .sized_body(Cursor::new(self.body))
+            .ok()
+    }
+}
@@ -0,0 +1,5 @@
+mod export_section;
+mod world_content;
+
+pub use export_section::*;
+pub use world_content::*;
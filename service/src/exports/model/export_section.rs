@@ -0,0 +1,30 @@
+use strum_macros::{Display, EnumIter, EnumString};
+
+/// The sections of a world that can be included in an exported book, in the order that they appear by
+/// default
+#[derive(Debug, PartialEq, Clone, Copy, Display, EnumString, EnumIter)]
+#[strum(serialize_all = "snake_case")]
+pub enum ExportSection {
+    /// The overview of the world itself
+    Overview,
+    /// The random tables in the world
+    Tables,
+    /// The campaigns run in the world
+    Campaigns,
+    /// The characters in the world
+    Characters,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!("overview".parse(), Ok(ExportSection::Overview));
+        assert_eq!("tables".parse(), Ok(ExportSection::Tables));
+        assert_eq!("campaigns".parse(), Ok(ExportSection::Campaigns));
+        assert_eq!("characters".parse(), Ok(ExportSection::Characters));
+        assert!("maps".parse::<ExportSection>().is_err());
+    }
+}
@@ -0,0 +1,46 @@
+use crate::{
+    attachments::AttachmentModel,
+    campaigns::{CampaignModel, SessionModel},
+    characters::CharacterModel,
+    tables::TableModel,
+    worlds::WorldModel,
+};
+
+/// All of the published content of a world, gathered together so that it can be exported
+#[derive(Debug)]
+pub struct WorldContent {
+    /// The world itself
+    pub world: WorldModel,
+
+    /// The random tables in the world, ordered by name
+    pub tables: Vec<TableModel>,
+
+    /// The campaigns run in the world, ordered by name
+    pub campaigns: Vec<CampaignContent>,
+
+    /// The characters in the world, ordered by name
+    pub characters: Vec<CharacterModel>,
+
+    /// The files attached to the world, newest first
+    pub attachments: Vec<AttachmentContent>,
+}
+
+/// A campaign within a world, along with the sessions that have been played
+#[derive(Debug)]
+pub struct CampaignContent {
+    /// The campaign
+    pub campaign: CampaignModel,
+
+    /// The sessions of the campaign, with the earliest played first
+    pub sessions: Vec<SessionModel>,
+}
+
+/// A file attached to a world, along with its contents
+#[derive(Debug)]
+pub struct AttachmentContent {
+    /// The attachment
+    pub attachment: AttachmentModel,
+
+    /// The contents of the file
+    pub contents: Vec<u8>,
+}
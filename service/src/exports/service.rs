@@ -0,0 +1,46 @@
+mod archive;
+mod content;
+mod epub;
+mod site;
+mod templates;
+mod wiki_links;
+
+use crate::{
+    attachments::AttachmentsService, campaigns::CampaignsService, characters::CharactersService,
+    tables::TablesService,
+};
+
+/// Service Layer for exporting the contents of worlds into other formats
+#[derive(Clone)]
+pub struct ExportsService {
+    tables: TablesService,
+    campaigns: CampaignsService,
+    characters: CharactersService,
+    attachments: AttachmentsService,
+}
+
+impl ExportsService {
+    /// Create a new instance of the Exports Service
+    ///
+    /// # Parameters
+    /// - `tables` - The tables service to read random tables with
+    /// - `campaigns` - The campaigns service to read campaigns and sessions with
+    /// - `characters` - The characters service to read characters with
+    /// - `attachments` - The attachments service to read attached files with
+    ///
+    /// # Returns
+    /// The Exports Service ready to use
+    pub(super) fn new(
+        tables: TablesService,
+        campaigns: CampaignsService,
+        characters: CharactersService,
+        attachments: AttachmentsService,
+    ) -> Self {
+        Self {
+            tables,
+            campaigns,
+            characters,
+            attachments,
+        }
+    }
+}
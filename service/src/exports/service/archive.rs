@@ -0,0 +1,132 @@
+use std::io::{Cursor, Write};
+use zip::{write::FileOptions, CompressionMethod, DateTime, ZipWriter};
+
+/// A single file to be written into an archive
+#[derive(Debug, PartialEq)]
+pub(super) struct ArchiveFile {
+    /// The path of the file within the archive
+    pub path: String,
+    /// The contents of the file
+    pub contents: Vec<u8>,
+    /// Whether the file is compressed within the archive
+    pub compressed: bool,
+}
+
+impl ArchiveFile {
+    /// Create a new file to write into an archive
+    ///
+    /// # Parameters
+    /// - `path` - The path of the file within the archive
+    /// - `contents` - The contents of the file
+    ///
+    /// # Returns
+    /// The file
+    pub(super) fn new<P, C>(path: P, contents: C) -> Self
+    where
+        P: Into<String>,
+        C: Into<Vec<u8>>,
+    {
+        Self {
+            path: path.into(),
+            contents: contents.into(),
+            compressed: true,
+        }
+    }
+
+    /// Create a new file to write into an archive without any compression, for formats that require
+    /// certain files to be readable without decompressing them
+    ///
+    /// # Parameters
+    /// - `path` - The path of the file within the archive
+    /// - `contents` - The contents of the file
+    ///
+    /// # Returns
+    /// The file
+    pub(super) fn stored<P, C>(path: P, contents: C) -> Self
+    where
+        P: Into<String>,
+        C: Into<Vec<u8>>,
+    {
+        Self {
+            compressed: false,
+            ..Self::new(path, contents)
+        }
+    }
+}
+
+/// Build a Zip archive containing the given files.
+///
+/// Every file is given the same fixed modification time, so that archiving the same files always
+/// produces exactly the same bytes
+///
+/// # Parameters
+/// - `files` - The files to write into the archive, in the order they should appear
+///
+/// # Returns
+/// The bytes of the archive
+pub(super) fn build_archive(files: &[ArchiveFile]) -> Vec<u8> {
+    let mut writer = ZipWriter::new(Cursor::new(vec![]));
+    for file in files {
+        let compression = if file.compressed {
+            CompressionMethod::Deflated
+        } else {
+            CompressionMethod::Stored
+        };
+        let options = FileOptions::default()
+            .compression_method(compression)
+            .last_modified_time(DateTime::default());
+
+        writer
+            .start_file(file.path.as_str(), options)
+            .expect("Failed to start file in archive");
+        writer
+            .write_all(&file.contents)
+            .expect("Failed to write file to archive");
+    }
+
+    writer
+        .finish()
+        .expect("Failed to finish archive")
+        .into_inner()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Read;
+    use zip::ZipArchive;
+
+    #[test]
+    fn test_build_archive() {
+        let archive = build_archive(&[
+            ArchiveFile::new("index.html", "<h1>Hello</h1>"),
+            ArchiveFile::new("tables/weather.html", "<h1>Weather</h1>"),
+        ]);
+
+        let mut zip = ZipArchive::new(Cursor::new(archive)).unwrap();
+        assert_eq!(zip.len(), 2);
+
+        let mut contents = String::new();
+        zip.by_name("tables/weather.html")
+            .unwrap()
+            .read_to_string(&mut contents)
+            .unwrap();
+        assert_eq!(contents, "<h1>Weather</h1>");
+    }
+
+    #[test]
+    fn test_build_archive_stored() {
+        let archive = build_archive(&[ArchiveFile::stored("mimetype", "application/epub+zip")]);
+
+        let mut zip = ZipArchive::new(Cursor::new(archive)).unwrap();
+        let file = zip.by_index(0).unwrap();
+        assert_eq!(file.name(), "mimetype");
+        assert_eq!(file.compression(), CompressionMethod::Stored);
+    }
+
+    #[test]
+    fn test_build_archive_is_deterministic() {
+        let files = vec![ArchiveFile::new("index.html", "<h1>Hello</h1>")];
+        assert_eq!(build_archive(&files), build_archive(&files));
+    }
+}
@@ -0,0 +1,131 @@
+use super::ExportsService;
+use crate::{
+    attachments::AttachmentModel,
+    exports::{AttachmentContent, CampaignContent, WorldContent},
+    model::fetch_all_pages,
+    worlds::WorldModel,
+};
+
+impl ExportsService {
+    /// Gather together all of the published content of a world.
+    ///
+    /// Attachments whose files can't be loaded are left out, rather than failing the whole export
+    ///
+    /// # Parameters
+    /// - `world` - The world to gather the content of
+    ///
+    /// # Returns
+    /// The content of the world
+    pub(super) fn gather_content(&self, world: WorldModel) -> WorldContent {
+        let tables = fetch_all_pages(|pagination| {
+            self.tables
+                .list_tables(&world.identity.id, false, pagination)
+        });
+
+        let campaigns = self
+            .campaigns
+            .find_campaigns_in_world(&world.identity.id, false)
+            .into_iter()
+            .map(|campaign| {
+                let sessions = self.campaigns.find_campaign_sessions(&campaign.identity.id);
+                CampaignContent { campaign, sessions }
+            })
+            .collect();
+
+        let characters = fetch_all_pages(|pagination| {
+            self.characters
+                .list_characters(&world.identity.id, false, pagination)
+        });
+
+        let attachments = fetch_all_pages(|pagination| {
+            self.attachments
+                .search_attachments(&world.identity.id, pagination)
+        })
+        .into_iter()
+        .filter_map(|attachment| {
+            let contents = self.attachments.load_content(&attachment)?;
+            Some(AttachmentContent {
+                attachment,
+                contents,
+            })
+        })
+        .collect();
+
+        WorldContent {
+            world,
+            tables,
+            campaigns,
+            characters,
+            attachments,
+        }
+    }
+}
+
+/// Generate the path of an attached file within an export, relative to the root of the export.
+///
+/// The path is built from the ID of the attachment, so that it is always safe to use whatever the file
+/// was called, and keeps the extension of the original file so that it can still be opened
+///
+/// # Parameters
+/// - `attachment` - The attachment
+///
+/// # Returns
+/// The path of the file
+pub(super) fn attachment_path(attachment: &AttachmentModel) -> String {
+    let extension = attachment
+        .data
+        .filename
+        .rfind('.')
+        .map(|dot| attachment.data.filename[dot + 1..].to_ascii_lowercase())
+        .filter(|extension| {
+            !extension.is_empty()
+                && extension.len() <= 8
+                && extension.chars().all(|c| c.is_ascii_alphanumeric())
+        })
+        .map(|extension| format!(".{}", extension))
+        .unwrap_or_default();
+
+    format!("attachments/{}{}", attachment.identity.id, extension)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{attachments::AttachmentData, model::Identity, users::UserID, worlds::WorldID};
+
+    fn attachment(filename: &str) -> AttachmentModel {
+        AttachmentModel {
+            identity: Identity::default(),
+            data: AttachmentData {
+                world: WorldID::default(),
+                owner: UserID::default(),
+                filename: filename.to_owned(),
+                content_type: "image/png".to_owned(),
+                size: 0,
+                hash: "".to_owned(),
+                has_thumbnail: false,
+            },
+        }
+    }
+
+    #[test]
+    fn test_attachment_path() {
+        let map = attachment("World Map.PNG");
+        assert_eq!(
+            attachment_path(&map),
+            format!("attachments/{}.png", map.identity.id)
+        );
+
+        let unsafe_name = attachment("../../etc/passwd");
+        assert_eq!(
+            attachment_path(&unsafe_name),
+            format!("attachments/{}", unsafe_name.identity.id)
+        );
+
+        let no_extension = attachment("README");
+        assert_eq!(
+            attachment_path(&no_extension),
+            format!("attachments/{}", no_extension.identity.id)
+        );
+    }
+}
@@ -0,0 +1,686 @@
+use super::{
+    archive::{build_archive, ArchiveFile},
+    content::attachment_path,
+    templates::{escape_html, load, render},
+    wiki_links::resolve_wiki_links,
+    ExportsService,
+};
+use crate::{
+    characters::CharacterModel,
+    exports::{AttachmentContent, CampaignContent, ExportSection, WorldContent},
+    http::{markdown, wiki_links::LinkTargets},
+    tables::TableModel,
+    worlds::WorldModel,
+};
+use std::iter::once;
+
+/// The elements that never have any content, and so must be self-closing in XHTML
+const VOID_ELEMENTS: &[&str] = &[
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "source", "track",
+    "wbr",
+];
+
+/// The image types that every EPUB reader must support. Only attachments of these types are included in
+/// a book, since anything else can't be displayed
+const IMAGE_TYPES: &[&str] = &[
+    "image/gif",
+    "image/jpeg",
+    "image/png",
+    "image/svg+xml",
+    "image/webp",
+];
+
+impl ExportsService {
+    /// Export the published content of a world as an EPUB 3 book.
+    ///
+    /// Images attached to the world are included so that they can be embedded in chapters, but any other
+    /// attached files are left out
+    ///
+    /// # Parameters
+    /// - `world` - The world to export
+    /// - `sections` - The sections to include in the book, in the order they should appear
+    ///
+    /// # Returns
+    /// The bytes of the EPUB file
+    pub fn export_epub(&self, world: WorldModel, sections: &[ExportSection]) -> Vec<u8> {
+        let content = self.gather_content(world);
+        tracing::debug!(world = ?content.world.identity.id, sections = ?sections, "Exporting world as an EPUB");
+
+        build_archive(&render_epub(&content, sections))
+    }
+}
+
+/// Convert HTML into XHTML, by making sure that every void element is self-closing and that no named
+/// entities other than those defined by XML are used
+///
+/// # Parameters
+/// - `html` - The HTML to convert
+///
+/// # Returns
+/// The equivalent XHTML
+fn to_xhtml(html: &str) -> String {
+    let mut result = String::with_capacity(html.len());
+    let mut remaining = html;
+
+    while let Some(start) = remaining.find('<') {
+        result.push_str(&remaining[..start]);
+        let tag = &remaining[start..];
+
+        let mut quote = None;
+        let end = tag.char_indices().find(|(_, c)| match (quote, c) {
+            (None, '"') | (None, '\'') => {
+                quote = Some(*c);
+                false
+            }
+            (Some(q), c) if q == *c => {
+                quote = None;
+                false
+            }
+            (None, '>') => true,
+            _ => false,
+        });
+
+        match end {
+            Some((end, _)) => {
+                let name: String = tag[1..]
+                    .chars()
+                    .take_while(char::is_ascii_alphanumeric)
+                    .collect();
+                let inner = &tag[..end];
+                if VOID_ELEMENTS.contains(&name.to_ascii_lowercase().as_str())
+                    && !inner.ends_with('/')
+                {
+                    result.push_str(inner);
+                    result.push_str("/>");
+                } else {
+                    result.push_str(&tag[..=end]);
+                }
+                remaining = &tag[end + 1..];
+            }
+            None => {
+                result.push_str(tag);
+                remaining = "";
+            }
+        }
+    }
+    result.push_str(remaining);
+
+    result.replace("&nbsp;", "&#160;")
+}
+
+/// A single chapter of the book
+struct Chapter {
+    /// The ID of the chapter within the package manifest
+    id: String,
+    /// The title of the chapter
+    title: String,
+    /// The XHTML content of the chapter
+    content: String,
+}
+
+impl Chapter {
+    /// The name of the file that the chapter is stored in
+    fn file(&self) -> String {
+        format!("{}.xhtml", self.id)
+    }
+}
+
+/// Generate the manifest ID of the chapter for a random table
+fn table_id(table: &TableModel) -> String {
+    format!("table-{}", table.data.url_slug)
+}
+
+/// Generate the manifest ID of the chapter for a campaign
+fn campaign_id(campaign: &CampaignContent) -> String {
+    format!("campaign-{}", campaign.campaign.identity.id)
+}
+
+/// Generate the manifest ID of the chapter for a character
+fn character_id(character: &CharacterModel) -> String {
+    format!("character-{}", character.identity.id)
+}
+
+/// Generate the manifest ID of an image included in the book
+fn image_id(image: &AttachmentContent) -> String {
+    format!("attachment-{}", image.attachment.identity.id)
+}
+
+/// Select the attachments that can be included in a book, which are those that are images in a format
+/// that every reader supports
+fn images(content: &WorldContent) -> impl Iterator<Item = &AttachmentContent> {
+    content.attachments.iter().filter(|attachment| {
+        IMAGE_TYPES.contains(&attachment.attachment.data.content_type.as_str())
+    })
+}
+
+/// Renders the chapters of a book, resolving wiki links between them as it goes
+struct EpubRenderer<'a> {
+    /// The content being rendered
+    content: &'a WorldContent,
+    /// The chapters that wiki links can resolve to
+    targets: LinkTargets,
+}
+
+impl<'a> EpubRenderer<'a> {
+    /// Create a new renderer for the given content. Wiki links only resolve to chapters in the sections
+    /// that are being included, or to images that are included in the book
+    fn new(content: &'a WorldContent, sections: &[ExportSection]) -> Self {
+        let mut targets = LinkTargets::default();
+        for image in images(content) {
+            targets.add(
+                &image.attachment.data.filename,
+                attachment_path(&image.attachment),
+            );
+        }
+        for section in sections {
+            match section {
+                ExportSection::Overview => {
+                    targets.add(&content.world.data.name, "overview.xhtml");
+                }
+                ExportSection::Tables => {
+                    for table in &content.tables {
+                        let file = format!("{}.xhtml", table_id(table));
+                        targets.add(&table.data.name, file.clone());
+                        targets.add(&table.data.url_slug.to_string(), file);
+                    }
+                }
+                ExportSection::Campaigns => {
+                    for campaign in &content.campaigns {
+                        targets.add(
+                            &campaign.campaign.data.name,
+                            format!("{}.xhtml", campaign_id(campaign)),
+                        );
+                    }
+                }
+                ExportSection::Characters => {
+                    for character in &content.characters {
+                        targets.add(
+                            &character.data.name,
+                            format!("{}.xhtml", character_id(character)),
+                        );
+                    }
+                }
+            }
+        }
+
+        Self { content, targets }
+    }
+
+    /// Render some Markdown into XHTML, resolving any wiki links within it
+    fn markdown(&self, text: &str) -> String {
+        to_xhtml(&markdown::render(&resolve_wiki_links(
+            text,
+            &self.targets,
+            "",
+        )))
+    }
+
+    /// Render the chapter giving an overview of the world
+    fn overview(&self) -> Chapter {
+        let world = &self.content.world.data;
+        Chapter {
+            id: "overview".to_owned(),
+            title: world.name.clone(),
+            content: format!(
+                "<h1>{}</h1>\n{}",
+                escape_html(&world.name),
+                self.markdown(&world.description)
+            ),
+        }
+    }
+
+    /// Render the chapter for a single random table
+    fn table(&self, table: &TableModel) -> Chapter {
+        let entries: Vec<String> = table
+            .data
+            .entries
+            .iter()
+            .map(|entry| {
+                format!(
+                    "<tr><td>{}</td><td>{}</td></tr>",
+                    entry.weight,
+                    escape_html(&entry.text)
+                )
+            })
+            .collect();
+
+        Chapter {
+            id: table_id(table),
+            title: table.data.name.clone(),
+            content: format!(
+                "<h1>{}</h1>\n{}<table>\n<thead><tr><th>Weight</th><th>Entry</th></tr></thead>\n<tbody>\n{}\n</tbody>\n</table>",
+                escape_html(&table.data.name),
+                self.markdown(&table.data.description),
+                entries.join("\n")
+            ),
+        }
+    }
+
+    /// Render the chapter for a single campaign, including all of its sessions
+    fn campaign(&self, campaign: &CampaignContent) -> Chapter {
+        let sessions: Vec<String> = campaign
+            .sessions
+            .iter()
+            .map(|session| {
+                format!(
+                    "<h2>{}</h2>\n{}",
+                    session.data.date.format("%Y-%m-%d"),
+                    self.markdown(&session.data.summary)
+                )
+            })
+            .collect();
+
+        let data = &campaign.campaign.data;
+        Chapter {
+            id: campaign_id(campaign),
+            title: data.name.clone(),
+            content: format!(
+                "<h1>{}</h1>\n{}{}",
+                escape_html(&data.name),
+                self.markdown(&data.description),
+                sessions.join("\n")
+            ),
+        }
+    }
+
+    /// Render the chapter for a single character
+    fn character(&self, character: &CharacterModel) -> Chapter {
+        Chapter {
+            id: character_id(character),
+            title: character.data.name.clone(),
+            content: format!(
+                "<h1>{}</h1>\n{}",
+                escape_html(&character.data.name),
+                self.markdown(&character.data.description)
+            ),
+        }
+    }
+
+    /// Wrap the content of a chapter in a complete XHTML document
+    fn document(&self, chapter: &Chapter) -> String {
+        render(
+            &load("epub/chapter.xhtml"),
+            &[
+                ("title", escape_html(&chapter.title).as_str()),
+                ("content", chapter.content.as_str()),
+            ],
+        )
+    }
+}
+
+/// Render a list of chapters as entries in the navigation document
+fn nav_entries(chapters: &[Chapter]) -> String {
+    chapters
+        .iter()
+        .map(|chapter| {
+            format!(
+                "<li><a href=\"{}\">{}</a></li>",
+                chapter.file(),
+                escape_html(&chapter.title)
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// Render every file of the EPUB book for some world
+///
+/// # Parameters
+/// - `content` - The content of the world
+/// - `sections` - The sections to include, in the order they should appear
+///
+/// # Returns
+/// The files that make up the book
+fn render_epub(content: &WorldContent, sections: &[ExportSection]) -> Vec<ArchiveFile> {
+    let renderer = EpubRenderer::new(content, sections);
+    let title = escape_html(&content.world.data.name);
+
+    let cover = Chapter {
+        id: "cover".to_owned(),
+        title: content.world.data.name.clone(),
+        content: format!("<h1 class=\"cover\">{}</h1>", title),
+    };
+
+    let mut chapters = vec![];
+    let mut nav = vec![];
+    for section in sections {
+        match section {
+            ExportSection::Overview => {
+                let overview = vec![renderer.overview()];
+                nav.push(nav_entries(&overview));
+                chapters.extend(overview);
+            }
+            ExportSection::Tables if !content.tables.is_empty() => {
+                let tables: Vec<Chapter> =
+                    content.tables.iter().map(|t| renderer.table(t)).collect();
+                nav.push(format!(
+                    "<li><span>Random Tables</span><ol>\n{}\n</ol></li>",
+                    nav_entries(&tables)
+                ));
+                chapters.extend(tables);
+            }
+            ExportSection::Campaigns if !content.campaigns.is_empty() => {
+                let campaigns: Vec<Chapter> = content
+                    .campaigns
+                    .iter()
+                    .map(|c| renderer.campaign(c))
+                    .collect();
+                nav.push(format!(
+                    "<li><span>Campaigns</span><ol>\n{}\n</ol></li>",
+                    nav_entries(&campaigns)
+                ));
+                chapters.extend(campaigns);
+            }
+            ExportSection::Characters if !content.characters.is_empty() => {
+                let characters: Vec<Chapter> = content
+                    .characters
+                    .iter()
+                    .map(|c| renderer.character(c))
+                    .collect();
+                nav.push(format!(
+                    "<li><span>Characters</span><ol>\n{}\n</ol></li>",
+                    nav_entries(&characters)
+                ));
+                chapters.extend(characters);
+            }
+            _ => {}
+        }
+    }
+
+    let modified = once(&content.world.identity.updated)
+        .chain(content.tables.iter().map(|t| &t.identity.updated))
+        .chain(content.campaigns.iter().flat_map(|c| {
+            once(&c.campaign.identity.updated).chain(c.sessions.iter().map(|s| &s.identity.updated))
+        }))
+        .chain(content.characters.iter().map(|c| &c.identity.updated))
+        .max()
+        .expect("There is always a world");
+
+    let manifest: Vec<String> = vec![
+        "<item id=\"nav\" href=\"nav.xhtml\" media-type=\"application/xhtml+xml\" properties=\"nav\"/>".to_owned(),
+        "<item id=\"style\" href=\"style.css\" media-type=\"text/css\"/>".to_owned(),
+    ]
+    .into_iter()
+    .chain(once(&cover).chain(chapters.iter()).map(|chapter| {
+        format!(
+            "<item id=\"{}\" href=\"{}\" media-type=\"application/xhtml+xml\"/>",
+            chapter.id,
+            chapter.file()
+        )
+    }))
+    .chain(images(content).map(|image| {
+        format!(
+            "<item id=\"{}\" href=\"{}\" media-type=\"{}\"/>",
+            image_id(image),
+            attachment_path(&image.attachment),
+            image.attachment.data.content_type
+        )
+    }))
+    .collect();
+    let spine: Vec<String> = once(&cover)
+        .chain(chapters.iter())
+        .map(|chapter| format!("<itemref idref=\"{}\"/>", chapter.id))
+        .collect();
+
+    let package = render(
+        &load("epub/content.opf"),
+        &[
+            ("id", content.world.identity.id.to_string().as_str()),
+            ("title", title.as_str()),
+            (
+                "modified",
+                modified.format("%Y-%m-%dT%H:%M:%SZ").to_string().as_str(),
+            ),
+            ("manifest", manifest.join("\n").as_str()),
+            ("spine", spine.join("\n").as_str()),
+        ],
+    );
+    let navigation = render(
+        &load("epub/nav.xhtml"),
+        &[
+            ("title", title.as_str()),
+            ("entries", nav.join("\n").as_str()),
+        ],
+    );
+
+    let mut files = vec![
+        ArchiveFile::stored("mimetype", "application/epub+zip"),
+        ArchiveFile::new("META-INF/container.xml", load("epub/container.xml")),
+        ArchiveFile::new("OEBPS/content.opf", package),
+        ArchiveFile::new("OEBPS/nav.xhtml", navigation),
+        ArchiveFile::new("OEBPS/style.css", load("epub/style.css")),
+    ];
+    files.extend(once(&cover).chain(chapters.iter()).map(|chapter| {
+        ArchiveFile::new(
+            format!("OEBPS/{}", chapter.file()),
+            renderer.document(chapter),
+        )
+    }));
+    files.extend(images(content).map(|image| {
+        ArchiveFile::new(
+            format!("OEBPS/{}", attachment_path(&image.attachment)),
+            image.contents.clone(),
+        )
+    }));
+
+    files
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        attachments::{AttachmentData, AttachmentModel},
+        campaigns::{CampaignData, CampaignModel},
+        characters::CharacterData,
+        model::{Identity, Publication},
+        tables::{TableData, TableEntry},
+        users::UserID,
+        worlds::WorldData,
+    };
+
+    fn content() -> WorldContent {
+        let world = WorldModel {
+            identity: Identity::default(),
+            data: WorldData {
+                name: "Faerûn".to_owned(),
+                owner: UserID::default(),
+                description: "See [[Tavern Names]] and [[Dragon Heist]] ![[map.png]] [[notes.pdf]]"
+                    .to_owned(),
+                url_slug: "faerun".parse().unwrap(),
+                publication: Publication::Published,
+                language: Default::default(),
+                translations: Default::default(),
+                hidden: false,
+            },
+        };
+        let table = TableModel {
+            identity: Identity::default(),
+            data: TableData {
+                world: world.identity.id.clone(),
+                name: "Tavern Names".to_owned(),
+                description: "".to_owned(),
+                url_slug: "tavern-names".parse().unwrap(),
+                entries: vec![TableEntry {
+                    weight: 1,
+                    text: "The Yawning Portal".to_owned(),
+                }],
+                publication: Publication::Published,
+            },
+        };
+        let campaign = CampaignModel {
+            identity: Identity::default(),
+            data: CampaignData {
+                world: world.identity.id.clone(),
+                name: "Dragon Heist".to_owned(),
+                description: "".to_owned(),
+                players: vec![],
+                publication: Publication::Published,
+            },
+        };
+
+        let character = CharacterModel {
+            identity: Identity::default(),
+            data: CharacterData {
+                world: world.identity.id.clone(),
+                name: "Volothamp Geddarm".to_owned(),
+                description: "".to_owned(),
+                controlled_by: None,
+                publication: Publication::Published,
+            },
+        };
+        let attachment = |filename: &str, content_type: &str| AttachmentContent {
+            attachment: AttachmentModel {
+                identity: Identity::default(),
+                data: AttachmentData {
+                    world: world.identity.id.clone(),
+                    owner: UserID::default(),
+                    filename: filename.to_owned(),
+                    content_type: content_type.to_owned(),
+                    size: 3,
+                    hash: "".to_owned(),
+                    has_thumbnail: false,
+                },
+            },
+            contents: vec![1, 2, 3],
+        };
+        let attachments = vec![
+            attachment("map.png", "image/png"),
+            attachment("notes.pdf", "application/pdf"),
+        ];
+
+        WorldContent {
+            world,
+            tables: vec![table],
+            campaigns: vec![CampaignContent {
+                campaign,
+                sessions: vec![],
+            }],
+            characters: vec![character],
+            attachments,
+        }
+    }
+
+    fn file<'a>(files: &'a [ArchiveFile], path: &str) -> &'a str {
+        let file = files
+            .iter()
+            .find(|file| file.path == path)
+            .expect("File not found");
+        std::str::from_utf8(&file.contents).unwrap()
+    }
+
+    #[test]
+    fn test_to_xhtml() {
+        assert_eq!(
+            to_xhtml("<p>One<br>Two&nbsp;<img src=\"a>b.png\" alt=\"\"></p><hr/>"),
+            "<p>One<br/>Two&#160;<img src=\"a>b.png\" alt=\"\"/></p><hr/>"
+        );
+    }
+
+    #[test]
+    fn test_mimetype_first_and_stored() {
+        let files = render_epub(&content(), &[ExportSection::Overview]);
+        assert_eq!(
+            files[0],
+            ArchiveFile::stored("mimetype", "application/epub+zip")
+        );
+    }
+
+    #[test]
+    fn test_spine_follows_sections() {
+        let content = content();
+        let files = render_epub(
+            &content,
+            &[ExportSection::Campaigns, ExportSection::Overview],
+        );
+        let package = file(&files, "OEBPS/content.opf");
+
+        let spine: Vec<&str> = package
+            .lines()
+            .filter(|line| line.starts_with("<itemref"))
+            .collect();
+        assert_eq!(
+            spine,
+            vec![
+                "<itemref idref=\"cover\"/>".to_owned(),
+                format!(
+                    "<itemref idref=\"campaign-{}\"/>",
+                    content.campaigns[0].campaign.identity.id
+                ),
+                "<itemref idref=\"overview\"/>".to_owned(),
+            ]
+        );
+        assert!(!files
+            .iter()
+            .any(|file| file.path == "OEBPS/table-tavern-names.xhtml"));
+    }
+
+    #[test]
+    fn test_links_to_excluded_sections_are_removed() {
+        let content = content();
+        let files = render_epub(&content, &[ExportSection::Overview, ExportSection::Tables]);
+        let overview = file(&files, "OEBPS/overview.xhtml");
+
+        assert!(overview.contains(
+            "<a href=\"table-tavern-names.xhtml\" rel=\"noopener noreferrer\">Tavern Names</a> and Dragon Heist"
+        ));
+    }
+
+    #[test]
+    fn test_nav_groups_sections() {
+        let files = render_epub(&content(), &[ExportSection::Tables]);
+        let nav = file(&files, "OEBPS/nav.xhtml");
+
+        assert!(nav.contains(
+            "<li><span>Random Tables</span><ol>\n<li><a href=\"table-tavern-names.xhtml\">Tavern Names</a></li>\n</ol></li>"
+        ));
+    }
+
+    #[test]
+    fn test_character_chapters() {
+        let content = content();
+        let files = render_epub(&content, &[ExportSection::Characters]);
+        let nav = file(&files, "OEBPS/nav.xhtml");
+        let id = content.characters[0].identity.id.to_string();
+
+        assert!(nav.contains(&format!(
+            "<li><span>Characters</span><ol>\n<li><a href=\"character-{}.xhtml\">Volothamp Geddarm</a></li>\n</ol></li>",
+            id
+        )));
+        assert!(file(&files, &format!("OEBPS/character-{}.xhtml", id))
+            .contains("<h1>Volothamp Geddarm</h1>"));
+    }
+
+    #[test]
+    fn test_only_images_are_included() {
+        let content = content();
+        let files = render_epub(&content, &[ExportSection::Overview]);
+        let image = format!(
+            "attachments/{}.png",
+            content.attachments[0].attachment.identity.id
+        );
+        let document = format!(
+            "attachments/{}.pdf",
+            content.attachments[1].attachment.identity.id
+        );
+
+        let package = file(&files, "OEBPS/content.opf");
+        assert!(package.contains(&format!(
+            "<item id=\"attachment-{}\" href=\"{}\" media-type=\"image/png\"/>",
+            content.attachments[0].attachment.identity.id, image
+        )));
+        assert!(!package.contains(&document));
+
+        assert!(files
+            .iter()
+            .any(|file| file.path == format!("OEBPS/{}", image)));
+        assert!(!files
+            .iter()
+            .any(|file| file.path == format!("OEBPS/{}", document)));
+
+        let overview = file(&files, "OEBPS/overview.xhtml");
+        assert!(overview.contains(&format!("<img src=\"{}\" alt=\"map.png\"/>", image)));
+        assert!(overview.contains("notes.pdf"));
+        assert!(!overview.contains(&document));
+    }
+}
@@ -0,0 +1,493 @@
+use super::{
+    archive::{build_archive, ArchiveFile},
+    content::attachment_path,
+    templates::{escape_html, load, render},
+    wiki_links::resolve_wiki_links,
+    ExportsService,
+};
+use crate::{
+    characters::CharacterModel,
+    exports::{AttachmentContent, CampaignContent, WorldContent},
+    http::{markdown, wiki_links::LinkTargets},
+    tables::TableModel,
+    worlds::WorldModel,
+};
+
+impl ExportsService {
+    /// Export the published content of a world as a standalone website, packaged as a Zip archive.
+    ///
+    /// Every file attached to the world is included alongside the pages, so that links to them and
+    /// images embedded from them keep working
+    ///
+    /// # Parameters
+    /// - `world` - The world to export
+    ///
+    /// # Returns
+    /// The bytes of the Zip archive
+    pub fn export_site(&self, world: WorldModel) -> Vec<u8> {
+        let content = self.gather_content(world);
+        tracing::debug!(world = ?content.world.identity.id, tables = content.tables.len(), campaigns = content.campaigns.len(), characters = content.characters.len(), attachments = content.attachments.len(), "Exporting world as a site");
+
+        build_archive(&render_site(&content))
+    }
+}
+
+/// Generate the path within the site of the page for a random table
+fn table_path(table: &TableModel) -> String {
+    format!("tables/{}.html", table.data.url_slug)
+}
+
+/// Generate the path within the site of the page for a campaign
+fn campaign_path(campaign: &CampaignContent) -> String {
+    format!("campaigns/{}.html", campaign.campaign.identity.id)
+}
+
+/// Generate the path within the site of the page for a character
+fn character_path(character: &CharacterModel) -> String {
+    format!("characters/{}.html", character.identity.id)
+}
+
+/// Renders the pages of a site, resolving wiki links between them as it goes
+struct SiteRenderer<'a> {
+    /// The content being rendered
+    content: &'a WorldContent,
+    /// The pages that wiki links can resolve to
+    targets: LinkTargets,
+}
+
+impl<'a> SiteRenderer<'a> {
+    /// Create a new renderer for the given content
+    fn new(content: &'a WorldContent) -> Self {
+        let mut targets = LinkTargets::default();
+        targets.add(&content.world.data.name, "index.html");
+        for table in &content.tables {
+            targets.add(&table.data.name, table_path(table));
+            targets.add(&table.data.url_slug.to_string(), table_path(table));
+        }
+        for campaign in &content.campaigns {
+            targets.add(&campaign.campaign.data.name, campaign_path(campaign));
+        }
+        for character in &content.characters {
+            targets.add(&character.data.name, character_path(character));
+        }
+        for attachment in &content.attachments {
+            targets.add(
+                &attachment.attachment.data.filename,
+                attachment_path(&attachment.attachment),
+            );
+        }
+
+        Self { content, targets }
+    }
+
+    /// Render some Markdown into HTML, resolving any wiki links within it
+    ///
+    /// # Parameters
+    /// - `text` - The Markdown to render
+    /// - `root` - The relative path from the page being rendered to the root of the site
+    fn markdown(&self, text: &str, root: &str) -> String {
+        markdown::render(&resolve_wiki_links(text, &self.targets, root))
+    }
+
+    /// Wrap the content of a page in the common layout
+    ///
+    /// # Parameters
+    /// - `title` - The title of the page
+    /// - `root` - The relative path from the page to the root of the site
+    /// - `content` - The HTML content of the page
+    fn page(&self, title: &str, root: &str, content: &str) -> Vec<u8> {
+        render(
+            &load("site/layout.html"),
+            &[
+                ("title", escape_html(title).as_str()),
+                ("world", escape_html(&self.content.world.data.name).as_str()),
+                ("root", root),
+                ("content", content),
+            ],
+        )
+        .into_bytes()
+    }
+
+    /// Render the index page of the site
+    fn index(&self) -> ArchiveFile {
+        let tables: Vec<String> = self
+            .content
+            .tables
+            .iter()
+            .map(|table| {
+                format!(
+                    "<li><a href=\"{}\">{}</a></li>",
+                    table_path(table),
+                    escape_html(&table.data.name)
+                )
+            })
+            .collect();
+        let campaigns: Vec<String> = self
+            .content
+            .campaigns
+            .iter()
+            .map(|campaign| {
+                format!(
+                    "<li><a href=\"{}\">{}</a></li>",
+                    campaign_path(campaign),
+                    escape_html(&campaign.campaign.data.name)
+                )
+            })
+            .collect();
+        let characters: Vec<String> = self
+            .content
+            .characters
+            .iter()
+            .map(|character| {
+                format!(
+                    "<li><a href=\"{}\">{}</a></li>",
+                    character_path(character),
+                    escape_html(&character.data.name)
+                )
+            })
+            .collect();
+        let attachments: Vec<String> = self
+            .content
+            .attachments
+            .iter()
+            .map(|attachment| {
+                format!(
+                    "<li><a href=\"{}\">{}</a></li>",
+                    attachment_path(&attachment.attachment),
+                    escape_html(&attachment.attachment.data.filename)
+                )
+            })
+            .collect();
+
+        let world = &self.content.world.data;
+        let content = render(
+            &load("site/index.html"),
+            &[
+                ("name", escape_html(&world.name).as_str()),
+                (
+                    "description",
+                    self.markdown(&world.description, "").as_str(),
+                ),
+                ("tables", tables.join("\n").as_str()),
+                ("campaigns", campaigns.join("\n").as_str()),
+                ("characters", characters.join("\n").as_str()),
+                ("attachments", attachments.join("\n").as_str()),
+            ],
+        );
+
+        ArchiveFile::new("index.html", self.page(&world.name, "", &content))
+    }
+
+    /// Render the page for a single random table
+    fn table(&self, table: &TableModel) -> ArchiveFile {
+        let entries: Vec<String> = table
+            .data
+            .entries
+            .iter()
+            .map(|entry| {
+                format!(
+                    "<tr><td>{}</td><td>{}</td></tr>",
+                    entry.weight,
+                    escape_html(&entry.text)
+                )
+            })
+            .collect();
+
+        let content = render(
+            &load("site/table.html"),
+            &[
+                ("name", escape_html(&table.data.name).as_str()),
+                (
+                    "description",
+                    self.markdown(&table.data.description, "../").as_str(),
+                ),
+                ("entries", entries.join("\n").as_str()),
+            ],
+        );
+
+        ArchiveFile::new(
+            table_path(table),
+            self.page(&table.data.name, "../", &content),
+        )
+    }
+
+    /// Render the page for a single campaign, including all of its sessions
+    fn campaign(&self, campaign: &CampaignContent) -> ArchiveFile {
+        let sessions: Vec<String> = campaign
+            .sessions
+            .iter()
+            .map(|session| {
+                format!(
+                    "<section>\n<h3>{}</h3>\n{}</section>",
+                    session.data.date.format("%Y-%m-%d"),
+                    self.markdown(&session.data.summary, "../")
+                )
+            })
+            .collect();
+
+        let data = &campaign.campaign.data;
+        let content = render(
+            &load("site/campaign.html"),
+            &[
+                ("name", escape_html(&data.name).as_str()),
+                (
+                    "description",
+                    self.markdown(&data.description, "../").as_str(),
+                ),
+                ("sessions", sessions.join("\n").as_str()),
+            ],
+        );
+
+        ArchiveFile::new(
+            campaign_path(campaign),
+            self.page(&data.name, "../", &content),
+        )
+    }
+}
+
+impl<'a> SiteRenderer<'a> {
+    /// Render the page for a single character
+    fn character(&self, character: &CharacterModel) -> ArchiveFile {
+        let content = render(
+            &load("site/character.html"),
+            &[
+                ("name", escape_html(&character.data.name).as_str()),
+                (
+                    "description",
+                    self.markdown(&character.data.description, "../").as_str(),
+                ),
+            ],
+        );
+
+        ArchiveFile::new(
+            character_path(character),
+            self.page(&character.data.name, "../", &content),
+        )
+    }
+}
+
+/// Include an attached file in the site exactly as it was uploaded
+fn attachment_file(attachment: &AttachmentContent) -> ArchiveFile {
+    ArchiveFile::new(
+        attachment_path(&attachment.attachment),
+        attachment.contents.clone(),
+    )
+}
+
+/// Render every page of the site for some world
+///
+/// # Parameters
+/// - `content` - The content of the world
+///
+/// # Returns
+/// The files that make up the site
+fn render_site(content: &WorldContent) -> Vec<ArchiveFile> {
+    let renderer = SiteRenderer::new(content);
+
+    let mut files = vec![
+        renderer.index(),
+        ArchiveFile::new("style.css", load("site/style.css")),
+    ];
+    files.extend(content.tables.iter().map(|table| renderer.table(table)));
+    files.extend(
+        content
+            .campaigns
+            .iter()
+            .map(|campaign| renderer.campaign(campaign)),
+    );
+    files.extend(
+        content
+            .characters
+            .iter()
+            .map(|character| renderer.character(character)),
+    );
+    files.extend(content.attachments.iter().map(attachment_file));
+
+    files
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        attachments::AttachmentData,
+        campaigns::{CampaignData, CampaignModel, SessionData, SessionModel},
+        characters::CharacterData,
+        model::{Identity, Publication},
+        tables::{TableData, TableEntry},
+        users::UserID,
+        worlds::WorldData,
+    };
+    use chrono::NaiveDate;
+
+    fn content() -> WorldContent {
+        let world = WorldModel {
+            identity: Identity::default(),
+            data: WorldData {
+                name: "Faerûn".to_owned(),
+                owner: UserID::default(),
+                description: "Start at [[The Inns|an inn]] or join [[Dragon Heist]]".to_owned(),
+                url_slug: "faerun".parse().unwrap(),
+                publication: Publication::Published,
+                language: Default::default(),
+                translations: Default::default(),
+                hidden: false,
+            },
+        };
+        let table = TableModel {
+            identity: Identity::default(),
+            data: TableData {
+                world: world.identity.id.clone(),
+                name: "The Inns".to_owned(),
+                description: "Back to [[Faerûn]]".to_owned(),
+                url_slug: "inns".parse().unwrap(),
+                entries: vec![TableEntry {
+                    weight: 2,
+                    text: "The <Yawning> Portal".to_owned(),
+                }],
+                publication: Publication::Published,
+            },
+        };
+        let campaign = CampaignModel {
+            identity: Identity::default(),
+            data: CampaignData {
+                world: world.identity.id.clone(),
+                name: "Dragon Heist".to_owned(),
+                description: "".to_owned(),
+                players: vec![],
+                publication: Publication::Published,
+            },
+        };
+        let session = SessionModel {
+            identity: Identity::default(),
+            data: SessionData {
+                campaign: campaign.identity.id.clone(),
+                date: NaiveDate::from_ymd(2020, 7, 1),
+                summary: "We drank at [[inns]] and met [[Volo]]".to_owned(),
+                entities: vec![],
+            },
+        };
+        let character = CharacterModel {
+            identity: Identity::default(),
+            data: CharacterData {
+                world: world.identity.id.clone(),
+                name: "Volothamp Geddarm".to_owned(),
+                description: "![[map.png]]".to_owned(),
+                controlled_by: None,
+                publication: Publication::Published,
+            },
+        };
+        let attachment = AttachmentContent {
+            attachment: AttachmentModel {
+                identity: Identity::default(),
+                data: AttachmentData {
+                    world: world.identity.id.clone(),
+                    owner: UserID::default(),
+                    filename: "Map.PNG".to_owned(),
+                    content_type: "image/png".to_owned(),
+                    size: 3,
+                    hash: "".to_owned(),
+                    has_thumbnail: false,
+                },
+            },
+            contents: vec![1, 2, 3],
+        };
+
+        WorldContent {
+            world,
+            tables: vec![table],
+            campaigns: vec![CampaignContent {
+                campaign,
+                sessions: vec![session],
+            }],
+            characters: vec![character],
+            attachments: vec![attachment],
+        }
+    }
+
+    #[test]
+    fn test_render_site_files() {
+        let content = content();
+        let files = render_site(&content);
+
+        let paths: Vec<String> = files.iter().map(|file| file.path.clone()).collect();
+        assert_eq!(
+            paths,
+            vec![
+                "index.html".to_owned(),
+                "style.css".to_owned(),
+                "tables/inns.html".to_owned(),
+                format!(
+                    "campaigns/{}.html",
+                    content.campaigns[0].campaign.identity.id
+                ),
+                format!("characters/{}.html", content.characters[0].identity.id),
+                format!(
+                    "attachments/{}.png",
+                    content.attachments[0].attachment.identity.id
+                ),
+            ]
+        );
+        assert_eq!(files[5].contents, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_render_index() {
+        let content = content();
+        let files = render_site(&content);
+        let index = String::from_utf8(files[0].contents.clone()).unwrap();
+
+        assert!(index.contains("<title>Faerûn</title>"));
+        assert!(index.contains("href=\"style.css\""));
+        assert!(
+            index.contains("<a href=\"tables/inns.html\" rel=\"noopener noreferrer\">an inn</a>")
+        );
+        assert!(index.contains(&format!(
+            "<a href=\"campaigns/{}.html\" rel=\"noopener noreferrer\">Dragon Heist</a>",
+            content.campaigns[0].campaign.identity.id
+        )));
+        assert!(index.contains("<li><a href=\"tables/inns.html\">The Inns</a></li>"));
+        assert!(index.contains(&format!(
+            "<li><a href=\"characters/{}.html\">Volothamp Geddarm</a></li>",
+            content.characters[0].identity.id
+        )));
+        assert!(index.contains(&format!(
+            "<li><a href=\"attachments/{}.png\">Map.PNG</a></li>",
+            content.attachments[0].attachment.identity.id
+        )));
+    }
+
+    #[test]
+    fn test_render_table() {
+        let files = render_site(&content());
+        let table = String::from_utf8(files[2].contents.clone()).unwrap();
+
+        assert!(table.contains("href=\"../style.css\""));
+        assert!(table.contains("<a href=\"../index.html\" rel=\"noopener noreferrer\">Faerûn</a>"));
+        assert!(table.contains("<tr><td>2</td><td>The &lt;Yawning&gt; Portal</td></tr>"));
+    }
+
+    #[test]
+    fn test_render_campaign() {
+        let files = render_site(&content());
+        let campaign = String::from_utf8(files[3].contents.clone()).unwrap();
+
+        assert!(campaign.contains("<h3>2020-07-01</h3>"));
+        assert!(campaign.contains(
+            "<p>We drank at <a href=\"../tables/inns.html\" rel=\"noopener noreferrer\">inns</a> and met Volo</p>"
+        ));
+    }
+
+    #[test]
+    fn test_render_character() {
+        let content = content();
+        let files = render_site(&content);
+        let character = String::from_utf8(files[4].contents.clone()).unwrap();
+
+        assert!(character.contains("<h1>Volothamp Geddarm</h1>"));
+        assert!(character.contains(&format!(
+            "<img src=\"../attachments/{}.png\" alt=\"map.png\">",
+            content.attachments[0].attachment.identity.id
+        )));
+    }
+}
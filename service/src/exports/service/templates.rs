@@ -0,0 +1,119 @@
+use rust_embed::RustEmbed;
+
+/// The embedded templates used to render exported content
+#[derive(RustEmbed)]
+#[folder = "templates/"]
+struct Templates;
+
+/// Load one of the embedded templates
+///
+/// # Parameters
+/// - `name` - The path of the template, relative to the templates directory
+///
+/// # Returns
+/// The contents of the template
+pub(super) fn load(name: &str) -> String {
+    let contents = Templates::get(name).expect("Failed to load template");
+    String::from_utf8(contents.into_owned()).expect("Template was not valid UTF-8")
+}
+
+/// Render a template, replacing every `{{name}}` placeholder with the matching value.
+///
+/// Values are inserted exactly as provided, so anything that isn't already HTML must be escaped first.
+/// Placeholders with no matching value are left untouched
+///
+/// # Parameters
+/// - `template` - The template to render
+/// - `values` - The values to insert into the template
+///
+/// # Returns
+/// The rendered template
+pub(super) fn render(template: &str, values: &[(&str, &str)]) -> String {
+    let mut result = String::with_capacity(template.len());
+    let mut remaining = template;
+
+    while let Some(start) = remaining.find("{{") {
+        result.push_str(&remaining[..start]);
+        let placeholder = &remaining[start..];
+
+        let value = placeholder.find("}}").and_then(|end| {
+            let name = placeholder[2..end].trim();
+            values
+                .iter()
+                .find(|(key, _)| *key == name)
+                .map(|(_, value)| (*value, end + 2))
+        });
+
+        match value {
+            Some((value, length)) => {
+                result.push_str(value);
+                remaining = &placeholder[length..];
+            }
+            None => {
+                result.push_str("{{");
+                remaining = &placeholder[2..];
+            }
+        }
+    }
+    result.push_str(remaining);
+
+    result
+}
+
+/// Escape some text so that it can be safely included within HTML
+///
+/// # Parameters
+/// - `value` - The text to escape
+///
+/// # Returns
+/// The escaped text
+pub(super) fn escape_html(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_values() {
+        assert_eq!(
+            render(
+                "<h1>{{title}}</h1><p>{{ body }}</p>",
+                &[("title", "Hello"), ("body", "World")]
+            ),
+            "<h1>Hello</h1><p>World</p>"
+        );
+    }
+
+    #[test]
+    fn test_render_unknown_placeholder() {
+        assert_eq!(render("{{unknown}} {{", &[]), "{{unknown}} {{");
+    }
+
+    #[test]
+    fn test_render_does_not_expand_values() {
+        assert_eq!(
+            render("{{a}}{{b}}", &[("a", "{{b}}"), ("b", "B")]),
+            "{{b}}B"
+        );
+    }
+
+    #[test]
+    fn test_escape_html() {
+        assert_eq!(
+            escape_html("<a href=\"x\">Tom & Jerry's</a>"),
+            "&lt;a href=&quot;x&quot;&gt;Tom &amp; Jerry&#39;s&lt;/a&gt;"
+        );
+    }
+
+    #[test]
+    fn test_load_template() {
+        assert!(load("site/layout.html").contains("{{content}}"));
+    }
+}
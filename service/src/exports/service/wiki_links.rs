@@ -0,0 +1,71 @@
+use crate::http::wiki_links::{replace_wiki_links, LinkTargets};
+
+/// Replace every wiki link in some Markdown with a standard Markdown link to the page that it refers to.
+///
+/// Links that don't refer to a known page are replaced with just their text
+///
+/// # Parameters
+/// - `markdown` - The Markdown to resolve the links in
+/// - `targets` - The paths to the pages that links can resolve to, relative to the root of the export
+/// - `root` - The relative path from the page being rendered to the root of the export
+///
+/// # Returns
+/// The Markdown with all of the wiki links resolved
+pub(super) fn resolve_wiki_links(markdown: &str, targets: &LinkTargets, root: &str) -> String {
+    replace_wiki_links(markdown, |link| {
+        let label = link
+            .label
+            .unwrap_or(link.target)
+            .replace('[', "\\[")
+            .replace(']', "\\]");
+
+        Some(match targets.resolve(link.target) {
+            Some(path) => format!("[{}]({}{})", label, root, path),
+            None => label,
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn targets() -> LinkTargets {
+        let mut targets = LinkTargets::default();
+        targets.add("Tavern Names", "tables/tavern-names.html");
+        targets.add("tavern-names", "tables/tavern-names.html");
+        targets
+    }
+
+    #[test]
+    fn test_resolve_known_link() {
+        assert_eq!(
+            resolve_wiki_links("Roll on [[tavern names]].", &targets(), "../"),
+            "Roll on [tavern names](../tables/tavern-names.html)."
+        );
+    }
+
+    #[test]
+    fn test_resolve_link_with_label() {
+        assert_eq!(
+            resolve_wiki_links("[[tavern-names|the inns]]", &targets(), ""),
+            "[the inns](tables/tavern-names.html)"
+        );
+    }
+
+    #[test]
+    fn test_resolve_unknown_link() {
+        assert_eq!(
+            resolve_wiki_links("Visit [[Waterdeep]]!", &targets(), ""),
+            "Visit Waterdeep!"
+        );
+    }
+
+    #[test]
+    fn test_resolve_unterminated_link() {
+        assert_eq!(
+            resolve_wiki_links("Not [[a link\n]] here", &targets(), ""),
+            "Not [[a link\n]] here"
+        );
+    }
+}
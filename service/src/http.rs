@@ -1,5 +1,12 @@
+pub(crate) mod blob;
+pub(crate) mod csv;
+pub(crate) mod language;
 pub(crate) mod link;
+pub(crate) mod markdown;
 pub(crate) mod pagination;
 pub(crate) mod patch;
 pub(crate) mod problem;
+pub(crate) mod publication;
 pub(crate) mod sorts;
+pub(crate) mod upload;
+pub(crate) mod wiki_links;
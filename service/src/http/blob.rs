@@ -0,0 +1,197 @@
+use rocket::{
+    http::{
+        hyper::header::{CacheControl, CacheDirective, ETag, EntityTag},
+        Status,
+    },
+    response, Request,
+};
+use std::io::Cursor;
+
+/// API Model representing a blob of binary data, such as an uploaded file.
+///
+/// Blobs are served with a strong ETag, so that clients can make conditional requests with
+/// `If-None-Match`, and support single byte ranges with `Range` and `If-Range`
+#[derive(Debug)]
+pub struct BlobResponse {
+    /// The contents of the blob
+    pub body: Vec<u8>,
+    /// The content type of the blob
+    pub content_type: String,
+    /// The strong entity tag of the blob, which must change whenever the contents do
+    pub etag: String,
+    /// The filename to suggest to the client, if any
+    pub filename: Option<String>,
+}
+
+/// The part of a blob that the client asked for
+#[derive(Debug, PartialEq)]
+enum ByteRange {
+    /// The entire blob
+    Full,
+    /// The bytes from the start to the end, inclusive
+    Partial(usize, usize),
+    /// A range that doesn't overlap the blob at all
+    Unsatisfiable,
+}
+
+/// Parse the `Range` header of a request.
+///
+/// Only a single range of bytes is supported. Anything else - including multiple ranges and headers
+/// that can't be parsed - is ignored, and the entire blob is returned, as RFC 7233 allows
+///
+/// # Parameters
+/// - `header` - The value of the `Range` header
+/// - `length` - The length of the blob
+///
+/// # Returns
+/// The range of the blob to return
+fn parse_range(header: &str, length: usize) -> ByteRange {
+    let spec = match header.trim().strip_prefix("bytes=") {
+        Some(spec) if !spec.contains(',') => spec.trim(),
+        _ => return ByteRange::Full,
+    };
+    let (start, end) = match spec.find('-') {
+        Some(index) => (spec[..index].trim(), spec[index + 1..].trim()),
+        None => return ByteRange::Full,
+    };
+
+    let range = match (start.parse::<usize>(), end.parse::<usize>()) {
+        (Ok(start), Ok(end)) if start <= end => (start, end),
+        (Ok(start), Err(_)) if end.is_empty() => (start, usize::MAX),
+        (Err(_), Ok(suffix)) if start.is_empty() => {
+            if suffix == 0 {
+                return ByteRange::Unsatisfiable;
+            }
+            (length.saturating_sub(suffix), usize::MAX)
+        }
+        _ => return ByteRange::Full,
+    };
+
+    if range.0 >= length {
+        ByteRange::Unsatisfiable
+    } else {
+        ByteRange::Partial(range.0, range.1.min(length - 1))
+    }
+}
+
+impl BlobResponse {
+    /// Check if a list of entity tags from a conditional request header matches this blob
+    ///
+    /// # Parameters
+    /// - `header` - The value of the header, such as `If-None-Match`
+    ///
+    /// # Returns
+    /// `true` if the header matches this blob
+    fn matches_etag(&self, header: &str) -> bool {
+        header
+            .split(',')
+            .map(str::trim)
+            .any(|tag| tag == "*" || tag.trim_start_matches("W/").trim_matches('"') == self.etag)
+    }
+}
+
+impl<'r> response::Responder<'r> for BlobResponse {
+    fn respond_to(self, req: &Request) -> response::Result<'r> {
+        let mut response = response::Response::build();
+        response
+            .header(CacheControl(vec![
+                CacheDirective::Private,
+                CacheDirective::MaxAge(3600),
+            ]))
+            .header(ETag(EntityTag::new(false, self.etag.clone())))
+            .raw_header("Accept-Ranges", "bytes");
+
+        if let Some(if_none_match) = req.headers().get_one("If-None-Match") {
+            if self.matches_etag(if_none_match) {
+                return response.status(Status::NotModified).ok();
+            }
+        }
+
+        // A range is only honoured if the client's copy is still current, as given by `If-Range`
+        let range = req
+            .headers()
+            .get_one("Range")
+            .filter(|_| {
+                req.headers()
+                    .get_one("If-Range")
+                    .map(|if_range| {
+                        !if_range.starts_with("W/") && if_range.trim_matches('"') == self.etag
+                    })
+                    .unwrap_or(true)
+            })
+            .map(|header| parse_range(header, self.body.len()))
+            .unwrap_or(ByteRange::Full);
+
+        let length = self.body.len();
+        let body = match range {
+            ByteRange::Full => self.body,
+            ByteRange::Partial(start, end) => {
+                response.status(Status::PartialContent).raw_header(
+                    "Content-Range",
+                    format!("bytes {}-{}/{}", start, end, length),
+                );
+                self.body[start..=end].to_vec()
+            }
+            ByteRange::Unsatisfiable => {
+                return response
+                    .status(Status::RangeNotSatisfiable)
+                    .raw_header("Content-Range", format!("bytes */{}", length))
+                    .ok();
+            }
+        };
+
+        if let Some(filename) = self.filename {
+            response.raw_header(
+                "Content-Disposition",
+                format!("inline; filename=\"{}\"", filename.replace('"', "")),
+            );
+        }
+
+        response
+            .raw_header("Content-Type", self.content_type)
+            .sized_body(Cursor::new(body))
+            .ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_range() {
+        assert_eq!(parse_range("bytes=0-9", 100), ByteRange::Partial(0, 9));
+        assert_eq!(parse_range("bytes=90-", 100), ByteRange::Partial(90, 99));
+        assert_eq!(parse_range("bytes=90-200", 100), ByteRange::Partial(90, 99));
+        assert_eq!(parse_range("bytes=-10", 100), ByteRange::Partial(90, 99));
+        assert_eq!(parse_range("bytes=-200", 100), ByteRange::Partial(0, 99));
+        assert_eq!(parse_range("bytes=100-", 100), ByteRange::Unsatisfiable);
+        assert_eq!(parse_range("bytes=-0", 100), ByteRange::Unsatisfiable);
+        assert_eq!(parse_range("bytes=0-", 0), ByteRange::Unsatisfiable);
+    }
+
+    #[test]
+    fn test_parse_ignored_range() {
+        assert_eq!(parse_range("bytes=0-9,20-29", 100), ByteRange::Full);
+        assert_eq!(parse_range("bytes=9-0", 100), ByteRange::Full);
+        assert_eq!(parse_range("bytes=a-b", 100), ByteRange::Full);
+        assert_eq!(parse_range("items=0-9", 100), ByteRange::Full);
+        assert_eq!(parse_range("bytes=5", 100), ByteRange::Full);
+    }
+
+    #[test]
+    fn test_matches_etag() {
+        let blob = BlobResponse {
+            body: vec![],
+            content_type: "text/plain".to_owned(),
+            etag: "abc".to_owned(),
+            filename: None,
+        };
+
+        assert!(blob.matches_etag("\"abc\""));
+        assert!(blob.matches_etag("\"xyz\", \"abc\""));
+        assert!(blob.matches_etag("W/\"abc\""));
+        assert!(blob.matches_etag("*"));
+        assert!(!blob.matches_etag("\"xyz\""));
+    }
+}
@@ -0,0 +1,220 @@
+use crate::http::problem::{Problem, ProblemType};
+use rocket::{
+    http::{
+        hyper::header::{CacheControl, CacheDirective},
+        ContentType, Status,
+    },
+    response, Data, Request,
+};
+use rocket_contrib::json::Json;
+use serde_json::{json, Value};
+use std::io::{Cursor, Read};
+
+/// The largest CSV file, in bytes, that can be uploaded
+const MAX_CSV_SIZE: u64 = 5 * 1024 * 1024;
+
+/// Problem Types that can happen when working with CSV files
+#[derive(Debug, thiserror::Error)]
+pub enum CsvProblemType {
+    /// The uploaded file was not valid CSV
+    #[error("The uploaded file was not valid CSV")]
+    InvalidCsv,
+
+    /// The uploaded file was larger than the largest that can be imported
+    #[error("The uploaded file was too large")]
+    TooLarge,
+
+    /// At least one of the rows in the uploaded file was invalid, so nothing was imported
+    #[error("One or more rows were invalid")]
+    InvalidRows,
+}
+
+impl ProblemType for CsvProblemType {
+    /// Generate a Type value for the `ProblemType` values.
+    ///
+    /// These are used in the `type` field in the RFC-7807 Problem Response
+    fn error_code(&self) -> &'static str {
+        match self {
+            CsvProblemType::InvalidCsv => "tag:multiverse,2020:problems/invalid_csv",
+            CsvProblemType::TooLarge => "tag:multiverse,2020:problems/csv_too_large",
+            CsvProblemType::InvalidRows => "tag:multiverse,2020:problems/invalid_rows",
+        }
+    }
+}
+
+/// Read an uploaded CSV file into a set of records
+///
+/// # Parameters
+/// - `data` - The uploaded file
+///
+/// # Returns
+/// Each record in the file, along with the line in the file that the record starts on
+///
+/// # Errors
+/// A Problem if the file was too large or wasn't valid CSV
+pub fn read_csv<T>(data: Data) -> Result<Vec<(u64, T)>, Problem>
+where
+    T: serde::de::DeserializeOwned,
+{
+    let mut body = vec![];
+    data.open()
+        .take(MAX_CSV_SIZE + 1)
+        .read_to_end(&mut body)
+        .map_err(|e| {
+            tracing::warn!(e = ?e, "Failed to read uploaded CSV");
+            Problem::new(CsvProblemType::InvalidCsv, Status::BadRequest)
+        })?;
+    if body.len() as u64 > MAX_CSV_SIZE {
+        tracing::warn!("Uploaded CSV was too large");
+        return Err(Problem::new(
+            CsvProblemType::TooLarge,
+            Status::PayloadTooLarge,
+        ));
+    }
+
+    let invalid = |e: csv::Error| {
+        tracing::warn!(e = ?e, "Failed to parse uploaded CSV");
+        Problem::new(CsvProblemType::InvalidCsv, Status::UnprocessableEntity)
+            .with_detail(e.to_string())
+    };
+
+    let mut reader = csv::ReaderBuilder::new()
+        .trim(csv::Trim::Headers)
+        .from_reader(body.as_slice());
+    let headers = reader.headers().map_err(invalid)?.clone();
+
+    let mut records = vec![];
+    let mut record = csv::StringRecord::new();
+    while reader.read_record(&mut record).map_err(invalid)? {
+        let line = record
+            .position()
+            .map(csv::Position::line)
+            .unwrap_or_default();
+        records.push((line, record.deserialize(Some(&headers)).map_err(invalid)?));
+    }
+
+    Ok(records)
+}
+
+/// Write a set of records as a CSV file, with a header row taken from the field names of the records
+///
+/// # Parameters
+/// - `records` - The records to write
+///
+/// # Returns
+/// The bytes of the CSV file
+pub fn write_csv<T>(records: &[T]) -> Vec<u8>
+where
+    T: serde::Serialize,
+{
+    let mut writer = csv::Writer::from_writer(vec![]);
+    for record in records {
+        writer
+            .serialize(record)
+            .expect("Failed to write CSV record");
+    }
+    writer.into_inner().expect("Failed to write CSV")
+}
+
+/// API Model representing a list of resources that is downloaded as a CSV file
+#[derive(Debug)]
+pub struct CsvResponse {
+    /// The name of the file that the CSV should be saved as
+    pub filename: String,
+    /// The bytes of the CSV file
+    pub body: Vec<u8>,
+}
+
+impl<'r> response::Responder<'r> for CsvResponse {
+    fn respond_to(self, _: &Request) -> response::Result<'r> {
+        response::Response::build()
+            .header(ContentType::new("text", "csv").with_params(("charset", "utf-8")))
+            .raw_header(
+                "Content-Disposition",
+                format!("attachment; filename=\"{}\"", self.filename),
+            )
+            .header(CacheControl(vec![
+                CacheDirective::Private,
+                CacheDirective::NoCache,
+            ]))
+            .sized_body(Cursor::new(self.body))
+            .ok()
+    }
+}
+
+/// The outcome of importing a single row of a CSV file
+#[derive(Debug)]
+pub enum CsvRowStatus {
+    /// The row is valid, but wasn't imported because this was a dry run
+    Valid,
+    /// The row was invalid. The value is the `fields` from the Validation Problem for the row
+    Invalid(Value),
+    /// The row was imported, creating the resource at the given link
+    Created(String),
+}
+
+/// Report of the outcome of importing a single row of a CSV file
+#[derive(Debug)]
+pub struct CsvRowReport {
+    /// The line in the file that the row starts on
+    pub line: u64,
+    /// The outcome of importing the row
+    pub status: CsvRowStatus,
+}
+
+/// Generate the JSON representation of the rows of an import report
+fn rows_json(rows: &[CsvRowReport]) -> Value {
+    rows.iter()
+        .map(|row| match &row.status {
+            CsvRowStatus::Valid => json!({ "line": row.line, "status": "valid" }),
+            CsvRowStatus::Invalid(fields) => {
+                json!({ "line": row.line, "status": "invalid", "fields": fields })
+            }
+            CsvRowStatus::Created(link) => {
+                json!({ "line": row.line, "status": "created", "href": link })
+            }
+        })
+        .collect()
+}
+
+/// Build the Problem to respond with when some of the rows of an import were invalid
+///
+/// # Parameters
+/// - `rows` - The report of every row in the import
+///
+/// # Returns
+/// The problem
+pub fn invalid_rows_problem(rows: &[CsvRowReport]) -> Problem {
+    Problem::new(CsvProblemType::InvalidRows, Status::UnprocessableEntity)
+        .with_extra("rows", rows_json(rows))
+}
+
+/// API Model representing the report of importing a CSV file
+#[derive(Debug)]
+pub struct CsvImportResponse {
+    /// Whether this was a dry run, in which case nothing was actually imported
+    pub dry_run: bool,
+    /// The report of every row in the file
+    pub rows: Vec<CsvRowReport>,
+}
+
+impl<'r> response::Responder<'r> for CsvImportResponse {
+    fn respond_to(self, req: &Request) -> response::Result<'r> {
+        let valid = self
+            .rows
+            .iter()
+            .all(|row| !matches!(row.status, CsvRowStatus::Invalid(_)));
+
+        response::Response::build()
+            .merge(
+                Json(json!({
+                  "dry_run": self.dry_run,
+                  "valid": valid,
+                  "rows": rows_json(&self.rows)
+                }))
+                .respond_to(req)
+                .unwrap(),
+            )
+            .ok()
+    }
+}
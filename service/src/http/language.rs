@@ -0,0 +1,197 @@
+use crate::{http::problem::ValidationType, model::LanguageTag};
+use rocket::request::{FromRequest, Outcome, Request};
+use std::cmp::Ordering;
+
+/// Validation errors that can occur when working with languages
+#[derive(Debug, thiserror::Error)]
+pub enum LanguageValidation {
+    /// The language was not a well-formed language tag
+    #[error("The language tag was invalid")]
+    InvalidLanguage,
+}
+
+impl ValidationType for LanguageValidation {
+    /// Generate a Type value for the `ValidationType` values.
+    fn error_code(&self) -> &'static str {
+        match self {
+            LanguageValidation::InvalidLanguage => {
+                "tag:multiverse,2020:problems/validation_error/invalid_language"
+            }
+        }
+    }
+}
+
+/// A single language range that the client is willing to accept
+#[derive(Debug, PartialEq)]
+enum LanguageRange {
+    /// Any language at all, represented as `*`
+    Any,
+    /// A specific language
+    Tag(LanguageTag),
+}
+
+/// The languages that the client would like content in, in order of preference.
+///
+/// This comes from the `lang` querystring parameter if present, so that links to a specific translation
+/// can be followed, and otherwise from the `Accept-Language` header
+#[derive(Debug, Default, PartialEq)]
+pub struct AcceptLanguage(Vec<LanguageRange>);
+
+impl AcceptLanguage {
+    /// Parse the value of an `Accept-Language` header.
+    ///
+    /// Ranges that can't be understood are ignored, as are any with a quality of zero
+    ///
+    /// # Parameters
+    /// - `header` - The header value to parse
+    ///
+    /// # Returns
+    /// The language ranges, ordered by the quality that the client gave them
+    fn parse(header: &str) -> Self {
+        let mut ranges: Vec<(f32, LanguageRange)> = header
+            .split(',')
+            .filter_map(|item| {
+                let mut parts = item.split(';').map(str::trim);
+                let range = match parts.next()? {
+                    "*" => LanguageRange::Any,
+                    tag => LanguageRange::Tag(tag.parse().ok()?),
+                };
+                let quality = parts
+                    .filter_map(|param| param.strip_prefix("q="))
+                    .map(|q| q.parse::<f32>().ok())
+                    .next()
+                    .unwrap_or(Some(1.0))?;
+
+                if quality > 0.0 {
+                    Some((quality, range))
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        ranges.sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap_or(Ordering::Equal));
+        AcceptLanguage(ranges.into_iter().map(|(_, range)| range).collect())
+    }
+
+    /// Select the language that best matches what the client asked for.
+    ///
+    /// Each requested language is tried in order of preference. A requested language matches an available
+    /// one that is the same, then one that is more specific, and then one that is less specific - so a
+    /// request for `de-CH` can be served in `de`.
+    ///
+    /// # Parameters
+    /// - `available` - The languages that the content is available in
+    ///
+    /// # Returns
+    /// The best language, or `None` if the client will accept any language or none of the available ones
+    /// were acceptable. In this case the default language of the content should be used
+    pub fn select<'a>(&self, available: &[&'a LanguageTag]) -> Option<&'a LanguageTag> {
+        for range in &self.0 {
+            let tag = match range {
+                LanguageRange::Any => return None,
+                LanguageRange::Tag(tag) => tag,
+            };
+
+            if let Some(exact) = available.iter().find(|language| **language == tag) {
+                return Some(*exact);
+            }
+
+            let more_specific = available.iter().find(|language| {
+                std::iter::successors(language.parent(), LanguageTag::parent)
+                    .any(|parent| &parent == tag)
+            });
+            if let Some(more_specific) = more_specific {
+                return Some(*more_specific);
+            }
+
+            let less_specific = std::iter::successors(tag.parent(), LanguageTag::parent)
+                .find_map(|parent| available.iter().find(|language| ***language == parent));
+            if let Some(less_specific) = less_specific {
+                return Some(*less_specific);
+            }
+        }
+
+        None
+    }
+}
+
+impl<'a, 'r> FromRequest<'a, 'r> for AcceptLanguage {
+    /// No error is ever returned
+    type Error = ();
+
+    /// Load the languages that the client would like from the HTTP Request
+    ///
+    /// # Parameters
+    /// - `request` - The HTTP Request to load the languages from
+    ///
+    /// # Returns
+    /// The languages that the client would like
+    fn from_request(request: &'a Request<'r>) -> Outcome<Self, Self::Error> {
+        let lang = request
+            .get_query_value::<String>("lang")
+            .and_then(Result::ok)
+            .and_then(|lang| lang.parse().ok());
+
+        let accept_language = match lang {
+            Some(lang) => AcceptLanguage(vec![LanguageRange::Tag(lang)]),
+            None => request
+                .headers()
+                .get_one("Accept-Language")
+                .map(AcceptLanguage::parse)
+                .unwrap_or_default(),
+        };
+
+        Outcome::Success(accept_language)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn tag(tag: &str) -> LanguageTag {
+        LanguageTag::from_str(tag).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            AcceptLanguage::parse("fr;q=0.5, de-CH, *;q=0.1, en-GB;q=0.8, xx_YY, es;q=0"),
+            AcceptLanguage(vec![
+                LanguageRange::Tag(tag("de-CH")),
+                LanguageRange::Tag(tag("en-GB")),
+                LanguageRange::Tag(tag("fr")),
+                LanguageRange::Any,
+            ])
+        );
+    }
+
+    #[test]
+    fn test_parse_empty() {
+        assert_eq!(AcceptLanguage::parse(""), AcceptLanguage(vec![]));
+    }
+
+    #[test]
+    fn test_select() {
+        let en = tag("en");
+        let de = tag("de");
+        let pt_br = tag("pt-BR");
+        let available = vec![&en, &de, &pt_br];
+
+        assert_eq!(AcceptLanguage::parse("de").select(&available), Some(&de));
+        assert_eq!(AcceptLanguage::parse("DE-ch").select(&available), Some(&de));
+        assert_eq!(AcceptLanguage::parse("pt").select(&available), Some(&pt_br));
+        assert_eq!(
+            AcceptLanguage::parse("fr, de;q=0.5, en;q=0.8").select(&available),
+            Some(&en)
+        );
+        assert_eq!(AcceptLanguage::parse("fr").select(&available), None);
+        assert_eq!(
+            AcceptLanguage::parse("*, de;q=0.5").select(&available),
+            None
+        );
+        assert_eq!(AcceptLanguage::default().select(&available), None);
+    }
+}
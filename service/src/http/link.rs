@@ -6,8 +6,12 @@ use std::fmt::{Display, Formatter};
 pub struct LinkRel(&'static str);
 
 impl LinkRel {
+    pub const ALTERNATE: LinkRel = LinkRel("alternate");
     pub const AUTHOR: LinkRel = LinkRel("author");
+    pub const ENCLOSURE: LinkRel = LinkRel("enclosure");
     pub const ITEM: LinkRel = LinkRel("item");
+    pub const NEXT: LinkRel = LinkRel("next");
+    pub const PREVIEW: LinkRel = LinkRel("preview");
     pub const RELATED: LinkRel = LinkRel("related");
     pub const SELF: LinkRel = LinkRel("self");
 }
@@ -23,6 +27,8 @@ pub struct Link {
     title: Option<String>,
     /// The anchor of the link
     anchor: Option<String>,
+    /// The language of the link target
+    hreflang: Option<String>,
 }
 
 /// Representation of a set of link headers
@@ -46,6 +52,7 @@ impl Link {
             rel,
             title: None,
             anchor: None,
+            hreflang: None,
         }
     }
 
@@ -82,6 +89,23 @@ impl Link {
             ..self
         }
     }
+
+    /// Specify the language of the Link target
+    ///
+    /// # Parameters
+    /// - `hreflang` - The language of the link target
+    ///
+    /// # Returns
+    /// The link
+    pub fn hreflang<T>(self, hreflang: T) -> Self
+    where
+        T: Into<String>,
+    {
+        Self {
+            hreflang: Some(hreflang.into()),
+            ..self
+        }
+    }
 }
 
 impl Display for LinkRel {
@@ -100,6 +124,9 @@ impl Display for Link {
         if let Some(anchor) = &self.anchor {
             write!(f, "; anchor=\"{}\"", anchor)?;
         }
+        if let Some(hreflang) = &self.hreflang {
+            write!(f, "; hreflang=\"{}\"", hreflang)?;
+        }
         Ok(())
     }
 }
@@ -176,6 +203,17 @@ mod tests {
         );
     }
 
+    #[test]
+    pub fn test_format_link_with_hreflang() {
+        let link = Link::new("/example?lang=de", LinkRel::ALTERNATE).hreflang("de");
+        let formatted = format!("{}", link);
+
+        assert_that!(
+            &formatted,
+            eq("</example?lang=de>; rel=\"alternate\"; hreflang=\"de\"".to_owned())
+        );
+    }
+
     #[test]
     pub fn test_build_single_link_header() {
         let link = Link::new("/example", LinkRel::SELF);
@@ -0,0 +1,65 @@
+use pulldown_cmark::{html, Options, Parser};
+
+/// Render some user-provided Markdown into HTML that is safe to display.
+///
+/// The Markdown is rendered as CommonMark, with tables and strikethrough enabled, and the resulting HTML
+/// is then sanitised so that any scripts, event handlers or other unsafe markup are removed.
+///
+/// # Parameters
+/// - `markdown` - The Markdown to render
+///
+/// # Returns
+/// The sanitised HTML
+pub fn render(markdown: &str) -> String {
+    let mut options = Options::empty();
+    options.insert(Options::ENABLE_TABLES);
+    options.insert(Options::ENABLE_STRIKETHROUGH);
+
+    let parser = Parser::new_ext(markdown, options);
+    let mut unsafe_html = String::new();
+    html::push_html(&mut unsafe_html, parser);
+
+    ammonia::clean(&unsafe_html)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_plain_text() {
+        assert_eq!(render("Hello"), "<p>Hello</p>\n");
+    }
+
+    #[test]
+    fn test_render_formatting() {
+        assert_eq!(
+            render("*Hello* **World**"),
+            "<p><em>Hello</em> <strong>World</strong></p>\n"
+        );
+    }
+
+    #[test]
+    fn test_render_strips_scripts() {
+        assert_eq!(
+            render("Hello <script>alert('Hi')</script>"),
+            "<p>Hello </p>\n"
+        );
+    }
+
+    #[test]
+    fn test_render_strips_event_handlers() {
+        assert_eq!(
+            render("<a href=\"/worlds\" onclick=\"alert('Hi')\">Worlds</a>"),
+            "<p><a href=\"/worlds\" rel=\"noopener noreferrer\">Worlds</a></p>\n"
+        );
+    }
+
+    #[test]
+    fn test_render_strips_javascript_links() {
+        assert_eq!(
+            render("[Click](javascript:alert('Hi'))"),
+            "<p><a rel=\"noopener noreferrer\">Click</a></p>\n"
+        );
+    }
+}
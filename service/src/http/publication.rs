@@ -0,0 +1,151 @@
+use crate::{
+    http::problem::{GenericValidation, Problem, ValidationProblem, ValidationType},
+    model::{Publication, PublicationStatus},
+};
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+/// Validation errors that can occur when working with publication states
+#[derive(Debug, thiserror::Error)]
+pub enum PublicationValidation {
+    /// The publication status was not one that is supported
+    #[error("The publication status was unknown")]
+    UnknownStatus,
+
+    /// The publish time was not a valid timestamp
+    #[error("The publish time was invalid")]
+    InvalidPublishAt,
+}
+
+impl ValidationType for PublicationValidation {
+    /// Generate a Type value for the `ValidationType` values.
+    fn error_code(&self) -> &'static str {
+        match self {
+            PublicationValidation::UnknownStatus => {
+                "tag:multiverse,2020:problems/validation_error/unknown_status"
+            }
+            PublicationValidation::InvalidPublishAt => {
+                "tag:multiverse,2020:problems/validation_error/invalid_publish_at"
+            }
+        }
+    }
+}
+
+/// Incoming details representing the publication state of some content.
+/// This is intended to be flattened into the request for the content itself
+#[derive(Debug, Default, Deserialize)]
+pub struct PublicationRequest {
+    /// The publication status. If omitted then the content is published immediately
+    status: Option<String>,
+    /// When the content is to be published. Required if, and only used if, the status is "scheduled"
+    publish_at: Option<String>,
+}
+
+/// Parse a publication status provided by the client
+///
+/// # Parameters
+/// - `status` - The status to parse
+///
+/// # Returns
+/// The status, or `None` if it wasn't a known status
+pub fn parse_publication_status(status: &str) -> Option<PublicationStatus> {
+    status.trim().parse().ok()
+}
+
+/// Parse the publication status that content is to be filtered by when searching
+///
+/// # Parameters
+/// - `status` - The status provided by the client, if any
+///
+/// # Returns
+/// The status to filter by, if any
+///
+/// # Errors
+/// A Validation Problem if the status wasn't a known status
+pub fn parse_status_filter(status: Option<String>) -> Result<Option<PublicationStatus>, Problem> {
+    match status {
+        None => Ok(None),
+        Some(status) => match parse_publication_status(&status) {
+            Some(status) => Ok(Some(status)),
+            None => {
+                let mut problem = ValidationProblem::new();
+                problem.with_field_error("status", PublicationValidation::UnknownStatus);
+                Err(problem.build())
+            }
+        },
+    }
+}
+
+impl PublicationRequest {
+    /// Create a new publication request from values provided by the client in some other way than a
+    /// JSON body
+    ///
+    /// # Parameters
+    /// - `status` - The publication status
+    /// - `publish_at` - When the content is to be published
+    ///
+    /// # Returns
+    /// The publication request
+    pub fn new(status: Option<String>, publish_at: Option<String>) -> Self {
+        Self { status, publish_at }
+    }
+
+    /// Validate the request and build the publication state that it represents
+    ///
+    /// # Parameters
+    /// - `problem` - The validation problem to record any errors into
+    ///
+    /// # Returns
+    /// The publication state, or `None` if the request was invalid. In this case the errors will have been
+    /// recorded into `problem`
+    pub fn build(&self, problem: &mut ValidationProblem) -> Option<Publication> {
+        let status = match self.status.as_ref().filter(|v| !v.trim().is_empty()) {
+            None => PublicationStatus::Published,
+            Some(status) => match parse_publication_status(status) {
+                Some(status) => status,
+                None => {
+                    problem.with_field_error("status", PublicationValidation::UnknownStatus);
+                    return None;
+                }
+            },
+        };
+
+        match status {
+            PublicationStatus::Draft => Some(Publication::Draft),
+            PublicationStatus::Published => Some(Publication::Published),
+            PublicationStatus::Scheduled => {
+                match self.publish_at.as_ref().filter(|v| !v.trim().is_empty()) {
+                    None => {
+                        problem.with_field_error("publish_at", GenericValidation::Missing);
+                        None
+                    }
+                    Some(publish_at) => match DateTime::parse_from_rfc3339(publish_at.trim()) {
+                        Ok(publish_at) => {
+                            Some(Publication::Scheduled(publish_at.with_timezone(&Utc)))
+                        }
+                        Err(_) => {
+                            problem.with_field_error(
+                                "publish_at",
+                                PublicationValidation::InvalidPublishAt,
+                            );
+                            None
+                        }
+                    },
+                }
+            }
+        }
+    }
+}
+
+/// Add the publication state of some content to the JSON representation of that content
+///
+/// # Parameters
+/// - `value` - The JSON representation of the content
+/// - `publication` - The publication state of the content
+pub fn add_publication_json(value: &mut Value, publication: &Publication) {
+    value["status"] = json!(publication.status().to_string());
+    if let Some(publish_at) = publication.publish_at() {
+        value["publish_at"] = json!(publish_at);
+    }
+}
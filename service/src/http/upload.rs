@@ -0,0 +1,126 @@
+use crate::http::problem::{Problem, ProblemType};
+use multipart::server::Multipart;
+use rocket::{
+    http::{ContentType, Status},
+    Data,
+};
+use std::io::Read;
+
+/// Problem Types that can happen when uploading files
+#[derive(Debug, thiserror::Error)]
+pub enum UploadProblemType {
+    /// The request was not a valid `multipart/form-data` upload
+    #[error("The upload was not valid multipart/form-data")]
+    InvalidUpload,
+
+    /// The request did not contain the expected file
+    #[error("The upload did not contain a file")]
+    MissingFile,
+
+    /// The uploaded file was larger than the largest that is allowed
+    #[error("The uploaded file was too large")]
+    TooLarge,
+}
+
+impl ProblemType for UploadProblemType {
+    /// Generate a Type value for the `ProblemType` values.
+    ///
+    /// These are used in the `type` field in the RFC-7807 Problem Response
+    fn error_code(&self) -> &'static str {
+        match self {
+            UploadProblemType::InvalidUpload => "tag:multiverse,2020:problems/invalid_upload",
+            UploadProblemType::MissingFile => "tag:multiverse,2020:problems/missing_file",
+            UploadProblemType::TooLarge => "tag:multiverse,2020:problems/upload_too_large",
+        }
+    }
+}
+
+/// A file that was uploaded as part of a `multipart/form-data` request
+#[derive(Debug)]
+pub struct UploadedFile {
+    /// The name of the file, as provided by the client
+    pub filename: Option<String>,
+    /// The contents of the file
+    pub content: Vec<u8>,
+}
+
+/// Read a single file from a `multipart/form-data` request.
+///
+/// Any other fields in the request are ignored
+///
+/// # Parameters
+/// - `content_type` - The content type of the request
+/// - `data` - The body of the request
+/// - `field` - The name of the form field that contains the file
+/// - `max_size` - The largest file, in bytes, that is allowed
+///
+/// # Returns
+/// The uploaded file
+///
+/// # Errors
+/// A Problem if the request wasn't a valid upload, didn't contain the file, or the file was too large
+pub fn read_upload(
+    content_type: &ContentType,
+    data: Data,
+    field: &str,
+    max_size: u64,
+) -> Result<UploadedFile, Problem> {
+    let invalid = || Problem::new(UploadProblemType::InvalidUpload, Status::BadRequest);
+
+    let boundary = content_type
+        .params()
+        .find(|(name, _)| *name == "boundary")
+        .map(|(_, boundary)| boundary)
+        .filter(|_| content_type.is_form_data())
+        .ok_or_else(|| {
+            tracing::warn!(content_type = ?content_type, "Upload was not multipart/form-data");
+            Problem::new(
+                UploadProblemType::InvalidUpload,
+                Status::UnsupportedMediaType,
+            )
+        })?;
+
+    let mut multipart = Multipart::with_body(data.open(), boundary);
+    while let Some(mut entry) = multipart.read_entry().map_err(|e| {
+        tracing::warn!(e = ?e, "Failed to read multipart entry");
+        invalid()
+    })? {
+        if &*entry.headers.name != field {
+            continue;
+        }
+
+        let mut content = vec![];
+        entry
+            .data
+            .by_ref()
+            .take(max_size + 1)
+            .read_to_end(&mut content)
+            .map_err(|e| {
+                tracing::warn!(e = ?e, "Failed to read uploaded file");
+                invalid()
+            })?;
+        if content.len() as u64 > max_size {
+            tracing::warn!("Uploaded file was too large");
+            return Err(
+                Problem::new(UploadProblemType::TooLarge, Status::PayloadTooLarge)
+                    .with_extra("max_size", max_size),
+            );
+        }
+
+        return Ok(UploadedFile {
+            filename: entry
+                .headers
+                .filename
+                .clone()
+                .map(|filename| filename.trim().to_owned())
+                .filter(|filename| !filename.is_empty()),
+            content,
+        });
+    }
+
+    tracing::warn!(field = field, "Upload did not contain the file");
+    Err(Problem::new(
+        UploadProblemType::MissingFile,
+        Status::UnprocessableEntity,
+    ))
+}
@@ -0,0 +1,157 @@
+use std::collections::HashMap;
+
+/// The names that wiki links can refer to, and what each of them resolves to
+#[derive(Debug, Default)]
+pub struct LinkTargets(HashMap<String, String>);
+
+impl LinkTargets {
+    /// Add a new link target. Names are matched case-insensitively, and the first target added for a
+    /// given name wins
+    ///
+    /// # Parameters
+    /// - `name` - The name that wiki links use to refer to the target
+    /// - `target` - What the name resolves to
+    pub fn add<S: Into<String>>(&mut self, name: &str, target: S) {
+        let key = name.trim().to_lowercase();
+        if !key.is_empty() {
+            self.0.entry(key).or_insert_with(|| target.into());
+        }
+    }
+
+    /// Find what a name used in a wiki link resolves to
+    ///
+    /// # Parameters
+    /// - `name` - The name used in the link
+    ///
+    /// # Returns
+    /// The target of the link, if there is one
+    pub fn resolve(&self, name: &str) -> Option<&str> {
+        self.0.get(&name.trim().to_lowercase()).map(String::as_str)
+    }
+}
+
+/// A single wiki link within some Markdown
+#[derive(Debug, PartialEq)]
+pub struct WikiLink<'a> {
+    /// The target of the link, which is everything before any `|`
+    pub target: &'a str,
+    /// The text to display for the link, if it's different to the target
+    pub label: Option<&'a str>,
+    /// Whether the link is an embed, of the form `![[Target]]`
+    pub embed: bool,
+}
+
+/// Replace the wiki links in some Markdown.
+///
+/// Wiki links are written as `[[Target]]`, or as `[[Target|Label]]` to use different link text, and
+/// must start and end on the same line. The `!` in front of an embed is not part of the link, and so is
+/// always kept
+///
+/// # Parameters
+/// - `markdown` - The Markdown to replace the links in
+/// - `replace` - Produce the replacement for a link, or `None` to leave the link untouched
+///
+/// # Returns
+/// The Markdown with the links replaced
+pub fn replace_wiki_links<F>(markdown: &str, mut replace: F) -> String
+where
+    F: FnMut(&WikiLink) -> Option<String>,
+{
+    let mut result = String::with_capacity(markdown.len());
+    let mut remaining = markdown;
+
+    while let Some(start) = remaining.find("[[") {
+        result.push_str(&remaining[..start]);
+        let embed = remaining[..start].ends_with('!');
+        let text = &remaining[start + 2..];
+
+        match text.find("]]").filter(|end| !text[..*end].contains('\n')) {
+            Some(end) => {
+                let mut parts = text[..end].splitn(2, '|');
+                let link = WikiLink {
+                    target: parts.next().unwrap_or_default().trim(),
+                    label: parts.next().map(str::trim),
+                    embed,
+                };
+
+                match replace(&link) {
+                    Some(replacement) => result.push_str(&replacement),
+                    None => result.push_str(&remaining[start..start + end + 4]),
+                }
+                remaining = &text[end + 2..];
+            }
+            None => {
+                result.push_str("[[");
+                remaining = text;
+            }
+        }
+    }
+    result.push_str(remaining);
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn links(markdown: &str) -> Vec<(String, Option<String>, bool)> {
+        let mut links = vec![];
+        replace_wiki_links(markdown, |link| {
+            links.push((
+                link.target.to_owned(),
+                link.label.map(ToOwned::to_owned),
+                link.embed,
+            ));
+            None
+        });
+        links
+    }
+
+    #[test]
+    fn test_resolve() {
+        let mut targets = LinkTargets::default();
+        targets.add("Tavern Names", "tavern-names");
+        targets.add("  ", "blank");
+
+        assert_eq!(targets.resolve(" tavern NAMES "), Some("tavern-names"));
+        assert_eq!(targets.resolve(""), None);
+        assert_eq!(targets.resolve("Waterdeep"), None);
+    }
+
+    #[test]
+    fn test_first_target_wins() {
+        let mut targets = LinkTargets::default();
+        targets.add("Inns", "tavern-names");
+        targets.add("INNS", "other");
+
+        assert_eq!(targets.resolve("inns"), Some("tavern-names"));
+    }
+
+    #[test]
+    fn test_parse_links() {
+        assert_eq!(
+            links("See [[Tavern Names]], [[ Inns | the inns ]] and ![[map.png]]"),
+            vec![
+                ("Tavern Names".to_owned(), None, false),
+                ("Inns".to_owned(), Some("the inns".to_owned()), false),
+                ("map.png".to_owned(), None, true),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unterminated_links() {
+        assert!(links("Not [[a link\n]] here, nor [[this").is_empty());
+    }
+
+    #[test]
+    fn test_replace_links() {
+        assert_eq!(
+            replace_wiki_links("Visit [[Waterdeep|the city]], not [[Luskan]]!", |link| {
+                link.label.map(ToOwned::to_owned)
+            }),
+            "Visit the city, not [[Luskan]]!"
+        );
+    }
+}
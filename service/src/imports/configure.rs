@@ -0,0 +1,38 @@
+use super::ImportsService;
+use crate::{campaigns::CampaignsService, tables::TablesService};
+use std::sync::Arc;
+
+/// Application Configuration for the Imports module
+pub struct ImportsConfig {
+    pub imports_service: ImportsService,
+}
+
+impl ImportsConfig {
+    /// Create the Configuration for the Imports Module
+    ///
+    /// # Parameters
+    /// - `tables_service` - The tables service to create random tables with
+    /// - `campaigns_service` - The campaigns service to create campaigns with
+    ///
+    /// # Returns
+    /// The Imports Config object
+    pub fn new(tables_service: TablesService, campaigns_service: CampaignsService) -> Self {
+        Self {
+            imports_service: ImportsService::new(tables_service, campaigns_service),
+        }
+    }
+
+    /// Generate the configuration callback needed for the HTTP Server to actually add the Imports
+    /// endpoints to the server
+    ///
+    /// # Returns
+    /// The callback to provide to the HTTP Server to configure up the Imports endpoints
+    pub fn configure(&self) -> Arc<dyn Fn(rocket::Rocket) -> rocket::Rocket + Send + Sync> {
+        let imports_service = self.imports_service.clone();
+        Arc::new(move |config| {
+            config
+                .manage(imports_service.clone())
+                .mount("/", rocket::routes![super::endpoints::import_markdown])
+        })
+    }
+}
@@ -0,0 +1,5 @@
+mod errors;
+mod import_markdown;
+pub(crate) mod model;
+
+pub use import_markdown::*;
@@ -0,0 +1,55 @@
+use crate::http::problem::{ProblemType, ValidationType};
+
+/// Problem Types that can happen when importing content
+#[derive(Debug, thiserror::Error)]
+pub enum ImportProblemType {
+    /// The uploaded archive could not be read
+    #[error("The uploaded archive could not be read")]
+    InvalidArchive,
+
+    /// The uploaded archive was larger than the largest that can be imported
+    #[error("The uploaded archive was too large")]
+    ArchiveTooLarge,
+
+    /// The uploaded archive contained more files than can be imported
+    #[error("The uploaded archive contained too many files")]
+    TooManyFiles,
+}
+
+impl ProblemType for ImportProblemType {
+    /// Generate a Type value for the `ProblemType` values.
+    ///
+    /// These are used in the `type` field in the RFC-7807 Problem Response
+    fn error_code(&self) -> &'static str {
+        match self {
+            ImportProblemType::InvalidArchive => {
+                "tag:multiverse,2020:imports/problems/invalid_archive"
+            }
+            ImportProblemType::ArchiveTooLarge => {
+                "tag:multiverse,2020:imports/problems/archive_too_large"
+            }
+            ImportProblemType::TooManyFiles => {
+                "tag:multiverse,2020:imports/problems/too_many_files"
+            }
+        }
+    }
+}
+
+/// Validation errors that are specific to imports
+#[derive(Debug, thiserror::Error)]
+pub enum ImportValidation {
+    /// The uploaded archive was larger than can be imported once it was decompressed
+    #[error("The uploaded archive was too large once decompressed")]
+    TooLarge,
+}
+
+impl ValidationType for ImportValidation {
+    /// Generate a Type value for the `ValidationType` values.
+    fn error_code(&self) -> &'static str {
+        match self {
+            ImportValidation::TooLarge => {
+                "tag:multiverse,2020:imports/problems/validation_error/too_large"
+            }
+        }
+    }
+}
@@ -0,0 +1,82 @@
+use super::{
+    errors::{ImportProblemType, ImportValidation},
+    model::ImportReportResponse,
+};
+use crate::{
+    authorization::Authorizer,
+    http::problem::{Problem, ValidationProblem},
+    imports::{ImportMarkdownError, ImportsService},
+    worlds::{endpoints::world_owner::authorize_world_editor, WorldID, WorldsService},
+};
+use rocket::{http::Status, post, Data, State};
+use std::io::Read;
+
+/// The largest archive, in bytes, that can be imported
+const MAX_ARCHIVE_SIZE: u64 = 10 * 1024 * 1024;
+
+/// Handler to import a vault of Markdown notes, such as an Obsidian vault, into a World.
+///
+/// The request body is a Zip archive of the vault. Notes become random tables or campaigns depending
+/// on their folder or front matter, and everything that couldn't be imported is listed in the report
+///
+/// # Parameters
+/// - `imports_service` - The imports service to use
+/// - `worlds_service` - The worlds service to use
+/// - `world` - The ID of the world to import the notes into
+/// - `body` - The Zip archive of the vault
+/// - `authorizer` - The authorizer to prove we're allowed to add content to the world
+///
+/// # Returns
+/// The report of what was and wasn't imported, or a Problem if the import failed
+#[tracing::instrument(
+    name = "POST /worlds/{id}/import/markdown",
+    skip(imports_service, worlds_service, body)
+)]
+#[post("/worlds/<world>/import/markdown", data = "<body>")]
+pub fn import_markdown(
+    imports_service: State<ImportsService>,
+    worlds_service: State<WorldsService>,
+    world: WorldID,
+    body: Data,
+    authorizer: Authorizer,
+) -> Result<ImportReportResponse, Problem> {
+    authorize_world_editor(&worlds_service, &authorizer, &world)?;
+
+    let mut archive = vec![];
+    body.open()
+        .take(MAX_ARCHIVE_SIZE + 1)
+        .read_to_end(&mut archive)
+        .map_err(|e| {
+            tracing::warn!(e = ?e, "Failed to read uploaded archive");
+            Problem::new(ImportProblemType::InvalidArchive, Status::BadRequest)
+        })?;
+    if archive.len() as u64 > MAX_ARCHIVE_SIZE {
+        tracing::warn!("Uploaded archive was too large");
+        return Err(Problem::new(
+            ImportProblemType::ArchiveTooLarge,
+            Status::PayloadTooLarge,
+        ));
+    }
+
+    let report = imports_service.import_markdown(&world, &archive)?;
+    Ok(ImportReportResponse(report))
+}
+
+impl From<ImportMarkdownError> for Problem {
+    fn from(e: ImportMarkdownError) -> Self {
+        match e {
+            ImportMarkdownError::InvalidArchive => Problem::new(
+                ImportProblemType::InvalidArchive,
+                Status::UnprocessableEntity,
+            ),
+            ImportMarkdownError::TooManyFiles => {
+                Problem::new(ImportProblemType::TooManyFiles, Status::UnprocessableEntity)
+            }
+            ImportMarkdownError::TooLarge => {
+                let mut problem = ValidationProblem::new();
+                problem.with_field_error("archive", ImportValidation::TooLarge);
+                problem.build()
+            }
+        }
+    }
+}
@@ -0,0 +1,3 @@
+mod import_report_response;
+
+pub use import_report_response::*;
@@ -0,0 +1,78 @@
+use crate::{
+    http::link::{Link, LinkRel, Links},
+    imports::ImportReport,
+};
+use rocket::{response, Request};
+use rocket_contrib::json::Json;
+use serde_json::{json, Value};
+
+/// API Model representing the report of an import
+#[derive(Debug)]
+pub struct ImportReportResponse(pub ImportReport);
+
+impl<'r> response::Responder<'r> for ImportReportResponse {
+    fn respond_to(self, req: &Request) -> response::Result<'r> {
+        let imported: Vec<Value> = self
+            .0
+            .imported
+            .iter()
+            .map(|item| {
+                json!({
+                  "path": item.path,
+                  "kind": item.kind.to_string(),
+                  "name": item.name
+                })
+            })
+            .collect();
+        let unmapped: Vec<Value> = self
+            .0
+            .unmapped
+            .iter()
+            .map(|item| {
+                let mut value = json!({
+                  "path": item.path,
+                  "type": item.reason.error_code(),
+                  "title": item.reason.to_string()
+                });
+                if let Some(field) = &item.field {
+                    value["field"] = json!(field);
+                }
+                value
+            })
+            .collect();
+        let unmapped_fields: Vec<Value> = self
+            .0
+            .unmapped_fields
+            .iter()
+            .map(|item| json!({ "path": item.path, "field": item.field }))
+            .collect();
+        let unresolved_links: Vec<Value> = self
+            .0
+            .unresolved_links
+            .iter()
+            .map(|item| json!({ "path": item.path, "link": item.link }))
+            .collect();
+
+        let response_body = json!({
+          "imported": imported,
+          "unmapped": unmapped,
+          "unmapped_fields": unmapped_fields,
+          "unresolved_links": unresolved_links
+        });
+
+        let links: Vec<Link> = self
+            .0
+            .imported
+            .into_iter()
+            .enumerate()
+            .map(|(index, item)| {
+                Link::new(item.link, LinkRel::ITEM).anchor(format!("#/imported/{}", index))
+            })
+            .collect();
+
+        response::Response::build()
+            .merge(Json(response_body).respond_to(req).unwrap())
+            .header(Links(links))
+            .ok()
+    }
+}
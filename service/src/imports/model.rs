@@ -0,0 +1,3 @@
+mod import_report;
+
+pub use import_report::*;
@@ -0,0 +1,145 @@
+use crate::autocomplete::EntityKind;
+
+/// The reasons that an item in an imported vault could not be mapped onto the content of a world
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum UnmappedReason {
+    /// The file was not a Markdown note
+    #[error("The file was not a Markdown note")]
+    UnsupportedFile,
+
+    /// The note was larger than the largest note that can be imported
+    #[error("The note was too large to import")]
+    TooLarge,
+
+    /// The note was not in a folder that maps to a kind of content
+    #[error("The folder of the note did not match any kind of content")]
+    UnknownCategory,
+
+    /// The front matter of the note was not a valid YAML mapping
+    #[error("The front matter of the note could not be parsed")]
+    InvalidFrontMatter,
+
+    /// One of the known fields in the front matter had an invalid value
+    #[error("A field in the front matter had an invalid value")]
+    InvalidField,
+
+    /// A list item in a random table was not a valid table entry
+    #[error("A table entry contained an invalid expression")]
+    InvalidEntry,
+
+    /// The URL Slug is already used by another table in the same world
+    #[error("The URL Slug was already present for this world")]
+    DuplicateUrlSlug,
+
+    /// Importing the note would have taken the owner of the world over their quota
+    #[error("The quota has been exceeded")]
+    QuotaExceeded,
+
+    /// An unknown error occurred
+    #[error("An unknown error occurred")]
+    UnknownError,
+}
+
+impl UnmappedReason {
+    /// Generate a Type value for the reason, in the same style as the `type` field of a Problem
+    pub fn error_code(&self) -> &'static str {
+        match self {
+            UnmappedReason::UnsupportedFile => {
+                "tag:multiverse,2020:imports/problems/unsupported_file"
+            }
+            UnmappedReason::TooLarge => "tag:multiverse,2020:imports/problems/too_large",
+            UnmappedReason::UnknownCategory => {
+                "tag:multiverse,2020:imports/problems/unknown_category"
+            }
+            UnmappedReason::InvalidFrontMatter => {
+                "tag:multiverse,2020:imports/problems/invalid_front_matter"
+            }
+            UnmappedReason::InvalidField => "tag:multiverse,2020:imports/problems/invalid_field",
+            UnmappedReason::InvalidEntry => "tag:multiverse,2020:imports/problems/invalid_entry",
+            UnmappedReason::DuplicateUrlSlug => {
+                "tag:multiverse,2020:imports/problems/duplicate_url_slug"
+            }
+            UnmappedReason::QuotaExceeded => "tag:multiverse,2020:problems/quota_exceeded",
+            UnmappedReason::UnknownError => "tag:multiverse,2020:imports/problems/unknown_error",
+        }
+    }
+}
+
+/// A note that was successfully imported into the world
+#[derive(Debug, PartialEq)]
+pub struct ImportedItem {
+    /// The path of the note within the vault
+    pub path: String,
+    /// The kind of content that the note was imported as
+    pub kind: EntityKind,
+    /// The name of the content that was created
+    pub name: String,
+    /// The link to the content that was created
+    pub link: String,
+}
+
+/// An item in the vault that could not be imported
+#[derive(Debug, PartialEq)]
+pub struct UnmappedItem {
+    /// The path of the item within the vault
+    pub path: String,
+    /// Why the item could not be imported
+    pub reason: UnmappedReason,
+    /// The front matter field that caused the problem, if there was one
+    pub field: Option<String>,
+}
+
+impl UnmappedItem {
+    /// Create a new unmapped item that isn't caused by any specific field
+    pub fn new<P: Into<String>>(path: P, reason: UnmappedReason) -> Self {
+        Self {
+            path: path.into(),
+            reason,
+            field: None,
+        }
+    }
+
+    /// Create a new unmapped item that is caused by a specific front matter field
+    pub fn for_field<P: Into<String>, F: Into<String>>(
+        path: P,
+        reason: UnmappedReason,
+        field: F,
+    ) -> Self {
+        Self {
+            path: path.into(),
+            reason,
+            field: Some(field.into()),
+        }
+    }
+}
+
+/// A front matter field of an imported note that has no equivalent in the world, and so was dropped
+#[derive(Debug, PartialEq)]
+pub struct UnmappedField {
+    /// The path of the note within the vault
+    pub path: String,
+    /// The name of the field
+    pub field: String,
+}
+
+/// A wiki link in an imported note that didn't refer to any content in the world or the vault
+#[derive(Debug, PartialEq)]
+pub struct UnresolvedLink {
+    /// The path of the note within the vault
+    pub path: String,
+    /// The target of the link
+    pub link: String,
+}
+
+/// Report of the outcome of importing a vault of Markdown notes into a world
+#[derive(Debug, Default, PartialEq)]
+pub struct ImportReport {
+    /// The notes that were imported
+    pub imported: Vec<ImportedItem>,
+    /// The items that could not be imported at all
+    pub unmapped: Vec<UnmappedItem>,
+    /// The front matter fields that were dropped from notes that were imported
+    pub unmapped_fields: Vec<UnmappedField>,
+    /// The wiki links in imported notes that couldn't be resolved
+    pub unresolved_links: Vec<UnresolvedLink>,
+}
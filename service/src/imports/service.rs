@@ -0,0 +1,29 @@
+mod front_matter;
+mod import_markdown;
+mod notes;
+mod vault;
+mod wiki_links;
+
+use crate::{campaigns::CampaignsService, tables::TablesService};
+pub use import_markdown::ImportMarkdownError;
+
+/// Service Layer for importing content into worlds from other formats
+#[derive(Clone)]
+pub struct ImportsService {
+    tables: TablesService,
+    campaigns: CampaignsService,
+}
+
+impl ImportsService {
+    /// Create a new instance of the Imports Service
+    ///
+    /// # Parameters
+    /// - `tables` - The tables service to create random tables with
+    /// - `campaigns` - The campaigns service to create campaigns with
+    ///
+    /// # Returns
+    /// The Imports Service ready to use
+    pub(super) fn new(tables: TablesService, campaigns: CampaignsService) -> Self {
+        Self { tables, campaigns }
+    }
+}
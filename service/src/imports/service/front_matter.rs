@@ -0,0 +1,140 @@
+use serde_yaml::Value;
+
+/// The fields of some front matter, in the order they were written
+pub(super) type Fields = Vec<(String, Value)>;
+
+/// Errors that can occur when parsing the front matter of a note
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub(super) enum FrontMatterError {
+    /// The front matter was not valid YAML
+    #[error("The front matter was not valid YAML")]
+    InvalidYaml,
+
+    /// The front matter was valid YAML but was not a mapping of fields
+    #[error("The front matter was not a mapping")]
+    NotMapping,
+}
+
+/// Split a note into its front matter and its body.
+///
+/// Front matter is a YAML mapping at the very start of the note, between two lines consisting of `---`.
+/// Notes that don't start with such a block have no front matter.
+///
+/// # Parameters
+/// - `text` - The text of the note
+///
+/// # Returns
+/// The fields of the front matter and the remaining body of the note
+///
+/// # Errors
+/// If the note has front matter but it isn't a valid YAML mapping
+pub(super) fn split_front_matter(text: &str) -> Result<(Fields, &str), FrontMatterError> {
+    let text = text.trim_start_matches('\u{feff}');
+
+    let first_end = match text.find('\n') {
+        Some(first_end) => first_end,
+        None => return Ok((vec![], text)),
+    };
+    if text[..first_end].trim_end() != "---" {
+        return Ok((vec![], text));
+    }
+
+    let start = first_end + 1;
+    let mut position = start;
+    while position < text.len() {
+        let line_end = text[position..]
+            .find('\n')
+            .map(|index| position + index + 1)
+            .unwrap_or_else(|| text.len());
+        let line = text[position..line_end].trim_end();
+        if line == "---" || line == "..." {
+            let fields = parse_fields(&text[start..position])?;
+            return Ok((fields, &text[line_end..]));
+        }
+        position = line_end;
+    }
+
+    // Without a closing line this was never front matter, just a note that starts with a horizontal rule
+    Ok((vec![], text))
+}
+
+/// Parse the YAML of some front matter into the fields that it contains
+fn parse_fields(yaml: &str) -> Result<Fields, FrontMatterError> {
+    if yaml.trim().is_empty() {
+        return Ok(vec![]);
+    }
+
+    let value: Value = serde_yaml::from_str(yaml).map_err(|e| {
+        tracing::debug!(e = ?e, "Failed to parse front matter");
+        FrontMatterError::InvalidYaml
+    })?;
+
+    match value {
+        Value::Null => Ok(vec![]),
+        Value::Mapping(mapping) => Ok(mapping
+            .into_iter()
+            .filter_map(|(key, value)| {
+                let key = match key {
+                    Value::String(key) => key,
+                    Value::Number(key) => key.to_string(),
+                    Value::Bool(key) => key.to_string(),
+                    _ => return None,
+                };
+                Some((key, value))
+            })
+            .collect()),
+        _ => Err(FrontMatterError::NotMapping),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_no_front_matter() {
+        assert_eq!(
+            split_front_matter("# Title\n\nBody"),
+            Ok((vec![], "# Title\n\nBody"))
+        );
+    }
+
+    #[test]
+    fn test_unclosed_front_matter() {
+        assert_eq!(split_front_matter("---\nBody"), Ok((vec![], "---\nBody")));
+    }
+
+    #[test]
+    fn test_empty_front_matter() {
+        assert_eq!(split_front_matter("---\n---\nBody"), Ok((vec![], "Body")));
+    }
+
+    #[test]
+    fn test_front_matter() {
+        assert_eq!(
+            split_front_matter("---\nname: Tavern Names\ntags:\n  - inns\n---\r\nBody\n"),
+            Ok((
+                vec![
+                    ("name".to_owned(), Value::String("Tavern Names".to_owned())),
+                    (
+                        "tags".to_owned(),
+                        Value::Sequence(vec![Value::String("inns".to_owned())])
+                    ),
+                ],
+                "Body\n"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_invalid_front_matter() {
+        assert_eq!(
+            split_front_matter("---\nname: [unclosed\n---\nBody"),
+            Err(FrontMatterError::InvalidYaml)
+        );
+        assert_eq!(
+            split_front_matter("---\n- one\n- two\n---\nBody"),
+            Err(FrontMatterError::NotMapping)
+        );
+    }
+}
@@ -0,0 +1,211 @@
+use super::{
+    notes::{map_note, MappedNote},
+    vault::{read_vault, ReadVaultError},
+    wiki_links::rewrite_wiki_links,
+    ImportsService,
+};
+use crate::{
+    autocomplete::EntityKind,
+    campaigns::{endpoints::model::CampaignLink, CampaignData, CreateCampaignError},
+    http::wiki_links::LinkTargets,
+    imports::{
+        ImportReport, ImportedItem, UnmappedField, UnmappedItem, UnmappedReason, UnresolvedLink,
+    },
+    model::fetch_all_pages,
+    tables::{endpoints::model::TableLink, CreateTableError, TableData},
+    worlds::WorldID,
+};
+
+/// Errors that can occur when importing a vault of Markdown notes
+#[derive(Debug, thiserror::Error)]
+pub enum ImportMarkdownError {
+    /// The archive containing the vault could not be read
+    #[error("The archive could not be read")]
+    InvalidArchive,
+
+    /// The archive containing the vault had too many files in it
+    #[error("The archive contained too many files")]
+    TooManyFiles,
+
+    /// The archive containing the vault was too large once decompressed
+    #[error("The archive was too large once decompressed")]
+    TooLarge,
+}
+
+impl ImportsService {
+    /// Import a vault of Markdown notes, such as an Obsidian vault, into a world.
+    ///
+    /// Notes are mapped onto random tables and campaigns by their folder or the `type` field of their
+    /// front matter, and wiki links between them are rewritten to refer to the imported content by name.
+    /// Anything that can't be mapped onto the world is left out and recorded in the report instead.
+    ///
+    /// # Parameters
+    /// - `world` - The ID of the world to import the notes into
+    /// - `archive` - The bytes of a Zip archive containing the vault
+    ///
+    /// # Returns
+    /// The report of what was and wasn't imported
+    ///
+    /// # Errors
+    /// If the archive couldn't be read at all, or was too large once decompressed
+    pub fn import_markdown(
+        &self,
+        world: &WorldID,
+        archive: &[u8],
+    ) -> Result<ImportReport, ImportMarkdownError> {
+        let vault = read_vault(archive).map_err(|e| {
+            tracing::warn!(e = ?e, "Failed to read vault");
+            match e {
+                ReadVaultError::InvalidArchive(_) => ImportMarkdownError::InvalidArchive,
+                ReadVaultError::TooManyFiles => ImportMarkdownError::TooManyFiles,
+                ReadVaultError::TooLarge => ImportMarkdownError::TooLarge,
+            }
+        })?;
+
+        let mut report = ImportReport {
+            unmapped: vault.skipped,
+            ..ImportReport::default()
+        };
+
+        let mut notes = vec![];
+        for note in vault.notes {
+            match map_note(&note) {
+                Ok(mapped) => notes.push((note.path, note.stem, mapped)),
+                Err(unmapped) => report.unmapped.push(unmapped),
+            }
+        }
+
+        let mut targets = self.existing_targets(world);
+        for (path, stem, mapped) in &notes {
+            targets.add(&mapped.name, &mapped.name);
+            targets.add(path.trim_end_matches(".md"), &mapped.name);
+            targets.add(stem, &mapped.name);
+            for alias in &mapped.aliases {
+                targets.add(alias, &mapped.name);
+            }
+        }
+
+        for (path, _, mut mapped) in notes {
+            let (description, unresolved) = rewrite_wiki_links(&mapped.description, &targets);
+            mapped.description = description;
+            let unmapped_fields = std::mem::take(&mut mapped.unmapped_fields);
+
+            match self.create_content(world, mapped) {
+                Ok((kind, name, link)) => {
+                    report
+                        .unmapped_fields
+                        .extend(unmapped_fields.into_iter().map(|field| UnmappedField {
+                            path: path.clone(),
+                            field,
+                        }));
+                    report
+                        .unresolved_links
+                        .extend(unresolved.into_iter().map(|link| UnresolvedLink {
+                            path: path.clone(),
+                            link,
+                        }));
+                    report.imported.push(ImportedItem {
+                        path,
+                        kind,
+                        name,
+                        link,
+                    });
+                }
+                Err(reason) => report.unmapped.push(UnmappedItem::new(path, reason)),
+            }
+        }
+
+        tracing::debug!(world = ?world, imported = report.imported.len(), unmapped = report.unmapped.len(), "Imported vault");
+        Ok(report)
+    }
+
+    /// Build the link targets for all of the content that already exists in the world, so that notes can
+    /// link to content that wasn't part of the vault
+    fn existing_targets(&self, world: &WorldID) -> LinkTargets {
+        let mut targets = LinkTargets::default();
+
+        for table in fetch_all_pages(|pagination| self.tables.list_tables(world, true, pagination))
+        {
+            targets.add(&table.data.name, &table.data.name);
+            targets.add(&table.data.url_slug.to_string(), &table.data.name);
+        }
+
+        for campaign in self.campaigns.find_campaigns_in_world(world, true) {
+            targets.add(&campaign.data.name, &campaign.data.name);
+        }
+
+        targets
+    }
+
+    /// Create the content that a single note maps onto
+    ///
+    /// # Returns
+    /// The kind, name and link of the content that was created
+    ///
+    /// # Errors
+    /// The reason that the content couldn't be created
+    fn create_content(
+        &self,
+        world: &WorldID,
+        note: MappedNote,
+    ) -> Result<(EntityKind, String, String), UnmappedReason> {
+        match note.kind {
+            EntityKind::Table => {
+                let table = self.tables.create_table(TableData {
+                    world: world.clone(),
+                    name: note.name,
+                    description: note.description,
+                    url_slug: note.url_slug.expect("Tables always have a URL Slug"),
+                    entries: note.entries,
+                    publication: note.publication,
+                })?;
+
+                Ok((
+                    EntityKind::Table,
+                    table.data.name,
+                    TableLink::new(table.data.world, table.data.url_slug).into(),
+                ))
+            }
+            EntityKind::Campaign => {
+                let campaign = self.campaigns.create_campaign(CampaignData {
+                    world: world.clone(),
+                    name: note.name,
+                    description: note.description,
+                    players: vec![],
+                    publication: note.publication,
+                })?;
+
+                Ok((
+                    EntityKind::Campaign,
+                    campaign.data.name,
+                    CampaignLink::new(campaign.identity.id).into(),
+                ))
+            }
+        }
+    }
+}
+
+impl From<CreateTableError> for UnmappedReason {
+    fn from(e: CreateTableError) -> Self {
+        match e {
+            CreateTableError::DuplicateUrlSlug => UnmappedReason::DuplicateUrlSlug,
+            CreateTableError::QuotaExceeded(_) => UnmappedReason::QuotaExceeded,
+            _ => {
+                tracing::warn!(e = ?e, "Failed to import table");
+                UnmappedReason::UnknownError
+            }
+        }
+    }
+}
+
+impl From<CreateCampaignError> for UnmappedReason {
+    fn from(e: CreateCampaignError) -> Self {
+        match e {
+            CreateCampaignError::QuotaExceeded(_) => UnmappedReason::QuotaExceeded,
+            _ => {
+                tracing::warn!(e = ?e, "Failed to import campaign");
+                UnmappedReason::UnknownError
+            }
+        }
+    }
+}
@@ -0,0 +1,387 @@
+use super::{front_matter::split_front_matter, vault::VaultNote};
+use crate::{
+    autocomplete::EntityKind,
+    imports::{UnmappedItem, UnmappedReason},
+    model::{Publication, PublicationStatus},
+    tables::{TableEntry, Template},
+    worlds::UrlSlug,
+};
+use chrono::{DateTime, Utc};
+use serde_yaml::Value;
+use str_slug::slug;
+
+/// The front matter fields that are understood for every kind of content
+const KNOWN_FIELDS: &[&str] = &["type", "name", "title", "aliases", "status", "publish_at"];
+
+/// A note from a vault, once it has been mapped onto the content of a world
+#[derive(Debug, PartialEq)]
+pub(super) struct MappedNote {
+    /// The kind of content that the note maps to
+    pub kind: EntityKind,
+    /// The name of the content
+    pub name: String,
+    /// Any other names that wiki links can use to refer to the note
+    pub aliases: Vec<String>,
+    /// The URL Slug of the content, for those kinds that have one
+    pub url_slug: Option<UrlSlug>,
+    /// The description of the content, in Markdown
+    pub description: String,
+    /// The entries of a random table
+    pub entries: Vec<TableEntry>,
+    /// The publication state of the content
+    pub publication: Publication,
+    /// The front matter fields that have no equivalent on the content
+    pub unmapped_fields: Vec<String>,
+}
+
+/// Determine the kind of content that notes in a folder map to
+fn category(folder: &str) -> Option<EntityKind> {
+    match slug(folder).as_str() {
+        "tables" | "random-tables" => Some(EntityKind::Table),
+        "campaigns" => Some(EntityKind::Campaign),
+        _ => None,
+    }
+}
+
+/// Get the text of a scalar front matter value
+fn as_text(value: &Value) -> Option<String> {
+    match value {
+        Value::String(value) => Some(value.trim().to_owned()),
+        Value::Number(value) => Some(value.to_string()),
+        Value::Bool(value) => Some(value.to_string()),
+        _ => None,
+    }
+}
+
+/// Get the text of a list item, if the line is one
+fn list_item(line: &str) -> Option<&str> {
+    let line = line.trim_start();
+    if let Some(text) = ["- ", "* ", "+ "]
+        .iter()
+        .find_map(|marker| line.strip_prefix(marker))
+    {
+        return Some(text.trim());
+    }
+
+    let digits = line.chars().take_while(char::is_ascii_digit).count();
+    if digits > 0 {
+        let rest = &line[digits..];
+        if let Some(text) = rest.strip_prefix(". ").or_else(|| rest.strip_prefix(") ")) {
+            return Some(text.trim());
+        }
+    }
+
+    None
+}
+
+/// Split the body of a note for a random table into the description of the table and its entries.
+/// Every list item becomes an entry with a weight of 1, and everything else becomes the description
+fn split_entries(body: &str) -> (String, Vec<TableEntry>) {
+    let mut description = vec![];
+    let mut entries = vec![];
+
+    for line in body.lines() {
+        match list_item(line) {
+            Some(text) if !text.is_empty() => entries.push(TableEntry {
+                weight: 1,
+                text: text.to_owned(),
+            }),
+            _ => description.push(line),
+        }
+    }
+
+    (description.join("\n").trim().to_owned(), entries)
+}
+
+/// Map a note from a vault onto the content of a world.
+///
+/// The kind of content comes from the `type` field of the front matter if present, or else from the
+/// first folder of the note that matches a kind of content. The name comes from the `name` or `title`
+/// field, or else the file name. Notes are imported as drafts unless the `status` field says otherwise.
+///
+/// # Parameters
+/// - `note` - The note to map
+///
+/// # Returns
+/// The mapped note
+///
+/// # Errors
+/// If the note couldn't be mapped onto any content, the details of why not
+pub(super) fn map_note(note: &VaultNote) -> Result<MappedNote, UnmappedItem> {
+    let (fields, body) = split_front_matter(&note.text).map_err(|e| {
+        tracing::debug!(e = ?e, path = ?note.path, "Invalid front matter");
+        UnmappedItem::new(note.path.clone(), UnmappedReason::InvalidFrontMatter)
+    })?;
+
+    let field = |name: &str| {
+        fields
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value)
+            .filter(|value| !value.is_null())
+    };
+    let invalid =
+        |name: &str| UnmappedItem::for_field(note.path.clone(), UnmappedReason::InvalidField, name);
+
+    let kind = match field("type") {
+        Some(value) => as_text(value)
+            .and_then(|kind| kind.to_lowercase().parse().ok())
+            .ok_or_else(|| invalid("type"))?,
+        None => note
+            .folders
+            .iter()
+            .find_map(|folder| category(folder))
+            .ok_or_else(|| UnmappedItem::new(note.path.clone(), UnmappedReason::UnknownCategory))?,
+    };
+
+    let name_field = if field("name").is_some() {
+        "name"
+    } else {
+        "title"
+    };
+    let name = match field(name_field) {
+        Some(value) => as_text(value)
+            .filter(|name| !name.is_empty())
+            .ok_or_else(|| invalid(name_field))?,
+        None => note.stem.trim().to_owned(),
+    };
+
+    let aliases = match field("aliases") {
+        None => vec![],
+        Some(Value::Sequence(values)) => values
+            .iter()
+            .map(as_text)
+            .collect::<Option<Vec<String>>>()
+            .ok_or_else(|| invalid("aliases"))?,
+        Some(value) => vec![as_text(value).ok_or_else(|| invalid("aliases"))?],
+    };
+
+    let publication = match field("status") {
+        None => Publication::Draft,
+        Some(value) => match as_text(value).and_then(|status| status.parse().ok()) {
+            Some(PublicationStatus::Draft) => Publication::Draft,
+            Some(PublicationStatus::Published) => Publication::Published,
+            Some(PublicationStatus::Scheduled) => {
+                let publish_at = field("publish_at")
+                    .and_then(as_text)
+                    .and_then(|publish_at| DateTime::parse_from_rfc3339(&publish_at).ok())
+                    .ok_or_else(|| invalid("publish_at"))?;
+                Publication::Scheduled(publish_at.with_timezone(&Utc))
+            }
+            None => return Err(invalid("status")),
+        },
+    };
+
+    let (description, entries, url_slug) = match kind {
+        EntityKind::Table => {
+            let url_slug = match field("url_slug") {
+                Some(value) => as_text(value),
+                None => Some(slug(&name)),
+            }
+            .and_then(|url_slug| url_slug.parse().ok())
+            .ok_or_else(|| invalid("url_slug"))?;
+
+            let (description, entries) = split_entries(body);
+            if entries
+                .iter()
+                .any(|entry| entry.text.parse::<Template>().is_err())
+            {
+                return Err(UnmappedItem::new(
+                    note.path.clone(),
+                    UnmappedReason::InvalidEntry,
+                ));
+            }
+
+            (description, entries, Some(url_slug))
+        }
+        EntityKind::Campaign => (body.trim().to_owned(), vec![], None),
+    };
+
+    let unmapped_fields = fields
+        .iter()
+        .map(|(key, _)| key)
+        .filter(|key| {
+            let known = KNOWN_FIELDS.contains(&key.as_str())
+                || (kind == EntityKind::Table && key.as_str() == "url_slug");
+            !known
+        })
+        .cloned()
+        .collect();
+
+    Ok(MappedNote {
+        kind,
+        name,
+        aliases,
+        url_slug,
+        description,
+        entries,
+        publication,
+        unmapped_fields,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn note(path: &str, text: &str) -> VaultNote {
+        let mut folders: Vec<String> = path.split('/').map(ToOwned::to_owned).collect();
+        let file_name = folders.pop().unwrap();
+
+        VaultNote {
+            path: path.to_owned(),
+            folders,
+            stem: file_name.trim_end_matches(".md").to_owned(),
+            text: text.to_owned(),
+        }
+    }
+
+    #[test]
+    fn test_map_table() {
+        let mapped = map_note(&note(
+            "Vault/Random Tables/Tavern Names.md",
+            "---\naliases: Inns\nstatus: published\ncolour: red\n---\nNames for [[Inns]]\n\n- The Prancing Pony\n* The {1d3} Broomsticks\n1. The Green Dragon\n",
+        ))
+        .unwrap();
+
+        assert_eq!(
+            mapped,
+            MappedNote {
+                kind: EntityKind::Table,
+                name: "Tavern Names".to_owned(),
+                aliases: vec!["Inns".to_owned()],
+                url_slug: Some("tavern-names".parse().unwrap()),
+                description: "Names for [[Inns]]".to_owned(),
+                entries: vec![
+                    TableEntry {
+                        weight: 1,
+                        text: "The Prancing Pony".to_owned()
+                    },
+                    TableEntry {
+                        weight: 1,
+                        text: "The {1d3} Broomsticks".to_owned()
+                    },
+                    TableEntry {
+                        weight: 1,
+                        text: "The Green Dragon".to_owned()
+                    },
+                ],
+                publication: Publication::Published,
+                unmapped_fields: vec!["colour".to_owned()],
+            }
+        );
+    }
+
+    #[test]
+    fn test_map_campaign_by_type() {
+        let mapped = map_note(&note(
+            "Notes/heist.md",
+            "---\ntype: Campaign\ntitle: Dragon Heist\nurl_slug: heist\n---\n- Session one\n",
+        ))
+        .unwrap();
+
+        assert_eq!(
+            mapped,
+            MappedNote {
+                kind: EntityKind::Campaign,
+                name: "Dragon Heist".to_owned(),
+                aliases: vec![],
+                url_slug: None,
+                description: "- Session one".to_owned(),
+                entries: vec![],
+                publication: Publication::Draft,
+                unmapped_fields: vec!["url_slug".to_owned()],
+            }
+        );
+    }
+
+    #[test]
+    fn test_map_scheduled() {
+        let mapped = map_note(&note(
+            "Campaigns/Heist.md",
+            "---\nstatus: scheduled\npublish_at: 2030-01-01T00:00:00Z\n---\n",
+        ))
+        .unwrap();
+
+        assert_eq!(
+            mapped.publication,
+            Publication::Scheduled("2030-01-01T00:00:00Z".parse().unwrap())
+        );
+    }
+
+    #[test]
+    fn test_map_unknown_category() {
+        assert_eq!(
+            map_note(&note("Characters/Volo.md", "")),
+            Err(UnmappedItem::new(
+                "Characters/Volo.md",
+                UnmappedReason::UnknownCategory
+            ))
+        );
+        assert_eq!(
+            map_note(&note("Volo.md", "")),
+            Err(UnmappedItem::new(
+                "Volo.md",
+                UnmappedReason::UnknownCategory
+            ))
+        );
+    }
+
+    #[test]
+    fn test_map_invalid_front_matter() {
+        assert_eq!(
+            map_note(&note("Tables/Inns.md", "---\nname: [unclosed\n---\n")),
+            Err(UnmappedItem::new(
+                "Tables/Inns.md",
+                UnmappedReason::InvalidFrontMatter
+            ))
+        );
+    }
+
+    #[test]
+    fn test_map_invalid_fields() {
+        assert_eq!(
+            map_note(&note("Tables/Inns.md", "---\ntype: character\n---\n")),
+            Err(UnmappedItem::for_field(
+                "Tables/Inns.md",
+                UnmappedReason::InvalidField,
+                "type"
+            ))
+        );
+        assert_eq!(
+            map_note(&note("Tables/Inns.md", "---\nstatus: hidden\n---\n")),
+            Err(UnmappedItem::for_field(
+                "Tables/Inns.md",
+                UnmappedReason::InvalidField,
+                "status"
+            ))
+        );
+        assert_eq!(
+            map_note(&note("Tables/Inns.md", "---\nstatus: scheduled\n---\n")),
+            Err(UnmappedItem::for_field(
+                "Tables/Inns.md",
+                UnmappedReason::InvalidField,
+                "publish_at"
+            ))
+        );
+        assert_eq!(
+            map_note(&note("Tables/Inns.md", "---\nname:\n  first: Inns\n---\n")),
+            Err(UnmappedItem::for_field(
+                "Tables/Inns.md",
+                UnmappedReason::InvalidField,
+                "name"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_map_invalid_entry() {
+        assert_eq!(
+            map_note(&note("Tables/Inns.md", "- The {3d6 Inn\n")),
+            Err(UnmappedItem::new(
+                "Tables/Inns.md",
+                UnmappedReason::InvalidEntry
+            ))
+        );
+    }
+}
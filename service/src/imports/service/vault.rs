@@ -0,0 +1,253 @@
+use crate::imports::{UnmappedItem, UnmappedReason};
+use std::io::{Cursor, Read};
+use zip::{result::ZipError, ZipArchive};
+
+/// The largest note, in bytes, that will be read out of a vault
+const MAX_NOTE_SIZE: u64 = 1024 * 1024;
+
+/// The most bytes that will be read out of a vault in total, across every note. This is counted from
+/// what is actually decompressed, since the sizes recorded in the archive can't be trusted
+const MAX_VAULT_SIZE: u64 = 20 * 1024 * 1024;
+
+/// The most files and folders that a vault can contain
+const MAX_ENTRIES: usize = 2000;
+
+/// Errors that can occur when reading a vault
+#[derive(Debug, thiserror::Error)]
+pub(super) enum ReadVaultError {
+    /// The archive could not be read
+    #[error("The archive could not be read: {0}")]
+    InvalidArchive(#[from] ZipError),
+
+    /// The archive contained more files than can be imported
+    #[error("The archive contained too many files")]
+    TooManyFiles,
+
+    /// The notes in the archive were larger in total than can be imported
+    #[error("The archive was too large once decompressed")]
+    TooLarge,
+}
+
+/// A single Markdown note read out of a vault
+#[derive(Debug, PartialEq)]
+pub(super) struct VaultNote {
+    /// The path of the note within the vault
+    pub path: String,
+    /// The folders that the note is within, starting from the root of the vault
+    pub folders: Vec<String>,
+    /// The file name of the note, without the `.md` extension
+    pub stem: String,
+    /// The full text of the note, including any front matter
+    pub text: String,
+}
+
+/// The contents of a vault that has been read out of a Zip archive
+#[derive(Debug, Default, PartialEq)]
+pub(super) struct Vault {
+    /// The notes in the vault, ordered by path
+    pub notes: Vec<VaultNote>,
+    /// The files in the vault that couldn't be read as notes
+    pub skipped: Vec<UnmappedItem>,
+}
+
+/// Determine if a path within the archive should be ignored entirely. This covers directories, and the
+/// hidden files and folders that editors and operating systems leave behind, such as `.obsidian/`
+fn is_ignored(path: &str) -> bool {
+    path.ends_with('/')
+        || path
+            .split('/')
+            .any(|part| part.starts_with('.') || part == "__MACOSX")
+}
+
+/// Read the notes out of a vault that has been packaged as a Zip archive
+///
+/// # Parameters
+/// - `archive` - The bytes of the Zip archive
+///
+/// # Returns
+/// The contents of the vault
+///
+/// # Errors
+/// If the archive could not be read, contained too many files, or was too large once decompressed
+pub(super) fn read_vault(archive: &[u8]) -> Result<Vault, ReadVaultError> {
+    let mut zip = ZipArchive::new(Cursor::new(archive))?;
+    if zip.len() > MAX_ENTRIES {
+        return Err(ReadVaultError::TooManyFiles);
+    }
+
+    let paths: Vec<String> = zip
+        .file_names()
+        .filter(|path| !is_ignored(path))
+        .map(ToOwned::to_owned)
+        .collect();
+
+    let mut vault = Vault::default();
+    let mut total_size = 0;
+    for path in paths {
+        let mut file = zip.by_name(&path)?;
+
+        if !path.to_lowercase().ends_with(".md") {
+            vault
+                .skipped
+                .push(UnmappedItem::new(path, UnmappedReason::UnsupportedFile));
+            continue;
+        }
+        if file.size() > MAX_NOTE_SIZE {
+            vault
+                .skipped
+                .push(UnmappedItem::new(path, UnmappedReason::TooLarge));
+            continue;
+        }
+
+        // The size in the archive can't be trusted, so never read more than the limit
+        let mut contents = vec![];
+        (&mut file)
+            .take(MAX_NOTE_SIZE + 1)
+            .read_to_end(&mut contents)
+            .map_err(ZipError::from)?;
+        total_size += contents.len() as u64;
+        if total_size > MAX_VAULT_SIZE {
+            return Err(ReadVaultError::TooLarge);
+        }
+        if contents.len() as u64 > MAX_NOTE_SIZE {
+            vault
+                .skipped
+                .push(UnmappedItem::new(path, UnmappedReason::TooLarge));
+            continue;
+        }
+
+        let text = match String::from_utf8(contents) {
+            Ok(text) => text,
+            Err(_) => {
+                vault
+                    .skipped
+                    .push(UnmappedItem::new(path, UnmappedReason::UnsupportedFile));
+                continue;
+            }
+        };
+
+        let mut folders: Vec<String> = path.split('/').map(ToOwned::to_owned).collect();
+        let file_name = folders.pop().unwrap_or_default();
+        let stem = file_name[..file_name.len() - 3].to_owned();
+
+        vault.notes.push(VaultNote {
+            path,
+            folders,
+            stem,
+            text,
+        });
+    }
+
+    vault.notes.sort_by(|a, b| a.path.cmp(&b.path));
+    vault.skipped.sort_by(|a, b| a.path.cmp(&b.path));
+
+    Ok(vault)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use zip::{write::FileOptions, ZipWriter};
+
+    fn archive(files: &[(&str, &[u8])]) -> Vec<u8> {
+        let mut zip = ZipWriter::new(Cursor::new(vec![]));
+        for (path, contents) in files {
+            if path.ends_with('/') {
+                zip.add_directory(*path, FileOptions::default()).unwrap();
+            } else {
+                zip.start_file(*path, FileOptions::default()).unwrap();
+                zip.write_all(contents).unwrap();
+            }
+        }
+        zip.finish().unwrap().into_inner()
+    }
+
+    #[test]
+    fn test_invalid_archive() {
+        assert!(read_vault(b"Not a zip file").is_err());
+    }
+
+    #[test]
+    fn test_too_many_files() {
+        let names: Vec<String> = (0..=MAX_ENTRIES).map(|i| format!("{}.md", i)).collect();
+        let files: Vec<(&str, &[u8])> =
+            names.iter().map(|name| (name.as_str(), &b""[..])).collect();
+
+        assert!(matches!(
+            read_vault(&archive(&files)),
+            Err(ReadVaultError::TooManyFiles)
+        ));
+    }
+
+    #[test]
+    fn test_note_too_large() {
+        let large = vec![b'a'; MAX_NOTE_SIZE as usize + 1];
+        let vault = read_vault(&archive(&[
+            ("Large.md", &large[..]),
+            ("Small.md", b"Small"),
+        ]))
+        .unwrap();
+
+        assert_eq!(vault.notes.len(), 1);
+        assert_eq!(
+            vault.skipped,
+            vec![UnmappedItem::new("Large.md", UnmappedReason::TooLarge)]
+        );
+    }
+
+    #[test]
+    fn test_vault_too_large() {
+        let note = vec![b'a'; MAX_NOTE_SIZE as usize];
+        let names: Vec<String> = (0..=MAX_VAULT_SIZE / MAX_NOTE_SIZE)
+            .map(|i| format!("{}.md", i))
+            .collect();
+        let files: Vec<(&str, &[u8])> = names
+            .iter()
+            .map(|name| (name.as_str(), &note[..]))
+            .collect();
+
+        assert!(matches!(
+            read_vault(&archive(&files)),
+            Err(ReadVaultError::TooLarge)
+        ));
+    }
+
+    #[test]
+    fn test_read_vault() {
+        let vault = read_vault(&archive(&[
+            ("Tables/", b""),
+            ("Tables/Tavern Names.md", b"- The Prancing Pony"),
+            ("README.md", b"# My Vault"),
+            ("Images/map.png", b"PNG"),
+            ("Broken.md", &[0xff, 0xfe]),
+            (".obsidian/workspace.json", b"{}"),
+            ("Tables/.DS_Store", b""),
+        ]))
+        .unwrap();
+
+        assert_eq!(
+            vault,
+            Vault {
+                notes: vec![
+                    VaultNote {
+                        path: "README.md".to_owned(),
+                        folders: vec![],
+                        stem: "README".to_owned(),
+                        text: "# My Vault".to_owned(),
+                    },
+                    VaultNote {
+                        path: "Tables/Tavern Names.md".to_owned(),
+                        folders: vec!["Tables".to_owned()],
+                        stem: "Tavern Names".to_owned(),
+                        text: "- The Prancing Pony".to_owned(),
+                    },
+                ],
+                skipped: vec![
+                    UnmappedItem::new("Broken.md", UnmappedReason::UnsupportedFile),
+                    UnmappedItem::new("Images/map.png", UnmappedReason::UnsupportedFile),
+                ],
+            }
+        );
+    }
+}
@@ -0,0 +1,123 @@
+use crate::http::wiki_links::{replace_wiki_links, LinkTargets};
+
+/// Resolve the target of a wiki link from the vault into the name of the content that it refers to.
+///
+/// Links may refer to a note by its full path within the vault or by its file name, with or without
+/// the `.md` extension
+///
+/// # Parameters
+/// - `targets` - The targets that links can resolve to
+/// - `target` - The target of the wiki link
+///
+/// # Returns
+/// The name of the content, if the link resolves to anything
+fn resolve<'a>(targets: &'a LinkTargets, target: &str) -> Option<&'a str> {
+    let target = target.strip_suffix(".md").unwrap_or(target);
+    let file_name = target.rsplit('/').next().unwrap_or(target);
+
+    targets
+        .resolve(target)
+        .or_else(|| targets.resolve(file_name))
+}
+
+/// Rewrite the wiki links in some Markdown from the vault so that they refer to content by name.
+///
+/// The target of a link may include a heading or block reference after a `#`. Links that resolve are
+/// rewritten to `[[Name]]` or `[[Name|Label]]`, keeping the text that was displayed in the vault. Links
+/// that don't resolve, and embeds of the form `![[Target]]`, are left untouched.
+///
+/// # Parameters
+/// - `markdown` - The Markdown to rewrite
+/// - `targets` - The targets that links can resolve to
+///
+/// # Returns
+/// The rewritten Markdown, and the targets of every link that couldn't be resolved
+pub(super) fn rewrite_wiki_links(markdown: &str, targets: &LinkTargets) -> (String, Vec<String>) {
+    let mut unresolved = vec![];
+
+    let result = replace_wiki_links(markdown, |link| {
+        let target = link.target.split('#').next().unwrap_or_default().trim();
+
+        match resolve(targets, target).filter(|_| !link.embed && !target.is_empty()) {
+            Some(name) => {
+                let label = link.label.unwrap_or(target);
+                if label == name {
+                    Some(format!("[[{}]]", name))
+                } else {
+                    Some(format!("[[{}|{}]]", name, label))
+                }
+            }
+            None => {
+                if !target.is_empty() {
+                    unresolved.push(target.to_owned());
+                }
+                None
+            }
+        }
+    });
+
+    (result, unresolved)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn targets() -> LinkTargets {
+        let mut targets = LinkTargets::default();
+        targets.add("Tables/tavern-names", "Tavern Names");
+        targets.add("tavern-names", "Tavern Names");
+        targets.add("Tavern Names", "Tavern Names");
+        targets.add("Inns", "Tavern Names");
+        targets.add("Dragon Heist", "Dragon Heist");
+        targets
+    }
+
+    #[test]
+    fn test_resolve() {
+        let targets = targets();
+        assert_eq!(resolve(&targets, "tavern names"), Some("Tavern Names"));
+        assert_eq!(
+            resolve(&targets, "Tables/tavern-names.md"),
+            Some("Tavern Names")
+        );
+        assert_eq!(
+            resolve(&targets, "Other/tavern-names"),
+            Some("Tavern Names")
+        );
+        assert_eq!(resolve(&targets, "Volo"), None);
+    }
+
+    #[test]
+    fn test_rewrite_links() {
+        let (markdown, unresolved) = rewrite_wiki_links(
+            "See [[Tavern Names]], [[tavern-names]], [[Inns#Famous|the inns]] and [[Dragon Heist]]",
+            &targets(),
+        );
+        assert_eq!(
+            markdown,
+            "See [[Tavern Names]], [[Tavern Names|tavern-names]], [[Tavern Names|the inns]] and [[Dragon Heist]]"
+        );
+        assert!(unresolved.is_empty());
+    }
+
+    #[test]
+    fn test_unresolved_links() {
+        let (markdown, unresolved) = rewrite_wiki_links(
+            "Met [[Volo|the author]], saw ![[Tavern Names]] and ![[map.png]] [[unclosed",
+            &targets(),
+        );
+        assert_eq!(
+            markdown,
+            "Met [[Volo|the author]], saw ![[Tavern Names]] and ![[map.png]] [[unclosed"
+        );
+        assert_eq!(
+            unresolved,
+            vec![
+                "Volo".to_owned(),
+                "Tavern Names".to_owned(),
+                "map.png".to_owned()
+            ]
+        );
+    }
+}
@@ -1,3 +1,4 @@
+pub(crate) mod blobs;
 pub(crate) mod database;
 pub(crate) mod healthchecker;
 pub(super) mod server;
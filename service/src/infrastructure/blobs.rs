@@ -0,0 +1,87 @@
+mod local;
+
+pub use local::LocalBlobStore;
+use std::sync::Arc;
+
+/// Errors that can occur when working with a blob store
+#[derive(Debug, thiserror::Error)]
+pub enum BlobStoreError {
+    /// The key of the blob was not valid
+    #[error("The blob key was invalid")]
+    InvalidKey,
+
+    /// An error occurred reading or writing the blob
+    #[error("An error occurred accessing the blob store")]
+    IoError(#[from] std::io::Error),
+}
+
+/// Storage for arbitrary binary data, such as uploaded files.
+///
+/// Blobs are identified by a key made of `/`-separated segments, each of which may only contain ASCII
+/// letters, digits, `.`, `-` and `_`
+pub trait BlobStore: Send + Sync {
+    /// Store a blob, replacing any existing blob with the same key
+    ///
+    /// # Parameters
+    /// - `key` - The key to store the blob under
+    /// - `data` - The data to store
+    fn put(&self, key: &str, data: &[u8]) -> Result<(), BlobStoreError>;
+
+    /// Load a blob
+    ///
+    /// # Parameters
+    /// - `key` - The key of the blob
+    ///
+    /// # Returns
+    /// The data of the blob, or `None` if there is no blob with this key
+    fn get(&self, key: &str) -> Result<Option<Vec<u8>>, BlobStoreError>;
+
+    /// Delete a blob. Deleting a blob that doesn't exist is not an error
+    ///
+    /// # Parameters
+    /// - `key` - The key of the blob
+    fn delete(&self, key: &str) -> Result<(), BlobStoreError>;
+}
+
+/// A blob store that can be shared between the components that need it
+pub type SharedBlobStore = Arc<dyn BlobStore>;
+
+/// Check if a blob key is valid
+///
+/// # Parameters
+/// - `key` - The key to check
+///
+/// # Returns
+/// `true` if the key is valid
+pub(crate) fn is_valid_key(key: &str) -> bool {
+    key.split('/').all(|segment| {
+        !segment.is_empty()
+            && segment != "."
+            && segment != ".."
+            && segment
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '.' || c == '-' || c == '_')
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_keys() {
+        assert!(is_valid_key("attachments/3d3b9f0c-3c2b-4d8e"));
+        assert!(is_valid_key("avatars/user_1.png"));
+        assert!(is_valid_key("blob"));
+    }
+
+    #[test]
+    fn test_invalid_keys() {
+        assert!(!is_valid_key(""));
+        assert!(!is_valid_key("/attachments/abc"));
+        assert!(!is_valid_key("attachments//abc"));
+        assert!(!is_valid_key("attachments/../../etc/passwd"));
+        assert!(!is_valid_key("attachments/a b"));
+        assert!(!is_valid_key("attachments\\abc"));
+    }
+}
@@ -0,0 +1,138 @@
+use super::{is_valid_key, BlobStore, BlobStoreError};
+use std::{
+    fs,
+    io::ErrorKind,
+    path::{Path, PathBuf},
+};
+use uuid::Uuid;
+
+/// Blob store that keeps blobs as files on the local filesystem
+#[derive(Debug)]
+pub struct LocalBlobStore {
+    /// The directory that all blobs are stored under
+    root: PathBuf,
+}
+
+impl LocalBlobStore {
+    /// Create a new blob store rooted at the given directory. The directory is created when the first
+    /// blob is stored if it doesn't already exist
+    ///
+    /// # Parameters
+    /// - `root` - The directory to store blobs under
+    ///
+    /// # Returns
+    /// The blob store
+    pub fn new<P>(root: P) -> Self
+    where
+        P: Into<PathBuf>,
+    {
+        Self { root: root.into() }
+    }
+
+    /// Get the path of the file that stores the blob with the given key
+    ///
+    /// # Parameters
+    /// - `key` - The key of the blob
+    ///
+    /// # Returns
+    /// The path to the file
+    fn path(&self, key: &str) -> Result<PathBuf, BlobStoreError> {
+        if is_valid_key(key) {
+            Ok(key
+                .split('/')
+                .fold(self.root.clone(), |path, segment| path.join(segment)))
+        } else {
+            Err(BlobStoreError::InvalidKey)
+        }
+    }
+}
+
+impl BlobStore for LocalBlobStore {
+    /// Store a blob, replacing any existing blob with the same key.
+    ///
+    /// The data is written to a temporary file first and then moved into place, so that readers never
+    /// see a partially written blob
+    #[tracing::instrument(skip(self, data))]
+    fn put(&self, key: &str, data: &[u8]) -> Result<(), BlobStoreError> {
+        let path = self.path(key)?;
+        let parent = path.parent().unwrap_or_else(|| Path::new("."));
+        fs::create_dir_all(parent)?;
+
+        let temp_path = parent.join(format!(".{}.tmp", Uuid::new_v4()));
+        fs::write(&temp_path, data)?;
+        fs::rename(&temp_path, &path).map_err(|e| {
+            let _ = fs::remove_file(&temp_path);
+            e
+        })?;
+
+        tracing::debug!(path = ?path, size = data.len(), "Stored blob");
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self))]
+    fn get(&self, key: &str) -> Result<Option<Vec<u8>>, BlobStoreError> {
+        match fs::read(self.path(key)?) {
+            Ok(data) => Ok(Some(data)),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    #[tracing::instrument(skip(self))]
+    fn delete(&self, key: &str) -> Result<(), BlobStoreError> {
+        match fs::remove_file(self.path(key)?) {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn store() -> (LocalBlobStore, PathBuf) {
+        let root = std::env::temp_dir().join(format!("multiverse-blobs-{}", Uuid::new_v4()));
+        (LocalBlobStore::new(root.clone()), root)
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let (store, root) = store();
+
+        assert_eq!(store.get("attachments/abc").unwrap(), None);
+
+        store.put("attachments/abc", b"Hello").unwrap();
+        assert_eq!(
+            store.get("attachments/abc").unwrap(),
+            Some(b"Hello".to_vec())
+        );
+
+        store.put("attachments/abc", b"World").unwrap();
+        assert_eq!(
+            store.get("attachments/abc").unwrap(),
+            Some(b"World".to_vec())
+        );
+
+        store.delete("attachments/abc").unwrap();
+        assert_eq!(store.get("attachments/abc").unwrap(), None);
+        store.delete("attachments/abc").unwrap();
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_invalid_key() {
+        let (store, _) = store();
+
+        assert!(matches!(
+            store.put("../outside", b"Hello"),
+            Err(BlobStoreError::InvalidKey)
+        ));
+        assert!(matches!(
+            store.get("../outside"),
+            Err(BlobStoreError::InvalidKey)
+        ));
+    }
+}
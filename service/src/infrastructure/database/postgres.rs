@@ -7,6 +7,8 @@ use std::str::FromStr;
 pub struct Database {
     /// The actual connection pool connecting to the database
     pool: Pool<PostgresConnectionManager<postgres::tls::NoTls>>,
+    /// The configuration used to connect to the database
+    config: postgres::config::Config,
 }
 
 impl Database {
@@ -25,7 +27,7 @@ impl Database {
         tracing::info!(url = ?url, "Connecting to database");
 
         let config = postgres::config::Config::from_str(&url).expect("Failed to parse URL");
-        let manager = PostgresConnectionManager::new(config, postgres::NoTls);
+        let manager = PostgresConnectionManager::new(config.clone(), postgres::NoTls);
 
         let pool = Pool::builder()
             .connection_timeout(std::time::Duration::from_secs(10))
@@ -33,7 +35,7 @@ impl Database {
             .expect("Failed to create connection pool");
 
         pool.get().expect("Failed to check out connection");
-        Self { pool }
+        Self { pool, config }
     }
 
     /// Check out a connection with which we can send queries to the database
@@ -48,6 +50,19 @@ impl Database {
             .get()
             .map_err(|e| DatabaseError::Checkout(format!("{}", e)))
     }
+
+    /// Open a new connection to the database that isn't part of the connection pool.
+    ///
+    /// This is for work that holds on to a connection indefinitely, such as listening for
+    /// notifications, which would otherwise permanently take a connection away from the pool
+    ///
+    /// # Returns
+    /// A Postgres connection that belongs solely to the caller
+    pub fn connect(&self) -> Result<postgres::Client, DatabaseError> {
+        self.config
+            .connect(postgres::NoTls)
+            .map_err(|e| DatabaseError::Connect(format!("{}", e)))
+    }
 }
 
 /// Errors that can happen when working with the database
@@ -56,4 +71,8 @@ pub enum DatabaseError {
     /// An error occurred trying to check out a connection from the connection pool
     #[error("Error checking out connection: {0}")]
     Checkout(String),
+
+    /// An error occurred trying to open a new connection to the database
+    #[error("Error connecting to database: {0}")]
+    Connect(String),
 }
@@ -6,16 +6,26 @@ pub type FnConfig = Arc<dyn Fn(Rocket) -> Rocket + Send + Sync>;
 
 /// The actual HTTP Server that will be handling all of the web traffic
 pub struct Server {
+    /// The number of workers to add on top of the default, for long-lived responses
+    reserved_workers: u16,
     configs: Vec<FnConfig>,
 }
 
 impl Server {
     /// Create a new web server that will be ready to process requests
     ///
+    /// # Parameters
+    /// - `reserved_workers` - The number of workers to add on top of the default, so that long-lived
+    ///   responses such as event streams can't starve every other request
+    /// - `configs` - The configuration callbacks for every module
+    ///
     /// # Returns
     /// The web server, ready to work with
-    pub fn new(configs: Vec<FnConfig>) -> Self {
-        Server { configs }
+    pub fn new(reserved_workers: u16, configs: Vec<FnConfig>) -> Self {
+        Server {
+            reserved_workers,
+            configs,
+        }
     }
 
     /// Actually start the web server listening for incomiing HTTP connections
@@ -46,11 +56,12 @@ impl Server {
         .to_cors()
         .unwrap();
 
-        let config =
+        let mut config =
             Config::build(Environment::active().expect("Invalid rocket environment specified"))
                 .port(port)
                 .finalize()
                 .expect("Failed to create rocket config");
+        config.workers = config.workers.saturating_add(self.reserved_workers);
 
         let mut rocket = rocket::custom(config).attach(cors);
 
@@ -1,8 +1,28 @@
 use super::server::Server;
 use crate::{
+    activity::configure::ActivityConfig,
+    attachments::configure::AttachmentsConfig,
     authentication::configure::AuthenticationConfig,
-    infrastructure::{database, healthchecker::configure::HealthcheckConfig},
+    autocomplete::configure::AutocompleteConfig,
+    campaigns::configure::CampaignsConfig,
+    characters::configure::CharactersConfig,
+    comments::configure::CommentsConfig,
+    events::configure::WorldEventsConfig,
+    exports::configure::ExportsConfig,
+    imports::configure::ImportsConfig,
+    infrastructure::{
+        blobs::{LocalBlobStore, SharedBlobStore},
+        database,
+        healthchecker::configure::HealthcheckConfig,
+    },
+    invitations::configure::InvitationsConfig,
+    moderation::configure::ModerationConfig,
+    notifications::configure::NotificationsConfig,
+    quotas::configure::QuotasConfig,
+    tables::configure::TablesConfig,
+    templates::configure::TemplatesConfig,
     users::configure::UsersConfig,
+    webhooks::configure::WebhooksConfig,
     worlds::configure::WorldsConfig,
 };
 use std::sync::Arc;
@@ -26,24 +46,112 @@ impl Service {
         let database = database::Database::new(settings.database_url);
         database::migrate::migrate_database(&database).expect("Failed to migrate database");
 
-        let users = UsersConfig::new(database.clone());
+        let blobs: SharedBlobStore = Arc::new(LocalBlobStore::new(settings.blob_storage_path));
+
+        let users = UsersConfig::new(
+            database.clone(),
+            blobs.clone(),
+            settings.allow_external_avatars,
+        );
+        let quotas = QuotasConfig::new(database.clone(), settings.quotas);
         let authorization = crate::authorization::configure::AuthorizationConfig::new();
         let authentication = AuthenticationConfig::new(
             users.users_service.clone(),
             authorization.authorization_service.clone(),
         );
-        let worlds = WorldsConfig::new(database.clone());
+        let activity = ActivityConfig::new(database.clone());
+        let notifications = NotificationsConfig::new(database.clone());
+        let webhooks = WebhooksConfig::new(database.clone(), settings.allow_loopback_webhooks);
+        let events = WorldEventsConfig::new(database.clone(), settings.max_event_streams.into());
+        let autocomplete = AutocompleteConfig::new(database.clone());
+        let worlds = WorldsConfig::new(
+            database.clone(),
+            activity.activity_service.clone(),
+            notifications.notifications_service.clone(),
+            quotas.quotas_service.clone(),
+            webhooks.webhooks_service.clone(),
+        );
+        let tables = TablesConfig::new(
+            database.clone(),
+            activity.activity_service.clone(),
+            webhooks.webhooks_service.clone(),
+            quotas.quotas_service.clone(),
+        );
+        let campaigns = CampaignsConfig::new(
+            database.clone(),
+            activity.activity_service.clone(),
+            webhooks.webhooks_service.clone(),
+            quotas.quotas_service.clone(),
+        );
+        let characters = CharactersConfig::new(
+            database.clone(),
+            activity.activity_service.clone(),
+            webhooks.webhooks_service.clone(),
+            quotas.quotas_service.clone(),
+        );
+        let invitations = InvitationsConfig::new(
+            database.clone(),
+            activity.activity_service.clone(),
+            notifications.notifications_service.clone(),
+        );
+        let attachments = AttachmentsConfig::new(
+            database.clone(),
+            blobs,
+            settings.max_attachment_size,
+            quotas.quotas_service.clone(),
+        );
+        let exports = ExportsConfig::new(
+            tables.tables_service.clone(),
+            campaigns.campaigns_service.clone(),
+            characters.characters_service.clone(),
+            attachments.attachments_service.clone(),
+        );
+        let imports = ImportsConfig::new(
+            tables.tables_service.clone(),
+            campaigns.campaigns_service.clone(),
+        );
+        let templates = TemplatesConfig::new(
+            database.clone(),
+            worlds.worlds_service.clone(),
+            tables.tables_service.clone(),
+            campaigns.campaigns_service.clone(),
+            quotas.quotas_service.clone(),
+        );
+        let comments = CommentsConfig::new(
+            database.clone(),
+            notifications.notifications_service.clone(),
+        );
+        let moderation = ModerationConfig::new(database.clone(), users.users_service.clone());
 
         let healthchecks = HealthcheckConfig::default().with_component("db", Arc::new(database));
 
         Service {
-            server: Server::new(vec![
-                healthchecks.configure(),
-                users.configure(),
-                authorization.configure(),
-                authentication.configure(),
-                worlds.configure(),
-            ]),
+            server: Server::new(
+                settings.max_event_streams,
+                vec![
+                    healthchecks.configure(),
+                    users.configure(),
+                    quotas.configure(),
+                    authorization.configure(),
+                    authentication.configure(),
+                    worlds.configure(),
+                    tables.configure(),
+                    campaigns.configure(),
+                    characters.configure(),
+                    invitations.configure(),
+                    comments.configure(),
+                    attachments.configure(),
+                    activity.configure(),
+                    notifications.configure(),
+                    webhooks.configure(),
+                    events.configure(),
+                    autocomplete.configure(),
+                    exports.configure(),
+                    imports.configure(),
+                    templates.configure(),
+                    moderation.configure(),
+                ],
+            ),
         }
     }
 
@@ -0,0 +1,57 @@
+use super::{repository::InvitationRepository, InvitationsService};
+use crate::{
+    activity::ActivityService, infrastructure::database::Database,
+    notifications::NotificationsService,
+};
+use std::sync::Arc;
+
+/// Application Configuration for the Invitations module
+pub struct InvitationsConfig {
+    pub invitations_service: InvitationsService,
+}
+
+impl InvitationsConfig {
+    /// Create the Configuration for the Invitations Module
+    ///
+    /// # Parameters
+    /// - `database` - The database connection to use
+    /// - `activity_service` - The activity service to record activity with
+    /// - `notifications_service` - The notifications service to notify users with
+    ///
+    /// # Returns
+    /// The Invitations Config object
+    pub fn new(
+        database: Database,
+        activity_service: ActivityService,
+        notifications_service: NotificationsService,
+    ) -> Self {
+        let repository = InvitationRepository::new(database);
+        Self {
+            invitations_service: InvitationsService::new(
+                repository,
+                activity_service,
+                notifications_service,
+            ),
+        }
+    }
+
+    /// Generate the configuration callback needed for the HTTP Server to actually add the Invitations
+    /// endpoints to the server
+    ///
+    /// # Returns
+    /// The callback to provide to the HTTP Server to configure up the Invitations endpoints
+    pub fn configure(&self) -> Arc<dyn Fn(rocket::Rocket) -> rocket::Rocket + Send + Sync> {
+        let invitations_service = self.invitations_service.clone();
+        Arc::new(move |config| {
+            config.manage(invitations_service.clone()).mount(
+                "/",
+                rocket::routes![
+                    super::endpoints::accept_invitation,
+                    super::endpoints::create_invitation,
+                    super::endpoints::list_invitations,
+                    super::endpoints::revoke_invitation
+                ],
+            )
+        })
+    }
+}
@@ -0,0 +1,11 @@
+mod accept_invitation;
+mod create_invitation;
+mod errors;
+mod list_invitations;
+pub(crate) mod model;
+mod revoke_invitation;
+
+pub use accept_invitation::*;
+pub use create_invitation::*;
+pub use list_invitations::*;
+pub use revoke_invitation::*;
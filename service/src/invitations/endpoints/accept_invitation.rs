@@ -0,0 +1,74 @@
+use super::{errors::InvitationProblemType, model::MembershipResponse};
+use crate::{
+    authorization::Authorizer,
+    http::problem::Problem,
+    invitations::{AcceptInvitationError, InvitationToken, InvitationsService},
+    worlds::{endpoints::errors::WorldProblemType, WorldsService},
+};
+use rocket::{http::Status, post, State};
+
+/// Handler to accept an Invitation, making the current user a member of the world it is for
+///
+/// # Parameters
+/// - `invitations_service` - The invitations service to use
+/// - `worlds_service` - The worlds service to use
+/// - `token` - The token of the invitation to accept
+/// - `authorizer` - The authorizer to prove who is accepting the invitation
+///
+/// # Returns
+/// The details of the new membership, or a Problem if the invitation couldn't be accepted
+#[tracing::instrument(
+    name = "POST /invitations/{token}/accept",
+    skip(invitations_service, worlds_service)
+)]
+#[post("/invitations/<token>/accept")]
+pub fn accept_invitation(
+    invitations_service: State<InvitationsService>,
+    worlds_service: State<WorldsService>,
+    token: InvitationToken,
+    authorizer: Authorizer,
+) -> Result<MembershipResponse, Problem> {
+    let user = authorizer
+        .authorize()
+        .authorized()
+        .finish()?
+        .expect("No authenticated user");
+
+    let invitation = invitations_service.find_invitation_by_token(&token)?;
+
+    let world = worlds_service
+        .find_world_by_id(invitation.data.world.clone())
+        .ok_or_else(|| Problem::new(WorldProblemType::UnknownWorldID, Status::NotFound))?;
+    if world.data.owner == user {
+        tracing::warn!(world = ?world, "World owner can not accept an invitation to their own world");
+        return Err(AcceptInvitationError::AlreadyMember.into());
+    }
+
+    invitations_service.accept_invitation(&invitation, &user)?;
+
+    Ok(MembershipResponse(invitation))
+}
+
+impl From<AcceptInvitationError> for Problem {
+    fn from(e: AcceptInvitationError) -> Self {
+        match e {
+            AcceptInvitationError::UnknownInvitation => {
+                Problem::new(InvitationProblemType::UnknownInvitation, Status::NotFound)
+            }
+            AcceptInvitationError::ExpiredInvitation => {
+                Problem::new(InvitationProblemType::ExpiredInvitation, Status::Gone)
+            }
+            AcceptInvitationError::AlreadyMember => Problem::new(
+                InvitationProblemType::AlreadyMember,
+                Status::UnprocessableEntity,
+            ),
+            _ => {
+                tracing::warn!(error = ?e, "An unexpected error occurred");
+                Problem::new(
+                    InvitationProblemType::UnknownError,
+                    Status::InternalServerError,
+                )
+            }
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use super::{
+    errors::InvitationProblemType,
+    model::{InvitationRequest, InvitationResponse},
+};
+use crate::{
+    authorization::Authorizer,
+    http::problem::Problem,
+    invitations::{CreateInvitationError, InvitationsService},
+    worlds::{
+        endpoints::{errors::WorldProblemType, world_owner::authorize_world_owner},
+        WorldID, WorldsService,
+    },
+};
+use chrono::Utc;
+use rocket::{http::Status, post, State};
+use rocket_contrib::json::Json;
+
+/// Handler to create a new Invitation to join a World
+///
+/// # Parameters
+/// - `invitations_service` - The invitations service to use
+/// - `worlds_service` - The worlds service to use
+/// - `world` - The ID of the world to create the invitation for
+/// - `body` - The details of the invitation to create
+/// - `authorizer` - The authorizer to prove we're allowed to create the invitation
+///
+/// # Returns
+/// The newly created invitation details, including the token needed to accept it, or a Problem if the
+/// creation failed
+#[tracing::instrument(
+    name = "POST /worlds/{id}/invitations",
+    skip(invitations_service, worlds_service)
+)]
+#[post("/worlds/<world>/invitations", data = "<body>")]
+pub fn create_invitation(
+    invitations_service: State<InvitationsService>,
+    worlds_service: State<WorldsService>,
+    world: WorldID,
+    body: Json<InvitationRequest>,
+    authorizer: Authorizer,
+) -> Result<InvitationResponse, Problem> {
+    authorize_world_owner(&worlds_service, &authorizer, &world)?;
+
+    let invitation = body.build(world, &Utc::now())?;
+    tracing::debug!(invitation = ?invitation, "Creating new invitation");
+
+    let (new_invitation, token) = invitations_service.create_invitation(invitation)?;
+    Ok(InvitationResponse(new_invitation, Some(token)))
+}
+
+impl From<CreateInvitationError> for Problem {
+    fn from(e: CreateInvitationError) -> Self {
+        match e {
+            CreateInvitationError::UnknownWorld => {
+                Problem::new(WorldProblemType::UnknownWorldID, Status::NotFound)
+            }
+            _ => {
+                tracing::warn!(error = ?e, "An unexpected error occurred");
+                Problem::new(
+                    InvitationProblemType::UnknownError,
+                    Status::InternalServerError,
+                )
+            }
+        }
+    }
+}
@@ -0,0 +1,76 @@
+use crate::http::problem::{ProblemType, ValidationType};
+
+/// Problem Types that can happen when working with invitations
+#[derive(Debug, thiserror::Error)]
+pub enum InvitationProblemType {
+    /// An unknown error occurred
+    #[error("An unknown error occurred")]
+    UnknownError,
+
+    /// The invitation that was looked up was not found
+    #[error("The requested invitation was unknown")]
+    UnknownInvitation,
+
+    /// The invitation has expired or has been used up
+    #[error("The requested invitation has expired")]
+    ExpiredInvitation,
+
+    /// The user is already a member of the world
+    #[error("The user is already a member of the world")]
+    AlreadyMember,
+}
+
+impl ProblemType for InvitationProblemType {
+    /// Generate a Type value for the `ProblemType` values.
+    ///
+    /// These are used in the `type` field in the RFC-7807 Problem Response
+    fn error_code(&self) -> &'static str {
+        match self {
+            InvitationProblemType::UnknownError => {
+                "tag:multiverse,2020:invitations/problems/unknown_error"
+            }
+            InvitationProblemType::UnknownInvitation => {
+                "tag:multiverse,2020:invitations/problems/unknown_invitation"
+            }
+            InvitationProblemType::ExpiredInvitation => {
+                "tag:multiverse,2020:invitations/problems/expired_invitation"
+            }
+            InvitationProblemType::AlreadyMember => {
+                "tag:multiverse,2020:invitations/problems/already_member"
+            }
+        }
+    }
+}
+
+/// Validation errors that are specific to invitations
+#[derive(Debug, thiserror::Error)]
+pub enum InvitationValidation {
+    /// The role was not one of the known roles
+    #[error("The role was not a known role")]
+    InvalidRole,
+
+    /// The expiry was not a valid timestamp in the future
+    #[error("The expiry must be a timestamp in the future")]
+    InvalidExpiry,
+
+    /// The maximum number of uses was not a positive number
+    #[error("The maximum number of uses must be a positive number")]
+    InvalidMaxUses,
+}
+
+impl ValidationType for InvitationValidation {
+    /// Generate a Type value for the `ValidationType` values.
+    fn error_code(&self) -> &'static str {
+        match self {
+            InvitationValidation::InvalidRole => {
+                "tag:multiverse,2020:invitations/problems/validation_error/invalid_role"
+            }
+            InvitationValidation::InvalidExpiry => {
+                "tag:multiverse,2020:invitations/problems/validation_error/invalid_expiry"
+            }
+            InvitationValidation::InvalidMaxUses => {
+                "tag:multiverse,2020:invitations/problems/validation_error/invalid_max_uses"
+            }
+        }
+    }
+}
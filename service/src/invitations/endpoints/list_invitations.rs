@@ -0,0 +1,39 @@
+use super::model::InvitationsResponse;
+use crate::{
+    authorization::Authorizer,
+    http::{pagination::PaginationRequest, problem::Problem},
+    invitations::InvitationsService,
+    worlds::{endpoints::world_owner::authorize_world_owner, WorldID, WorldsService},
+};
+use rocket::{get, State};
+
+/// Handler to list the Invitations for a World
+///
+/// # Parameters
+/// - `invitations_service` - The invitations service to use
+/// - `worlds_service` - The worlds service to use
+/// - `world` - The ID of the world to list the invitations of
+/// - `pagination` - The pagination controls to apply
+/// - `authorizer` - The authorizer to prove we're allowed to see the invitations
+///
+/// # Returns
+/// The details of the invitations for the world
+#[tracing::instrument(
+    name = "GET /worlds/{id}/invitations",
+    skip(invitations_service, worlds_service)
+)]
+#[get("/worlds/<world>/invitations?<pagination..>")]
+pub fn list_invitations(
+    invitations_service: State<InvitationsService>,
+    worlds_service: State<WorldsService>,
+    world: WorldID,
+    pagination: PaginationRequest,
+    authorizer: Authorizer,
+) -> Result<InvitationsResponse, Problem> {
+    authorize_world_owner(&worlds_service, &authorizer, &world)?;
+
+    let invitations = invitations_service.list_invitations(&world, &pagination);
+    tracing::debug!(invitations = ?invitations, "Found invitations");
+
+    Ok(InvitationsResponse(invitations))
+}
@@ -0,0 +1,11 @@
+mod invitation_link;
+mod invitation_request;
+mod invitation_response;
+mod invitations_response;
+mod membership_response;
+
+pub use invitation_link::*;
+pub use invitation_request::*;
+pub use invitation_response::*;
+pub use invitations_response::*;
+pub use membership_response::*;
@@ -0,0 +1,19 @@
+use crate::{invitations::InvitationID, worlds::WorldID};
+
+/// Representation of a link to an invitation
+pub struct InvitationLink(WorldID, InvitationID);
+
+impl InvitationLink {
+    pub fn new(world_id: WorldID, invitation_id: InvitationID) -> Self {
+        Self(world_id, invitation_id)
+    }
+}
+
+impl From<InvitationLink> for String {
+    fn from(invitation_link: InvitationLink) -> String {
+        format!(
+            "/worlds/{}/invitations/{}",
+            invitation_link.0, invitation_link.1
+        )
+    }
+}
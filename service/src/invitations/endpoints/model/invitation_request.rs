@@ -0,0 +1,93 @@
+use crate::{
+    http::problem::{GenericValidation, Problem, ValidationProblem},
+    invitations::{endpoints::errors::InvitationValidation, InvitationData},
+    worlds::{WorldID, WorldRole},
+};
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use std::convert::TryFrom;
+
+/// Incoming details representing an invitation to create
+#[derive(Debug, Deserialize)]
+pub struct InvitationRequest {
+    /// The role that users accepting the invitation are given
+    role: Option<String>,
+    /// When the invitation expires, as an RFC-3339 timestamp. May be omitted
+    expires: Option<String>,
+    /// The number of times the invitation can be accepted. May be omitted
+    max_uses: Option<i64>,
+}
+
+impl InvitationRequest {
+    /// Validate the request and build the details of the invitation that it represents
+    ///
+    /// # Parameters
+    /// - `world` - The ID of the world that the invitation is for
+    /// - `now` - The current time, to ensure that the expiry is in the future
+    ///
+    /// # Returns
+    /// The details of the invitation
+    ///
+    /// # Errors
+    /// A Validation Problem if the request was invalid in any way
+    pub fn build(&self, world: WorldID, now: &DateTime<Utc>) -> Result<InvitationData, Problem> {
+        let mut problem = ValidationProblem::new();
+        let mut valid = true;
+
+        let role = match self.role.as_ref().filter(|v| !v.trim().is_empty()) {
+            None => {
+                problem.with_field_error("role", GenericValidation::Missing);
+                valid = false;
+                None
+            }
+            Some(role) => {
+                let role = role.trim().parse::<WorldRole>().ok();
+                if role.is_none() {
+                    problem.with_field_error("role", InvitationValidation::InvalidRole);
+                    valid = false;
+                }
+                role
+            }
+        };
+
+        let expires = match self.expires.as_ref().filter(|v| !v.trim().is_empty()) {
+            None => None,
+            Some(expires) => {
+                let expires = DateTime::parse_from_rfc3339(expires.trim())
+                    .ok()
+                    .map(|expires| expires.with_timezone(&Utc))
+                    .filter(|expires| expires > now);
+                if expires.is_none() {
+                    problem.with_field_error("expires", InvitationValidation::InvalidExpiry);
+                    valid = false;
+                }
+                expires
+            }
+        };
+
+        let max_uses = match self.max_uses {
+            None => None,
+            Some(max_uses) => {
+                let max_uses = u32::try_from(max_uses).ok().filter(|max| *max > 0);
+                if max_uses.is_none() {
+                    problem.with_field_error("max_uses", InvitationValidation::InvalidMaxUses);
+                    valid = false;
+                }
+                max_uses
+            }
+        };
+
+        if !valid {
+            tracing::warn!("Validation error building invitation");
+            return Err(problem.build());
+        }
+
+        Ok(InvitationData {
+            world,
+            role: role.unwrap(),
+            expires,
+            max_uses,
+            uses: 0,
+        })
+    }
+}
@@ -0,0 +1,54 @@
+use super::InvitationLink;
+use crate::{
+    http::link::{Link, LinkRel, Links},
+    invitations::{InvitationModel, InvitationToken},
+    worlds::endpoints::model::WorldLink,
+};
+use rocket::{
+    http::hyper::header::{CacheControl, CacheDirective, ETag, EntityTag},
+    response, Request,
+};
+use rocket_contrib::json::Json;
+use serde_json::{json, Value};
+
+/// API Model representing an Invitation
+///
+/// The token is only present when the invitation has just been created, since it is never stored.
+#[derive(Debug)]
+pub struct InvitationResponse(pub InvitationModel, pub Option<InvitationToken>);
+
+pub(super) fn to_json(invitation: &InvitationModel) -> Value {
+    json!({
+      "role": invitation.data.role.to_string(),
+      "expires": invitation.data.expires,
+      "max_uses": invitation.data.max_uses,
+      "uses": invitation.data.uses
+    })
+}
+
+impl<'r> response::Responder<'r> for InvitationResponse {
+    fn respond_to(self, req: &Request) -> response::Result<'r> {
+        let etag = self.0.identity.version.to_string();
+
+        let mut body = to_json(&self.0);
+        if let Some(token) = &self.1 {
+            body["token"] = json!(token.as_str());
+        }
+
+        response::Response::build()
+            .merge(Json(body).respond_to(req).unwrap())
+            .header(CacheControl(vec![
+                CacheDirective::Private,
+                CacheDirective::MaxAge(3600),
+            ]))
+            .header(ETag(EntityTag::new(false, etag)))
+            .header(Links(vec![
+                Link::new(
+                    InvitationLink::new(self.0.data.world.clone(), self.0.identity.id.clone()),
+                    LinkRel::SELF,
+                ),
+                Link::new(WorldLink::new(self.0.data.world), LinkRel::RELATED),
+            ]))
+            .ok()
+    }
+}
@@ -0,0 +1,61 @@
+use super::{to_json, InvitationLink};
+use crate::{
+    http::link::{Link, LinkRel, Links},
+    invitations::InvitationModel,
+    model::Page,
+};
+use rocket::{
+    http::hyper::header::{CacheControl, CacheDirective},
+    response, Request,
+};
+use rocket_contrib::json::Json;
+use serde_json::{json, Value};
+
+/// API Model representing a page of Invitations
+#[derive(Debug)]
+pub struct InvitationsResponse(pub Page<InvitationModel>);
+
+impl<'r> response::Responder<'r> for InvitationsResponse {
+    fn respond_to(self, req: &Request) -> response::Result<'r> {
+        let invitations: Vec<Value> = self
+            .0
+            .entries
+            .iter()
+            .map(|invitation| to_json(&invitation))
+            .collect();
+
+        let response_body = json!({
+          "entries": invitations,
+          "pagination": {
+            "offset": self.0.offset,
+            "count": self.0.count,
+            "total": self.0.total
+          }
+        });
+
+        let invitation_links: Vec<Link> = self
+            .0
+            .entries
+            .iter()
+            .map(|invitation| {
+                InvitationLink::new(
+                    invitation.data.world.clone(),
+                    invitation.identity.id.clone(),
+                )
+            })
+            .enumerate()
+            .map(|(index, link)| {
+                Link::new(link, LinkRel::ITEM).anchor(format!("#/entries/{}", index))
+            })
+            .collect();
+
+        response::Response::build()
+            .merge(Json(response_body).respond_to(req).unwrap())
+            .header(CacheControl(vec![
+                CacheDirective::Private,
+                CacheDirective::MaxAge(3600),
+            ]))
+            .header(Links(invitation_links))
+            .ok()
+    }
+}
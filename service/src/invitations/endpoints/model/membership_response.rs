@@ -0,0 +1,33 @@
+use crate::{
+    http::link::{Link, LinkRel, Links},
+    invitations::InvitationModel,
+    worlds::endpoints::model::WorldLink,
+};
+use rocket::{response, Request};
+use rocket_contrib::json::Json;
+use serde_json::json;
+
+/// API Model representing the membership of a world that was granted by accepting an invitation
+#[derive(Debug)]
+pub struct MembershipResponse(pub InvitationModel);
+
+impl<'r> response::Responder<'r> for MembershipResponse {
+    fn respond_to(self, req: &Request) -> response::Result<'r> {
+        let world: String = WorldLink::new(self.0.data.world.clone()).into();
+
+        response::Response::build()
+            .merge(
+                Json(json!({
+                  "world": world,
+                  "role": self.0.data.role.to_string()
+                }))
+                .respond_to(req)
+                .unwrap(),
+            )
+            .header(Links(vec![Link::new(
+                WorldLink::new(self.0.data.world),
+                LinkRel::RELATED,
+            )]))
+            .ok()
+    }
+}
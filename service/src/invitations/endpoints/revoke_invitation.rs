@@ -0,0 +1,43 @@
+use super::errors::InvitationProblemType;
+use crate::{
+    authorization::Authorizer,
+    http::problem::Problem,
+    invitations::{InvitationID, InvitationsService},
+    worlds::{endpoints::world_owner::authorize_world_owner, WorldID, WorldsService},
+};
+use rocket::{delete, http::Status, Response, State};
+
+/// Handler to revoke an existing Invitation so that it can no longer be accepted
+///
+/// # Parameters
+/// - `invitations_service` - The invitations service to use
+/// - `worlds_service` - The worlds service to use
+/// - `world` - The ID of the world that the invitation is for
+/// - `id` - The ID of the invitation to revoke
+/// - `authorizer` - The authorizer to prove we're allowed to revoke the invitation
+///
+/// # Returns
+/// An empty response if the invitation was revoked, or a Problem if revoking failed
+#[tracing::instrument(
+    name = "DELETE /worlds/{id}/invitations/{invitation}",
+    skip(invitations_service, worlds_service)
+)]
+#[delete("/worlds/<world>/invitations/<id>")]
+pub fn revoke_invitation(
+    invitations_service: State<InvitationsService>,
+    worlds_service: State<WorldsService>,
+    world: WorldID,
+    id: InvitationID,
+    authorizer: Authorizer,
+) -> Result<Response<'static>, Problem> {
+    authorize_world_owner(&worlds_service, &authorizer, &world)?;
+
+    if invitations_service.revoke_invitation(&world, &id) {
+        Ok(Response::build().status(Status::NoContent).finalize())
+    } else {
+        Err(Problem::new(
+            InvitationProblemType::UnknownInvitation,
+            Status::NotFound,
+        ))
+    }
+}
@@ -0,0 +1,7 @@
+mod invitation;
+mod invitation_id;
+mod invitation_token;
+
+pub use invitation::*;
+pub use invitation_id::*;
+pub use invitation_token::*;
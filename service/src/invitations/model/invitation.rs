@@ -0,0 +1,96 @@
+use super::InvitationID;
+use crate::{
+    model::Model,
+    worlds::{WorldID, WorldRole},
+};
+use chrono::{DateTime, Utc};
+
+/// Data to represent an invitation for users to join a world
+#[derive(Debug, Clone)]
+pub struct InvitationData {
+    /// The ID of the world that the invitation is for
+    pub world: WorldID,
+
+    /// The role that users accepting the invitation are given
+    pub role: WorldRole,
+
+    /// When the invitation expires, if it ever does
+    pub expires: Option<DateTime<Utc>>,
+
+    /// The number of times the invitation can be accepted, if it is limited
+    pub max_uses: Option<u32>,
+
+    /// The number of times the invitation has been accepted
+    pub uses: u32,
+}
+
+impl InvitationData {
+    /// Determine if the invitation can no longer be accepted, either because it has expired or because it
+    /// has been used up
+    ///
+    /// # Parameters
+    /// - `now` - The time to check the expiry against
+    ///
+    /// # Returns
+    /// True if the invitation can no longer be accepted
+    pub fn is_expired(&self, now: &DateTime<Utc>) -> bool {
+        let expired = self.expires.map(|expires| &expires <= now).unwrap_or(false);
+        let used_up = self.max_uses.map(|max| self.uses >= max).unwrap_or(false);
+
+        expired || used_up
+    }
+}
+
+/// Model representation of an invitation
+pub type InvitationModel = Model<InvitationID, InvitationData>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn invitation(
+        expires: Option<DateTime<Utc>>,
+        max_uses: Option<u32>,
+        uses: u32,
+    ) -> InvitationData {
+        InvitationData {
+            world: WorldID::default(),
+            role: WorldRole::Viewer,
+            expires,
+            max_uses,
+            uses,
+        }
+    }
+
+    #[test]
+    fn test_unlimited_invitation() {
+        let now = Utc::now();
+
+        assert_eq!(invitation(None, None, 1000).is_expired(&now), false);
+    }
+
+    #[test]
+    fn test_expired_invitation() {
+        let now = Utc::now();
+
+        assert_eq!(
+            invitation(Some(now + Duration::hours(1)), None, 0).is_expired(&now),
+            false
+        );
+        assert_eq!(invitation(Some(now), None, 0).is_expired(&now), true);
+        assert_eq!(
+            invitation(Some(now - Duration::hours(1)), None, 0).is_expired(&now),
+            true
+        );
+    }
+
+    #[test]
+    fn test_used_up_invitation() {
+        let now = Utc::now();
+
+        assert_eq!(invitation(None, Some(1), 0).is_expired(&now), false);
+        assert_eq!(invitation(None, Some(1), 1).is_expired(&now), true);
+        assert_eq!(invitation(None, Some(5), 4).is_expired(&now), false);
+    }
+}
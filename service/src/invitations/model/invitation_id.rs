@@ -0,0 +1,61 @@
+use bytes::BytesMut;
+use postgres_types::{accepts, to_sql_checked, FromSql, IsNull, ToSql, Type};
+use rocket::{http::RawStr, request};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use uuid::Uuid;
+
+/// The ID of an Invitation
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, FromSql)]
+pub struct InvitationID(Uuid);
+
+impl InvitationID {
+    /// Wrap a UUID as an Invitation ID object
+    ///
+    /// # Parameters
+    /// - `uuid` - The UUID to wrap
+    ///
+    /// # The Invitation ID
+    pub fn new(uuid: Uuid) -> Self {
+        InvitationID(uuid)
+    }
+}
+
+impl Default for InvitationID {
+    fn default() -> Self {
+        Self(Uuid::new_v4())
+    }
+}
+
+impl ToSql for InvitationID {
+    accepts!(UUID);
+
+    to_sql_checked!();
+
+    fn to_sql(
+        &self,
+        t: &Type,
+        w: &mut BytesMut,
+    ) -> Result<IsNull, Box<dyn std::error::Error + Sync + Send>> {
+        self.0.to_sql(t, w)
+    }
+}
+
+impl<'r> request::FromParam<'r> for InvitationID {
+    type Error = &'r RawStr;
+
+    fn from_param(param: &'r RawStr) -> Result<Self, Self::Error> {
+        param
+            .percent_decode()
+            .map(|cow| cow.into_owned())
+            .map_err(|_| param)
+            .and_then(|invitation_id| Uuid::parse_str(&invitation_id).map_err(|_| param))
+            .map(|invitation_id| InvitationID::new(invitation_id))
+    }
+}
+
+impl Display for InvitationID {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
@@ -0,0 +1,88 @@
+use rand::Rng;
+use rocket::{http::RawStr, request};
+use sha2::{Digest, Sha256};
+
+/// The number of random bytes that make up an invitation token
+const TOKEN_BYTES: usize = 32;
+
+/// Typesafe representation of the secret token that is used to accept an invitation.
+///
+/// Only the hash of the token is ever stored, so the token itself can only be seen when it is first generated.
+#[derive(PartialEq, Clone)]
+pub struct InvitationToken(String);
+
+impl InvitationToken {
+    /// Generate a new, random invitation token
+    ///
+    /// # Returns
+    /// The new token
+    pub fn generate() -> Self {
+        let bytes: [u8; TOKEN_BYTES] = rand::thread_rng().gen();
+        Self(hex::encode(bytes))
+    }
+
+    /// Get the hash of the token, as it is stored in the database
+    ///
+    /// # Returns
+    /// The hex encoded SHA-256 hash of the token
+    pub fn hash(&self) -> String {
+        hex::encode(Sha256::digest(self.0.as_bytes()))
+    }
+
+    /// Get the actual value of the token, to give to the user that created it
+    ///
+    /// # Returns
+    /// The token
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Debug for InvitationToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "InvitationToken(Redacted)")
+    }
+}
+
+impl<'r> request::FromParam<'r> for InvitationToken {
+    type Error = &'r RawStr;
+
+    fn from_param(param: &'r RawStr) -> Result<Self, Self::Error> {
+        param
+            .percent_decode()
+            .map(|cow| cow.trim().to_owned())
+            .map_err(|_| param)
+            .and_then(|token| {
+                if token.is_empty() {
+                    Err(param)
+                } else {
+                    Ok(InvitationToken(token))
+                }
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_is_random() {
+        let first = InvitationToken::generate();
+        let second = InvitationToken::generate();
+
+        assert_eq!(first.as_str().len(), TOKEN_BYTES * 2);
+        assert_ne!(first, second);
+    }
+
+    #[test]
+    fn test_hash_is_stable() {
+        let token = InvitationToken("abc".to_owned());
+
+        assert_eq!(
+            token.hash(),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+        assert_ne!(token.hash(), token.as_str());
+    }
+}
@@ -0,0 +1,29 @@
+mod accept;
+mod create;
+mod delete;
+mod errors;
+mod find;
+mod parse;
+
+use crate::infrastructure::database::Database;
+pub use errors::*;
+
+/// Repository used to access Invitation data from the database
+#[derive(Clone)]
+pub(super) struct InvitationRepository {
+    /// The database with which to access invitation records
+    database: Database,
+}
+
+impl InvitationRepository {
+    /// Create a new Invitation Repository to work with
+    ///
+    /// # Parameters
+    /// - `database` - The database connection to use
+    ///
+    /// # Returns
+    /// The Invitation Repository to use
+    pub(super) fn new(database: Database) -> Self {
+        Self { database }
+    }
+}
@@ -0,0 +1,53 @@
+use super::{AcceptError, InvitationRepository};
+use crate::{invitations::InvitationModel, users::UserID};
+use chrono::Utc;
+
+impl InvitationRepository {
+    /// Accept an invitation on behalf of a user, making them a member of the world with the role from
+    /// the invitation and using up one use of the invitation.
+    ///
+    /// This is done in a single transaction, so that an invitation can never be accepted more times than
+    /// it allows even if several users accept it at once.
+    ///
+    /// # Parameters
+    /// - `invitation` - The invitation to accept
+    /// - `user` - The ID of the user accepting the invitation
+    ///
+    /// # Errors
+    /// Any errors that occurred accepting the invitation
+    #[tracing::instrument(skip(self))]
+    pub fn accept(&self, invitation: &InvitationModel, user: &UserID) -> Result<(), AcceptError> {
+        let now = Utc::now();
+
+        let mut connection = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+        let mut transaction = connection.transaction()?;
+
+        let used = transaction.execute(
+            "UPDATE invitations SET uses = uses + 1, updated = $2 WHERE invitation_id = $1 AND (max_uses IS NULL OR uses < max_uses) AND (expires IS NULL OR expires > $2)",
+            &[&invitation.identity.id, &now],
+        )?;
+        if used == 0 {
+            return Err(AcceptError::Expired);
+        }
+
+        let added = transaction.execute(
+            "INSERT INTO world_members(world_id, user_id, created, role) VALUES($1, $2, $3, $4) ON CONFLICT DO NOTHING",
+            &[
+                &invitation.data.world,
+                user,
+                &now,
+                &invitation.data.role.to_string(),
+            ],
+        )?;
+        if added == 0 {
+            return Err(AcceptError::AlreadyMember);
+        }
+
+        transaction.commit()?;
+
+        Ok(())
+    }
+}
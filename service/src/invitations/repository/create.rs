@@ -0,0 +1,49 @@
+use super::{InvitationRepository, SaveInvitationError};
+use crate::invitations::model::*;
+use chrono::Utc;
+use uuid::Uuid;
+
+impl InvitationRepository {
+    /// Create a new invitation in the database
+    ///
+    /// # Parameters
+    /// - `invitation` - The invitation to create
+    /// - `token` - The token that is used to accept the invitation. Only the hash of this is stored
+    ///
+    /// # Returns
+    /// The newly created invitation
+    ///
+    /// # Errors
+    /// Any errors that occurred creating the new invitation
+    #[tracing::instrument(skip(self))]
+    pub fn create(
+        &self,
+        invitation: InvitationData,
+        token: &InvitationToken,
+    ) -> Result<InvitationModel, SaveInvitationError> {
+        let id = InvitationID::default();
+        let now = Utc::now();
+        let version = Uuid::new_v4();
+        let max_uses = invitation.max_uses.map(|max_uses| max_uses as i32);
+
+        let mut connection = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+
+        let new_invitation = connection.query_one("INSERT INTO invitations(invitation_id, version, created, updated, world_id, token_hash, role, expires, max_uses, uses) VALUES($1, $2, $3, $3, $4, $5, $6, $7, $8, 0) RETURNING *",
+            &[
+                &id,
+                &version,
+                &now,
+                &invitation.world,
+                &token.hash(),
+                &invitation.role.to_string(),
+                &invitation.expires,
+                &max_uses,
+            ])
+            .map(|row| self.parse_row(&row))?;
+
+        Ok(new_invitation)
+    }
+}
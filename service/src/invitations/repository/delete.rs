@@ -0,0 +1,29 @@
+use super::InvitationRepository;
+use crate::{invitations::InvitationID, worlds::WorldID};
+
+impl InvitationRepository {
+    /// Delete an invitation from the database
+    ///
+    /// # Parameters
+    /// - `world` - The ID of the world that the invitation is for
+    /// - `id` - The ID of the invitation to delete
+    ///
+    /// # Returns
+    /// True if the invitation was deleted. False if it didn't exist
+    #[tracing::instrument(skip(self))]
+    pub fn delete(&self, world: &WorldID, id: &InvitationID) -> bool {
+        let mut connection = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+
+        let deleted = connection
+            .execute(
+                "DELETE FROM invitations WHERE world_id = $1 AND invitation_id = $2",
+                &[world, id],
+            )
+            .expect("Failed to delete invitation");
+
+        deleted > 0
+    }
+}
@@ -0,0 +1,75 @@
+use postgres::error::{DbError, SqlState};
+
+/// Errors that can occur when saving an invitation record
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum SaveInvitationError {
+    /// An unknown error occurred
+    #[error("An unknown error occurred")]
+    UnknownError,
+
+    /// The world that the invitation is for does not exist
+    #[error("The desired world does not exist")]
+    UnknownWorld,
+}
+
+/// Errors that can occur when accepting an invitation
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum AcceptError {
+    /// An unknown error occurred
+    #[error("An unknown error occurred")]
+    UnknownError,
+
+    /// The user is already a member of the world
+    #[error("The user is already a member of the world")]
+    AlreadyMember,
+
+    /// The invitation expired or was used up before it could be accepted
+    #[error("The invitation has expired")]
+    Expired,
+}
+
+impl From<postgres::Error> for SaveInvitationError {
+    /// Convert a database error into a SaveInvitationError.
+    ///
+    /// The conversion returns an `UnknownWorld` iff the error is a `FOREIGN_KEY_VIOLATION` and the constraint
+    /// is `invitations_world_id_fkey`. Otherwise it returns an `UnknownError`
+    ///
+    /// # Parameters
+    /// - `e` - The error to convert
+    ///
+    /// # Returns
+    /// The new error code
+    fn from(e: postgres::Error) -> Self {
+        let mut result = None;
+
+        if e.code() == Some(&SqlState::FOREIGN_KEY_VIOLATION) {
+            let db_error: Option<DbError> = e
+                .into_source()
+                .and_then(|e| e.downcast_ref::<DbError>().cloned());
+
+            result = db_error
+                .and_then(|e| e.constraint().map(|c| c.to_owned()))
+                .map(|constraint| match constraint.as_ref() {
+                    "invitations_world_id_fkey" => SaveInvitationError::UnknownWorld,
+                    _ => {
+                        tracing::warn!(
+                            "Unexpected foreign key constraint violation error: {:?}",
+                            constraint
+                        );
+                        SaveInvitationError::UnknownError
+                    }
+                });
+        } else {
+            tracing::warn!("Unexpected database error: {:?}", e);
+        }
+
+        result.unwrap_or(SaveInvitationError::UnknownError)
+    }
+}
+
+impl From<postgres::Error> for AcceptError {
+    fn from(e: postgres::Error) -> Self {
+        tracing::warn!("Unexpected database error: {:?}", e);
+        AcceptError::UnknownError
+    }
+}
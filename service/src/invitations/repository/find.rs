@@ -0,0 +1,82 @@
+use super::InvitationRepository;
+use crate::{
+    invitations::{InvitationModel, InvitationToken},
+    model::{Page, Pagination},
+    worlds::WorldID,
+};
+
+impl InvitationRepository {
+    /// Find an invitation in the database by the token used to accept it
+    ///
+    /// # Parameters
+    /// - `token` - The token of the invitation
+    ///
+    /// # Returns
+    /// The invitation, if it exists
+    #[tracing::instrument(skip(self))]
+    pub fn find_invitation_by_token(&self, token: &InvitationToken) -> Option<InvitationModel> {
+        let mut connection = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+        connection
+            .query_opt(
+                "SELECT * FROM invitations WHERE token_hash = $1",
+                &[&token.hash()],
+            )
+            .expect("Failed to query for invitation by token")
+            .map(|row| self.parse_row(&row))
+    }
+
+    /// List the invitations for a world, newest first
+    ///
+    /// # Parameters
+    /// - `world` - The ID of the world to list the invitations for
+    /// - `pagination` - The pagination details for which page of results are wanted
+    ///
+    /// # Returns
+    /// The requested page of results
+    #[tracing::instrument(skip(self))]
+    pub fn list_invitations(
+        &self,
+        world: &WorldID,
+        pagination: &Pagination,
+    ) -> Page<InvitationModel> {
+        let mut connection = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+
+        let select_query = format!(
+            "SELECT * FROM invitations WHERE world_id = $1 ORDER BY created DESC, invitation_id ASC OFFSET {} LIMIT {}",
+            pagination.offset, pagination.count
+        );
+        let records: Vec<InvitationModel> = connection
+            .query(select_query.as_str(), &[world])
+            .map(|rows| rows.iter().map(|row| self.parse_row(row)).collect())
+            .expect("Failed to select invitations for world");
+
+        let num_records = records.len() as u64;
+        let total = if records.is_empty() || num_records == pagination.count {
+            // If we got zero records back, or exactly the number we asked for, then we don't know
+            // how many there are in the database
+            let count: i32 = connection
+                .query_one(
+                    "SELECT COUNT(*)::INTEGER AS c FROM invitations WHERE world_id = $1",
+                    &[world],
+                )
+                .map(|row| row.get("c"))
+                .expect("Failed to count invitations for world");
+            count as u64
+        } else {
+            pagination.offset + num_records
+        };
+
+        Page {
+            entries: records,
+            offset: pagination.offset,
+            count: pagination.count,
+            total,
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use super::InvitationRepository;
+use crate::{invitations::model::*, model::Identity};
+use postgres::row::Row;
+
+impl InvitationRepository {
+    /// Parse a row from the database into an Invitation Model
+    ///
+    /// # Parameters
+    /// - `row` - The row to parse
+    ///
+    /// # Returns
+    /// The invitation that the row represented
+    pub(super) fn parse_row(&self, row: &Row) -> InvitationModel {
+        let role: String = row.get("role");
+        let max_uses: Option<i32> = row.get("max_uses");
+        let uses: i32 = row.get("uses");
+
+        InvitationModel {
+            identity: Identity {
+                id: row.get("invitation_id"),
+                version: row.get("version"),
+                created: row.get("created"),
+                updated: row.get("updated"),
+            },
+            data: InvitationData {
+                world: row.get("world_id"),
+                role: role.parse().expect("Invalid role stored for invitation"),
+                expires: row.get("expires"),
+                max_uses: max_uses.map(|max_uses| max_uses as u32),
+                uses: uses as u32,
+            },
+        }
+    }
+}
@@ -0,0 +1,35 @@
+mod accept;
+mod create_invitation;
+mod find;
+mod revoke;
+
+use super::repository::InvitationRepository;
+use crate::{activity::ActivityService, notifications::NotificationsService};
+pub use accept::AcceptInvitationError;
+pub use create_invitation::CreateInvitationError;
+
+/// Service Layer for dealing with Invitations to join worlds
+#[derive(Clone)]
+pub struct InvitationsService {
+    repository: InvitationRepository,
+    activity: ActivityService,
+    notifications: NotificationsService,
+}
+
+impl InvitationsService {
+    /// Create a new instance of the Invitations Service
+    ///
+    /// # Returns
+    /// The Invitations Service ready to use
+    pub(super) fn new(
+        repository: InvitationRepository,
+        activity: ActivityService,
+        notifications: NotificationsService,
+    ) -> Self {
+        Self {
+            repository,
+            activity,
+            notifications,
+        }
+    }
+}
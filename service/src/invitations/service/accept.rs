@@ -0,0 +1,99 @@
+use crate::{
+    activity::{ActivityData, ActivityEvent},
+    invitations::{repository::AcceptError, *},
+    notifications::{NotificationData, NotificationKind},
+    users::UserID,
+};
+use chrono::Utc;
+
+/// Errors that can occur when accepting an invitation
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum AcceptInvitationError {
+    /// An unknown error occurred
+    #[error("An unknown error occurred")]
+    UnknownError,
+
+    /// There is no invitation with the given token
+    #[error("The invitation was not found")]
+    UnknownInvitation,
+
+    /// The invitation has expired or been used up
+    #[error("The invitation has expired")]
+    ExpiredInvitation,
+
+    /// The user is already a member of the world
+    #[error("The user is already a member of the world")]
+    AlreadyMember,
+}
+
+impl InvitationsService {
+    /// Find the invitation that has the given token, as long as it can still be accepted
+    ///
+    /// # Parameters
+    /// - `token` - The token of the invitation
+    ///
+    /// # Returns
+    /// The invitation
+    ///
+    /// # Errors
+    /// If the invitation doesn't exist, or if it has expired or been used up
+    pub fn find_invitation_by_token(
+        &self,
+        token: &InvitationToken,
+    ) -> Result<InvitationModel, AcceptInvitationError> {
+        let invitation = self
+            .repository
+            .find_invitation_by_token(token)
+            .ok_or(AcceptInvitationError::UnknownInvitation)?;
+
+        if invitation.data.is_expired(&Utc::now()) {
+            tracing::warn!(invitation = ?invitation, "Invitation has expired");
+            return Err(AcceptInvitationError::ExpiredInvitation);
+        }
+
+        Ok(invitation)
+    }
+
+    /// Accept an invitation, making the user a member of the world that it is for
+    ///
+    /// # Parameters
+    /// - `invitation` - The invitation to accept
+    /// - `user` - The ID of the user that is accepting the invitation
+    ///
+    /// # Errors
+    /// Any errors that occurred accepting the invitation
+    pub fn accept_invitation(
+        &self,
+        invitation: &InvitationModel,
+        user: &UserID,
+    ) -> Result<(), AcceptInvitationError> {
+        self.repository.accept(invitation, user)?;
+        tracing::debug!(invitation = ?invitation, user = ?user, "Accepted invitation");
+
+        self.activity.record_activity(ActivityData {
+            world: invitation.data.world.clone(),
+            user: Some(user.clone()),
+            event: ActivityEvent::MemberJoined,
+            target: None,
+        });
+        self.notifications.notify(NotificationData {
+            user: None,
+            actor: user.clone(),
+            kind: NotificationKind::MemberJoined,
+            world: invitation.data.world.clone(),
+            target: None,
+        });
+
+        Ok(())
+    }
+}
+
+impl From<AcceptError> for AcceptInvitationError {
+    fn from(e: AcceptError) -> Self {
+        match e {
+            AcceptError::AlreadyMember => AcceptInvitationError::AlreadyMember,
+            AcceptError::Expired => AcceptInvitationError::ExpiredInvitation,
+            _ => AcceptInvitationError::UnknownError,
+        }
+    }
+}
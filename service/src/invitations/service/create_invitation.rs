@@ -0,0 +1,47 @@
+use crate::invitations::{repository::SaveInvitationError, *};
+
+/// Errors that can occur when creating a new invitation
+#[derive(Debug, thiserror::Error)]
+pub enum CreateInvitationError {
+    /// An unknown error occurred
+    #[error("An unknown error occurred")]
+    UnknownError,
+
+    #[error("The desired world does not exist")]
+    UnknownWorld,
+}
+
+impl InvitationsService {
+    /// Create a new invitation to join a world
+    ///
+    /// # Parameters
+    /// - `invitation` - The invitation to create
+    ///
+    /// # Returns
+    /// The newly created invitation, and the token that is used to accept it. This is the only time that
+    /// the token is ever available
+    ///
+    /// # Errors
+    /// Any errors that occurred creating the new invitation
+    pub fn create_invitation(
+        &self,
+        invitation: InvitationData,
+    ) -> Result<(InvitationModel, InvitationToken), CreateInvitationError> {
+        tracing::debug!(invitation = ?invitation, "Creating invitation");
+
+        let token = InvitationToken::generate();
+        let new_invitation = self.repository.create(invitation, &token)?;
+        tracing::debug!(invitation = ?new_invitation, "Created invitation");
+
+        Ok((new_invitation, token))
+    }
+}
+
+impl From<SaveInvitationError> for CreateInvitationError {
+    fn from(e: SaveInvitationError) -> Self {
+        match e {
+            SaveInvitationError::UnknownWorld => CreateInvitationError::UnknownWorld,
+            _ => CreateInvitationError::UnknownError,
+        }
+    }
+}
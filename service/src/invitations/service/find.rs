@@ -0,0 +1,24 @@
+use super::InvitationsService;
+use crate::{
+    invitations::InvitationModel,
+    model::{Page, Pagination},
+    worlds::WorldID,
+};
+
+impl InvitationsService {
+    /// List the invitations for a world, newest first
+    ///
+    /// # Parameters
+    /// - `world` - The ID of the world to list the invitations for
+    /// - `pagination` - The pagination controls to apply
+    ///
+    /// # Returns
+    /// The page of invitations
+    pub fn list_invitations(
+        &self,
+        world: &WorldID,
+        pagination: &Pagination,
+    ) -> Page<InvitationModel> {
+        self.repository.list_invitations(world, pagination)
+    }
+}
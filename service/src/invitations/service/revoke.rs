@@ -0,0 +1,16 @@
+use super::InvitationsService;
+use crate::{invitations::InvitationID, worlds::WorldID};
+
+impl InvitationsService {
+    /// Revoke an invitation so that it can no longer be accepted
+    ///
+    /// # Parameters
+    /// - `world` - The ID of the world that the invitation is for
+    /// - `id` - The ID of the invitation to revoke
+    ///
+    /// # Returns
+    /// True if the invitation was revoked. False if it didn't exist
+    pub fn revoke_invitation(&self, world: &WorldID, id: &InvitationID) -> bool {
+        self.repository.delete(world, id)
+    }
+}
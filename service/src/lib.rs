@@ -4,16 +4,34 @@
     allow(clippy::module_name_repetitions, clippy::wildcard_imports)
 )]
 
+mod activity;
+mod attachments;
 mod authentication;
 mod authorization;
+mod autocomplete;
+mod campaigns;
+mod characters;
+mod comments;
+mod events;
+mod exports;
 mod http;
+mod imports;
 mod infrastructure;
+mod invitations;
 mod model;
+mod moderation;
+mod notifications;
+mod quotas;
+mod tables;
+mod templates;
 mod testing;
 mod users;
+mod webhooks;
 mod worlds;
 
 pub use infrastructure::service::Service;
+pub use quotas::QuotaLimits;
+use std::path::PathBuf;
 pub use testing::database::TestDatabase;
 
 /// Representation of the configuration settings needed to build and run the application service
@@ -21,4 +39,18 @@ pub use testing::database::TestDatabase;
 pub struct Settings {
     /// The URL to connect to the database with
     pub database_url: String,
+    /// The directory in which to store uploaded files
+    pub blob_storage_path: PathBuf,
+    /// The largest file, in bytes, that can be attached to a world
+    pub max_attachment_size: u64,
+    /// The quotas that users have unless they have been given an override
+    pub quotas: QuotaLimits,
+    /// Whether users may use avatars that are hosted elsewhere, rather than uploading them
+    pub allow_external_avatars: bool,
+    /// Whether webhooks may deliver events to the loopback interface. This should only be enabled for
+    /// testing
+    pub allow_loopback_webhooks: bool,
+    /// The maximum number of world event streams that can be open at once. Every open stream occupies
+    /// an HTTP worker, so this many workers are added to the server on top of the usual ones
+    pub max_event_streams: u16,
 }
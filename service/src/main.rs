@@ -2,6 +2,12 @@ use config::{Config, Environment};
 use dotenv::dotenv;
 use serde::Deserialize;
 
+/// The default largest file, in bytes, that can be attached to a world
+const DEFAULT_MAX_ATTACHMENT_SIZE: u64 = 10 * 1024 * 1024;
+
+/// The default maximum number of world event streams that can be open at once
+const DEFAULT_MAX_EVENT_STREAMS: u16 = 16;
+
 /// Representation of the application settings that will be loaded from the environment
 #[derive(Deserialize)]
 struct Settings {
@@ -9,6 +15,28 @@ struct Settings {
     pub port: Option<u16>,
     /// The URL to connect to the database with
     pub database_url: String,
+    /// The directory in which to store uploaded files
+    pub blob_storage_path: Option<String>,
+    /// The largest file, in bytes, that can be attached to a world
+    pub max_attachment_size: Option<u64>,
+    /// The number of worlds that a single user can own, unless overridden for that user
+    pub quota_worlds: Option<u64>,
+    /// The number of random tables that a single user can have, unless overridden for that user
+    pub quota_tables: Option<u64>,
+    /// The number of campaigns that a single user can have, unless overridden for that user
+    pub quota_campaigns: Option<u64>,
+    /// The number of characters that a single user can have, unless overridden for that user
+    pub quota_characters: Option<u64>,
+    /// The total size of all files, in bytes, that a single user can attach, unless overridden for
+    /// that user
+    pub quota_attachment_bytes: Option<u64>,
+    /// Whether users may use avatars that are hosted elsewhere, rather than uploading them
+    pub allow_external_avatars: Option<bool>,
+    /// Whether webhooks may deliver events to the loopback interface. This should only be enabled for
+    /// testing
+    pub allow_loopback_webhooks: Option<bool>,
+    /// The maximum number of world event streams that can be open at once
+    pub max_event_streams: Option<u16>,
 }
 
 impl Default for Settings {
@@ -32,8 +60,30 @@ impl Settings {
     /// # Returns
     /// The settings needed to run the application
     pub fn build(&self) -> multiverse_lib::Settings {
+        let default_quotas = multiverse_lib::QuotaLimits::default();
+
         multiverse_lib::Settings {
             database_url: self.database_url.clone(),
+            blob_storage_path: self
+                .blob_storage_path
+                .clone()
+                .unwrap_or_else(|| "blobs".to_owned())
+                .into(),
+            max_attachment_size: self
+                .max_attachment_size
+                .unwrap_or(DEFAULT_MAX_ATTACHMENT_SIZE),
+            quotas: multiverse_lib::QuotaLimits {
+                worlds: self.quota_worlds.unwrap_or(default_quotas.worlds),
+                tables: self.quota_tables.unwrap_or(default_quotas.tables),
+                campaigns: self.quota_campaigns.unwrap_or(default_quotas.campaigns),
+                characters: self.quota_characters.unwrap_or(default_quotas.characters),
+                attachment_bytes: self
+                    .quota_attachment_bytes
+                    .unwrap_or(default_quotas.attachment_bytes),
+            },
+            allow_external_avatars: self.allow_external_avatars.unwrap_or(true),
+            allow_loopback_webhooks: self.allow_loopback_webhooks.unwrap_or(false),
+            max_event_streams: self.max_event_streams.unwrap_or(DEFAULT_MAX_EVENT_STREAMS),
         }
     }
 }
@@ -1,11 +1,15 @@
 mod identity;
+mod language_tag;
 mod model;
 mod page;
 mod pagination;
+mod publication;
 mod sorts;
 
 pub use identity::*;
+pub use language_tag::*;
 pub use model::*;
 pub use page::*;
 pub use pagination::*;
+pub use publication::*;
 pub use sorts::*;
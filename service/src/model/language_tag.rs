@@ -0,0 +1,183 @@
+use bytes::BytesMut;
+use postgres_types::{accepts, to_sql_checked, FromSql, IsNull, ToSql, Type};
+use rocket::{http::RawStr, request};
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt::{Display, Formatter},
+    str::FromStr,
+};
+
+/// Typesafe representation of a BCP 47 Language Tag, such as `en` or `de-CH`.
+///
+/// Tags are always stored in their canonical case, so that they can be compared directly
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Serialize, Deserialize, FromSql)]
+pub struct LanguageTag(String);
+
+/// Errors that can occur when parsing a Language Tag
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum LanguageTagParseError {
+    /// The Language Tag was blank
+    #[error("The Language Tag was blank")]
+    Blank,
+
+    /// The Language Tag was not well-formed
+    #[error("The Language Tag was malformed")]
+    Malformed,
+}
+
+impl Default for LanguageTag {
+    /// The language that content is in if nothing else was specified
+    fn default() -> Self {
+        LanguageTag("en".to_owned())
+    }
+}
+
+impl LanguageTag {
+    /// Get the tag that is one step less specific than this one, by removing the final subtag.
+    /// For example, `de-CH` becomes `de`
+    ///
+    /// # Returns
+    /// The less specific tag, or `None` if this is only a primary language
+    pub fn parent(&self) -> Option<LanguageTag> {
+        self.0
+            .rfind('-')
+            .map(|index| LanguageTag(self.0[..index].to_owned()))
+    }
+}
+
+impl FromStr for LanguageTag {
+    type Err = LanguageTagParseError;
+
+    /// Parse a string into a LanguageTag object.
+    ///
+    /// The primary language must be 2 to 8 letters, and every following subtag 1 to 8 letters or
+    /// digits. The tag is then normalized to the canonical case - lowercase languages, titlecase
+    /// scripts and uppercase regions - so `EN-gb` becomes `en-GB`
+    ///
+    /// # Parameters
+    /// - `s` - The input string to parse
+    ///
+    /// # Returns
+    /// The Language Tag object
+    ///
+    /// # Errors
+    /// - `LanguageTagParseError::Blank` - If the input string was blank
+    /// - `LanguageTagParseError::Malformed` - If the input string was not a well-formed tag
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.is_empty() {
+            return Err(LanguageTagParseError::Blank);
+        }
+
+        let mut subtags = vec![];
+        for (index, subtag) in s.split('-').enumerate() {
+            let valid = match index {
+                0 => {
+                    (2..=8).contains(&subtag.len())
+                        && subtag.chars().all(|c| c.is_ascii_alphabetic())
+                }
+                _ => {
+                    (1..=8).contains(&subtag.len())
+                        && subtag.chars().all(|c| c.is_ascii_alphanumeric())
+                }
+            };
+            if !valid {
+                return Err(LanguageTagParseError::Malformed);
+            }
+
+            let subtag = match (index, subtag.len()) {
+                (0, _) => subtag.to_ascii_lowercase(),
+                (_, 2) => subtag.to_ascii_uppercase(),
+                (_, 4) if subtag.chars().all(|c| c.is_ascii_alphabetic()) => {
+                    let lower = subtag.to_ascii_lowercase();
+                    format!("{}{}", lower[..1].to_ascii_uppercase(), &lower[1..])
+                }
+                _ => subtag.to_ascii_lowercase(),
+            };
+            subtags.push(subtag);
+        }
+
+        Ok(LanguageTag(subtags.join("-")))
+    }
+}
+
+impl ToSql for LanguageTag {
+    accepts!(TEXT, VARCHAR);
+
+    to_sql_checked!();
+
+    fn to_sql(
+        &self,
+        t: &Type,
+        w: &mut BytesMut,
+    ) -> Result<IsNull, Box<dyn std::error::Error + Sync + Send>> {
+        self.0.to_sql(t, w)
+    }
+}
+
+impl<'r> request::FromParam<'r> for LanguageTag {
+    type Error = &'r RawStr;
+
+    fn from_param(param: &'r RawStr) -> Result<Self, Self::Error> {
+        param
+            .percent_decode()
+            .map_err(|_| param)
+            .and_then(|language| LanguageTag::from_str(&language).map_err(|_| param))
+    }
+}
+
+impl Display for LanguageTag {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_valid() {
+        assert_eq!(LanguageTag::from_str("en").unwrap().to_string(), "en");
+        assert_eq!(LanguageTag::from_str(" DE ").unwrap().to_string(), "de");
+        assert_eq!(LanguageTag::from_str("en-gb").unwrap().to_string(), "en-GB");
+        assert_eq!(
+            LanguageTag::from_str("ZH-hant-tw").unwrap().to_string(),
+            "zh-Hant-TW"
+        );
+        assert_eq!(
+            LanguageTag::from_str("es-419").unwrap().to_string(),
+            "es-419"
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert_eq!(LanguageTag::from_str(""), Err(LanguageTagParseError::Blank));
+        assert_eq!(
+            LanguageTag::from_str("e"),
+            Err(LanguageTagParseError::Malformed)
+        );
+        assert_eq!(
+            LanguageTag::from_str("en_GB"),
+            Err(LanguageTagParseError::Malformed)
+        );
+        assert_eq!(
+            LanguageTag::from_str("en-"),
+            Err(LanguageTagParseError::Malformed)
+        );
+        assert_eq!(
+            LanguageTag::from_str("*"),
+            Err(LanguageTagParseError::Malformed)
+        );
+    }
+
+    #[test]
+    fn test_parent() {
+        let tag = LanguageTag::from_str("zh-Hant-TW").unwrap();
+        let parent = tag.parent().unwrap();
+        assert_eq!(parent.to_string(), "zh-Hant");
+        assert_eq!(parent.parent().unwrap().to_string(), "zh");
+        assert_eq!(parent.parent().unwrap().parent(), None);
+    }
+}
@@ -1,3 +1,8 @@
+use super::Pagination;
+
+/// The number of records to load at a time when loading every page of a resultset
+const FETCH_ALL_PAGE_SIZE: u64 = 100;
+
 /// Representation of a page of results
 #[derive(Debug)]
 pub struct Page<T> {
@@ -10,3 +15,76 @@ pub struct Page<T> {
     /// The total number of records in the resultset
     pub total: u64,
 }
+
+/// Load every page of a resultset, one after another, and collect together all of the entries.
+///
+/// This stops as soon as an empty page is returned or the total number of records has been loaded, so
+/// that records being added or removed part way through can never cause it to loop forever
+///
+/// # Parameters
+/// - `fetch` - Load the page of results for the given pagination details
+///
+/// # Returns
+/// Every entry in the resultset
+pub fn fetch_all_pages<T, F>(mut fetch: F) -> Vec<T>
+where
+    F: FnMut(&Pagination) -> Page<T>,
+{
+    let mut entries = vec![];
+    loop {
+        let page = fetch(&Pagination {
+            offset: entries.len() as u64,
+            count: FETCH_ALL_PAGE_SIZE,
+        });
+        let finished = page.entries.is_empty();
+        entries.extend(page.entries);
+        if finished || entries.len() as u64 >= page.total {
+            break;
+        }
+    }
+
+    entries
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fetch(total: u64) -> impl FnMut(&Pagination) -> Page<u64> {
+        move |pagination| Page {
+            entries: (pagination.offset..total.min(pagination.offset + pagination.count)).collect(),
+            offset: pagination.offset,
+            count: pagination.count,
+            total,
+        }
+    }
+
+    #[test]
+    fn test_fetch_all_pages() {
+        assert_eq!(fetch_all_pages(fetch(0)), Vec::<u64>::new());
+        assert_eq!(fetch_all_pages(fetch(3)), vec![0, 1, 2]);
+        assert_eq!(fetch_all_pages(fetch(250)).len(), 250);
+    }
+
+    #[test]
+    fn test_fetch_all_pages_shrinking() {
+        // The resultset shrinks after the first page, so the second page comes back empty
+        let mut calls = 0;
+        let entries = fetch_all_pages(|pagination| {
+            calls += 1;
+            Page {
+                entries: if pagination.offset == 0 {
+                    (0..pagination.count).collect()
+                } else {
+                    vec![]
+                },
+                offset: pagination.offset,
+                count: pagination.count,
+                total: 1000,
+            }
+        });
+
+        assert_eq!(entries.len(), 100);
+        assert_eq!(calls, 2);
+    }
+}
@@ -0,0 +1,171 @@
+use chrono::{DateTime, Utc};
+
+/// The publication statuses that content can be in
+#[derive(Debug, PartialEq, Clone, strum_macros::EnumString, strum_macros::Display)]
+#[strum(serialize_all = "snake_case")]
+pub enum PublicationStatus {
+    /// The content is only visible to the editors of the world
+    Draft,
+    /// The content is visible to everyone
+    Published,
+    /// The content will become visible to everyone at some point in the future
+    Scheduled,
+}
+
+/// The publication state of some piece of content
+#[derive(Debug, PartialEq, Clone)]
+pub enum Publication {
+    /// The content is a draft, only visible to editors
+    Draft,
+    /// The content is published, and visible to everyone
+    Published,
+    /// The content is to be published at the given time
+    Scheduled(DateTime<Utc>),
+}
+
+impl Default for Publication {
+    fn default() -> Self {
+        Publication::Published
+    }
+}
+
+impl Publication {
+    /// Build the publication state from the values stored in the database
+    ///
+    /// # Parameters
+    /// - `status` - The stored status
+    /// - `publish_at` - The stored time at which the content is to be published
+    ///
+    /// # Returns
+    /// The publication state. Anything that can't be understood is treated as a draft, so that it isn't
+    /// accidentally made visible
+    pub fn from_stored(status: &str, publish_at: Option<DateTime<Utc>>) -> Self {
+        match (status.parse(), publish_at) {
+            (Ok(PublicationStatus::Published), _) => Publication::Published,
+            (Ok(PublicationStatus::Scheduled), Some(publish_at)) => {
+                Publication::Scheduled(publish_at)
+            }
+            _ => Publication::Draft,
+        }
+    }
+
+    /// Get the status to store in the database for this publication state
+    pub fn stored_status(&self) -> PublicationStatus {
+        match self {
+            Publication::Draft => PublicationStatus::Draft,
+            Publication::Published => PublicationStatus::Published,
+            Publication::Scheduled(_) => PublicationStatus::Scheduled,
+        }
+    }
+
+    /// Get the time at which the content is to be published, if it is scheduled
+    pub fn publish_at(&self) -> Option<DateTime<Utc>> {
+        match self {
+            Publication::Scheduled(publish_at) => Some(*publish_at),
+            _ => None,
+        }
+    }
+
+    /// Get the current status of the content.
+    /// Scheduled content whose publish time has passed counts as published
+    pub fn status(&self) -> PublicationStatus {
+        match self {
+            Publication::Scheduled(publish_at) if publish_at <= &Utc::now() => {
+                PublicationStatus::Published
+            }
+            _ => self.stored_status(),
+        }
+    }
+
+    /// Check if the content is currently visible to everyone
+    pub fn is_visible(&self) -> bool {
+        self.status() == PublicationStatus::Published
+    }
+}
+
+/// Build an SQL condition that matches the rows of a table that are currently visible to everyone
+///
+/// # Parameters
+/// - `table` - The name or alias of the table, which must have `status` and `publish_at` columns
+///
+/// # Returns
+/// The SQL condition
+pub(crate) fn published_sql(table: &str) -> String {
+    format!(
+        "({0}.status = 'published' OR ({0}.status = 'scheduled' AND {0}.publish_at <= NOW()))",
+        table
+    )
+}
+
+/// Build an SQL condition that matches the rows of a table that currently have the given status
+///
+/// # Parameters
+/// - `table` - The name or alias of the table, which must have `status` and `publish_at` columns
+/// - `status` - The status to match
+///
+/// # Returns
+/// The SQL condition
+pub(crate) fn status_sql(table: &str, status: &PublicationStatus) -> String {
+    match status {
+        PublicationStatus::Draft => format!("{}.status = 'draft'", table),
+        PublicationStatus::Published => published_sql(table),
+        PublicationStatus::Scheduled => format!(
+            "({0}.status = 'scheduled' AND {0}.publish_at > NOW())",
+            table
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[test]
+    fn test_from_stored() {
+        let now = Utc::now();
+
+        assert_eq!(Publication::from_stored("draft", None), Publication::Draft);
+        assert_eq!(
+            Publication::from_stored("published", None),
+            Publication::Published
+        );
+        assert_eq!(
+            Publication::from_stored("scheduled", Some(now)),
+            Publication::Scheduled(now)
+        );
+        assert_eq!(
+            Publication::from_stored("scheduled", None),
+            Publication::Draft
+        );
+        assert_eq!(
+            Publication::from_stored("unknown", None),
+            Publication::Draft
+        );
+    }
+
+    #[test]
+    fn test_status() {
+        assert_eq!(Publication::Draft.status(), PublicationStatus::Draft);
+        assert_eq!(
+            Publication::Published.status(),
+            PublicationStatus::Published
+        );
+        assert_eq!(
+            Publication::Scheduled(Utc::now() + Duration::hours(1)).status(),
+            PublicationStatus::Scheduled
+        );
+        assert_eq!(
+            Publication::Scheduled(Utc::now() - Duration::hours(1)).status(),
+            PublicationStatus::Published
+        );
+    }
+
+    #[test]
+    fn test_is_visible() {
+        assert!(!Publication::Draft.is_visible());
+        assert!(Publication::Published.is_visible());
+        assert!(!Publication::Scheduled(Utc::now() + Duration::hours(1)).is_visible());
+        assert!(Publication::Scheduled(Utc::now() - Duration::hours(1)).is_visible());
+    }
+}
@@ -0,0 +1,46 @@
+use super::{repository::ModerationRepository, ModerationService};
+use crate::{infrastructure::database::Database, users::UsersService};
+use std::sync::Arc;
+
+/// Application Configuration for the Moderation module
+pub struct ModerationConfig {
+    pub moderation_service: ModerationService,
+}
+
+impl ModerationConfig {
+    /// Create the Configuration for the Moderation Module
+    ///
+    /// # Parameters
+    /// - `database` - The database connection to use
+    /// - `users_service` - The users service to determine who is allowed to moderate with
+    ///
+    /// # Returns
+    /// The Moderation Config object
+    pub fn new(database: Database, users_service: UsersService) -> Self {
+        let repository = ModerationRepository::new(database);
+        Self {
+            moderation_service: ModerationService::new(repository, users_service),
+        }
+    }
+
+    /// Generate the configuration callback needed for the HTTP Server to actually add the Moderation
+    /// endpoints to the server
+    ///
+    /// # Returns
+    /// The callback to provide to the HTTP Server to configure up the Moderation endpoints
+    pub fn configure(&self) -> Arc<dyn Fn(rocket::Rocket) -> rocket::Rocket + Send + Sync> {
+        let moderation_service = self.moderation_service.clone();
+        Arc::new(move |config| {
+            config.manage(moderation_service.clone()).mount(
+                "/",
+                rocket::routes![
+                    super::endpoints::create_report,
+                    super::endpoints::get_report,
+                    super::endpoints::list_audit,
+                    super::endpoints::list_reports,
+                    super::endpoints::moderate_report
+                ],
+            )
+        })
+    }
+}
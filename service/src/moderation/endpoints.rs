@@ -0,0 +1,14 @@
+mod create_report;
+pub(crate) mod errors;
+mod get_report;
+mod list_audit;
+mod list_reports;
+pub(crate) mod model;
+mod moderate_report;
+mod moderator;
+
+pub use create_report::*;
+pub use get_report::*;
+pub use list_audit::*;
+pub use list_reports::*;
+pub use moderate_report::*;
@@ -0,0 +1,93 @@
+use super::{
+    errors::{ModerationProblemType, ModerationValidation},
+    model::ReportResponse,
+};
+use crate::{
+    authorization::Authorizer,
+    http::problem::{GenericValidation, Problem, ValidationProblem},
+    moderation::{ModerationService, ReportContentError, ReportTarget},
+};
+use rocket::{http::Status, post, State};
+use rocket_contrib::json::Json;
+use serde::Deserialize;
+
+/// Handler to report a world, comment or user so that a moderator can look at it
+///
+/// # Parameters
+/// - `moderation_service` - The moderation service to use
+/// - `body` - The details of the report
+/// - `authorizer` - The authorizer to prove we're allowed to make the report
+///
+/// # Returns
+/// The newly created report, or a Problem if the report failed
+#[tracing::instrument(name = "POST /reports", skip(moderation_service))]
+#[post("/reports", data = "<body>")]
+pub fn create_report(
+    moderation_service: State<ModerationService>,
+    body: Json<ReportRequest>,
+    authorizer: Authorizer,
+) -> Result<ReportResponse, Problem> {
+    let reporter = authorizer
+        .authorize()
+        .authorized()
+        .finish()?
+        .expect("No authenticated user");
+
+    let mut problem = ValidationProblem::new();
+    let target = match body.target.as_ref().filter(|v| !v.trim().is_empty()) {
+        None => {
+            problem.with_field_error("target", GenericValidation::Missing);
+            None
+        }
+        Some(target) => {
+            let target = target.parse::<ReportTarget>().ok();
+            if target.is_none() {
+                problem.with_field_error("target", ModerationValidation::InvalidTarget);
+            }
+            target
+        }
+    };
+    let reason = body.reason.clone().filter(|v| !v.trim().is_empty());
+    if reason.is_none() {
+        problem.with_field_error("reason", GenericValidation::Missing);
+    }
+
+    match (target, reason) {
+        (Some(target), Some(reason)) => {
+            let report = moderation_service.report_content(&reporter, &target, &reason)?;
+            Ok(ReportResponse(report))
+        }
+        _ => {
+            tracing::warn!("Validation error creating report");
+            Err(problem.build())
+        }
+    }
+}
+
+/// Incoming details representing a request to report some content
+#[derive(Debug, Deserialize)]
+pub struct ReportRequest {
+    /// The link to the world, comment or user being reported
+    target: Option<String>,
+    /// The reason for the report
+    reason: Option<String>,
+}
+
+impl From<ReportContentError> for Problem {
+    fn from(e: ReportContentError) -> Self {
+        match e {
+            ReportContentError::UnknownTarget => {
+                let mut problem = ValidationProblem::new();
+                problem.with_field_error("target", ModerationValidation::UnknownTarget);
+                problem.build()
+            }
+            _ => {
+                tracing::warn!(error = ?e, "An unexpected error occurred");
+                Problem::new(
+                    ModerationProblemType::UnknownError,
+                    Status::InternalServerError,
+                )
+            }
+        }
+    }
+}
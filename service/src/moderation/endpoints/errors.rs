@@ -0,0 +1,90 @@
+use crate::http::problem::{ProblemType, ValidationType};
+
+/// Problem Types that can happen when working with reports and moderation
+#[derive(Debug, thiserror::Error)]
+pub enum ModerationProblemType {
+    /// An unknown error occurred
+    #[error("An unknown error occurred")]
+    UnknownError,
+
+    /// The report that was looked up was not found
+    #[error("The requested report was unknown")]
+    UnknownReport,
+
+    /// The report has already been resolved by a moderator
+    #[error("The report has already been resolved")]
+    AlreadyResolved,
+
+    /// The reported content no longer exists
+    #[error("The reported content no longer exists")]
+    UnknownTarget,
+}
+
+impl ProblemType for ModerationProblemType {
+    /// Generate a Type value for the `ProblemType` values.
+    ///
+    /// These are used in the `type` field in the RFC-7807 Problem Response
+    fn error_code(&self) -> &'static str {
+        match self {
+            ModerationProblemType::UnknownError => {
+                "tag:multiverse,2020:moderation/problems/unknown_error"
+            }
+            ModerationProblemType::UnknownReport => {
+                "tag:multiverse,2020:moderation/problems/unknown_report"
+            }
+            ModerationProblemType::AlreadyResolved => {
+                "tag:multiverse,2020:moderation/problems/already_resolved"
+            }
+            ModerationProblemType::UnknownTarget => {
+                "tag:multiverse,2020:moderation/problems/unknown_target"
+            }
+        }
+    }
+}
+
+/// Validation errors that are specific to reports and moderation
+#[derive(Debug, thiserror::Error)]
+pub enum ModerationValidation {
+    /// The target was not a link to a world, comment or user
+    #[error("The target must be a link to a world, comment or user")]
+    InvalidTarget,
+
+    /// The target was a well-formed link but the content it links to doesn't exist
+    #[error("The target was unknown")]
+    UnknownTarget,
+
+    /// The action was not one of the known actions
+    #[error("The action was not a known action")]
+    InvalidAction,
+
+    /// The action can not be taken against the reported content
+    #[error("The action can not be taken against the reported content")]
+    UnsupportedAction,
+
+    /// The status was not one of the known report statuses
+    #[error("The status was not a known status")]
+    InvalidStatus,
+}
+
+impl ValidationType for ModerationValidation {
+    /// Generate a Type value for the `ValidationType` values.
+    fn error_code(&self) -> &'static str {
+        match self {
+            ModerationValidation::InvalidTarget => {
+                "tag:multiverse,2020:moderation/problems/validation_error/invalid_target"
+            }
+            ModerationValidation::UnknownTarget => {
+                "tag:multiverse,2020:moderation/problems/validation_error/unknown_target"
+            }
+            ModerationValidation::InvalidAction => {
+                "tag:multiverse,2020:moderation/problems/validation_error/invalid_action"
+            }
+            ModerationValidation::UnsupportedAction => {
+                "tag:multiverse,2020:moderation/problems/validation_error/unsupported_action"
+            }
+            ModerationValidation::InvalidStatus => {
+                "tag:multiverse,2020:moderation/problems/validation_error/invalid_status"
+            }
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use super::{errors::ModerationProblemType, model::ReportResponse, moderator::authorize_moderator};
+use crate::{
+    authorization::Authorizer,
+    http::problem::Problem,
+    moderation::{ModerationService, ReportID},
+};
+use rocket::{get, http::Status, State};
+
+/// Handler to get a single report from the moderation queue
+///
+/// # Parameters
+/// - `moderation_service` - The moderation service to use
+/// - `id` - The ID of the report
+/// - `authorizer` - The authorizer to prove we're allowed to see the report
+///
+/// # Returns
+/// The report, or a Problem if it couldn't be found
+#[tracing::instrument(name = "GET /reports/{id}", skip(moderation_service))]
+#[get("/reports/<id>")]
+pub fn get_report(
+    moderation_service: State<ModerationService>,
+    id: ReportID,
+    authorizer: Authorizer,
+) -> Result<ReportResponse, Problem> {
+    authorize_moderator(&moderation_service, &authorizer)?;
+
+    moderation_service
+        .find_report(&id)
+        .map(ReportResponse)
+        .ok_or_else(|| Problem::new(ModerationProblemType::UnknownReport, Status::NotFound))
+}
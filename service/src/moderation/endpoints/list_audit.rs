@@ -0,0 +1,31 @@
+use super::{model::AuditResponse, moderator::authorize_moderator};
+use crate::{
+    authorization::Authorizer,
+    http::{pagination::PaginationRequest, problem::Problem},
+    moderation::ModerationService,
+};
+use rocket::{get, State};
+
+/// Handler to list the audit trail of actions that moderators have taken
+///
+/// # Parameters
+/// - `moderation_service` - The moderation service to use
+/// - `pagination` - The pagination controls to apply
+/// - `authorizer` - The authorizer to prove we're allowed to see the audit trail
+///
+/// # Returns
+/// The actions, newest first
+#[tracing::instrument(name = "GET /moderation/audit", skip(moderation_service))]
+#[get("/moderation/audit?<pagination..>")]
+pub fn list_audit(
+    moderation_service: State<ModerationService>,
+    pagination: PaginationRequest,
+    authorizer: Authorizer,
+) -> Result<AuditResponse, Problem> {
+    authorize_moderator(&moderation_service, &authorizer)?;
+
+    let entries = moderation_service.list_audit(&pagination);
+    tracing::debug!(entries = ?entries, "Found audit entries");
+
+    Ok(AuditResponse(entries))
+}
@@ -0,0 +1,45 @@
+use super::{errors::ModerationValidation, model::ReportsResponse, moderator::authorize_moderator};
+use crate::{
+    authorization::Authorizer,
+    http::{
+        pagination::PaginationRequest,
+        problem::{Problem, ValidationProblem},
+    },
+    moderation::{ModerationService, ReportStatus},
+};
+use rocket::{get, State};
+
+/// Handler to list the moderation queue of reports
+///
+/// # Parameters
+/// - `moderation_service` - The moderation service to use
+/// - `status` - The status of the reports to list. If omitted then the open reports are listed
+/// - `pagination` - The pagination controls to apply
+/// - `authorizer` - The authorizer to prove we're allowed to see the reports
+///
+/// # Returns
+/// The reports, oldest first
+#[tracing::instrument(name = "GET /reports", skip(moderation_service))]
+#[get("/reports?<status>&<pagination..>")]
+pub fn list_reports(
+    moderation_service: State<ModerationService>,
+    status: Option<String>,
+    pagination: PaginationRequest,
+    authorizer: Authorizer,
+) -> Result<ReportsResponse, Problem> {
+    authorize_moderator(&moderation_service, &authorizer)?;
+
+    let status = match status {
+        None => ReportStatus::Open,
+        Some(status) => status.parse().map_err(|_| {
+            let mut problem = ValidationProblem::new();
+            problem.with_field_error("status", ModerationValidation::InvalidStatus);
+            problem.build()
+        })?,
+    };
+
+    let reports = moderation_service.list_reports(&status, &pagination);
+    tracing::debug!(reports = ?reports, "Found reports");
+
+    Ok(ReportsResponse(reports))
+}
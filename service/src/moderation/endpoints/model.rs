@@ -0,0 +1,9 @@
+mod audit_response;
+mod report_link;
+mod report_response;
+mod reports_response;
+
+pub use audit_response::*;
+pub use report_link::*;
+pub use report_response::*;
+pub use reports_response::*;
@@ -0,0 +1,97 @@
+use super::ReportLink;
+use crate::{
+    http::link::{Link, LinkRel, Links},
+    model::Page,
+    moderation::AuditEntry,
+    users::{endpoints::model::UserLink, UserID, UserModel, UsersService},
+};
+use itertools::*;
+use rocket::{
+    http::hyper::header::{CacheControl, CacheDirective},
+    response, Request, State,
+};
+use rocket_contrib::json::Json;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+
+/// API Model representing a page of the audit trail of moderator actions
+#[derive(Debug)]
+pub struct AuditResponse(pub Page<AuditEntry>);
+
+impl<'r> response::Responder<'r> for AuditResponse {
+    fn respond_to(self, req: &Request) -> response::Result<'r> {
+        let users_service = req.guard::<State<UsersService>>().unwrap();
+
+        let user_ids: Vec<&UserID> = self
+            .0
+            .entries
+            .iter()
+            .filter_map(|entry| entry.moderator.as_ref())
+            .unique()
+            .collect();
+        let users: HashMap<UserID, UserModel> = if user_ids.is_empty() {
+            HashMap::new()
+        } else {
+            users_service
+                .find_users_by_id(&user_ids[..])
+                .into_iter()
+                .map(|user| (user.identity.id.clone(), user))
+                .collect()
+        };
+
+        let entries: Vec<Value> = self
+            .0
+            .entries
+            .iter()
+            .map(|entry| {
+                let report: Option<String> =
+                    entry.report.clone().map(|id| ReportLink::new(id).into());
+
+                json!({
+                  "action": entry.action.to_string(),
+                  "target": entry.target.to_string(),
+                  "report": report,
+                  "note": entry.note,
+                  "created": entry.created
+                })
+            })
+            .collect();
+
+        let response_body = json!({
+          "entries": entries,
+          "pagination": {
+            "offset": self.0.offset,
+            "count": self.0.count,
+            "total": self.0.total
+          }
+        });
+
+        let links: Vec<Link> = self
+            .0
+            .entries
+            .iter()
+            .enumerate()
+            .filter_map(|(index, entry)| {
+                entry
+                    .moderator
+                    .as_ref()
+                    .and_then(|moderator| users.get(moderator))
+                    .map(|user| (index, user))
+            })
+            .map(|(index, user)| {
+                Link::new(UserLink::new(user.identity.id.clone()), LinkRel::AUTHOR)
+                    .anchor(format!("#/entries/{}", index))
+                    .title(user.data.display_name.clone())
+            })
+            .collect();
+
+        response::Response::build()
+            .merge(Json(response_body).respond_to(req).unwrap())
+            .header(CacheControl(vec![
+                CacheDirective::Private,
+                CacheDirective::NoCache,
+            ]))
+            .header(Links(links))
+            .ok()
+    }
+}
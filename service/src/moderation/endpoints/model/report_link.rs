@@ -0,0 +1,16 @@
+use crate::moderation::ReportID;
+
+/// Representation of a link to a report
+pub struct ReportLink(ReportID);
+
+impl ReportLink {
+    pub fn new(report_id: ReportID) -> Self {
+        Self(report_id)
+    }
+}
+
+impl From<ReportLink> for String {
+    fn from(report_link: ReportLink) -> String {
+        format!("/reports/{}", report_link.0)
+    }
+}
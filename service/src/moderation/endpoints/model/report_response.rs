@@ -0,0 +1,50 @@
+use super::ReportLink;
+use crate::{
+    http::link::{Link, LinkRel, Links},
+    moderation::ReportModel,
+    users::{endpoints::model::UserLink, UsersService},
+};
+use rocket::{
+    http::hyper::header::{CacheControl, CacheDirective, ETag, EntityTag},
+    response, Request, State,
+};
+use rocket_contrib::json::Json;
+use serde_json::{json, Value};
+
+/// API Model representing a Report
+#[derive(Debug)]
+pub struct ReportResponse(pub ReportModel);
+
+pub(super) fn to_json(report: &ReportModel) -> Value {
+    json!({
+      "target": report.data.target.to_string(),
+      "reason": report.data.reason,
+      "status": report.data.status.to_string(),
+      "created": report.identity.created,
+      "updated": report.identity.updated
+    })
+}
+
+impl<'r> response::Responder<'r> for ReportResponse {
+    fn respond_to(self, req: &Request) -> response::Result<'r> {
+        let users_service = req.guard::<State<UsersService>>().unwrap();
+        let user = users_service.find_user_by_id(&self.0.data.reporter);
+        let user_name = user.map(|u| u.data.display_name).unwrap_or_default();
+
+        let etag = self.0.identity.version.to_string();
+
+        response::Response::build()
+            .merge(Json(to_json(&self.0)).respond_to(req).unwrap())
+            .header(CacheControl(vec![
+                CacheDirective::Private,
+                CacheDirective::NoCache,
+            ]))
+            .header(ETag(EntityTag::new(false, etag)))
+            .header(Links(vec![
+                Link::new(ReportLink::new(self.0.identity.id), LinkRel::SELF),
+                Link::new(UserLink::new(self.0.data.reporter), LinkRel::AUTHOR).title(user_name),
+                Link::new(self.0.data.target.to_string(), LinkRel::RELATED),
+            ]))
+            .ok()
+    }
+}
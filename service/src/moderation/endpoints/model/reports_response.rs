@@ -0,0 +1,87 @@
+use super::{to_json, ReportLink};
+use crate::{
+    http::link::{Link, LinkRel, Links},
+    model::Page,
+    moderation::ReportModel,
+    users::{endpoints::model::UserLink, UserID, UserModel, UsersService},
+};
+use itertools::*;
+use rocket::{
+    http::hyper::header::{CacheControl, CacheDirective},
+    response, Request, State,
+};
+use rocket_contrib::json::Json;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+
+/// API Model representing a page of Reports from the moderation queue
+#[derive(Debug)]
+pub struct ReportsResponse(pub Page<ReportModel>);
+
+impl<'r> response::Responder<'r> for ReportsResponse {
+    fn respond_to(self, req: &Request) -> response::Result<'r> {
+        let users_service = req.guard::<State<UsersService>>().unwrap();
+
+        let user_ids: Vec<&UserID> = self
+            .0
+            .entries
+            .iter()
+            .map(|report| &report.data.reporter)
+            .unique()
+            .collect();
+        let users: HashMap<UserID, UserModel> = if user_ids.is_empty() {
+            HashMap::new()
+        } else {
+            users_service
+                .find_users_by_id(&user_ids[..])
+                .into_iter()
+                .map(|user| (user.identity.id.clone(), user))
+                .collect()
+        };
+
+        let reports: Vec<Value> = self.0.entries.iter().map(to_json).collect();
+
+        let response_body = json!({
+          "entries": reports,
+          "pagination": {
+            "offset": self.0.offset,
+            "count": self.0.count,
+            "total": self.0.total
+          }
+        });
+
+        let mut links: Vec<Link> = self
+            .0
+            .entries
+            .iter()
+            .enumerate()
+            .map(|(index, report)| {
+                Link::new(ReportLink::new(report.identity.id.clone()), LinkRel::ITEM)
+                    .anchor(format!("#/entries/{}", index))
+            })
+            .collect();
+        links.extend(
+            self.0
+                .entries
+                .iter()
+                .enumerate()
+                .filter_map(|(index, report)| {
+                    users.get(&report.data.reporter).map(|user| (index, user))
+                })
+                .map(|(index, user)| {
+                    Link::new(UserLink::new(user.identity.id.clone()), LinkRel::AUTHOR)
+                        .anchor(format!("#/entries/{}", index))
+                        .title(user.data.display_name.clone())
+                }),
+        );
+
+        response::Response::build()
+            .merge(Json(response_body).respond_to(req).unwrap())
+            .header(CacheControl(vec![
+                CacheDirective::Private,
+                CacheDirective::NoCache,
+            ]))
+            .header(Links(links))
+            .ok()
+    }
+}
@@ -0,0 +1,100 @@
+use super::{
+    errors::{ModerationProblemType, ModerationValidation},
+    model::ReportResponse,
+    moderator::authorize_moderator,
+};
+use crate::{
+    authorization::Authorizer,
+    http::problem::{GenericValidation, Problem, ValidationProblem},
+    moderation::{ModerateReportError, ModerationAction, ModerationService, ReportID},
+};
+use rocket::{http::Status, post, State};
+use rocket_contrib::json::Json;
+use serde::Deserialize;
+
+/// Handler to resolve a report from the moderation queue by taking an action against the reported
+/// content. The action is recorded in the audit trail
+///
+/// # Parameters
+/// - `moderation_service` - The moderation service to use
+/// - `id` - The ID of the report
+/// - `body` - The action to take
+/// - `authorizer` - The authorizer to prove we're allowed to moderate the report
+///
+/// # Returns
+/// The resolved report, or a Problem if the action failed
+#[tracing::instrument(name = "POST /reports/{id}/actions", skip(moderation_service))]
+#[post("/reports/<id>/actions", data = "<body>")]
+pub fn moderate_report(
+    moderation_service: State<ModerationService>,
+    id: ReportID,
+    body: Json<ModerateRequest>,
+    authorizer: Authorizer,
+) -> Result<ReportResponse, Problem> {
+    let moderator = authorize_moderator(&moderation_service, &authorizer)?;
+
+    let mut problem = ValidationProblem::new();
+    let action = match body.action.as_ref().filter(|v| !v.trim().is_empty()) {
+        None => {
+            problem.with_field_error("action", GenericValidation::Missing);
+            None
+        }
+        Some(action) => {
+            let action = action.trim().parse::<ModerationAction>().ok();
+            if action.is_none() {
+                problem.with_field_error("action", ModerationValidation::InvalidAction);
+            }
+            action
+        }
+    };
+    let action = action.ok_or_else(|| {
+        tracing::warn!("Validation error moderating report");
+        problem.build()
+    })?;
+
+    let report = moderation_service.moderate_report(
+        &id,
+        &moderator,
+        &action,
+        body.note.as_deref().unwrap_or(""),
+    )?;
+
+    Ok(ReportResponse(report))
+}
+
+/// Incoming details representing a request to moderate a report
+#[derive(Debug, Deserialize)]
+pub struct ModerateRequest {
+    /// The action to take - one of `dismiss`, `hide` or `suspend`
+    action: Option<String>,
+    /// A note explaining the action, kept in the audit trail. May be omitted
+    note: Option<String>,
+}
+
+impl From<ModerateReportError> for Problem {
+    fn from(e: ModerateReportError) -> Self {
+        match e {
+            ModerateReportError::UnknownReport => {
+                Problem::new(ModerationProblemType::UnknownReport, Status::NotFound)
+            }
+            ModerateReportError::AlreadyResolved => {
+                Problem::new(ModerationProblemType::AlreadyResolved, Status::Conflict)
+            }
+            ModerateReportError::UnknownTarget => {
+                Problem::new(ModerationProblemType::UnknownTarget, Status::NotFound)
+            }
+            ModerateReportError::UnsupportedAction => {
+                let mut problem = ValidationProblem::new();
+                problem.with_field_error("action", ModerationValidation::UnsupportedAction);
+                problem.build()
+            }
+            _ => {
+                tracing::warn!(error = ?e, "An unexpected error occurred");
+                Problem::new(
+                    ModerationProblemType::UnknownError,
+                    Status::InternalServerError,
+                )
+            }
+        }
+    }
+}
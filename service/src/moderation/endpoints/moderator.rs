@@ -0,0 +1,27 @@
+use crate::{
+    authorization::Authorizer, http::problem::Problem, moderation::ModerationService, users::UserID,
+};
+
+/// Ensure that the current user is allowed to moderate content
+///
+/// # Parameters
+/// - `moderation_service` - The moderation service to use
+/// - `authorizer` - The authorizer to identify the current user
+///
+/// # Returns
+/// The ID of the current user, if they are a moderator
+///
+/// # Errors
+/// A Problem if there is no current user or they are not a moderator
+pub(super) fn authorize_moderator(
+    moderation_service: &ModerationService,
+    authorizer: &Authorizer,
+) -> Result<UserID, Problem> {
+    let moderator = authorizer
+        .authorize()
+        .user_matching(|user| moderation_service.is_moderator(user))
+        .finish()?
+        .expect("No authenticated user");
+
+    Ok(moderator)
+}
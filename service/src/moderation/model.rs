@@ -0,0 +1,13 @@
+mod audit_entry;
+mod moderation_action;
+mod report;
+mod report_id;
+mod report_status;
+mod report_target;
+
+pub use audit_entry::*;
+pub use moderation_action::*;
+pub use report::*;
+pub use report_id::*;
+pub use report_status::*;
+pub use report_target::*;
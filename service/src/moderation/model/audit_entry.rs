@@ -0,0 +1,29 @@
+use super::{ModerationAction, ReportID, ReportTarget};
+use crate::users::UserID;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+/// A record of an action that a moderator took, kept as an audit trail
+#[derive(Debug, Clone)]
+pub struct AuditEntry {
+    /// The ID of the entry
+    pub id: Uuid,
+
+    /// When the action was taken
+    pub created: DateTime<Utc>,
+
+    /// The moderator that took the action, if they still exist
+    pub moderator: Option<UserID>,
+
+    /// The report that the action resolved, if it still exists
+    pub report: Option<ReportID>,
+
+    /// The action that was taken
+    pub action: ModerationAction,
+
+    /// The content that the action was taken against
+    pub target: ReportTarget,
+
+    /// The note that the moderator left to explain the action
+    pub note: String,
+}
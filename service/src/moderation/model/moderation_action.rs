@@ -0,0 +1,13 @@
+use strum_macros::{Display, EnumString};
+
+/// The actions that a moderator can take to resolve a report
+#[derive(Debug, PartialEq, Clone, Copy, Display, EnumString)]
+#[strum(serialize_all = "snake_case")]
+pub enum ModerationAction {
+    /// Close the report without doing anything to the reported content
+    Dismiss,
+    /// Hide the reported world or comment from everyone except its editors
+    Hide,
+    /// Suspend the user that was reported, or that is responsible for the reported content
+    Suspend,
+}
@@ -0,0 +1,21 @@
+use super::{ReportID, ReportStatus, ReportTarget};
+use crate::{model::Model, users::UserID};
+
+/// Data to represent a report of some content that needs moderating
+#[derive(Debug, Clone)]
+pub struct ReportData {
+    /// The ID of the user that made the report
+    pub reporter: UserID,
+
+    /// The content that was reported
+    pub target: ReportTarget,
+
+    /// The reason given for the report
+    pub reason: String,
+
+    /// Where the report is in the moderation queue
+    pub status: ReportStatus,
+}
+
+/// Model representation of a report
+pub type ReportModel = Model<ReportID, ReportData>;
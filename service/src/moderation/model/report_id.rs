@@ -0,0 +1,67 @@
+use bytes::BytesMut;
+use postgres_types::{accepts, to_sql_checked, FromSql, IsNull, ToSql, Type};
+use rocket::{http::RawStr, request};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use uuid::Uuid;
+
+/// The ID of a Report
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, FromSql)]
+pub struct ReportID(Uuid);
+
+impl ReportID {
+    /// Wrap a UUID as a Report ID object
+    ///
+    /// # Parameters
+    /// - `uuid` - The UUID to wrap
+    ///
+    /// # The Report ID
+    pub fn new(uuid: Uuid) -> Self {
+        ReportID(uuid)
+    }
+}
+
+impl Default for ReportID {
+    fn default() -> Self {
+        Self(Uuid::new_v4())
+    }
+}
+
+impl ToSql for ReportID {
+    accepts!(UUID);
+
+    to_sql_checked!();
+
+    fn to_sql(
+        &self,
+        t: &Type,
+        w: &mut BytesMut,
+    ) -> Result<IsNull, Box<dyn std::error::Error + Sync + Send>> {
+        self.0.to_sql(t, w)
+    }
+}
+
+impl<'r> request::FromParam<'r> for ReportID {
+    type Error = &'r RawStr;
+
+    fn from_param(param: &'r RawStr) -> Result<Self, Self::Error> {
+        param
+            .percent_decode()
+            .map(|cow| cow.into_owned())
+            .map_err(|_| param)
+            .and_then(|report_id| Uuid::parse_str(&report_id).map_err(|_| param))
+            .map(|report_id| ReportID::new(report_id))
+    }
+}
+
+impl Display for ReportID {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<ReportID> for Uuid {
+    fn from(report_id: ReportID) -> Self {
+        report_id.0
+    }
+}
@@ -0,0 +1,13 @@
+use strum_macros::{Display, EnumString};
+
+/// The states that a report can be in as it moves through the moderation queue
+#[derive(Debug, PartialEq, Clone, Copy, Display, EnumString)]
+#[strum(serialize_all = "snake_case")]
+pub enum ReportStatus {
+    /// The report is waiting for a moderator to look at it
+    Open,
+    /// A moderator decided that no action was needed
+    Dismissed,
+    /// A moderator took action against the reported content
+    Actioned,
+}
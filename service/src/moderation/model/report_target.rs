@@ -0,0 +1,145 @@
+use crate::{comments::CommentID, users::UserID, worlds::WorldID};
+use std::{
+    fmt::{Display, Formatter},
+    str::FromStr,
+};
+use uuid::Uuid;
+
+/// The content that a report is about
+#[derive(Debug, PartialEq, Clone)]
+pub enum ReportTarget {
+    /// A world
+    World(WorldID),
+    /// A comment on a world
+    Comment(CommentID),
+    /// A user
+    User(UserID),
+}
+
+/// Errors that can occur when parsing a Report Target
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum ReportTargetParseError {
+    /// The Report Target was not a link to a world, comment or user
+    #[error("The Report Target was not a link to a world, comment or user")]
+    Malformed,
+}
+
+impl ReportTarget {
+    /// Build a Report Target from the way that it is stored in the database
+    ///
+    /// # Parameters
+    /// - `kind` - The kind of target, as returned by `kind()`
+    /// - `id` - The ID of the target
+    ///
+    /// # Returns
+    /// The target, or `None` if the kind was not recognised
+    pub fn from_stored(kind: &str, id: Uuid) -> Option<Self> {
+        match kind {
+            "world" => Some(ReportTarget::World(WorldID::new(id))),
+            "comment" => Some(ReportTarget::Comment(CommentID::new(id))),
+            "user" => Some(ReportTarget::User(UserID::new(id))),
+            _ => None,
+        }
+    }
+
+    /// Get the kind of target that this is, as stored in the database
+    pub fn kind(&self) -> &'static str {
+        match self {
+            ReportTarget::World(_) => "world",
+            ReportTarget::Comment(_) => "comment",
+            ReportTarget::User(_) => "user",
+        }
+    }
+}
+
+impl FromStr for ReportTarget {
+    type Err = ReportTargetParseError;
+
+    /// Parse a link to the content that is being reported - e.g. `/worlds/7da4cb77-8839-4805-b93a-f4c536c8bc85`
+    ///
+    /// # Parameters
+    /// - `s` - The input string to parse
+    ///
+    /// # Returns
+    /// The parsed target
+    ///
+    /// # Errors
+    /// If the input was not a link to a world, comment or user
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.trim().trim_start_matches('/').splitn(2, '/');
+        let kind = parts.next().unwrap_or("");
+        let id = parts
+            .next()
+            .and_then(|id| Uuid::parse_str(id).ok())
+            .ok_or(ReportTargetParseError::Malformed)?;
+
+        match kind {
+            "worlds" => Ok(ReportTarget::World(WorldID::new(id))),
+            "comments" => Ok(ReportTarget::Comment(CommentID::new(id))),
+            "users" => Ok(ReportTarget::User(UserID::new(id))),
+            _ => Err(ReportTargetParseError::Malformed),
+        }
+    }
+}
+
+impl Display for ReportTarget {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReportTarget::World(id) => write!(f, "/worlds/{}", id),
+            ReportTarget::Comment(id) => write!(f, "/comments/{}", id),
+            ReportTarget::User(id) => write!(f, "/users/{}", id),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_links() {
+        let id = Uuid::parse_str("7da4cb77-8839-4805-b93a-f4c536c8bc85").unwrap();
+
+        assert_eq!(
+            "/worlds/7da4cb77-8839-4805-b93a-f4c536c8bc85".parse(),
+            Ok(ReportTarget::World(WorldID::new(id)))
+        );
+        assert_eq!(
+            "/comments/7da4cb77-8839-4805-b93a-f4c536c8bc85".parse(),
+            Ok(ReportTarget::Comment(CommentID::new(id)))
+        );
+        assert_eq!(
+            "/users/7da4cb77-8839-4805-b93a-f4c536c8bc85".parse(),
+            Ok(ReportTarget::User(UserID::new(id)))
+        );
+    }
+
+    #[test]
+    fn test_parse_malformed() {
+        for input in &[
+            "",
+            "/worlds",
+            "/worlds/not-a-uuid",
+            "/tables/7da4cb77-8839-4805-b93a-f4c536c8bc85",
+            "/worlds/7da4cb77-8839-4805-b93a-f4c536c8bc85/tables",
+        ] {
+            assert_eq!(
+                input.parse::<ReportTarget>(),
+                Err(ReportTargetParseError::Malformed),
+                "{}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let target = ReportTarget::Comment(CommentID::default());
+
+        assert_eq!(target.to_string().parse(), Ok(target.clone()));
+        assert_eq!(
+            ReportTarget::from_stored(target.kind(), Uuid::new_v4()).map(|t| t.kind()),
+            Some("comment")
+        );
+    }
+}
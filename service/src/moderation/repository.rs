@@ -0,0 +1,46 @@
+mod audit;
+mod create;
+mod errors;
+mod find;
+mod moderate;
+mod parse;
+mod targets;
+
+use crate::{infrastructure::database::Database, moderation::ReportTarget};
+pub use errors::*;
+use postgres::types::ToSql;
+
+/// Repository used to access Report and Moderation data from the database
+#[derive(Clone)]
+pub(super) struct ModerationRepository {
+    /// The database with which to access report records
+    database: Database,
+}
+
+impl ModerationRepository {
+    /// Create a new Moderation Repository to work with
+    ///
+    /// # Parameters
+    /// - `database` - The database connection to use
+    ///
+    /// # Returns
+    /// The Moderation Repository to use
+    pub(super) fn new(database: Database) -> Self {
+        Self { database }
+    }
+}
+
+/// Get the ID of the content that a report target refers to, ready to bind into a query
+///
+/// # Parameters
+/// - `target` - The report target
+///
+/// # Returns
+/// The ID of the target
+fn target_id(target: &ReportTarget) -> &(dyn ToSql + Sync) {
+    match target {
+        ReportTarget::World(id) => id,
+        ReportTarget::Comment(id) => id,
+        ReportTarget::User(id) => id,
+    }
+}
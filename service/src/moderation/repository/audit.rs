@@ -0,0 +1,51 @@
+use super::ModerationRepository;
+use crate::{
+    model::{Page, Pagination},
+    moderation::AuditEntry,
+};
+
+impl ModerationRepository {
+    /// List the actions that moderators have taken, newest first
+    ///
+    /// # Parameters
+    /// - `pagination` - The pagination details for which page of results are wanted
+    ///
+    /// # Returns
+    /// The requested page of results
+    #[tracing::instrument(skip(self))]
+    pub fn list_audit(&self, pagination: &Pagination) -> Page<AuditEntry> {
+        let mut connection = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+
+        let select_query = format!(
+            "SELECT * FROM moderation_actions ORDER BY created DESC, action_id ASC OFFSET {} LIMIT {}",
+            pagination.offset, pagination.count
+        );
+        let records: Vec<AuditEntry> = connection
+            .query(select_query.as_str(), &[])
+            .map(|rows| rows.iter().map(|row| self.parse_audit_row(row)).collect())
+            .expect("Failed to select moderation actions");
+
+        let num_records = records.len() as u64;
+        let total = if records.is_empty() || num_records == pagination.count {
+            // If we got zero records back, or exactly the number we asked for, then we don't know
+            // how many there are in the database
+            let count: i32 = connection
+                .query_one("SELECT COUNT(*)::INTEGER AS c FROM moderation_actions", &[])
+                .map(|row| row.get("c"))
+                .expect("Failed to count moderation actions");
+            count as u64
+        } else {
+            pagination.offset + num_records
+        };
+
+        Page {
+            entries: records,
+            offset: pagination.offset,
+            count: pagination.count,
+            total,
+        }
+    }
+}
@@ -0,0 +1,50 @@
+use super::{target_id, ModerationRepository, SaveReportError};
+use crate::{moderation::model::*, users::UserID};
+use chrono::Utc;
+use uuid::Uuid;
+
+impl ModerationRepository {
+    /// Create a new report in the database. New reports are always open
+    ///
+    /// # Parameters
+    /// - `reporter` - The ID of the user making the report
+    /// - `target` - The content being reported
+    /// - `reason` - The reason for the report
+    ///
+    /// # Returns
+    /// The newly created report
+    ///
+    /// # Errors
+    /// Any errors that occurred creating the new report
+    #[tracing::instrument(skip(self))]
+    pub fn create(
+        &self,
+        reporter: &UserID,
+        target: &ReportTarget,
+        reason: &str,
+    ) -> Result<ReportModel, SaveReportError> {
+        let id = ReportID::default();
+        let now = Utc::now();
+        let version = Uuid::new_v4();
+
+        let mut connection = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+
+        let new_report = connection.query_one("INSERT INTO reports(report_id, version, created, updated, reporter_id, target_type, target_id, reason, status) VALUES($1, $2, $3, $3, $4, $5, $6, $7, $8) RETURNING *",
+            &[
+                &id,
+                &version,
+                &now,
+                reporter,
+                &target.kind(),
+                target_id(target),
+                &reason,
+                &ReportStatus::Open.to_string(),
+            ])
+            .map(|row| self.parse_row(&row))?;
+
+        Ok(new_report)
+    }
+}
@@ -0,0 +1,41 @@
+/// Errors that can occur when saving a report record
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum SaveReportError {
+    /// An unknown error occurred
+    #[error("An unknown error occurred")]
+    UnknownError,
+}
+
+impl From<postgres::Error> for SaveReportError {
+    fn from(e: postgres::Error) -> Self {
+        tracing::warn!("Unexpected database error: {:?}", e);
+        SaveReportError::UnknownError
+    }
+}
+
+/// Errors that can occur when moderating a report
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum ModerateError {
+    /// An unknown error occurred
+    #[error("An unknown error occurred")]
+    UnknownError,
+
+    /// The report has already been resolved by a moderator
+    #[error("The report has already been resolved")]
+    AlreadyResolved,
+
+    /// The action can not be taken against the reported content
+    #[error("The action can not be taken against the reported content")]
+    UnsupportedAction,
+
+    /// The reported content no longer exists
+    #[error("The reported content no longer exists")]
+    UnknownTarget,
+}
+
+impl From<postgres::Error> for ModerateError {
+    fn from(e: postgres::Error) -> Self {
+        tracing::warn!("Unexpected database error: {:?}", e);
+        ModerateError::UnknownError
+    }
+}
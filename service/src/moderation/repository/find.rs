@@ -0,0 +1,81 @@
+use super::ModerationRepository;
+use crate::{
+    model::{Page, Pagination},
+    moderation::{ReportID, ReportModel, ReportStatus},
+};
+
+impl ModerationRepository {
+    /// Find a report in the database by its unique ID
+    ///
+    /// # Parameters
+    /// - `id` - The ID of the report
+    ///
+    /// # Returns
+    /// The report, if it exists
+    #[tracing::instrument(skip(self))]
+    pub fn find_report_by_id(&self, id: &ReportID) -> Option<ReportModel> {
+        let mut connection = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+        connection
+            .query_opt("SELECT * FROM reports WHERE report_id = $1", &[id])
+            .expect("Failed to query for report by ID")
+            .map(|row| self.parse_row(&row))
+    }
+
+    /// List the reports that have the given status, oldest first so that the queue is worked through in
+    /// the order that reports were made
+    ///
+    /// # Parameters
+    /// - `status` - The status of the reports to list
+    /// - `pagination` - The pagination details for which page of results are wanted
+    ///
+    /// # Returns
+    /// The requested page of results
+    #[tracing::instrument(skip(self))]
+    pub fn list_reports(
+        &self,
+        status: &ReportStatus,
+        pagination: &Pagination,
+    ) -> Page<ReportModel> {
+        let status = status.to_string();
+
+        let mut connection = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+
+        let select_query = format!(
+            "SELECT * FROM reports WHERE status = $1 ORDER BY created ASC, report_id ASC OFFSET {} LIMIT {}",
+            pagination.offset, pagination.count
+        );
+        let records: Vec<ReportModel> = connection
+            .query(select_query.as_str(), &[&status])
+            .map(|rows| rows.iter().map(|row| self.parse_row(row)).collect())
+            .expect("Failed to select reports");
+
+        let num_records = records.len() as u64;
+        let total = if records.is_empty() || num_records == pagination.count {
+            // If we got zero records back, or exactly the number we asked for, then we don't know
+            // how many there are in the database
+            let count: i32 = connection
+                .query_one(
+                    "SELECT COUNT(*)::INTEGER AS c FROM reports WHERE status = $1",
+                    &[&status],
+                )
+                .map(|row| row.get("c"))
+                .expect("Failed to count reports");
+            count as u64
+        } else {
+            pagination.offset + num_records
+        };
+
+        Page {
+            entries: records,
+            offset: pagination.offset,
+            count: pagination.count,
+            total,
+        }
+    }
+}
@@ -0,0 +1,102 @@
+use super::{target_id, targets::owner_sql, ModerateError, ModerationRepository};
+use crate::{moderation::model::*, users::UserID};
+use chrono::Utc;
+use uuid::Uuid;
+
+impl ModerationRepository {
+    /// Resolve an open report by taking an action against the reported content, and record the action in
+    /// the audit trail.
+    ///
+    /// This is done in a single transaction, so that the content, the report and the audit trail always
+    /// agree with each other, and so that a report can only ever be resolved once.
+    ///
+    /// # Parameters
+    /// - `report` - The report to resolve
+    /// - `moderator` - The ID of the moderator taking the action
+    /// - `action` - The action to take
+    /// - `note` - A note from the moderator explaining the action
+    ///
+    /// # Returns
+    /// The resolved report
+    ///
+    /// # Errors
+    /// Any errors that occurred resolving the report
+    #[tracing::instrument(skip(self))]
+    pub fn moderate(
+        &self,
+        report: &ReportModel,
+        moderator: &UserID,
+        action: &ModerationAction,
+        note: &str,
+    ) -> Result<ReportModel, ModerateError> {
+        let target = &report.data.target;
+        let now = Utc::now();
+        let version = Uuid::new_v4();
+
+        let mut connection = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+        let mut transaction = connection.transaction()?;
+
+        let status = match action {
+            ModerationAction::Dismiss => ReportStatus::Dismissed,
+            ModerationAction::Hide => {
+                let sql = match target {
+                    ReportTarget::World(_) => "UPDATE worlds SET hidden = TRUE, version = $2, updated = $3 WHERE world_id = $1",
+                    ReportTarget::Comment(_) => "UPDATE comments SET hidden = TRUE, version = $2, updated = $3 WHERE comment_id = $1",
+                    ReportTarget::User(_) => return Err(ModerateError::UnsupportedAction),
+                };
+                let hidden = transaction.execute(sql, &[target_id(target), &version, &now])?;
+                if hidden == 0 {
+                    return Err(ModerateError::UnknownTarget);
+                }
+                ReportStatus::Actioned
+            }
+            ModerationAction::Suspend => {
+                let sql = format!(
+                    "UPDATE users SET suspended = TRUE, version = $2, updated = $3 WHERE user_id = ({})",
+                    owner_sql(target)
+                );
+                let suspended =
+                    transaction.execute(sql.as_str(), &[target_id(target), &version, &now])?;
+                if suspended == 0 {
+                    return Err(ModerateError::UnknownTarget);
+                }
+                ReportStatus::Actioned
+            }
+        };
+
+        let resolved = transaction
+            .query_opt(
+                "UPDATE reports SET status = $2, version = $3, updated = $4 WHERE report_id = $1 AND status = $5 RETURNING *",
+                &[
+                    &report.identity.id,
+                    &status.to_string(),
+                    &version,
+                    &now,
+                    &ReportStatus::Open.to_string(),
+                ],
+            )?
+            .map(|row| self.parse_row(&row))
+            .ok_or(ModerateError::AlreadyResolved)?;
+
+        transaction.execute(
+            "INSERT INTO moderation_actions(action_id, created, moderator_id, report_id, action, target_type, target_id, note) VALUES($1, $2, $3, $4, $5, $6, $7, $8)",
+            &[
+                &Uuid::new_v4(),
+                &now,
+                moderator,
+                &report.identity.id,
+                &action.to_string(),
+                &target.kind(),
+                target_id(target),
+                &note,
+            ],
+        )?;
+
+        transaction.commit()?;
+
+        Ok(resolved)
+    }
+}
@@ -0,0 +1,56 @@
+use super::ModerationRepository;
+use crate::{model::Identity, moderation::model::*};
+use postgres::row::Row;
+
+impl ModerationRepository {
+    /// Parse a row from the database into a Report Model
+    ///
+    /// # Parameters
+    /// - `row` - The row to parse
+    ///
+    /// # Returns
+    /// The report that the row represented
+    pub(super) fn parse_row(&self, row: &Row) -> ReportModel {
+        let status: String = row.get("status");
+
+        ReportModel {
+            identity: Identity {
+                id: row.get("report_id"),
+                version: row.get("version"),
+                created: row.get("created"),
+                updated: row.get("updated"),
+            },
+            data: ReportData {
+                reporter: row.get("reporter_id"),
+                target: ReportTarget::from_stored(row.get("target_type"), row.get("target_id"))
+                    .expect("Invalid target stored for report"),
+                reason: row.get("reason"),
+                status: status.parse().expect("Invalid status stored for report"),
+            },
+        }
+    }
+
+    /// Parse a row from the database into an Audit Entry
+    ///
+    /// # Parameters
+    /// - `row` - The row to parse
+    ///
+    /// # Returns
+    /// The audit entry that the row represented
+    pub(super) fn parse_audit_row(&self, row: &Row) -> AuditEntry {
+        let action: String = row.get("action");
+
+        AuditEntry {
+            id: row.get("action_id"),
+            created: row.get("created"),
+            moderator: row.get("moderator_id"),
+            report: row.get("report_id"),
+            action: action
+                .parse()
+                .expect("Invalid action stored for audit entry"),
+            target: ReportTarget::from_stored(row.get("target_type"), row.get("target_id"))
+                .expect("Invalid target stored for audit entry"),
+            note: row.get("note"),
+        }
+    }
+}
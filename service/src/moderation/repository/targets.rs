@@ -0,0 +1,41 @@
+use super::{target_id, ModerationRepository};
+use crate::{moderation::ReportTarget, users::UserID};
+
+impl ModerationRepository {
+    /// Find the user that is responsible for some reportable content. This is the owner of a world, the
+    /// author of a comment, or the user themselves
+    ///
+    /// # Parameters
+    /// - `target` - The content to find the responsible user for
+    ///
+    /// # Returns
+    /// The ID of the responsible user, or `None` if the content doesn't exist
+    #[tracing::instrument(skip(self))]
+    pub fn find_target_owner(&self, target: &ReportTarget) -> Option<UserID> {
+        let mut connection = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+        connection
+            .query_opt(owner_sql(target), &[target_id(target)])
+            .expect("Failed to query for owner of reported content")
+            .map(|row| row.get("user_id"))
+    }
+}
+
+/// Build the SQL that selects the user that is responsible for some reportable content, as `user_id`
+///
+/// # Parameters
+/// - `target` - The content to find the responsible user for
+///
+/// # Returns
+/// The SQL, taking the ID of the content as the only bind parameter
+pub(super) fn owner_sql(target: &ReportTarget) -> &'static str {
+    match target {
+        ReportTarget::World(_) => "SELECT owner_id AS user_id FROM worlds WHERE world_id = $1",
+        ReportTarget::Comment(_) => {
+            "SELECT author_id AS user_id FROM comments WHERE comment_id = $1"
+        }
+        ReportTarget::User(_) => "SELECT user_id FROM users WHERE user_id = $1",
+    }
+}
@@ -0,0 +1,26 @@
+mod audit;
+mod find;
+mod moderate;
+mod report;
+
+use super::repository::ModerationRepository;
+use crate::users::UsersService;
+pub use moderate::ModerateReportError;
+pub use report::ReportContentError;
+
+/// Service Layer for dealing with Reports of content and the Moderation of them
+#[derive(Clone)]
+pub struct ModerationService {
+    repository: ModerationRepository,
+    users: UsersService,
+}
+
+impl ModerationService {
+    /// Create a new instance of the Moderation Service
+    ///
+    /// # Returns
+    /// The Moderation Service ready to use
+    pub(super) fn new(repository: ModerationRepository, users: UsersService) -> Self {
+        Self { repository, users }
+    }
+}
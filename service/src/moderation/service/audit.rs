@@ -0,0 +1,18 @@
+use super::ModerationService;
+use crate::{
+    model::{Page, Pagination},
+    moderation::AuditEntry,
+};
+
+impl ModerationService {
+    /// List the audit trail of actions that moderators have taken, newest first
+    ///
+    /// # Parameters
+    /// - `pagination` - The pagination controls to apply
+    ///
+    /// # Returns
+    /// The page of audit entries
+    pub fn list_audit(&self, pagination: &Pagination) -> Page<AuditEntry> {
+        self.repository.list_audit(pagination)
+    }
+}
@@ -0,0 +1,50 @@
+use super::ModerationService;
+use crate::{
+    model::{Page, Pagination},
+    moderation::{ReportID, ReportModel, ReportStatus},
+    users::UserID,
+};
+
+impl ModerationService {
+    /// Check if a user is allowed to moderate content. Only administrators that haven't themselves been
+    /// suspended are
+    ///
+    /// # Parameters
+    /// - `user` - The ID of the user
+    ///
+    /// # Returns
+    /// `true` if the user is a moderator
+    pub fn is_moderator(&self, user: &UserID) -> bool {
+        self.users
+            .find_user_by_id(user)
+            .map(|user| user.data.admin && !user.data.suspended)
+            .unwrap_or(false)
+    }
+
+    /// Find a single report by its ID
+    ///
+    /// # Parameters
+    /// - `id` - The ID of the report
+    ///
+    /// # Returns
+    /// The report, if it exists
+    pub fn find_report(&self, id: &ReportID) -> Option<ReportModel> {
+        self.repository.find_report_by_id(id)
+    }
+
+    /// List the reports that have the given status, oldest first
+    ///
+    /// # Parameters
+    /// - `status` - The status of the reports to list
+    /// - `pagination` - The pagination controls to apply
+    ///
+    /// # Returns
+    /// The page of reports
+    pub fn list_reports(
+        &self,
+        status: &ReportStatus,
+        pagination: &Pagination,
+    ) -> Page<ReportModel> {
+        self.repository.list_reports(status, pagination)
+    }
+}
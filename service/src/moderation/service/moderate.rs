@@ -0,0 +1,72 @@
+use crate::{
+    moderation::{repository::ModerateError, *},
+    users::UserID,
+};
+
+/// Errors that can occur when moderating a report
+#[derive(Debug, thiserror::Error)]
+pub enum ModerateReportError {
+    /// An unknown error occurred
+    #[error("An unknown error occurred")]
+    UnknownError,
+
+    /// The report does not exist
+    #[error("The report does not exist")]
+    UnknownReport,
+
+    /// The report has already been resolved by a moderator
+    #[error("The report has already been resolved")]
+    AlreadyResolved,
+
+    /// The action can not be taken against the reported content
+    #[error("The action can not be taken against the reported content")]
+    UnsupportedAction,
+
+    /// The reported content no longer exists
+    #[error("The reported content no longer exists")]
+    UnknownTarget,
+}
+
+impl ModerationService {
+    /// Resolve an open report by taking an action against the reported content
+    ///
+    /// # Parameters
+    /// - `id` - The ID of the report to resolve
+    /// - `moderator` - The ID of the moderator taking the action
+    /// - `action` - The action to take
+    /// - `note` - A note from the moderator explaining the action, kept in the audit trail
+    ///
+    /// # Returns
+    /// The resolved report
+    ///
+    /// # Errors
+    /// Any errors that occurred resolving the report
+    pub fn moderate_report(
+        &self,
+        id: &ReportID,
+        moderator: &UserID,
+        action: &ModerationAction,
+        note: &str,
+    ) -> Result<ReportModel, ModerateReportError> {
+        let report = self
+            .repository
+            .find_report_by_id(id)
+            .ok_or(ModerateReportError::UnknownReport)?;
+
+        let resolved = self.repository.moderate(&report, moderator, action, note)?;
+        tracing::info!(report = ?resolved, moderator = ?moderator, action = ?action, "Moderated report");
+
+        Ok(resolved)
+    }
+}
+
+impl From<ModerateError> for ModerateReportError {
+    fn from(e: ModerateError) -> Self {
+        match e {
+            ModerateError::AlreadyResolved => ModerateReportError::AlreadyResolved,
+            ModerateError::UnsupportedAction => ModerateReportError::UnsupportedAction,
+            ModerateError::UnknownTarget => ModerateReportError::UnknownTarget,
+            ModerateError::UnknownError => ModerateReportError::UnknownError,
+        }
+    }
+}
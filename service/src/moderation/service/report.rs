@@ -0,0 +1,52 @@
+use crate::{
+    moderation::{repository::SaveReportError, *},
+    users::UserID,
+};
+
+/// Errors that can occur when reporting content
+#[derive(Debug, thiserror::Error)]
+pub enum ReportContentError {
+    /// An unknown error occurred
+    #[error("An unknown error occurred")]
+    UnknownError,
+
+    /// The content being reported does not exist
+    #[error("The reported content does not exist")]
+    UnknownTarget,
+}
+
+impl ModerationService {
+    /// Report some content so that a moderator can look at it
+    ///
+    /// # Parameters
+    /// - `reporter` - The ID of the user making the report
+    /// - `target` - The content being reported
+    /// - `reason` - The reason for the report
+    ///
+    /// # Returns
+    /// The newly created report
+    ///
+    /// # Errors
+    /// Any errors that occurred reporting the content
+    pub fn report_content(
+        &self,
+        reporter: &UserID,
+        target: &ReportTarget,
+        reason: &str,
+    ) -> Result<ReportModel, ReportContentError> {
+        if self.repository.find_target_owner(target).is_none() {
+            return Err(ReportContentError::UnknownTarget);
+        }
+
+        let report = self.repository.create(reporter, target, reason)?;
+        tracing::debug!(report = ?report, "Created report");
+
+        Ok(report)
+    }
+}
+
+impl From<SaveReportError> for ReportContentError {
+    fn from(_: SaveReportError) -> Self {
+        ReportContentError::UnknownError
+    }
+}
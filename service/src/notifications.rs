@@ -0,0 +1,8 @@
+pub(crate) mod configure;
+pub(crate) mod endpoints;
+mod model;
+mod repository;
+mod service;
+
+pub use model::*;
+pub use service::*;
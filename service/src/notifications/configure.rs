@@ -0,0 +1,42 @@
+use super::{repository::NotificationRepository, NotificationsService};
+use crate::infrastructure::database::Database;
+use std::sync::Arc;
+
+/// Application Configuration for the Notifications module
+pub struct NotificationsConfig {
+    pub notifications_service: NotificationsService,
+}
+
+impl NotificationsConfig {
+    /// Create the Configuration for the Notifications Module
+    ///
+    /// # Returns
+    /// The Notifications Config object
+    pub fn new(database: Database) -> Self {
+        let repository = NotificationRepository::new(database);
+        Self {
+            notifications_service: NotificationsService::new(repository),
+        }
+    }
+
+    /// Generate the configuration callback needed for the HTTP Server to actually add the
+    /// Notifications endpoints to the server
+    ///
+    /// # Returns
+    /// The callback to provide to the HTTP Server to configure up the Notifications endpoints
+    pub fn configure(&self) -> Arc<dyn Fn(rocket::Rocket) -> rocket::Rocket + Send + Sync> {
+        let notifications_service = self.notifications_service.clone();
+        Arc::new(move |config| {
+            config.manage(notifications_service.clone()).mount(
+                "/",
+                rocket::routes![
+                    super::endpoints::get_preferences,
+                    super::endpoints::list_notifications,
+                    super::endpoints::read_all_notifications,
+                    super::endpoints::read_notification,
+                    super::endpoints::update_preferences
+                ],
+            )
+        })
+    }
+}
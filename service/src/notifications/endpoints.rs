@@ -0,0 +1,13 @@
+mod errors;
+mod get_preferences;
+mod list_notifications;
+pub(crate) mod model;
+mod read_all_notifications;
+mod read_notification;
+mod update_preferences;
+
+pub use get_preferences::*;
+pub use list_notifications::*;
+pub use read_all_notifications::*;
+pub use read_notification::*;
+pub use update_preferences::*;
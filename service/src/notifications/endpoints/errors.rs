@@ -0,0 +1,48 @@
+use crate::http::problem::{ProblemType, ValidationType};
+
+/// Problem Types that can happen when working with notifications
+#[derive(Debug, thiserror::Error)]
+pub enum NotificationProblemType {
+    /// The notification that was looked up was not found
+    #[error("The requested notification was unknown")]
+    UnknownNotification,
+}
+
+impl ProblemType for NotificationProblemType {
+    /// Generate a Type value for the `ProblemType` values.
+    ///
+    /// These are used in the `type` field in the RFC-7807 Problem Response
+    fn error_code(&self) -> &'static str {
+        match self {
+            NotificationProblemType::UnknownNotification => {
+                "tag:multiverse,2020:notifications/problems/unknown_notification"
+            }
+        }
+    }
+}
+
+/// Validation errors that are specific to notifications
+#[derive(Debug, thiserror::Error)]
+pub enum NotificationValidation {
+    /// The kind of notification is not one that exists
+    #[error("The notification kind was unknown")]
+    UnknownKind,
+
+    /// The preference for a kind of notification was not a boolean
+    #[error("The preference must be true or false")]
+    InvalidPreference,
+}
+
+impl ValidationType for NotificationValidation {
+    /// Generate a Type value for the `ValidationType` values.
+    fn error_code(&self) -> &'static str {
+        match self {
+            NotificationValidation::UnknownKind => {
+                "tag:multiverse,2020:notifications/problems/validation_error/unknown_kind"
+            }
+            NotificationValidation::InvalidPreference => {
+                "tag:multiverse,2020:notifications/problems/validation_error/invalid_preference"
+            }
+        }
+    }
+}
@@ -0,0 +1,30 @@
+use super::model::PreferencesResponse;
+use crate::{
+    authorization::Authorizer, http::problem::Problem, notifications::NotificationsService,
+};
+use rocket::{get, State};
+
+/// Handler to get which kinds of Notification the current user wants to receive
+///
+/// # Parameters
+/// - `notifications_service` - The notifications service to use
+/// - `authorizer` - The authorizer to prove whose preferences to get
+///
+/// # Returns
+/// The notification preferences
+#[tracing::instrument(name = "GET /notifications/preferences", skip(notifications_service))]
+#[get("/notifications/preferences")]
+pub fn get_preferences(
+    notifications_service: State<NotificationsService>,
+    authorizer: Authorizer,
+) -> Result<PreferencesResponse, Problem> {
+    let user = authorizer
+        .authorize()
+        .authorized()
+        .finish()?
+        .expect("No authenticated user");
+
+    let preferences = notifications_service.get_preferences(&user);
+
+    Ok(PreferencesResponse(preferences))
+}
@@ -0,0 +1,38 @@
+use super::model::NotificationsResponse;
+use crate::{
+    authorization::Authorizer,
+    http::{pagination::PaginationRequest, problem::Problem},
+    notifications::NotificationsService,
+};
+use rocket::{get, State};
+
+/// Handler to list the Notifications that have been sent to the current user, most recent first
+///
+/// # Parameters
+/// - `notifications_service` - The notifications service to use
+/// - `unread` - Whether to only include notifications that haven't been read yet
+/// - `pagination` - The pagination controls to apply
+/// - `authorizer` - The authorizer to prove whose notifications to list
+///
+/// # Returns
+/// The page of notifications
+#[tracing::instrument(name = "GET /notifications", skip(notifications_service))]
+#[get("/notifications?<unread>&<pagination..>")]
+pub fn list_notifications(
+    notifications_service: State<NotificationsService>,
+    unread: Option<bool>,
+    pagination: PaginationRequest,
+    authorizer: Authorizer,
+) -> Result<NotificationsResponse, Problem> {
+    let user = authorizer
+        .authorize()
+        .authorized()
+        .finish()?
+        .expect("No authenticated user");
+
+    let notifications =
+        notifications_service.search_notifications(&user, unread.unwrap_or(false), &pagination);
+    tracing::debug!(notifications = ?notifications, "Found notifications");
+
+    Ok(NotificationsResponse(notifications))
+}
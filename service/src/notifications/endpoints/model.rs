@@ -0,0 +1,7 @@
+mod notification_link;
+mod notifications_response;
+mod preferences_response;
+
+pub use notification_link::*;
+pub use notifications_response::*;
+pub use preferences_response::*;
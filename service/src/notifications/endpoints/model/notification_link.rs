@@ -0,0 +1,16 @@
+use crate::notifications::NotificationID;
+
+/// Representation of a link to a notification
+pub struct NotificationLink(NotificationID);
+
+impl NotificationLink {
+    pub fn new(notification_id: NotificationID) -> Self {
+        Self(notification_id)
+    }
+}
+
+impl From<NotificationLink> for String {
+    fn from(notification_link: NotificationLink) -> String {
+        format!("/notifications/{}", notification_link.0)
+    }
+}
@@ -0,0 +1,119 @@
+use super::NotificationLink;
+use crate::{
+    http::link::{Link, LinkRel, Links},
+    model::Page,
+    notifications::NotificationModel,
+    users::{endpoints::model::UserLink, UserID, UserModel, UsersService},
+    worlds::endpoints::model::WorldLink,
+};
+use itertools::*;
+use rocket::{
+    http::hyper::header::{CacheControl, CacheDirective},
+    response, Request, State,
+};
+use rocket_contrib::json::Json;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+
+/// API Model representing a page of Notifications
+#[derive(Debug)]
+pub struct NotificationsResponse(pub Page<NotificationModel>);
+
+impl<'r> response::Responder<'r> for NotificationsResponse {
+    fn respond_to(self, req: &Request) -> response::Result<'r> {
+        let users_service = req.guard::<State<UsersService>>().unwrap();
+
+        let user_ids: Vec<&UserID> = self
+            .0
+            .entries
+            .iter()
+            .map(|notification| &notification.actor)
+            .unique()
+            .collect();
+        let users: HashMap<UserID, UserModel> = if user_ids.is_empty() {
+            HashMap::new()
+        } else {
+            users_service
+                .find_users_by_id(&user_ids[..])
+                .into_iter()
+                .map(|user| (user.identity.id.clone(), user))
+                .collect()
+        };
+
+        let entries: Vec<Value> = self
+            .0
+            .entries
+            .iter()
+            .map(|notification| {
+                let world: String = WorldLink::new(notification.world.clone()).into();
+                let actor: String = UserLink::new(notification.actor.clone()).into();
+                json!({
+                  "kind": notification.kind.to_string(),
+                  "actor": actor,
+                  "world": world,
+                  "target": notification.target,
+                  "created": notification.created,
+                  "read": notification.read
+                })
+            })
+            .collect();
+
+        let response_body = json!({
+          "entries": entries,
+          "pagination": {
+            "offset": self.0.offset,
+            "count": self.0.count,
+            "total": self.0.total
+          }
+        });
+
+        let mut links: Vec<Link> = self
+            .0
+            .entries
+            .iter()
+            .enumerate()
+            .map(|(index, notification)| {
+                Link::new(
+                    NotificationLink::new(notification.id.clone()),
+                    LinkRel::SELF,
+                )
+                .anchor(format!("#/entries/{}", index))
+            })
+            .collect();
+        let world_links: Vec<Link> = self
+            .0
+            .entries
+            .iter()
+            .enumerate()
+            .map(|(index, notification)| {
+                Link::new(WorldLink::new(notification.world.clone()), LinkRel::RELATED)
+                    .anchor(format!("#/entries/{}", index))
+            })
+            .collect();
+        let user_links: Vec<Link> = self
+            .0
+            .entries
+            .iter()
+            .enumerate()
+            .filter_map(|(index, notification)| {
+                users.get(&notification.actor).map(|user| (index, user))
+            })
+            .map(|(index, user)| {
+                Link::new(UserLink::new(user.identity.id.clone()), LinkRel::AUTHOR)
+                    .anchor(format!("#/entries/{}", index))
+                    .title(user.data.display_name.clone())
+            })
+            .collect();
+        links.extend(world_links);
+        links.extend(user_links);
+
+        response::Response::build()
+            .merge(Json(response_body).respond_to(req).unwrap())
+            .header(CacheControl(vec![
+                CacheDirective::Private,
+                CacheDirective::MaxAge(60),
+            ]))
+            .header(Links(links))
+            .ok()
+    }
+}
@@ -0,0 +1,30 @@
+use crate::notifications::NotificationKind;
+use rocket::{
+    http::hyper::header::{CacheControl, CacheDirective},
+    response, Request,
+};
+use rocket_contrib::json::Json;
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+
+/// API Model representing the notification preferences of a user
+#[derive(Debug)]
+pub struct PreferencesResponse(pub HashMap<NotificationKind, bool>);
+
+impl<'r> response::Responder<'r> for PreferencesResponse {
+    fn respond_to(self, req: &Request) -> response::Result<'r> {
+        let response_body: Map<String, Value> = self
+            .0
+            .into_iter()
+            .map(|(kind, enabled)| (kind.to_string(), Value::Bool(enabled)))
+            .collect();
+
+        response::Response::build()
+            .merge(Json(response_body).respond_to(req).unwrap())
+            .header(CacheControl(vec![
+                CacheDirective::Private,
+                CacheDirective::MaxAge(60),
+            ]))
+            .ok()
+    }
+}
@@ -0,0 +1,29 @@
+use crate::{
+    authorization::Authorizer, http::problem::Problem, notifications::NotificationsService,
+};
+use rocket::{http::Status, post, Response, State};
+
+/// Handler to mark every Notification sent to the current user as read
+///
+/// # Parameters
+/// - `notifications_service` - The notifications service to use
+/// - `authorizer` - The authorizer to prove whose notifications these are
+///
+/// # Returns
+/// An empty response if the notifications were marked as read, or a Problem if they weren't
+#[tracing::instrument(name = "POST /notifications/read-all", skip(notifications_service))]
+#[post("/notifications/read-all")]
+pub fn read_all_notifications(
+    notifications_service: State<NotificationsService>,
+    authorizer: Authorizer,
+) -> Result<Response<'static>, Problem> {
+    let user = authorizer
+        .authorize()
+        .authorized()
+        .finish()?
+        .expect("No authenticated user");
+
+    notifications_service.mark_all_read(&user);
+
+    Ok(Response::build().status(Status::NoContent).finalize())
+}
@@ -0,0 +1,39 @@
+use super::errors::NotificationProblemType;
+use crate::{
+    authorization::Authorizer,
+    http::problem::Problem,
+    notifications::{NotificationID, NotificationsService},
+};
+use rocket::{http::Status, post, Response, State};
+
+/// Handler to mark a single Notification sent to the current user as read
+///
+/// # Parameters
+/// - `notifications_service` - The notifications service to use
+/// - `id` - The ID of the notification
+/// - `authorizer` - The authorizer to prove whose notification this is
+///
+/// # Returns
+/// An empty response if the notification was marked as read, or a Problem if it wasn't
+#[tracing::instrument(name = "POST /notifications/{id}/read", skip(notifications_service))]
+#[post("/notifications/<id>/read")]
+pub fn read_notification(
+    notifications_service: State<NotificationsService>,
+    id: NotificationID,
+    authorizer: Authorizer,
+) -> Result<Response<'static>, Problem> {
+    let user = authorizer
+        .authorize()
+        .authorized()
+        .finish()?
+        .expect("No authenticated user");
+
+    if notifications_service.mark_read(&user, &id) {
+        Ok(Response::build().status(Status::NoContent).finalize())
+    } else {
+        Err(Problem::new(
+            NotificationProblemType::UnknownNotification,
+            Status::NotFound,
+        ))
+    }
+}
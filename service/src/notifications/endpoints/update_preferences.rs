@@ -0,0 +1,63 @@
+use super::{errors::NotificationValidation, model::PreferencesResponse};
+use crate::{
+    authorization::Authorizer,
+    http::problem::{Problem, ValidationProblem},
+    notifications::{NotificationKind, NotificationsService},
+};
+use rocket::{put, State};
+use rocket_contrib::json::Json;
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Handler to change which kinds of Notification the current user wants to receive.
+///
+/// Only the kinds present in the request are changed. Any others keep their current setting
+///
+/// # Parameters
+/// - `notifications_service` - The notifications service to use
+/// - `body` - Whether each kind of notification should be enabled
+/// - `authorizer` - The authorizer to prove whose preferences to change
+///
+/// # Returns
+/// The full set of notification preferences after the change
+#[tracing::instrument(name = "PUT /notifications/preferences", skip(notifications_service))]
+#[put("/notifications/preferences", data = "<body>")]
+pub fn update_preferences(
+    notifications_service: State<NotificationsService>,
+    body: Json<HashMap<String, Value>>,
+    authorizer: Authorizer,
+) -> Result<PreferencesResponse, Problem> {
+    let user = authorizer
+        .authorize()
+        .authorized()
+        .finish()?
+        .expect("No authenticated user");
+
+    let mut problem = ValidationProblem::new();
+    let mut valid = true;
+    let mut preferences: HashMap<NotificationKind, bool> = HashMap::new();
+
+    for (kind, enabled) in body.0 {
+        match (kind.parse::<NotificationKind>(), enabled.as_bool()) {
+            (Err(_), _) => {
+                problem.with_field_error(kind, NotificationValidation::UnknownKind);
+                valid = false;
+            }
+            (Ok(_), None) => {
+                problem.with_field_error(kind, NotificationValidation::InvalidPreference);
+                valid = false;
+            }
+            (Ok(kind), Some(enabled)) => {
+                preferences.insert(kind, enabled);
+            }
+        }
+    }
+
+    if !valid {
+        return Err(problem.build());
+    }
+
+    let preferences = notifications_service.update_preferences(&user, &preferences);
+
+    Ok(PreferencesResponse(preferences))
+}
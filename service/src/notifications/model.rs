@@ -0,0 +1,7 @@
+mod notification;
+mod notification_id;
+mod notification_kind;
+
+pub use notification::*;
+pub use notification_id::*;
+pub use notification_kind::*;
@@ -0,0 +1,50 @@
+use super::{NotificationID, NotificationKind};
+use crate::{users::UserID, worlds::WorldID};
+use chrono::{DateTime, Utc};
+
+/// Data to represent a notification that is to be sent to a user
+#[derive(Debug, Clone)]
+pub struct NotificationData {
+    /// The ID of the user to notify. If `None` then the owner of the world is notified
+    pub user: Option<UserID>,
+
+    /// The ID of the user that caused the notification
+    pub actor: UserID,
+
+    /// What the notification is about
+    pub kind: NotificationKind,
+
+    /// The ID of the world that the notification relates to
+    pub world: WorldID,
+
+    /// A link to the thing that the notification is about, if it isn't the world itself
+    pub target: Option<String>,
+}
+
+/// Model representation of a notification that has been sent to a user
+#[derive(Debug, Clone)]
+pub struct NotificationModel {
+    /// The ID of the notification
+    pub id: NotificationID,
+
+    /// When the notification was created
+    pub created: DateTime<Utc>,
+
+    /// The ID of the user that was notified
+    pub user: UserID,
+
+    /// The ID of the user that caused the notification
+    pub actor: UserID,
+
+    /// What the notification is about
+    pub kind: NotificationKind,
+
+    /// The ID of the world that the notification relates to
+    pub world: WorldID,
+
+    /// A link to the thing that the notification is about, if it isn't the world itself
+    pub target: Option<String>,
+
+    /// When the notification was read, if it has been
+    pub read: Option<DateTime<Utc>>,
+}
@@ -0,0 +1,42 @@
+/// The kinds of things that a user can be notified about
+#[derive(
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    Clone,
+    strum_macros::EnumString,
+    strum_macros::Display,
+    strum_macros::EnumIter,
+)]
+#[strum(serialize_all = "snake_case")]
+pub enum NotificationKind {
+    /// Someone commented on a world that the user owns
+    Comment,
+    /// Someone replied to a comment that the user wrote
+    Reply,
+    /// Someone starred a world that the user owns
+    Star,
+    /// Someone accepted an invitation to a world that the user owns
+    MemberJoined,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use strum::IntoEnumIterator;
+
+    #[test]
+    fn test_kind_round_trip() {
+        for kind in NotificationKind::iter() {
+            let serialized = kind.to_string();
+            assert_eq!(serialized.parse::<NotificationKind>(), Ok(kind));
+        }
+    }
+
+    #[test]
+    fn test_kind_names() {
+        assert_eq!(NotificationKind::Comment.to_string(), "comment");
+        assert_eq!(NotificationKind::MemberJoined.to_string(), "member_joined");
+    }
+}
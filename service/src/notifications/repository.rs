@@ -0,0 +1,27 @@
+mod create;
+mod find;
+mod parse;
+mod preferences;
+mod read;
+
+use crate::infrastructure::database::Database;
+
+/// Repository used to access Notification data from the database
+#[derive(Clone)]
+pub(super) struct NotificationRepository {
+    /// The database with which to access notification records
+    database: Database,
+}
+
+impl NotificationRepository {
+    /// Create a new Notification Repository to work with
+    ///
+    /// # Parameters
+    /// - `database` - The database connection to use
+    ///
+    /// # Returns
+    /// The Notification Repository to use
+    pub(super) fn new(database: Database) -> Self {
+        Self { database }
+    }
+}
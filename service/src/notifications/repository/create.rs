@@ -0,0 +1,49 @@
+use super::NotificationRepository;
+use crate::notifications::NotificationData;
+use chrono::Utc;
+use uuid::Uuid;
+
+impl NotificationRepository {
+    /// Create a notification in the database.
+    ///
+    /// Nothing is created if the user being notified is the one that caused the notification, or if
+    /// the user being notified has turned off notifications of this kind
+    ///
+    /// # Parameters
+    /// - `notification` - The notification to create
+    ///
+    /// # Errors
+    /// Any errors that occurred creating the notification
+    #[tracing::instrument(skip(self))]
+    pub fn create(&self, notification: &NotificationData) -> Result<(), postgres::Error> {
+        let id = Uuid::new_v4();
+        let now = Utc::now();
+
+        let mut connection = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+
+        connection.execute(
+            "INSERT INTO notifications(notification_id, created, user_id, actor_id, kind, world_id, target)
+            SELECT $1, $2, recipient, $4, $5, world_id, $6
+            FROM (SELECT world_id, COALESCE($3, owner_id) AS recipient FROM worlds WHERE world_id = $7) w
+            WHERE recipient != $4
+            AND NOT EXISTS (
+                SELECT 1 FROM notification_preferences p
+                WHERE p.user_id = recipient AND p.kind = $5 AND NOT p.enabled
+            )",
+            &[
+                &id,
+                &now,
+                &notification.user,
+                &notification.actor,
+                &notification.kind.to_string(),
+                &notification.target,
+                &notification.world,
+            ],
+        )?;
+
+        Ok(())
+    }
+}
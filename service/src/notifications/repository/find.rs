@@ -0,0 +1,69 @@
+use super::NotificationRepository;
+use crate::{
+    model::{Page, Pagination},
+    notifications::NotificationModel,
+    users::UserID,
+};
+
+impl NotificationRepository {
+    /// Search for the notifications sent to a user, with the most recent first
+    ///
+    /// # Parameters
+    /// - `user` - The ID of the user whose notifications are wanted
+    /// - `unread` - Whether to only include notifications that haven't been read yet
+    /// - `pagination` - The pagination details for which page of results are wanted
+    ///
+    /// # Returns
+    /// The requested page of results
+    #[tracing::instrument(skip(self))]
+    pub fn search_notifications(
+        &self,
+        user: &UserID,
+        unread: bool,
+        pagination: &Pagination,
+    ) -> Page<NotificationModel> {
+        let where_clause = if unread {
+            "WHERE user_id = $1 AND read IS NULL"
+        } else {
+            "WHERE user_id = $1"
+        };
+
+        let mut connection = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+
+        let select_query = format!(
+            "SELECT * FROM notifications {} ORDER BY created DESC, notification_id ASC OFFSET {} LIMIT {}",
+            where_clause, pagination.offset, pagination.count
+        );
+        let records: Vec<NotificationModel> = connection
+            .query(select_query.as_str(), &[user])
+            .map(|rows| rows.iter().map(|row| self.parse_row(row)).collect())
+            .expect("Failed to select notifications");
+
+        let num_records = records.len() as u64;
+        let total = if records.is_empty() || num_records == pagination.count {
+            // If we got zero records back, or exactly the number we asked for, then we don't know
+            // how many there are in the database
+            let count_query = format!(
+                "SELECT COUNT(*)::INTEGER AS c FROM notifications {}",
+                where_clause
+            );
+            let count: i32 = connection
+                .query_one(count_query.as_str(), &[user])
+                .map(|row| row.get("c"))
+                .expect("Failed to count notifications");
+            count as u64
+        } else {
+            pagination.offset + num_records
+        };
+
+        Page {
+            entries: records,
+            offset: pagination.offset,
+            count: pagination.count,
+            total,
+        }
+    }
+}
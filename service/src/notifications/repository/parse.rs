@@ -0,0 +1,27 @@
+use super::NotificationRepository;
+use crate::notifications::model::*;
+use postgres::row::Row;
+
+impl NotificationRepository {
+    /// Parse a row from the database into a Notification Model
+    ///
+    /// # Parameters
+    /// - `row` - The row to parse
+    ///
+    /// # Returns
+    /// The notification that the row represented
+    pub(super) fn parse_row(&self, row: &Row) -> NotificationModel {
+        let kind: String = row.get("kind");
+
+        NotificationModel {
+            id: row.get("notification_id"),
+            created: row.get("created"),
+            user: row.get("user_id"),
+            actor: row.get("actor_id"),
+            kind: kind.parse().expect("Unknown notification kind in database"),
+            world: row.get("world_id"),
+            target: row.get("target"),
+            read: row.get("read"),
+        }
+    }
+}
@@ -0,0 +1,63 @@
+use super::NotificationRepository;
+use crate::{notifications::NotificationKind, users::UserID};
+use std::collections::HashMap;
+
+impl NotificationRepository {
+    /// Find the notification preferences that a user has explicitly set
+    ///
+    /// # Parameters
+    /// - `user` - The ID of the user whose preferences are wanted
+    ///
+    /// # Returns
+    /// Whether each kind of notification is enabled. Kinds that the user has never set are not present
+    #[tracing::instrument(skip(self))]
+    pub fn find_preferences(&self, user: &UserID) -> HashMap<NotificationKind, bool> {
+        let mut connection = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+
+        connection
+            .query(
+                "SELECT kind, enabled FROM notification_preferences WHERE user_id = $1",
+                &[user],
+            )
+            .expect("Failed to select notification preferences")
+            .iter()
+            .filter_map(|row| {
+                let kind: String = row.get("kind");
+                kind.parse().ok().map(|kind| (kind, row.get("enabled")))
+            })
+            .collect()
+    }
+
+    /// Save some notification preferences for a user, leaving any others unchanged
+    ///
+    /// # Parameters
+    /// - `user` - The ID of the user whose preferences are being saved
+    /// - `preferences` - Whether each kind of notification is enabled
+    #[tracing::instrument(skip(self))]
+    pub fn save_preferences(&self, user: &UserID, preferences: &HashMap<NotificationKind, bool>) {
+        let mut connection = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+        let mut transaction = connection
+            .transaction()
+            .expect("Failed to start transaction");
+
+        for (kind, enabled) in preferences {
+            transaction
+                .execute(
+                    "INSERT INTO notification_preferences(user_id, kind, enabled) VALUES($1, $2, $3)
+                    ON CONFLICT (user_id, kind) DO UPDATE SET enabled = EXCLUDED.enabled",
+                    &[user, &kind.to_string(), enabled],
+                )
+                .expect("Failed to save notification preference");
+        }
+
+        transaction
+            .commit()
+            .expect("Failed to commit notification preferences");
+    }
+}
@@ -0,0 +1,50 @@
+use super::NotificationRepository;
+use crate::{notifications::NotificationID, users::UserID};
+use chrono::Utc;
+
+impl NotificationRepository {
+    /// Mark a single notification as read. Notifications that were already read keep their
+    /// original read time
+    ///
+    /// # Parameters
+    /// - `user` - The ID of the user that the notification was sent to
+    /// - `id` - The ID of the notification
+    ///
+    /// # Returns
+    /// True if the notification exists for this user. False if not
+    #[tracing::instrument(skip(self))]
+    pub fn mark_read(&self, user: &UserID, id: &NotificationID) -> bool {
+        let mut connection = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+
+        let updated = connection
+            .execute(
+                "UPDATE notifications SET read = COALESCE(read, $1) WHERE notification_id = $2 AND user_id = $3",
+                &[&Utc::now(), id, user],
+            )
+            .expect("Failed to mark notification as read");
+
+        updated == 1
+    }
+
+    /// Mark every unread notification for a user as read
+    ///
+    /// # Parameters
+    /// - `user` - The ID of the user whose notifications are to be marked as read
+    #[tracing::instrument(skip(self))]
+    pub fn mark_all_read(&self, user: &UserID) {
+        let mut connection = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+
+        connection
+            .execute(
+                "UPDATE notifications SET read = $1 WHERE user_id = $2 AND read IS NULL",
+                &[&Utc::now(), user],
+            )
+            .expect("Failed to mark notifications as read");
+    }
+}
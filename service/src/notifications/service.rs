@@ -0,0 +1,22 @@
+mod find;
+mod notify;
+mod preferences;
+mod read;
+
+use super::repository::NotificationRepository;
+
+/// Service Layer for dealing with Notifications sent to users
+#[derive(Clone)]
+pub struct NotificationsService {
+    repository: NotificationRepository,
+}
+
+impl NotificationsService {
+    /// Create a new instance of the Notifications Service
+    ///
+    /// # Returns
+    /// The Notifications Service ready to use
+    pub(super) fn new(repository: NotificationRepository) -> Self {
+        Self { repository }
+    }
+}
@@ -0,0 +1,27 @@
+use super::NotificationsService;
+use crate::{
+    model::{Page, Pagination},
+    notifications::NotificationModel,
+    users::UserID,
+};
+
+impl NotificationsService {
+    /// Search for the notifications sent to a user, most recent first
+    ///
+    /// # Parameters
+    /// - `user` - The ID of the user whose notifications are wanted
+    /// - `unread` - Whether to only include notifications that haven't been read yet
+    /// - `pagination` - The pagination details for which page of results are wanted
+    ///
+    /// # Returns
+    /// The requested page of results
+    pub fn search_notifications(
+        &self,
+        user: &UserID,
+        unread: bool,
+        pagination: &Pagination,
+    ) -> Page<NotificationModel> {
+        self.repository
+            .search_notifications(user, unread, pagination)
+    }
+}
@@ -0,0 +1,17 @@
+use super::NotificationsService;
+use crate::notifications::NotificationData;
+
+impl NotificationsService {
+    /// Notify a user that something has happened.
+    ///
+    /// Failing to send a notification is never allowed to fail the action that caused it, so any errors
+    /// are logged and then ignored
+    ///
+    /// # Parameters
+    /// - `notification` - The notification to send
+    pub fn notify(&self, notification: NotificationData) {
+        if let Err(e) = self.repository.create(&notification) {
+            tracing::warn!(e = ?e, notification = ?notification, "Failed to send notification");
+        }
+    }
+}
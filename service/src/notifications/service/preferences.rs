@@ -0,0 +1,43 @@
+use super::NotificationsService;
+use crate::{notifications::NotificationKind, users::UserID};
+use std::collections::HashMap;
+use strum::IntoEnumIterator;
+
+impl NotificationsService {
+    /// Get the notification preferences for a user. Every kind of notification is enabled unless the
+    /// user has turned it off
+    ///
+    /// # Parameters
+    /// - `user` - The ID of the user whose preferences are wanted
+    ///
+    /// # Returns
+    /// Whether each kind of notification is enabled
+    pub fn get_preferences(&self, user: &UserID) -> HashMap<NotificationKind, bool> {
+        let saved = self.repository.find_preferences(user);
+
+        NotificationKind::iter()
+            .map(|kind| {
+                let enabled = saved.get(&kind).cloned().unwrap_or(true);
+                (kind, enabled)
+            })
+            .collect()
+    }
+
+    /// Update some of the notification preferences for a user, leaving any others unchanged
+    ///
+    /// # Parameters
+    /// - `user` - The ID of the user whose preferences are being updated
+    /// - `preferences` - Whether each kind of notification is enabled
+    ///
+    /// # Returns
+    /// The full set of preferences after the update
+    pub fn update_preferences(
+        &self,
+        user: &UserID,
+        preferences: &HashMap<NotificationKind, bool>,
+    ) -> HashMap<NotificationKind, bool> {
+        self.repository.save_preferences(user, preferences);
+
+        self.get_preferences(user)
+    }
+}
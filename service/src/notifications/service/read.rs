@@ -0,0 +1,24 @@
+use super::NotificationsService;
+use crate::{notifications::NotificationID, users::UserID};
+
+impl NotificationsService {
+    /// Mark a single notification sent to a user as read
+    ///
+    /// # Parameters
+    /// - `user` - The ID of the user that the notification was sent to
+    /// - `id` - The ID of the notification
+    ///
+    /// # Returns
+    /// True if the notification exists for this user. False if not
+    pub fn mark_read(&self, user: &UserID, id: &NotificationID) -> bool {
+        self.repository.mark_read(user, id)
+    }
+
+    /// Mark every notification sent to a user as read
+    ///
+    /// # Parameters
+    /// - `user` - The ID of the user whose notifications are to be marked as read
+    pub fn mark_all_read(&self, user: &UserID) {
+        self.repository.mark_all_read(user)
+    }
+}
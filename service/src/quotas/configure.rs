@@ -0,0 +1,39 @@
+use super::{repository::QuotaRepository, QuotaLimits, QuotasService};
+use crate::infrastructure::database::Database;
+use std::sync::Arc;
+
+/// Application Configuration for the Quotas module
+pub struct QuotasConfig {
+    pub quotas_service: QuotasService,
+}
+
+impl QuotasConfig {
+    /// Create the Configuration for the Quotas Module
+    ///
+    /// # Parameters
+    /// - `database` - The database connection to use
+    /// - `defaults` - The quotas that users have unless they have been given an override
+    ///
+    /// # Returns
+    /// The Quotas Config object
+    pub fn new(database: Database, defaults: QuotaLimits) -> Self {
+        let repository = QuotaRepository::new(database);
+        Self {
+            quotas_service: QuotasService::new(repository, defaults),
+        }
+    }
+
+    /// Generate the configuration callback needed for the HTTP Server to actually add the Quotas
+    /// endpoints to the server
+    ///
+    /// # Returns
+    /// The callback to provide to the HTTP Server to configure up the Quotas endpoints
+    pub fn configure(&self) -> Arc<dyn Fn(rocket::Rocket) -> rocket::Rocket + Send + Sync> {
+        let quotas_service = self.quotas_service.clone();
+        Arc::new(move |config| {
+            config
+                .manage(quotas_service.clone())
+                .mount("/", rocket::routes![super::endpoints::get_quota])
+        })
+    }
+}
@@ -0,0 +1,5 @@
+mod errors;
+mod get_quota;
+mod model;
+
+pub use get_quota::*;
@@ -0,0 +1,33 @@
+use crate::{
+    http::problem::{Problem, ProblemType},
+    quotas::QuotaExceededError,
+};
+use rocket::http::Status;
+
+/// Problem Types that can happen when working with quotas
+#[derive(Debug, thiserror::Error)]
+pub enum QuotaProblemType {
+    /// The action would take the user over their quota
+    #[error("The quota has been exceeded")]
+    QuotaExceeded,
+}
+
+impl ProblemType for QuotaProblemType {
+    /// Generate a Type value for the `ProblemType` values.
+    ///
+    /// These are used in the `type` field in the RFC-7807 Problem Response
+    fn error_code(&self) -> &'static str {
+        match self {
+            QuotaProblemType::QuotaExceeded => "tag:multiverse,2020:problems/quota_exceeded",
+        }
+    }
+}
+
+impl From<QuotaExceededError> for Problem {
+    fn from(e: QuotaExceededError) -> Self {
+        Problem::new(QuotaProblemType::QuotaExceeded, Status::Forbidden)
+            .with_extra("resource", e.resource.to_string())
+            .with_extra("used", e.used)
+            .with_extra("limit", e.limit)
+    }
+}
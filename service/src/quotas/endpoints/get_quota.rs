@@ -0,0 +1,29 @@
+use super::model::QuotasResponse;
+use crate::{
+    authorization::Authorizer, http::problem::Problem, quotas::QuotasService, users::UserID,
+};
+use rocket::{get, State};
+
+/// Handler to get how much of each resource a user is using, and how much they are allowed
+///
+/// # Parameters
+/// - `quotas_service` - The quotas service to use
+/// - `id` - The ID of the user
+/// - `authorizer` - The authorizer to prove we're allowed to see the quotas of the user
+///
+/// # Returns
+/// The quotas of the user
+#[tracing::instrument(name = "GET /users/{id}/quota", skip(quotas_service))]
+#[get("/users/<id>/quota")]
+pub fn get_quota(
+    quotas_service: State<QuotasService>,
+    id: UserID,
+    authorizer: Authorizer,
+) -> Result<QuotasResponse, Problem> {
+    authorizer.authorize().same_user(&id).finish()?;
+
+    let quotas = quotas_service.find_quotas(&id);
+    tracing::debug!(quotas = ?quotas, "Found quotas");
+
+    Ok(QuotasResponse { user: id, quotas })
+}
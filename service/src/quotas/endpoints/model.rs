@@ -0,0 +1,3 @@
+mod quotas_response;
+
+pub use quotas_response::*;
@@ -0,0 +1,50 @@
+use crate::{
+    http::link::{Link, LinkRel, Links},
+    quotas::Quota,
+    users::{endpoints::model::UserLink, UserID},
+};
+use rocket::{
+    http::hyper::header::{CacheControl, CacheDirective},
+    response, Request,
+};
+use rocket_contrib::json::Json;
+use serde_json::{json, Map, Value};
+
+/// API Model representing the quotas of a user
+#[derive(Debug)]
+pub struct QuotasResponse {
+    /// The ID of the user
+    pub user: UserID,
+    /// The quotas of the user
+    pub quotas: Vec<Quota>,
+}
+
+impl<'r> response::Responder<'r> for QuotasResponse {
+    fn respond_to(self, req: &Request) -> response::Result<'r> {
+        let quotas: Map<String, Value> = self
+            .quotas
+            .iter()
+            .map(|quota| {
+                (
+                    quota.resource.to_string(),
+                    json!({
+                        "used": quota.used,
+                        "limit": quota.limit
+                    }),
+                )
+            })
+            .collect();
+
+        response::Response::build()
+            .merge(Json(Value::Object(quotas)).respond_to(req).unwrap())
+            .header(CacheControl(vec![
+                CacheDirective::Private,
+                CacheDirective::NoCache,
+            ]))
+            .header(Links(vec![
+                Link::new(format!("/users/{}/quota", self.user), LinkRel::SELF),
+                Link::new(UserLink::new(self.user), LinkRel::RELATED),
+            ]))
+            .ok()
+    }
+}
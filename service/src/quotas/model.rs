@@ -0,0 +1,9 @@
+mod quota;
+mod quota_allowance;
+mod quota_limits;
+mod quota_resource;
+
+pub use quota::*;
+pub use quota_allowance::*;
+pub use quota_limits::*;
+pub use quota_resource::*;
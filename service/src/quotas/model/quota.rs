@@ -0,0 +1,12 @@
+use super::QuotaResource;
+
+/// How much of a resource a user has used, compared to how much they are allowed
+#[derive(Debug, Clone, PartialEq)]
+pub struct Quota {
+    /// The resource
+    pub resource: QuotaResource,
+    /// How much of the resource the user has used
+    pub used: u64,
+    /// How much of the resource the user is allowed
+    pub limit: u64,
+}
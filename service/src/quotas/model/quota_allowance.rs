@@ -0,0 +1,18 @@
+use super::QuotaResource;
+use crate::users::UserID;
+
+/// Permission for a user to use some more of a resource, granted after checking their quota.
+///
+/// The check alone can't stop concurrent requests from all fitting within the same remaining quota, so
+/// the allowance must be enforced again with `enforce_quota` in the transaction that uses the resource
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuotaAllowance {
+    /// The user whose quota the resource counts towards
+    pub user: UserID,
+    /// The resource
+    pub resource: QuotaResource,
+    /// How much more of the resource is going to be used
+    pub amount: u64,
+    /// How much of the resource the user is allowed
+    pub limit: u64,
+}
@@ -0,0 +1,47 @@
+use super::QuotaResource;
+
+/// The quotas that users have unless they have been given an override
+#[derive(Debug, Clone)]
+pub struct QuotaLimits {
+    /// The number of worlds that a user can own
+    pub worlds: u64,
+    /// The number of random tables that a user can have across all of their worlds
+    pub tables: u64,
+    /// The number of campaigns that a user can have across all of their worlds
+    pub campaigns: u64,
+    /// The number of characters that a user can have across all of their worlds
+    pub characters: u64,
+    /// The total size, in bytes, of the files that a user can attach
+    pub attachment_bytes: u64,
+}
+
+impl Default for QuotaLimits {
+    fn default() -> Self {
+        Self {
+            worlds: 100,
+            tables: 1000,
+            campaigns: 100,
+            characters: 1000,
+            attachment_bytes: 100 * 1024 * 1024,
+        }
+    }
+}
+
+impl QuotaLimits {
+    /// Get the limit for a single resource
+    ///
+    /// # Parameters
+    /// - `resource` - The resource to get the limit for
+    ///
+    /// # Returns
+    /// The limit
+    pub fn get(&self, resource: QuotaResource) -> u64 {
+        match resource {
+            QuotaResource::Worlds => self.worlds,
+            QuotaResource::Tables => self.tables,
+            QuotaResource::Campaigns => self.campaigns,
+            QuotaResource::Characters => self.characters,
+            QuotaResource::AttachmentBytes => self.attachment_bytes,
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use strum_macros::{Display, EnumString};
+
+/// The resources that each user has a quota of
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Display, EnumString)]
+#[strum(serialize_all = "snake_case")]
+pub enum QuotaResource {
+    /// The number of worlds that the user owns
+    Worlds,
+    /// The number of random tables in worlds that the user owns
+    Tables,
+    /// The number of campaigns in worlds that the user owns
+    Campaigns,
+    /// The number of characters in worlds that the user owns
+    Characters,
+    /// The total size, in bytes, of the files that the user has attached
+    AttachmentBytes,
+}
+
+impl QuotaResource {
+    /// Every resource that has a quota
+    pub const ALL: [QuotaResource; 5] = [
+        QuotaResource::Worlds,
+        QuotaResource::Tables,
+        QuotaResource::Campaigns,
+        QuotaResource::Characters,
+        QuotaResource::AttachmentBytes,
+    ];
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_round_trip() {
+        for resource in &QuotaResource::ALL {
+            assert_eq!(
+                QuotaResource::from_str(&resource.to_string()),
+                Ok(*resource)
+            );
+        }
+        assert_eq!(
+            QuotaResource::AttachmentBytes.to_string(),
+            "attachment_bytes"
+        );
+    }
+}
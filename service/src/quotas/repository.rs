@@ -0,0 +1,26 @@
+mod enforce;
+mod find;
+
+pub(crate) use enforce::enforce_quota;
+
+use crate::infrastructure::database::Database;
+
+/// Repository used to access Quota data from the database
+#[derive(Clone)]
+pub(super) struct QuotaRepository {
+    /// The database with which to access quota records
+    database: Database,
+}
+
+impl QuotaRepository {
+    /// Create a new Quota Repository to work with
+    ///
+    /// # Parameters
+    /// - `database` - The database connection to use
+    ///
+    /// # Returns
+    /// The Quota Repository to use
+    pub(super) fn new(database: Database) -> Self {
+        Self { database }
+    }
+}
@@ -0,0 +1,43 @@
+use super::find::usage_query;
+use crate::quotas::{QuotaAllowance, QuotaExceededError};
+
+/// Enforce a quota allowance within the transaction that is about to use the resource.
+///
+/// The row of the user is locked for the rest of the transaction, so any other transaction enforcing a
+/// quota for the same user waits until this one has finished and then sees what it used. Usage is then
+/// checked again under that lock, so that concurrent requests can never take the user over their quota.
+///
+/// # Parameters
+/// - `transaction` - The transaction that will use the resource
+/// - `allowance` - The allowance that was granted when the quota was checked
+///
+/// # Errors
+/// If using this much more would take the user over their quota
+pub(crate) fn enforce_quota(
+    transaction: &mut postgres::Transaction<'_>,
+    allowance: &QuotaAllowance,
+) -> Result<(), QuotaExceededError> {
+    transaction
+        .execute(
+            "SELECT 1 FROM users WHERE user_id = $1 FOR NO KEY UPDATE",
+            &[&allowance.user],
+        )
+        .expect("Failed to lock user for quota");
+
+    let used: i64 = transaction
+        .query_one(usage_query(allowance.resource), &[&allowance.user])
+        .map(|row| row.get("used"))
+        .expect("Failed to find quota usage");
+    let used = used as u64;
+
+    if used + allowance.amount > allowance.limit {
+        tracing::warn!(allowance = ?allowance, used = used, "Quota exceeded");
+        Err(QuotaExceededError {
+            resource: allowance.resource,
+            used,
+            limit: allowance.limit,
+        })
+    } else {
+        Ok(())
+    }
+}
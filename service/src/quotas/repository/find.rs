@@ -0,0 +1,100 @@
+use super::QuotaRepository;
+use crate::{quotas::QuotaResource, users::UserID, worlds::WorldID};
+use std::{collections::HashMap, str::FromStr};
+
+/// Get the query that finds how much of a resource a user is using. The query takes the ID of the user
+/// as its only parameter, and returns the usage in the `used` column
+///
+/// # Parameters
+/// - `resource` - The resource to find the usage of
+///
+/// # Returns
+/// The SQL for the query
+pub(super) fn usage_query(resource: QuotaResource) -> &'static str {
+    match resource {
+        QuotaResource::Worlds => "SELECT COUNT(*)::BIGINT AS used FROM worlds WHERE owner_id = $1",
+        QuotaResource::Tables => "SELECT COUNT(*)::BIGINT AS used FROM random_tables t JOIN worlds w ON t.world_id = w.world_id WHERE w.owner_id = $1",
+        QuotaResource::Campaigns => "SELECT COUNT(*)::BIGINT AS used FROM campaigns c JOIN worlds w ON c.world_id = w.world_id WHERE w.owner_id = $1",
+        QuotaResource::Characters => "SELECT COUNT(*)::BIGINT AS used FROM characters c JOIN worlds w ON c.world_id = w.world_id WHERE w.owner_id = $1",
+        QuotaResource::AttachmentBytes => "SELECT COALESCE(SUM(size), 0)::BIGINT AS used FROM attachments WHERE owner_id = $1",
+    }
+}
+
+impl QuotaRepository {
+    /// Find how much of a resource a user is currently using
+    ///
+    /// # Parameters
+    /// - `user` - The ID of the user
+    /// - `resource` - The resource to check
+    ///
+    /// # Returns
+    /// How much of the resource the user is using
+    #[tracing::instrument(skip(self))]
+    pub fn find_usage(&self, user: &UserID, resource: QuotaResource) -> u64 {
+        let mut connection = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+
+        let used: i64 = connection
+            .query_one(usage_query(resource), &[user])
+            .map(|row| row.get("used"))
+            .expect("Failed to find quota usage");
+        used as u64
+    }
+
+    /// Find the quotas that have been set specifically for a user, instead of the defaults
+    ///
+    /// # Parameters
+    /// - `user` - The ID of the user
+    ///
+    /// # Returns
+    /// The quotas of each resource that has been overridden
+    #[tracing::instrument(skip(self))]
+    pub fn find_overrides(&self, user: &UserID) -> HashMap<QuotaResource, u64> {
+        let mut connection = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+
+        connection
+            .query(
+                "SELECT resource, quota FROM user_quotas WHERE user_id = $1",
+                &[user],
+            )
+            .expect("Failed to find quota overrides")
+            .iter()
+            .filter_map(|row| {
+                let resource: String = row.get("resource");
+                let quota: i64 = row.get("quota");
+                match QuotaResource::from_str(&resource) {
+                    Ok(resource) => Some((resource, quota.max(0) as u64)),
+                    Err(_) => {
+                        tracing::warn!(resource = ?resource, "Unknown quota resource");
+                        None
+                    }
+                }
+            })
+            .collect()
+    }
+
+    /// Find the owner of a world, who is the user whose quota content in the world counts towards
+    ///
+    /// # Parameters
+    /// - `world` - The ID of the world
+    ///
+    /// # Returns
+    /// The ID of the owner, or `None` if the world doesn't exist
+    #[tracing::instrument(skip(self))]
+    pub fn find_world_owner(&self, world: &WorldID) -> Option<UserID> {
+        let mut connection = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+
+        connection
+            .query_opt("SELECT owner_id FROM worlds WHERE world_id = $1", &[world])
+            .expect("Failed to find world owner")
+            .map(|row| row.get("owner_id"))
+    }
+}
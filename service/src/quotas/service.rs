@@ -0,0 +1,26 @@
+mod check;
+mod usage;
+
+use super::{repository::QuotaRepository, QuotaLimits};
+pub use check::QuotaExceededError;
+
+/// Service Layer for dealing with Quotas
+#[derive(Clone)]
+pub struct QuotasService {
+    repository: QuotaRepository,
+    /// The quotas that users have unless they have been given an override
+    defaults: QuotaLimits,
+}
+
+impl QuotasService {
+    /// Create a new instance of the Quotas Service
+    ///
+    /// # Returns
+    /// The Quotas Service ready to use
+    pub(super) fn new(repository: QuotaRepository, defaults: QuotaLimits) -> Self {
+        Self {
+            repository,
+            defaults,
+        }
+    }
+}
@@ -0,0 +1,87 @@
+use super::QuotasService;
+use crate::{
+    quotas::{QuotaAllowance, QuotaResource},
+    users::UserID,
+    worlds::WorldID,
+};
+
+/// Error indicating that an action would take a user over their quota
+#[derive(Debug, PartialEq, thiserror::Error)]
+#[error("The quota for {resource} has been exceeded")]
+pub struct QuotaExceededError {
+    /// The resource that the quota is for
+    pub resource: QuotaResource,
+    /// How much of the resource the user has already used
+    pub used: u64,
+    /// How much of the resource the user is allowed
+    pub limit: u64,
+}
+
+impl QuotasService {
+    /// Check that a user can use some more of a resource without going over their quota.
+    ///
+    /// The allowance that this grants must still be enforced when the resource is actually used
+    ///
+    /// # Parameters
+    /// - `user` - The ID of the user
+    /// - `resource` - The resource to check
+    /// - `amount` - How much more of the resource is going to be used
+    ///
+    /// # Returns
+    /// The allowance to enforce when using the resource
+    ///
+    /// # Errors
+    /// If using this much more would take the user over their quota
+    pub fn check_quota(
+        &self,
+        user: &UserID,
+        resource: QuotaResource,
+        amount: u64,
+    ) -> Result<QuotaAllowance, QuotaExceededError> {
+        let quota = self.find_quota(user, resource);
+
+        if quota.used + amount > quota.limit {
+            tracing::warn!(user = ?user, quota = ?quota, amount = amount, "Quota exceeded");
+            Err(QuotaExceededError {
+                resource,
+                used: quota.used,
+                limit: quota.limit,
+            })
+        } else {
+            Ok(QuotaAllowance {
+                user: user.clone(),
+                resource,
+                amount,
+                limit: quota.limit,
+            })
+        }
+    }
+
+    /// Check that some more of a resource can be used within a world, without the owner of the world
+    /// going over their quota.
+    ///
+    /// If the world doesn't exist then there is no quota to exceed, and creating content in it will
+    /// fail for that reason instead
+    ///
+    /// # Parameters
+    /// - `world` - The ID of the world
+    /// - `resource` - The resource to check
+    /// - `amount` - How much more of the resource is going to be used
+    ///
+    /// # Returns
+    /// The allowance to enforce when using the resource, or `None` if the world doesn't exist
+    ///
+    /// # Errors
+    /// If using this much more would take the owner of the world over their quota
+    pub fn check_world_quota(
+        &self,
+        world: &WorldID,
+        resource: QuotaResource,
+        amount: u64,
+    ) -> Result<Option<QuotaAllowance>, QuotaExceededError> {
+        match self.repository.find_world_owner(world) {
+            Some(owner) => self.check_quota(&owner, resource, amount).map(Some),
+            None => Ok(None),
+        }
+    }
+}
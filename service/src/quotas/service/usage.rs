@@ -0,0 +1,52 @@
+use super::QuotasService;
+use crate::{
+    quotas::{Quota, QuotaResource},
+    users::UserID,
+};
+
+impl QuotasService {
+    /// Get how much of a resource a user is using, and how much they are allowed
+    ///
+    /// # Parameters
+    /// - `user` - The ID of the user
+    /// - `resource` - The resource to check
+    ///
+    /// # Returns
+    /// The quota of the resource
+    pub fn find_quota(&self, user: &UserID, resource: QuotaResource) -> Quota {
+        let limit = self
+            .repository
+            .find_overrides(user)
+            .remove(&resource)
+            .unwrap_or_else(|| self.defaults.get(resource));
+
+        Quota {
+            resource,
+            used: self.repository.find_usage(user, resource),
+            limit,
+        }
+    }
+
+    /// Get how much of every resource a user is using, and how much they are allowed
+    ///
+    /// # Parameters
+    /// - `user` - The ID of the user
+    ///
+    /// # Returns
+    /// The quota of every resource
+    pub fn find_quotas(&self, user: &UserID) -> Vec<Quota> {
+        let overrides = self.repository.find_overrides(user);
+
+        QuotaResource::ALL
+            .iter()
+            .map(|&resource| Quota {
+                resource,
+                used: self.repository.find_usage(user, resource),
+                limit: overrides
+                    .get(&resource)
+                    .copied()
+                    .unwrap_or_else(|| self.defaults.get(resource)),
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,9 @@
+pub(crate) mod configure;
+pub(crate) mod endpoints;
+mod model;
+mod repository;
+mod service;
+
+pub use model::*;
+pub(crate) use repository::{insert_table, SaveTableError};
+pub use service::*;
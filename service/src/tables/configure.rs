@@ -0,0 +1,64 @@
+use super::{repository::TableRepository, TablesService};
+use crate::{
+    activity::ActivityService, infrastructure::database::Database, quotas::QuotasService,
+    webhooks::WebhooksService,
+};
+use std::sync::Arc;
+
+/// Application Configuration for the Random Tables module
+pub struct TablesConfig {
+    pub tables_service: TablesService,
+}
+
+impl TablesConfig {
+    /// Create the Configuration for the Random Tables Module
+    ///
+    /// # Parameters
+    /// - `database` - The database connection to use
+    /// - `activity_service` - The activity service to record activity with
+    /// - `webhooks_service` - The webhooks service to dispatch events with
+    /// - `quotas_service` - The quotas service to limit how many random tables users can have
+    ///
+    /// # Returns
+    /// The Random Tables Config object
+    pub fn new(
+        database: Database,
+        activity_service: ActivityService,
+        webhooks_service: WebhooksService,
+        quotas_service: QuotasService,
+    ) -> Self {
+        let repository = TableRepository::new(database);
+        Self {
+            tables_service: TablesService::new(
+                repository,
+                activity_service,
+                webhooks_service,
+                quotas_service,
+            ),
+        }
+    }
+
+    /// Generate the configuration callback needed for the HTTP Server to actually add the Random Tables
+    /// endpoints to the server
+    ///
+    /// # Returns
+    /// The callback to provide to the HTTP Server to configure up the Random Tables endpoints
+    pub fn configure(&self) -> Arc<dyn Fn(rocket::Rocket) -> rocket::Rocket + Send + Sync> {
+        let tables_service = self.tables_service.clone();
+        Arc::new(move |config| {
+            config.manage(tables_service.clone()).mount(
+                "/",
+                rocket::routes![
+                    super::endpoints::create_table,
+                    super::endpoints::delete_table,
+                    super::endpoints::export_tables_csv,
+                    super::endpoints::get_table,
+                    super::endpoints::import_tables_csv,
+                    super::endpoints::list_tables,
+                    super::endpoints::roll_table,
+                    super::endpoints::update_table
+                ],
+            )
+        })
+    }
+}
@@ -0,0 +1,19 @@
+mod create_table;
+mod delete_table;
+mod errors;
+mod export_tables_csv;
+mod get_table;
+mod import_tables_csv;
+mod list_tables;
+pub(crate) mod model;
+mod roll_table;
+mod update_table;
+
+pub use create_table::*;
+pub use delete_table::*;
+pub use export_tables_csv::*;
+pub use get_table::*;
+pub use import_tables_csv::*;
+pub use list_tables::*;
+pub use roll_table::*;
+pub use update_table::*;
@@ -0,0 +1,66 @@
+use super::{
+    errors::TableProblemType,
+    model::{TableRequest, TableResponse},
+};
+use crate::{
+    authorization::Authorizer,
+    http::problem::Problem,
+    tables::{CreateTableError, TablesService},
+    worlds::{
+        endpoints::{errors::WorldProblemType, world_owner::authorize_world_editor},
+        WorldID, WorldsService,
+    },
+};
+use rocket::{http::Status, post, State};
+use rocket_contrib::json::Json;
+
+/// Handler to create a new Random Table in a World
+///
+/// # Parameters
+/// - `tables_service` - The tables service to use
+/// - `worlds_service` - The worlds service to use
+/// - `world` - The ID of the world to create the table in
+/// - `body` - The details of the table to create
+/// - `authorizer` - The authorizer to prove we're allowed to create the table
+///
+/// # Returns
+/// The newly created table details, or a Problem if the creation failed
+#[tracing::instrument(
+    name = "POST /worlds/{id}/tables",
+    skip(tables_service, worlds_service)
+)]
+#[post("/worlds/<world>/tables", data = "<body>")]
+pub fn create_table(
+    tables_service: State<TablesService>,
+    worlds_service: State<WorldsService>,
+    world: WorldID,
+    body: Json<TableRequest>,
+    authorizer: Authorizer,
+) -> Result<TableResponse, Problem> {
+    authorize_world_editor(&worlds_service, &authorizer, &world)?;
+
+    let table = body.build(world)?;
+    tracing::debug!(table = ?table, "Creating new table");
+
+    let new_table = tables_service.create_table(table)?;
+    Ok(TableResponse(new_table))
+}
+
+impl From<CreateTableError> for Problem {
+    fn from(e: CreateTableError) -> Self {
+        match e {
+            CreateTableError::DuplicateUrlSlug => Problem::new(
+                TableProblemType::DuplicateUrlSlug,
+                Status::UnprocessableEntity,
+            ),
+            CreateTableError::UnknownWorld => {
+                Problem::new(WorldProblemType::UnknownWorldID, Status::NotFound)
+            }
+            CreateTableError::QuotaExceeded(e) => e.into(),
+            _ => {
+                tracing::warn!(error = ?e, "An unexpected error occurred");
+                Problem::new(TableProblemType::UnknownError, Status::InternalServerError)
+            }
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use super::errors::TableProblemType;
+use crate::{
+    authorization::Authorizer,
+    http::problem::Problem,
+    tables::TablesService,
+    worlds::{endpoints::world_owner::authorize_world_editor, UrlSlug, WorldID, WorldsService},
+};
+use rocket::{delete, http::Status, Response, State};
+
+/// Handler to delete an existing Random Table
+///
+/// # Parameters
+/// - `tables_service` - The tables service to use
+/// - `worlds_service` - The worlds service to use
+/// - `world` - The ID of the world that the table belongs to
+/// - `table` - The URL Slug of the table to delete
+/// - `authorizer` - The authorizer to prove we're allowed to delete the table
+///
+/// # Returns
+/// An empty response if the table was deleted, or a Problem if the deletion failed
+#[tracing::instrument(
+    name = "DELETE /worlds/{id}/tables/{table}",
+    skip(tables_service, worlds_service)
+)]
+#[delete("/worlds/<world>/tables/<table>")]
+pub fn delete_table(
+    tables_service: State<TablesService>,
+    worlds_service: State<WorldsService>,
+    world: WorldID,
+    table: UrlSlug,
+    authorizer: Authorizer,
+) -> Result<Response<'static>, Problem> {
+    authorize_world_editor(&worlds_service, &authorizer, &world)?;
+
+    let existing = tables_service
+        .find_table_by_url_slug(&world, &table)
+        .ok_or_else(|| Problem::new(TableProblemType::UnknownTable, Status::NotFound))?;
+
+    if tables_service.delete_table(&existing.identity.id) {
+        Ok(Response::build().status(Status::NoContent).finalize())
+    } else {
+        Err(Problem::new(
+            TableProblemType::UnknownTable,
+            Status::NotFound,
+        ))
+    }
+}
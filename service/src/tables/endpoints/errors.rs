@@ -0,0 +1,101 @@
+use crate::http::problem::{ProblemType, ValidationType};
+
+/// Problem Types that can happen when working with random tables
+#[derive(Debug, thiserror::Error)]
+pub enum TableProblemType {
+    /// An unknown error occurred
+    #[error("An unknown error occurred")]
+    UnknownError,
+
+    /// The table that was looked up was not found
+    #[error("The requested table was unknown")]
+    UnknownTable,
+
+    /// The URL Slug is already used by another table in the same world
+    #[error("The URL Slug was already present for this world")]
+    DuplicateUrlSlug,
+
+    /// A table that was referred to by an entry was not found
+    #[error("A table referenced by the roll was unknown")]
+    UnknownReferencedTable,
+
+    /// A table that was rolled on had no entries
+    #[error("A table referenced by the roll had no entries")]
+    EmptyTable,
+
+    /// An entry that was selected could not be evaluated
+    #[error("A table entry selected by the roll was invalid")]
+    InvalidEntry,
+
+    /// The tables were nested too deeply to evaluate
+    #[error("The tables referenced by the roll were nested too deeply")]
+    RecursionLimit,
+
+    /// The roll referenced too many tables in total to evaluate
+    #[error("The roll referenced too many tables")]
+    RollLimit,
+}
+
+impl ProblemType for TableProblemType {
+    /// Generate a Type value for the `ProblemType` values.
+    ///
+    /// These are used in the `type` field in the RFC-7807 Problem Response
+    fn error_code(&self) -> &'static str {
+        match self {
+            TableProblemType::UnknownError => "tag:multiverse,2020:tables/problems/unknown_error",
+            TableProblemType::UnknownTable => "tag:multiverse,2020:tables/problems/unknown_table",
+            TableProblemType::DuplicateUrlSlug => {
+                "tag:multiverse,2020:tables/problems/duplicate_url_slug"
+            }
+            TableProblemType::UnknownReferencedTable => {
+                "tag:multiverse,2020:tables/problems/unknown_referenced_table"
+            }
+            TableProblemType::EmptyTable => "tag:multiverse,2020:tables/problems/empty_table",
+            TableProblemType::InvalidEntry => "tag:multiverse,2020:tables/problems/invalid_entry",
+            TableProblemType::RecursionLimit => {
+                "tag:multiverse,2020:tables/problems/recursion_limit"
+            }
+            TableProblemType::RollLimit => "tag:multiverse,2020:tables/problems/roll_limit",
+        }
+    }
+}
+
+/// Validation errors that are specific to random tables
+#[derive(Debug, thiserror::Error)]
+pub enum TableValidation {
+    /// The weight of an entry was not a positive number
+    #[error("The weight must be a positive number")]
+    InvalidWeight,
+
+    /// The weight of an entry was larger than is allowed
+    #[error("The weight was too large")]
+    WeightTooLarge,
+
+    /// The text of an entry contained an invalid inline expression
+    #[error("The text contained an invalid expression")]
+    InvalidExpression,
+
+    /// The URL Slug is already used by another table in the same world
+    #[error("The URL Slug was already present for this world")]
+    DuplicateUrlSlug,
+}
+
+impl ValidationType for TableValidation {
+    /// Generate a Type value for the `ValidationType` values.
+    fn error_code(&self) -> &'static str {
+        match self {
+            TableValidation::InvalidWeight => {
+                "tag:multiverse,2020:tables/problems/validation_error/invalid_weight"
+            }
+            TableValidation::WeightTooLarge => {
+                "tag:multiverse,2020:tables/problems/validation_error/weight_too_large"
+            }
+            TableValidation::InvalidExpression => {
+                "tag:multiverse,2020:tables/problems/validation_error/invalid_expression"
+            }
+            TableValidation::DuplicateUrlSlug => {
+                "tag:multiverse,2020:tables/problems/validation_error/duplicate_url_slug"
+            }
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use super::model::TableCsvRecord;
+use crate::{
+    authorization::Authorizer,
+    http::{
+        csv::{write_csv, CsvResponse},
+        problem::Problem,
+    },
+    model::fetch_all_pages,
+    tables::TablesService,
+    worlds::{
+        endpoints::world_owner::{authorize_world_viewer, is_world_editor},
+        WorldID, WorldsService,
+    },
+};
+use rocket::{get, State};
+
+/// Handler to download every Random Table in a World as a CSV file
+///
+/// # Parameters
+/// - `tables_service` - The tables service to use
+/// - `worlds_service` - The worlds service to use
+/// - `world` - The ID of the world to list the tables of
+/// - `authorizer` - The authorizer to identify the current user. Only editors can see unpublished tables
+///
+/// # Returns
+/// The CSV file of the tables in the world
+#[tracing::instrument(
+    name = "GET /worlds/{id}/tables.csv",
+    skip(tables_service, worlds_service)
+)]
+#[get("/worlds/<world>/tables.csv")]
+pub fn export_tables_csv(
+    tables_service: State<TablesService>,
+    worlds_service: State<WorldsService>,
+    world: WorldID,
+    authorizer: Authorizer,
+) -> Result<CsvResponse, Problem> {
+    let world = authorize_world_viewer(&worlds_service, &authorizer, &world)?;
+    let editor = is_world_editor(&worlds_service, &authorizer, &world);
+
+    let records: Vec<TableCsvRecord> = fetch_all_pages(|pagination| {
+        tables_service.list_tables(&world.identity.id, editor, pagination)
+    })
+    .iter()
+    .map(TableCsvRecord::from)
+    .collect();
+    tracing::debug!(tables = records.len(), "Exporting tables");
+
+    Ok(CsvResponse {
+        filename: format!("{}-tables.csv", world.data.url_slug),
+        body: write_csv(&records),
+    })
+}
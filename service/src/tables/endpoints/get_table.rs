@@ -0,0 +1,45 @@
+use super::{errors::TableProblemType, model::TableResponse};
+use crate::{
+    authorization::Authorizer,
+    http::problem::Problem,
+    tables::TablesService,
+    worlds::{
+        endpoints::world_owner::{authorize_world_viewer, is_world_editor},
+        UrlSlug, WorldID, WorldsService,
+    },
+};
+use rocket::{get, http::Status, State};
+
+/// Handler to get an existing Random Table by URL Slug
+///
+/// # Parameters
+/// - `tables_service` - The tables service to use
+/// - `worlds_service` - The worlds service to use
+/// - `world` - The ID of the world that the table belongs to
+/// - `table` - The URL Slug of the table
+/// - `authorizer` - The authorizer to identify the current user. Only editors can see unpublished tables
+///
+/// # Returns
+/// The details of the table
+#[tracing::instrument(
+    name = "GET /worlds/{id}/tables/{table}",
+    skip(tables_service, worlds_service)
+)]
+#[get("/worlds/<world>/tables/<table>")]
+pub fn get_table(
+    tables_service: State<TablesService>,
+    worlds_service: State<WorldsService>,
+    world: WorldID,
+    table: UrlSlug,
+    authorizer: Authorizer,
+) -> Result<TableResponse, Problem> {
+    let world = authorize_world_viewer(&worlds_service, &authorizer, &world)?;
+    let editor = is_world_editor(&worlds_service, &authorizer, &world);
+
+    tracing::debug!("Looking up table");
+    tables_service
+        .find_table_by_url_slug(&world.identity.id, &table)
+        .filter(|table| editor || table.data.publication.is_visible())
+        .ok_or_else(|| Problem::new(TableProblemType::UnknownTable, Status::NotFound))
+        .map(TableResponse)
+}
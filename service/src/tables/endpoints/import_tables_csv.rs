@@ -0,0 +1,108 @@
+use super::{
+    errors::TableValidation,
+    model::{TableCsvRecord, TableLink, TableRequest},
+};
+use crate::{
+    authorization::Authorizer,
+    http::{
+        csv::{invalid_rows_problem, read_csv, CsvImportResponse, CsvRowReport, CsvRowStatus},
+        problem::{Problem, ValidationProblem},
+    },
+    tables::{TableData, TablesService},
+    worlds::{endpoints::world_owner::authorize_world_editor, UrlSlug, WorldID, WorldsService},
+};
+use rocket::{post, Data, State};
+
+/// Handler to create Random Tables in a World from the rows of a CSV file.
+///
+/// Every row is validated in the same way as creating a single table, and nothing is created unless
+/// every row is valid. The tables are all created together, so if any of them can't be, including
+/// because they would take the owner of the world over their quota, then none of them are. In a dry run nothing is created at all, and the validation of every row is
+/// reported back instead
+///
+/// # Parameters
+/// - `tables_service` - The tables service to use
+/// - `worlds_service` - The worlds service to use
+/// - `world` - The ID of the world to create the tables in
+/// - `dry_run` - Whether to only validate the file, without creating anything
+/// - `body` - The CSV file, in the same format as is downloaded from `GET /worlds/{id}/tables.csv`
+/// - `authorizer` - The authorizer to prove we're allowed to create tables
+///
+/// # Returns
+/// The report of every row in the file, or a Problem if the import failed
+#[tracing::instrument(
+    name = "POST /worlds/{id}/tables.csv",
+    skip(tables_service, worlds_service, body)
+)]
+#[post("/worlds/<world>/tables.csv?<dry_run>", data = "<body>")]
+pub fn import_tables_csv(
+    tables_service: State<TablesService>,
+    worlds_service: State<WorldsService>,
+    world: WorldID,
+    dry_run: Option<bool>,
+    body: Data,
+    authorizer: Authorizer,
+) -> Result<CsvImportResponse, Problem> {
+    authorize_world_editor(&worlds_service, &authorizer, &world)?;
+    let dry_run = dry_run.unwrap_or(false);
+
+    let records: Vec<(u64, TableCsvRecord)> = read_csv(body)?;
+
+    let mut url_slugs: Vec<UrlSlug> = vec![];
+    let mut tables = vec![];
+    let mut rows = vec![];
+    for (line, record) in records {
+        let table = TableRequest::from(record)
+            .build(world.clone())
+            .and_then(|table| {
+                if url_slugs.contains(&table.url_slug)
+                    || tables_service
+                        .find_table_by_url_slug(&world, &table.url_slug)
+                        .is_some()
+                {
+                    let mut problem = ValidationProblem::new();
+                    problem.with_field_error("url_slug", TableValidation::DuplicateUrlSlug);
+                    return Err(problem.build());
+                }
+                Ok(table)
+            });
+
+        match table {
+            Ok(table) => {
+                url_slugs.push(table.url_slug.clone());
+                tables.push((line, table));
+                rows.push(CsvRowReport {
+                    line,
+                    status: CsvRowStatus::Valid,
+                });
+            }
+            Err(mut problem) => rows.push(CsvRowReport {
+                line,
+                status: CsvRowStatus::Invalid(problem.extra.remove("fields").unwrap_or_default()),
+            }),
+        }
+    }
+
+    if dry_run {
+        return Ok(CsvImportResponse { dry_run, rows });
+    }
+    if tables.len() < rows.len() {
+        tracing::warn!("Validation error importing tables");
+        return Err(invalid_rows_problem(&rows));
+    }
+
+    let (lines, tables): (Vec<u64>, Vec<TableData>) = tables.into_iter().unzip();
+    let rows = tables_service
+        .create_tables(&world, tables)?
+        .into_iter()
+        .zip(lines)
+        .map(|(new_table, line)| CsvRowReport {
+            line,
+            status: CsvRowStatus::Created(
+                TableLink::new(new_table.data.world, new_table.data.url_slug).into(),
+            ),
+        })
+        .collect();
+
+    Ok(CsvImportResponse { dry_run, rows })
+}
@@ -0,0 +1,40 @@
+use super::model::TablesResponse;
+use crate::{
+    authorization::Authorizer,
+    http::{pagination::PaginationRequest, problem::Problem},
+    tables::TablesService,
+    worlds::{
+        endpoints::world_owner::{authorize_world_viewer, is_world_editor},
+        WorldID, WorldsService,
+    },
+};
+use rocket::{get, State};
+
+/// Handler to list the Random Tables in a World
+///
+/// # Parameters
+/// - `tables_service` - The tables service to use
+/// - `worlds_service` - The worlds service to use
+/// - `world` - The ID of the world to list the tables of
+/// - `pagination` - The pagination controls to apply
+/// - `authorizer` - The authorizer to identify the current user. Only editors can see unpublished tables
+///
+/// # Returns
+/// The details of the tables in the world
+#[tracing::instrument(name = "GET /worlds/{id}/tables", skip(tables_service, worlds_service))]
+#[get("/worlds/<world>/tables?<pagination..>")]
+pub fn list_tables(
+    tables_service: State<TablesService>,
+    worlds_service: State<WorldsService>,
+    world: WorldID,
+    pagination: PaginationRequest,
+    authorizer: Authorizer,
+) -> Result<TablesResponse, Problem> {
+    let world = authorize_world_viewer(&worlds_service, &authorizer, &world)?;
+    let editor = is_world_editor(&worlds_service, &authorizer, &world);
+
+    let tables = tables_service.list_tables(&world.identity.id, editor, &pagination);
+    tracing::debug!(tables = ?tables, "Found tables");
+
+    Ok(TablesResponse(tables))
+}
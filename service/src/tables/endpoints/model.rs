@@ -0,0 +1,13 @@
+mod roll_response;
+mod table_csv;
+mod table_link;
+mod table_request;
+mod table_response;
+mod tables_response;
+
+pub use roll_response::*;
+pub use table_csv::*;
+pub use table_link::*;
+pub use table_request::*;
+pub use table_response::*;
+pub use tables_response::*;
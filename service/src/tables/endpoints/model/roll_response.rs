@@ -0,0 +1,82 @@
+use super::TableLink;
+use crate::{
+    http::link::{Link, LinkRel, Links},
+    tables::{DiceRoll, RollStep, TableRoll, TableRollStep},
+    worlds::WorldID,
+};
+use rocket::{
+    http::hyper::header::{CacheControl, CacheDirective},
+    response, Request,
+};
+use rocket_contrib::json::Json;
+use serde_json::{json, Value};
+
+/// API Model representing the outcome of rolling on a Random Table
+#[derive(Debug)]
+pub struct RollResponse(pub WorldID, pub TableRoll);
+
+/// Generate the JSON for a single step of a roll on a table
+fn table_step_to_json(step: &TableRollStep) -> Value {
+    let steps: Vec<Value> = step.steps.iter().map(step_to_json).collect();
+
+    json!({
+      "type": "table",
+      "table": step.table,
+      "name": step.name,
+      "roll": step.roll,
+      "total_weight": step.total_weight,
+      "entry": step.entry,
+      "result": step.result,
+      "steps": steps
+    })
+}
+
+/// Generate the JSON for a single roll of some dice
+fn dice_to_json(dice: &DiceRoll) -> Value {
+    let terms: Vec<Value> = dice
+        .terms
+        .iter()
+        .map(|term| {
+            json!({
+              "term": term.term.to_string(),
+              "rolls": term.rolls,
+              "kept": term.kept,
+              "value": term.value
+            })
+        })
+        .collect();
+
+    json!({
+      "type": "dice",
+      "expression": dice.expression.to_string(),
+      "terms": terms,
+      "total": dice.total
+    })
+}
+
+/// Generate the JSON for any step of a roll
+fn step_to_json(step: &RollStep) -> Value {
+    match step {
+        RollStep::Table(table) => table_step_to_json(table),
+        RollStep::Dice(dice) => dice_to_json(dice),
+    }
+}
+
+impl<'r> response::Responder<'r> for RollResponse {
+    fn respond_to(self, req: &Request) -> response::Result<'r> {
+        let response_body = json!({
+          "seed": self.1.seed,
+          "result": self.1.result.result,
+          "trace": table_step_to_json(&self.1.result)
+        });
+
+        response::Response::build()
+            .merge(Json(response_body).respond_to(req).unwrap())
+            .header(CacheControl(vec![CacheDirective::NoStore]))
+            .header(Links(vec![Link::new(
+                TableLink::new(self.0, self.1.result.table.clone()),
+                LinkRel::RELATED,
+            )]))
+            .ok()
+    }
+}
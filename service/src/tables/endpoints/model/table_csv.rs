@@ -0,0 +1,112 @@
+use crate::tables::{TableEntry, TableModel};
+use chrono::SecondsFormat;
+use serde::{Deserialize, Serialize};
+
+/// API Model representing a single Random Table as a row of a CSV file
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct TableCsvRecord {
+    /// The name of the table
+    pub name: Option<String>,
+    /// The URL Slug of the table
+    pub url_slug: Option<String>,
+    /// The description of the table
+    pub description: Option<String>,
+    /// The publication status of the table
+    pub status: Option<String>,
+    /// When the table is to be published, if it is scheduled
+    pub publish_at: Option<String>,
+    /// The entries in the table, one per line, each written as `weight: text`. The weight may be omitted
+    pub entries: Option<String>,
+}
+
+impl From<&TableModel> for TableCsvRecord {
+    fn from(table: &TableModel) -> Self {
+        Self {
+            name: Some(table.data.name.clone()),
+            url_slug: Some(table.data.url_slug.to_string()),
+            description: Some(table.data.description.clone()),
+            status: Some(table.data.publication.status().to_string()),
+            publish_at: table
+                .data
+                .publication
+                .publish_at()
+                .map(|publish_at| publish_at.to_rfc3339_opts(SecondsFormat::Secs, true)),
+            entries: Some(format_entries(&table.data.entries)),
+        }
+    }
+}
+
+/// Format the entries of a table into the text of a single CSV cell
+fn format_entries(entries: &[TableEntry]) -> String {
+    entries
+        .iter()
+        .map(|entry| format!("{}: {}", entry.weight, entry.text))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// Parse the entries of a table out of the text of a single CSV cell
+///
+/// # Parameters
+/// - `entries` - The text of the cell
+///
+/// # Returns
+/// The weight, if one was given, and the text of each entry
+pub(super) fn parse_entries(entries: &str) -> Vec<(Option<i64>, String)> {
+    entries
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            let weight = line.find(':').and_then(|index| {
+                line[..index]
+                    .trim()
+                    .parse()
+                    .ok()
+                    .map(|weight| (index, weight))
+            });
+            match weight {
+                Some((index, weight)) => (Some(weight), line[index + 1..].trim().to_owned()),
+                None => (None, line.to_owned()),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_entries() {
+        assert_eq!(
+            format_entries(&[
+                TableEntry {
+                    weight: 2,
+                    text: "The Prancing Pony".to_owned()
+                },
+                TableEntry {
+                    weight: 1,
+                    text: "The {1d3} Broomsticks".to_owned()
+                },
+            ]),
+            "2: The Prancing Pony\n1: The {1d3} Broomsticks"
+        );
+    }
+
+    #[test]
+    fn test_parse_entries() {
+        assert_eq!(
+            parse_entries(
+                "2: The Prancing Pony\r\n\nThe Green Dragon\n0:Nothing\nNote: 3 Inns\n3:"
+            ),
+            vec![
+                (Some(2), "The Prancing Pony".to_owned()),
+                (None, "The Green Dragon".to_owned()),
+                (Some(0), "Nothing".to_owned()),
+                (None, "Note: 3 Inns".to_owned()),
+                (Some(3), "".to_owned()),
+            ]
+        );
+    }
+}
@@ -0,0 +1,16 @@
+use crate::worlds::{UrlSlug, WorldID};
+
+/// Representation of a link to a random table
+pub struct TableLink(WorldID, UrlSlug);
+
+impl TableLink {
+    pub fn new(world_id: WorldID, url_slug: UrlSlug) -> Self {
+        Self(world_id, url_slug)
+    }
+}
+
+impl From<TableLink> for String {
+    fn from(table_link: TableLink) -> String {
+        format!("/worlds/{}/tables/{}", table_link.0, table_link.1)
+    }
+}
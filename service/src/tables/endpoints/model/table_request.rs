@@ -0,0 +1,185 @@
+use super::{table_csv::parse_entries, TableCsvRecord};
+use crate::{
+    http::{
+        problem::{GenericValidation, Problem, ValidationProblem},
+        publication::PublicationRequest,
+    },
+    tables::{
+        endpoints::errors::TableValidation, TableData, TableEntry, Template, MAX_ENTRY_WEIGHT,
+    },
+    worlds::{UrlSlug, UrlSlugParseError, WorldID},
+};
+use serde::Deserialize;
+use std::convert::TryFrom;
+use str_slug::slug;
+
+/// Incoming details representing a random table to create or replace
+#[derive(Debug, Deserialize)]
+pub struct TableRequest {
+    /// The name of the table
+    name: Option<String>,
+    /// The description of the table. May be omitted
+    description: Option<String>,
+    /// The URL Slug of the table. If omitted then this is generated from the name
+    url_slug: Option<String>,
+    /// The entries in the table
+    #[serde(default)]
+    entries: Vec<TableEntryRequest>,
+    /// The publication state of the table. If omitted then the table is published immediately
+    #[serde(flatten)]
+    publication: PublicationRequest,
+}
+
+/// Incoming details representing a single entry in a random table
+#[derive(Debug, Deserialize)]
+pub struct TableEntryRequest {
+    /// The weight of the entry. If omitted then this defaults to 1
+    weight: Option<i64>,
+    /// The text of the entry
+    text: Option<String>,
+}
+
+impl From<TableCsvRecord> for TableRequest {
+    fn from(record: TableCsvRecord) -> Self {
+        let entries = record
+            .entries
+            .as_deref()
+            .map(parse_entries)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(weight, text)| TableEntryRequest {
+                weight,
+                text: Some(text),
+            })
+            .collect();
+
+        Self {
+            name: record.name,
+            description: record.description,
+            url_slug: record.url_slug,
+            entries,
+            publication: PublicationRequest::new(record.status, record.publish_at),
+        }
+    }
+}
+
+impl TableRequest {
+    /// Get the name to use for the table
+    fn name(&self) -> Option<String> {
+        self.name.clone().filter(|v| !v.trim().is_empty())
+    }
+
+    /// Get the description to use for the table
+    fn description(&self) -> Option<String> {
+        self.description.clone().filter(|v| !v.trim().is_empty())
+    }
+
+    /// Get the URL Slug to use for the table
+    /// If one wasn't provided then it will be generated from the name
+    fn url_slug(&self) -> Result<UrlSlug, UrlSlugParseError> {
+        self.url_slug
+            .clone()
+            .filter(|v| !v.trim().is_empty())
+            .unwrap_or_else(|| slug(self.name.clone().unwrap_or("".to_owned())))
+            .parse()
+    }
+
+    /// Validate the request and build the details of the table that it represents
+    ///
+    /// # Parameters
+    /// - `world` - The ID of the world that the table belongs to
+    ///
+    /// # Returns
+    /// The details of the table
+    ///
+    /// # Errors
+    /// A Validation Problem if the request was invalid in any way
+    pub fn build(&self, world: WorldID) -> Result<TableData, Problem> {
+        let mut problem = ValidationProblem::new();
+        let mut valid = true;
+
+        let name = self.name();
+        if name == None {
+            problem.with_field_error("name", GenericValidation::Missing);
+            valid = false;
+        }
+
+        let url_slug = self.url_slug();
+        if let Err(err) = &url_slug {
+            match err {
+                UrlSlugParseError::Blank => {
+                    problem.with_field_error("url_slug", GenericValidation::Missing)
+                }
+            };
+            valid = false;
+        }
+
+        let mut entries = vec![];
+        for (index, entry) in self.entries.iter().enumerate() {
+            let weight = u32::try_from(entry.weight.unwrap_or(1))
+                .ok()
+                .filter(|weight| *weight > 0);
+            match weight {
+                None => {
+                    problem.with_field_error(
+                        format!("entries/{}/weight", index),
+                        TableValidation::InvalidWeight,
+                    );
+                    valid = false;
+                }
+                Some(weight) if weight > MAX_ENTRY_WEIGHT => {
+                    problem.with_field_error(
+                        format!("entries/{}/weight", index),
+                        TableValidation::WeightTooLarge,
+                    );
+                    valid = false;
+                }
+                Some(_) => {}
+            }
+
+            let text = entry.text.clone().filter(|v| !v.trim().is_empty());
+            match &text {
+                None => {
+                    problem.with_field_error(
+                        format!("entries/{}/text", index),
+                        GenericValidation::Missing,
+                    );
+                    valid = false;
+                }
+                Some(text) => {
+                    if let Err(e) = text.parse::<Template>() {
+                        tracing::warn!(e = ?e, text = ?text, "Invalid table entry");
+                        problem.with_field_error(
+                            format!("entries/{}/text", index),
+                            TableValidation::InvalidExpression,
+                        );
+                        valid = false;
+                    }
+                }
+            }
+
+            if let (Some(weight), Some(text)) = (weight, text) {
+                entries.push(TableEntry { weight, text });
+            }
+        }
+
+        let publication = self.publication.build(&mut problem);
+        if publication.is_none() {
+            valid = false;
+        }
+
+        if !valid {
+            tracing::warn!("Validation error building table");
+            return Err(problem.build());
+        }
+
+        Ok(TableData {
+            world,
+            name: name.unwrap(),
+            description: self.description().unwrap_or_default(),
+            url_slug: url_slug.unwrap(),
+            entries,
+            publication: publication.unwrap(),
+        })
+    }
+}
@@ -0,0 +1,52 @@
+use super::TableLink;
+use crate::{
+    http::{
+        link::{Link, LinkRel, Links},
+        publication::add_publication_json,
+    },
+    tables::TableModel,
+    worlds::endpoints::model::WorldLink,
+};
+use rocket::{
+    http::hyper::header::{CacheControl, CacheDirective, ETag, EntityTag},
+    response, Request,
+};
+use rocket_contrib::json::Json;
+use serde_json::{json, Value};
+
+/// API Model representing a Random Table
+#[derive(Debug)]
+pub struct TableResponse(pub TableModel);
+
+pub(super) fn to_json(table: &TableModel) -> Value {
+    let mut value = json!({
+      "name": table.data.name,
+      "description": table.data.description,
+      "url_slug": table.data.url_slug,
+      "entries": table.data.entries
+    });
+    add_publication_json(&mut value, &table.data.publication);
+    value
+}
+
+impl<'r> response::Responder<'r> for TableResponse {
+    fn respond_to(self, req: &Request) -> response::Result<'r> {
+        let etag = self.0.identity.version.to_string();
+
+        response::Response::build()
+            .merge(Json(to_json(&self.0)).respond_to(req).unwrap())
+            .header(CacheControl(vec![
+                CacheDirective::Private,
+                CacheDirective::MaxAge(3600),
+            ]))
+            .header(ETag(EntityTag::new(false, etag)))
+            .header(Links(vec![
+                Link::new(
+                    TableLink::new(self.0.data.world.clone(), self.0.data.url_slug.clone()),
+                    LinkRel::SELF,
+                ),
+                Link::new(WorldLink::new(self.0.data.world), LinkRel::RELATED),
+            ]))
+            .ok()
+    }
+}
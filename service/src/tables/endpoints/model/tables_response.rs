@@ -0,0 +1,51 @@
+use super::{to_json, TableLink};
+use crate::{
+    http::link::{Link, LinkRel, Links},
+    model::Page,
+    tables::TableModel,
+};
+use rocket::{
+    http::hyper::header::{CacheControl, CacheDirective},
+    response, Request,
+};
+use rocket_contrib::json::Json;
+use serde_json::{json, Value};
+
+/// API Model representing a page of Random Tables
+#[derive(Debug)]
+pub struct TablesResponse(pub Page<TableModel>);
+
+impl<'r> response::Responder<'r> for TablesResponse {
+    fn respond_to(self, req: &Request) -> response::Result<'r> {
+        let tables: Vec<Value> = self.0.entries.iter().map(|table| to_json(&table)).collect();
+
+        let response_body = json!({
+          "entries": tables,
+          "pagination": {
+            "offset": self.0.offset,
+            "count": self.0.count,
+            "total": self.0.total
+          }
+        });
+
+        let table_links: Vec<Link> = self
+            .0
+            .entries
+            .iter()
+            .map(|table| TableLink::new(table.data.world.clone(), table.data.url_slug.clone()))
+            .enumerate()
+            .map(|(index, link)| {
+                Link::new(link, LinkRel::ITEM).anchor(format!("#/entries/{}", index))
+            })
+            .collect();
+
+        response::Response::build()
+            .merge(Json(response_body).respond_to(req).unwrap())
+            .header(CacheControl(vec![
+                CacheDirective::Private,
+                CacheDirective::MaxAge(3600),
+            ]))
+            .header(Links(table_links))
+            .ok()
+    }
+}
@@ -0,0 +1,72 @@
+use super::{errors::TableProblemType, model::RollResponse};
+use crate::{
+    authorization::Authorizer,
+    http::problem::Problem,
+    tables::{RollTableError, TablesService},
+    worlds::{
+        endpoints::world_owner::{authorize_world_viewer, is_world_editor},
+        UrlSlug, WorldID, WorldsService,
+    },
+};
+use rocket::{http::Status, post, State};
+
+/// Handler to roll on a Random Table
+///
+/// # Parameters
+/// - `tables_service` - The tables service to use
+/// - `worlds_service` - The worlds service to use
+/// - `world` - The ID of the world that the table belongs to
+/// - `table` - The URL Slug of the table to roll on
+/// - `seed` - The seed to use for the roll, so that it can be reproduced. Random if not provided
+/// - `authorizer` - The authorizer to identify the current user. Only editors can roll on unpublished
+///   tables
+///
+/// # Returns
+/// The outcome of the roll, including the full trace of every table and dice roll involved
+#[tracing::instrument(
+    name = "POST /worlds/{id}/tables/{table}/roll",
+    skip(tables_service, worlds_service)
+)]
+#[post("/worlds/<world>/tables/<table>/roll?<seed>")]
+pub fn roll_table(
+    tables_service: State<TablesService>,
+    worlds_service: State<WorldsService>,
+    world: WorldID,
+    table: UrlSlug,
+    seed: Option<u32>,
+    authorizer: Authorizer,
+) -> Result<RollResponse, Problem> {
+    let world_model = authorize_world_viewer(&worlds_service, &authorizer, &world)?;
+    let editor = is_world_editor(&worlds_service, &authorizer, &world_model);
+
+    let roll = tables_service
+        .roll_table(&world, &table, seed, editor)
+        .map_err(|e| match e {
+            RollTableError::UnknownTable(ref url_slug) if url_slug == &table => {
+                Problem::new(TableProblemType::UnknownTable, Status::NotFound)
+            }
+            RollTableError::UnknownTable(url_slug) => Problem::new(
+                TableProblemType::UnknownReferencedTable,
+                Status::UnprocessableEntity,
+            )
+            .with_extra("table", url_slug.to_string()),
+            RollTableError::EmptyTable(url_slug) => {
+                Problem::new(TableProblemType::EmptyTable, Status::UnprocessableEntity)
+                    .with_extra("table", url_slug.to_string())
+            }
+            RollTableError::InvalidEntry(url_slug, entry) => {
+                Problem::new(TableProblemType::InvalidEntry, Status::UnprocessableEntity)
+                    .with_extra("table", url_slug.to_string())
+                    .with_extra("entry", entry)
+            }
+            RollTableError::RecursionLimit => Problem::new(
+                TableProblemType::RecursionLimit,
+                Status::UnprocessableEntity,
+            ),
+            RollTableError::RollLimit => {
+                Problem::new(TableProblemType::RollLimit, Status::UnprocessableEntity)
+            }
+        })?;
+
+    Ok(RollResponse(world, roll))
+}
@@ -0,0 +1,68 @@
+use super::{
+    errors::TableProblemType,
+    model::{TableRequest, TableResponse},
+};
+use crate::{
+    authorization::Authorizer,
+    http::problem::Problem,
+    tables::{TablesService, UpdateTableError},
+    worlds::{endpoints::world_owner::authorize_world_editor, UrlSlug, WorldID, WorldsService},
+};
+use rocket::{http::Status, put, State};
+use rocket_contrib::json::Json;
+
+/// Handler to replace an existing Random Table
+///
+/// # Parameters
+/// - `tables_service` - The tables service to use
+/// - `worlds_service` - The worlds service to use
+/// - `world` - The ID of the world that the table belongs to
+/// - `table` - The URL Slug of the table to replace
+/// - `body` - The new details of the table
+/// - `authorizer` - The authorizer to prove we're allowed to change the table
+///
+/// # Returns
+/// The updated table details, or a Problem if the update failed
+#[tracing::instrument(
+    name = "PUT /worlds/{id}/tables/{table}",
+    skip(tables_service, worlds_service)
+)]
+#[put("/worlds/<world>/tables/<table>", data = "<body>")]
+pub fn update_table(
+    tables_service: State<TablesService>,
+    worlds_service: State<WorldsService>,
+    world: WorldID,
+    table: UrlSlug,
+    body: Json<TableRequest>,
+    authorizer: Authorizer,
+) -> Result<TableResponse, Problem> {
+    authorize_world_editor(&worlds_service, &authorizer, &world)?;
+
+    let existing = tables_service
+        .find_table_by_url_slug(&world, &table)
+        .ok_or_else(|| Problem::new(TableProblemType::UnknownTable, Status::NotFound))?;
+
+    let table = body.build(world)?;
+    tracing::debug!(table = ?table, "Updating table");
+
+    let updated = tables_service.update_table(&existing.identity.id, table)?;
+    Ok(TableResponse(updated))
+}
+
+impl From<UpdateTableError> for Problem {
+    fn from(e: UpdateTableError) -> Self {
+        match e {
+            UpdateTableError::DuplicateUrlSlug => Problem::new(
+                TableProblemType::DuplicateUrlSlug,
+                Status::UnprocessableEntity,
+            ),
+            UpdateTableError::UnknownTable => {
+                Problem::new(TableProblemType::UnknownTable, Status::NotFound)
+            }
+            _ => {
+                tracing::warn!(error = ?e, "An unexpected error occurred");
+                Problem::new(TableProblemType::UnknownError, Status::InternalServerError)
+            }
+        }
+    }
+}
@@ -0,0 +1,11 @@
+mod dice;
+mod roll;
+mod table;
+mod table_id;
+mod template;
+
+pub use dice::*;
+pub use roll::*;
+pub use table::*;
+pub use table_id::*;
+pub use template::*;
@@ -0,0 +1,558 @@
+use rand::Rng;
+use std::{
+    fmt::{Display, Formatter},
+    str::FromStr,
+};
+
+/// The maximum number of dice that can be rolled by a single term
+const MAX_DICE: u32 = 100;
+
+/// The maximum number of sides that a single die can have
+const MAX_SIDES: u32 = 1000;
+
+/// Which of the rolled dice to keep when totalling a term
+#[derive(Debug, PartialEq, Clone)]
+pub enum Keep {
+    /// Keep the highest N dice
+    Highest(u32),
+    /// Keep the lowest N dice
+    Lowest(u32),
+}
+
+/// A single term in a dice expression
+#[derive(Debug, PartialEq, Clone)]
+pub enum DiceTerm {
+    /// Roll a number of identical dice, optionally only keeping some of them
+    Dice {
+        /// The number of dice to roll
+        count: u32,
+        /// The number of sides on each die
+        sides: u32,
+        /// Which of the dice to keep, if not all of them
+        keep: Option<Keep>,
+    },
+    /// A constant modifier
+    Constant(u32),
+}
+
+/// A term in a dice expression, along with whether it is added or subtracted
+#[derive(Debug, PartialEq, Clone)]
+pub struct SignedTerm {
+    /// Whether the term is subtracted from the total
+    pub negative: bool,
+    /// The actual term
+    pub term: DiceTerm,
+}
+
+/// A parsed dice expression, such as `3d6+2` or `4d6kh3`
+#[derive(Debug, PartialEq, Clone)]
+pub struct DiceExpression(pub Vec<SignedTerm>);
+
+/// The result of rolling a single term of a dice expression
+#[derive(Debug, PartialEq, Clone)]
+pub struct TermRoll {
+    /// The term that was rolled
+    pub term: SignedTerm,
+    /// Every die that was rolled, in the order they were rolled. Empty for constants.
+    pub rolls: Vec<u32>,
+    /// The dice that were kept towards the total. Empty for constants.
+    pub kept: Vec<u32>,
+    /// The value that this term contributed to the total
+    pub value: i64,
+}
+
+/// The result of rolling an entire dice expression
+#[derive(Debug, PartialEq, Clone)]
+pub struct DiceRoll {
+    /// The expression that was rolled
+    pub expression: DiceExpression,
+    /// The results of the individual terms
+    pub terms: Vec<TermRoll>,
+    /// The total of the roll
+    pub total: i64,
+}
+
+/// Errors that can occur when parsing a dice expression
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum DiceParseError {
+    /// The dice expression was blank
+    #[error("The dice expression was blank")]
+    Blank,
+
+    /// An unexpected character was found
+    #[error("Unexpected character in dice expression at position {0}")]
+    UnexpectedCharacter(usize),
+
+    /// The expression ended before it was complete
+    #[error("Unexpected end of dice expression")]
+    UnexpectedEnd,
+
+    /// A number in the expression was too large
+    #[error("A number in the dice expression was too large")]
+    NumberTooLarge,
+
+    /// The number of dice to roll was invalid
+    #[error("The number of dice to roll was invalid")]
+    InvalidDiceCount,
+
+    /// The number of sides on the dice was invalid
+    #[error("The number of sides on the dice was invalid")]
+    InvalidSides,
+
+    /// The number of dice to keep was invalid
+    #[error("The number of dice to keep was invalid")]
+    InvalidKeep,
+}
+
+impl DiceExpression {
+    /// Roll the dice expression using the provided source of randomness
+    ///
+    /// # Parameters
+    /// - `rng` - The random number generator to roll the dice with
+    ///
+    /// # Returns
+    /// The result of the roll, including every individual die that was rolled
+    pub fn roll<R>(&self, rng: &mut R) -> DiceRoll
+    where
+        R: Rng,
+    {
+        let terms: Vec<TermRoll> = self.0.iter().map(|term| term.roll(rng)).collect();
+        let total = terms.iter().map(|term| term.value).sum();
+
+        DiceRoll {
+            expression: self.clone(),
+            terms,
+            total,
+        }
+    }
+}
+
+impl SignedTerm {
+    /// Roll a single term of the dice expression
+    ///
+    /// # Parameters
+    /// - `rng` - The random number generator to roll the dice with
+    ///
+    /// # Returns
+    /// The result of rolling this term
+    fn roll<R>(&self, rng: &mut R) -> TermRoll
+    where
+        R: Rng,
+    {
+        let (rolls, kept, value) = match &self.term {
+            DiceTerm::Constant(value) => (vec![], vec![], i64::from(*value)),
+            DiceTerm::Dice { count, sides, keep } => {
+                let rolls: Vec<u32> = (0..*count).map(|_| rng.gen_range(1, sides + 1)).collect();
+
+                let mut sorted = rolls.clone();
+                let kept: Vec<u32> = match keep {
+                    None => rolls.clone(),
+                    Some(Keep::Highest(n)) => {
+                        sorted.sort_unstable_by(|a, b| b.cmp(a));
+                        sorted.into_iter().take(*n as usize).collect()
+                    }
+                    Some(Keep::Lowest(n)) => {
+                        sorted.sort_unstable();
+                        sorted.into_iter().take(*n as usize).collect()
+                    }
+                };
+                let value = kept.iter().map(|v| i64::from(*v)).sum();
+
+                (rolls, kept, value)
+            }
+        };
+
+        TermRoll {
+            term: self.clone(),
+            rolls,
+            kept,
+            value: if self.negative { -value } else { value },
+        }
+    }
+}
+
+impl Display for DiceTerm {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DiceTerm::Constant(value) => write!(f, "{}", value),
+            DiceTerm::Dice { count, sides, keep } => {
+                write!(f, "{}d{}", count, sides)?;
+                match keep {
+                    Some(Keep::Highest(n)) => write!(f, "kh{}", n),
+                    Some(Keep::Lowest(n)) => write!(f, "kl{}", n),
+                    None => Ok(()),
+                }
+            }
+        }
+    }
+}
+
+impl Display for SignedTerm {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.negative {
+            write!(f, "-{}", self.term)
+        } else {
+            write!(f, "{}", self.term)
+        }
+    }
+}
+
+impl Display for DiceExpression {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (index, term) in self.0.iter().enumerate() {
+            if index > 0 && !term.negative {
+                write!(f, "+")?;
+            }
+            write!(f, "{}", term)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for DiceExpression {
+    type Err = DiceParseError;
+
+    /// Parse a string into a Dice Expression.
+    ///
+    /// The supported syntax is a sequence of terms separated by `+` or `-`, where each term is either
+    /// a constant or a dice roll of the form `NdS`, optionally followed by `khN` or `klN` to keep only the
+    /// highest or lowest N dice. `d%` is accepted as a shorthand for `d100`.
+    ///
+    /// # Parameters
+    /// - `s` - The input string to parse
+    ///
+    /// # Returns
+    /// The dice expression
+    ///
+    /// # Errors
+    /// Any errors that occurred parsing the expression
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let input: Vec<char> = s
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .flat_map(|c| c.to_lowercase())
+            .collect();
+
+        if input.is_empty() {
+            return Err(DiceParseError::Blank);
+        }
+
+        let result = DiceParser {
+            input: &input,
+            position: 0,
+        }
+        .parse_expression();
+
+        tracing::debug!(input = s, result = ?result, "Parse dice expression");
+        result
+    }
+}
+
+/// Simple recursive descent parser for dice expressions
+struct DiceParser<'a> {
+    /// The input characters, with all whitespace removed
+    input: &'a [char],
+    /// The current position in the input
+    position: usize,
+}
+
+impl<'a> DiceParser<'a> {
+    /// Look at the next character without consuming it
+    fn peek(&self) -> Option<char> {
+        self.input.get(self.position).cloned()
+    }
+
+    /// Consume the next character if it is the one provided
+    fn consume(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.position += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Build the error to return for the current position in the input
+    fn unexpected(&self) -> DiceParseError {
+        if self.position >= self.input.len() {
+            DiceParseError::UnexpectedEnd
+        } else {
+            DiceParseError::UnexpectedCharacter(self.position)
+        }
+    }
+
+    /// Parse an entire expression, consuming all of the input
+    fn parse_expression(&mut self) -> Result<DiceExpression, DiceParseError> {
+        let mut terms = vec![];
+        let mut negative = if self.consume('-') {
+            true
+        } else {
+            self.consume('+');
+            false
+        };
+
+        loop {
+            let term = self.parse_term()?;
+            terms.push(SignedTerm { negative, term });
+
+            if self.consume('+') {
+                negative = false;
+            } else if self.consume('-') {
+                negative = true;
+            } else if self.position >= self.input.len() {
+                break;
+            } else {
+                return Err(self.unexpected());
+            }
+        }
+
+        Ok(DiceExpression(terms))
+    }
+
+    /// Parse a single term - either a constant or a roll of some dice
+    fn parse_term(&mut self) -> Result<DiceTerm, DiceParseError> {
+        let count = self.parse_number()?;
+
+        if !self.consume('d') {
+            return count
+                .map(DiceTerm::Constant)
+                .ok_or_else(|| self.unexpected());
+        }
+
+        let count = count.unwrap_or(1);
+        let sides = if self.consume('%') {
+            100
+        } else {
+            self.parse_number()?.ok_or_else(|| self.unexpected())?
+        };
+        let keep = self.parse_keep()?;
+
+        if count == 0 || count > MAX_DICE {
+            return Err(DiceParseError::InvalidDiceCount);
+        }
+        if sides == 0 || sides > MAX_SIDES {
+            return Err(DiceParseError::InvalidSides);
+        }
+        match keep {
+            Some(Keep::Highest(n)) | Some(Keep::Lowest(n)) if n == 0 || n > count => {
+                Err(DiceParseError::InvalidKeep)
+            }
+            _ => Ok(DiceTerm::Dice { count, sides, keep }),
+        }
+    }
+
+    /// Parse the optional "keep" suffix on a roll of dice
+    fn parse_keep(&mut self) -> Result<Option<Keep>, DiceParseError> {
+        if !self.consume('k') {
+            return Ok(None);
+        }
+
+        let highest = !self.consume('l');
+        if highest {
+            self.consume('h');
+        }
+
+        let n = self.parse_number()?.ok_or_else(|| self.unexpected())?;
+        Ok(Some(if highest {
+            Keep::Highest(n)
+        } else {
+            Keep::Lowest(n)
+        }))
+    }
+
+    /// Parse a number, if there is one at the current position
+    fn parse_number(&mut self) -> Result<Option<u32>, DiceParseError> {
+        let start = self.position;
+        let mut value: u32 = 0;
+
+        while let Some(digit) = self.peek().and_then(|c| c.to_digit(10)) {
+            value = value
+                .checked_mul(10)
+                .and_then(|v| v.checked_add(digit))
+                .ok_or(DiceParseError::NumberTooLarge)?;
+            self.position += 1;
+        }
+
+        Ok(Some(value).filter(|_| self.position > start))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use galvanic_assert::{
+        assert_that,
+        matchers::{variant::*, *},
+    };
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    fn dice(negative: bool, count: u32, sides: u32, keep: Option<Keep>) -> SignedTerm {
+        SignedTerm {
+            negative,
+            term: DiceTerm::Dice { count, sides, keep },
+        }
+    }
+
+    fn constant(negative: bool, value: u32) -> SignedTerm {
+        SignedTerm {
+            negative,
+            term: DiceTerm::Constant(value),
+        }
+    }
+
+    #[test]
+    fn test_parse_single_die() {
+        let result = DiceExpression::from_str("d20");
+
+        assert_that!(
+            &result,
+            maybe_ok(eq(DiceExpression(vec![dice(false, 1, 20, None)])))
+        );
+    }
+
+    #[test]
+    fn test_parse_dice_with_modifier() {
+        let result = DiceExpression::from_str("3d6+2");
+
+        assert_that!(
+            &result,
+            maybe_ok(eq(DiceExpression(vec![
+                dice(false, 3, 6, None),
+                constant(false, 2)
+            ])))
+        );
+    }
+
+    #[test]
+    fn test_parse_keep_highest() {
+        let result = DiceExpression::from_str("4d6kh3");
+
+        assert_that!(
+            &result,
+            maybe_ok(eq(DiceExpression(vec![dice(
+                false,
+                4,
+                6,
+                Some(Keep::Highest(3))
+            )])))
+        );
+    }
+
+    #[test]
+    fn test_parse_keep_lowest() {
+        let result = DiceExpression::from_str("2d20kl1");
+
+        assert_that!(
+            &result,
+            maybe_ok(eq(DiceExpression(vec![dice(
+                false,
+                2,
+                20,
+                Some(Keep::Lowest(1))
+            )])))
+        );
+    }
+
+    #[test]
+    fn test_parse_complex_expression() {
+        let result = DiceExpression::from_str(" -1 + 2D8 - d% ");
+
+        assert_that!(
+            &result,
+            maybe_ok(eq(DiceExpression(vec![
+                constant(true, 1),
+                dice(false, 2, 8, None),
+                dice(true, 1, 100, None)
+            ])))
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_that!(
+            &DiceExpression::from_str(""),
+            maybe_err(eq(DiceParseError::Blank))
+        );
+        assert_that!(
+            &DiceExpression::from_str("  "),
+            maybe_err(eq(DiceParseError::Blank))
+        );
+        assert_that!(
+            &DiceExpression::from_str("3d"),
+            maybe_err(eq(DiceParseError::UnexpectedEnd))
+        );
+        assert_that!(
+            &DiceExpression::from_str("3d6+"),
+            maybe_err(eq(DiceParseError::UnexpectedEnd))
+        );
+        assert_that!(
+            &DiceExpression::from_str("3x6"),
+            maybe_err(eq(DiceParseError::UnexpectedCharacter(1)))
+        );
+        assert_that!(
+            &DiceExpression::from_str("0d6"),
+            maybe_err(eq(DiceParseError::InvalidDiceCount))
+        );
+        assert_that!(
+            &DiceExpression::from_str("1000d6"),
+            maybe_err(eq(DiceParseError::InvalidDiceCount))
+        );
+        assert_that!(
+            &DiceExpression::from_str("1d0"),
+            maybe_err(eq(DiceParseError::InvalidSides))
+        );
+        assert_that!(
+            &DiceExpression::from_str("4d6kh5"),
+            maybe_err(eq(DiceParseError::InvalidKeep))
+        );
+        assert_that!(
+            &DiceExpression::from_str("99999999999"),
+            maybe_err(eq(DiceParseError::NumberTooLarge))
+        );
+    }
+
+    #[test]
+    fn test_display_expression() {
+        let expression = DiceExpression::from_str("4d6 kh3 - 1d4 + 2").unwrap();
+
+        assert_eq!("4d6kh3-1d4+2", format!("{}", expression));
+    }
+
+    #[test]
+    fn test_roll_constant() {
+        let mut rng = ChaCha8Rng::seed_from_u64(1);
+        let result = DiceExpression::from_str("5-2").unwrap().roll(&mut rng);
+
+        assert_eq!(3, result.total);
+        assert_eq!(
+            vec![5, -2],
+            result.terms.iter().map(|t| t.value).collect::<Vec<i64>>()
+        );
+    }
+
+    #[test]
+    fn test_roll_keep_highest() {
+        let mut rng = ChaCha8Rng::seed_from_u64(1);
+        let result = DiceExpression::from_str("4d6kh3").unwrap().roll(&mut rng);
+
+        let term = &result.terms[0];
+        assert_eq!(4, term.rolls.len());
+        assert_eq!(3, term.kept.len());
+        assert!(term.rolls.iter().all(|r| *r >= 1 && *r <= 6));
+
+        let mut sorted = term.rolls.clone();
+        sorted.sort_unstable();
+        assert_eq!(i64::from(sorted[1] + sorted[2] + sorted[3]), result.total);
+    }
+
+    #[test]
+    fn test_roll_is_reproducible() {
+        let expression = DiceExpression::from_str("10d20+3d6-4").unwrap();
+
+        let first = expression.roll(&mut ChaCha8Rng::seed_from_u64(1234));
+        let second = expression.roll(&mut ChaCha8Rng::seed_from_u64(1234));
+
+        assert_eq!(first, second);
+    }
+}
@@ -0,0 +1,39 @@
+use super::DiceRoll;
+use crate::worlds::UrlSlug;
+
+/// A single step taken when rolling on a random table
+#[derive(Debug, PartialEq, Clone)]
+pub enum RollStep {
+    /// An entry was selected from a table
+    Table(TableRollStep),
+    /// Some dice were rolled
+    Dice(DiceRoll),
+}
+
+/// The details of selecting an entry from a table
+#[derive(Debug, PartialEq, Clone)]
+pub struct TableRollStep {
+    /// The URL Slug of the table that was rolled on
+    pub table: UrlSlug,
+    /// The name of the table that was rolled on
+    pub name: String,
+    /// The number that was rolled to select the entry, between 1 and `total_weight`
+    pub roll: u64,
+    /// The total weight of all the entries in the table
+    pub total_weight: u64,
+    /// The index of the entry that was selected
+    pub entry: usize,
+    /// The text of the selected entry, with all inline expressions evaluated
+    pub result: String,
+    /// The steps that were taken to evaluate the inline expressions in the entry
+    pub steps: Vec<RollStep>,
+}
+
+/// The outcome of rolling on a random table
+#[derive(Debug, PartialEq, Clone)]
+pub struct TableRoll {
+    /// The seed that was used for the roll. Rolling again with the same seed gives the same outcome
+    pub seed: u32,
+    /// The roll on the requested table
+    pub result: TableRollStep,
+}
@@ -0,0 +1,57 @@
+use super::TableID;
+use crate::{
+    model::{Model, Publication},
+    worlds::{UrlSlug, WorldID},
+};
+use serde::{Deserialize, Serialize};
+
+/// The largest weight that a single entry in a table may have
+pub const MAX_ENTRY_WEIGHT: u32 = 1_000_000;
+
+/// A single entry in a random table
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TableEntry {
+    /// The relative weight of this entry when rolling on the table
+    pub weight: u32,
+
+    /// The text of the entry. This may contain inline expressions to roll dice or other tables
+    pub text: String,
+}
+
+/// Data to represent a random table
+#[derive(Debug, Clone)]
+pub struct TableData {
+    /// The ID of the world that the table belongs to
+    pub world: WorldID,
+
+    /// The name of the table
+    pub name: String,
+
+    /// A description of the table
+    pub description: String,
+
+    /// The URL Slug of the table, unique within the world
+    pub url_slug: UrlSlug,
+
+    /// The entries in the table
+    pub entries: Vec<TableEntry>,
+
+    /// The publication state of the table
+    pub publication: Publication,
+}
+
+impl TableData {
+    /// Get the total weight of all the entries in the table
+    ///
+    /// # Returns
+    /// The sum of the weights of every entry. This is summed as a `u64` so that it can never overflow
+    pub fn total_weight(&self) -> u64 {
+        self.entries
+            .iter()
+            .map(|entry| u64::from(entry.weight))
+            .sum()
+    }
+}
+
+/// Model representation of a random table
+pub type TableModel = Model<TableID, TableData>;
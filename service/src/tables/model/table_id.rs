@@ -0,0 +1,53 @@
+use bytes::BytesMut;
+use postgres_types::{accepts, to_sql_checked, FromSql, IsNull, ToSql, Type};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use uuid::Uuid;
+
+/// The ID of a Random Table
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, FromSql)]
+pub struct TableID(Uuid);
+
+impl TableID {
+    /// Wrap a UUID as a Table ID object
+    ///
+    /// # Parameters
+    /// - `uuid` - The UUID to wrap
+    ///
+    /// # The Table ID
+    pub fn new(uuid: Uuid) -> Self {
+        TableID(uuid)
+    }
+}
+
+impl Default for TableID {
+    fn default() -> Self {
+        Self(Uuid::new_v4())
+    }
+}
+
+impl ToSql for TableID {
+    accepts!(UUID);
+
+    to_sql_checked!();
+
+    fn to_sql(
+        &self,
+        t: &Type,
+        w: &mut BytesMut,
+    ) -> Result<IsNull, Box<dyn std::error::Error + Sync + Send>> {
+        self.0.to_sql(t, w)
+    }
+}
+
+impl Display for TableID {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<TableID> for Uuid {
+    fn from(table_id: TableID) -> Self {
+        table_id.0
+    }
+}
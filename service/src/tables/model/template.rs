@@ -0,0 +1,165 @@
+use super::{DiceExpression, DiceParseError};
+use crate::worlds::{UrlSlug, UrlSlugParseError};
+use std::str::FromStr;
+
+/// The prefix used inside an inline expression to indicate that it refers to another table
+const TABLE_PREFIX: &str = "table:";
+
+/// A single part of the text of a table entry
+#[derive(Debug, PartialEq, Clone)]
+pub enum TemplatePart {
+    /// Literal text that is output as-is
+    Literal(String),
+    /// A dice expression to roll, written as `{3d6+2}`
+    Dice(DiceExpression),
+    /// A reference to another table in the same world to roll on, written as `{table:tavern-names}`
+    Table(UrlSlug),
+}
+
+/// The parsed text of a table entry
+#[derive(Debug, PartialEq, Clone)]
+pub struct Template(pub Vec<TemplatePart>);
+
+/// Errors that can occur when parsing the text of a table entry
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum TemplateParseError {
+    /// An inline expression was opened but never closed
+    #[error("An inline expression was not closed")]
+    UnclosedExpression,
+
+    /// An inline expression was closed without being opened
+    #[error("An inline expression was closed without being opened")]
+    UnopenedExpression,
+
+    /// An inline expression referred to a table without giving the table
+    #[error("The table reference was blank")]
+    InvalidTable(#[from] UrlSlugParseError),
+
+    /// An inline expression contained an invalid dice expression
+    #[error("The dice expression was invalid: {0}")]
+    InvalidDice(#[from] DiceParseError),
+}
+
+impl FromStr for Template {
+    type Err = TemplateParseError;
+
+    /// Parse the text of a table entry into the parts that it is made up of
+    ///
+    /// # Parameters
+    /// - `s` - The input string to parse
+    ///
+    /// # Returns
+    /// The parsed template
+    ///
+    /// # Errors
+    /// Any errors that occurred parsing the template
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = vec![];
+        let mut remaining = s;
+
+        while let Some(start) = remaining.find(|c: char| c == '{' || c == '}') {
+            if remaining[start..].starts_with('}') {
+                return Err(TemplateParseError::UnopenedExpression);
+            }
+
+            let end = remaining[start..]
+                .find('}')
+                .map(|end| start + end)
+                .ok_or(TemplateParseError::UnclosedExpression)?;
+
+            if start > 0 {
+                parts.push(TemplatePart::Literal(remaining[..start].to_owned()));
+            }
+
+            let expression = remaining[start + 1..end].trim();
+            if expression.contains('{') {
+                return Err(TemplateParseError::UnclosedExpression);
+            }
+
+            let part = if expression.starts_with(TABLE_PREFIX) {
+                TemplatePart::Table(expression[TABLE_PREFIX.len()..].trim().parse()?)
+            } else {
+                TemplatePart::Dice(expression.parse()?)
+            };
+            parts.push(part);
+
+            remaining = &remaining[end + 1..];
+        }
+
+        if !remaining.is_empty() {
+            parts.push(TemplatePart::Literal(remaining.to_owned()));
+        }
+
+        Ok(Template(parts))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use galvanic_assert::{
+        assert_that,
+        matchers::{variant::*, *},
+    };
+
+    #[test]
+    fn test_parse_literal() {
+        let result = Template::from_str("The Prancing Pony");
+
+        assert_that!(
+            &result,
+            maybe_ok(eq(Template(vec![TemplatePart::Literal(
+                "The Prancing Pony".to_owned()
+            )])))
+        );
+    }
+
+    #[test]
+    fn test_parse_empty() {
+        let result = Template::from_str("");
+
+        assert_that!(&result, maybe_ok(eq(Template(vec![]))));
+    }
+
+    #[test]
+    fn test_parse_expressions() {
+        let result = Template::from_str("{2d4} goblins led by {table:goblin-bosses}");
+
+        assert_that!(
+            &result,
+            maybe_ok(eq(Template(vec![
+                TemplatePart::Dice("2d4".parse().unwrap()),
+                TemplatePart::Literal(" goblins led by ".to_owned()),
+                TemplatePart::Table("goblin-bosses".parse().unwrap()),
+            ])))
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_that!(
+            &Template::from_str("{2d4 goblins"),
+            maybe_err(eq(TemplateParseError::UnclosedExpression))
+        );
+        assert_that!(
+            &Template::from_str("2d4} goblins"),
+            maybe_err(eq(TemplateParseError::UnopenedExpression))
+        );
+        assert_that!(
+            &Template::from_str("{{2d4}}"),
+            maybe_err(eq(TemplateParseError::UnclosedExpression))
+        );
+        assert_that!(
+            &Template::from_str("{table: }"),
+            maybe_err(eq(TemplateParseError::InvalidTable(
+                UrlSlugParseError::Blank
+            )))
+        );
+        assert_that!(
+            &Template::from_str("{goblins}"),
+            maybe_err(eq(TemplateParseError::InvalidDice(
+                DiceParseError::UnexpectedCharacter(0)
+            )))
+        );
+    }
+}
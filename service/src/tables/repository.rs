@@ -0,0 +1,30 @@
+mod create;
+mod delete;
+mod errors;
+mod find;
+mod parse;
+mod update;
+
+use crate::infrastructure::database::Database;
+pub(crate) use create::insert_table;
+pub use errors::*;
+
+/// Repository used to access Random Table data from the database
+#[derive(Clone)]
+pub(super) struct TableRepository {
+    /// The database with which to access table records
+    database: Database,
+}
+
+impl TableRepository {
+    /// Create a new Table Repository to work with
+    ///
+    /// # Parameters
+    /// - `database` - The database connection to use
+    ///
+    /// # Returns
+    /// The Table Repository to use
+    pub(super) fn new(database: Database) -> Self {
+        Self { database }
+    }
+}
@@ -0,0 +1,118 @@
+use super::{SaveTableError, TableRepository};
+use crate::{
+    events::{publish_change, EntityType, WorldChange},
+    quotas::{enforce_quota, QuotaAllowance},
+    tables::model::*,
+};
+use chrono::Utc;
+use postgres_types::Json;
+use uuid::Uuid;
+
+impl TableRepository {
+    /// Create a new random table in the database, enforcing the quota of tables that the owner of the
+    /// world has in the same transaction
+    ///
+    /// # Parameters
+    /// - `table` - The table to create
+    /// - `quota` - The allowance for the owner of the world to create another table, if the world exists
+    ///
+    /// # Returns
+    /// The newly created table
+    ///
+    /// # Errors
+    /// Any errors that occurred creating the new table
+    pub fn create(
+        &self,
+        table: TableData,
+        quota: Option<&QuotaAllowance>,
+    ) -> Result<TableModel, SaveTableError> {
+        self.create_many(vec![table], quota)
+            .map(|mut tables| tables.remove(0))
+    }
+
+    /// Create several new random tables in the database in a single transaction, so that either all of
+    /// them are created or none are. The quota of tables that the owner of the world has is enforced for
+    /// all of them together in the same transaction
+    ///
+    /// # Parameters
+    /// - `tables` - The tables to create
+    /// - `quota` - The allowance for the owner of the world to create this many tables, if the world
+    ///   exists
+    ///
+    /// # Returns
+    /// The newly created tables, in the same order
+    ///
+    /// # Errors
+    /// Any errors that occurred creating the new tables
+    #[tracing::instrument(skip(self))]
+    pub fn create_many(
+        &self,
+        tables: Vec<TableData>,
+        quota: Option<&QuotaAllowance>,
+    ) -> Result<Vec<TableModel>, SaveTableError> {
+        let mut connection = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+        let mut transaction = connection.transaction()?;
+
+        if let Some(quota) = quota {
+            enforce_quota(&mut transaction, quota)?;
+        }
+
+        let mut new_tables = vec![];
+        for table in tables {
+            new_tables.push(insert_table(&mut transaction, &table)?);
+        }
+
+        transaction.commit()?;
+
+        for new_table in &new_tables {
+            publish_change(
+                &mut connection,
+                WorldChange {
+                    world: new_table.data.world.clone(),
+                    entity_type: EntityType::Table,
+                    entity_id: new_table.identity.id.clone().into(),
+                    version: Some(new_table.identity.version),
+                },
+            );
+        }
+
+        Ok(new_tables)
+    }
+}
+
+/// Insert a new random table using an existing transaction. Enforcing quotas and publishing the change
+/// once the transaction commits are left to the caller
+///
+/// # Parameters
+/// - `transaction` - The transaction to insert the table in
+/// - `table` - The table to create
+///
+/// # Returns
+/// The newly created table
+///
+/// # Errors
+/// Any errors that occurred creating the new table
+pub(crate) fn insert_table(
+    transaction: &mut postgres::Transaction<'_>,
+    table: &TableData,
+) -> Result<TableModel, SaveTableError> {
+    let new_table = transaction.query_one("INSERT INTO random_tables(table_id, version, created, updated, world_id, name, description, url_slug, entries, status, publish_at) VALUES($1, $2, $3, $3, $4, $5, $6, $7, $8, $9, $10) RETURNING *",
+        &[
+            &TableID::default(),
+            &Uuid::new_v4(),
+            &Utc::now(),
+            &table.world,
+            &table.name,
+            &table.description,
+            &table.url_slug,
+            &Json(&table.entries),
+            &table.publication.stored_status().to_string(),
+            &table.publication.publish_at(),
+        ])
+        .map(|row| TableRepository::parse_row(&row))?;
+
+    Ok(new_table)
+}
@@ -0,0 +1,44 @@
+use super::TableRepository;
+use crate::{
+    events::{publish_change, EntityType, WorldChange},
+    tables::{TableID, TableModel},
+};
+
+impl TableRepository {
+    /// Delete the random table with the given ID
+    ///
+    /// # Parameters
+    /// - `id` - The ID of the table to delete
+    ///
+    /// # Returns
+    /// The table that was deleted, or `None` if it didn't exist
+    #[tracing::instrument(skip(self))]
+    pub fn delete(&self, id: &TableID) -> Option<TableModel> {
+        let mut connection = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+
+        let deleted = connection
+            .query_opt(
+                "DELETE FROM random_tables WHERE table_id = $1 RETURNING *",
+                &[id],
+            )
+            .expect("Failed to delete table")
+            .map(|row| Self::parse_row(&row));
+
+        if let Some(table) = &deleted {
+            publish_change(
+                &mut connection,
+                WorldChange {
+                    world: table.data.world.clone(),
+                    entity_type: EntityType::Table,
+                    entity_id: table.identity.id.clone().into(),
+                    version: None,
+                },
+            );
+        }
+
+        deleted
+    }
+}
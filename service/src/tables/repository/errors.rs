@@ -0,0 +1,83 @@
+use crate::quotas::QuotaExceededError;
+use postgres::error::{DbError, SqlState};
+
+/// Errors that can occur when saving a random table record
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum SaveTableError {
+    /// An unknown error occurred
+    #[error("An unknown error occurred")]
+    UnknownError,
+
+    /// The URL Slug is already used by another table in the same world
+    #[error("The URL Slug was already present for this world")]
+    DuplicateUrlSlug,
+
+    /// The world that the table belongs to does not exist
+    #[error("The desired world does not exist")]
+    UnknownWorld,
+
+    /// The table was not found
+    #[error("The table was not found")]
+    UnknownTable,
+
+    /// The owner of the world has reached their quota of tables
+    #[error("The owner of the world has reached their quota of tables")]
+    QuotaExceeded(#[from] QuotaExceededError),
+}
+
+impl From<postgres::Error> for SaveTableError {
+    /// Convert a database error into a SaveTableError.
+    ///
+    /// The conversion returns a `DuplicateUrlSlug` iff the error is a `UNIQUE_VIOLATION` and the constraint is
+    /// `random_tables_world_url_slug_key`, and an `UnknownWorld` iff the error is a `FOREIGN_KEY_VIOLATION`
+    /// and the constraint is `random_tables_world_id_fkey`. Otherwise it returns an `UnknownError`
+    ///
+    /// # Parameters
+    /// - `e` - The error to convert
+    ///
+    /// # Returns
+    /// The new error code
+    fn from(e: postgres::Error) -> Self {
+        let mut result = None;
+
+        if e.code() == Some(&SqlState::UNIQUE_VIOLATION) {
+            let db_error: Option<DbError> = e
+                .into_source()
+                .and_then(|e| e.downcast_ref::<DbError>().cloned());
+
+            result = db_error
+                .and_then(|e| e.constraint().map(|c| c.to_owned()))
+                .map(|constraint| match constraint.as_ref() {
+                    "random_tables_world_url_slug_key" => SaveTableError::DuplicateUrlSlug,
+                    _ => {
+                        tracing::warn!(
+                            "Unexpected unique key constraint violation error: {:?}",
+                            constraint
+                        );
+                        SaveTableError::UnknownError
+                    }
+                });
+        } else if e.code() == Some(&SqlState::FOREIGN_KEY_VIOLATION) {
+            let db_error: Option<DbError> = e
+                .into_source()
+                .and_then(|e| e.downcast_ref::<DbError>().cloned());
+
+            result = db_error
+                .and_then(|e| e.constraint().map(|c| c.to_owned()))
+                .map(|constraint| match constraint.as_ref() {
+                    "random_tables_world_id_fkey" => SaveTableError::UnknownWorld,
+                    _ => {
+                        tracing::warn!(
+                            "Unexpected foreign key constraint violation error: {:?}",
+                            constraint
+                        );
+                        SaveTableError::UnknownError
+                    }
+                });
+        } else {
+            tracing::warn!("Unexpected database error: {:?}", e);
+        }
+
+        result.unwrap_or(SaveTableError::UnknownError)
+    }
+}
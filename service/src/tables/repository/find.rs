@@ -0,0 +1,96 @@
+use super::TableRepository;
+use crate::{
+    model::{published_sql, Page, Pagination},
+    tables::TableModel,
+    worlds::{UrlSlug, WorldID},
+};
+
+impl TableRepository {
+    /// Find a random table in the database by the world it belongs to and its URL Slug
+    ///
+    /// # Parameters
+    /// - `world` - The ID of the world that the table belongs to
+    /// - `url_slug` - The URL Slug of the table
+    ///
+    /// # Returns
+    /// The table, if it exists
+    #[tracing::instrument(skip(self))]
+    pub fn find_table_by_url_slug(
+        &self,
+        world: &WorldID,
+        url_slug: &UrlSlug,
+    ) -> Option<TableModel> {
+        let mut connection = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+        connection
+            .query_opt(
+                "SELECT * FROM random_tables WHERE world_id = $1 AND url_slug = $2",
+                &[world, url_slug],
+            )
+            .expect("Failed to query for table by URL Slug")
+            .map(|row| Self::parse_row(&row))
+    }
+
+    /// List the random tables that belong to a world, ordered by name
+    ///
+    /// # Parameters
+    /// - `world` - The ID of the world to list the tables for
+    /// - `include_unpublished` - Whether to include tables that aren't yet published
+    /// - `pagination` - The pagination details for which page of results are wanted
+    ///
+    /// # Returns
+    /// The requested page of results
+    #[tracing::instrument(skip(self))]
+    pub fn list_tables(
+        &self,
+        world: &WorldID,
+        include_unpublished: bool,
+        pagination: &Pagination,
+    ) -> Page<TableModel> {
+        let mut connection = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+
+        let where_clause = if include_unpublished {
+            "WHERE world_id = $1".to_owned()
+        } else {
+            format!("WHERE world_id = $1 AND {}", published_sql("random_tables"))
+        };
+
+        let select_query = format!(
+            "SELECT * FROM random_tables {} ORDER BY name ASC, table_id ASC OFFSET {} LIMIT {}",
+            where_clause, pagination.offset, pagination.count
+        );
+        let records: Vec<TableModel> = connection
+            .query(select_query.as_str(), &[world])
+            .map(|rows| rows.iter().map(Self::parse_row).collect())
+            .expect("Failed to select tables for world");
+
+        let num_records = records.len() as u64;
+        let total = if records.is_empty() || num_records == pagination.count {
+            // If we got zero records back, or exactly the number we asked for, then we don't know
+            // how many there are in the database
+            let count_query = format!(
+                "SELECT COUNT(*)::INTEGER AS c FROM random_tables {}",
+                where_clause
+            );
+            let count: i32 = connection
+                .query_one(count_query.as_str(), &[world])
+                .map(|row| row.get("c"))
+                .expect("Failed to count tables for world");
+            count as u64
+        } else {
+            pagination.offset + num_records
+        };
+
+        Page {
+            entries: records,
+            offset: pagination.offset,
+            count: pagination.count,
+            total,
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use super::TableRepository;
+use crate::{
+    model::{Identity, Publication},
+    tables::model::*,
+};
+use postgres::row::Row;
+use postgres_types::Json;
+
+impl TableRepository {
+    /// Parse a row from the database into a Table Model
+    ///
+    /// # Parameters
+    /// - `row` - The row to parse
+    ///
+    /// # Returns
+    /// The table that the row represented
+    pub(super) fn parse_row(row: &Row) -> TableModel {
+        let entries: Json<Vec<TableEntry>> = row.get("entries");
+
+        TableModel {
+            identity: Identity {
+                id: row.get("table_id"),
+                version: row.get("version"),
+                created: row.get("created"),
+                updated: row.get("updated"),
+            },
+            data: TableData {
+                world: row.get("world_id"),
+                name: row.get("name"),
+                description: row.get("description"),
+                url_slug: row.get("url_slug"),
+                entries: entries.0,
+                publication: Publication::from_stored(row.get("status"), row.get("publish_at")),
+            },
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use super::{SaveTableError, TableRepository};
+use crate::{
+    events::{publish_change, EntityType, WorldChange},
+    tables::model::*,
+};
+use chrono::Utc;
+use postgres_types::Json;
+use uuid::Uuid;
+
+impl TableRepository {
+    /// Update the given random table in the database
+    ///
+    /// # Parameters
+    /// - `id` - The ID of the table to update
+    /// - `table` - The details to update in the database
+    ///
+    /// # Returns
+    /// The updated table
+    ///
+    /// # Errors
+    /// Any errors that occurred updating the table
+    #[tracing::instrument(skip(self))]
+    pub fn update(&self, id: &TableID, table: TableData) -> Result<TableModel, SaveTableError> {
+        let now = Utc::now();
+        let version = Uuid::new_v4();
+
+        let mut connection = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+
+        let updated_table = connection.query_opt("UPDATE random_tables SET version = $2, updated = $3, name = $4, description = $5, url_slug = $6, entries = $7, status = $8, publish_at = $9 WHERE table_id = $1 RETURNING *",
+            &[
+                &id,
+                &version,
+                &now,
+                &table.name,
+                &table.description,
+                &table.url_slug,
+                &Json(&table.entries),
+                &table.publication.stored_status().to_string(),
+                &table.publication.publish_at(),
+            ])?
+            .map(|row| Self::parse_row(&row))
+            .ok_or(SaveTableError::UnknownTable)?;
+
+        publish_change(
+            &mut connection,
+            WorldChange {
+                world: updated_table.data.world.clone(),
+                entity_type: EntityType::Table,
+                entity_id: updated_table.identity.id.clone().into(),
+                version: Some(updated_table.identity.version),
+            },
+        );
+
+        Ok(updated_table)
+    }
+}
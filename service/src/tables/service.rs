@@ -0,0 +1,40 @@
+mod create_table;
+mod delete;
+mod find;
+mod roll_table;
+mod update;
+
+use super::repository::TableRepository;
+use crate::{activity::ActivityService, quotas::QuotasService, webhooks::WebhooksService};
+pub use create_table::CreateTableError;
+pub use roll_table::RollTableError;
+pub use update::UpdateTableError;
+
+/// Service Layer for dealing with Random Tables
+#[derive(Clone)]
+pub struct TablesService {
+    repository: TableRepository,
+    activity: ActivityService,
+    webhooks: WebhooksService,
+    quotas: QuotasService,
+}
+
+impl TablesService {
+    /// Create a new instance of the Tables Service
+    ///
+    /// # Returns
+    /// The Tables Service ready to use
+    pub(super) fn new(
+        repository: TableRepository,
+        activity: ActivityService,
+        webhooks: WebhooksService,
+        quotas: QuotasService,
+    ) -> Self {
+        Self {
+            repository,
+            activity,
+            webhooks,
+            quotas,
+        }
+    }
+}
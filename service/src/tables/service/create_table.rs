@@ -0,0 +1,115 @@
+use crate::{
+    activity::{ActivityData, ActivityEvent},
+    quotas::{QuotaExceededError, QuotaResource},
+    tables::{endpoints::model::TableLink, repository::SaveTableError, *},
+    webhooks::{WebhookEvent, WebhookEventKind},
+    worlds::WorldID,
+};
+
+/// Errors that can occur when creating a new random table
+#[derive(Debug, thiserror::Error)]
+pub enum CreateTableError {
+    /// An unknown error occurred
+    #[error("An unknown error occurred")]
+    UnknownError,
+
+    #[error("The URL Slug was already present for this world")]
+    DuplicateUrlSlug,
+
+    #[error("The desired world does not exist")]
+    UnknownWorld,
+
+    #[error("The owner of the world has reached their quota of tables")]
+    QuotaExceeded(#[from] QuotaExceededError),
+}
+
+impl TablesService {
+    /// Create a new random table in the system
+    ///
+    /// # Parameters
+    /// - `table` - The table to create
+    ///
+    /// # Returns
+    /// The newly created table
+    ///
+    /// # Errors
+    /// Any errors that occurred creating the new table
+    pub fn create_table(&self, table: TableData) -> Result<TableModel, CreateTableError> {
+        tracing::debug!(table = ?table, "Creating table");
+
+        let quota = self
+            .quotas
+            .check_world_quota(&table.world, QuotaResource::Tables, 1)?;
+
+        let new_table = self.repository.create(table, quota.as_ref())?;
+        tracing::debug!(table = ?new_table, "Created table");
+
+        self.table_created(&new_table);
+
+        Ok(new_table)
+    }
+
+    /// Create several new random tables in a world at once. Either every table is created or, if any of
+    /// them can't be, none of them are
+    ///
+    /// # Parameters
+    /// - `world` - The ID of the world that every table belongs to
+    /// - `tables` - The tables to create
+    ///
+    /// # Returns
+    /// The newly created tables, in the same order
+    ///
+    /// # Errors
+    /// Any errors that occurred creating the new tables
+    pub fn create_tables(
+        &self,
+        world: &WorldID,
+        tables: Vec<TableData>,
+    ) -> Result<Vec<TableModel>, CreateTableError> {
+        tracing::debug!(world = ?world, tables = tables.len(), "Creating tables");
+
+        let quota =
+            self.quotas
+                .check_world_quota(world, QuotaResource::Tables, tables.len() as u64)?;
+
+        let new_tables = self.repository.create_many(tables, quota.as_ref())?;
+        tracing::debug!(tables = ?new_tables, "Created tables");
+
+        for new_table in &new_tables {
+            self.table_created(new_table);
+        }
+
+        Ok(new_tables)
+    }
+
+    /// Record the activity and dispatch the webhook event for a table that has just been created
+    pub(crate) fn table_created(&self, new_table: &TableModel) {
+        let target: String = TableLink::new(
+            new_table.data.world.clone(),
+            new_table.data.url_slug.clone(),
+        )
+        .into();
+        self.activity.record_activity(ActivityData {
+            world: new_table.data.world.clone(),
+            user: None,
+            event: ActivityEvent::TableCreated,
+            target: Some(target.clone()),
+        });
+        self.webhooks.dispatch(WebhookEvent {
+            world: new_table.data.world.clone(),
+            kind: WebhookEventKind::TableCreated,
+            target,
+        });
+    }
+}
+
+impl From<SaveTableError> for CreateTableError {
+    fn from(e: SaveTableError) -> Self {
+        match e {
+            SaveTableError::DuplicateUrlSlug => CreateTableError::DuplicateUrlSlug,
+            SaveTableError::UnknownWorld => CreateTableError::UnknownWorld,
+            SaveTableError::QuotaExceeded(e) => CreateTableError::QuotaExceeded(e),
+            _ => CreateTableError::UnknownError,
+        }
+    }
+}
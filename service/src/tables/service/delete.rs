@@ -0,0 +1,27 @@
+use crate::{
+    tables::{endpoints::model::TableLink, TableID, TablesService},
+    webhooks::{WebhookEvent, WebhookEventKind},
+};
+
+impl TablesService {
+    /// Delete a random table
+    ///
+    /// # Parameters
+    /// - `id` - The ID of the table to delete
+    ///
+    /// # Returns
+    /// `true` if the table was deleted. `false` if it didn't exist
+    pub fn delete_table(&self, id: &TableID) -> bool {
+        match self.repository.delete(id) {
+            Some(table) => {
+                self.webhooks.dispatch(WebhookEvent {
+                    world: table.data.world.clone(),
+                    kind: WebhookEventKind::TableDeleted,
+                    target: TableLink::new(table.data.world, table.data.url_slug).into(),
+                });
+                true
+            }
+            None => false,
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use crate::{
+    model::{Page, Pagination},
+    tables::{TableModel, TablesService},
+    worlds::{UrlSlug, WorldID},
+};
+
+impl TablesService {
+    /// Look up a random table by the world it belongs to and its URL Slug
+    ///
+    /// # Parameters
+    /// - `world` - The ID of the world that the table belongs to
+    /// - `url_slug` - The URL Slug of the table
+    ///
+    /// # Returns
+    /// The table, or `None` if it wasn't found
+    pub fn find_table_by_url_slug(
+        &self,
+        world: &WorldID,
+        url_slug: &UrlSlug,
+    ) -> Option<TableModel> {
+        self.repository.find_table_by_url_slug(world, url_slug)
+    }
+
+    /// List the random tables that belong to a world
+    ///
+    /// # Parameters
+    /// - `world` - The ID of the world to list the tables for
+    /// - `include_unpublished` - Whether to include tables that aren't yet published
+    /// - `pagination` - The pagination details for which page of results are wanted
+    ///
+    /// # Returns
+    /// The requested page of results
+    pub fn list_tables(
+        &self,
+        world: &WorldID,
+        include_unpublished: bool,
+        pagination: &Pagination,
+    ) -> Page<TableModel> {
+        self.repository
+            .list_tables(world, include_unpublished, pagination)
+    }
+}
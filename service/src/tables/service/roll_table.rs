@@ -0,0 +1,183 @@
+use crate::{
+    tables::{RollStep, TableRoll, TableRollStep, TablesService, Template, TemplatePart},
+    worlds::{UrlSlug, WorldID},
+};
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+/// The maximum depth of nested tables that will be followed before giving up
+const MAX_DEPTH: usize = 10;
+
+/// The maximum number of tables that will be rolled on in total for a single roll, however they are nested
+const MAX_TABLE_ROLLS: usize = 100;
+
+/// Errors that can occur when rolling on a random table
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum RollTableError {
+    /// The table that was rolled on, or one that it refers to, doesn't exist
+    #[error("The table was not found")]
+    UnknownTable(UrlSlug),
+
+    /// The table that was rolled on, or one that it refers to, has no entries to select
+    #[error("The table has no entries")]
+    EmptyTable(UrlSlug),
+
+    /// An entry in the table has text that can not be evaluated
+    #[error("The table entry was invalid")]
+    InvalidEntry(UrlSlug, usize),
+
+    /// The tables referred to each other too deeply, most likely because of a cycle
+    #[error("The tables were nested too deeply")]
+    RecursionLimit,
+
+    /// The tables referred to too many other tables in total
+    #[error("Too many tables were rolled on")]
+    RollLimit,
+}
+
+impl TablesService {
+    /// Roll on a random table, evaluating any dice and nested tables in the selected entry.
+    ///
+    /// The roll is driven entirely from the seed, so rolling again with the same seed against the same
+    /// tables will produce exactly the same outcome.
+    ///
+    /// # Parameters
+    /// - `world` - The ID of the world that the table belongs to
+    /// - `url_slug` - The URL Slug of the table to roll on
+    /// - `seed` - The seed to use for the roll. If not provided then a random one will be used
+    /// - `include_unpublished` - Whether tables that aren't yet published can be rolled on. If not then
+    ///   they are treated as not existing, both when rolled on directly and when nested
+    ///
+    /// # Returns
+    /// The outcome of the roll, including every step that was taken to reach it
+    ///
+    /// # Errors
+    /// Any errors that occurred rolling on the table
+    pub fn roll_table(
+        &self,
+        world: &WorldID,
+        url_slug: &UrlSlug,
+        seed: Option<u32>,
+        include_unpublished: bool,
+    ) -> Result<TableRoll, RollTableError> {
+        let seed = seed.unwrap_or_else(rand::random);
+        let mut rng = ChaCha8Rng::seed_from_u64(u64::from(seed));
+
+        let mut remaining_rolls = MAX_TABLE_ROLLS;
+        let result = self.roll_on_table(
+            world,
+            url_slug,
+            include_unpublished,
+            &mut rng,
+            &mut remaining_rolls,
+            0,
+        )?;
+        tracing::debug!(seed = ?seed, result = ?result, "Rolled on table");
+
+        Ok(TableRoll { seed, result })
+    }
+
+    /// Select an entry from a single table and evaluate it
+    ///
+    /// # Parameters
+    /// - `world` - The ID of the world that the table belongs to
+    /// - `url_slug` - The URL Slug of the table to roll on
+    /// - `include_unpublished` - Whether tables that aren't yet published can be rolled on
+    /// - `rng` - The random number generator to use
+    /// - `remaining_rolls` - How many more tables may be rolled on, shared across the whole roll
+    /// - `depth` - How deeply nested this table is
+    ///
+    /// # Returns
+    /// The details of the roll on this table
+    fn roll_on_table<R>(
+        &self,
+        world: &WorldID,
+        url_slug: &UrlSlug,
+        include_unpublished: bool,
+        rng: &mut R,
+        remaining_rolls: &mut usize,
+        depth: usize,
+    ) -> Result<TableRollStep, RollTableError>
+    where
+        R: Rng,
+    {
+        if depth >= MAX_DEPTH {
+            tracing::warn!(table = ?url_slug, "Nested tables too deeply");
+            return Err(RollTableError::RecursionLimit);
+        }
+        if *remaining_rolls == 0 {
+            tracing::warn!(table = ?url_slug, "Rolled on too many tables");
+            return Err(RollTableError::RollLimit);
+        }
+        *remaining_rolls -= 1;
+
+        let table = self
+            .repository
+            .find_table_by_url_slug(world, url_slug)
+            .filter(|table| include_unpublished || table.data.publication.is_visible())
+            .ok_or_else(|| RollTableError::UnknownTable(url_slug.clone()))?;
+
+        let total_weight = table.data.total_weight();
+        if total_weight == 0 {
+            return Err(RollTableError::EmptyTable(url_slug.clone()));
+        }
+
+        let roll = rng.gen_range(1, total_weight + 1);
+        let mut remaining = roll;
+        let (entry, selected) = table
+            .data
+            .entries
+            .iter()
+            .enumerate()
+            .find(|(_, entry)| {
+                let weight = u64::from(entry.weight);
+                if remaining <= weight {
+                    true
+                } else {
+                    remaining -= weight;
+                    false
+                }
+            })
+            .expect("Roll was outside the total weight of the table");
+
+        let template: Template = selected.text.parse().map_err(|e| {
+            tracing::warn!(e = ?e, table = ?url_slug, entry = entry, "Failed to parse table entry");
+            RollTableError::InvalidEntry(url_slug.clone(), entry)
+        })?;
+
+        let mut result = String::new();
+        let mut steps = vec![];
+        for part in template.0 {
+            match part {
+                TemplatePart::Literal(text) => result.push_str(&text),
+                TemplatePart::Dice(expression) => {
+                    let dice = expression.roll(rng);
+                    result.push_str(&dice.total.to_string());
+                    steps.push(RollStep::Dice(dice));
+                }
+                TemplatePart::Table(nested) => {
+                    let nested = self.roll_on_table(
+                        world,
+                        &nested,
+                        include_unpublished,
+                        rng,
+                        remaining_rolls,
+                        depth + 1,
+                    )?;
+                    result.push_str(&nested.result);
+                    steps.push(RollStep::Table(nested));
+                }
+            }
+        }
+
+        Ok(TableRollStep {
+            table: url_slug.clone(),
+            name: table.data.name,
+            roll,
+            total_weight,
+            entry,
+            result,
+            steps,
+        })
+    }
+}
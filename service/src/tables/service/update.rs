@@ -0,0 +1,64 @@
+use crate::{
+    tables::{endpoints::model::TableLink, repository::SaveTableError, *},
+    webhooks::{WebhookEvent, WebhookEventKind},
+};
+
+/// Errors that can occur when updating a random table
+#[derive(Debug, thiserror::Error)]
+pub enum UpdateTableError {
+    /// An unknown error occurred
+    #[error("An unknown error occurred")]
+    UnknownError,
+
+    #[error("The URL Slug was already present for this world")]
+    DuplicateUrlSlug,
+
+    #[error("The table was not found")]
+    UnknownTable,
+}
+
+impl TablesService {
+    /// Replace the details of an existing random table
+    ///
+    /// # Parameters
+    /// - `id` - The ID of the table to update
+    /// - `table` - The new details of the table
+    ///
+    /// # Returns
+    /// The updated table
+    ///
+    /// # Errors
+    /// Any errors that occurred updating the table
+    pub fn update_table(
+        &self,
+        id: &TableID,
+        table: TableData,
+    ) -> Result<TableModel, UpdateTableError> {
+        tracing::debug!(id = ?id, table = ?table, "Updating table");
+
+        let updated_table = self.repository.update(id, table)?;
+        tracing::debug!(table = ?updated_table, "Updated table");
+
+        self.webhooks.dispatch(WebhookEvent {
+            world: updated_table.data.world.clone(),
+            kind: WebhookEventKind::TableUpdated,
+            target: TableLink::new(
+                updated_table.data.world.clone(),
+                updated_table.data.url_slug.clone(),
+            )
+            .into(),
+        });
+
+        Ok(updated_table)
+    }
+}
+
+impl From<SaveTableError> for UpdateTableError {
+    fn from(e: SaveTableError) -> Self {
+        match e {
+            SaveTableError::DuplicateUrlSlug => UpdateTableError::DuplicateUrlSlug,
+            SaveTableError::UnknownTable => UpdateTableError::UnknownTable,
+            _ => UpdateTableError::UnknownError,
+        }
+    }
+}
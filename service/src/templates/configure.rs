@@ -0,0 +1,63 @@
+use super::{repository::TemplateRepository, TemplatesService};
+use crate::{
+    campaigns::CampaignsService, infrastructure::database::Database, quotas::QuotasService,
+    tables::TablesService, worlds::WorldsService,
+};
+use std::sync::Arc;
+
+/// Application Configuration for the World Templates module
+pub struct TemplatesConfig {
+    pub templates_service: TemplatesService,
+}
+
+impl TemplatesConfig {
+    /// Create the Configuration for the World Templates Module
+    ///
+    /// # Parameters
+    /// - `database` - The database connection to use
+    /// - `worlds_service` - The worlds service to announce newly created worlds with
+    /// - `tables_service` - The tables service to read and announce random tables with
+    /// - `campaigns_service` - The campaigns service to read and announce campaigns with
+    /// - `quotas_service` - The quotas service to check that the content of a template can be created
+    ///
+    /// # Returns
+    /// The World Templates Config object
+    pub fn new(
+        database: Database,
+        worlds_service: WorldsService,
+        tables_service: TablesService,
+        campaigns_service: CampaignsService,
+        quotas_service: QuotasService,
+    ) -> Self {
+        let repository = TemplateRepository::new(database);
+        Self {
+            templates_service: TemplatesService::new(
+                repository,
+                worlds_service,
+                tables_service,
+                campaigns_service,
+                quotas_service,
+            ),
+        }
+    }
+
+    /// Generate the configuration callback needed for the HTTP Server to actually add the World
+    /// Templates endpoints to the server
+    ///
+    /// # Returns
+    /// The callback to provide to the HTTP Server to configure up the World Templates endpoints
+    pub fn configure(&self) -> Arc<dyn Fn(rocket::Rocket) -> rocket::Rocket + Send + Sync> {
+        let templates_service = self.templates_service.clone();
+        Arc::new(move |config| {
+            config.manage(templates_service.clone()).mount(
+                "/",
+                rocket::routes![
+                    super::endpoints::delete_template,
+                    super::endpoints::get_template,
+                    super::endpoints::list_templates,
+                    super::endpoints::publish_template
+                ],
+            )
+        })
+    }
+}
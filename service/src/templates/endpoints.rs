@@ -0,0 +1,11 @@
+mod delete_template;
+pub(crate) mod errors;
+mod get_template;
+mod list_templates;
+pub(crate) mod model;
+mod publish_template;
+
+pub use delete_template::*;
+pub use get_template::*;
+pub use list_templates::*;
+pub use publish_template::*;
@@ -0,0 +1,50 @@
+use super::errors::TemplateProblemType;
+use crate::{
+    authorization::Authorizer,
+    http::problem::Problem,
+    templates::{TemplateReference, TemplatesService},
+};
+use rocket::{delete, http::Status, Response, State};
+
+/// Handler to delete a published World Template. Only the user that published it may do so, and the
+/// bundled templates can not be deleted
+///
+/// # Parameters
+/// - `templates_service` - The templates service to use
+/// - `reference` - The ID of the template to delete
+/// - `authorizer` - The authorizer to prove we're allowed to delete the template
+///
+/// # Returns
+/// An empty response if the template was deleted, or a Problem if the deletion failed
+#[tracing::instrument(name = "DELETE /templates/{id}", skip(templates_service))]
+#[delete("/templates/<reference>")]
+pub fn delete_template(
+    templates_service: State<TemplatesService>,
+    reference: TemplateReference,
+    authorizer: Authorizer,
+) -> Result<Response<'static>, Problem> {
+    let template = templates_service
+        .find_template(&reference)
+        .ok_or_else(|| Problem::new(TemplateProblemType::UnknownTemplate, Status::NotFound))?;
+
+    let (id, owner) = match (template.reference, template.owner) {
+        (TemplateReference::Published(id), Some(owner)) => (id, owner),
+        _ => {
+            return Err(Problem::new(
+                TemplateProblemType::UnknownTemplate,
+                Status::NotFound,
+            ))
+        }
+    };
+
+    authorizer.authorize().same_user(&owner).finish()?;
+
+    if templates_service.delete_template(&id) {
+        Ok(Response::build().status(Status::NoContent).finalize())
+    } else {
+        Err(Problem::new(
+            TemplateProblemType::UnknownTemplate,
+            Status::NotFound,
+        ))
+    }
+}
@@ -0,0 +1,55 @@
+use crate::http::problem::{ProblemType, ValidationType};
+
+/// Problem Types that can happen when working with world templates
+#[derive(Debug, thiserror::Error)]
+pub enum TemplateProblemType {
+    /// An unknown error occurred
+    #[error("An unknown error occurred")]
+    UnknownError,
+
+    /// The template that was looked up was not found
+    #[error("The requested template was unknown")]
+    UnknownTemplate,
+}
+
+impl ProblemType for TemplateProblemType {
+    /// Generate a Type value for the `ProblemType` values.
+    ///
+    /// These are used in the `type` field in the RFC-7807 Problem Response
+    fn error_code(&self) -> &'static str {
+        match self {
+            TemplateProblemType::UnknownError => {
+                "tag:multiverse,2020:templates/problems/unknown_error"
+            }
+            TemplateProblemType::UnknownTemplate => {
+                "tag:multiverse,2020:templates/problems/unknown_template"
+            }
+        }
+    }
+}
+
+/// Validation errors that are specific to world templates
+#[derive(Debug, thiserror::Error)]
+pub enum TemplateValidation {
+    /// The template that was referred to was not a known template
+    #[error("The template was unknown")]
+    UnknownTemplate,
+
+    /// The content of the template could not be created in the new world
+    #[error("The template could not be applied")]
+    InvalidTemplate,
+}
+
+impl ValidationType for TemplateValidation {
+    /// Generate a Type value for the `ValidationType` values.
+    fn error_code(&self) -> &'static str {
+        match self {
+            TemplateValidation::UnknownTemplate => {
+                "tag:multiverse,2020:templates/problems/validation_error/unknown_template"
+            }
+            TemplateValidation::InvalidTemplate => {
+                "tag:multiverse,2020:templates/problems/validation_error/invalid_template"
+            }
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use super::{errors::TemplateProblemType, model::TemplateResponse};
+use crate::{
+    http::problem::Problem,
+    templates::{TemplateReference, TemplatesService},
+};
+use rocket::{get, http::Status, State};
+
+/// Handler to get a single World Template, including all of its content
+///
+/// # Parameters
+/// - `templates_service` - The templates service to use
+/// - `reference` - The name of a bundled template, or the ID of a published one
+///
+/// # Returns
+/// The details of the template
+#[tracing::instrument(name = "GET /templates/{id}", skip(templates_service))]
+#[get("/templates/<reference>")]
+pub fn get_template(
+    templates_service: State<TemplatesService>,
+    reference: TemplateReference,
+) -> Result<TemplateResponse, Problem> {
+    templates_service
+        .find_template(&reference)
+        .map(TemplateResponse)
+        .ok_or_else(|| Problem::new(TemplateProblemType::UnknownTemplate, Status::NotFound))
+}
@@ -0,0 +1,16 @@
+use super::model::TemplatesResponse;
+use crate::templates::TemplatesService;
+use rocket::{get, State};
+
+/// Handler to list the World Templates that new worlds can be created from
+///
+/// # Parameters
+/// - `templates_service` - The templates service to use
+///
+/// # Returns
+/// The bundled templates, followed by those that users have published
+#[tracing::instrument(name = "GET /templates", skip(templates_service))]
+#[get("/templates")]
+pub fn list_templates(templates_service: State<TemplatesService>) -> TemplatesResponse {
+    TemplatesResponse(templates_service.list_templates())
+}
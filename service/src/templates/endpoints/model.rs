@@ -0,0 +1,7 @@
+mod template_link;
+mod template_response;
+mod templates_response;
+
+pub use template_link::*;
+pub use template_response::*;
+pub use templates_response::*;
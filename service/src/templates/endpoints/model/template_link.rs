@@ -0,0 +1,16 @@
+use crate::templates::TemplateReference;
+
+/// Representation of a link to a world template
+pub struct TemplateLink(TemplateReference);
+
+impl TemplateLink {
+    pub fn new(reference: TemplateReference) -> Self {
+        Self(reference)
+    }
+}
+
+impl From<TemplateLink> for String {
+    fn from(template_link: TemplateLink) -> String {
+        format!("/templates/{}", template_link.0)
+    }
+}
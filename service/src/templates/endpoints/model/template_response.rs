@@ -0,0 +1,60 @@
+use super::TemplateLink;
+use crate::{
+    http::link::{Link, LinkRel, Links},
+    templates::WorldTemplate,
+    users::{endpoints::model::UserLink, UsersService},
+};
+use rocket::{
+    http::hyper::header::{CacheControl, CacheDirective},
+    response, Request, State,
+};
+use rocket_contrib::json::Json;
+use serde_json::{json, Value};
+
+/// API Model representing a World Template
+#[derive(Debug)]
+pub struct TemplateResponse(pub WorldTemplate);
+
+/// Build the summary of a template, used both on its own and in lists of templates
+///
+/// # Parameters
+/// - `template` - The template to represent
+///
+/// # Returns
+/// The JSON representation of the template
+pub(super) fn to_json(template: &WorldTemplate) -> Value {
+    json!({
+      "name": template.name,
+      "description": template.description,
+      "bundled": template.owner.is_none(),
+      "tables": template.content.tables.len(),
+      "campaigns": template.content.campaigns.len()
+    })
+}
+
+impl<'r> response::Responder<'r> for TemplateResponse {
+    fn respond_to(self, req: &Request) -> response::Result<'r> {
+        let mut links = vec![Link::new(
+            TemplateLink::new(self.0.reference.clone()),
+            LinkRel::SELF,
+        )];
+        if let Some(owner) = &self.0.owner {
+            let users_service = req.guard::<State<UsersService>>().unwrap();
+            let user = users_service.find_user_by_id(owner);
+            let user_name = user.map(|u| u.data.display_name).unwrap_or_default();
+            links.push(Link::new(UserLink::new(owner.clone()), LinkRel::AUTHOR).title(user_name));
+        }
+
+        let mut body = to_json(&self.0);
+        body["content"] = serde_json::to_value(&self.0.content).unwrap();
+
+        response::Response::build()
+            .merge(Json(body).respond_to(req).unwrap())
+            .header(CacheControl(vec![
+                CacheDirective::Private,
+                CacheDirective::MaxAge(3600),
+            ]))
+            .header(Links(links))
+            .ok()
+    }
+}
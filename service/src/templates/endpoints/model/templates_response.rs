@@ -0,0 +1,76 @@
+use super::{to_json, TemplateLink};
+use crate::{
+    http::link::{Link, LinkRel, Links},
+    templates::WorldTemplate,
+    users::{endpoints::model::UserLink, UserID, UserModel, UsersService},
+};
+use itertools::*;
+use rocket::{
+    http::hyper::header::{CacheControl, CacheDirective},
+    response, Request, State,
+};
+use rocket_contrib::json::Json;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+
+/// API Model representing a list of World Templates
+#[derive(Debug)]
+pub struct TemplatesResponse(pub Vec<WorldTemplate>);
+
+impl<'r> response::Responder<'r> for TemplatesResponse {
+    fn respond_to(self, req: &Request) -> response::Result<'r> {
+        let users_service = req.guard::<State<UsersService>>().unwrap();
+
+        let user_ids: Vec<&UserID> = self
+            .0
+            .iter()
+            .filter_map(|template| template.owner.as_ref())
+            .unique()
+            .collect();
+        let users: HashMap<UserID, UserModel> = if user_ids.is_empty() {
+            HashMap::new()
+        } else {
+            users_service
+                .find_users_by_id(&user_ids[..])
+                .into_iter()
+                .map(|user| (user.identity.id.clone(), user))
+                .collect()
+        };
+
+        let templates: Vec<Value> = self.0.iter().map(to_json).collect();
+
+        let mut links: Vec<Link> = self
+            .0
+            .iter()
+            .enumerate()
+            .map(|(index, template)| {
+                Link::new(TemplateLink::new(template.reference.clone()), LinkRel::ITEM)
+                    .anchor(format!("#/entries/{}", index))
+            })
+            .collect();
+        links.extend(self.0.iter().enumerate().filter_map(|(index, template)| {
+            template
+                .owner
+                .as_ref()
+                .and_then(|owner| users.get(owner))
+                .map(|user| {
+                    Link::new(UserLink::new(user.identity.id.clone()), LinkRel::AUTHOR)
+                        .anchor(format!("#/entries/{}", index))
+                        .title(user.data.display_name.clone())
+                })
+        }));
+
+        response::Response::build()
+            .merge(
+                Json(json!({ "entries": templates }))
+                    .respond_to(req)
+                    .unwrap(),
+            )
+            .header(CacheControl(vec![
+                CacheDirective::Private,
+                CacheDirective::MaxAge(3600),
+            ]))
+            .header(Links(links))
+            .ok()
+    }
+}
@@ -0,0 +1,100 @@
+use super::{errors::TemplateProblemType, model::TemplateResponse};
+use crate::{
+    authorization::Authorizer,
+    http::problem::{GenericValidation, Problem, ValidationProblem},
+    templates::{PublishTemplateError, TemplatesService},
+    worlds::{
+        endpoints::{errors::WorldValidation, world_owner::authorize_world_owner},
+        WorldID, WorldsService,
+    },
+};
+use rocket::{http::Status, post, State};
+use rocket_contrib::json::Json;
+use serde::Deserialize;
+use uuid::Uuid;
+
+/// Handler to publish a new World Template, made up of the published content of one of the current
+/// user's worlds
+///
+/// # Parameters
+/// - `templates_service` - The templates service to use
+/// - `worlds_service` - The worlds service to use
+/// - `body` - The details of the template to publish
+/// - `authorizer` - The authorizer to prove we're allowed to publish the template
+///
+/// # Returns
+/// The newly published template, or a Problem if publishing failed
+#[tracing::instrument(name = "POST /templates", skip(templates_service, worlds_service))]
+#[post("/templates", data = "<body>")]
+pub fn publish_template(
+    templates_service: State<TemplatesService>,
+    worlds_service: State<WorldsService>,
+    body: Json<PublishTemplateRequest>,
+    authorizer: Authorizer,
+) -> Result<TemplateResponse, Problem> {
+    let owner = authorizer
+        .authorize()
+        .authorized()
+        .finish()?
+        .expect("No authenticated user");
+
+    let mut problem = ValidationProblem::new();
+    let name = body.name.clone().filter(|v| !v.trim().is_empty());
+    if name.is_none() {
+        problem.with_field_error("name", GenericValidation::Missing);
+    }
+    let world = match body.world.as_ref().filter(|v| !v.trim().is_empty()) {
+        None => {
+            problem.with_field_error("world", GenericValidation::Missing);
+            None
+        }
+        Some(world) => {
+            let world = Uuid::parse_str(world.trim().trim_start_matches("/worlds/"))
+                .ok()
+                .map(WorldID::new);
+            if world.is_none() {
+                problem.with_field_error("world", WorldValidation::UnknownWorld);
+            }
+            world
+        }
+    };
+
+    match (name, world) {
+        (Some(name), Some(world)) => {
+            let world = authorize_world_owner(&worlds_service, &authorizer, &world)?;
+
+            let template = templates_service.publish_template(
+                &owner,
+                &world,
+                &name,
+                body.description.as_deref().unwrap_or(""),
+            )?;
+            Ok(TemplateResponse(template))
+        }
+        _ => {
+            tracing::warn!("Validation error publishing template");
+            Err(problem.build())
+        }
+    }
+}
+
+/// Incoming details representing a request to publish a new template
+#[derive(Debug, Deserialize)]
+pub struct PublishTemplateRequest {
+    /// The name of the template
+    name: Option<String>,
+    /// The description of the template. May be omitted
+    description: Option<String>,
+    /// The link to the world to copy the content of
+    world: Option<String>,
+}
+
+impl From<PublishTemplateError> for Problem {
+    fn from(e: PublishTemplateError) -> Self {
+        tracing::warn!(error = ?e, "An unexpected error occurred");
+        Problem::new(
+            TemplateProblemType::UnknownError,
+            Status::InternalServerError,
+        )
+    }
+}
@@ -0,0 +1,9 @@
+mod template_content;
+mod template_id;
+mod template_reference;
+mod world_template;
+
+pub use template_content::*;
+pub use template_id::*;
+pub use template_reference::*;
+pub use world_template::*;
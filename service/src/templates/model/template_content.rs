@@ -0,0 +1,78 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+/// The content that a World Template pre-populates a new world with
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TemplateContent {
+    /// The random tables to create
+    #[serde(default)]
+    pub tables: Vec<TemplateTable>,
+
+    /// The campaigns to create
+    #[serde(default)]
+    pub campaigns: Vec<TemplateCampaign>,
+}
+
+/// A random table within a World Template
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TemplateTable {
+    /// The name of the table
+    pub name: String,
+
+    /// A description of the table
+    #[serde(default)]
+    pub description: String,
+
+    /// The URL Slug of the table. If omitted then this is generated from the name
+    #[serde(default)]
+    pub url_slug: Option<String>,
+
+    /// The entries in the table
+    pub entries: Vec<TemplateTableEntry>,
+}
+
+/// A single entry in a random table within a World Template
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TemplateTableEntry {
+    /// The relative weight of this entry when rolling on the table
+    #[serde(default = "default_weight")]
+    pub weight: u32,
+
+    /// The text of the entry
+    pub text: String,
+}
+
+/// A campaign within a World Template
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TemplateCampaign {
+    /// The name of the campaign
+    pub name: String,
+
+    /// A description of the campaign
+    #[serde(default)]
+    pub description: String,
+
+    /// Example sessions of the campaign
+    #[serde(default)]
+    pub sessions: Vec<TemplateSession>,
+}
+
+/// An example session of a campaign within a World Template
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TemplateSession {
+    /// The date that the session was played on
+    pub date: NaiveDate,
+
+    /// The summary of the session, in Markdown
+    #[serde(default)]
+    pub summary: String,
+
+    /// References to the entities that appeared in the session
+    #[serde(default)]
+    pub entities: Vec<String>,
+}
+
+/// The weight of a table entry if none was given
+fn default_weight() -> u32 {
+    1
+}
@@ -0,0 +1,67 @@
+use bytes::BytesMut;
+use postgres_types::{accepts, to_sql_checked, FromSql, IsNull, ToSql, Type};
+use rocket::{http::RawStr, request};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use uuid::Uuid;
+
+/// The ID of a published World Template
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize, FromSql)]
+pub struct TemplateID(Uuid);
+
+impl TemplateID {
+    /// Wrap a UUID as a Template ID object
+    ///
+    /// # Parameters
+    /// - `uuid` - The UUID to wrap
+    ///
+    /// # The Template ID
+    pub fn new(uuid: Uuid) -> Self {
+        TemplateID(uuid)
+    }
+}
+
+impl Default for TemplateID {
+    fn default() -> Self {
+        Self(Uuid::new_v4())
+    }
+}
+
+impl ToSql for TemplateID {
+    accepts!(UUID);
+
+    to_sql_checked!();
+
+    fn to_sql(
+        &self,
+        t: &Type,
+        w: &mut BytesMut,
+    ) -> Result<IsNull, Box<dyn std::error::Error + Sync + Send>> {
+        self.0.to_sql(t, w)
+    }
+}
+
+impl<'r> request::FromParam<'r> for TemplateID {
+    type Error = &'r RawStr;
+
+    fn from_param(param: &'r RawStr) -> Result<Self, Self::Error> {
+        param
+            .percent_decode()
+            .map(|cow| cow.into_owned())
+            .map_err(|_| param)
+            .and_then(|template_id| Uuid::parse_str(&template_id).map_err(|_| param))
+            .map(|template_id| TemplateID::new(template_id))
+    }
+}
+
+impl Display for TemplateID {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<TemplateID> for Uuid {
+    fn from(template_id: TemplateID) -> Self {
+        template_id.0
+    }
+}
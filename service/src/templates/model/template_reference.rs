@@ -0,0 +1,126 @@
+use super::TemplateID;
+use rocket::{http::RawStr, request};
+use std::{
+    fmt::{Display, Formatter},
+    str::FromStr,
+};
+use uuid::Uuid;
+
+/// The prefix of a link to a template, which may be included when referring to one
+const LINK_PREFIX: &str = "/templates/";
+
+/// A reference to a World Template, which is either bundled with the application or published by a user
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum TemplateReference {
+    /// A template that is bundled with the application, identified by its name
+    Bundled(String),
+    /// A template that was published by a user
+    Published(TemplateID),
+}
+
+/// Errors that can occur when parsing a Template Reference
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum TemplateReferenceParseError {
+    /// The Template Reference was blank
+    #[error("The Template Reference was blank")]
+    Blank,
+}
+
+impl FromStr for TemplateReference {
+    type Err = TemplateReferenceParseError;
+
+    /// Parse a reference to a template. This is either the name of a bundled template or the ID of a
+    /// published one, optionally written as a link - e.g. `/templates/fantasy`
+    ///
+    /// # Parameters
+    /// - `s` - The input string to parse
+    ///
+    /// # Returns
+    /// The parsed reference
+    ///
+    /// # Errors
+    /// If the input was blank
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let s = s.strip_prefix(LINK_PREFIX).unwrap_or(s);
+
+        if s.is_empty() {
+            Err(TemplateReferenceParseError::Blank)
+        } else if let Ok(id) = Uuid::parse_str(s) {
+            Ok(TemplateReference::Published(TemplateID::new(id)))
+        } else {
+            Ok(TemplateReference::Bundled(s.to_owned()))
+        }
+    }
+}
+
+impl<'r> request::FromParam<'r> for TemplateReference {
+    type Error = &'r RawStr;
+
+    fn from_param(param: &'r RawStr) -> Result<Self, Self::Error> {
+        param
+            .percent_decode()
+            .map_err(|_| param)
+            .and_then(|reference| reference.parse().map_err(|_| param))
+    }
+}
+
+impl Display for TemplateReference {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TemplateReference::Bundled(name) => write!(f, "{}", name),
+            TemplateReference::Published(id) => write!(f, "{}", id),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_bundled() {
+        assert_eq!(
+            "fantasy".parse(),
+            Ok(TemplateReference::Bundled("fantasy".to_owned()))
+        );
+        assert_eq!(
+            "/templates/fantasy".parse(),
+            Ok(TemplateReference::Bundled("fantasy".to_owned()))
+        );
+    }
+
+    #[test]
+    fn test_parse_published() {
+        let id = Uuid::parse_str("7da4cb77-8839-4805-b93a-f4c536c8bc85").unwrap();
+
+        assert_eq!(
+            "7da4cb77-8839-4805-b93a-f4c536c8bc85".parse(),
+            Ok(TemplateReference::Published(TemplateID::new(id)))
+        );
+        assert_eq!(
+            "/templates/7da4cb77-8839-4805-b93a-f4c536c8bc85".parse(),
+            Ok(TemplateReference::Published(TemplateID::new(id)))
+        );
+    }
+
+    #[test]
+    fn test_parse_blank() {
+        assert_eq!(
+            "  ".parse::<TemplateReference>(),
+            Err(TemplateReferenceParseError::Blank)
+        );
+        assert_eq!(
+            "/templates/".parse::<TemplateReference>(),
+            Err(TemplateReferenceParseError::Blank)
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            TemplateReference::Bundled("fantasy".to_owned()).to_string(),
+            "fantasy"
+        );
+    }
+}
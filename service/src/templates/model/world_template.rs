@@ -0,0 +1,21 @@
+use super::{TemplateContent, TemplateReference};
+use crate::users::UserID;
+
+/// A template that new worlds can be created from, pre-populated with content
+#[derive(Debug, Clone)]
+pub struct WorldTemplate {
+    /// The reference to the template
+    pub reference: TemplateReference,
+
+    /// The user that published the template, or `None` if it is bundled with the application
+    pub owner: Option<UserID>,
+
+    /// The name of the template
+    pub name: String,
+
+    /// A description of the template
+    pub description: String,
+
+    /// The content to create in new worlds
+    pub content: TemplateContent,
+}
@@ -0,0 +1,30 @@
+mod create;
+mod create_world;
+mod delete;
+mod errors;
+mod find;
+mod parse;
+
+use crate::infrastructure::database::Database;
+pub use create_world::{NewTemplatedWorld, TemplatedWorld};
+pub use errors::*;
+
+/// Repository used to access published World Template data from the database
+#[derive(Clone)]
+pub(super) struct TemplateRepository {
+    /// The database with which to access template records
+    database: Database,
+}
+
+impl TemplateRepository {
+    /// Create a new Template Repository to work with
+    ///
+    /// # Parameters
+    /// - `database` - The database connection to use
+    ///
+    /// # Returns
+    /// The Template Repository to use
+    pub(super) fn new(database: Database) -> Self {
+        Self { database }
+    }
+}
@@ -0,0 +1,55 @@
+use super::{SaveTemplateError, TemplateRepository};
+use crate::{
+    templates::{TemplateContent, TemplateID, WorldTemplate},
+    users::UserID,
+};
+use chrono::Utc;
+use postgres_types::Json;
+use uuid::Uuid;
+
+impl TemplateRepository {
+    /// Create a new published template in the database
+    ///
+    /// # Parameters
+    /// - `owner` - The ID of the user that is publishing the template
+    /// - `name` - The name of the template
+    /// - `description` - The description of the template
+    /// - `content` - The content of the template
+    ///
+    /// # Returns
+    /// The newly created template
+    ///
+    /// # Errors
+    /// Any errors that occurred creating the new template
+    #[tracing::instrument(skip(self, content))]
+    pub fn create(
+        &self,
+        owner: &UserID,
+        name: &str,
+        description: &str,
+        content: &TemplateContent,
+    ) -> Result<WorldTemplate, SaveTemplateError> {
+        let id = TemplateID::default();
+        let now = Utc::now();
+        let version = Uuid::new_v4();
+
+        let mut connection = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+
+        let new_template = connection.query_one("INSERT INTO world_templates(template_id, version, created, updated, owner_id, name, description, content) VALUES($1, $2, $3, $3, $4, $5, $6, $7) RETURNING *",
+            &[
+                &id,
+                &version,
+                &now,
+                owner,
+                &name,
+                &description,
+                &Json(content),
+            ])
+            .map(|row| self.parse_row(&row))?;
+
+        Ok(new_template)
+    }
+}
@@ -0,0 +1,122 @@
+use super::{SaveTemplatedWorldError, TemplateRepository};
+use crate::{
+    campaigns::{
+        insert_campaign, insert_session, CampaignData, CampaignID, CampaignModel, SessionData,
+        SessionModel,
+    },
+    events::{publish_change, EntityType, WorldChange},
+    quotas::{enforce_quota, QuotaAllowance},
+    tables::{insert_table, TableData, TableModel},
+    worlds::{insert_world, WorldData, WorldID, WorldModel},
+};
+
+/// Everything that is to be created for a new world that is populated from a template
+#[derive(Debug)]
+pub struct NewTemplatedWorld {
+    /// The ID to give the new world
+    pub id: WorldID,
+    /// The details of the new world
+    pub world: WorldData,
+    /// The random tables to create in the world
+    pub tables: Vec<TableData>,
+    /// The campaigns to create in the world, along with the IDs to give them
+    pub campaigns: Vec<(CampaignID, CampaignData)>,
+    /// The sessions to create in the campaigns
+    pub sessions: Vec<SessionData>,
+}
+
+/// Everything that was created for a new world that was populated from a template
+#[derive(Debug)]
+pub struct TemplatedWorld {
+    /// The new world
+    pub world: WorldModel,
+    /// The random tables that were created in the world
+    pub tables: Vec<TableModel>,
+    /// The campaigns that were created in the world
+    pub campaigns: Vec<CampaignModel>,
+    /// The sessions that were created in the campaigns
+    pub sessions: Vec<SessionModel>,
+}
+
+impl TemplateRepository {
+    /// Create a new world along with all of the content from a template in a single transaction, so
+    /// that either the world is created with all of its content or nothing is created at all. Every quota
+    /// that the content uses is enforced in the same transaction
+    ///
+    /// # Parameters
+    /// - `new_world` - The world and its content to create
+    /// - `quotas` - The allowances for the owner of the world to create all of this
+    ///
+    /// # Returns
+    /// The newly created world and its content
+    ///
+    /// # Errors
+    /// Any errors that occurred creating the world or any of its content
+    #[tracing::instrument(skip(self))]
+    pub fn create_world(
+        &self,
+        new_world: NewTemplatedWorld,
+        quotas: &[QuotaAllowance],
+    ) -> Result<TemplatedWorld, SaveTemplatedWorldError> {
+        let mut connection = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+        let mut transaction = connection.transaction()?;
+
+        for quota in quotas {
+            enforce_quota(&mut transaction, quota)?;
+        }
+
+        let world = insert_world(&mut transaction, &new_world.id, &new_world.world)?;
+
+        let mut tables = vec![];
+        for table in &new_world.tables {
+            tables.push(insert_table(&mut transaction, table)?);
+        }
+
+        let mut campaigns = vec![];
+        for (id, campaign) in &new_world.campaigns {
+            campaigns.push(insert_campaign(&mut transaction, id, campaign)?);
+        }
+
+        let mut sessions = vec![];
+        for session in &new_world.sessions {
+            sessions.push(insert_session(&mut transaction, session)?);
+        }
+
+        transaction.commit()?;
+
+        let changes: Vec<WorldChange> = tables
+            .iter()
+            .map(|table| WorldChange {
+                world: world.identity.id.clone(),
+                entity_type: EntityType::Table,
+                entity_id: table.identity.id.clone().into(),
+                version: Some(table.identity.version),
+            })
+            .chain(campaigns.iter().map(|campaign| WorldChange {
+                world: world.identity.id.clone(),
+                entity_type: EntityType::Campaign,
+                entity_id: campaign.identity.id.clone().into(),
+                version: Some(campaign.identity.version),
+            }))
+            .chain(sessions.iter().map(|session| WorldChange {
+                world: world.identity.id.clone(),
+                entity_type: EntityType::Session,
+                entity_id: session.identity.id.clone().into(),
+                version: Some(session.identity.version),
+            }))
+            .collect();
+        for change in changes {
+            publish_change(&mut connection, change);
+        }
+
+        Ok(TemplatedWorld {
+            world,
+            tables,
+            campaigns,
+            sessions,
+        })
+    }
+}
@@ -0,0 +1,25 @@
+use super::TemplateRepository;
+use crate::templates::TemplateID;
+
+impl TemplateRepository {
+    /// Delete a published template from the database
+    ///
+    /// # Parameters
+    /// - `id` - The ID of the template to delete
+    ///
+    /// # Returns
+    /// True if the template was deleted. False if it didn't exist
+    #[tracing::instrument(skip(self))]
+    pub fn delete(&self, id: &TemplateID) -> bool {
+        let mut connection = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+
+        let deleted = connection
+            .execute("DELETE FROM world_templates WHERE template_id = $1", &[id])
+            .expect("Failed to delete template");
+
+        deleted == 1
+    }
+}
@@ -0,0 +1,121 @@
+use crate::{
+    campaigns::{SaveCampaignError, SaveSessionError},
+    quotas::QuotaExceededError,
+    tables::SaveTableError,
+    worlds::SaveWorldError,
+};
+use postgres::error::{DbError, SqlState};
+
+/// Errors that can occur when saving a template record
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum SaveTemplateError {
+    /// An unknown error occurred
+    #[error("An unknown error occurred")]
+    UnknownError,
+
+    /// The user that is publishing the template does not exist
+    #[error("The desired owner does not exist")]
+    UnknownOwner,
+}
+
+impl From<postgres::Error> for SaveTemplateError {
+    /// Convert a database error into a SaveTemplateError.
+    ///
+    /// The conversion returns an `UnknownOwner` iff the error is a `FOREIGN_KEY_VIOLATION` and the constraint
+    /// is `world_templates_owner_id_fkey`. Otherwise it returns an `UnknownError`
+    ///
+    /// # Parameters
+    /// - `e` - The error to convert
+    ///
+    /// # Returns
+    /// The new error code
+    fn from(e: postgres::Error) -> Self {
+        if e.code() == Some(&SqlState::FOREIGN_KEY_VIOLATION) {
+            let db_error: Option<DbError> = e
+                .into_source()
+                .and_then(|e| e.downcast_ref::<DbError>().cloned());
+
+            match db_error.as_ref().and_then(|e| e.constraint()) {
+                Some("world_templates_owner_id_fkey") => SaveTemplateError::UnknownOwner,
+                constraint => {
+                    tracing::warn!(
+                        "Unexpected foreign key constraint violation error: {:?}",
+                        constraint
+                    );
+                    SaveTemplateError::UnknownError
+                }
+            }
+        } else {
+            tracing::warn!("Unexpected database error: {:?}", e);
+            SaveTemplateError::UnknownError
+        }
+    }
+}
+
+/// Errors that can occur when saving a new world along with the content of a template
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum SaveTemplatedWorldError {
+    /// An unknown error occurred
+    #[error("An unknown error occurred")]
+    UnknownError,
+
+    /// The URL Slug of the world was already used by another world of the same owner
+    #[error("The URL Slug was already present for this user")]
+    DuplicateUrlSlug,
+
+    /// The owner of the world does not exist
+    #[error("The desired owner does not exist")]
+    UnknownOwner,
+
+    /// The content of the template could not be saved, for example because two tables have the same
+    /// URL Slug
+    #[error("The content of the template was invalid")]
+    InvalidContent,
+
+    /// The owner of the world has reached one of their quotas
+    #[error("The owner has reached their quota")]
+    QuotaExceeded(#[from] QuotaExceededError),
+}
+
+impl From<postgres::Error> for SaveTemplatedWorldError {
+    fn from(e: postgres::Error) -> Self {
+        tracing::warn!("Unexpected database error: {:?}", e);
+        SaveTemplatedWorldError::UnknownError
+    }
+}
+
+impl From<SaveWorldError> for SaveTemplatedWorldError {
+    fn from(e: SaveWorldError) -> Self {
+        match e {
+            SaveWorldError::DuplicateUrlSlug => SaveTemplatedWorldError::DuplicateUrlSlug,
+            SaveWorldError::UnknownOwner => SaveTemplatedWorldError::UnknownOwner,
+            SaveWorldError::QuotaExceeded(e) => SaveTemplatedWorldError::QuotaExceeded(e),
+            _ => SaveTemplatedWorldError::UnknownError,
+        }
+    }
+}
+
+impl From<SaveTableError> for SaveTemplatedWorldError {
+    fn from(e: SaveTableError) -> Self {
+        match e {
+            SaveTableError::DuplicateUrlSlug => SaveTemplatedWorldError::InvalidContent,
+            SaveTableError::QuotaExceeded(e) => SaveTemplatedWorldError::QuotaExceeded(e),
+            _ => SaveTemplatedWorldError::UnknownError,
+        }
+    }
+}
+
+impl From<SaveCampaignError> for SaveTemplatedWorldError {
+    fn from(e: SaveCampaignError) -> Self {
+        match e {
+            SaveCampaignError::QuotaExceeded(e) => SaveTemplatedWorldError::QuotaExceeded(e),
+            _ => SaveTemplatedWorldError::UnknownError,
+        }
+    }
+}
+
+impl From<SaveSessionError> for SaveTemplatedWorldError {
+    fn from(_: SaveSessionError) -> Self {
+        SaveTemplatedWorldError::UnknownError
+    }
+}
@@ -0,0 +1,49 @@
+use super::TemplateRepository;
+use crate::templates::{TemplateID, WorldTemplate};
+
+impl TemplateRepository {
+    /// Find a published template in the database by its unique ID
+    ///
+    /// # Parameters
+    /// - `id` - The ID of the template
+    ///
+    /// # Returns
+    /// The template, or `None` if it wasn't found
+    #[tracing::instrument(skip(self))]
+    pub fn find_template_by_id(&self, id: &TemplateID) -> Option<WorldTemplate> {
+        let mut connection = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+
+        connection
+            .query_opt(
+                "SELECT * FROM world_templates WHERE template_id = $1",
+                &[id],
+            )
+            .expect("Failed to query for template by ID")
+            .map(|row| self.parse_row(&row))
+    }
+
+    /// Find every published template
+    ///
+    /// # Returns
+    /// The templates, ordered by name
+    #[tracing::instrument(skip(self))]
+    pub fn find_templates(&self) -> Vec<WorldTemplate> {
+        let mut connection = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+
+        connection
+            .query(
+                "SELECT * FROM world_templates ORDER BY name, template_id",
+                &[],
+            )
+            .expect("Failed to query for templates")
+            .iter()
+            .map(|row| self.parse_row(row))
+            .collect()
+    }
+}
@@ -0,0 +1,25 @@
+use super::TemplateRepository;
+use crate::templates::model::*;
+use postgres::row::Row;
+use postgres_types::Json;
+
+impl TemplateRepository {
+    /// Parse a row from the database into a World Template
+    ///
+    /// # Parameters
+    /// - `row` - The row to parse
+    ///
+    /// # Returns
+    /// The template that the row represented
+    pub(super) fn parse_row(&self, row: &Row) -> WorldTemplate {
+        let content: Json<TemplateContent> = row.get("content");
+
+        WorldTemplate {
+            reference: TemplateReference::Published(row.get("template_id")),
+            owner: Some(row.get("owner_id")),
+            name: row.get("name"),
+            description: row.get("description"),
+            content: content.0,
+        }
+    }
+}
@@ -0,0 +1,52 @@
+mod apply;
+mod bundled;
+mod delete;
+mod find;
+mod publish;
+
+use super::repository::TemplateRepository;
+use crate::{
+    campaigns::CampaignsService, quotas::QuotasService, tables::TablesService,
+    worlds::WorldsService,
+};
+pub use apply::ApplyTemplateError;
+pub use publish::PublishTemplateError;
+
+/// Service Layer for dealing with World Templates
+#[derive(Clone)]
+pub struct TemplatesService {
+    repository: TemplateRepository,
+    worlds: WorldsService,
+    tables: TablesService,
+    campaigns: CampaignsService,
+    quotas: QuotasService,
+}
+
+impl TemplatesService {
+    /// Create a new instance of the Templates Service
+    ///
+    /// # Parameters
+    /// - `repository` - The repository of published templates
+    /// - `worlds` - The worlds service to announce newly created worlds with
+    /// - `tables` - The tables service to read and announce random tables with
+    /// - `campaigns` - The campaigns service to read and announce campaigns with
+    /// - `quotas` - The quotas service to check that the content of a template can be created
+    ///
+    /// # Returns
+    /// The Templates Service ready to use
+    pub(super) fn new(
+        repository: TemplateRepository,
+        worlds: WorldsService,
+        tables: TablesService,
+        campaigns: CampaignsService,
+        quotas: QuotasService,
+    ) -> Self {
+        Self {
+            repository,
+            worlds,
+            tables,
+            campaigns,
+            quotas,
+        }
+    }
+}
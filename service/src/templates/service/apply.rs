@@ -0,0 +1,209 @@
+use super::TemplatesService;
+use crate::{
+    campaigns::{CampaignData, CampaignID, SessionData},
+    model::Publication,
+    quotas::{QuotaExceededError, QuotaResource},
+    tables::{TableData, TableEntry},
+    templates::{
+        repository::{NewTemplatedWorld, SaveTemplatedWorldError},
+        WorldTemplate,
+    },
+    worlds::{UrlSlug, WorldData, WorldID, WorldModel},
+};
+use str_slug::slug;
+
+/// Errors that can occur when creating a new world from a template
+#[derive(Debug, thiserror::Error)]
+pub enum ApplyTemplateError {
+    /// An unknown error occurred
+    #[error("An unknown error occurred")]
+    UnknownError,
+
+    #[error("The URL Slug was already present for this user")]
+    DuplicateUrlSlug,
+
+    #[error("The desired owner does not exist")]
+    UnknownOwner,
+
+    /// The content of the template could not be created in the new world
+    #[error("The content of the template was invalid")]
+    InvalidTemplate,
+
+    #[error("The owner has reached their quota")]
+    QuotaExceeded(#[from] QuotaExceededError),
+}
+
+/// Placeholder used in the entity references of templates in place of the world that the template is
+/// applied to, so that references to the content of the template still work in the new world
+pub(super) const WORLD_PLACEHOLDER: &str = "/worlds/{world}/";
+
+/// Replace the world placeholder in an entity reference with a real world
+///
+/// # Parameters
+/// - `entity` - The entity reference
+/// - `world` - The world to refer to
+///
+/// # Returns
+/// The updated entity reference
+fn resolve_entity(entity: &str, world: &WorldID) -> String {
+    entity.replace(WORLD_PLACEHOLDER, &format!("/worlds/{}/", world))
+}
+
+impl TemplatesService {
+    /// Create a new world populated with the content of a template.
+    ///
+    /// The world and all of its content are created together, so if any of it can't be created - for
+    /// example because the owner of the world has reached one of their quotas - then none of it is created, not even the world
+    ///
+    /// # Parameters
+    /// - `world` - The world to create
+    /// - `template` - The template to populate the world from
+    ///
+    /// # Returns
+    /// The newly created world
+    ///
+    /// # Errors
+    /// Any errors that occurred creating the world or its content
+    pub fn create_world(
+        &self,
+        world: WorldData,
+        template: &WorldTemplate,
+    ) -> Result<WorldModel, ApplyTemplateError> {
+        tracing::debug!(template = ?template.reference, world = ?world, "Creating world from template");
+
+        let id = WorldID::default();
+
+        let mut tables = vec![];
+        for table in &template.content.tables {
+            let url_slug = table
+                .url_slug
+                .clone()
+                .unwrap_or_else(|| slug(&table.name))
+                .parse::<UrlSlug>()
+                .map_err(|e| {
+                    tracing::warn!(e = ?e, table = ?table, "Invalid URL Slug for template table");
+                    ApplyTemplateError::InvalidTemplate
+                })?;
+
+            tables.push(TableData {
+                world: id.clone(),
+                name: table.name.clone(),
+                description: table.description.clone(),
+                url_slug,
+                entries: table
+                    .entries
+                    .iter()
+                    .map(|entry| TableEntry {
+                        weight: entry.weight,
+                        text: entry.text.clone(),
+                    })
+                    .collect(),
+                publication: Publication::default(),
+            });
+        }
+
+        let mut campaigns = vec![];
+        let mut sessions = vec![];
+        for campaign in &template.content.campaigns {
+            let campaign_id = CampaignID::default();
+            for session in &campaign.sessions {
+                sessions.push(SessionData {
+                    campaign: campaign_id.clone(),
+                    date: session.date,
+                    summary: session.summary.clone(),
+                    entities: session
+                        .entities
+                        .iter()
+                        .map(|entity| resolve_entity(entity, &id))
+                        .collect(),
+                });
+            }
+            campaigns.push((
+                campaign_id,
+                CampaignData {
+                    world: id.clone(),
+                    name: campaign.name.clone(),
+                    description: campaign.description.clone(),
+                    players: vec![],
+                    publication: Publication::default(),
+                },
+            ));
+        }
+
+        let mut quotas = vec![self
+            .quotas
+            .check_quota(&world.owner, QuotaResource::Worlds, 1)?];
+        if !tables.is_empty() {
+            quotas.push(self.quotas.check_quota(
+                &world.owner,
+                QuotaResource::Tables,
+                tables.len() as u64,
+            )?);
+        }
+        if !campaigns.is_empty() {
+            quotas.push(self.quotas.check_quota(
+                &world.owner,
+                QuotaResource::Campaigns,
+                campaigns.len() as u64,
+            )?);
+        }
+
+        let created = self.repository.create_world(
+            NewTemplatedWorld {
+                id,
+                world,
+                tables,
+                campaigns,
+                sessions,
+            },
+            &quotas,
+        )?;
+        tracing::debug!(world = ?created.world, "Created world from template");
+
+        self.worlds.world_created(&created.world);
+        for table in &created.tables {
+            self.tables.table_created(table);
+        }
+        for campaign in &created.campaigns {
+            self.campaigns.campaign_created(campaign);
+        }
+        for session in &created.sessions {
+            self.campaigns
+                .session_created(session, &created.world.identity.id);
+        }
+
+        Ok(created.world)
+    }
+}
+
+impl From<SaveTemplatedWorldError> for ApplyTemplateError {
+    fn from(e: SaveTemplatedWorldError) -> Self {
+        match e {
+            SaveTemplatedWorldError::DuplicateUrlSlug => ApplyTemplateError::DuplicateUrlSlug,
+            SaveTemplatedWorldError::UnknownOwner => ApplyTemplateError::UnknownOwner,
+            SaveTemplatedWorldError::InvalidContent => ApplyTemplateError::InvalidTemplate,
+            SaveTemplatedWorldError::QuotaExceeded(e) => ApplyTemplateError::QuotaExceeded(e),
+            SaveTemplatedWorldError::UnknownError => ApplyTemplateError::UnknownError,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    #[test]
+    fn test_resolve_entity() {
+        let world = WorldID::new(Uuid::parse_str("7da4cb77-8839-4805-b93a-f4c536c8bc85").unwrap());
+
+        assert_eq!(
+            resolve_entity("/worlds/{world}/tables/tavern-names", &world),
+            "/worlds/7da4cb77-8839-4805-b93a-f4c536c8bc85/tables/tavern-names"
+        );
+        assert_eq!(
+            resolve_entity("/characters/queen-ileosa", &world),
+            "/characters/queen-ileosa"
+        );
+    }
+}
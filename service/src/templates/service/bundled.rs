@@ -0,0 +1,95 @@
+use crate::templates::{TemplateContent, TemplateReference, WorldTemplate};
+use rust_embed::RustEmbed;
+use serde::Deserialize;
+
+/// The World Templates that are bundled with the application
+#[derive(RustEmbed)]
+#[folder = "templates/worlds/"]
+struct BundledTemplates;
+
+/// The file extension of the bundled templates
+const EXTENSION: &str = ".yaml";
+
+/// The structure of a bundled template file
+#[derive(Debug, Deserialize)]
+struct BundledTemplate {
+    /// The name of the template
+    name: String,
+    /// A description of the template
+    #[serde(default)]
+    description: String,
+    /// The content of the template
+    #[serde(flatten)]
+    content: TemplateContent,
+}
+
+/// Load a single bundled template
+///
+/// # Parameters
+/// - `name` - The name of the template, which is the name of the file without the extension
+///
+/// # Returns
+/// The template, or `None` if there is no bundled template with this name
+pub(super) fn load(name: &str) -> Option<WorldTemplate> {
+    let contents = BundledTemplates::get(&format!("{}{}", name, EXTENSION))?;
+    let template: BundledTemplate =
+        serde_yaml::from_slice(&contents).expect("Bundled template was not valid");
+
+    Some(WorldTemplate {
+        reference: TemplateReference::Bundled(name.to_owned()),
+        owner: None,
+        name: template.name,
+        description: template.description,
+        content: template.content,
+    })
+}
+
+/// Load every bundled template
+///
+/// # Returns
+/// The templates, ordered by the name of the file
+pub(super) fn load_all() -> Vec<WorldTemplate> {
+    let mut names: Vec<String> = BundledTemplates::iter()
+        .filter_map(|file| file.strip_suffix(EXTENSION).map(str::to_owned))
+        .collect();
+    names.sort();
+
+    names.iter().filter_map(|name| load(name)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tables::Template, worlds::UrlSlug};
+
+    #[test]
+    fn test_load_unknown() {
+        assert!(load("unknown").is_none());
+    }
+
+    #[test]
+    fn test_load_all() {
+        let templates = load_all();
+
+        assert!(!templates.is_empty());
+        assert_eq!(
+            templates[0].reference,
+            TemplateReference::Bundled("fantasy".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_bundled_tables_are_valid() {
+        for template in load_all() {
+            for table in template.content.tables {
+                let url_slug = table.url_slug.expect("Bundled tables need a URL Slug");
+                assert!(url_slug.parse::<UrlSlug>().is_ok(), "{}", url_slug);
+                assert!(!table.entries.is_empty(), "{}", table.name);
+
+                for entry in table.entries {
+                    assert!(entry.text.parse::<Template>().is_ok(), "{}", entry.text);
+                }
+            }
+        }
+    }
+}
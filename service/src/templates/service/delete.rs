@@ -0,0 +1,15 @@
+use super::TemplatesService;
+use crate::templates::TemplateID;
+
+impl TemplatesService {
+    /// Delete a published template. Worlds that were created from it are unaffected
+    ///
+    /// # Parameters
+    /// - `id` - The ID of the template to delete
+    ///
+    /// # Returns
+    /// True if the template was deleted. False if it didn't exist
+    pub fn delete_template(&self, id: &TemplateID) -> bool {
+        self.repository.delete(id)
+    }
+}
@@ -0,0 +1,28 @@
+use super::{bundled, TemplatesService};
+use crate::templates::{TemplateReference, WorldTemplate};
+
+impl TemplatesService {
+    /// Find a single template
+    ///
+    /// # Parameters
+    /// - `reference` - The reference to the template
+    ///
+    /// # Returns
+    /// The template, or `None` if it wasn't found
+    pub fn find_template(&self, reference: &TemplateReference) -> Option<WorldTemplate> {
+        match reference {
+            TemplateReference::Bundled(name) => bundled::load(name),
+            TemplateReference::Published(id) => self.repository.find_template_by_id(id),
+        }
+    }
+
+    /// List every template that worlds can be created from
+    ///
+    /// # Returns
+    /// The bundled templates, followed by the templates that users have published
+    pub fn list_templates(&self) -> Vec<WorldTemplate> {
+        let mut templates = bundled::load_all();
+        templates.extend(self.repository.find_templates());
+        templates
+    }
+}
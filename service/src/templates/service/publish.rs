@@ -0,0 +1,120 @@
+use super::{apply::WORLD_PLACEHOLDER, TemplatesService};
+use crate::{
+    model::fetch_all_pages,
+    templates::{
+        repository::SaveTemplateError, TemplateCampaign, TemplateContent, TemplateSession,
+        TemplateTable, TemplateTableEntry, WorldTemplate,
+    },
+    users::UserID,
+    worlds::WorldModel,
+};
+
+/// Errors that can occur when publishing a template
+#[derive(Debug, thiserror::Error)]
+pub enum PublishTemplateError {
+    /// An unknown error occurred
+    #[error("An unknown error occurred")]
+    UnknownError,
+}
+
+impl TemplatesService {
+    /// Publish a new template, made up of the published content of an existing world
+    ///
+    /// # Parameters
+    /// - `owner` - The user that is publishing the template
+    /// - `world` - The world to copy the content of
+    /// - `name` - The name of the template
+    /// - `description` - The description of the template
+    ///
+    /// # Returns
+    /// The newly published template
+    ///
+    /// # Errors
+    /// Any errors that occurred publishing the template
+    pub fn publish_template(
+        &self,
+        owner: &UserID,
+        world: &WorldModel,
+        name: &str,
+        description: &str,
+    ) -> Result<WorldTemplate, PublishTemplateError> {
+        let content = self.snapshot(world);
+        tracing::debug!(world = ?world.identity.id, content = ?content, "Publishing template");
+
+        let template = self.repository.create(owner, name, description, &content)?;
+        tracing::debug!(template = ?template.reference, "Published template");
+
+        Ok(template)
+    }
+
+    /// Copy the published content of a world into the content of a template.
+    ///
+    /// References from sessions to content within the world itself are rewritten to refer to whichever
+    /// world the template is later applied to
+    ///
+    /// # Parameters
+    /// - `world` - The world to copy the content of
+    ///
+    /// # Returns
+    /// The template content
+    fn snapshot(&self, world: &WorldModel) -> TemplateContent {
+        let world_prefix = format!("/worlds/{}/", world.identity.id);
+
+        let tables = fetch_all_pages(|pagination| {
+            self.tables
+                .list_tables(&world.identity.id, false, pagination)
+        });
+
+        let campaigns = self
+            .campaigns
+            .find_campaigns_in_world(&world.identity.id, false)
+            .into_iter()
+            .map(|campaign| TemplateCampaign {
+                sessions: self
+                    .campaigns
+                    .find_campaign_sessions(&campaign.identity.id)
+                    .into_iter()
+                    .map(|session| TemplateSession {
+                        date: session.data.date,
+                        summary: session.data.summary,
+                        entities: session
+                            .data
+                            .entities
+                            .iter()
+                            .map(|entity| entity.replace(&world_prefix, WORLD_PLACEHOLDER))
+                            .collect(),
+                    })
+                    .collect(),
+                name: campaign.data.name,
+                description: campaign.data.description,
+            })
+            .collect();
+
+        TemplateContent {
+            tables: tables
+                .into_iter()
+                .map(|table| TemplateTable {
+                    name: table.data.name,
+                    description: table.data.description,
+                    url_slug: Some(table.data.url_slug.to_string()),
+                    entries: table
+                        .data
+                        .entries
+                        .into_iter()
+                        .map(|entry| TemplateTableEntry {
+                            weight: entry.weight,
+                            text: entry.text,
+                        })
+                        .collect(),
+                })
+                .collect(),
+            campaigns,
+        }
+    }
+}
+
+impl From<SaveTemplateError> for PublishTemplateError {
+    fn from(_: SaveTemplateError) -> Self {
+        PublishTemplateError::UnknownError
+    }
+}
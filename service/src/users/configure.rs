@@ -1,5 +1,5 @@
 use super::{repository::UserRepository, UsersService};
-use crate::infrastructure::database::Database;
+use crate::infrastructure::{blobs::SharedBlobStore, database::Database};
 use std::sync::Arc;
 
 /// Application Configuration for the Users module
@@ -10,12 +10,17 @@ pub struct UsersConfig {
 impl UsersConfig {
     /// Create the Configuration for the Users Module
     ///
+    /// # Parameters
+    /// - `database` - The database connection to use
+    /// - `blobs` - The blob store to keep uploaded avatars in
+    /// - `allow_external_avatars` - Whether users may use avatars that are hosted elsewhere
+    ///
     /// # Returns
     /// The Users Config object
-    pub fn new(database: Database) -> Self {
+    pub fn new(database: Database, blobs: SharedBlobStore, allow_external_avatars: bool) -> Self {
         let repository = UserRepository::new(database);
         Self {
-            users_service: UsersService::new(repository),
+            users_service: UsersService::new(repository, blobs, allow_external_avatars),
         }
     }
 
@@ -30,9 +35,12 @@ impl UsersConfig {
             config.manage(users_service.clone()).mount(
                 "/",
                 rocket::routes![
+                    super::endpoints::delete_avatar,
+                    super::endpoints::get_avatar,
                     super::endpoints::lookup_user,
                     super::endpoints::patch_user,
-                    super::endpoints::lookup_username
+                    super::endpoints::lookup_username,
+                    super::endpoints::put_avatar
                 ],
             )
         })
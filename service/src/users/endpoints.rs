@@ -1,9 +1,15 @@
-mod errors;
+mod delete_avatar;
+pub(crate) mod errors;
+mod get_avatar;
 mod lookup_user;
 mod lookup_username;
 pub(crate) mod model;
 mod patch_user;
+mod put_avatar;
 
+pub use delete_avatar::*;
+pub use get_avatar::*;
 pub use lookup_user::*;
 pub use lookup_username::*;
 pub use patch_user::*;
+pub use put_avatar::*;
@@ -0,0 +1,31 @@
+use super::model::UserResponse;
+use crate::{
+    authorization::Authorizer,
+    http::problem::Problem,
+    users::{UserID, UsersService},
+};
+use rocket::{delete, State};
+
+/// Handler to remove the avatar of a user, whether it was uploaded or hosted elsewhere
+///
+/// # Parameters
+/// - `users_service` - The users service to use
+/// - `id` - The ID of the user
+/// - `authorizer` - The authorizer to prove we're allowed to change the user
+///
+/// # Returns
+/// The updated details of the user
+#[tracing::instrument(name = "DELETE /users/{id}/avatar", skip(users_service))]
+#[delete("/users/<id>/avatar")]
+pub fn delete_avatar(
+    users_service: State<UsersService>,
+    id: UserID,
+    authorizer: Authorizer,
+) -> Result<UserResponse, Problem> {
+    authorizer.authorize().same_user(&id).finish()?;
+
+    users_service
+        .clear_avatar(&id)
+        .map(|user| user.into())
+        .map_err(|e| e.into())
+}
@@ -1,4 +1,4 @@
-use crate::http::problem::ProblemType;
+use crate::http::problem::{ProblemType, ValidationType};
 
 /// Problem Types that can happen when working with users
 #[derive(Debug, thiserror::Error)]
@@ -14,6 +14,18 @@ pub enum UserProblemType {
     /// Changing a password without providing the correct current password
     #[error("Old Password incorrect when changing password")]
     InvalidOldPassword,
+
+    /// The user does not have an uploaded avatar
+    #[error("The requested avatar was unknown")]
+    UnknownAvatar,
+
+    /// The uploaded avatar was not an image that could be used
+    #[error("The avatar was not a valid image")]
+    InvalidAvatar,
+
+    /// An unknown error occurred
+    #[error("An unknown error occurred")]
+    UnknownError,
 }
 
 impl ProblemType for UserProblemType {
@@ -29,6 +41,35 @@ impl ProblemType for UserProblemType {
             UserProblemType::InvalidOldPassword => {
                 "tag:multiverse,2020:users/problems/invalid_old_password"
             }
+            UserProblemType::UnknownAvatar => "tag:multiverse,2020:users/problems/unknown_avatar",
+            UserProblemType::InvalidAvatar => "tag:multiverse,2020:users/problems/invalid_avatar",
+            UserProblemType::UnknownError => "tag:multiverse,2020:users/problems/unknown_error",
+        }
+    }
+}
+
+/// Validation errors that are specific to users
+#[derive(Debug, thiserror::Error)]
+pub enum UserValidation {
+    /// The avatar URL is hosted somewhere other than this server, and that isn't allowed
+    #[error("The avatar URL must be uploaded to this server")]
+    ExternalAvatarUrl,
+
+    /// The uploaded avatar had more pixels than are allowed
+    #[error("The avatar image was too large")]
+    AvatarTooLarge,
+}
+
+impl ValidationType for UserValidation {
+    /// Generate a Type value for the `ValidationType` values.
+    fn error_code(&self) -> &'static str {
+        match self {
+            UserValidation::ExternalAvatarUrl => {
+                "tag:multiverse,2020:users/problems/validation_error/external_avatar_url"
+            }
+            UserValidation::AvatarTooLarge => {
+                "tag:multiverse,2020:users/problems/validation_error/avatar_too_large"
+            }
         }
     }
 }
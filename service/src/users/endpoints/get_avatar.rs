@@ -0,0 +1,34 @@
+use super::errors::UserProblemType;
+use crate::{
+    http::{blob::BlobResponse, problem::Problem},
+    users::{UserID, UsersService, DEFAULT_AVATAR_SIZE},
+};
+use rocket::{get, http::Status, State};
+
+/// Handler to get the uploaded avatar of a user
+///
+/// # Parameters
+/// - `users_service` - The users service to use
+/// - `id` - The ID of the user
+/// - `size` - The size of the avatar wanted, in pixels. The nearest standard size is served
+///
+/// # Returns
+/// The avatar, as a PNG
+#[tracing::instrument(name = "GET /users/{id}/avatar", skip(users_service))]
+#[get("/users/<id>/avatar?<size>")]
+pub fn get_avatar(
+    users_service: State<UsersService>,
+    id: UserID,
+    size: Option<u32>,
+) -> Result<BlobResponse, Problem> {
+    let avatar = users_service
+        .load_avatar(&id, size.unwrap_or(DEFAULT_AVATAR_SIZE))
+        .ok_or_else(|| Problem::new(UserProblemType::UnknownAvatar, Status::NotFound))?;
+
+    Ok(BlobResponse {
+        body: avatar.content,
+        content_type: "image/png".to_owned(),
+        etag: avatar.hash,
+        filename: None,
+    })
+}
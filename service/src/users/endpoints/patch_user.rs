@@ -1,4 +1,7 @@
-use super::{errors::UserProblemType, model::UserResponse};
+use super::{
+    errors::{UserProblemType, UserValidation},
+    model::UserResponse,
+};
 use crate::{
     authorization::Authorizer,
     http::{
@@ -39,8 +42,13 @@ pub fn patch_user(
         .map_err(|_| GenericValidation::Missing)
         .and_then(|email| email.ok_not_null(GenericValidation::Missing));
 
-    // Avatar URL: Missing = No Change, Null = Clear, Value = Update
-    let avatar_url = body.avatar_url();
+    // Avatar URL: Missing = No Change, Null = Clear, Value = Update if it's allowed
+    let avatar_url = match body.avatar_url() {
+        Patch::Value(url) if !users_service.is_allowed_avatar_url(&id, &url) => {
+            Err(UserValidation::ExternalAvatarUrl)
+        }
+        avatar_url => Ok(avatar_url),
+    };
 
     // Password: Missing = No Change, Null = Validation Error, Value = Update
     let password = body
@@ -48,8 +56,8 @@ pub fn patch_user(
         .map_err(|_| GenericValidation::Missing)
         .and_then(|password| password.ok_not_null(GenericValidation::Missing));
 
-    if let (Ok(display_name), Ok(email_address), Ok(password)) =
-        (&display_name, &email_address, &password)
+    if let (Ok(display_name), Ok(email_address), Ok(avatar_url), Ok(password)) =
+        (&display_name, &email_address, &avatar_url, &password)
     {
         tracing::info!("Updating user");
         if let (Some(_), None) = (&password, &body.old_password) {
@@ -101,6 +109,9 @@ pub fn patch_user(
         if let Err(err) = email_address {
             problem.with_field_error("email_address", err);
         }
+        if let Err(err) = avatar_url {
+            problem.with_field_error("avatar_url", err);
+        }
         if let Err(err) = password {
             problem.with_field_error("password", err);
         }
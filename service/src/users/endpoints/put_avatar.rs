@@ -0,0 +1,71 @@
+use super::{
+    errors::{UserProblemType, UserValidation},
+    model::UserResponse,
+};
+use crate::{
+    authorization::Authorizer,
+    http::{
+        problem::{Problem, ValidationProblem},
+        upload::read_upload,
+    },
+    users::{AvatarError, UserID, UsersService, MAX_AVATAR_SIZE},
+};
+use rocket::{
+    http::{ContentType, Status},
+    put, Data, State,
+};
+
+/// Handler to upload a new avatar for a user.
+///
+/// The image is uploaded as the `file` field of a `multipart/form-data` request, and the avatar URL of
+/// the user is changed to point to it
+///
+/// # Parameters
+/// - `users_service` - The users service to use
+/// - `id` - The ID of the user
+/// - `content_type` - The content type of the request
+/// - `data` - The body of the request
+/// - `authorizer` - The authorizer to prove we're allowed to change the user
+///
+/// # Returns
+/// The updated details of the user
+#[tracing::instrument(name = "PUT /users/{id}/avatar", skip(users_service, data))]
+#[put("/users/<id>/avatar", data = "<data>")]
+pub fn put_avatar(
+    users_service: State<UsersService>,
+    id: UserID,
+    content_type: &ContentType,
+    data: Data,
+    authorizer: Authorizer,
+) -> Result<UserResponse, Problem> {
+    authorizer.authorize().same_user(&id).finish()?;
+
+    let upload = read_upload(content_type, data, "file", MAX_AVATAR_SIZE)?;
+
+    users_service
+        .set_avatar(&id, &upload.content)
+        .map(|user| user.into())
+        .map_err(|e| e.into())
+}
+
+impl From<AvatarError> for Problem {
+    fn from(e: AvatarError) -> Self {
+        match e {
+            AvatarError::UnknownUser => {
+                Problem::new(UserProblemType::UnknownUserID, Status::NotFound)
+            }
+            AvatarError::InvalidImage => {
+                Problem::new(UserProblemType::InvalidAvatar, Status::UnprocessableEntity)
+            }
+            AvatarError::TooLarge => {
+                let mut problem = ValidationProblem::new();
+                problem.with_field_error("file", UserValidation::AvatarTooLarge);
+                problem.build()
+            }
+            _ => {
+                tracing::warn!(error = ?e, "An unexpected error occurred");
+                Problem::new(UserProblemType::UnknownError, Status::InternalServerError)
+            }
+        }
+    }
+}
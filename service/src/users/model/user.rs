@@ -14,6 +14,10 @@ pub struct UserData {
     pub avatar_url: Option<String>,
     /// The hashed password for the User
     pub password: Password,
+    /// Whether the User is an administrator, and so is allowed to moderate content
+    pub admin: bool,
+    /// Whether the User has been suspended by a moderator, and so is no longer allowed to sign in
+    pub suspended: bool,
 }
 
 /// Model representation of a User
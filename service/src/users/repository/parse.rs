@@ -24,6 +24,8 @@ impl UserRepository {
                 email_address: row.get("email_address"),
                 avatar_url: row.get("avatar_url"),
                 password: row.get("password"),
+                admin: row.get("admin"),
+                suspended: row.get("suspended"),
             },
         }
     }
@@ -1,8 +1,11 @@
+mod avatar;
 mod create_user;
 mod find;
 mod update;
 
 use super::repository::UserRepository;
+use crate::infrastructure::blobs::SharedBlobStore;
+pub use avatar::*;
 pub use create_user::CreateUserError;
 pub use update::UpdateError;
 
@@ -10,6 +13,10 @@ pub use update::UpdateError;
 #[derive(Clone)]
 pub struct UsersService {
     repository: UserRepository,
+    /// The blob store that uploaded avatars are kept in
+    blobs: SharedBlobStore,
+    /// Whether users may use avatars that are hosted elsewhere
+    allow_external_avatars: bool,
 }
 
 impl UsersService {
@@ -17,7 +24,15 @@ impl UsersService {
     ///
     /// # Returns
     /// The Users Service ready to use
-    pub(super) fn new(repository: UserRepository) -> Self {
-        Self { repository }
+    pub(super) fn new(
+        repository: UserRepository,
+        blobs: SharedBlobStore,
+        allow_external_avatars: bool,
+    ) -> Self {
+        Self {
+            repository,
+            blobs,
+            allow_external_avatars,
+        }
     }
 }
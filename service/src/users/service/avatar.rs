@@ -0,0 +1,278 @@
+use crate::users::{UserData, UserID, UserModel, UsersService};
+use image::{imageops::FilterType, io::Reader, ImageOutputFormat};
+use sha2::{Digest, Sha256};
+use std::io::Cursor;
+
+/// The sizes, in pixels, that avatars are stored at. Avatars are always square
+pub const AVATAR_SIZES: &[u32] = &[64, 128, 256];
+
+/// The size, in pixels, that avatars are served at if no size is requested
+pub const DEFAULT_AVATAR_SIZE: u32 = 128;
+
+/// The largest image, in bytes, that can be uploaded as an avatar
+pub const MAX_AVATAR_SIZE: u64 = 2 * 1024 * 1024;
+
+/// The largest image, in pixels, that can be uploaded as an avatar
+pub const MAX_AVATAR_PIXELS: u64 = 16_000_000;
+
+/// Errors that can occur when changing the avatar of a user
+#[derive(Debug, thiserror::Error)]
+pub enum AvatarError {
+    /// An unknown error occurred
+    #[error("An unknown error occurred")]
+    UnknownError,
+
+    /// The user does not exist
+    #[error("The user does not exist")]
+    UnknownUser,
+
+    /// The uploaded file could not be decoded as an image
+    #[error("The avatar was not a valid image")]
+    InvalidImage,
+
+    /// The uploaded image had more pixels than can be decoded
+    #[error("The avatar image was too large")]
+    TooLarge,
+}
+
+/// A stored avatar image
+#[derive(Debug)]
+pub struct Avatar {
+    /// The image, as a PNG
+    pub content: Vec<u8>,
+    /// The SHA-256 hash of the image, in hex
+    pub hash: String,
+}
+
+/// Get the URL that the avatar of a user is served from, once they have uploaded one
+///
+/// # Parameters
+/// - `user` - The ID of the user
+///
+/// # Returns
+/// The URL of the avatar
+pub fn hosted_avatar_url(user: &UserID) -> String {
+    format!("/users/{}/avatar", user)
+}
+
+/// Get the key of the blob that stores the avatar of a user at a given size
+fn avatar_key(user: &UserID, size: u32) -> String {
+    format!("avatars/{}/{}", user, size)
+}
+
+/// Check that an uploaded image is small enough to decode, using only the dimensions from its header
+///
+/// # Parameters
+/// - `content` - The contents of the uploaded image
+///
+/// # Errors
+/// If the content isn't a recognisable image, or has more pixels than are allowed
+fn check_avatar_dimensions(content: &[u8]) -> Result<(), AvatarError> {
+    let (width, height) = Reader::new(Cursor::new(content))
+        .with_guessed_format()
+        .map_err(|e| tracing::warn!(e = ?e, "Failed to guess avatar format"))
+        .ok()
+        .and_then(|reader| {
+            reader
+                .into_dimensions()
+                .map_err(|e| tracing::warn!(e = ?e, "Failed to read avatar dimensions"))
+                .ok()
+        })
+        .ok_or(AvatarError::InvalidImage)?;
+
+    if u64::from(width) * u64::from(height) > MAX_AVATAR_PIXELS {
+        tracing::warn!(width = width, height = height, "Avatar image too large");
+        return Err(AvatarError::TooLarge);
+    }
+
+    Ok(())
+}
+
+/// Resize an uploaded image to each of the standard avatar sizes.
+///
+/// Images that aren't square are cropped around the center
+///
+/// # Parameters
+/// - `content` - The contents of the uploaded image
+///
+/// # Returns
+/// Each standard size along with the image at that size as a PNG, or `None` if the content couldn't be
+/// decoded as an image
+fn resize_avatar(content: &[u8]) -> Option<Vec<(u32, Vec<u8>)>> {
+    let image = image::load_from_memory(content)
+        .map_err(|e| tracing::warn!(e = ?e, "Failed to decode avatar"))
+        .ok()?;
+
+    AVATAR_SIZES
+        .iter()
+        .map(|&size| {
+            let mut resized = vec![];
+            image
+                .resize_to_fill(size, size, FilterType::Lanczos3)
+                .write_to(&mut resized, ImageOutputFormat::Png)
+                .map_err(|e| tracing::warn!(e = ?e, size = size, "Failed to encode avatar"))
+                .ok()?;
+            Some((size, resized))
+        })
+        .collect()
+}
+
+impl UsersService {
+    /// Check if a user is allowed to set their avatar to the given URL.
+    ///
+    /// If external avatars are not allowed then the only URL that can be used is the one that the
+    /// user's uploaded avatar is served from
+    ///
+    /// # Parameters
+    /// - `user` - The ID of the user
+    /// - `url` - The URL of the avatar
+    ///
+    /// # Returns
+    /// `true` if the URL can be used
+    pub fn is_allowed_avatar_url(&self, user: &UserID, url: &str) -> bool {
+        self.allow_external_avatars || url == hosted_avatar_url(user)
+    }
+
+    /// Upload a new avatar for a user, replacing any existing one.
+    ///
+    /// The image is resized to each of the standard sizes and stored, and the avatar URL of the user is
+    /// changed to point to it
+    ///
+    /// # Parameters
+    /// - `id` - The ID of the user
+    /// - `content` - The contents of the uploaded image
+    ///
+    /// # Returns
+    /// The updated user
+    ///
+    /// # Errors
+    /// Any errors that occurred storing the avatar
+    #[tracing::instrument(skip(self, content))]
+    pub fn set_avatar(&self, id: &UserID, content: &[u8]) -> Result<UserModel, AvatarError> {
+        let user = self
+            .repository
+            .find_user_by_id(id)
+            .ok_or(AvatarError::UnknownUser)?;
+        check_avatar_dimensions(content)?;
+        let resized = resize_avatar(content).ok_or(AvatarError::InvalidImage)?;
+
+        for (size, image) in resized {
+            self.blobs.put(&avatar_key(id, size), &image).map_err(|e| {
+                tracing::error!(e = ?e, size = size, "Failed to store avatar");
+                AvatarError::UnknownError
+            })?;
+        }
+
+        self.repository
+            .update(
+                id,
+                UserData {
+                    avatar_url: Some(hosted_avatar_url(id)),
+                    ..user.data
+                },
+            )
+            .map_err(|_| AvatarError::UnknownError)
+    }
+
+    /// Remove the avatar of a user, including any that they uploaded
+    ///
+    /// # Parameters
+    /// - `id` - The ID of the user
+    ///
+    /// # Returns
+    /// The updated user
+    ///
+    /// # Errors
+    /// Any errors that occurred removing the avatar
+    #[tracing::instrument(skip(self))]
+    pub fn clear_avatar(&self, id: &UserID) -> Result<UserModel, AvatarError> {
+        let user = self
+            .repository
+            .find_user_by_id(id)
+            .ok_or(AvatarError::UnknownUser)?;
+
+        for &size in AVATAR_SIZES {
+            if let Err(e) = self.blobs.delete(&avatar_key(id, size)) {
+                tracing::warn!(e = ?e, size = size, "Failed to delete avatar");
+            }
+        }
+
+        self.repository
+            .update(
+                id,
+                UserData {
+                    avatar_url: None,
+                    ..user.data
+                },
+            )
+            .map_err(|_| AvatarError::UnknownError)
+    }
+
+    /// Load the uploaded avatar of a user.
+    ///
+    /// The smallest standard size that is at least as large as the one requested is used, or the
+    /// largest standard size if the request is larger than all of them
+    ///
+    /// # Parameters
+    /// - `id` - The ID of the user
+    /// - `size` - The size of avatar wanted, in pixels
+    ///
+    /// # Returns
+    /// The avatar, or `None` if the user has not uploaded one
+    pub fn load_avatar(&self, id: &UserID, size: u32) -> Option<Avatar> {
+        let size = AVATAR_SIZES
+            .iter()
+            .copied()
+            .find(|&standard| standard >= size)
+            .unwrap_or(AVATAR_SIZES[AVATAR_SIZES.len() - 1]);
+
+        self.blobs
+            .get(&avatar_key(id, size))
+            .map_err(|e| tracing::error!(e = ?e, size = size, "Failed to load avatar"))
+            .ok()
+            .flatten()
+            .map(|content| Avatar {
+                hash: hex::encode(Sha256::digest(&content)),
+                content,
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{DynamicImage, GenericImageView};
+
+    #[test]
+    fn test_resize_avatar() {
+        let mut original = vec![];
+        DynamicImage::new_rgb8(600, 300)
+            .write_to(&mut original, ImageOutputFormat::Jpeg(90))
+            .unwrap();
+
+        let resized = resize_avatar(&original).unwrap();
+        let sizes: Vec<(u32, (u32, u32))> = resized
+            .iter()
+            .map(|(size, image)| (*size, image::load_from_memory(image).unwrap().dimensions()))
+            .collect();
+        assert_eq!(
+            sizes,
+            vec![(64, (64, 64)), (128, (128, 128)), (256, (256, 256))]
+        );
+    }
+
+    #[test]
+    fn test_check_avatar_dimensions_too_large() {
+        // A GIF header claiming to be 65535x65535 pixels, with no image data
+        let header = b"GIF89a\xff\xff\xff\xff\x00\x00\x00\x3b";
+        assert!(matches!(
+            check_avatar_dimensions(header),
+            Err(AvatarError::TooLarge)
+        ));
+    }
+
+    #[test]
+    fn test_resize_invalid_avatar() {
+        assert!(resize_avatar(b"Not an image").is_none());
+    }
+}
@@ -0,0 +1,47 @@
+use super::{repository::WebhookRepository, TargetPolicy, WebhooksService};
+use crate::infrastructure::database::Database;
+use std::sync::Arc;
+
+/// Application Configuration for the Webhooks module
+pub struct WebhooksConfig {
+    pub webhooks_service: WebhooksService,
+}
+
+impl WebhooksConfig {
+    /// Create the Configuration for the Webhooks Module, and start the workers that deliver events
+    ///
+    /// # Parameters
+    /// - `database` - The database connection to use
+    /// - `allow_loopback` - Whether webhooks may target the loopback interface
+    ///
+    /// # Returns
+    /// The Webhooks Config object
+    pub fn new(database: Database, allow_loopback: bool) -> Self {
+        let repository = WebhookRepository::new(database);
+        let webhooks_service = WebhooksService::new(repository, TargetPolicy { allow_loopback });
+        webhooks_service.start_workers();
+
+        Self { webhooks_service }
+    }
+
+    /// Generate the configuration callback needed for the HTTP Server to actually add the Webhooks
+    /// endpoints to the server
+    ///
+    /// # Returns
+    /// The callback to provide to the HTTP Server to configure up the Webhooks endpoints
+    pub fn configure(&self) -> Arc<dyn Fn(rocket::Rocket) -> rocket::Rocket + Send + Sync> {
+        let webhooks_service = self.webhooks_service.clone();
+        Arc::new(move |config| {
+            config.manage(webhooks_service.clone()).mount(
+                "/",
+                rocket::routes![
+                    super::endpoints::create_webhook,
+                    super::endpoints::delete_webhook,
+                    super::endpoints::list_deliveries,
+                    super::endpoints::list_webhooks,
+                    super::endpoints::redeliver
+                ],
+            )
+        })
+    }
+}
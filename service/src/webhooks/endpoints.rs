@@ -0,0 +1,13 @@
+mod create_webhook;
+mod delete_webhook;
+mod errors;
+mod list_deliveries;
+mod list_webhooks;
+pub(crate) mod model;
+mod redeliver;
+
+pub use create_webhook::*;
+pub use delete_webhook::*;
+pub use list_deliveries::*;
+pub use list_webhooks::*;
+pub use redeliver::*;
@@ -0,0 +1,63 @@
+use super::{
+    errors::WebhookProblemType,
+    model::{WebhookRequest, WebhookResponse},
+};
+use crate::{
+    authorization::Authorizer,
+    http::problem::Problem,
+    webhooks::{CreateWebhookError, WebhooksService},
+    worlds::{
+        endpoints::{errors::WorldProblemType, world_owner::authorize_world_owner},
+        WorldID, WorldsService,
+    },
+};
+use rocket::{http::Status, post, State};
+use rocket_contrib::json::Json;
+
+/// Handler to register a new Webhook to receive events for a World
+///
+/// # Parameters
+/// - `webhooks_service` - The webhooks service to use
+/// - `worlds_service` - The worlds service to use
+/// - `world` - The ID of the world to register the webhook for
+/// - `body` - The details of the webhook to register
+/// - `authorizer` - The authorizer to prove we're allowed to register the webhook
+///
+/// # Returns
+/// The newly created webhook details, or a Problem if the creation failed
+#[tracing::instrument(
+    name = "POST /worlds/{id}/webhooks",
+    skip(webhooks_service, worlds_service, body)
+)]
+#[post("/worlds/<world>/webhooks", data = "<body>")]
+pub fn create_webhook(
+    webhooks_service: State<WebhooksService>,
+    worlds_service: State<WorldsService>,
+    world: WorldID,
+    body: Json<WebhookRequest>,
+    authorizer: Authorizer,
+) -> Result<WebhookResponse, Problem> {
+    authorize_world_owner(&worlds_service, &authorizer, &world)?;
+
+    let webhook = body.build(world, webhooks_service.target_policy())?;
+
+    let new_webhook = webhooks_service.create_webhook(webhook)?;
+    Ok(WebhookResponse(new_webhook))
+}
+
+impl From<CreateWebhookError> for Problem {
+    fn from(e: CreateWebhookError) -> Self {
+        match e {
+            CreateWebhookError::UnknownWorld => {
+                Problem::new(WorldProblemType::UnknownWorldID, Status::NotFound)
+            }
+            _ => {
+                tracing::warn!(error = ?e, "An unexpected error occurred");
+                Problem::new(
+                    WebhookProblemType::UnknownError,
+                    Status::InternalServerError,
+                )
+            }
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use super::errors::WebhookProblemType;
+use crate::{
+    authorization::Authorizer,
+    http::problem::Problem,
+    webhooks::{WebhookID, WebhooksService},
+    worlds::{endpoints::world_owner::authorize_world_owner, WorldID, WorldsService},
+};
+use rocket::{delete, http::Status, Response, State};
+
+/// Handler to delete a Webhook so that it no longer receives events
+///
+/// # Parameters
+/// - `webhooks_service` - The webhooks service to use
+/// - `worlds_service` - The worlds service to use
+/// - `world` - The ID of the world that the webhook is for
+/// - `id` - The ID of the webhook to delete
+/// - `authorizer` - The authorizer to prove we're allowed to delete the webhook
+///
+/// # Returns
+/// An empty response if the webhook was deleted, or a Problem if deleting failed
+#[tracing::instrument(
+    name = "DELETE /worlds/{id}/webhooks/{webhook}",
+    skip(webhooks_service, worlds_service)
+)]
+#[delete("/worlds/<world>/webhooks/<id>")]
+pub fn delete_webhook(
+    webhooks_service: State<WebhooksService>,
+    worlds_service: State<WorldsService>,
+    world: WorldID,
+    id: WebhookID,
+    authorizer: Authorizer,
+) -> Result<Response<'static>, Problem> {
+    authorize_world_owner(&worlds_service, &authorizer, &world)?;
+
+    if webhooks_service.delete_webhook(&world, &id) {
+        Ok(Response::build().status(Status::NoContent).finalize())
+    } else {
+        Err(Problem::new(
+            WebhookProblemType::UnknownWebhook,
+            Status::NotFound,
+        ))
+    }
+}
@@ -0,0 +1,62 @@
+use crate::http::problem::{ProblemType, ValidationType};
+
+/// Problem Types that can happen when working with webhooks
+#[derive(Debug, thiserror::Error)]
+pub enum WebhookProblemType {
+    /// An unknown error occurred
+    #[error("An unknown error occurred")]
+    UnknownError,
+
+    /// The webhook that was looked up was not found
+    #[error("The requested webhook was unknown")]
+    UnknownWebhook,
+
+    /// The delivery that was looked up was not found
+    #[error("The requested delivery was unknown")]
+    UnknownDelivery,
+}
+
+impl ProblemType for WebhookProblemType {
+    /// Generate a Type value for the `ProblemType` values.
+    ///
+    /// These are used in the `type` field in the RFC-7807 Problem Response
+    fn error_code(&self) -> &'static str {
+        match self {
+            WebhookProblemType::UnknownError => {
+                "tag:multiverse,2020:webhooks/problems/unknown_error"
+            }
+            WebhookProblemType::UnknownWebhook => {
+                "tag:multiverse,2020:webhooks/problems/unknown_webhook"
+            }
+            WebhookProblemType::UnknownDelivery => {
+                "tag:multiverse,2020:webhooks/problems/unknown_delivery"
+            }
+        }
+    }
+}
+
+/// Validation errors that are specific to webhooks
+#[derive(Debug, thiserror::Error)]
+pub enum WebhookValidation {
+    /// The URL was not an absolute HTTP or HTTPS URL
+    #[error("The URL must be an absolute HTTP or HTTPS URL")]
+    InvalidUrl,
+
+    /// The URL targets a loopback, private or otherwise internal address
+    #[error("The URL must not target a private or internal address")]
+    ForbiddenUrl,
+}
+
+impl ValidationType for WebhookValidation {
+    /// Generate a Type value for the `ValidationType` values.
+    fn error_code(&self) -> &'static str {
+        match self {
+            WebhookValidation::InvalidUrl => {
+                "tag:multiverse,2020:webhooks/problems/validation_error/invalid_url"
+            }
+            WebhookValidation::ForbiddenUrl => {
+                "tag:multiverse,2020:webhooks/problems/validation_error/forbidden_url"
+            }
+        }
+    }
+}
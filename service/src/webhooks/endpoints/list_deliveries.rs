@@ -0,0 +1,44 @@
+use super::{errors::WebhookProblemType, model::DeliveriesResponse};
+use crate::{
+    authorization::Authorizer,
+    http::{pagination::PaginationRequest, problem::Problem},
+    webhooks::{WebhookID, WebhooksService},
+    worlds::{endpoints::world_owner::authorize_world_owner, WorldID, WorldsService},
+};
+use rocket::{get, http::Status, State};
+
+/// Handler to list the recent Deliveries made to a Webhook, including every attempt made for each
+///
+/// # Parameters
+/// - `webhooks_service` - The webhooks service to use
+/// - `worlds_service` - The worlds service to use
+/// - `world` - The ID of the world that the webhook is for
+/// - `id` - The ID of the webhook
+/// - `pagination` - The pagination controls to apply
+/// - `authorizer` - The authorizer to prove we're allowed to see the deliveries
+///
+/// # Returns
+/// The deliveries made to the webhook
+#[tracing::instrument(
+    name = "GET /worlds/{id}/webhooks/{webhook}/deliveries",
+    skip(webhooks_service, worlds_service)
+)]
+#[get("/worlds/<world>/webhooks/<id>/deliveries?<pagination..>")]
+pub fn list_deliveries(
+    webhooks_service: State<WebhooksService>,
+    worlds_service: State<WorldsService>,
+    world: WorldID,
+    id: WebhookID,
+    pagination: PaginationRequest,
+    authorizer: Authorizer,
+) -> Result<DeliveriesResponse, Problem> {
+    authorize_world_owner(&worlds_service, &authorizer, &world)?;
+
+    let webhook = webhooks_service
+        .find_webhook_by_id(&world, &id)
+        .ok_or_else(|| Problem::new(WebhookProblemType::UnknownWebhook, Status::NotFound))?;
+
+    let deliveries = webhooks_service.list_deliveries(&webhook.identity.id, &pagination);
+
+    Ok(DeliveriesResponse { world, deliveries })
+}
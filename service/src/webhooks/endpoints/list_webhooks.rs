@@ -0,0 +1,38 @@
+use super::model::WebhooksResponse;
+use crate::{
+    authorization::Authorizer,
+    http::{pagination::PaginationRequest, problem::Problem},
+    webhooks::WebhooksService,
+    worlds::{endpoints::world_owner::authorize_world_owner, WorldID, WorldsService},
+};
+use rocket::{get, State};
+
+/// Handler to list the Webhooks registered for a World
+///
+/// # Parameters
+/// - `webhooks_service` - The webhooks service to use
+/// - `worlds_service` - The worlds service to use
+/// - `world` - The ID of the world to list the webhooks of
+/// - `pagination` - The pagination controls to apply
+/// - `authorizer` - The authorizer to prove we're allowed to see the webhooks
+///
+/// # Returns
+/// The details of the webhooks for the world
+#[tracing::instrument(
+    name = "GET /worlds/{id}/webhooks",
+    skip(webhooks_service, worlds_service)
+)]
+#[get("/worlds/<world>/webhooks?<pagination..>")]
+pub fn list_webhooks(
+    webhooks_service: State<WebhooksService>,
+    worlds_service: State<WorldsService>,
+    world: WorldID,
+    pagination: PaginationRequest,
+    authorizer: Authorizer,
+) -> Result<WebhooksResponse, Problem> {
+    authorize_world_owner(&worlds_service, &authorizer, &world)?;
+
+    let webhooks = webhooks_service.list_webhooks(&world, &pagination);
+
+    Ok(WebhooksResponse(webhooks))
+}
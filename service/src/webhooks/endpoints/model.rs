@@ -0,0 +1,15 @@
+mod deliveries_response;
+mod delivery_link;
+mod delivery_response;
+mod webhook_link;
+mod webhook_request;
+mod webhook_response;
+mod webhooks_response;
+
+pub use deliveries_response::*;
+pub use delivery_link::*;
+pub use delivery_response::*;
+pub use webhook_link::*;
+pub use webhook_request::*;
+pub use webhook_response::*;
+pub use webhooks_response::*;
@@ -0,0 +1,66 @@
+use super::{delivery_to_json, DeliveryLink};
+use crate::{
+    http::link::{Link, LinkRel, Links},
+    model::Page,
+    webhooks::DeliveryModel,
+    worlds::WorldID,
+};
+use rocket::{
+    http::hyper::header::{CacheControl, CacheDirective},
+    response, Request,
+};
+use rocket_contrib::json::Json;
+use serde_json::{json, Value};
+
+/// API Model representing a page of Deliveries made to a Webhook
+#[derive(Debug)]
+pub struct DeliveriesResponse {
+    /// The ID of the world that the webhook is for
+    pub world: WorldID,
+
+    /// The page of deliveries
+    pub deliveries: Page<DeliveryModel>,
+}
+
+impl<'r> response::Responder<'r> for DeliveriesResponse {
+    fn respond_to(self, req: &Request) -> response::Result<'r> {
+        let deliveries: Vec<Value> = self
+            .deliveries
+            .entries
+            .iter()
+            .map(|delivery| delivery_to_json(&delivery))
+            .collect();
+
+        let response_body = json!({
+          "entries": deliveries,
+          "pagination": {
+            "offset": self.deliveries.offset,
+            "count": self.deliveries.count,
+            "total": self.deliveries.total
+          }
+        });
+
+        let world = self.world;
+        let delivery_links: Vec<Link> = self
+            .deliveries
+            .entries
+            .iter()
+            .map(|delivery| {
+                DeliveryLink::new(world.clone(), delivery.webhook.clone(), delivery.id.clone())
+            })
+            .enumerate()
+            .map(|(index, link)| {
+                Link::new(link, LinkRel::ITEM).anchor(format!("#/entries/{}", index))
+            })
+            .collect();
+
+        response::Response::build()
+            .merge(Json(response_body).respond_to(req).unwrap())
+            .header(CacheControl(vec![
+                CacheDirective::Private,
+                CacheDirective::MaxAge(60),
+            ]))
+            .header(Links(delivery_links))
+            .ok()
+    }
+}
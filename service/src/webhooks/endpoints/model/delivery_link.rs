@@ -0,0 +1,22 @@
+use crate::{
+    webhooks::{DeliveryID, WebhookID},
+    worlds::WorldID,
+};
+
+/// Representation of a link to a delivery made to a webhook
+pub struct DeliveryLink(WorldID, WebhookID, DeliveryID);
+
+impl DeliveryLink {
+    pub fn new(world_id: WorldID, webhook_id: WebhookID, delivery_id: DeliveryID) -> Self {
+        Self(world_id, webhook_id, delivery_id)
+    }
+}
+
+impl From<DeliveryLink> for String {
+    fn from(delivery_link: DeliveryLink) -> String {
+        format!(
+            "/worlds/{}/webhooks/{}/deliveries/{}",
+            delivery_link.0, delivery_link.1, delivery_link.2
+        )
+    }
+}
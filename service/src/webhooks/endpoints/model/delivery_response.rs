@@ -0,0 +1,69 @@
+use super::{DeliveryLink, WebhookLink};
+use crate::{
+    http::link::{Link, LinkRel, Links},
+    webhooks::DeliveryModel,
+    worlds::WorldID,
+};
+use rocket::{http::Status, response, Request};
+use rocket_contrib::json::Json;
+use serde_json::{json, Value};
+
+/// API Model representing a Delivery that has just been started
+#[derive(Debug)]
+pub struct DeliveryResponse {
+    /// The ID of the world that the webhook is for
+    pub world: WorldID,
+
+    /// The delivery
+    pub delivery: DeliveryModel,
+}
+
+pub(super) fn delivery_to_json(delivery: &DeliveryModel) -> Value {
+    let payload: Value = serde_json::from_str(&delivery.payload).unwrap_or(Value::Null);
+    let attempts: Vec<Value> = delivery
+        .attempts
+        .iter()
+        .map(|attempt| {
+            json!({
+              "attempted": attempt.attempted,
+              "response_status": attempt.response_status,
+              "error": attempt.error
+            })
+        })
+        .collect();
+
+    json!({
+      "event": delivery.event,
+      "status": delivery.status.to_string(),
+      "created": delivery.created,
+      "payload": payload,
+      "attempts": attempts
+    })
+}
+
+impl<'r> response::Responder<'r> for DeliveryResponse {
+    fn respond_to(self, req: &Request) -> response::Result<'r> {
+        response::Response::build()
+            .merge(
+                Json(delivery_to_json(&self.delivery))
+                    .respond_to(req)
+                    .unwrap(),
+            )
+            .status(Status::Accepted)
+            .header(Links(vec![
+                Link::new(
+                    DeliveryLink::new(
+                        self.world.clone(),
+                        self.delivery.webhook.clone(),
+                        self.delivery.id.clone(),
+                    ),
+                    LinkRel::SELF,
+                ),
+                Link::new(
+                    WebhookLink::new(self.world, self.delivery.webhook),
+                    LinkRel::RELATED,
+                ),
+            ]))
+            .ok()
+    }
+}
@@ -0,0 +1,16 @@
+use crate::{webhooks::WebhookID, worlds::WorldID};
+
+/// Representation of a link to a webhook
+pub struct WebhookLink(WorldID, WebhookID);
+
+impl WebhookLink {
+    pub fn new(world_id: WorldID, webhook_id: WebhookID) -> Self {
+        Self(world_id, webhook_id)
+    }
+}
+
+impl From<WebhookLink> for String {
+    fn from(webhook_link: WebhookLink) -> String {
+        format!("/worlds/{}/webhooks/{}", webhook_link.0, webhook_link.1)
+    }
+}
@@ -0,0 +1,78 @@
+use crate::{
+    http::problem::{GenericValidation, Problem, ValidationProblem},
+    webhooks::{endpoints::errors::WebhookValidation, TargetPolicy, WebhookData},
+    worlds::WorldID,
+};
+use serde::Deserialize;
+use url::Url;
+
+/// Incoming details representing a webhook to register
+#[derive(Debug, Deserialize)]
+pub struct WebhookRequest {
+    /// The URL to deliver events to
+    url: Option<String>,
+    /// The secret used to sign the payloads that are delivered
+    secret: Option<String>,
+}
+
+impl WebhookRequest {
+    /// Validate the request and build the details of the webhook that it represents
+    ///
+    /// # Parameters
+    /// - `world` - The ID of the world that the webhook is for
+    /// - `policy` - The rules for which addresses the webhook may target
+    ///
+    /// # Returns
+    /// The details of the webhook
+    ///
+    /// # Errors
+    /// A Validation Problem if the request was invalid in any way
+    pub fn build(&self, world: WorldID, policy: &TargetPolicy) -> Result<WebhookData, Problem> {
+        let mut problem = ValidationProblem::new();
+        let mut valid = true;
+
+        let url = match self.url.as_ref().filter(|v| !v.trim().is_empty()) {
+            None => {
+                problem.with_field_error("url", GenericValidation::Missing);
+                valid = false;
+                None
+            }
+            Some(url) => {
+                let url = Url::parse(url.trim())
+                    .ok()
+                    .filter(|url| url.scheme() == "http" || url.scheme() == "https")
+                    .filter(|url| url.has_host());
+                match url {
+                    None => {
+                        problem.with_field_error("url", WebhookValidation::InvalidUrl);
+                        valid = false;
+                        None
+                    }
+                    Some(url) if !policy.permits_url(&url) => {
+                        problem.with_field_error("url", WebhookValidation::ForbiddenUrl);
+                        valid = false;
+                        None
+                    }
+                    url => url,
+                }
+            }
+        };
+
+        let secret = self.secret.as_ref().filter(|v| !v.is_empty());
+        if secret.is_none() {
+            problem.with_field_error("secret", GenericValidation::Missing);
+            valid = false;
+        }
+
+        if !valid {
+            tracing::warn!("Validation error building webhook");
+            return Err(problem.build());
+        }
+
+        Ok(WebhookData {
+            world,
+            url: url.unwrap().to_string(),
+            secret: secret.unwrap().clone(),
+        })
+    }
+}
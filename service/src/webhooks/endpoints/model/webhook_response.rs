@@ -0,0 +1,47 @@
+use super::WebhookLink;
+use crate::{
+    http::link::{Link, LinkRel, Links},
+    webhooks::WebhookModel,
+    worlds::endpoints::model::WorldLink,
+};
+use rocket::{
+    http::hyper::header::{CacheControl, CacheDirective, ETag, EntityTag},
+    response, Request,
+};
+use rocket_contrib::json::Json;
+use serde_json::{json, Value};
+
+/// API Model representing a Webhook.
+///
+/// The secret is never returned, since it is only needed by the owner of the receiver
+#[derive(Debug)]
+pub struct WebhookResponse(pub WebhookModel);
+
+pub(super) fn to_json(webhook: &WebhookModel) -> Value {
+    json!({
+      "url": webhook.data.url,
+      "created": webhook.identity.created
+    })
+}
+
+impl<'r> response::Responder<'r> for WebhookResponse {
+    fn respond_to(self, req: &Request) -> response::Result<'r> {
+        let etag = self.0.identity.version.to_string();
+
+        response::Response::build()
+            .merge(Json(to_json(&self.0)).respond_to(req).unwrap())
+            .header(CacheControl(vec![
+                CacheDirective::Private,
+                CacheDirective::MaxAge(3600),
+            ]))
+            .header(ETag(EntityTag::new(false, etag)))
+            .header(Links(vec![
+                Link::new(
+                    WebhookLink::new(self.0.data.world.clone(), self.0.identity.id.clone()),
+                    LinkRel::SELF,
+                ),
+                Link::new(WorldLink::new(self.0.data.world), LinkRel::RELATED),
+            ]))
+            .ok()
+    }
+}
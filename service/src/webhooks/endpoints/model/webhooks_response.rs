@@ -0,0 +1,58 @@
+use super::{to_json, WebhookLink};
+use crate::{
+    http::link::{Link, LinkRel, Links},
+    model::Page,
+    webhooks::WebhookModel,
+};
+use rocket::{
+    http::hyper::header::{CacheControl, CacheDirective},
+    response, Request,
+};
+use rocket_contrib::json::Json;
+use serde_json::{json, Value};
+
+/// API Model representing a page of Webhooks
+#[derive(Debug)]
+pub struct WebhooksResponse(pub Page<WebhookModel>);
+
+impl<'r> response::Responder<'r> for WebhooksResponse {
+    fn respond_to(self, req: &Request) -> response::Result<'r> {
+        let webhooks: Vec<Value> = self
+            .0
+            .entries
+            .iter()
+            .map(|webhook| to_json(&webhook))
+            .collect();
+
+        let response_body = json!({
+          "entries": webhooks,
+          "pagination": {
+            "offset": self.0.offset,
+            "count": self.0.count,
+            "total": self.0.total
+          }
+        });
+
+        let webhook_links: Vec<Link> = self
+            .0
+            .entries
+            .iter()
+            .map(|webhook| {
+                WebhookLink::new(webhook.data.world.clone(), webhook.identity.id.clone())
+            })
+            .enumerate()
+            .map(|(index, link)| {
+                Link::new(link, LinkRel::ITEM).anchor(format!("#/entries/{}", index))
+            })
+            .collect();
+
+        response::Response::build()
+            .merge(Json(response_body).respond_to(req).unwrap())
+            .header(CacheControl(vec![
+                CacheDirective::Private,
+                CacheDirective::MaxAge(3600),
+            ]))
+            .header(Links(webhook_links))
+            .ok()
+    }
+}
@@ -0,0 +1,51 @@
+use super::{errors::WebhookProblemType, model::DeliveryResponse};
+use crate::{
+    authorization::Authorizer,
+    http::problem::Problem,
+    webhooks::{DeliveryID, WebhookID, WebhooksService},
+    worlds::{endpoints::world_owner::authorize_world_owner, WorldID, WorldsService},
+};
+use rocket::{http::Status, post, State};
+
+/// Handler to deliver the payload of a previous Delivery to a Webhook again.
+///
+/// This creates a new delivery, which is made in the background in the same way as any other
+///
+/// # Parameters
+/// - `webhooks_service` - The webhooks service to use
+/// - `worlds_service` - The worlds service to use
+/// - `world` - The ID of the world that the webhook is for
+/// - `id` - The ID of the webhook
+/// - `delivery` - The ID of the delivery to repeat
+/// - `authorizer` - The authorizer to prove we're allowed to redeliver
+///
+/// # Returns
+/// The new delivery, or a Problem if the redelivery failed
+#[tracing::instrument(
+    name = "POST /worlds/{id}/webhooks/{webhook}/deliveries/{delivery}/redeliver",
+    skip(webhooks_service, worlds_service)
+)]
+#[post("/worlds/<world>/webhooks/<id>/deliveries/<delivery>/redeliver")]
+pub fn redeliver(
+    webhooks_service: State<WebhooksService>,
+    worlds_service: State<WorldsService>,
+    world: WorldID,
+    id: WebhookID,
+    delivery: DeliveryID,
+    authorizer: Authorizer,
+) -> Result<DeliveryResponse, Problem> {
+    authorize_world_owner(&worlds_service, &authorizer, &world)?;
+
+    let webhook = webhooks_service
+        .find_webhook_by_id(&world, &id)
+        .ok_or_else(|| Problem::new(WebhookProblemType::UnknownWebhook, Status::NotFound))?;
+
+    let new_delivery = webhooks_service
+        .redeliver(webhook, &delivery)
+        .ok_or_else(|| Problem::new(WebhookProblemType::UnknownDelivery, Status::NotFound))?;
+
+    Ok(DeliveryResponse {
+        world,
+        delivery: new_delivery,
+    })
+}
@@ -0,0 +1,17 @@
+mod delivery;
+mod delivery_id;
+mod delivery_status;
+mod signature;
+mod target_policy;
+mod webhook;
+mod webhook_event;
+mod webhook_id;
+
+pub use delivery::*;
+pub use delivery_id::*;
+pub use delivery_status::*;
+pub use signature::*;
+pub use target_policy::*;
+pub use webhook::*;
+pub use webhook_event::*;
+pub use webhook_id::*;
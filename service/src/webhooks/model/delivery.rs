@@ -0,0 +1,40 @@
+use super::{DeliveryID, DeliveryStatus, WebhookID};
+use chrono::{DateTime, Utc};
+
+/// Model representation of a single attempt to deliver an event to a webhook
+#[derive(Debug, Clone)]
+pub struct AttemptModel {
+    /// When the attempt was made
+    pub attempted: DateTime<Utc>,
+
+    /// The HTTP status code that the receiver responded with, if it responded at all
+    pub response_status: Option<u16>,
+
+    /// A description of why the attempt failed, if it did
+    pub error: Option<String>,
+}
+
+/// Model representation of the delivery of an event to a webhook
+#[derive(Debug, Clone)]
+pub struct DeliveryModel {
+    /// The ID of the delivery
+    pub id: DeliveryID,
+
+    /// When the delivery was created
+    pub created: DateTime<Utc>,
+
+    /// The ID of the webhook that the event is being delivered to
+    pub webhook: WebhookID,
+
+    /// The name of the event being delivered
+    pub event: String,
+
+    /// The exact JSON payload being delivered
+    pub payload: String,
+
+    /// The status of the delivery
+    pub status: DeliveryStatus,
+
+    /// The attempts made to deliver the event, oldest first
+    pub attempts: Vec<AttemptModel>,
+}
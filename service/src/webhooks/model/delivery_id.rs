@@ -0,0 +1,67 @@
+use bytes::BytesMut;
+use postgres_types::{accepts, to_sql_checked, FromSql, IsNull, ToSql, Type};
+use rocket::{http::RawStr, request};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use uuid::Uuid;
+
+/// The ID of a Webhook Delivery
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, FromSql)]
+pub struct DeliveryID(Uuid);
+
+impl DeliveryID {
+    /// Wrap a UUID as a Webhook Delivery ID object
+    ///
+    /// # Parameters
+    /// - `uuid` - The UUID to wrap
+    ///
+    /// # The Delivery ID
+    pub fn new(uuid: Uuid) -> Self {
+        DeliveryID(uuid)
+    }
+}
+
+impl Default for DeliveryID {
+    fn default() -> Self {
+        Self(Uuid::new_v4())
+    }
+}
+
+impl ToSql for DeliveryID {
+    accepts!(UUID);
+
+    to_sql_checked!();
+
+    fn to_sql(
+        &self,
+        t: &Type,
+        w: &mut BytesMut,
+    ) -> Result<IsNull, Box<dyn std::error::Error + Sync + Send>> {
+        self.0.to_sql(t, w)
+    }
+}
+
+impl<'r> request::FromParam<'r> for DeliveryID {
+    type Error = &'r RawStr;
+
+    fn from_param(param: &'r RawStr) -> Result<Self, Self::Error> {
+        param
+            .percent_decode()
+            .map(|cow| cow.into_owned())
+            .map_err(|_| param)
+            .and_then(|delivery_id| Uuid::parse_str(&delivery_id).map_err(|_| param))
+            .map(|delivery_id| DeliveryID::new(delivery_id))
+    }
+}
+
+impl Display for DeliveryID {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<DeliveryID> for Uuid {
+    fn from(delivery_id: DeliveryID) -> Self {
+        delivery_id.0
+    }
+}
@@ -0,0 +1,11 @@
+/// The status of the delivery of an event to a webhook
+#[derive(Debug, PartialEq, Clone, strum_macros::EnumString, strum_macros::Display)]
+#[strum(serialize_all = "snake_case")]
+pub enum DeliveryStatus {
+    /// The event has not yet been successfully delivered, but will be tried again
+    Pending,
+    /// The event was successfully delivered
+    Succeeded,
+    /// Every attempt to deliver the event failed, and no more will be made
+    Failed,
+}
@@ -0,0 +1,36 @@
+use hmac::{Hmac, Mac, NewMac};
+use sha2::Sha256;
+
+/// Generate the signature of a payload being delivered to a webhook, so that the receiver can verify
+/// that it came from us.
+///
+/// The signature is the HMAC-SHA256 of the payload, keyed with the secret of the webhook, in the form
+/// `sha256=<hex digest>`
+///
+/// # Parameters
+/// - `secret` - The secret of the webhook
+/// - `payload` - The exact payload being delivered
+///
+/// # Returns
+/// The signature
+pub fn sign(secret: &str, payload: &str) -> String {
+    let mut mac =
+        Hmac::<Sha256>::new_varkey(secret.as_bytes()).expect("HMAC can take keys of any size");
+    mac.update(payload.as_bytes());
+
+    format!("sha256={}", hex::encode(mac.finalize().into_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sign() {
+        // Test Case 2 from RFC-4231
+        assert_eq!(
+            sign("Jefe", "what do ya want for nothing?"),
+            "sha256=5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+    }
+}
@@ -0,0 +1,184 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use url::{Host, Url};
+
+/// The rules for which addresses a webhook is allowed to deliver events to.
+///
+/// The status of every delivery is visible to the owner of the webhook, so allowing webhooks to target
+/// loopback, private or link-local addresses would let anyone who owns a world probe the network that
+/// the service runs on
+#[derive(Debug, Clone, Default)]
+pub struct TargetPolicy {
+    /// Whether webhooks may target the loopback interface. This only exists so that the service can be
+    /// tested against a local receiver
+    pub allow_loopback: bool,
+}
+
+impl TargetPolicy {
+    /// Determine whether a URL is permitted as the target of a webhook.
+    ///
+    /// This can only check hosts that are literal IP addresses or are obviously local. Hosts that are
+    /// domain names are checked again when delivering, once they have been resolved
+    ///
+    /// # Parameters
+    /// - `url` - The URL to check
+    ///
+    /// # Returns
+    /// True if the URL is permitted. False if not
+    pub fn permits_url(&self, url: &Url) -> bool {
+        match url.host() {
+            None => false,
+            Some(Host::Ipv4(address)) => self.permits_address(&IpAddr::V4(address)),
+            Some(Host::Ipv6(address)) => self.permits_address(&IpAddr::V6(address)),
+            Some(Host::Domain(domain)) => {
+                let domain = domain.trim_end_matches('.').to_lowercase();
+                let local = domain == "localhost" || domain.ends_with(".localhost");
+
+                self.allow_loopback || !local
+            }
+        }
+    }
+
+    /// Determine whether an IP Address is permitted as the target of a webhook
+    ///
+    /// # Parameters
+    /// - `address` - The address to check
+    ///
+    /// # Returns
+    /// True if the address is permitted. False if not
+    pub fn permits_address(&self, address: &IpAddr) -> bool {
+        match address {
+            IpAddr::V4(address) => self.permits_ipv4(address),
+            IpAddr::V6(address) => self.permits_ipv6(address),
+        }
+    }
+
+    /// Determine whether an IPv4 Address is permitted as the target of a webhook
+    fn permits_ipv4(&self, address: &Ipv4Addr) -> bool {
+        let octets = address.octets();
+
+        if address.is_loopback() {
+            return self.allow_loopback;
+        }
+
+        !(octets[0] == 0 // "This" network
+            || address.is_private()
+            || address.is_link_local()
+            || (octets[0] == 100 && (octets[1] & 0xc0) == 64) // Carrier-grade NAT
+            || (octets[0] == 192 && octets[1] == 0 && octets[2] == 0) // IETF protocol assignments
+            || (octets[0] == 198 && (octets[1] & 0xfe) == 18) // Benchmarking
+            || address.is_documentation()
+            || address.is_multicast()
+            || octets[0] >= 240) // Reserved, including broadcast
+    }
+
+    /// Determine whether an IPv6 Address is permitted as the target of a webhook
+    fn permits_ipv6(&self, address: &Ipv6Addr) -> bool {
+        let segments = address.segments();
+
+        if address.is_loopback() {
+            return self.allow_loopback;
+        }
+
+        // IPv4-mapped and NAT64 addresses are only as good as the IPv4 address that they contain
+        let embedded_ipv4 = (segments[0..5] == [0, 0, 0, 0, 0] && segments[5] == 0xffff)
+            || segments[0..6] == [0x64, 0xff9b, 0, 0, 0, 0];
+        if embedded_ipv4 {
+            let [_, _, _, _, _, _, high, low] = segments;
+            let mapped = Ipv4Addr::new((high >> 8) as u8, high as u8, (low >> 8) as u8, low as u8);
+            return self.permits_ipv4(&mapped);
+        }
+
+        !(address.is_unspecified()
+            || address.is_multicast()
+            || (segments[0] & 0xfe00) == 0xfc00 // Unique local
+            || (segments[0] & 0xffc0) == 0xfe80 // Link-local
+            || (segments[0] & 0xffc0) == 0xfec0 // Site-local
+            || (segments[0] == 0x2001 && segments[1] == 0x0db8)) // Documentation
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_permitted_addresses() {
+        let policy = TargetPolicy::default();
+
+        for address in &[
+            "93.184.216.34",
+            "8.8.8.8",
+            "2606:2800:220:1:248:1893:25c8:1946",
+        ] {
+            let address: IpAddr = address.parse().unwrap();
+            assert!(policy.permits_address(&address), "{}", address);
+        }
+    }
+
+    #[test]
+    fn test_forbidden_addresses() {
+        let policy = TargetPolicy::default();
+
+        for address in &[
+            "127.0.0.1",
+            "127.1.2.3",
+            "0.0.0.0",
+            "10.0.0.1",
+            "172.16.0.1",
+            "192.168.1.1",
+            "169.254.169.254",
+            "100.64.0.1",
+            "224.0.0.1",
+            "255.255.255.255",
+            "::",
+            "::1",
+            "fc00::1",
+            "fd12:3456::1",
+            "fe80::1",
+            "ff02::1",
+            "::ffff:127.0.0.1",
+            "::ffff:169.254.169.254",
+            "64:ff9b::a00:1",
+        ] {
+            let address: IpAddr = address.parse().unwrap();
+            assert!(!policy.permits_address(&address), "{}", address);
+        }
+    }
+
+    #[test]
+    fn test_allow_loopback() {
+        let policy = TargetPolicy {
+            allow_loopback: true,
+        };
+
+        for address in &["127.0.0.1", "::1", "::ffff:127.0.0.1"] {
+            let address: IpAddr = address.parse().unwrap();
+            assert!(policy.permits_address(&address), "{}", address);
+        }
+
+        let address: IpAddr = "10.0.0.1".parse().unwrap();
+        assert!(!policy.permits_address(&address));
+    }
+
+    #[test]
+    fn test_urls() {
+        let policy = TargetPolicy::default();
+
+        for url in &["https://example.com/hook", "http://93.184.216.34:8080/"] {
+            assert!(policy.permits_url(&Url::parse(url).unwrap()), "{}", url);
+        }
+
+        for url in &[
+            "http://localhost/",
+            "http://LOCALHOST./",
+            "http://api.localhost/",
+            "http://127.0.0.1:8080/",
+            "http://2130706433/",
+            "http://[::1]/",
+            "http://169.254.169.254/latest/meta-data/",
+            "http://192.168.0.1/",
+        ] {
+            assert!(!policy.permits_url(&Url::parse(url).unwrap()), "{}", url);
+        }
+    }
+}
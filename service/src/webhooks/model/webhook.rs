@@ -0,0 +1,18 @@
+use super::WebhookID;
+use crate::{model::Model, worlds::WorldID};
+
+/// Data to represent a webhook that is registered to receive events for a world
+#[derive(Debug, Clone)]
+pub struct WebhookData {
+    /// The ID of the world that the webhook receives events for
+    pub world: WorldID,
+
+    /// The URL to deliver events to
+    pub url: String,
+
+    /// The secret used to sign the payloads that are delivered
+    pub secret: String,
+}
+
+/// Model representation of a webhook
+pub type WebhookModel = Model<WebhookID, WebhookData>;
@@ -0,0 +1,111 @@
+use crate::worlds::WorldID;
+use chrono::{DateTime, Utc};
+use serde_json::{json, Value};
+
+/// The kinds of event that are delivered to webhooks
+#[derive(Debug, PartialEq, Clone, strum_macros::EnumString, strum_macros::Display)]
+pub enum WebhookEventKind {
+    /// A random table was created
+    #[strum(serialize = "table.created")]
+    TableCreated,
+    /// A random table was updated
+    #[strum(serialize = "table.updated")]
+    TableUpdated,
+    /// A random table was deleted
+    #[strum(serialize = "table.deleted")]
+    TableDeleted,
+    /// A world was created
+    #[strum(serialize = "world.created")]
+    WorldCreated,
+    /// The details or publication status of a world were updated
+    #[strum(serialize = "world.updated")]
+    WorldUpdated,
+    /// A campaign was created
+    #[strum(serialize = "campaign.created")]
+    CampaignCreated,
+    /// A session was created
+    #[strum(serialize = "session.created")]
+    SessionCreated,
+    /// A character was created
+    #[strum(serialize = "character.created")]
+    CharacterCreated,
+    /// A character was updated
+    #[strum(serialize = "character.updated")]
+    CharacterUpdated,
+    /// A character was deleted
+    #[strum(serialize = "character.deleted")]
+    CharacterDeleted,
+}
+
+/// An event that has happened within a world, to be delivered to the webhooks for that world
+#[derive(Debug, Clone)]
+pub struct WebhookEvent {
+    /// The ID of the world that the event happened in
+    pub world: WorldID,
+
+    /// What happened
+    pub kind: WebhookEventKind,
+
+    /// A link to the resource that the event happened to
+    pub target: String,
+}
+
+impl WebhookEvent {
+    /// Build the JSON payload to deliver for this event
+    ///
+    /// # Parameters
+    /// - `occurred` - When the event happened
+    ///
+    /// # Returns
+    /// The payload to deliver
+    pub fn payload(&self, occurred: &DateTime<Utc>) -> Value {
+        json!({
+          "event": self.kind.to_string(),
+          "world": format!("/worlds/{}", self.world),
+          "target": self.target,
+          "occurred": occurred
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_event_names() {
+        assert_eq!(WebhookEventKind::TableCreated.to_string(), "table.created");
+        assert_eq!(WebhookEventKind::TableDeleted.to_string(), "table.deleted");
+        assert_eq!(WebhookEventKind::WorldUpdated.to_string(), "world.updated");
+        assert_eq!(
+            WebhookEventKind::CharacterDeleted.to_string(),
+            "character.deleted"
+        );
+        assert_eq!(
+            "session.created".parse(),
+            Ok(WebhookEventKind::SessionCreated)
+        );
+    }
+
+    #[test]
+    fn test_payload() {
+        let world =
+            WorldID::new(uuid::Uuid::parse_str("d6c124d5-1060-48ef-aeb8-e591a8261c2b").unwrap());
+        let event = WebhookEvent {
+            world,
+            kind: WebhookEventKind::TableCreated,
+            target: "/worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b/tables/names".to_owned(),
+        };
+
+        assert_eq!(
+            event.payload(&Utc.ymd(2020, 7, 1).and_hms(12, 0, 0)),
+            json!({
+              "event": "table.created",
+              "world": "/worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b",
+              "target": "/worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b/tables/names",
+              "occurred": "2020-07-01T12:00:00Z"
+            })
+        );
+    }
+}
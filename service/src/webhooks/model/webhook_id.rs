@@ -0,0 +1,61 @@
+use bytes::BytesMut;
+use postgres_types::{accepts, to_sql_checked, FromSql, IsNull, ToSql, Type};
+use rocket::{http::RawStr, request};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use uuid::Uuid;
+
+/// The ID of a Webhook
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, FromSql)]
+pub struct WebhookID(Uuid);
+
+impl WebhookID {
+    /// Wrap a UUID as a Webhook ID object
+    ///
+    /// # Parameters
+    /// - `uuid` - The UUID to wrap
+    ///
+    /// # The Webhook ID
+    pub fn new(uuid: Uuid) -> Self {
+        WebhookID(uuid)
+    }
+}
+
+impl Default for WebhookID {
+    fn default() -> Self {
+        Self(Uuid::new_v4())
+    }
+}
+
+impl ToSql for WebhookID {
+    accepts!(UUID);
+
+    to_sql_checked!();
+
+    fn to_sql(
+        &self,
+        t: &Type,
+        w: &mut BytesMut,
+    ) -> Result<IsNull, Box<dyn std::error::Error + Sync + Send>> {
+        self.0.to_sql(t, w)
+    }
+}
+
+impl<'r> request::FromParam<'r> for WebhookID {
+    type Error = &'r RawStr;
+
+    fn from_param(param: &'r RawStr) -> Result<Self, Self::Error> {
+        param
+            .percent_decode()
+            .map(|cow| cow.into_owned())
+            .map_err(|_| param)
+            .and_then(|webhook_id| Uuid::parse_str(&webhook_id).map_err(|_| param))
+            .map(|webhook_id| WebhookID::new(webhook_id))
+    }
+}
+
+impl Display for WebhookID {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
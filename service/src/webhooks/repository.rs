@@ -0,0 +1,29 @@
+mod create;
+mod delete;
+mod deliveries;
+mod errors;
+mod find;
+mod parse;
+
+use crate::infrastructure::database::Database;
+pub use errors::*;
+
+/// Repository used to access Webhook data from the database
+#[derive(Clone)]
+pub(super) struct WebhookRepository {
+    /// The database with which to access webhook records
+    database: Database,
+}
+
+impl WebhookRepository {
+    /// Create a new Webhook Repository to work with
+    ///
+    /// # Parameters
+    /// - `database` - The database connection to use
+    ///
+    /// # Returns
+    /// The Webhook Repository to use
+    pub(super) fn new(database: Database) -> Self {
+        Self { database }
+    }
+}
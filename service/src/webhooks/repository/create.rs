@@ -0,0 +1,45 @@
+use super::{SaveWebhookError, WebhookRepository};
+use crate::webhooks::model::*;
+use chrono::Utc;
+use uuid::Uuid;
+
+impl WebhookRepository {
+    /// Create a new webhook in the database
+    ///
+    /// # Parameters
+    /// - `webhook` - The webhook to create
+    ///
+    /// # Returns
+    /// The newly created webhook
+    ///
+    /// # Errors
+    /// Any errors that occurred creating the new webhook
+    #[tracing::instrument(skip(self))]
+    pub fn create(&self, webhook: WebhookData) -> Result<WebhookModel, SaveWebhookError> {
+        let id = WebhookID::default();
+        let now = Utc::now();
+        let version = Uuid::new_v4();
+
+        let mut connection = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+
+        let new_webhook = connection
+            .query_one(
+                "INSERT INTO webhooks(webhook_id, version, created, updated, world_id, url, secret)
+                VALUES($1, $2, $3, $3, $4, $5, $6) RETURNING *",
+                &[
+                    &id,
+                    &version,
+                    &now,
+                    &webhook.world,
+                    &webhook.url,
+                    &webhook.secret,
+                ],
+            )
+            .map(|row| self.parse_row(&row))?;
+
+        Ok(new_webhook)
+    }
+}
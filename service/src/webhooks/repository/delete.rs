@@ -0,0 +1,29 @@
+use super::WebhookRepository;
+use crate::{webhooks::WebhookID, worlds::WorldID};
+
+impl WebhookRepository {
+    /// Delete a webhook from a world
+    ///
+    /// # Parameters
+    /// - `world` - The ID of the world that the webhook is for
+    /// - `id` - The ID of the webhook to delete
+    ///
+    /// # Returns
+    /// `true` if the webhook was deleted. `false` if it didn't exist
+    #[tracing::instrument(skip(self))]
+    pub fn delete(&self, world: &WorldID, id: &WebhookID) -> bool {
+        let mut connection = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+
+        let count = connection
+            .execute(
+                "DELETE FROM webhooks WHERE webhook_id = $1 AND world_id = $2",
+                &[id, world],
+            )
+            .expect("Failed to delete webhook");
+
+        count > 0
+    }
+}
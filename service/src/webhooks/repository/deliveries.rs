@@ -0,0 +1,292 @@
+use super::WebhookRepository;
+use crate::{
+    model::{Page, Pagination},
+    webhooks::{DeliveryID, DeliveryModel, DeliveryStatus, WebhookID, WebhookModel},
+};
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+impl WebhookRepository {
+    /// Create a new, pending, delivery of an event to a webhook. The delivery is due to be attempted
+    /// immediately
+    ///
+    /// # Parameters
+    /// - `webhook` - The ID of the webhook to deliver to
+    /// - `event` - The name of the event being delivered
+    /// - `payload` - The exact payload to deliver
+    ///
+    /// # Returns
+    /// The new delivery
+    #[tracing::instrument(skip(self))]
+    pub fn create_delivery(
+        &self,
+        webhook: &WebhookID,
+        event: &str,
+        payload: &str,
+    ) -> DeliveryModel {
+        let id = DeliveryID::new(Uuid::new_v4());
+        let now = Utc::now();
+
+        let mut connection = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+
+        connection
+            .query_one(
+                "INSERT INTO webhook_deliveries(delivery_id, created, webhook_id, event, payload, status, next_attempt)
+                VALUES($1, $2, $3, $4, $5, $6, $2) RETURNING *",
+                &[
+                    &id,
+                    &now,
+                    webhook,
+                    &event,
+                    &payload,
+                    &DeliveryStatus::Pending.to_string(),
+                ],
+            )
+            .map(|row| self.parse_delivery_row(&row))
+            .expect("Failed to create webhook delivery")
+    }
+
+    /// Claim the pending delivery that has been due for the longest, so that no other worker will
+    /// attempt it until the claim expires.
+    ///
+    /// If the claim expires before an attempt is recorded - for example because the service was
+    /// stopped part way through - then the delivery becomes due again and will be claimed by another
+    /// worker
+    ///
+    /// # Parameters
+    /// - `claim_until` - When the claim expires
+    ///
+    /// # Returns
+    /// The claimed delivery, including all of the attempts made so far, and the webhook that it is
+    /// to be delivered to. `None` if there are no deliveries due
+    #[tracing::instrument(skip(self))]
+    pub fn claim_due_delivery(
+        &self,
+        claim_until: &DateTime<Utc>,
+    ) -> Option<(WebhookModel, DeliveryModel)> {
+        let mut connection = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+
+        let delivery = connection
+            .query_opt(
+                "UPDATE webhook_deliveries SET next_attempt = $1
+                WHERE delivery_id = (
+                    SELECT delivery_id FROM webhook_deliveries
+                    WHERE status = $2 AND next_attempt <= $3
+                    ORDER BY next_attempt ASC
+                    LIMIT 1
+                    FOR UPDATE SKIP LOCKED
+                )
+                RETURNING *",
+                &[
+                    claim_until,
+                    &DeliveryStatus::Pending.to_string(),
+                    &Utc::now(),
+                ],
+            )
+            .expect("Failed to claim webhook delivery")
+            .map(|row| self.parse_delivery_row(&row))?;
+
+        // The webhook may have been deleted since the delivery was claimed, taking the delivery with it
+        let webhook = connection
+            .query_opt(
+                "SELECT * FROM webhooks WHERE webhook_id = $1",
+                &[&delivery.webhook],
+            )
+            .expect("Failed to load webhook for delivery")
+            .map(|row| self.parse_row(&row))?;
+
+        Some((webhook, self.load_attempts(vec![delivery]).remove(0)))
+    }
+
+    /// Record an attempt to deliver an event, and the resulting status of the delivery
+    ///
+    /// # Parameters
+    /// - `delivery` - The ID of the delivery
+    /// - `response_status` - The HTTP status code that the receiver responded with, if any
+    /// - `error` - A description of why the attempt failed, if it did
+    /// - `status` - The status of the delivery after this attempt
+    /// - `next_attempt` - When the next attempt is due, if the delivery is still pending
+    #[tracing::instrument(skip(self))]
+    pub fn record_attempt(
+        &self,
+        delivery: &DeliveryID,
+        response_status: Option<u16>,
+        error: Option<&str>,
+        status: &DeliveryStatus,
+        next_attempt: Option<DateTime<Utc>>,
+    ) {
+        let response_status = response_status.map(i32::from);
+
+        let mut connection = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+        let mut transaction = connection
+            .transaction()
+            .expect("Failed to start transaction");
+
+        transaction
+            .execute(
+                "INSERT INTO webhook_delivery_attempts(delivery_id, attempted, response_status, error)
+                VALUES($1, $2, $3, $4)",
+                &[delivery, &Utc::now(), &response_status, &error],
+            )
+            .expect("Failed to record delivery attempt");
+        transaction
+            .execute(
+                "UPDATE webhook_deliveries SET status = $1, next_attempt = $2 WHERE delivery_id = $3",
+                &[&status.to_string(), &next_attempt, delivery],
+            )
+            .expect("Failed to update delivery status");
+
+        transaction
+            .commit()
+            .expect("Failed to commit delivery attempt");
+    }
+
+    /// Find a single delivery to a webhook, including all of the attempts made
+    ///
+    /// # Parameters
+    /// - `webhook` - The ID of the webhook
+    /// - `id` - The ID of the delivery
+    ///
+    /// # Returns
+    /// The delivery, if it was found
+    #[tracing::instrument(skip(self))]
+    pub fn find_delivery_by_id(
+        &self,
+        webhook: &WebhookID,
+        id: &DeliveryID,
+    ) -> Option<DeliveryModel> {
+        let mut connection = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+
+        let delivery = connection
+            .query_opt(
+                "SELECT * FROM webhook_deliveries WHERE delivery_id = $1 AND webhook_id = $2",
+                &[id, webhook],
+            )
+            .expect("Failed to query for delivery by ID")
+            .map(|row| self.parse_delivery_row(&row));
+
+        delivery.map(|delivery| self.load_attempts(vec![delivery]).remove(0))
+    }
+
+    /// List the deliveries made to a webhook, most recent first
+    ///
+    /// # Parameters
+    /// - `webhook` - The ID of the webhook
+    /// - `pagination` - The pagination details for which page of results are wanted
+    ///
+    /// # Returns
+    /// The requested page of results
+    #[tracing::instrument(skip(self))]
+    pub fn list_deliveries(
+        &self,
+        webhook: &WebhookID,
+        pagination: &Pagination,
+    ) -> Page<DeliveryModel> {
+        let mut connection = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+
+        let select_query = format!(
+            "SELECT * FROM webhook_deliveries WHERE webhook_id = $1 ORDER BY created DESC, delivery_id ASC OFFSET {} LIMIT {}",
+            pagination.offset, pagination.count
+        );
+        let records: Vec<DeliveryModel> = connection
+            .query(select_query.as_str(), &[webhook])
+            .map(|rows| {
+                rows.iter()
+                    .map(|row| self.parse_delivery_row(row))
+                    .collect()
+            })
+            .expect("Failed to select deliveries");
+
+        let num_records = records.len() as u64;
+        let total = if records.is_empty() || num_records == pagination.count {
+            // If we got zero records back, or exactly the number we asked for, then we don't know
+            // how many there are in the database
+            let count: i32 = connection
+                .query_one(
+                    "SELECT COUNT(*)::INTEGER AS c FROM webhook_deliveries WHERE webhook_id = $1",
+                    &[webhook],
+                )
+                .map(|row| row.get("c"))
+                .expect("Failed to count deliveries");
+            count as u64
+        } else {
+            pagination.offset + num_records
+        };
+
+        Page {
+            entries: self.load_attempts(records),
+            offset: pagination.offset,
+            count: pagination.count,
+            total,
+        }
+    }
+
+    /// Load the attempts for each of the given deliveries
+    ///
+    /// # Parameters
+    /// - `deliveries` - The deliveries to load the attempts for
+    ///
+    /// # Returns
+    /// The same deliveries, in the same order, with their attempts populated
+    fn load_attempts(&self, mut deliveries: Vec<DeliveryModel>) -> Vec<DeliveryModel> {
+        if deliveries.is_empty() {
+            return deliveries;
+        }
+
+        let mut connection = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+
+        let delivery_id_binds = deliveries
+            .iter()
+            .enumerate()
+            .map(|(index, _)| format!("${}", index + 1))
+            .collect::<Vec<String>>()
+            .join(",");
+        let query = format!(
+            "SELECT * FROM webhook_delivery_attempts WHERE delivery_id IN ({}) ORDER BY attempted ASC",
+            delivery_id_binds
+        );
+
+        let binds: Vec<&(dyn postgres::types::ToSql + Sync)> = deliveries
+            .iter()
+            .map(|delivery| &delivery.id as &(dyn postgres::types::ToSql + Sync))
+            .collect();
+
+        let mut attempts: HashMap<Uuid, Vec<_>> = HashMap::new();
+        for row in connection
+            .query(query.as_str(), &binds[..])
+            .expect("Failed to select delivery attempts")
+        {
+            let delivery_id: Uuid = row.get("delivery_id");
+            attempts
+                .entry(delivery_id)
+                .or_default()
+                .push(self.parse_attempt_row(&row));
+        }
+
+        for delivery in deliveries.iter_mut() {
+            let delivery_id: Uuid = delivery.id.clone().into();
+            delivery.attempts = attempts.remove(&delivery_id).unwrap_or_default();
+        }
+
+        deliveries
+    }
+}
@@ -0,0 +1,52 @@
+use postgres::error::{DbError, SqlState};
+
+/// Errors that can occur when saving a webhook record
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum SaveWebhookError {
+    /// An unknown error occurred
+    #[error("An unknown error occurred")]
+    UnknownError,
+
+    /// The world that the webhook is for does not exist
+    #[error("The desired world does not exist")]
+    UnknownWorld,
+}
+
+impl From<postgres::Error> for SaveWebhookError {
+    /// Convert a database error into a SaveWebhookError.
+    ///
+    /// The conversion returns an `UnknownWorld` iff the error is a `FOREIGN_KEY_VIOLATION` and the constraint
+    /// is `webhooks_world_id_fkey`. Otherwise it returns an `UnknownError`
+    ///
+    /// # Parameters
+    /// - `e` - The error to convert
+    ///
+    /// # Returns
+    /// The new error code
+    fn from(e: postgres::Error) -> Self {
+        let mut result = None;
+
+        if e.code() == Some(&SqlState::FOREIGN_KEY_VIOLATION) {
+            let db_error: Option<DbError> = e
+                .into_source()
+                .and_then(|e| e.downcast_ref::<DbError>().cloned());
+
+            result = db_error
+                .and_then(|e| e.constraint().map(|c| c.to_owned()))
+                .map(|constraint| match constraint.as_ref() {
+                    "webhooks_world_id_fkey" => SaveWebhookError::UnknownWorld,
+                    _ => {
+                        tracing::warn!(
+                            "Unexpected foreign key constraint violation error: {:?}",
+                            constraint
+                        );
+                        SaveWebhookError::UnknownError
+                    }
+                });
+        } else {
+            tracing::warn!("Unexpected database error: {:?}", e);
+        }
+
+        result.unwrap_or(SaveWebhookError::UnknownError)
+    }
+}
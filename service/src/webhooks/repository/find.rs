@@ -0,0 +1,100 @@
+use super::WebhookRepository;
+use crate::{
+    model::{Page, Pagination},
+    webhooks::{WebhookID, WebhookModel},
+    worlds::WorldID,
+};
+
+impl WebhookRepository {
+    /// Find a single webhook for a world
+    ///
+    /// # Parameters
+    /// - `world` - The ID of the world that the webhook is for
+    /// - `id` - The ID of the webhook
+    ///
+    /// # Returns
+    /// The webhook, if it was found
+    #[tracing::instrument(skip(self))]
+    pub fn find_webhook_by_id(&self, world: &WorldID, id: &WebhookID) -> Option<WebhookModel> {
+        let mut connection = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+
+        connection
+            .query_opt(
+                "SELECT * FROM webhooks WHERE webhook_id = $1 AND world_id = $2",
+                &[id, world],
+            )
+            .expect("Failed to query for webhook by ID")
+            .map(|row| self.parse_row(&row))
+    }
+
+    /// Find every webhook that is registered for a world
+    ///
+    /// # Parameters
+    /// - `world` - The ID of the world
+    ///
+    /// # Returns
+    /// The webhooks for the world
+    #[tracing::instrument(skip(self))]
+    pub fn find_webhooks_for_world(&self, world: &WorldID) -> Vec<WebhookModel> {
+        let mut connection = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+
+        connection
+            .query("SELECT * FROM webhooks WHERE world_id = $1", &[world])
+            .map(|rows| rows.iter().map(|row| self.parse_row(row)).collect())
+            .expect("Failed to select webhooks for world")
+    }
+
+    /// List the webhooks that are registered for a world, oldest first
+    ///
+    /// # Parameters
+    /// - `world` - The ID of the world
+    /// - `pagination` - The pagination details for which page of results are wanted
+    ///
+    /// # Returns
+    /// The requested page of results
+    #[tracing::instrument(skip(self))]
+    pub fn list_webhooks(&self, world: &WorldID, pagination: &Pagination) -> Page<WebhookModel> {
+        let mut connection = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+
+        let select_query = format!(
+            "SELECT * FROM webhooks WHERE world_id = $1 ORDER BY created ASC, webhook_id ASC OFFSET {} LIMIT {}",
+            pagination.offset, pagination.count
+        );
+        let records: Vec<WebhookModel> = connection
+            .query(select_query.as_str(), &[world])
+            .map(|rows| rows.iter().map(|row| self.parse_row(row)).collect())
+            .expect("Failed to select webhooks");
+
+        let num_records = records.len() as u64;
+        let total = if records.is_empty() || num_records == pagination.count {
+            // If we got zero records back, or exactly the number we asked for, then we don't know
+            // how many there are in the database
+            let count: i32 = connection
+                .query_one(
+                    "SELECT COUNT(*)::INTEGER AS c FROM webhooks WHERE world_id = $1",
+                    &[world],
+                )
+                .map(|row| row.get("c"))
+                .expect("Failed to count webhooks");
+            count as u64
+        } else {
+            pagination.offset + num_records
+        };
+
+        Page {
+            entries: records,
+            offset: pagination.offset,
+            count: pagination.count,
+            total,
+        }
+    }
+}
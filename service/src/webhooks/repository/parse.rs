@@ -0,0 +1,66 @@
+use super::WebhookRepository;
+use crate::{model::Identity, webhooks::model::*};
+use postgres::row::Row;
+
+impl WebhookRepository {
+    /// Parse a row from the database into a Webhook Model
+    ///
+    /// # Parameters
+    /// - `row` - The row to parse
+    ///
+    /// # Returns
+    /// The webhook that the row represented
+    pub(super) fn parse_row(&self, row: &Row) -> WebhookModel {
+        WebhookModel {
+            identity: Identity {
+                id: row.get("webhook_id"),
+                version: row.get("version"),
+                created: row.get("created"),
+                updated: row.get("updated"),
+            },
+            data: WebhookData {
+                world: row.get("world_id"),
+                url: row.get("url"),
+                secret: row.get("secret"),
+            },
+        }
+    }
+
+    /// Parse a row from the database into a Delivery Model, without any of the attempts
+    ///
+    /// # Parameters
+    /// - `row` - The row to parse
+    ///
+    /// # Returns
+    /// The delivery that the row represented
+    pub(super) fn parse_delivery_row(&self, row: &Row) -> DeliveryModel {
+        let status: String = row.get("status");
+
+        DeliveryModel {
+            id: row.get("delivery_id"),
+            created: row.get("created"),
+            webhook: row.get("webhook_id"),
+            event: row.get("event"),
+            payload: row.get("payload"),
+            status: status.parse().expect("Unknown delivery status in database"),
+            attempts: vec![],
+        }
+    }
+
+    /// Parse a row from the database into an Attempt Model
+    ///
+    /// # Parameters
+    /// - `row` - The row to parse
+    ///
+    /// # Returns
+    /// The attempt that the row represented
+    pub(super) fn parse_attempt_row(&self, row: &Row) -> AttemptModel {
+        let response_status: Option<i32> = row.get("response_status");
+
+        AttemptModel {
+            attempted: row.get("attempted"),
+            response_status: response_status.map(|status| status as u16),
+            error: row.get("error"),
+        }
+    }
+}
@@ -0,0 +1,45 @@
+mod create_webhook;
+mod delete;
+mod deliver;
+mod dispatch;
+mod find;
+mod redeliver;
+
+use super::{repository::WebhookRepository, TargetPolicy};
+pub use create_webhook::CreateWebhookError;
+use deliver::DeliveryQueue;
+use std::sync::Arc;
+
+/// Service Layer for dealing with Webhooks
+#[derive(Clone)]
+pub struct WebhooksService {
+    repository: WebhookRepository,
+    policy: TargetPolicy,
+    queue: Arc<DeliveryQueue>,
+}
+
+impl WebhooksService {
+    /// Create a new instance of the Webhooks Service
+    ///
+    /// # Parameters
+    /// - `repository` - The repository to access webhook data with
+    /// - `policy` - The rules for which addresses webhooks may target
+    ///
+    /// # Returns
+    /// The Webhooks Service ready to use
+    pub(super) fn new(repository: WebhookRepository, policy: TargetPolicy) -> Self {
+        Self {
+            repository,
+            policy,
+            queue: Arc::new(DeliveryQueue::default()),
+        }
+    }
+
+    /// Get the rules for which addresses webhooks may target
+    ///
+    /// # Returns
+    /// The target policy
+    pub fn target_policy(&self) -> &TargetPolicy {
+        &self.policy
+    }
+}
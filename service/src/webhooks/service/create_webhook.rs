@@ -0,0 +1,42 @@
+use crate::webhooks::{repository::SaveWebhookError, *};
+
+/// Errors that can occur when creating a new webhook
+#[derive(Debug, thiserror::Error)]
+pub enum CreateWebhookError {
+    /// An unknown error occurred
+    #[error("An unknown error occurred")]
+    UnknownError,
+
+    /// The world that the webhook is for does not exist
+    #[error("The desired world does not exist")]
+    UnknownWorld,
+}
+
+impl WebhooksService {
+    /// Register a new webhook for a world
+    ///
+    /// # Parameters
+    /// - `webhook` - The webhook to create
+    ///
+    /// # Returns
+    /// The newly created webhook
+    ///
+    /// # Errors
+    /// Any errors that occurred creating the new webhook
+    pub fn create_webhook(&self, webhook: WebhookData) -> Result<WebhookModel, CreateWebhookError> {
+        tracing::debug!(world = ?webhook.world, url = ?webhook.url, "Creating webhook");
+        let new_webhook = self.repository.create(webhook)?;
+        tracing::debug!(id = ?new_webhook.identity.id, "Created webhook");
+
+        Ok(new_webhook)
+    }
+}
+
+impl From<SaveWebhookError> for CreateWebhookError {
+    fn from(e: SaveWebhookError) -> Self {
+        match e {
+            SaveWebhookError::UnknownWorld => CreateWebhookError::UnknownWorld,
+            _ => CreateWebhookError::UnknownError,
+        }
+    }
+}
@@ -0,0 +1,18 @@
+use crate::{
+    webhooks::{WebhookID, WebhooksService},
+    worlds::WorldID,
+};
+
+impl WebhooksService {
+    /// Delete a webhook from a world, so that it no longer receives events
+    ///
+    /// # Parameters
+    /// - `world` - The ID of the world that the webhook is for
+    /// - `id` - The ID of the webhook to delete
+    ///
+    /// # Returns
+    /// `true` if the webhook was deleted. `false` if it didn't exist
+    pub fn delete_webhook(&self, world: &WorldID, id: &WebhookID) -> bool {
+        self.repository.delete(world, id)
+    }
+}
@@ -0,0 +1,210 @@
+use super::WebhooksService;
+use crate::webhooks::{
+    repository::WebhookRepository, sign, DeliveryModel, DeliveryStatus, TargetPolicy, WebhookModel,
+};
+use chrono::Utc;
+use std::{
+    net::{SocketAddr, ToSocketAddrs},
+    panic::AssertUnwindSafe,
+    sync::{Arc, Condvar, Mutex, Weak},
+    time::Duration,
+};
+
+/// The maximum number of times to attempt to deliver an event
+const MAX_ATTEMPTS: u32 = 5;
+
+/// How long to wait before the first retry. This doubles for every subsequent retry
+const INITIAL_BACKOFF: Duration = Duration::from_secs(1);
+
+/// How long to wait for a receiver to respond before treating the attempt as failed
+const TIMEOUT_MILLIS: u64 = 10_000;
+
+/// The number of workers delivering events concurrently
+const WORKER_COUNT: usize = 4;
+
+/// How often an idle worker checks for deliveries that have become due
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// How long a worker has to make an attempt before the delivery is picked up by another worker. This
+/// must comfortably exceed the time that a single attempt can take
+const CLAIM_DURATION: Duration = Duration::from_secs(60);
+
+/// Signal shared between the service and the workers, used to wake the workers up when new deliveries
+/// have been created
+#[derive(Default)]
+pub(super) struct DeliveryQueue {
+    /// Whether there are new deliveries that the workers haven't yet looked for
+    pending: Mutex<bool>,
+    /// Condition Variable used to wake the workers
+    condvar: Condvar,
+}
+
+impl DeliveryQueue {
+    /// Wake every waiting worker
+    fn wake(&self) {
+        *self.pending.lock().expect("Delivery queue lock poisoned") = true;
+        self.condvar.notify_all();
+    }
+
+    /// Wait until either a worker is woken or the poll interval has elapsed
+    fn wait(&self) {
+        let pending = self.pending.lock().expect("Delivery queue lock poisoned");
+        let (mut pending, _) = self
+            .condvar
+            .wait_timeout_while(pending, POLL_INTERVAL, |pending| !*pending)
+            .expect("Delivery queue lock poisoned");
+        *pending = false;
+    }
+}
+
+impl WebhooksService {
+    /// Start the fixed pool of workers that deliver events in the background.
+    ///
+    /// Pending deliveries are stored in the database, so any that were still outstanding when the
+    /// service last stopped are picked up again. The workers stop once the service has been dropped
+    pub(crate) fn start_workers(&self) {
+        for index in 0..WORKER_COUNT {
+            let worker = DeliveryWorker {
+                repository: self.repository.clone(),
+                policy: self.policy.clone(),
+                queue: Arc::downgrade(&self.queue),
+            };
+
+            std::thread::Builder::new()
+                .name(format!("webhooks-{}", index))
+                .spawn(move || worker.run())
+                .expect("Failed to start webhook delivery worker");
+        }
+    }
+
+    /// Wake the workers so that they deliver any newly created deliveries
+    pub(super) fn wake_workers(&self) {
+        self.queue.wake();
+    }
+}
+
+/// A single worker that delivers events to webhooks
+struct DeliveryWorker {
+    repository: WebhookRepository,
+    policy: TargetPolicy,
+    queue: Weak<DeliveryQueue>,
+}
+
+impl DeliveryWorker {
+    /// Repeatedly make every delivery that is due, and then wait for more to become due
+    fn run(&self) {
+        loop {
+            // Failing to reach the database mustn't kill the worker, so just try again next time
+            if std::panic::catch_unwind(AssertUnwindSafe(|| self.deliver_due())).is_err() {
+                tracing::warn!("Failed to process webhook deliveries");
+            }
+
+            match self.queue.upgrade() {
+                Some(queue) => queue.wait(),
+                None => break,
+            }
+        }
+
+        tracing::debug!("Stopping webhook delivery worker");
+    }
+
+    /// Claim and attempt deliveries until there are no more that are due
+    fn deliver_due(&self) {
+        loop {
+            let claim_until = Utc::now()
+                + chrono::Duration::from_std(CLAIM_DURATION).expect("Claim duration out of range");
+
+            match self.repository.claim_due_delivery(&claim_until) {
+                Some((webhook, delivery)) => self.attempt(&webhook, &delivery),
+                None => break,
+            }
+        }
+    }
+
+    /// Make a single attempt to deliver an event to a webhook, and record the outcome. If the attempt
+    /// failed and there are attempts remaining then the delivery is rescheduled with exponential
+    /// backoff
+    ///
+    /// # Parameters
+    /// - `webhook` - The webhook to deliver to
+    /// - `delivery` - The delivery to make, including the attempts made so far
+    fn attempt(&self, webhook: &WebhookModel, delivery: &DeliveryModel) {
+        let signature = sign(&webhook.data.secret, &delivery.payload);
+        let attempt = delivery.attempts.len() as u32 + 1;
+
+        // Only connect to addresses that the policy permits, checking them after resolution so that
+        // a domain name can't be used to smuggle in an internal address
+        let policy = self.policy.clone();
+        let mut agent = ureq::agent();
+        agent.set_resolver(move |netloc: &str| resolve(&policy, netloc));
+
+        let response = agent
+            .post(&webhook.data.url)
+            .set("Content-Type", "application/json")
+            .set("X-Multiverse-Event", &delivery.event)
+            .set("X-Multiverse-Delivery", &delivery.id.to_string())
+            .set("X-Multiverse-Signature", &signature)
+            .redirects(0)
+            .timeout_connect(TIMEOUT_MILLIS)
+            .timeout_read(TIMEOUT_MILLIS)
+            .send_string(&delivery.payload);
+
+        let (response_status, error) = match response.synthetic_error() {
+            Some(e) => (None, Some(e.to_string())),
+            None if response.ok() => (Some(response.status()), None),
+            None => (
+                Some(response.status()),
+                Some(format!("Receiver responded with {}", response.status())),
+            ),
+        };
+
+        let status = if error.is_none() {
+            DeliveryStatus::Succeeded
+        } else if attempt >= MAX_ATTEMPTS {
+            DeliveryStatus::Failed
+        } else {
+            DeliveryStatus::Pending
+        };
+        let next_attempt = if status == DeliveryStatus::Pending {
+            let backoff = INITIAL_BACKOFF * 2u32.pow(attempt - 1);
+            Some(Utc::now() + chrono::Duration::from_std(backoff).expect("Backoff out of range"))
+        } else {
+            None
+        };
+        tracing::debug!(delivery = ?delivery.id, attempt = attempt, response_status = ?response_status, error = ?error, status = ?status, next_attempt = ?next_attempt, "Attempted delivery");
+
+        self.repository.record_attempt(
+            &delivery.id,
+            response_status,
+            error.as_deref(),
+            &status,
+            next_attempt,
+        );
+    }
+}
+
+/// Resolve the host and port that a webhook targets, refusing to connect at all if any of the
+/// addresses that it resolves to are not permitted
+///
+/// # Parameters
+/// - `policy` - The rules for which addresses are permitted
+/// - `netloc` - The host and port to resolve
+///
+/// # Returns
+/// The addresses to connect to
+fn resolve(policy: &TargetPolicy, netloc: &str) -> std::io::Result<Vec<SocketAddr>> {
+    let addresses: Vec<SocketAddr> = netloc.to_socket_addrs()?.collect();
+
+    if addresses
+        .iter()
+        .any(|address| !policy.permits_address(&address.ip()))
+    {
+        tracing::warn!(netloc = ?netloc, addresses = ?addresses, "Webhook resolved to a forbidden address");
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::PermissionDenied,
+            "The receiver resolved to a private or internal address",
+        ));
+    }
+
+    Ok(addresses)
+}
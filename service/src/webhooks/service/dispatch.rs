@@ -0,0 +1,26 @@
+use super::WebhooksService;
+use crate::webhooks::WebhookEvent;
+use chrono::Utc;
+
+impl WebhooksService {
+    /// Dispatch an event to every webhook registered for the world that it happened in.
+    ///
+    /// Delivery happens in the background from the queue of pending deliveries, so this returns without waiting for any receivers, and
+    /// failing to deliver an event is never allowed to fail the action that caused it
+    ///
+    /// # Parameters
+    /// - `event` - The event to dispatch
+    pub fn dispatch(&self, event: WebhookEvent) {
+        let payload = event.payload(&Utc::now()).to_string();
+        let event_name = event.kind.to_string();
+
+        for webhook in self.repository.find_webhooks_for_world(&event.world) {
+            let delivery =
+                self.repository
+                    .create_delivery(&webhook.identity.id, &event_name, &payload);
+            tracing::debug!(webhook = ?webhook.identity.id, delivery = ?delivery.id, "Dispatching event");
+        }
+
+        self.wake_workers();
+    }
+}
@@ -0,0 +1,48 @@
+use super::WebhooksService;
+use crate::{
+    model::{Page, Pagination},
+    webhooks::{DeliveryModel, WebhookID, WebhookModel},
+    worlds::WorldID,
+};
+
+impl WebhooksService {
+    /// Find a single webhook for a world
+    ///
+    /// # Parameters
+    /// - `world` - The ID of the world that the webhook is for
+    /// - `id` - The ID of the webhook
+    ///
+    /// # Returns
+    /// The webhook, if it was found
+    pub fn find_webhook_by_id(&self, world: &WorldID, id: &WebhookID) -> Option<WebhookModel> {
+        self.repository.find_webhook_by_id(world, id)
+    }
+
+    /// List the webhooks that are registered for a world, oldest first
+    ///
+    /// # Parameters
+    /// - `world` - The ID of the world
+    /// - `pagination` - The pagination details for which page of results are wanted
+    ///
+    /// # Returns
+    /// The requested page of results
+    pub fn list_webhooks(&self, world: &WorldID, pagination: &Pagination) -> Page<WebhookModel> {
+        self.repository.list_webhooks(world, pagination)
+    }
+
+    /// List the deliveries made to a webhook, most recent first
+    ///
+    /// # Parameters
+    /// - `webhook` - The ID of the webhook
+    /// - `pagination` - The pagination details for which page of results are wanted
+    ///
+    /// # Returns
+    /// The requested page of results
+    pub fn list_deliveries(
+        &self,
+        webhook: &WebhookID,
+        pagination: &Pagination,
+    ) -> Page<DeliveryModel> {
+        self.repository.list_deliveries(webhook, pagination)
+    }
+}
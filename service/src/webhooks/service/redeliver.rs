@@ -0,0 +1,29 @@
+use super::WebhooksService;
+use crate::webhooks::{DeliveryID, DeliveryModel, WebhookModel};
+
+impl WebhooksService {
+    /// Deliver the payload of a previous delivery to a webhook again, as a new delivery
+    ///
+    /// # Parameters
+    /// - `webhook` - The webhook to deliver to
+    /// - `id` - The ID of the previous delivery
+    ///
+    /// # Returns
+    /// The new delivery, or `None` if the previous delivery wasn't found
+    pub fn redeliver(&self, webhook: WebhookModel, id: &DeliveryID) -> Option<DeliveryModel> {
+        let previous = self
+            .repository
+            .find_delivery_by_id(&webhook.identity.id, id)?;
+
+        let delivery = self.repository.create_delivery(
+            &webhook.identity.id,
+            &previous.event,
+            &previous.payload,
+        );
+        tracing::debug!(previous = ?previous.id, delivery = ?delivery.id, "Redelivering event");
+
+        self.wake_workers();
+
+        Some(delivery)
+    }
+}
@@ -3,6 +3,9 @@ pub(crate) mod endpoints;
 mod model;
 mod repository;
 mod service;
+mod visibility;
 
 pub use model::*;
+pub(crate) use repository::{insert_world, SaveWorldError};
 pub use service::*;
+pub(crate) use visibility::*;
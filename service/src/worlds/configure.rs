@@ -1,5 +1,8 @@
 use super::{repository::WorldRepository, WorldsService};
-use crate::infrastructure::database::Database;
+use crate::{
+    activity::ActivityService, infrastructure::database::Database,
+    notifications::NotificationsService, quotas::QuotasService, webhooks::WebhooksService,
+};
 use std::sync::Arc;
 
 /// Application Configuration for the Worlds module
@@ -10,12 +13,31 @@ pub struct WorldsConfig {
 impl WorldsConfig {
     /// Create the Configuration for the Worlds Module
     ///
+    /// # Parameters
+    /// - `database` - The database connection to use
+    /// - `activity_service` - The activity service to record activity with
+    /// - `notifications_service` - The notifications service to notify users with
+    /// - `quotas_service` - The quotas service to limit how many worlds users can own
+    /// - `webhooks_service` - The webhooks service to dispatch events with
+    ///
     /// # Returns
     /// The Worlds Config object
-    pub fn new(database: Database) -> Self {
+    pub fn new(
+        database: Database,
+        activity_service: ActivityService,
+        notifications_service: NotificationsService,
+        quotas_service: QuotasService,
+        webhooks_service: WebhooksService,
+    ) -> Self {
         let repository = WorldRepository::new(database);
         Self {
-            worlds_service: WorldsService::new(repository),
+            worlds_service: WorldsService::new(
+                repository,
+                activity_service,
+                notifications_service,
+                quotas_service,
+                webhooks_service,
+            ),
         }
     }
 
@@ -30,9 +52,20 @@ impl WorldsConfig {
             config.manage(worlds_service.clone()).mount(
                 "/",
                 rocket::routes![
+                    super::endpoints::create_connection,
                     super::endpoints::create_world,
+                    super::endpoints::delete_connection,
+                    super::endpoints::delete_world_translation,
+                    super::endpoints::export_worlds_csv,
                     super::endpoints::get_world,
-                    super::endpoints::search_worlds
+                    super::endpoints::get_world_stats,
+                    super::endpoints::list_connections,
+                    super::endpoints::list_starred_worlds,
+                    super::endpoints::save_world_translation,
+                    super::endpoints::search_worlds,
+                    super::endpoints::star_world,
+                    super::endpoints::unstar_world,
+                    super::endpoints::update_world_status
                 ],
             )
         })
@@ -1,9 +1,32 @@
+mod create_connection;
 mod create_world;
-mod errors;
+mod delete_connection;
+mod delete_world_translation;
+pub(crate) mod errors;
+mod export_worlds_csv;
 mod get_world;
+mod get_world_stats;
+mod list_connections;
+mod list_starred_worlds;
 pub(crate) mod model;
+mod save_world_translation;
 mod search_worlds;
+mod star_world;
+mod unstar_world;
+mod update_world_status;
+pub(crate) mod world_owner;
 
+pub use create_connection::*;
 pub use create_world::*;
+pub use delete_connection::*;
+pub use delete_world_translation::*;
+pub use export_worlds_csv::*;
 pub use get_world::*;
+pub use get_world_stats::*;
+pub use list_connections::*;
+pub use list_starred_worlds::*;
+pub use save_world_translation::*;
 pub use search_worlds::*;
+pub use star_world::*;
+pub use unstar_world::*;
+pub use update_world_status::*;
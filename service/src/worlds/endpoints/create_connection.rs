@@ -0,0 +1,63 @@
+use super::{
+    errors::{WorldProblemType, WorldValidation},
+    model::{ConnectionRequest, ConnectionResponse},
+    world_owner::authorize_world_owner,
+};
+use crate::{
+    authorization::Authorizer,
+    http::problem::{Problem, ValidationProblem},
+    worlds::{CreateConnectionError, WorldID, WorldsService},
+};
+use rocket::{http::Status, post, State};
+use rocket_contrib::json::Json;
+
+/// Handler to connect a World to another World
+///
+/// # Parameters
+/// - `worlds_service` - The worlds service to use
+/// - `world` - The ID of the world to connect from
+/// - `body` - The details of the connection to make
+/// - `authorizer` - The authorizer to prove we're allowed to connect the world
+///
+/// # Returns
+/// The newly created connection, or a Problem if the connection failed
+#[tracing::instrument(name = "POST /worlds/{id}/connections", skip(worlds_service, body))]
+#[post("/worlds/<world>/connections", data = "<body>")]
+pub fn create_connection(
+    worlds_service: State<WorldsService>,
+    world: WorldID,
+    body: Json<ConnectionRequest>,
+    authorizer: Authorizer,
+) -> Result<ConnectionResponse, Problem> {
+    authorize_world_owner(&worlds_service, &authorizer, &world)?;
+
+    let connection = body.build(world)?;
+
+    let new_connection = worlds_service.create_connection(connection)?;
+    Ok(ConnectionResponse(new_connection))
+}
+
+impl From<CreateConnectionError> for Problem {
+    fn from(e: CreateConnectionError) -> Self {
+        match e {
+            CreateConnectionError::DuplicateConnection => Problem::new(
+                WorldProblemType::DuplicateConnection,
+                Status::UnprocessableEntity,
+            ),
+            CreateConnectionError::UnknownWorld => {
+                let mut problem = ValidationProblem::new();
+                problem.with_field_error("world", WorldValidation::UnknownWorld);
+                problem.build()
+            }
+            CreateConnectionError::SameWorld => {
+                let mut problem = ValidationProblem::new();
+                problem.with_field_error("world", WorldValidation::SameWorld);
+                problem.build()
+            }
+            _ => {
+                tracing::warn!(error = ?e, "An unexpected error occurred");
+                Problem::new(WorldProblemType::UnknownError, Status::InternalServerError)
+            }
+        }
+    }
+}
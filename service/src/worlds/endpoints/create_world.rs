@@ -1,29 +1,44 @@
 use crate::{
     authorization::Authorizer,
-    http::problem::{GenericValidation, Problem, ProblemType, ValidationProblem},
+    http::{
+        language::LanguageValidation,
+        problem::{GenericValidation, Problem, ProblemType, ValidationProblem},
+        publication::PublicationRequest,
+    },
+    model::LanguageTag,
+    templates::{
+        endpoints::errors::TemplateValidation, ApplyTemplateError, TemplateReference,
+        TemplatesService, WorldTemplate,
+    },
     worlds::{
-        endpoints::model::WorldResponse, CreateWorldError, UrlSlug, UrlSlugParseError, WorldData,
-        WorldsService,
+        endpoints::{
+            errors::WorldValidation,
+            model::{WorldResponse, WorldTranslationRequest},
+        },
+        CreateWorldError, UrlSlug, UrlSlugParseError, WorldData, WorldTranslation, WorldsService,
     },
 };
 use rocket::{http::Status, post, State};
 use rocket_contrib::json::Json;
 use serde::Deserialize;
+use std::collections::{BTreeMap, HashMap};
 use str_slug::slug;
 
 /// Handler to create a new World
 ///
 /// # Parameters
 /// - `worlds_service` - The worlds service to use
+/// - `templates_service` - The templates service to populate the new world with
 /// - `body` - The details of the world to create
 /// - `authorizer` - The authorizer to prove we're allowed to create a world
 ///
 /// # Returns
 /// The newly created world details, or a Problem if the creation failed
-#[tracing::instrument(name = "POST /worlds", skip(worlds_service))]
+#[tracing::instrument(name = "POST /worlds", skip(worlds_service, templates_service))]
 #[post("/worlds", data = "<body>")]
 pub fn create_world(
     worlds_service: State<WorldsService>,
+    templates_service: State<TemplatesService>,
     body: Json<CreateWorldRequest>,
     authorizer: Authorizer,
 ) -> Result<WorldResponse, Problem> {
@@ -33,25 +48,61 @@ pub fn create_world(
     let description = body.description();
     let url_slug = body.url_slug();
 
+    let mut problem = ValidationProblem::new();
+    let publication = body.publication.build(&mut problem);
+    let language = body.language(&mut problem);
+    let translations = body.translations(language.as_ref(), &mut problem);
+    let template = body.template(&templates_service, &mut problem);
+
     tracing::debug!(
         name = ?name,
         description = ?description,
         url_slug = ?url_slug,
+        publication = ?publication,
+        language = ?language,
+        translations = ?translations,
+        template = ?template.as_ref().map(|t| t.as_ref().map(|t| &t.reference)),
         "Creating new world"
     );
 
-    match (&name, &url_slug, &owner) {
-        (Some(name), Ok(url_slug), Some(owner)) => {
-            // Try to create the world
-            let new_world = worlds_service.create_world(WorldData {
+    match (
+        &name,
+        &url_slug,
+        &owner,
+        &publication,
+        language,
+        translations,
+        template,
+    ) {
+        (
+            Some(name),
+            Ok(url_slug),
+            Some(owner),
+            Some(publication),
+            Some(language),
+            Some(translations),
+            Some(template),
+        ) => {
+            let world = WorldData {
                 name: name.clone(),
                 description: description.unwrap_or("".to_owned()),
                 url_slug: url_slug.clone(),
                 owner: owner.clone(),
-            })?;
+                publication: publication.clone(),
+                language,
+                translations,
+                hidden: false,
+            };
+
+            // Try to create the world, along with the content of the template if there is one
+            let new_world = match template {
+                Some(template) => templates_service.create_world(world, &template)?,
+                None => worlds_service.create_world(world)?,
+            };
+
             Ok(WorldResponse(new_world))
         }
-        (_, _, None) => {
+        (_, _, None, _, _, _, _) => {
             tracing::error!("No authenticated user");
             unreachable!()
         }
@@ -59,8 +110,6 @@ pub fn create_world(
             // Return a validation problem
             tracing::warn!("Validation error creating world");
 
-            let mut problem = ValidationProblem::new();
-
             if name == None {
                 problem.with_field_error("name", GenericValidation::Missing);
             }
@@ -85,6 +134,16 @@ pub struct CreateWorldRequest {
     description: Option<String>,
     /// The URL Slug of the world. If omitted then this is generated from the name
     url_slug: Option<String>,
+    /// The publication state of the world. If omitted then the world is published immediately
+    #[serde(flatten)]
+    publication: PublicationRequest,
+    /// The language that the name and description are written in. If omitted then this is English
+    language: Option<String>,
+    /// Translations of the name and description into other languages, keyed by language tag
+    #[serde(default)]
+    translations: HashMap<String, WorldTranslationRequest>,
+    /// The template to populate the world from. If omitted then the world starts empty
+    template: Option<String>,
 }
 
 impl CreateWorldRequest {
@@ -107,6 +166,95 @@ impl CreateWorldRequest {
             .unwrap_or_else(|| slug(self.name.clone().unwrap_or("".to_owned())))
             .parse()
     }
+
+    /// Get the language that the world is written in
+    ///
+    /// # Parameters
+    /// - `problem` - The validation problem to record any errors into
+    ///
+    /// # Returns
+    /// The language, or `None` if it was invalid
+    fn language(&self, problem: &mut ValidationProblem) -> Option<LanguageTag> {
+        match self.language.as_ref().filter(|v| !v.trim().is_empty()) {
+            None => Some(LanguageTag::default()),
+            Some(language) => language.parse().ok().or_else(|| {
+                problem.with_field_error("language", LanguageValidation::InvalidLanguage);
+                None
+            }),
+        }
+    }
+
+    /// Get the translations of the world into other languages
+    ///
+    /// # Parameters
+    /// - `default_language` - The default language of the world, which can not also be translated into
+    /// - `problem` - The validation problem to record any errors into
+    ///
+    /// # Returns
+    /// The translations, or `None` if any of them were invalid
+    fn translations(
+        &self,
+        default_language: Option<&LanguageTag>,
+        problem: &mut ValidationProblem,
+    ) -> Option<BTreeMap<LanguageTag, WorldTranslation>> {
+        let mut translations = BTreeMap::new();
+        let mut valid = true;
+
+        for (language, translation) in &self.translations {
+            let field = format!("translations/{}", language);
+            let translation = translation.build(&format!("{}/", field), problem);
+            match language.parse::<LanguageTag>() {
+                Ok(language) if Some(&language) == default_language => {
+                    problem.with_field_error(field, WorldValidation::DefaultLanguageTranslation);
+                    valid = false;
+                }
+                Ok(language) => {
+                    if let Some(translation) = translation {
+                        translations.insert(language, translation);
+                    } else {
+                        valid = false;
+                    }
+                }
+                Err(_) => {
+                    problem.with_field_error(field, LanguageValidation::InvalidLanguage);
+                    valid = false;
+                }
+            }
+        }
+
+        if valid {
+            Some(translations)
+        } else {
+            None
+        }
+    }
+
+    /// Get the template to populate the world from
+    ///
+    /// # Parameters
+    /// - `templates_service` - The templates service to look up the template with
+    /// - `problem` - The validation problem to record any errors into
+    ///
+    /// # Returns
+    /// The template, `Some(None)` if no template was requested, or `None` if it was invalid
+    fn template(
+        &self,
+        templates_service: &TemplatesService,
+        problem: &mut ValidationProblem,
+    ) -> Option<Option<WorldTemplate>> {
+        match self.template.as_ref().filter(|v| !v.trim().is_empty()) {
+            None => Some(None),
+            Some(template) => template
+                .parse::<TemplateReference>()
+                .ok()
+                .and_then(|reference| templates_service.find_template(&reference))
+                .map(Some)
+                .or_else(|| {
+                    problem.with_field_error("template", TemplateValidation::UnknownTemplate);
+                    None
+                }),
+        }
+    }
 }
 
 /// Problem Types that can happen when registering a user
@@ -143,6 +291,7 @@ impl From<CreateWorldError> for Problem {
                 CreateWorldProblemType::DuplicateUrlSlug,
                 Status::UnprocessableEntity,
             ),
+            CreateWorldError::QuotaExceeded(e) => e.into(),
             _ => {
                 tracing::warn!(error = ?e, "An unexpected error occurred");
                 Problem::new(
@@ -153,3 +302,19 @@ impl From<CreateWorldError> for Problem {
         }
     }
 }
+
+impl From<ApplyTemplateError> for Problem {
+    fn from(e: ApplyTemplateError) -> Self {
+        match e {
+            ApplyTemplateError::DuplicateUrlSlug => CreateWorldError::DuplicateUrlSlug.into(),
+            ApplyTemplateError::UnknownOwner => CreateWorldError::UnknownOwner.into(),
+            ApplyTemplateError::QuotaExceeded(e) => e.into(),
+            ApplyTemplateError::InvalidTemplate => {
+                let mut problem = ValidationProblem::new();
+                problem.with_field_error("template", TemplateValidation::InvalidTemplate);
+                problem.build()
+            }
+            ApplyTemplateError::UnknownError => CreateWorldError::UnknownError.into(),
+        }
+    }
+}
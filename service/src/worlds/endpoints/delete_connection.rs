@@ -0,0 +1,42 @@
+use super::{errors::WorldProblemType, world_owner::authorize_world_owner};
+use crate::{
+    authorization::Authorizer,
+    http::problem::Problem,
+    worlds::{ConnectionID, WorldID, WorldsService},
+};
+use rocket::{delete, http::Status, Response, State};
+
+/// Handler to delete a connection between Worlds.
+///
+/// The owner of the world at either end of the connection may delete it
+///
+/// # Parameters
+/// - `worlds_service` - The worlds service to use
+/// - `world` - The ID of the world at one end of the connection
+/// - `id` - The ID of the connection
+/// - `authorizer` - The authorizer to prove we're allowed to delete the connection
+///
+/// # Returns
+/// An empty response if the connection was deleted, or a Problem if the deletion failed
+#[tracing::instrument(
+    name = "DELETE /worlds/{id}/connections/{connection}",
+    skip(worlds_service)
+)]
+#[delete("/worlds/<world>/connections/<id>")]
+pub fn delete_connection(
+    worlds_service: State<WorldsService>,
+    world: WorldID,
+    id: ConnectionID,
+    authorizer: Authorizer,
+) -> Result<Response<'static>, Problem> {
+    authorize_world_owner(&worlds_service, &authorizer, &world)?;
+
+    if worlds_service.delete_connection(&world, &id) {
+        Ok(Response::build().status(Status::NoContent).finalize())
+    } else {
+        Err(Problem::new(
+            WorldProblemType::UnknownConnection,
+            Status::NotFound,
+        ))
+    }
+}
@@ -0,0 +1,37 @@
+use super::{errors::WorldProblemType, world_owner::authorize_world_editor};
+use crate::{
+    authorization::Authorizer,
+    http::problem::Problem,
+    model::LanguageTag,
+    worlds::{WorldID, WorldsService},
+};
+use rocket::{delete, http::Status, Response, State};
+
+/// Handler to delete the translation of a World into another language
+///
+/// # Parameters
+/// - `worlds_service` - The worlds service to use
+/// - `world` - The ID of the world
+/// - `language` - The language of the translation
+/// - `authorizer` - The authorizer to prove we're allowed to change the world
+///
+/// # Returns
+/// An empty response if the translation was deleted, or a Problem if the deletion failed
+#[tracing::instrument(
+    name = "DELETE /worlds/{id}/translations/{language}",
+    skip(worlds_service)
+)]
+#[delete("/worlds/<world>/translations/<language>")]
+pub fn delete_world_translation(
+    worlds_service: State<WorldsService>,
+    world: WorldID,
+    language: LanguageTag,
+    authorizer: Authorizer,
+) -> Result<Response<'static>, Problem> {
+    authorize_world_editor(&worlds_service, &authorizer, &world)?;
+
+    worlds_service
+        .delete_translation(&world, &language)
+        .map(|_| Response::build().status(Status::NoContent).finalize())
+        .ok_or_else(|| Problem::new(WorldProblemType::UnknownTranslation, Status::NotFound))
+}
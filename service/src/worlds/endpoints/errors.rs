@@ -1,11 +1,31 @@
-use crate::http::problem::ProblemType;
+use crate::http::problem::{ProblemType, ValidationType};
 
 /// Problem Types that can happen when working with worlds
 #[derive(Debug, thiserror::Error)]
 pub enum WorldProblemType {
+    /// An unknown error occurred
+    #[error("An unknown error occurred")]
+    UnknownError,
+
     /// The world ID that was looked up was not found
     #[error("The requested world ID was unknown")]
     UnknownWorldID,
+
+    /// The connection that was looked up was not found
+    #[error("The requested connection was unknown")]
+    UnknownConnection,
+
+    /// The worlds were already connected
+    #[error("The worlds are already connected")]
+    DuplicateConnection,
+
+    /// The translation that was looked up was not found
+    #[error("The requested translation was unknown")]
+    UnknownTranslation,
+
+    /// A translation was provided into the default language of the world
+    #[error("The world can not be translated into its default language")]
+    DefaultLanguageTranslation,
 }
 
 impl ProblemType for WorldProblemType {
@@ -14,9 +34,62 @@ impl ProblemType for WorldProblemType {
     /// These are used in the `type` field in the RFC-7807 Problem Response
     fn error_code(&self) -> &'static str {
         match self {
+            WorldProblemType::UnknownError => "tag:multiverse,2020:worlds/problems/unknown_error",
             WorldProblemType::UnknownWorldID => {
                 "tag:multiverse,2020:worlds/problems/unknown_world_id"
             }
+            WorldProblemType::UnknownConnection => {
+                "tag:multiverse,2020:worlds/problems/unknown_connection"
+            }
+            WorldProblemType::DuplicateConnection => {
+                "tag:multiverse,2020:worlds/problems/duplicate_connection"
+            }
+            WorldProblemType::UnknownTranslation => {
+                "tag:multiverse,2020:worlds/problems/unknown_translation"
+            }
+            WorldProblemType::DefaultLanguageTranslation => {
+                "tag:multiverse,2020:worlds/problems/default_language_translation"
+            }
+        }
+    }
+}
+
+/// Validation errors that are specific to worlds
+#[derive(Debug, thiserror::Error)]
+pub enum WorldValidation {
+    /// The world that was referred to was not a known world
+    #[error("The world was unknown")]
+    UnknownWorld,
+
+    /// A world was connected to itself
+    #[error("A world can not be connected to itself")]
+    SameWorld,
+
+    /// The kind of connection was not one that is supported
+    #[error("The kind of connection was unknown")]
+    UnknownConnectionKind,
+
+    /// A translation was provided into the default language of the world
+    #[error("The world can not be translated into its default language")]
+    DefaultLanguageTranslation,
+}
+
+impl ValidationType for WorldValidation {
+    /// Generate a Type value for the `ValidationType` values.
+    fn error_code(&self) -> &'static str {
+        match self {
+            WorldValidation::UnknownWorld => {
+                "tag:multiverse,2020:worlds/problems/validation_error/unknown_world"
+            }
+            WorldValidation::SameWorld => {
+                "tag:multiverse,2020:worlds/problems/validation_error/same_world"
+            }
+            WorldValidation::UnknownConnectionKind => {
+                "tag:multiverse,2020:worlds/problems/validation_error/unknown_connection_kind"
+            }
+            WorldValidation::DefaultLanguageTranslation => {
+                "tag:multiverse,2020:worlds/problems/validation_error/default_language_translation"
+            }
         }
     }
 }
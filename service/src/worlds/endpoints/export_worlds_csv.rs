@@ -0,0 +1,60 @@
+use super::model::WorldCsvRecord;
+use crate::{
+    authorization::Authorizer,
+    http::{
+        csv::{write_csv, CsvResponse},
+        problem::Problem,
+        publication::parse_status_filter,
+        sorts::SortFieldsRequest,
+    },
+    model::fetch_all_pages,
+    users::endpoints::model::UserLink,
+    worlds::{WorldSortField, WorldsFilters, WorldsService},
+};
+use rocket::{get, State};
+
+/// Handler to download every world that matches a search as a CSV file.
+///
+/// This takes the same filters and sorts as `GET /worlds`, but returns every matching world instead of
+/// a single page of them
+///
+/// # Parameters
+/// - `worlds_service` - The worlds service to use
+/// - `owner` - The owner to filter against
+/// - `url_slug` - The URL Slug to filter against
+/// - `status` - The publication status to filter against
+/// - `sort` - The sort ordering to apply
+/// - `authorizer` - The authorizer to identify the current user
+///
+/// # Returns
+/// The CSV file of the worlds that matched the search
+#[tracing::instrument(name = "GET /worlds.csv", skip(worlds_service))]
+#[get("/worlds.csv?<owner>&<url_slug>&<status>&<sort>")]
+pub fn export_worlds_csv(
+    worlds_service: State<WorldsService>,
+    owner: Option<UserLink>,
+    url_slug: Option<String>,
+    status: Option<String>,
+    sort: SortFieldsRequest<WorldSortField>,
+    authorizer: Authorizer,
+) -> Result<CsvResponse, Problem> {
+    let filters = WorldsFilters {
+        owner: owner.map(|link| link.into()),
+        url_slug,
+        status: parse_status_filter(status)?,
+        viewer: authorizer.authorize().finish()?,
+        ..Default::default()
+    };
+
+    let records: Vec<WorldCsvRecord> =
+        fetch_all_pages(|pagination| worlds_service.search_worlds(&filters, &sort, pagination))
+            .iter()
+            .map(WorldCsvRecord::from)
+            .collect();
+    tracing::debug!(worlds = records.len(), "Exporting worlds");
+
+    Ok(CsvResponse {
+        filename: "worlds.csv".to_owned(),
+        body: write_csv(&records),
+    })
+}
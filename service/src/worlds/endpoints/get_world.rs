@@ -1,16 +1,18 @@
 use crate::{
+    authorization::Authorizer,
     http::problem::Problem,
     worlds::{
-        endpoints::{errors::WorldProblemType, model::WorldResponse},
+        endpoints::{model::WorldResponse, world_owner::authorize_world_viewer},
         WorldID, WorldsService,
     },
 };
-use rocket::{get, http::Status, State};
+use rocket::{get, State};
 
 /// Handler to get an existing World by ID
 ///
 /// # Parameters
 /// - `worlds_service` - The worlds service to use
+/// - `authorizer` - The authorizer to identify the current user. Only editors can see unpublished worlds
 ///
 /// # Returns
 /// The details of the world
@@ -19,10 +21,8 @@ use rocket::{get, http::Status, State};
 pub fn get_world(
     worlds_service: State<WorldsService>,
     id: WorldID,
+    authorizer: Authorizer,
 ) -> Result<WorldResponse, Problem> {
     tracing::debug!("Looking up world");
-    worlds_service
-        .find_world_by_id(id)
-        .ok_or_else(|| Problem::new(WorldProblemType::UnknownWorldID, Status::NotFound))
-        .map(|world| WorldResponse(world))
+    authorize_world_viewer(&worlds_service, &authorizer, &id).map(WorldResponse)
 }
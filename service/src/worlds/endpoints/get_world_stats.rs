@@ -0,0 +1,34 @@
+use super::{
+    errors::WorldProblemType, model::WorldStatsResponse, world_owner::authorize_world_owner,
+};
+use crate::{
+    authorization::Authorizer,
+    http::problem::Problem,
+    worlds::{WorldID, WorldsService},
+};
+use rocket::{get, http::Status, State};
+
+/// Handler to get the aggregate statistics of a World
+///
+/// # Parameters
+/// - `worlds_service` - The worlds service to use
+/// - `world` - The ID of the world
+/// - `authorizer` - The authorizer to prove we're allowed to see the statistics. Only the owner can
+///
+/// # Returns
+/// The statistics of the world
+#[tracing::instrument(name = "GET /worlds/{id}/stats", skip(worlds_service))]
+#[get("/worlds/<world>/stats")]
+pub fn get_world_stats(
+    worlds_service: State<WorldsService>,
+    world: WorldID,
+    authorizer: Authorizer,
+) -> Result<WorldStatsResponse, Problem> {
+    let world = authorize_world_owner(&worlds_service, &authorizer, &world)?;
+
+    let stats = worlds_service
+        .world_stats(&world)
+        .ok_or_else(|| Problem::new(WorldProblemType::UnknownWorldID, Status::NotFound))?;
+
+    Ok(WorldStatsResponse { world, stats })
+}
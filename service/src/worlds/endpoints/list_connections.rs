@@ -0,0 +1,34 @@
+use super::{model::ConnectionGraphResponse, world_owner::authorize_world_viewer};
+use crate::{
+    authorization::Authorizer,
+    http::problem::Problem,
+    worlds::{WorldID, WorldsService},
+};
+use rocket::{get, State};
+
+/// Handler to get the graph of Worlds connected to a World
+///
+/// # Parameters
+/// - `worlds_service` - The worlds service to use
+/// - `world` - The ID of the world to start from
+/// - `depth` - How many connections away from the world to go. Defaults to 1
+/// - `authorizer` - The authorizer to identify the current user. Only editors can see unpublished worlds
+///
+/// # Returns
+/// The graph of connected worlds
+#[tracing::instrument(name = "GET /worlds/{id}/connections", skip(worlds_service))]
+#[get("/worlds/<world>/connections?<depth>")]
+pub fn list_connections(
+    worlds_service: State<WorldsService>,
+    world: WorldID,
+    depth: Option<u32>,
+    authorizer: Authorizer,
+) -> Result<ConnectionGraphResponse, Problem> {
+    let world = authorize_world_viewer(&worlds_service, &authorizer, &world)?;
+    let viewer = authorizer.authorize().finish()?;
+
+    let graph = worlds_service.connection_graph(world, depth.unwrap_or(1), viewer.as_ref());
+    tracing::debug!(graph = ?graph, "Found connected worlds");
+
+    Ok(ConnectionGraphResponse(graph))
+}
@@ -0,0 +1,48 @@
+use super::model::WorldsResponse;
+use crate::{
+    authorization::Authorizer,
+    http::{pagination::PaginationRequest, problem::Problem, sorts::SortFieldsRequest},
+    users::{endpoints::errors::UserProblemType, UserID, UsersService},
+    worlds::{WorldSortField, WorldsFilters, WorldsService},
+};
+use rocket::{get, http::Status, State};
+
+/// Handler to list the worlds that a user has starred
+///
+/// # Parameters
+/// - `worlds_service` - The worlds service to use
+/// - `users_service` - The users service to use
+/// - `id` - The ID of the user
+/// - `sort` - The sort ordering to apply
+/// - `pagination` - The pagination controls to apply
+/// - `authorizer` - The authorizer to identify the current user
+///
+/// # Returns
+/// The details of the worlds that the user has starred
+#[tracing::instrument(name = "GET /users/{id}/starred", skip(worlds_service, users_service))]
+#[get("/users/<id>/starred?<sort>&<pagination..>")]
+pub fn list_starred_worlds(
+    worlds_service: State<WorldsService>,
+    users_service: State<UsersService>,
+    id: UserID,
+    sort: SortFieldsRequest<WorldSortField>,
+    pagination: PaginationRequest,
+    authorizer: Authorizer,
+) -> Result<WorldsResponse, Problem> {
+    users_service
+        .find_user_by_id(&id)
+        .ok_or_else(|| Problem::new(UserProblemType::UnknownUserID, Status::NotFound))?;
+
+    let worlds = worlds_service.search_worlds(
+        &WorldsFilters {
+            starred_by: Some(id),
+            viewer: authorizer.authorize().finish()?,
+            ..Default::default()
+        },
+        &sort,
+        &pagination,
+    );
+    tracing::debug!(worlds = ?worlds, "Found starred worlds");
+
+    Ok(WorldsResponse(worlds))
+}
@@ -1,7 +1,21 @@
+mod connection_graph_response;
+mod connection_link;
+mod connection_request;
+mod connection_response;
+mod world_csv;
 mod world_link;
 mod world_response;
+mod world_stats_response;
+mod world_translation_request;
 mod worlds_response;
 
+pub use connection_graph_response::*;
+pub use connection_link::*;
+pub use connection_request::*;
+pub use connection_response::*;
+pub use world_csv::*;
 pub use world_link::*;
 pub use world_response::*;
+pub use world_stats_response::*;
+pub use world_translation_request::*;
 pub use worlds_response::*;
@@ -0,0 +1,83 @@
+use super::{connection_to_json, select_language, to_json, WorldLink};
+use crate::{
+    http::link::{Link, LinkRel, Links},
+    worlds::{ConnectionGraph, WorldsService},
+};
+use rocket::{
+    http::{
+        hyper::header::{CacheControl, CacheDirective},
+        Header,
+    },
+    response, Request, State,
+};
+use rocket_contrib::json::Json;
+use serde_json::{json, Value};
+
+/// API Model representing the graph of Worlds connected to some World
+#[derive(Debug)]
+pub struct ConnectionGraphResponse(pub ConnectionGraph);
+
+impl<'r> response::Responder<'r> for ConnectionGraphResponse {
+    fn respond_to(self, req: &Request) -> response::Result<'r> {
+        let worlds_service = req.guard::<State<WorldsService>>().unwrap();
+        let world_ids: Vec<_> = self
+            .0
+            .worlds
+            .iter()
+            .map(|connected| &connected.world.identity.id)
+            .collect();
+        let stars = worlds_service.count_stars(&world_ids);
+
+        let worlds: Vec<Value> = self
+            .0
+            .worlds
+            .iter()
+            .map(|connected| {
+                let mut json = to_json(
+                    &connected.world,
+                    stars
+                        .get(&connected.world.identity.id)
+                        .cloned()
+                        .unwrap_or(0),
+                    select_language(&connected.world, req),
+                );
+                json["depth"] = json!(connected.depth);
+                json
+            })
+            .collect();
+
+        let connections: Vec<Value> = self.0.connections.iter().map(connection_to_json).collect();
+
+        let links: Vec<Link> = self
+            .0
+            .worlds
+            .iter()
+            .enumerate()
+            .map(|(index, connected)| {
+                Link::new(
+                    WorldLink::new(connected.world.identity.id.clone()),
+                    LinkRel::ITEM,
+                )
+                .anchor(format!("#/worlds/{}", index))
+                .title(connected.world.data.name.clone())
+            })
+            .collect();
+
+        response::Response::build()
+            .merge(
+                Json(json!({
+                  "worlds": worlds,
+                  "connections": connections
+                }))
+                .respond_to(req)
+                .unwrap(),
+            )
+            .header(CacheControl(vec![
+                CacheDirective::Private,
+                CacheDirective::MaxAge(3600),
+            ]))
+            .header(Header::new("Vary", "Accept-Language"))
+            .header(Links(links))
+            .ok()
+    }
+}
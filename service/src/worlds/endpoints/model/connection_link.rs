@@ -0,0 +1,19 @@
+use crate::worlds::{ConnectionID, WorldID};
+
+/// Representation of a link to a connection between worlds
+pub struct ConnectionLink(WorldID, ConnectionID);
+
+impl ConnectionLink {
+    pub fn new(world_id: WorldID, connection_id: ConnectionID) -> Self {
+        Self(world_id, connection_id)
+    }
+}
+
+impl From<ConnectionLink> for String {
+    fn from(connection_link: ConnectionLink) -> String {
+        format!(
+            "/worlds/{}/connections/{}",
+            connection_link.0, connection_link.1
+        )
+    }
+}
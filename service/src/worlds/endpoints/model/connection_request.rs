@@ -0,0 +1,91 @@
+use crate::{
+    http::problem::{GenericValidation, Problem, ValidationProblem},
+    worlds::{endpoints::errors::WorldValidation, ConnectionData, ConnectionKind, WorldID},
+};
+use serde::Deserialize;
+use uuid::Uuid;
+
+/// Incoming details representing a connection to make from one world to another
+#[derive(Debug, Deserialize)]
+pub struct ConnectionRequest {
+    /// The link to the world to connect to
+    world: Option<String>,
+    /// The kind of connection
+    kind: Option<String>,
+    /// A description of the connection. May be omitted
+    description: Option<String>,
+}
+
+impl ConnectionRequest {
+    /// Validate the request and build the details of the connection that it represents
+    ///
+    /// # Parameters
+    /// - `from` - The ID of the world that the connection is from
+    ///
+    /// # Returns
+    /// The details of the connection
+    ///
+    /// # Errors
+    /// A Validation Problem if the request was invalid in any way
+    pub fn build(&self, from: WorldID) -> Result<ConnectionData, Problem> {
+        let mut problem = ValidationProblem::new();
+        let mut valid = true;
+
+        let to = match self.world.as_ref().filter(|v| !v.trim().is_empty()) {
+            None => {
+                problem.with_field_error("world", GenericValidation::Missing);
+                valid = false;
+                None
+            }
+            Some(world) => {
+                let to = Uuid::parse_str(world.trim().trim_start_matches("/worlds/"))
+                    .ok()
+                    .map(WorldID::new);
+                match &to {
+                    None => {
+                        problem.with_field_error("world", WorldValidation::UnknownWorld);
+                        valid = false;
+                    }
+                    Some(to) if to == &from => {
+                        problem.with_field_error("world", WorldValidation::SameWorld);
+                        valid = false;
+                    }
+                    _ => {}
+                }
+                to
+            }
+        };
+
+        let kind = match self.kind.as_ref().filter(|v| !v.trim().is_empty()) {
+            None => {
+                problem.with_field_error("kind", GenericValidation::Missing);
+                valid = false;
+                None
+            }
+            Some(kind) => {
+                let kind: Option<ConnectionKind> = kind.trim().parse().ok();
+                if kind.is_none() {
+                    problem.with_field_error("kind", WorldValidation::UnknownConnectionKind);
+                    valid = false;
+                }
+                kind
+            }
+        };
+
+        if !valid {
+            tracing::warn!("Validation error building connection");
+            return Err(problem.build());
+        }
+
+        Ok(ConnectionData {
+            from,
+            to: to.unwrap(),
+            kind: kind.unwrap(),
+            description: self
+                .description
+                .clone()
+                .filter(|v| !v.trim().is_empty())
+                .unwrap_or_default(),
+        })
+    }
+}
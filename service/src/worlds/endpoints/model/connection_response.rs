@@ -0,0 +1,50 @@
+use super::{ConnectionLink, WorldLink};
+use crate::{
+    http::link::{Link, LinkRel, Links},
+    worlds::ConnectionModel,
+};
+use rocket::{
+    http::hyper::header::{CacheControl, CacheDirective, ETag, EntityTag},
+    response, Request,
+};
+use rocket_contrib::json::Json;
+use serde_json::{json, Value};
+
+/// API Model representing a Connection between two Worlds
+#[derive(Debug)]
+pub struct ConnectionResponse(pub ConnectionModel);
+
+pub(super) fn connection_to_json(connection: &ConnectionModel) -> Value {
+    let from: String = WorldLink::new(connection.data.from.clone()).into();
+    let to: String = WorldLink::new(connection.data.to.clone()).into();
+
+    json!({
+      "from": from,
+      "to": to,
+      "kind": connection.data.kind.to_string(),
+      "description": connection.data.description,
+      "created": connection.identity.created
+    })
+}
+
+impl<'r> response::Responder<'r> for ConnectionResponse {
+    fn respond_to(self, req: &Request) -> response::Result<'r> {
+        let etag = self.0.identity.version.to_string();
+
+        response::Response::build()
+            .merge(Json(connection_to_json(&self.0)).respond_to(req).unwrap())
+            .header(CacheControl(vec![
+                CacheDirective::Private,
+                CacheDirective::MaxAge(3600),
+            ]))
+            .header(ETag(EntityTag::new(false, etag)))
+            .header(Links(vec![
+                Link::new(
+                    ConnectionLink::new(self.0.data.from.clone(), self.0.identity.id),
+                    LinkRel::SELF,
+                ),
+                Link::new(WorldLink::new(self.0.data.to), LinkRel::RELATED),
+            ]))
+            .ok()
+    }
+}
@@ -0,0 +1,52 @@
+use crate::worlds::WorldModel;
+use chrono::SecondsFormat;
+use serde::Serialize;
+
+/// API Model representing a single World as a row of a CSV file
+#[derive(Debug, PartialEq, Serialize)]
+pub struct WorldCsvRecord {
+    /// The ID of the world
+    pub id: String,
+    /// The name of the world
+    pub name: String,
+    /// The URL Slug of the world
+    pub url_slug: String,
+    /// The description of the world
+    pub description: String,
+    /// The ID of the user that owns the world
+    pub owner: String,
+    /// The publication status of the world
+    pub status: String,
+    /// When the world is to be published, if it is scheduled
+    pub publish_at: Option<String>,
+    /// When the world was created
+    pub created: String,
+    /// When the world was last updated
+    pub updated: String,
+}
+
+impl From<&WorldModel> for WorldCsvRecord {
+    fn from(world: &WorldModel) -> Self {
+        Self {
+            id: world.identity.id.to_string(),
+            name: world.data.name.clone(),
+            url_slug: world.data.url_slug.to_string(),
+            description: world.data.description.clone(),
+            owner: world.data.owner.to_string(),
+            status: world.data.publication.status().to_string(),
+            publish_at: world
+                .data
+                .publication
+                .publish_at()
+                .map(|publish_at| publish_at.to_rfc3339_opts(SecondsFormat::Secs, true)),
+            created: world
+                .identity
+                .created
+                .to_rfc3339_opts(SecondsFormat::Secs, true),
+            updated: world
+                .identity
+                .updated
+                .to_rfc3339_opts(SecondsFormat::Secs, true),
+        }
+    }
+}
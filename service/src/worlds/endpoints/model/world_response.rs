@@ -1,11 +1,20 @@
 use super::WorldLink;
 use crate::{
-    http::link::{Link, LinkRel, Links},
+    authorization::Authorizer,
+    http::{
+        language::AcceptLanguage,
+        link::{Link, LinkRel, Links},
+        publication::add_publication_json,
+    },
+    model::LanguageTag,
     users::{endpoints::model::UserLink, UsersService},
-    worlds::WorldModel,
+    worlds::{WorldModel, WorldsService},
 };
 use rocket::{
-    http::hyper::header::{CacheControl, CacheDirective, ETag, EntityTag},
+    http::{
+        hyper::header::{CacheControl, CacheDirective, ETag, EntityTag},
+        Header,
+    },
     response, Request, State,
 };
 use rocket_contrib::json::Json;
@@ -15,32 +24,99 @@ use serde_json::{json, Value};
 #[derive(Debug)]
 pub struct WorldResponse(pub WorldModel);
 
-pub(super) fn to_json(world: &WorldModel) -> Value {
-    json!({
-      "name": world.data.name,
-      "description": world.data.description,
-      "url_slug": world.data.url_slug
-    })
+pub(super) fn to_json(world: &WorldModel, stars: u64, language: &LanguageTag) -> Value {
+    let (name, description) = world.data.translated(language);
+    let mut value = json!({
+      "name": name,
+      "description": description,
+      "url_slug": world.data.url_slug,
+      "stars": stars
+    });
+    add_publication_json(&mut value, &world.data.publication);
+    value
 }
+
+/// Select the language to represent a world in, based on what the client asked for
+///
+/// # Parameters
+/// - `world` - The world to represent
+/// - `req` - The request to get the desired languages from
+///
+/// # Returns
+/// The language to use, falling back to the default language of the world
+pub(super) fn select_language<'a>(world: &'a WorldModel, req: &Request) -> &'a LanguageTag {
+    let accept_language = req.guard::<AcceptLanguage>().unwrap();
+    accept_language
+        .select(&world.data.languages())
+        .unwrap_or(&world.data.language)
+}
+
 impl<'r> response::Responder<'r> for WorldResponse {
     fn respond_to(self, req: &Request) -> response::Result<'r> {
         let users_service = req.guard::<State<UsersService>>().unwrap();
         let user = users_service.find_user_by_id(&self.0.data.owner);
         let user_name = user.map(|u| u.data.display_name).unwrap_or_default();
 
-        let etag = self.0.identity.version.to_string();
+        let worlds_service = req.guard::<State<WorldsService>>().unwrap();
+        let stars = worlds_service
+            .count_stars(&[&self.0.identity.id])
+            .remove(&self.0.identity.id)
+            .unwrap_or(0);
+
+        let viewer = req
+            .guard::<Authorizer>()
+            .unwrap()
+            .authorize()
+            .finish()
+            .ok()
+            .flatten();
+        let connections = worlds_service.find_connections(&self.0.identity.id, viewer.as_ref());
+
+        let language = select_language(&self.0, req);
+        let etag = if language == &self.0.data.language {
+            self.0.identity.version.to_string()
+        } else {
+            format!("{}-{}", self.0.identity.version, language)
+        };
+
+        let mut links = vec![
+            Link::new(WorldLink::new(self.0.identity.id.clone()), LinkRel::SELF),
+            Link::new(UserLink::new(self.0.data.owner.clone()), LinkRel::AUTHOR).title(user_name),
+        ];
+        links.extend(connections.into_iter().map(|connection| {
+            Link::new(
+                WorldLink::new(connection.data.other_end(&self.0.identity.id).clone()),
+                LinkRel::RELATED,
+            )
+            .title(connection.data.kind.to_string())
+        }));
+        links.extend(
+            self.0
+                .data
+                .languages()
+                .into_iter()
+                .filter(|other| other != &language)
+                .map(|other| {
+                    let target: String = WorldLink::new(self.0.identity.id.clone()).into();
+                    Link::new(format!("{}?lang={}", target, other), LinkRel::ALTERNATE)
+                        .hreflang(other.to_string())
+                }),
+        );
 
         response::Response::build()
-            .merge(Json(to_json(&self.0)).respond_to(req).unwrap())
+            .merge(
+                Json(to_json(&self.0, stars, language))
+                    .respond_to(req)
+                    .unwrap(),
+            )
             .header(CacheControl(vec![
                 CacheDirective::Private,
                 CacheDirective::MaxAge(3600),
             ]))
             .header(ETag(EntityTag::new(false, etag)))
-            .header(Links(vec![
-                Link::new(WorldLink::new(self.0.identity.id), LinkRel::SELF),
-                Link::new(UserLink::new(self.0.data.owner), LinkRel::AUTHOR).title(user_name),
-            ]))
+            .header(Header::new("Content-Language", language.to_string()))
+            .header(Header::new("Vary", "Accept-Language"))
+            .header(Links(links))
             .ok()
     }
 }
@@ -0,0 +1,71 @@
+use super::WorldLink;
+use crate::{
+    http::link::{Link, LinkRel, Links},
+    worlds::{WorldModel, WorldStats},
+};
+use rocket::{
+    http::hyper::header::{CacheControl, CacheDirective, ETag, EntityTag},
+    response, Request,
+};
+use rocket_contrib::json::Json;
+use serde_json::json;
+
+/// API Model representing the statistics of a World
+#[derive(Debug)]
+pub struct WorldStatsResponse {
+    /// The world that the statistics are for
+    pub world: WorldModel,
+    /// The statistics
+    pub stats: WorldStats,
+}
+
+impl<'r> response::Responder<'r> for WorldStatsResponse {
+    fn respond_to(self, req: &Request) -> response::Result<'r> {
+        let counts = &self.stats.counts;
+        let most_linked: Vec<_> = self
+            .stats
+            .most_linked
+            .iter()
+            .map(|linked| {
+                json!({
+                    "entity": linked.entity,
+                    "links": linked.links
+                })
+            })
+            .collect();
+
+        let body = json!({
+            "counts": {
+                "tables": counts.tables,
+                "characters": counts.characters,
+                "campaigns": counts.campaigns,
+                "sessions": counts.sessions,
+                "comments": counts.comments,
+                "attachments": counts.attachments,
+                "connections": counts.connections
+            },
+            "words": self.stats.words,
+            "broken_links": self.stats.broken_links,
+            "most_linked": most_linked,
+            "last_activity": self.stats.last_activity
+        });
+
+        let world: String = WorldLink::new(self.world.identity.id.clone()).into();
+
+        response::Response::build()
+            .merge(Json(body).respond_to(req).unwrap())
+            .header(CacheControl(vec![
+                CacheDirective::Private,
+                CacheDirective::MaxAge(3600),
+            ]))
+            .header(ETag(EntityTag::new(
+                false,
+                self.world.identity.version.to_string(),
+            )))
+            .header(Links(vec![
+                Link::new(format!("{}/stats", world), LinkRel::SELF),
+                Link::new(world, LinkRel::RELATED),
+            ]))
+            .ok()
+    }
+}
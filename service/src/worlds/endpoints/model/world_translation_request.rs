@@ -0,0 +1,43 @@
+use crate::{
+    http::problem::{GenericValidation, ValidationProblem},
+    worlds::WorldTranslation,
+};
+use serde::Deserialize;
+
+/// Incoming details representing the translation of a world into another language
+#[derive(Debug, Deserialize)]
+pub struct WorldTranslationRequest {
+    /// The name of the world in this language
+    name: Option<String>,
+    /// The description of the world in this language. May be omitted
+    description: Option<String>,
+}
+
+impl WorldTranslationRequest {
+    /// Validate the request and build the translation that it represents
+    ///
+    /// # Parameters
+    /// - `field_prefix` - The prefix to give the names of any fields that are in error
+    /// - `problem` - The validation problem to record any errors into
+    ///
+    /// # Returns
+    /// The translation, or `None` if the request was invalid. In this case the errors will have been
+    /// recorded into `problem`
+    pub fn build(
+        &self,
+        field_prefix: &str,
+        problem: &mut ValidationProblem,
+    ) -> Option<WorldTranslation> {
+        match self.name.as_ref().filter(|v| !v.trim().is_empty()) {
+            None => {
+                problem
+                    .with_field_error(format!("{}name", field_prefix), GenericValidation::Missing);
+                None
+            }
+            Some(name) => Some(WorldTranslation {
+                name: name.clone(),
+                description: self.description.clone().unwrap_or_default(),
+            }),
+        }
+    }
+}
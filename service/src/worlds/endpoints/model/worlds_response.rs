@@ -1,13 +1,16 @@
-use super::{to_json, WorldLink};
+use super::{select_language, to_json, WorldLink};
 use crate::{
     http::link::{Link, LinkRel, Links},
     model::Page,
     users::{endpoints::model::UserLink, UserID, UserModel, UsersService},
-    worlds::WorldModel,
+    worlds::{WorldID, WorldModel, WorldsService},
 };
 use itertools::*;
 use rocket::{
-    http::hyper::header::{CacheControl, CacheDirective},
+    http::{
+        hyper::header::{CacheControl, CacheDirective},
+        Header,
+    },
     response, Request, State,
 };
 use rocket_contrib::json::Json;
@@ -35,7 +38,27 @@ impl<'r> response::Responder<'r> for WorldsResponse {
             .map(|user| (user.identity.id.clone(), user))
             .collect();
 
-        let worlds: Vec<Value> = self.0.entries.iter().map(|world| to_json(&world)).collect();
+        let worlds_service = req.guard::<State<WorldsService>>().unwrap();
+        let world_ids: Vec<&WorldID> = self
+            .0
+            .entries
+            .iter()
+            .map(|world| &world.identity.id)
+            .collect();
+        let stars = worlds_service.count_stars(&world_ids[..]);
+
+        let worlds: Vec<Value> = self
+            .0
+            .entries
+            .iter()
+            .map(|world| {
+                to_json(
+                    &world,
+                    *stars.get(&world.identity.id).unwrap_or(&0),
+                    select_language(&world, req),
+                )
+            })
+            .collect();
 
         let response_body = json!({
           "entries": worlds,
@@ -86,6 +109,7 @@ impl<'r> response::Responder<'r> for WorldsResponse {
                 CacheDirective::Private,
                 CacheDirective::MaxAge(3600),
             ]))
+            .header(Header::new("Vary", "Accept-Language"))
             .header(Links(world_links))
             .ok()
     }
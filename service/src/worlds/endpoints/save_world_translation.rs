@@ -0,0 +1,57 @@
+use super::{
+    errors::WorldProblemType,
+    model::{WorldResponse, WorldTranslationRequest},
+    world_owner::authorize_world_editor,
+};
+use crate::{
+    authorization::Authorizer,
+    http::problem::{Problem, ValidationProblem},
+    model::LanguageTag,
+    worlds::{WorldID, WorldsService},
+};
+use rocket::{http::Status, put, State};
+use rocket_contrib::json::Json;
+
+/// Handler to save the translation of a World into another language, replacing any existing
+/// translation into that language
+///
+/// # Parameters
+/// - `worlds_service` - The worlds service to use
+/// - `world` - The ID of the world
+/// - `language` - The language of the translation
+/// - `body` - The translated details of the world
+/// - `authorizer` - The authorizer to prove we're allowed to change the world
+///
+/// # Returns
+/// The updated world details, or a Problem if the update failed
+#[tracing::instrument(
+    name = "PUT /worlds/{id}/translations/{language}",
+    skip(worlds_service)
+)]
+#[put("/worlds/<world>/translations/<language>", data = "<body>")]
+pub fn save_world_translation(
+    worlds_service: State<WorldsService>,
+    world: WorldID,
+    language: LanguageTag,
+    body: Json<WorldTranslationRequest>,
+    authorizer: Authorizer,
+) -> Result<WorldResponse, Problem> {
+    let existing = authorize_world_editor(&worlds_service, &authorizer, &world)?;
+    if existing.data.language == language {
+        return Err(Problem::new(
+            WorldProblemType::DefaultLanguageTranslation,
+            Status::UnprocessableEntity,
+        ));
+    }
+
+    let mut problem = ValidationProblem::new();
+    let translation = body.build("", &mut problem).ok_or_else(|| {
+        tracing::warn!("Validation error saving world translation");
+        problem.build()
+    })?;
+
+    worlds_service
+        .save_translation(&world, &language, translation)
+        .map(WorldResponse)
+        .ok_or_else(|| Problem::new(WorldProblemType::UnknownWorldID, Status::NotFound))
+}
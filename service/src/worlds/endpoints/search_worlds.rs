@@ -1,6 +1,10 @@
 use super::model::WorldsResponse;
 use crate::{
-    http::{pagination::PaginationRequest, sorts::SortFieldsRequest},
+    authorization::Authorizer,
+    http::{
+        pagination::PaginationRequest, problem::Problem, publication::parse_status_filter,
+        sorts::SortFieldsRequest,
+    },
     users::endpoints::model::UserLink,
     worlds::{WorldSortField, WorldsFilters, WorldsService},
 };
@@ -12,26 +16,35 @@ use rocket::{get, State};
 /// - `worlds_service` - The worlds service to use
 /// - `owner` - The owner to filter against
 /// - `url_slug` - The URL Slug to filter against
+/// - `status` - The publication status to filter against. Only editors of a world can see it if it isn't
+///   published, so this is mostly of use to owners looking for their own drafts
 /// - `sort` - The sort ordering to apply
 /// - `pagination` - The pagination controls to apply
+/// - `authorizer` - The authorizer to identify the current user
 ///
 /// # Returns
 /// The details of the worlds that matched the search
 #[tracing::instrument(name = "GET /worlds", skip(worlds_service))]
-#[get("/worlds?<owner>&<url_slug>&<sort>&<pagination..>")]
+#[get("/worlds?<owner>&<url_slug>&<status>&<sort>&<pagination..>")]
 pub fn search_worlds(
     worlds_service: State<WorldsService>,
     owner: Option<UserLink>,
     url_slug: Option<String>,
+    status: Option<String>,
     sort: SortFieldsRequest<WorldSortField>,
     pagination: PaginationRequest,
-) -> WorldsResponse {
+    authorizer: Authorizer,
+) -> Result<WorldsResponse, Problem> {
     tracing::debug!("Searching worlds");
 
+    let status = parse_status_filter(status)?;
+
     let worlds = worlds_service.search_worlds(
         &WorldsFilters {
             owner: owner.map(|link| link.into()),
             url_slug,
+            status,
+            viewer: authorizer.authorize().finish()?,
             ..Default::default()
         },
         &sort,
@@ -40,5 +53,5 @@ pub fn search_worlds(
 
     tracing::debug!(worlds = ?worlds, "Found worlds");
 
-    WorldsResponse(worlds)
+    Ok(WorldsResponse(worlds))
 }
@@ -0,0 +1,35 @@
+use crate::{
+    authorization::Authorizer,
+    http::problem::Problem,
+    worlds::{endpoints::world_owner::authorize_world_viewer, WorldID, WorldsService},
+};
+use rocket::{http::Status, put, Response, State};
+
+/// Handler to star a World on behalf of the current user
+///
+/// # Parameters
+/// - `worlds_service` - The worlds service to use
+/// - `id` - The ID of the world to star
+/// - `authorizer` - The authorizer to prove who is starring the world
+///
+/// # Returns
+/// An empty response if the world was starred, or a Problem if it wasn't
+#[tracing::instrument(name = "PUT /worlds/{id}/star", skip(worlds_service))]
+#[put("/worlds/<id>/star")]
+pub fn star_world(
+    worlds_service: State<WorldsService>,
+    id: WorldID,
+    authorizer: Authorizer,
+) -> Result<Response<'static>, Problem> {
+    let user = authorizer
+        .authorize()
+        .authorized()
+        .finish()?
+        .expect("No authenticated user");
+
+    let world = authorize_world_viewer(&worlds_service, &authorizer, &id)?;
+
+    worlds_service.star_world(&world.identity.id, &user);
+
+    Ok(Response::build().status(Status::NoContent).finalize())
+}
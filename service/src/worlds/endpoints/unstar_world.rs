@@ -0,0 +1,37 @@
+use crate::{
+    authorization::Authorizer,
+    http::problem::Problem,
+    worlds::{endpoints::errors::WorldProblemType, WorldID, WorldsService},
+};
+use rocket::{delete, http::Status, Response, State};
+
+/// Handler to remove the star that the current user has given a World
+///
+/// # Parameters
+/// - `worlds_service` - The worlds service to use
+/// - `id` - The ID of the world to unstar
+/// - `authorizer` - The authorizer to prove who is unstarring the world
+///
+/// # Returns
+/// An empty response if the world is no longer starred, or a Problem if it wasn't
+#[tracing::instrument(name = "DELETE /worlds/{id}/star", skip(worlds_service))]
+#[delete("/worlds/<id>/star")]
+pub fn unstar_world(
+    worlds_service: State<WorldsService>,
+    id: WorldID,
+    authorizer: Authorizer,
+) -> Result<Response<'static>, Problem> {
+    let user = authorizer
+        .authorize()
+        .authorized()
+        .finish()?
+        .expect("No authenticated user");
+
+    let world = worlds_service
+        .find_world_by_id(id)
+        .ok_or_else(|| Problem::new(WorldProblemType::UnknownWorldID, Status::NotFound))?;
+
+    worlds_service.unstar_world(&world.identity.id, &user);
+
+    Ok(Response::build().status(Status::NoContent).finalize())
+}
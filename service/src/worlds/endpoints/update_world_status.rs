@@ -0,0 +1,43 @@
+use super::{errors::WorldProblemType, model::WorldResponse, world_owner::authorize_world_editor};
+use crate::{
+    authorization::Authorizer,
+    http::{
+        problem::{Problem, ValidationProblem},
+        publication::PublicationRequest,
+    },
+    worlds::{WorldID, WorldsService},
+};
+use rocket::{http::Status, put, State};
+use rocket_contrib::json::Json;
+
+/// Handler to change the publication status of a World
+///
+/// # Parameters
+/// - `worlds_service` - The worlds service to use
+/// - `world` - The ID of the world
+/// - `body` - The new publication status of the world
+/// - `authorizer` - The authorizer to prove we're allowed to change the world
+///
+/// # Returns
+/// The updated world details, or a Problem if the update failed
+#[tracing::instrument(name = "PUT /worlds/{id}/status", skip(worlds_service))]
+#[put("/worlds/<world>/status", data = "<body>")]
+pub fn update_world_status(
+    worlds_service: State<WorldsService>,
+    world: WorldID,
+    body: Json<PublicationRequest>,
+    authorizer: Authorizer,
+) -> Result<WorldResponse, Problem> {
+    authorize_world_editor(&worlds_service, &authorizer, &world)?;
+
+    let mut problem = ValidationProblem::new();
+    let publication = body.build(&mut problem).ok_or_else(|| {
+        tracing::warn!("Validation error updating world status");
+        problem.build()
+    })?;
+
+    worlds_service
+        .update_publication(&world, publication)
+        .map(WorldResponse)
+        .ok_or_else(|| Problem::new(WorldProblemType::UnknownWorldID, Status::NotFound))
+}
@@ -0,0 +1,115 @@
+use super::errors::WorldProblemType;
+use crate::{
+    authorization::Authorizer,
+    http::problem::Problem,
+    worlds::{WorldID, WorldModel, WorldsService},
+};
+use rocket::http::Status;
+
+/// Look up a world and ensure that the current user is allowed to change it
+///
+/// # Parameters
+/// - `worlds_service` - The worlds service to use
+/// - `authorizer` - The authorizer to prove we're allowed to change the world
+/// - `world` - The ID of the world
+///
+/// # Returns
+/// The world, if it exists and the current user owns it
+///
+/// # Errors
+/// A Problem if the world doesn't exist or the current user doesn't own it
+pub(crate) fn authorize_world_owner(
+    worlds_service: &WorldsService,
+    authorizer: &Authorizer,
+    world: &WorldID,
+) -> Result<WorldModel, Problem> {
+    let world = worlds_service
+        .find_world_by_id(world.clone())
+        .ok_or_else(|| Problem::new(WorldProblemType::UnknownWorldID, Status::NotFound))?;
+
+    authorizer
+        .authorize()
+        .same_user(&world.data.owner)
+        .finish()?;
+
+    Ok(world)
+}
+
+/// Look up a world and ensure that the current user is allowed to edit its contents.
+///
+/// This is allowed for the owner of the world and for members of the world with the Editor role
+///
+/// # Parameters
+/// - `worlds_service` - The worlds service to use
+/// - `authorizer` - The authorizer to prove we're allowed to edit the world
+/// - `world` - The ID of the world
+///
+/// # Returns
+/// The world, if it exists and the current user is an editor of it
+///
+/// # Errors
+/// A Problem if the world doesn't exist or the current user isn't an editor of it
+pub(crate) fn authorize_world_editor(
+    worlds_service: &WorldsService,
+    authorizer: &Authorizer,
+    world: &WorldID,
+) -> Result<WorldModel, Problem> {
+    let world = worlds_service
+        .find_world_by_id(world.clone())
+        .ok_or_else(|| Problem::new(WorldProblemType::UnknownWorldID, Status::NotFound))?;
+
+    authorizer
+        .authorize()
+        .user_matching(|user| worlds_service.is_editor(&world, Some(user)))
+        .finish()?;
+
+    Ok(world)
+}
+
+/// Look up a world and ensure that the current user is allowed to see it.
+///
+/// Worlds that aren't yet published are only visible to their owner and members. To everyone else they
+/// appear not to exist at all.
+///
+/// # Parameters
+/// - `worlds_service` - The worlds service to use
+/// - `authorizer` - The authorizer to identify the current user
+/// - `world` - The ID of the world
+///
+/// # Returns
+/// The world, if it exists and the current user can see it
+///
+/// # Errors
+/// A Problem if the world doesn't exist or the current user can't see it
+pub(crate) fn authorize_world_viewer(
+    worlds_service: &WorldsService,
+    authorizer: &Authorizer,
+    world: &WorldID,
+) -> Result<WorldModel, Problem> {
+    let viewer = authorizer.authorize().finish()?;
+
+    worlds_service
+        .find_world_by_id(world.clone())
+        .filter(|world| worlds_service.can_view(world, viewer.as_ref()))
+        .ok_or_else(|| Problem::new(WorldProblemType::UnknownWorldID, Status::NotFound))
+}
+
+/// Check if the current user is an editor of a world, and so is able to see content in it that isn't
+/// yet published
+///
+/// # Parameters
+/// - `worlds_service` - The worlds service to use
+/// - `authorizer` - The authorizer to identify the current user
+/// - `world` - The world
+///
+/// # Returns
+/// `true` if the current user is an editor of the world
+pub(crate) fn is_world_editor(
+    worlds_service: &WorldsService,
+    authorizer: &Authorizer,
+    world: &WorldModel,
+) -> bool {
+    let viewer = authorizer.authorize().finish().ok().flatten();
+
+    worlds_service.is_editor(world, viewer.as_ref())
+}
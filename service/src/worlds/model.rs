@@ -1,9 +1,21 @@
+mod connection;
+mod connection_id;
+mod connection_kind;
 mod sorts;
 mod url_slug;
 mod world;
 mod world_id;
+mod world_role;
+mod world_stats;
+mod world_translation;
 
+pub use connection::*;
+pub use connection_id::*;
+pub use connection_kind::*;
 pub use sorts::*;
 pub use url_slug::*;
 pub use world::*;
 pub use world_id::*;
+pub use world_role::*;
+pub use world_stats::*;
+pub use world_translation::*;
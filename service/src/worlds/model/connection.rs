@@ -0,0 +1,78 @@
+use super::{ConnectionID, ConnectionKind, WorldID, WorldModel};
+use crate::model::Model;
+
+/// Data to represent a connection between two worlds
+#[derive(Debug, Clone)]
+pub struct ConnectionData {
+    /// The ID of the world that declared the connection
+    pub from: WorldID,
+
+    /// The ID of the world that the connection leads to
+    pub to: WorldID,
+
+    /// The kind of connection
+    pub kind: ConnectionKind,
+
+    /// A description of the connection
+    pub description: String,
+}
+
+impl ConnectionData {
+    /// Get the world at the other end of the connection from the given one
+    ///
+    /// # Parameters
+    /// - `world` - The ID of the world at one end of the connection
+    ///
+    /// # Returns
+    /// The ID of the world at the other end
+    pub fn other_end(&self, world: &WorldID) -> &WorldID {
+        if &self.from == world {
+            &self.to
+        } else {
+            &self.from
+        }
+    }
+}
+
+/// Model representation of a connection between two worlds
+pub type ConnectionModel = Model<ConnectionID, ConnectionData>;
+
+/// A world that is part of a connection graph
+#[derive(Debug)]
+pub struct ConnectedWorld {
+    /// The world
+    pub world: WorldModel,
+
+    /// How many connections away from the starting world this world is
+    pub depth: u32,
+}
+
+/// The graph of worlds connected to some starting world
+#[derive(Debug)]
+pub struct ConnectionGraph {
+    /// The worlds in the graph, including the starting world, nearest first
+    pub worlds: Vec<ConnectedWorld>,
+
+    /// The connections between the worlds in the graph
+    pub connections: Vec<ConnectionModel>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_other_end() {
+        let from = WorldID::default();
+        let to = WorldID::default();
+        let connection = ConnectionData {
+            from: from.clone(),
+            to: to.clone(),
+            kind: ConnectionKind::Portal,
+            description: "".to_owned(),
+        };
+
+        assert_eq!(connection.other_end(&from), &to);
+        assert_eq!(connection.other_end(&to), &from);
+    }
+}
@@ -0,0 +1,61 @@
+use bytes::BytesMut;
+use postgres_types::{accepts, to_sql_checked, FromSql, IsNull, ToSql, Type};
+use rocket::{http::RawStr, request};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use uuid::Uuid;
+
+/// The ID of a Connection between Worlds
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, FromSql, Eq, Hash)]
+pub struct ConnectionID(Uuid);
+
+impl ConnectionID {
+    /// Wrap a UUID as a Connection ID object
+    ///
+    /// # Parameters
+    /// - `uuid` - The UUID to wrap
+    ///
+    /// # The Connection ID
+    pub fn new(uuid: Uuid) -> Self {
+        ConnectionID(uuid)
+    }
+}
+
+impl Default for ConnectionID {
+    fn default() -> Self {
+        Self(Uuid::new_v4())
+    }
+}
+
+impl ToSql for ConnectionID {
+    accepts!(UUID);
+
+    to_sql_checked!();
+
+    fn to_sql(
+        &self,
+        t: &Type,
+        w: &mut BytesMut,
+    ) -> Result<IsNull, Box<dyn std::error::Error + Sync + Send>> {
+        self.0.to_sql(t, w)
+    }
+}
+
+impl<'r> request::FromParam<'r> for ConnectionID {
+    type Error = &'r RawStr;
+
+    fn from_param(param: &'r RawStr) -> Result<Self, Self::Error> {
+        param
+            .percent_decode()
+            .map(|cow| cow.into_owned())
+            .map_err(|_| param)
+            .and_then(|connection_id| Uuid::parse_str(&connection_id).map_err(|_| param))
+            .map(|connection_id| ConnectionID::new(connection_id))
+    }
+}
+
+impl Display for ConnectionID {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
@@ -0,0 +1,37 @@
+use strum_macros::{Display, EnumString};
+
+/// The ways in which two worlds can be connected
+#[derive(Debug, PartialEq, Clone, Display, EnumString)]
+#[strum(serialize_all = "snake_case")]
+pub enum ConnectionKind {
+    /// A portal leads from one world to the other
+    Portal,
+    /// The worlds are parallel universes of each other
+    ParallelUniverse,
+    /// One world is a sequel to the other
+    Sequel,
+    /// The worlds share a setting
+    SharedSetting,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serialize() {
+        assert_eq!(ConnectionKind::Portal.to_string(), "portal");
+        assert_eq!(
+            ConnectionKind::ParallelUniverse.to_string(),
+            "parallel_universe"
+        );
+        assert_eq!(ConnectionKind::Sequel.to_string(), "sequel");
+        assert_eq!(ConnectionKind::SharedSetting.to_string(), "shared_setting");
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!("shared_setting".parse(), Ok(ConnectionKind::SharedSetting));
+        assert!("wormhole".parse::<ConnectionKind>().is_err());
+    }
+}
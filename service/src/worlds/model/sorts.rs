@@ -9,4 +9,6 @@ pub enum WorldSortField {
     Created,
     /// Sort by when the world was last updated
     Updated,
+    /// Sort by the number of users that have starred the world
+    Stars,
 }
@@ -2,7 +2,10 @@ use bytes::BytesMut;
 use postgres_types::{accepts, to_sql_checked, FromSql, IsNull, ToSql, Type};
 use rocket::{http::RawStr, request};
 use serde::{Deserialize, Serialize};
-use std::str::FromStr;
+use std::{
+    fmt::{Display, Formatter},
+    str::FromStr,
+};
 
 /// Typesafe representation of the URL Slug of some world
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone, FromSql)]
@@ -63,3 +66,9 @@ impl<'r> request::FromParam<'r> for UrlSlug {
             .and_then(|url_slug| UrlSlug::from_str(&url_slug).map_err(|_| param))
     }
 }
+
+impl Display for UrlSlug {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
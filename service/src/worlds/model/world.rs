@@ -1,5 +1,9 @@
-use super::{UrlSlug, WorldID};
-use crate::{model::Model, users::UserID};
+use super::{UrlSlug, WorldID, WorldTranslation};
+use crate::{
+    model::{LanguageTag, Model, Publication},
+    users::UserID,
+};
+use std::collections::BTreeMap;
 
 /// Data to represent a world
 #[derive(Debug, Clone)]
@@ -15,6 +19,42 @@ pub struct WorldData {
 
     /// The URL Slug of the world
     pub url_slug: UrlSlug,
+
+    /// The publication state of the world
+    pub publication: Publication,
+
+    /// The language that the name and description of the world are written in
+    pub language: LanguageTag,
+
+    /// Translations of the name and description of the world into other languages
+    pub translations: BTreeMap<LanguageTag, WorldTranslation>,
+
+    /// Whether the world has been hidden by a moderator. Hidden worlds are only visible to their editors
+    pub hidden: bool,
+}
+
+impl WorldData {
+    /// Get every language that the world is available in, starting with its default language
+    pub fn languages(&self) -> Vec<&LanguageTag> {
+        std::iter::once(&self.language)
+            .chain(self.translations.keys())
+            .collect()
+    }
+
+    /// Get the name and description of the world in the given language
+    ///
+    /// # Parameters
+    /// - `language` - The language to get the details in
+    ///
+    /// # Returns
+    /// The name and description, falling back to the default language if there is no translation
+    /// into the requested one
+    pub fn translated(&self, language: &LanguageTag) -> (&str, &str) {
+        match self.translations.get(language) {
+            Some(translation) => (&translation.name, &translation.description),
+            None => (&self.name, &self.description),
+        }
+    }
 }
 
 /// Model representation of a World
@@ -0,0 +1,9 @@
+/// The roles that a member of a world other than the owner can have
+#[derive(Debug, PartialEq, Clone, strum_macros::EnumString, strum_macros::Display)]
+#[strum(serialize_all = "snake_case")]
+pub enum WorldRole {
+    /// The member can edit the contents of the world
+    Editor,
+    /// The member can view the contents of the world
+    Viewer,
+}
@@ -0,0 +1,44 @@
+use chrono::{DateTime, Utc};
+
+/// The number of each kind of entity that exists within a world
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct EntityCounts {
+    /// The number of random tables in the world
+    pub tables: u64,
+    /// The number of characters in the world
+    pub characters: u64,
+    /// The number of campaigns in the world
+    pub campaigns: u64,
+    /// The number of sessions across all of the campaigns in the world
+    pub sessions: u64,
+    /// The number of comments on the world and its content
+    pub comments: u64,
+    /// The number of files attached to the world
+    pub attachments: u64,
+    /// The number of connections from the world to other worlds
+    pub connections: u64,
+}
+
+/// An entity that is referenced from the sessions of a world, and how often
+#[derive(Debug, Clone, PartialEq)]
+pub struct LinkedEntity {
+    /// The reference to the entity
+    pub entity: String,
+    /// The number of sessions that reference the entity
+    pub links: u64,
+}
+
+/// Aggregate statistics about the size of a world
+#[derive(Debug, Clone, PartialEq)]
+pub struct WorldStats {
+    /// The number of each kind of entity in the world
+    pub counts: EntityCounts,
+    /// The total number of words written in session summaries and session notes
+    pub words: u64,
+    /// The number of references from sessions to tables, characters or campaigns of the world that no longer exist
+    pub broken_links: u64,
+    /// The entities that are referenced from the most sessions, most referenced first
+    pub most_linked: Vec<LinkedEntity>,
+    /// When anything last happened in the world
+    pub last_activity: DateTime<Utc>,
+}
@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+
+/// The translation of the details of a world into a language other than its default one
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WorldTranslation {
+    /// The name of the world in this language
+    pub name: String,
+
+    /// A description of the world in this language
+    pub description: String,
+}
@@ -1,10 +1,17 @@
+mod connections;
 mod create;
 mod errors;
 mod find;
 mod parse;
+mod publication;
+mod stars;
+mod stats;
+mod translations;
 
 use crate::infrastructure::database::Database;
+pub(crate) use create::insert_world;
 pub use errors::*;
+pub use stats::StatsKey;
 
 /// Repository used to access World data from the database
 #[derive(Clone)]
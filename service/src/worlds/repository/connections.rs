@@ -0,0 +1,162 @@
+use super::{SaveConnectionError, WorldRepository};
+use crate::{
+    model::Identity,
+    users::UserID,
+    worlds::{visible_world_sql, ConnectionData, ConnectionID, ConnectionModel, WorldID},
+};
+use chrono::Utc;
+use postgres::row::Row;
+use uuid::Uuid;
+
+impl WorldRepository {
+    /// Create a new connection between two worlds
+    ///
+    /// # Parameters
+    /// - `connection` - The connection to create
+    ///
+    /// # Returns
+    /// The newly created connection
+    ///
+    /// # Errors
+    /// Any errors that occurred creating the connection
+    #[tracing::instrument(skip(self))]
+    pub fn create_connection(
+        &self,
+        connection: ConnectionData,
+    ) -> Result<ConnectionModel, SaveConnectionError> {
+        let id = ConnectionID::default();
+        let now = Utc::now();
+        let version = Uuid::new_v4();
+
+        let mut client = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+
+        let new_connection = client.query_one("INSERT INTO world_connections(connection_id, version, created, updated, from_world_id, to_world_id, kind, description) VALUES($1, $2, $3, $3, $4, $5, $6, $7) RETURNING *",
+            &[
+                &id,
+                &version,
+                &now,
+                &connection.from,
+                &connection.to,
+                &connection.kind.to_string(),
+                &connection.description,
+            ])
+            .map(|row| self.parse_connection_row(&row))?;
+
+        Ok(new_connection)
+    }
+
+    /// Delete a connection that the given world is at either end of
+    ///
+    /// # Parameters
+    /// - `world` - The ID of the world at one end of the connection
+    /// - `id` - The ID of the connection to delete
+    ///
+    /// # Returns
+    /// `true` if the connection was deleted. `false` if it didn't exist
+    #[tracing::instrument(skip(self))]
+    pub fn delete_connection(&self, world: &WorldID, id: &ConnectionID) -> bool {
+        let mut client = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+
+        let count = client
+            .execute(
+                "DELETE FROM world_connections WHERE connection_id = $1 AND (from_world_id = $2 OR to_world_id = $2)",
+                &[id, world],
+            )
+            .expect("Failed to delete connection");
+
+        count > 0
+    }
+
+    /// Find every connection that any of the given worlds is at either end of.
+    /// Connections are only included if the worlds at both ends are visible to the viewer
+    ///
+    /// # Parameters
+    /// - `worlds` - The IDs of the worlds to find the connections of
+    /// - `viewer` - The user that is looking at the connections, if there is one
+    ///
+    /// # Returns
+    /// The connections, oldest first
+    #[tracing::instrument(skip(self))]
+    pub fn find_connections(
+        &self,
+        worlds: &[&WorldID],
+        viewer: Option<&UserID>,
+    ) -> Vec<ConnectionModel> {
+        if worlds.is_empty() {
+            return vec![];
+        }
+
+        let mut client = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+
+        let world_id_binds = worlds
+            .iter()
+            .enumerate()
+            .map(|(index, _)| format!("${}", index + 1))
+            .collect::<Vec<String>>()
+            .join(",");
+        let mut binds: Vec<&(dyn postgres::types::ToSql + Sync)> = worlds
+            .iter()
+            .map(|&id| id as &(dyn postgres::types::ToSql + Sync))
+            .collect();
+        let viewer_bind = viewer.map(|viewer| {
+            binds.push(viewer);
+            binds.len()
+        });
+
+        let query = format!(
+            "SELECT world_connections.* FROM world_connections
+            JOIN worlds from_worlds ON from_worlds.world_id = world_connections.from_world_id
+            JOIN worlds to_worlds ON to_worlds.world_id = world_connections.to_world_id
+            WHERE (world_connections.from_world_id IN ({0}) OR world_connections.to_world_id IN ({0}))
+            AND {1} AND {2}
+            ORDER BY world_connections.created ASC, world_connections.connection_id ASC",
+            world_id_binds,
+            visible_world_sql("from_worlds", viewer_bind),
+            visible_world_sql("to_worlds", viewer_bind),
+        );
+
+        client
+            .query(query.as_str(), &binds[..])
+            .map(|rows| {
+                rows.iter()
+                    .map(|row| self.parse_connection_row(row))
+                    .collect()
+            })
+            .expect("Failed to query for connections")
+    }
+
+    /// Parse a row from the database into a Connection Model
+    ///
+    /// # Parameters
+    /// - `row` - The row to parse
+    ///
+    /// # Returns
+    /// The connection that the row represented
+    fn parse_connection_row(&self, row: &Row) -> ConnectionModel {
+        let kind: String = row.get("kind");
+
+        ConnectionModel {
+            identity: Identity {
+                id: row.get("connection_id"),
+                version: row.get("version"),
+                created: row.get("created"),
+                updated: row.get("updated"),
+            },
+            data: ConnectionData {
+                from: row.get("from_world_id"),
+                to: row.get("to_world_id"),
+                kind: kind.parse().expect("Invalid connection kind"),
+                description: row.get("description"),
+            },
+        }
+    }
+}
@@ -1,13 +1,19 @@
 use super::{SaveWorldError, WorldRepository};
-use crate::worlds::model::*;
+use crate::{
+    quotas::{enforce_quota, QuotaAllowance},
+    worlds::model::*,
+};
 use chrono::Utc;
+use postgres_types::Json;
 use uuid::Uuid;
 
 impl WorldRepository {
-    /// Create a new world in the database
+    /// Create a new world in the database, enforcing the quota of worlds that the owner has in the same
+    /// transaction
     ///
     /// # Parameters
     /// - `world` - The world to create
+    /// - `quota` - The allowance for the owner to create another world
     ///
     /// # Returns
     /// The newly created world
@@ -15,28 +21,60 @@ impl WorldRepository {
     /// # Errors
     /// Any errors that occurred creating the new world
     #[tracing::instrument(skip(self))]
-    pub fn create(&self, world: WorldData) -> Result<WorldModel, SaveWorldError> {
-        let id = WorldID::default();
-        let now = Utc::now();
-        let version = Uuid::new_v4();
-
+    pub fn create(
+        &self,
+        world: WorldData,
+        quota: &QuotaAllowance,
+    ) -> Result<WorldModel, SaveWorldError> {
         let mut connection = self
             .database
             .checkout()
             .expect("Failed to get database connection");
+        let mut transaction = connection.transaction()?;
+
+        enforce_quota(&mut transaction, quota)?;
+
+        let new_world = insert_world(&mut transaction, &WorldID::default(), &world)?;
 
-        let new_world = connection.query_one("INSERT INTO worlds(world_id, version, created, updated, owner_id, name, description, url_slug) VALUES($1, $2, $3, $3, $4, $5, $6, $7) RETURNING *", 
-            &[
-                &id,
-                &version,
-                &now,
-                &world.owner,
-                &world.name,
-                &world.description,
-                &world.url_slug,
-            ])
-            .map(|row| self.parse_row(&row))?;
+        transaction.commit()?;
 
         Ok(new_world)
     }
 }
+
+/// Insert a new world as part of a larger transaction, such as one that also creates the content of a
+/// template. The quota of worlds is not enforced here
+///
+/// # Parameters
+/// - `transaction` - The transaction to insert the world in
+/// - `id` - The ID to give the new world
+/// - `world` - The world to create
+///
+/// # Returns
+/// The newly created world
+///
+/// # Errors
+/// Any errors that occurred creating the new world
+pub(crate) fn insert_world(
+    transaction: &mut postgres::Transaction<'_>,
+    id: &WorldID,
+    world: &WorldData,
+) -> Result<WorldModel, SaveWorldError> {
+    let new_world = transaction.query_one("INSERT INTO worlds(world_id, version, created, updated, owner_id, name, description, url_slug, status, publish_at, language, translations) VALUES($1, $2, $3, $3, $4, $5, $6, $7, $8, $9, $10, $11) RETURNING *",
+        &[
+            id,
+            &Uuid::new_v4(),
+            &Utc::now(),
+            &world.owner,
+            &world.name,
+            &world.description,
+            &world.url_slug,
+            &world.publication.stored_status().to_string(),
+            &world.publication.publish_at(),
+            &world.language,
+            &Json(&world.translations),
+        ])
+        .map(|row| WorldRepository::parse_row(&row))?;
+
+    Ok(new_world)
+}
@@ -1,3 +1,4 @@
+use crate::quotas::QuotaExceededError;
 use postgres::error::{DbError, SqlState};
 
 #[derive(Debug, PartialEq, thiserror::Error)]
@@ -10,6 +11,9 @@ pub enum SaveWorldError {
 
     #[error("The desired owner does not exist")]
     UnknownOwner,
+
+    #[error("The owner has reached their quota of worlds")]
+    QuotaExceeded(#[from] QuotaExceededError),
 }
 
 impl From<postgres::Error> for SaveWorldError {
@@ -67,3 +71,74 @@ impl From<postgres::Error> for SaveWorldError {
         result.unwrap_or(SaveWorldError::UnknownError)
     }
 }
+
+/// Errors that can occur when saving a connection between worlds
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum SaveConnectionError {
+    #[error("An unknown error occurred")]
+    UnknownError,
+
+    #[error("The worlds are already connected")]
+    DuplicateConnection,
+
+    #[error("The world to connect to does not exist")]
+    UnknownWorld,
+}
+
+impl From<postgres::Error> for SaveConnectionError {
+    /// Convert a database error into a SaveConnectionError.
+    ///
+    /// The conversion returns a `DuplicateConnection` iff the error is a `UNIQUE_VIOLATION` and the
+    /// constraint is `world_connections_worlds_key`, and an `UnknownWorld` iff the error is a
+    /// `FOREIGN_KEY_VIOLATION` and the constraint is `world_connections_to_world_id_fkey`.
+    /// Otherwise it returns an `UnknownError`
+    ///
+    /// # Parameters
+    /// - `e` - The error to convert
+    ///
+    /// # Returns
+    /// The new error code
+    fn from(e: postgres::Error) -> Self {
+        let mut result = None;
+
+        if e.code() == Some(&SqlState::UNIQUE_VIOLATION) {
+            let db_error: Option<DbError> = e
+                .into_source()
+                .and_then(|e| e.downcast_ref::<DbError>().cloned());
+
+            result = db_error
+                .and_then(|e| e.constraint().map(|c| c.to_owned()))
+                .map(|constraint| match constraint.as_ref() {
+                    "world_connections_worlds_key" => SaveConnectionError::DuplicateConnection,
+                    _ => {
+                        tracing::warn!(
+                            "Unexpected unique key constraint violation error: {:?}",
+                            constraint
+                        );
+                        SaveConnectionError::UnknownError
+                    }
+                });
+        } else if e.code() == Some(&SqlState::FOREIGN_KEY_VIOLATION) {
+            let db_error: Option<DbError> = e
+                .into_source()
+                .and_then(|e| e.downcast_ref::<DbError>().cloned());
+
+            result = db_error
+                .and_then(|e| e.constraint().map(|c| c.to_owned()))
+                .map(|constraint| match constraint.as_ref() {
+                    "world_connections_to_world_id_fkey" => SaveConnectionError::UnknownWorld,
+                    _ => {
+                        tracing::warn!(
+                            "Unexpected foreign key constraint violation error: {:?}",
+                            constraint
+                        );
+                        SaveConnectionError::UnknownError
+                    }
+                });
+        } else {
+            tracing::warn!("Unexpected database error: {:?}", e);
+        }
+
+        result.unwrap_or(SaveConnectionError::UnknownError)
+    }
+}
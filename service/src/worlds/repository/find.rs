@@ -1,7 +1,7 @@
 use super::WorldRepository;
 use crate::{
-    model::{Page, Pagination, SortDirection, SortFields},
-    worlds::{service::WorldsFilters, WorldID, WorldModel, WorldSortField},
+    model::{status_sql, Page, Pagination, SortDirection, SortFields},
+    worlds::{service::WorldsFilters, visible_world_sql, WorldID, WorldModel, WorldSortField},
 };
 
 impl WorldRepository {
@@ -21,7 +21,46 @@ impl WorldRepository {
         connection
             .query_opt("SELECT * FROM worlds WHERE world_id = $1", &[&id])
             .expect("Failed to query for world by ID")
-            .map(|row| self.parse_row(&row))
+            .map(|row| Self::parse_row(&row))
+    }
+
+    /// Find all of the worlds with the given IDs
+    ///
+    /// # Parameters
+    /// - `ids` - The IDs of the worlds to find
+    ///
+    /// # Returns
+    /// The worlds that exist, in no particular order
+    #[tracing::instrument(skip(self))]
+    pub fn find_worlds_by_ids(&self, ids: &[&WorldID]) -> Vec<WorldModel> {
+        if ids.is_empty() {
+            return vec![];
+        }
+
+        let mut connection = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+
+        let world_id_binds = ids
+            .iter()
+            .enumerate()
+            .map(|(index, _)| format!("${}", index + 1))
+            .collect::<Vec<String>>()
+            .join(",");
+        let query = format!(
+            "SELECT * FROM worlds WHERE world_id IN ({})",
+            world_id_binds
+        );
+        let binds: Vec<&(dyn postgres::types::ToSql + Sync)> = ids
+            .iter()
+            .map(|&id| id as &(dyn postgres::types::ToSql + Sync))
+            .collect();
+
+        connection
+            .query(query.as_str(), &binds[..])
+            .map(|rows| rows.iter().map(Self::parse_row).collect())
+            .expect("Failed to query for worlds by ID")
     }
 
     pub fn search_worlds(
@@ -42,6 +81,22 @@ impl WorldRepository {
             binds.push(url_slug);
             where_clauses.push(format!("url_slug = ${}", binds.len()));
         }
+        if let Some(starred_by) = &filters.starred_by {
+            binds.push(starred_by);
+            where_clauses.push(format!(
+                "world_id IN (SELECT world_id FROM world_stars WHERE user_id = ${})",
+                binds.len()
+            ));
+        }
+        if let Some(viewer) = &filters.viewer {
+            binds.push(viewer);
+            where_clauses.push(visible_world_sql("worlds", Some(binds.len())));
+        } else {
+            where_clauses.push(visible_world_sql("worlds", None));
+        }
+        if let Some(status) = &filters.status {
+            where_clauses.push(status_sql("worlds", status));
+        }
         let where_clause = if where_clauses.is_empty() {
             "".to_owned()
         } else {
@@ -57,12 +112,14 @@ impl WorldRepository {
                     WorldSortField::Updated => "worlds.updated",
                     WorldSortField::Name => "worlds.name",
                     WorldSortField::Owner => "users.display_name",
+                    WorldSortField::Stars => "(SELECT COUNT(*) FROM world_stars WHERE world_stars.world_id = worlds.world_id)",
                 };
                 let sort_direction = match (&sort.field, &sort.direction) {
                     (_, SortDirection::Ascending) => "ASC",
                     (_, SortDirection::Descending) => "DESC",
                     (WorldSortField::Created, SortDirection::Default) => "DESC",
                     (WorldSortField::Updated, SortDirection::Default) => "DESC",
+                    (WorldSortField::Stars, SortDirection::Default) => "DESC",
                     (_, SortDirection::Default) => "ASC",
                 };
 
@@ -86,7 +143,7 @@ impl WorldRepository {
 
         let records: Vec<WorldModel> = connection
             .query(select_query.as_str(), &binds[..])
-            .map(|rows| rows.iter().map(|row| self.parse_row(row)).collect())
+            .map(|rows| rows.iter().map(Self::parse_row).collect())
             .expect("Failed to select worlds matching query");
 
         let num_records = records.len() as u64;
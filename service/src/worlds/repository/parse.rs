@@ -1,6 +1,11 @@
 use super::WorldRepository;
-use crate::{model::Identity, worlds::model::*};
+use crate::{
+    model::{Identity, LanguageTag, Publication},
+    worlds::model::*,
+};
 use postgres::row::Row;
+use postgres_types::Json;
+use std::collections::BTreeMap;
 
 impl WorldRepository {
     /// Parse a row from the database into a World Model
@@ -10,7 +15,9 @@ impl WorldRepository {
     ///
     /// # Returns
     /// The world that the row represented
-    pub(super) fn parse_row(&self, row: &Row) -> WorldModel {
+    pub(super) fn parse_row(row: &Row) -> WorldModel {
+        let translations: Json<BTreeMap<LanguageTag, WorldTranslation>> = row.get("translations");
+
         WorldModel {
             identity: Identity {
                 id: row.get("world_id"),
@@ -23,6 +30,10 @@ impl WorldRepository {
                 name: row.get("name"),
                 description: row.get("description"),
                 url_slug: row.get("url_slug"),
+                publication: Publication::from_stored(row.get("status"), row.get("publish_at")),
+                language: row.get("language"),
+                translations: translations.0,
+                hidden: row.get("hidden"),
             },
         }
     }
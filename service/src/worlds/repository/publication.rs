@@ -0,0 +1,68 @@
+use super::WorldRepository;
+use crate::{
+    model::Publication,
+    users::UserID,
+    worlds::{WorldID, WorldModel, WorldRole},
+};
+use chrono::Utc;
+use uuid::Uuid;
+
+impl WorldRepository {
+    /// Change the publication state of a world
+    ///
+    /// # Parameters
+    /// - `id` - The ID of the world to update
+    /// - `publication` - The new publication state of the world
+    ///
+    /// # Returns
+    /// The updated world, or `None` if the world didn't exist
+    #[tracing::instrument(skip(self))]
+    pub fn update_publication(
+        &self,
+        id: &WorldID,
+        publication: &Publication,
+    ) -> Option<WorldModel> {
+        let mut connection = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+
+        connection
+            .query_opt(
+                "UPDATE worlds SET version = $2, updated = $3, status = $4, publish_at = $5 WHERE world_id = $1 RETURNING *",
+                &[
+                    id,
+                    &Uuid::new_v4(),
+                    &Utc::now(),
+                    &publication.stored_status().to_string(),
+                    &publication.publish_at(),
+                ],
+            )
+            .expect("Failed to update world publication")
+            .map(|row| Self::parse_row(&row))
+    }
+
+    /// Find the role that a user has as a member of a world
+    ///
+    /// # Parameters
+    /// - `world` - The ID of the world
+    /// - `user` - The ID of the user
+    ///
+    /// # Returns
+    /// The role that the user has, or `None` if they aren't a member of the world
+    #[tracing::instrument(skip(self))]
+    pub fn find_member_role(&self, world: &WorldID, user: &UserID) -> Option<WorldRole> {
+        let mut connection = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+
+        connection
+            .query_opt(
+                "SELECT role FROM world_members WHERE world_id = $1 AND user_id = $2",
+                &[world, user],
+            )
+            .expect("Failed to query for world member")
+            .and_then(|row| row.get::<_, String>("role").parse().ok())
+    }
+}
@@ -0,0 +1,97 @@
+use super::WorldRepository;
+use crate::{users::UserID, worlds::WorldID};
+use chrono::Utc;
+use std::collections::HashMap;
+
+impl WorldRepository {
+    /// Record that a user has starred a world. Starring a world that the user has already starred does
+    /// nothing
+    ///
+    /// # Parameters
+    /// - `world` - The ID of the world to star
+    /// - `user` - The ID of the user that is starring the world
+    ///
+    /// # Returns
+    /// True if the world was newly starred. False if the user had already starred it
+    #[tracing::instrument(skip(self))]
+    pub fn star_world(&self, world: &WorldID, user: &UserID) -> bool {
+        let mut connection = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+
+        let inserted = connection
+            .execute(
+                "INSERT INTO world_stars(world_id, user_id, created) VALUES($1, $2, $3) ON CONFLICT DO NOTHING",
+                &[world, user, &Utc::now()],
+            )
+            .expect("Failed to star world");
+
+        inserted == 1
+    }
+
+    /// Remove the star that a user has given a world, if there is one
+    ///
+    /// # Parameters
+    /// - `world` - The ID of the world to unstar
+    /// - `user` - The ID of the user that is unstarring the world
+    #[tracing::instrument(skip(self))]
+    pub fn unstar_world(&self, world: &WorldID, user: &UserID) {
+        let mut connection = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+
+        connection
+            .execute(
+                "DELETE FROM world_stars WHERE world_id = $1 AND user_id = $2",
+                &[world, user],
+            )
+            .expect("Failed to unstar world");
+    }
+
+    /// Count the number of stars that each of the given worlds has
+    ///
+    /// # Parameters
+    /// - `worlds` - The IDs of the worlds to count the stars of
+    ///
+    /// # Returns
+    /// The number of stars for each world. Worlds with no stars are not present
+    #[tracing::instrument(skip(self))]
+    pub fn count_stars(&self, worlds: &[&WorldID]) -> HashMap<WorldID, u64> {
+        if worlds.is_empty() {
+            return HashMap::new();
+        }
+
+        let mut connection = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+
+        let world_id_binds = worlds
+            .iter()
+            .enumerate()
+            .map(|(index, _)| format!("${}", index + 1))
+            .collect::<Vec<String>>()
+            .join(",");
+        let query = format!(
+            "SELECT world_id, COUNT(*)::INTEGER AS c FROM world_stars WHERE world_id IN ({}) GROUP BY world_id",
+            world_id_binds
+        );
+
+        let binds: Vec<&(dyn postgres::types::ToSql + Sync)> = worlds
+            .iter()
+            .map(|&id| id as &(dyn postgres::types::ToSql + Sync))
+            .collect();
+
+        connection
+            .query(query.as_str(), &binds[..])
+            .expect("Failed to count stars for worlds")
+            .iter()
+            .map(|row| {
+                let count: i32 = row.get("c");
+                (row.get("world_id"), count as u64)
+            })
+            .collect()
+    }
+}
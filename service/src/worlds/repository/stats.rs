@@ -0,0 +1,161 @@
+use super::WorldRepository;
+use crate::worlds::{EntityCounts, LinkedEntity, WorldID, WorldStats};
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+/// A cheap summary of everything in a world that its statistics are computed from. If this hasn't
+/// changed then neither have the statistics
+#[derive(Debug, Clone, PartialEq)]
+pub struct StatsKey {
+    /// The version of the world itself
+    version: Uuid,
+    /// The total number of records within the world. This catches anything being deleted
+    records: i64,
+    /// The most recent time that any record within the world was created or updated
+    last_change: Option<DateTime<Utc>>,
+}
+
+impl WorldRepository {
+    /// Find the summary of everything in a world that its statistics are computed from
+    ///
+    /// # Parameters
+    /// - `world` - The ID of the world
+    ///
+    /// # Returns
+    /// The summary of the world, or `None` if the world doesn't exist
+    #[tracing::instrument(skip(self))]
+    pub fn find_stats_key(&self, world: &WorldID) -> Option<StatsKey> {
+        let mut connection = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+
+        connection
+            .query_opt(
+                r#"WITH
+                    world_campaigns AS (SELECT campaign_id, updated FROM campaigns WHERE world_id = $1),
+                    world_sessions AS (SELECT s.session_id, s.updated FROM sessions s JOIN world_campaigns c ON s.campaign_id = c.campaign_id),
+                    records AS (
+                        SELECT updated FROM random_tables WHERE world_id = $1
+                        UNION ALL SELECT updated FROM characters WHERE world_id = $1
+                        UNION ALL SELECT updated FROM world_campaigns
+                        UNION ALL SELECT updated FROM world_sessions
+                        UNION ALL SELECT n.updated FROM session_notes n JOIN world_sessions s ON n.session_id = s.session_id
+                        UNION ALL SELECT updated FROM comments WHERE world_id = $1
+                        UNION ALL SELECT updated FROM attachments WHERE world_id = $1
+                        UNION ALL SELECT updated FROM world_connections WHERE from_world_id = $1
+                        UNION ALL SELECT created FROM activity WHERE world_id = $1
+                    )
+                SELECT w.version, (SELECT COUNT(*) FROM records)::BIGINT AS records, (SELECT MAX(updated) FROM records) AS last_change
+                FROM worlds w WHERE w.world_id = $1"#,
+                &[world],
+            )
+            .expect("Failed to summarise world")
+            .map(|row| StatsKey {
+                version: row.get("version"),
+                records: row.get("records"),
+                last_change: row.get("last_change"),
+            })
+    }
+
+    /// Compute the aggregate statistics for a world
+    ///
+    /// # Parameters
+    /// - `world` - The ID of the world
+    /// - `most_linked` - The number of most linked entities to include
+    ///
+    /// # Returns
+    /// The statistics for the world, or `None` if the world doesn't exist
+    #[tracing::instrument(skip(self))]
+    pub fn find_stats(&self, world: &WorldID, most_linked: u32) -> Option<WorldStats> {
+        let mut connection = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+
+        let totals = connection
+            .query_opt(
+                r#"SELECT
+                    (SELECT COUNT(*) FROM random_tables WHERE world_id = w.world_id)::BIGINT AS tables,
+                    (SELECT COUNT(*) FROM characters WHERE world_id = w.world_id)::BIGINT AS characters,
+                    (SELECT COUNT(*) FROM campaigns WHERE world_id = w.world_id)::BIGINT AS campaigns,
+                    (SELECT COUNT(*) FROM sessions s JOIN campaigns c ON s.campaign_id = c.campaign_id WHERE c.world_id = w.world_id)::BIGINT AS sessions,
+                    (SELECT COUNT(*) FROM comments WHERE world_id = w.world_id)::BIGINT AS comments,
+                    (SELECT COUNT(*) FROM attachments WHERE world_id = w.world_id)::BIGINT AS attachments,
+                    (SELECT COUNT(*) FROM world_connections WHERE from_world_id = w.world_id)::BIGINT AS connections,
+                    (SELECT COALESCE(SUM(array_length(regexp_split_to_array(trim(t.text), '\s+'), 1)), 0) FROM (
+                        SELECT s.summary AS text FROM sessions s JOIN campaigns c ON s.campaign_id = c.campaign_id WHERE c.world_id = w.world_id
+                        UNION ALL
+                        SELECT n.notes AS text FROM session_notes n JOIN sessions s ON n.session_id = s.session_id JOIN campaigns c ON s.campaign_id = c.campaign_id WHERE c.world_id = w.world_id
+                    ) t WHERE trim(t.text) <> '')::BIGINT AS words,
+                    GREATEST(w.updated, (SELECT MAX(created) FROM activity WHERE world_id = w.world_id)) AS last_activity
+                FROM worlds w WHERE w.world_id = $1"#,
+                &[world],
+            )
+            .expect("Failed to compute world statistics")?;
+
+        let broken: i64 = connection
+            .query_one(
+                r#"SELECT COUNT(*)::BIGINT AS broken
+                FROM sessions s
+                JOIN campaigns c ON s.campaign_id = c.campaign_id
+                CROSS JOIN LATERAL unnest(s.entities) AS e(entity)
+                WHERE c.world_id = $1
+                AND (
+                    (e.entity LIKE '/worlds/' || c.world_id::TEXT || '/tables/%'
+                        AND NOT EXISTS (SELECT 1 FROM random_tables t WHERE t.world_id = c.world_id AND '/worlds/' || t.world_id::TEXT || '/tables/' || t.url_slug = e.entity))
+                    OR (e.entity LIKE '/campaigns/%'
+                        AND NOT EXISTS (SELECT 1 FROM campaigns o WHERE o.world_id = c.world_id AND '/campaigns/' || o.campaign_id::TEXT = e.entity))
+                    OR (e.entity LIKE '/characters/%'
+                        AND NOT EXISTS (SELECT 1 FROM characters h WHERE h.world_id = c.world_id AND '/characters/' || h.character_id::TEXT = e.entity))
+                )"#,
+                &[world],
+            )
+            .map(|row| row.get("broken"))
+            .expect("Failed to count broken links");
+
+        let linked = connection
+            .query(
+                r#"SELECT e.entity, COUNT(DISTINCT s.session_id)::BIGINT AS links
+                FROM sessions s
+                JOIN campaigns c ON s.campaign_id = c.campaign_id
+                CROSS JOIN LATERAL unnest(s.entities) AS e(entity)
+                WHERE c.world_id = $1
+                GROUP BY e.entity
+                ORDER BY links DESC, e.entity ASC
+                LIMIT $2"#,
+                &[world, &i64::from(most_linked)],
+            )
+            .expect("Failed to find most linked entities")
+            .iter()
+            .map(|row| {
+                let links: i64 = row.get("links");
+                LinkedEntity {
+                    entity: row.get("entity"),
+                    links: links as u64,
+                }
+            })
+            .collect();
+
+        let count = |name: &str| -> u64 {
+            let value: i64 = totals.get(name);
+            value as u64
+        };
+
+        Some(WorldStats {
+            counts: EntityCounts {
+                tables: count("tables"),
+                characters: count("characters"),
+                campaigns: count("campaigns"),
+                sessions: count("sessions"),
+                comments: count("comments"),
+                attachments: count("attachments"),
+                connections: count("connections"),
+            },
+            words: count("words"),
+            broken_links: broken as u64,
+            most_linked: linked,
+            last_activity: totals.get("last_activity"),
+        })
+    }
+}
@@ -0,0 +1,65 @@
+use super::WorldRepository;
+use crate::{
+    model::LanguageTag,
+    worlds::{WorldID, WorldModel, WorldTranslation},
+};
+use chrono::Utc;
+use postgres_types::Json;
+use uuid::Uuid;
+
+impl WorldRepository {
+    /// Save the translation of a world into a language, replacing any existing translation into that
+    /// language
+    ///
+    /// # Parameters
+    /// - `id` - The ID of the world to update
+    /// - `language` - The language of the translation
+    /// - `translation` - The translated details of the world
+    ///
+    /// # Returns
+    /// The updated world, or `None` if the world didn't exist
+    #[tracing::instrument(skip(self))]
+    pub fn save_translation(
+        &self,
+        id: &WorldID,
+        language: &LanguageTag,
+        translation: &WorldTranslation,
+    ) -> Option<WorldModel> {
+        let mut connection = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+
+        connection
+            .query_opt(
+                "UPDATE worlds SET version = $2, updated = $3, translations = translations || jsonb_build_object($4::TEXT, $5::JSONB) WHERE world_id = $1 RETURNING *",
+                &[id, &Uuid::new_v4(), &Utc::now(), language, &Json(translation)],
+            )
+            .expect("Failed to save world translation")
+            .map(|row| Self::parse_row(&row))
+    }
+
+    /// Delete the translation of a world into a language
+    ///
+    /// # Parameters
+    /// - `id` - The ID of the world to update
+    /// - `language` - The language of the translation
+    ///
+    /// # Returns
+    /// The updated world, or `None` if the world didn't have a translation into this language
+    #[tracing::instrument(skip(self))]
+    pub fn delete_translation(&self, id: &WorldID, language: &LanguageTag) -> Option<WorldModel> {
+        let mut connection = self
+            .database
+            .checkout()
+            .expect("Failed to get database connection");
+
+        connection
+            .query_opt(
+                "UPDATE worlds SET version = $2, updated = $3, translations = translations - $4::TEXT WHERE world_id = $1 AND translations ? $4::TEXT RETURNING *",
+                &[id, &Uuid::new_v4(), &Utc::now(), language],
+            )
+            .expect("Failed to delete world translation")
+            .map(|row| Self::parse_row(&row))
+    }
+}
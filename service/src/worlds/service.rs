@@ -1,14 +1,35 @@
+mod connections;
 mod create_world;
 mod find;
+mod publication;
+mod stars;
+mod stats;
+mod translations;
 
-use super::repository::WorldRepository;
+use super::{repository::WorldRepository, WorldID};
+use crate::{
+    activity::ActivityService, notifications::NotificationsService, quotas::QuotasService,
+    webhooks::WebhooksService,
+};
+pub use connections::{CreateConnectionError, MAX_CONNECTION_DEPTH};
 pub use create_world::CreateWorldError;
 pub use find::WorldsFilters;
+use stats::CachedStats;
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
 
 /// Service Layer for dealing with Worlds
 #[derive(Clone)]
 pub struct WorldsService {
     repository: WorldRepository,
+    activity: ActivityService,
+    notifications: NotificationsService,
+    quotas: QuotasService,
+    webhooks: WebhooksService,
+    /// The most recently computed statistics of the most recently viewed worlds
+    stats: Arc<Mutex<HashMap<WorldID, CachedStats>>>,
 }
 
 impl WorldsService {
@@ -16,7 +37,20 @@ impl WorldsService {
     ///
     /// # Returns
     /// The Worlds Service ready to use
-    pub(super) fn new(repository: WorldRepository) -> Self {
-        Self { repository }
+    pub(super) fn new(
+        repository: WorldRepository,
+        activity: ActivityService,
+        notifications: NotificationsService,
+        quotas: QuotasService,
+        webhooks: WebhooksService,
+    ) -> Self {
+        Self {
+            repository,
+            activity,
+            notifications,
+            quotas,
+            webhooks,
+            stats: Arc::new(Mutex::new(HashMap::new())),
+        }
     }
 }
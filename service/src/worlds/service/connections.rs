@@ -0,0 +1,168 @@
+use crate::{
+    users::UserID,
+    worlds::{repository::SaveConnectionError, *},
+};
+use std::collections::{HashMap, HashSet};
+
+/// The furthest away from the starting world that a connection graph can reach
+pub const MAX_CONNECTION_DEPTH: u32 = 5;
+
+/// Errors that can occur when connecting two worlds
+#[derive(Debug, thiserror::Error)]
+pub enum CreateConnectionError {
+    /// An unknown error occurred
+    #[error("An unknown error occurred")]
+    UnknownError,
+
+    #[error("The worlds are already connected")]
+    DuplicateConnection,
+
+    #[error("The world to connect to does not exist")]
+    UnknownWorld,
+
+    #[error("A world can not be connected to itself")]
+    SameWorld,
+}
+
+impl WorldsService {
+    /// Connect one world to another
+    ///
+    /// # Parameters
+    /// - `connection` - The details of the connection to make
+    ///
+    /// # Returns
+    /// The new connection
+    ///
+    /// # Errors
+    /// Any errors that occurred connecting the worlds
+    pub fn create_connection(
+        &self,
+        connection: ConnectionData,
+    ) -> Result<ConnectionModel, CreateConnectionError> {
+        if connection.from == connection.to {
+            return Err(CreateConnectionError::SameWorld);
+        }
+
+        let new_connection = self.repository.create_connection(connection)?;
+        tracing::debug!(connection = ?new_connection, "Created connection");
+
+        Ok(new_connection)
+    }
+
+    /// Delete a connection that the given world is at either end of
+    ///
+    /// # Parameters
+    /// - `world` - The ID of the world at one end of the connection
+    /// - `id` - The ID of the connection to delete
+    ///
+    /// # Returns
+    /// `true` if the connection was deleted. `false` if it didn't exist
+    pub fn delete_connection(&self, world: &WorldID, id: &ConnectionID) -> bool {
+        self.repository.delete_connection(world, id)
+    }
+
+    /// Find every connection that the given world is at either end of
+    ///
+    /// # Parameters
+    /// - `world` - The ID of the world
+    /// - `viewer` - The user that is looking at the connections. Connections to worlds that this user
+    ///   can't see are not included
+    ///
+    /// # Returns
+    /// The connections, oldest first
+    pub fn find_connections(
+        &self,
+        world: &WorldID,
+        viewer: Option<&UserID>,
+    ) -> Vec<ConnectionModel> {
+        self.repository.find_connections(&[world], viewer)
+    }
+
+    /// Build the graph of worlds that can be reached from the given one by following connections
+    ///
+    /// # Parameters
+    /// - `world` - The world to start from
+    /// - `depth` - How many connections away from the starting world to go. This is capped at
+    ///   `MAX_CONNECTION_DEPTH`
+    /// - `viewer` - The user that is looking at the graph. Worlds that this user can't see are not
+    ///   included, and nor is anything only reachable through them
+    ///
+    /// # Returns
+    /// The graph of connected worlds
+    pub fn connection_graph(
+        &self,
+        world: WorldModel,
+        depth: u32,
+        viewer: Option<&UserID>,
+    ) -> ConnectionGraph {
+        let depth = depth.min(MAX_CONNECTION_DEPTH);
+
+        let mut depths: HashMap<WorldID, u32> = HashMap::new();
+        depths.insert(world.identity.id.clone(), 0);
+
+        let mut seen_connections: HashSet<ConnectionID> = HashSet::new();
+        let mut connections: Vec<ConnectionModel> = vec![];
+        let mut frontier = vec![world.identity.id.clone()];
+
+        for level in 1..=depth {
+            if frontier.is_empty() {
+                break;
+            }
+
+            let found = self
+                .repository
+                .find_connections(&frontier.iter().collect::<Vec<&WorldID>>(), viewer);
+
+            let mut next = vec![];
+            for connection in found {
+                for end in vec![connection.data.from.clone(), connection.data.to.clone()] {
+                    if !depths.contains_key(&end) {
+                        depths.insert(end.clone(), level);
+                        next.push(end);
+                    }
+                }
+
+                if seen_connections.insert(connection.identity.id.clone()) {
+                    connections.push(connection);
+                }
+            }
+
+            frontier = next;
+        }
+
+        let others: Vec<&WorldID> = depths
+            .keys()
+            .filter(|id| *id != &world.identity.id)
+            .collect();
+        let mut worlds: Vec<ConnectedWorld> = self
+            .repository
+            .find_worlds_by_ids(&others)
+            .into_iter()
+            .map(|connected| ConnectedWorld {
+                depth: depths[&connected.identity.id],
+                world: connected,
+            })
+            .collect();
+        worlds.sort_by(|a, b| {
+            a.depth
+                .cmp(&b.depth)
+                .then_with(|| a.world.data.name.cmp(&b.world.data.name))
+        });
+        worlds.insert(0, ConnectedWorld { world, depth: 0 });
+
+        ConnectionGraph {
+            worlds,
+            connections,
+        }
+    }
+}
+
+impl From<SaveConnectionError> for CreateConnectionError {
+    fn from(e: SaveConnectionError) -> Self {
+        match e {
+            SaveConnectionError::DuplicateConnection => CreateConnectionError::DuplicateConnection,
+            SaveConnectionError::UnknownWorld => CreateConnectionError::UnknownWorld,
+            _ => CreateConnectionError::UnknownError,
+        }
+    }
+}
@@ -1,4 +1,9 @@
-use crate::worlds::{repository::SaveWorldError, *};
+use crate::{
+    activity::{ActivityData, ActivityEvent},
+    quotas::{QuotaExceededError, QuotaResource},
+    webhooks::{WebhookEvent, WebhookEventKind},
+    worlds::{endpoints::model::WorldLink, repository::SaveWorldError, *},
+};
 
 /// Errors that can occur when creating a new user record
 #[derive(Debug, thiserror::Error)]
@@ -12,6 +17,9 @@ pub enum CreateWorldError {
 
     #[error("The desired owner does not exist")]
     UnknownOwner,
+
+    #[error("The owner has reached their quota of worlds")]
+    QuotaExceeded(#[from] QuotaExceededError),
 }
 
 impl WorldsService {
@@ -22,11 +30,33 @@ impl WorldsService {
     pub fn create_world(&self, world: WorldData) -> Result<WorldModel, CreateWorldError> {
         tracing::debug!(world = ?world, "Creating world");
 
-        let new_world = self.repository.create(world)?;
+        let quota = self
+            .quotas
+            .check_quota(&world.owner, QuotaResource::Worlds, 1)?;
+
+        let new_world = self.repository.create(world, &quota)?;
         tracing::debug!(world = ?new_world, "Created world");
 
+        self.world_created(&new_world);
+
         Ok(new_world)
     }
+
+    /// Record the activity and dispatch the webhook event for a world that has just been created
+    pub(crate) fn world_created(&self, new_world: &WorldModel) {
+        self.activity.record_activity(ActivityData {
+            world: new_world.identity.id.clone(),
+            user: Some(new_world.data.owner.clone()),
+            event: ActivityEvent::WorldCreated,
+            target: None,
+        });
+
+        self.webhooks.dispatch(WebhookEvent {
+            world: new_world.identity.id.clone(),
+            kind: WebhookEventKind::WorldCreated,
+            target: WorldLink::new(new_world.identity.id.clone()).into(),
+        });
+    }
 }
 
 impl From<SaveWorldError> for CreateWorldError {
@@ -34,6 +64,7 @@ impl From<SaveWorldError> for CreateWorldError {
         match e {
             SaveWorldError::DuplicateUrlSlug => CreateWorldError::DuplicateUrlSlug,
             SaveWorldError::UnknownOwner => CreateWorldError::UnknownOwner,
+            SaveWorldError::QuotaExceeded(e) => CreateWorldError::QuotaExceeded(e),
             _ => CreateWorldError::UnknownError,
         }
     }
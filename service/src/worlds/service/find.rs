@@ -1,6 +1,6 @@
 use super::WorldsService;
 use crate::{
-    model::{Page, Pagination, SortFields},
+    model::{Page, Pagination, PublicationStatus, SortFields},
     users::UserID,
     worlds::{WorldID, WorldModel, WorldSortField},
 };
@@ -13,6 +13,16 @@ pub struct WorldsFilters {
 
     /// Only include worlds that have this URL Slug
     pub url_slug: Option<String>,
+
+    /// Only include worlds that have been starred by this user
+    pub starred_by: Option<UserID>,
+
+    /// Only include worlds that currently have this publication status
+    pub status: Option<PublicationStatus>,
+
+    /// The user that is doing the search. Worlds that aren't published are only included if this user is
+    /// able to edit them
+    pub viewer: Option<UserID>,
 }
 
 impl WorldsService {
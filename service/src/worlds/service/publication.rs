@@ -0,0 +1,103 @@
+use super::WorldsService;
+use crate::{
+    activity::{ActivityData, ActivityEvent},
+    model::Publication,
+    users::UserID,
+    webhooks::{WebhookEvent, WebhookEventKind},
+    worlds::{endpoints::model::WorldLink, WorldID, WorldModel, WorldRole},
+};
+
+impl WorldsService {
+    /// Check if a user is able to edit a world, and so is able to see content in it that isn't yet published
+    ///
+    /// # Parameters
+    /// - `world` - The world to check
+    /// - `user` - The user to check, if there is one
+    ///
+    /// # Returns
+    /// `true` if the user is the owner of the world or a member of it with the Editor role
+    pub fn is_editor(&self, world: &WorldModel, user: Option<&UserID>) -> bool {
+        match user {
+            None => false,
+            Some(user) if user == &world.data.owner => true,
+            Some(user) => {
+                self.repository.find_member_role(&world.identity.id, user)
+                    == Some(WorldRole::Editor)
+            }
+        }
+    }
+
+    /// Check if a user is a member of a world, with any role, or is the owner of it
+    ///
+    /// # Parameters
+    /// - `world` - The world to check
+    /// - `user` - The user to check, if there is one
+    ///
+    /// # Returns
+    /// `true` if the user is the owner of the world or a member of it
+    pub fn is_member(&self, world: &WorldModel, user: Option<&UserID>) -> bool {
+        match user {
+            None => false,
+            Some(user) if user == &world.data.owner => true,
+            Some(user) => self
+                .repository
+                .find_member_role(&world.identity.id, user)
+                .is_some(),
+        }
+    }
+
+    /// Check if a user is able to see a world.
+    ///
+    /// Worlds that have been hidden by a moderator are treated the same as worlds that aren't yet published,
+    /// and both are visible only to the members of the world
+    ///
+    /// # Parameters
+    /// - `world` - The world to check
+    /// - `user` - The user to check, if there is one
+    ///
+    /// # Returns
+    /// `true` if the world is published and not hidden, or the user is a member of it
+    pub fn can_view(&self, world: &WorldModel, user: Option<&UserID>) -> bool {
+        (world.data.publication.is_visible() && !world.data.hidden) || self.is_member(world, user)
+    }
+
+    /// Change the publication state of a world
+    ///
+    /// # Parameters
+    /// - `world` - The ID of the world to update
+    /// - `publication` - The new publication state of the world
+    ///
+    /// # Returns
+    /// The updated world, or `None` if the world didn't exist
+    pub fn update_publication(
+        &self,
+        world: &WorldID,
+        publication: Publication,
+    ) -> Option<WorldModel> {
+        let updated = self.repository.update_publication(world, &publication);
+        tracing::debug!(world = ?updated, "Updated world publication");
+
+        self.dispatch_world_updated(updated.as_ref());
+        updated
+    }
+
+    /// Record the activity and dispatch the event that a world has been updated, if it was
+    ///
+    /// # Parameters
+    /// - `updated` - The updated world, or `None` if nothing was updated
+    pub(super) fn dispatch_world_updated(&self, updated: Option<&WorldModel>) {
+        if let Some(updated) = updated {
+            self.activity.record_activity(ActivityData {
+                world: updated.identity.id.clone(),
+                user: None,
+                event: ActivityEvent::WorldUpdated,
+                target: None,
+            });
+            self.webhooks.dispatch(WebhookEvent {
+                world: updated.identity.id.clone(),
+                kind: WebhookEventKind::WorldUpdated,
+                target: WorldLink::new(updated.identity.id.clone()).into(),
+            });
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use super::WorldsService;
+use crate::{
+    notifications::{NotificationData, NotificationKind},
+    users::UserID,
+    worlds::WorldID,
+};
+use std::collections::HashMap;
+
+impl WorldsService {
+    /// Star a world on behalf of a user. The owner of the world is notified the first time that a
+    /// user stars it
+    ///
+    /// # Parameters
+    /// - `world` - The ID of the world to star
+    /// - `user` - The ID of the user that is starring the world
+    pub fn star_world(&self, world: &WorldID, user: &UserID) {
+        if self.repository.star_world(world, user) {
+            self.notifications.notify(NotificationData {
+                user: None,
+                actor: user.clone(),
+                kind: NotificationKind::Star,
+                world: world.clone(),
+                target: None,
+            });
+        }
+    }
+
+    /// Remove the star that a user has given a world
+    ///
+    /// # Parameters
+    /// - `world` - The ID of the world to unstar
+    /// - `user` - The ID of the user that is unstarring the world
+    pub fn unstar_world(&self, world: &WorldID, user: &UserID) {
+        self.repository.unstar_world(world, user)
+    }
+
+    /// Count the number of stars that each of the given worlds has
+    ///
+    /// # Parameters
+    /// - `worlds` - The IDs of the worlds to count the stars of
+    ///
+    /// # Returns
+    /// The number of stars for each world. Worlds with no stars are not present
+    pub fn count_stars(&self, worlds: &[&WorldID]) -> HashMap<WorldID, u64> {
+        self.repository.count_stars(worlds)
+    }
+}
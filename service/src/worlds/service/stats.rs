@@ -0,0 +1,69 @@
+use super::WorldsService;
+use crate::worlds::{repository::StatsKey, WorldModel, WorldStats};
+use std::time::Instant;
+
+/// The number of most linked entities to include in the statistics of a world
+const MOST_LINKED_ENTITIES: u32 = 5;
+
+/// The most worlds to keep cached statistics for. Once this is reached, the statistics that were
+/// computed longest ago are discarded to make room
+const MAX_CACHED_WORLDS: usize = 1000;
+
+/// Statistics that have been computed for a world
+pub(super) struct CachedStats {
+    /// The summary of the world that the statistics were computed from
+    key: StatsKey,
+    /// When the statistics were computed
+    computed: Instant,
+    /// The statistics themselves
+    stats: WorldStats,
+}
+
+impl WorldsService {
+    /// Get the aggregate statistics for a world.
+    ///
+    /// Computing these is expensive, so they are cached against a summary of everything in the world
+    /// that they are computed from, and only recomputed once anything in the world has changed
+    ///
+    /// # Parameters
+    /// - `world` - The world to get the statistics for
+    ///
+    /// # Returns
+    /// The statistics for the world, or `None` if the world no longer exists
+    pub fn world_stats(&self, world: &WorldModel) -> Option<WorldStats> {
+        let key = self.repository.find_stats_key(&world.identity.id)?;
+
+        if let Some(cached) = self.stats.lock().unwrap().get(&world.identity.id) {
+            if cached.key == key {
+                tracing::debug!(world = ?world.identity.id, "Using cached world statistics");
+                return Some(cached.stats.clone());
+            }
+        }
+
+        let stats = self
+            .repository
+            .find_stats(&world.identity.id, MOST_LINKED_ENTITIES)?;
+        tracing::debug!(world = ?world.identity.id, stats = ?stats, "Computed world statistics");
+
+        let mut cache = self.stats.lock().unwrap();
+        if cache.len() >= MAX_CACHED_WORLDS && !cache.contains_key(&world.identity.id) {
+            let oldest = cache
+                .iter()
+                .min_by_key(|(_, cached)| cached.computed)
+                .map(|(id, _)| id.clone());
+            if let Some(oldest) = oldest {
+                cache.remove(&oldest);
+            }
+        }
+        cache.insert(
+            world.identity.id.clone(),
+            CachedStats {
+                key,
+                computed: Instant::now(),
+                stats: stats.clone(),
+            },
+        );
+
+        Some(stats)
+    }
+}
@@ -0,0 +1,51 @@
+use super::WorldsService;
+use crate::{
+    model::LanguageTag,
+    worlds::{WorldID, WorldModel, WorldTranslation},
+};
+
+impl WorldsService {
+    /// Save the translation of a world into a language
+    ///
+    /// # Parameters
+    /// - `world` - The ID of the world to update
+    /// - `language` - The language of the translation
+    /// - `translation` - The translated details of the world
+    ///
+    /// # Returns
+    /// The updated world, or `None` if the world didn't exist
+    pub fn save_translation(
+        &self,
+        world: &WorldID,
+        language: &LanguageTag,
+        translation: WorldTranslation,
+    ) -> Option<WorldModel> {
+        let updated = self
+            .repository
+            .save_translation(world, language, &translation);
+        tracing::debug!(world = ?updated, "Saved world translation");
+
+        self.dispatch_world_updated(updated.as_ref());
+        updated
+    }
+
+    /// Delete the translation of a world into a language
+    ///
+    /// # Parameters
+    /// - `world` - The ID of the world to update
+    /// - `language` - The language of the translation
+    ///
+    /// # Returns
+    /// The updated world, or `None` if the world didn't have a translation into this language
+    pub fn delete_translation(
+        &self,
+        world: &WorldID,
+        language: &LanguageTag,
+    ) -> Option<WorldModel> {
+        let updated = self.repository.delete_translation(world, language);
+        tracing::debug!(world = ?updated, "Deleted world translation");
+
+        self.dispatch_world_updated(updated.as_ref());
+        updated
+    }
+}
@@ -0,0 +1,57 @@
+use crate::model::published_sql;
+
+/// Build an SQL condition that matches the worlds that a user is allowed to edit, and so is allowed to
+/// see unpublished content in.
+///
+/// # Parameters
+/// - `table` - The name or alias of the worlds table in the query
+/// - `viewer_bind` - The index of the bind parameter holding the ID of the user doing the viewing
+///
+/// # Returns
+/// The SQL condition
+pub(crate) fn editable_world_sql(table: &str, viewer_bind: usize) -> String {
+    format!(
+        "({0}.owner_id = ${1} OR EXISTS (SELECT 1 FROM world_members WHERE world_members.world_id = {0}.world_id AND world_members.user_id = ${1} AND world_members.role = 'editor'))",
+        table, viewer_bind
+    )
+}
+
+/// Build an SQL condition that matches the worlds that a user is a member of, with any role, or owns
+///
+/// # Parameters
+/// - `table` - The name or alias of the worlds table in the query
+/// - `viewer_bind` - The index of the bind parameter holding the ID of the user doing the viewing
+///
+/// # Returns
+/// The SQL condition
+pub(crate) fn member_world_sql(table: &str, viewer_bind: usize) -> String {
+    format!(
+        "({0}.owner_id = ${1} OR EXISTS (SELECT 1 FROM world_members WHERE world_members.world_id = {0}.world_id AND world_members.user_id = ${1}))",
+        table, viewer_bind
+    )
+}
+
+/// Build an SQL condition that matches the worlds that a user is allowed to see.
+///
+/// Published worlds are visible to everyone. Worlds that aren't yet published, or that have been hidden by
+/// a moderator, are only visible to their owner and to the members of the world.
+///
+/// # Parameters
+/// - `table` - The name or alias of the worlds table in the query
+/// - `viewer_bind` - The index of the bind parameter holding the ID of the user doing the viewing, if
+///   there is one
+///
+/// # Returns
+/// The SQL condition
+pub(crate) fn visible_world_sql(table: &str, viewer_bind: Option<usize>) -> String {
+    let public_sql = format!("({} AND NOT {}.hidden)", published_sql(table), table);
+
+    match viewer_bind {
+        None => public_sql,
+        Some(viewer_bind) => format!(
+            "({} OR {})",
+            public_sql,
+            member_world_sql(table, viewer_bind)
+        ),
+    }
+}
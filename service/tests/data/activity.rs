@@ -0,0 +1,61 @@
+use crate::service::Seedable;
+use chrono::{DateTime, Timelike, Utc};
+use postgres::types::ToSql;
+use uuid::Uuid;
+
+/// An activity record that can be seeded into the database
+#[derive(Debug, Clone)]
+pub struct SeedActivity {
+    /// The ID of the activity record
+    pub activity_id: Uuid,
+    /// When the activity happened
+    pub created: DateTime<Utc>,
+    /// The ID of the world the activity happened in
+    pub world_id: Uuid,
+    /// The ID of the user that performed the activity
+    pub user_id: Uuid,
+    /// The event that happened
+    pub event: String,
+    /// The target of the event, if any
+    pub target: Option<String>,
+}
+
+impl Default for SeedActivity {
+    fn default() -> Self {
+        let now = Utc::now().with_nanosecond(0).unwrap();
+
+        Self {
+            activity_id: Uuid::new_v4(),
+            created: now,
+            world_id: Uuid::new_v4(),
+            user_id: Uuid::new_v4(),
+            event: "world_created".to_owned(),
+            target: None,
+        }
+    }
+}
+
+impl Seedable for SeedActivity {
+    /// Generate the SQL needed to insert the seeded record into the database
+    ///
+    /// # Returns
+    /// The SQL
+    fn sql(&self) -> &str {
+        "INSERT INTO activity(activity_id, created, world_id, user_id, event, target) VALUES($1, $2, $3, $4, $5, $6)"
+    }
+
+    /// Generate the binds needed to insert the seeded record into the database
+    ///
+    /// # Returns
+    /// The binds
+    fn binds(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![
+            &self.activity_id,
+            &self.created,
+            &self.world_id,
+            &self.user_id,
+            &self.event,
+            &self.target,
+        ]
+    }
+}
@@ -0,0 +1,105 @@
+use crate::service::Seedable;
+use chrono::{DateTime, Timelike, Utc};
+use postgres::types::ToSql;
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+/// An attachment that can be seeded into the database.
+///
+/// Only the database record is seeded. The contents of the file need seeding into the blob store
+/// separately, under the key `attachments/<attachment_id>`
+#[derive(Debug, Clone)]
+pub struct SeedAttachment {
+    /// The ID of the attachment
+    pub attachment_id: Uuid,
+    /// The version of the attachment
+    pub version: Uuid,
+    /// When the attachment was created
+    pub created: DateTime<Utc>,
+    /// When the attachment was last updated
+    pub updated: DateTime<Utc>,
+
+    /// The ID of the world that the attachment is on
+    pub world_id: Uuid,
+    /// The ID of the user that uploaded the attachment
+    pub owner_id: Uuid,
+    /// The name of the file
+    pub filename: String,
+    /// The content type of the file
+    pub content_type: String,
+    /// The size of the file, in bytes
+    pub size: i64,
+    /// The SHA-256 hash of the file, in hex
+    pub hash: String,
+    /// Whether the attachment has a thumbnail
+    pub has_thumbnail: bool,
+}
+
+impl SeedAttachment {
+    /// Set the size and hash of the attachment to match the given contents
+    ///
+    /// # Parameters
+    /// - `content` - The contents of the file
+    ///
+    /// # Returns
+    /// Self, for chaining
+    pub fn with_content(self, content: &[u8]) -> Self {
+        Self {
+            size: content.len() as i64,
+            hash: hex::encode(Sha256::digest(content)),
+            ..self
+        }
+    }
+}
+
+impl Default for SeedAttachment {
+    fn default() -> Self {
+        let now = Utc::now().with_nanosecond(0).unwrap();
+
+        Self {
+            attachment_id: Uuid::new_v4(),
+            version: Uuid::new_v4(),
+            created: now,
+            updated: now,
+
+            world_id: Uuid::new_v4(),
+            owner_id: Uuid::new_v4(),
+            filename: format!("attachment_{}.txt", Uuid::new_v4()),
+            content_type: "text/plain; charset=utf-8".to_owned(),
+            size: 0,
+            hash: hex::encode(Sha256::digest(b"")),
+            has_thumbnail: false,
+        }
+    }
+}
+
+impl Seedable for SeedAttachment {
+    /// Generate the SQL needed to insert the seeded record into the database
+    ///
+    /// # Returns
+    /// The SQL
+    fn sql(&self) -> &str {
+        "INSERT INTO attachments(attachment_id, version, created, updated, world_id, owner_id, filename, content_type, size, hash, has_thumbnail)
+        VALUES($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)"
+    }
+
+    /// Generate the binds needed to insert the seeded record into the database
+    ///
+    /// # Returns
+    /// The binds
+    fn binds(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![
+            &self.attachment_id,
+            &self.version,
+            &self.created,
+            &self.updated,
+            &self.world_id,
+            &self.owner_id,
+            &self.filename,
+            &self.content_type,
+            &self.size,
+            &self.hash,
+            &self.has_thumbnail,
+        ]
+    }
+}
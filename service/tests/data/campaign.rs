@@ -0,0 +1,148 @@
+use crate::service::Seedable;
+use chrono::{DateTime, NaiveDate, Timelike, Utc};
+use postgres::types::ToSql;
+use uuid::Uuid;
+
+/// A campaign that can be seeded into the database
+#[derive(Debug, Clone)]
+pub struct SeedCampaign {
+    /// The ID of the campaign
+    pub campaign_id: Uuid,
+    /// The version of the campaign
+    pub version: Uuid,
+    /// When the campaign was created
+    pub created: DateTime<Utc>,
+    /// When the campaign was last updated
+    pub updated: DateTime<Utc>,
+
+    /// The ID of the world that the campaign is run in
+    pub world_id: Uuid,
+    /// The name of the campaign
+    pub name: String,
+    /// The description of the campaign
+    pub description: String,
+    /// The User IDs of the players of the campaign
+    pub players: Vec<Uuid>,
+    /// The publication status of the campaign
+    pub status: String,
+    /// When the campaign is scheduled to be published, if it is scheduled
+    pub publish_at: Option<DateTime<Utc>>,
+}
+
+impl Default for SeedCampaign {
+    fn default() -> Self {
+        let now = Utc::now().with_nanosecond(0).unwrap();
+
+        Self {
+            campaign_id: Uuid::new_v4(),
+            version: Uuid::new_v4(),
+            created: now,
+            updated: now,
+
+            world_id: Uuid::new_v4(),
+            name: format!("name_{}", Uuid::new_v4()),
+            description: format!("Description {}", Uuid::new_v4()),
+            players: vec![],
+            status: "published".to_owned(),
+            publish_at: None,
+        }
+    }
+}
+
+impl Seedable for SeedCampaign {
+    /// Generate the SQL needed to insert the seeded record into the database
+    ///
+    /// # Returns
+    /// The SQL
+    fn sql(&self) -> &str {
+        "INSERT INTO campaigns(campaign_id, version, created, updated, world_id, name, description, players, status, publish_at)
+        VALUES($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)"
+    }
+
+    /// Generate the binds needed to insert the seeded record into the database
+    ///
+    /// # Returns
+    /// The binds
+    fn binds(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![
+            &self.campaign_id,
+            &self.version,
+            &self.created,
+            &self.updated,
+            &self.world_id,
+            &self.name,
+            &self.description,
+            &self.players,
+            &self.status,
+            &self.publish_at,
+        ]
+    }
+}
+
+/// A session of a campaign that can be seeded into the database
+#[derive(Debug, Clone)]
+pub struct SeedSession {
+    /// The ID of the session
+    pub session_id: Uuid,
+    /// The version of the session
+    pub version: Uuid,
+    /// When the session was created
+    pub created: DateTime<Utc>,
+    /// When the session was last updated
+    pub updated: DateTime<Utc>,
+
+    /// The ID of the campaign that the session belongs to
+    pub campaign_id: Uuid,
+    /// The date the session was played on
+    pub session_date: NaiveDate,
+    /// The summary of the session
+    pub summary: String,
+    /// The entities that appeared in the session
+    pub entities: Vec<String>,
+}
+
+impl Default for SeedSession {
+    fn default() -> Self {
+        let now = Utc::now().with_nanosecond(0).unwrap();
+
+        Self {
+            session_id: Uuid::new_v4(),
+            version: Uuid::new_v4(),
+            created: now,
+            updated: now,
+
+            campaign_id: Uuid::new_v4(),
+            session_date: now.date().naive_utc(),
+            summary: format!("Summary {}", Uuid::new_v4()),
+            entities: vec![],
+        }
+    }
+}
+
+impl Seedable for SeedSession {
+    /// Generate the SQL needed to insert the seeded record into the database
+    ///
+    /// # Returns
+    /// The SQL
+    fn sql(&self) -> &str {
+        "INSERT INTO sessions(session_id, version, created, updated, campaign_id, session_date, summary, entities)
+        VALUES($1, $2, $3, $4, $5, $6, $7, $8)"
+    }
+
+    /// Generate the binds needed to insert the seeded record into the database
+    ///
+    /// # Returns
+    /// The binds
+    fn binds(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![
+            &self.session_id,
+            &self.version,
+            &self.created,
+            &self.updated,
+            &self.campaign_id,
+            &self.session_date,
+            &self.summary,
+            &self.entities,
+        ]
+    }
+}
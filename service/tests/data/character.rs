@@ -0,0 +1,80 @@
+use crate::service::Seedable;
+use chrono::{DateTime, Timelike, Utc};
+use postgres::types::ToSql;
+use uuid::Uuid;
+
+/// A character that can be seeded into the database
+#[derive(Debug, Clone)]
+pub struct SeedCharacter {
+    /// The ID of the character
+    pub character_id: Uuid,
+    /// The version of the character
+    pub version: Uuid,
+    /// When the character was created
+    pub created: DateTime<Utc>,
+    /// When the character was last updated
+    pub updated: DateTime<Utc>,
+
+    /// The ID of the world that the character belongs to
+    pub world_id: Uuid,
+    /// The name of the character
+    pub name: String,
+    /// The description of the character
+    pub description: String,
+    /// The User ID of the player that controls the character, if any
+    pub controlled_by: Option<Uuid>,
+    /// The publication status of the character
+    pub status: String,
+    /// When the character is to be published, if it is scheduled
+    pub publish_at: Option<DateTime<Utc>>,
+}
+
+impl Default for SeedCharacter {
+    fn default() -> Self {
+        let now = Utc::now().with_nanosecond(0).unwrap();
+
+        Self {
+            character_id: Uuid::new_v4(),
+            version: Uuid::new_v4(),
+            created: now,
+            updated: now,
+
+            world_id: Uuid::new_v4(),
+            name: format!("name_{}", Uuid::new_v4()),
+            description: format!("Description {}", Uuid::new_v4()),
+            controlled_by: None,
+            status: "published".to_owned(),
+            publish_at: None,
+        }
+    }
+}
+
+impl Seedable for SeedCharacter {
+    /// Generate the SQL needed to insert the seeded record into the database
+    ///
+    /// # Returns
+    /// The SQL
+    fn sql(&self) -> &str {
+        "INSERT INTO characters(character_id, version, created, updated, world_id, name, description, controlled_by, status, publish_at)
+        VALUES($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)"
+    }
+
+    /// Generate the binds needed to insert the seeded record into the database
+    ///
+    /// # Returns
+    /// The binds
+    fn binds(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![
+            &self.character_id,
+            &self.version,
+            &self.created,
+            &self.updated,
+            &self.world_id,
+            &self.name,
+            &self.description,
+            &self.controlled_by,
+            &self.status,
+            &self.publish_at,
+        ]
+    }
+}
@@ -0,0 +1,80 @@
+use crate::service::Seedable;
+use chrono::{DateTime, Timelike, Utc};
+use postgres::types::ToSql;
+use uuid::Uuid;
+
+/// A comment that can be seeded into the database
+#[derive(Debug, Clone)]
+pub struct SeedComment {
+    /// The ID of the comment
+    pub comment_id: Uuid,
+    /// The version of the comment
+    pub version: Uuid,
+    /// When the comment was created
+    pub created: DateTime<Utc>,
+    /// When the comment was last updated
+    pub updated: DateTime<Utc>,
+
+    /// The ID of the world that the comment is on
+    pub world_id: Uuid,
+    /// The entity within the world that the comment is on
+    pub entity: Option<String>,
+    /// The ID of the comment that this is a reply to
+    pub parent_id: Option<Uuid>,
+    /// The ID of the user that wrote the comment
+    pub author_id: Uuid,
+    /// The body of the comment
+    pub body: String,
+    /// Whether the comment has been hidden by a moderator
+    pub hidden: bool,
+}
+
+impl Default for SeedComment {
+    fn default() -> Self {
+        let now = Utc::now().with_nanosecond(0).unwrap();
+
+        Self {
+            comment_id: Uuid::new_v4(),
+            version: Uuid::new_v4(),
+            created: now,
+            updated: now,
+
+            world_id: Uuid::new_v4(),
+            entity: None,
+            parent_id: None,
+            author_id: Uuid::new_v4(),
+            body: format!("Comment {}", Uuid::new_v4()),
+            hidden: false,
+        }
+    }
+}
+
+impl Seedable for SeedComment {
+    /// Generate the SQL needed to insert the seeded record into the database
+    ///
+    /// # Returns
+    /// The SQL
+    fn sql(&self) -> &str {
+        "INSERT INTO comments(comment_id, version, created, updated, world_id, entity, parent_id, author_id, body, hidden)
+        VALUES($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)"
+    }
+
+    /// Generate the binds needed to insert the seeded record into the database
+    ///
+    /// # Returns
+    /// The binds
+    fn binds(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![
+            &self.comment_id,
+            &self.version,
+            &self.created,
+            &self.updated,
+            &self.world_id,
+            &self.entity,
+            &self.parent_id,
+            &self.author_id,
+            &self.body,
+            &self.hidden,
+        ]
+    }
+}
@@ -0,0 +1,72 @@
+use crate::service::Seedable;
+use chrono::{DateTime, Timelike, Utc};
+use postgres::types::ToSql;
+use uuid::Uuid;
+
+/// A connection between two worlds that can be seeded into the database
+#[derive(Debug, Clone)]
+pub struct SeedWorldConnection {
+    /// The ID of the connection
+    pub connection_id: Uuid,
+    /// The version of the connection
+    pub version: Uuid,
+    /// When the connection was created
+    pub created: DateTime<Utc>,
+    /// When the connection was last updated
+    pub updated: DateTime<Utc>,
+
+    /// The ID of the world that the connection is from
+    pub from_world_id: Uuid,
+    /// The ID of the world that the connection is to
+    pub to_world_id: Uuid,
+    /// The kind of connection
+    pub kind: String,
+    /// The description of the connection
+    pub description: String,
+}
+
+impl Default for SeedWorldConnection {
+    fn default() -> Self {
+        let now = Utc::now().with_nanosecond(0).unwrap();
+
+        Self {
+            connection_id: Uuid::new_v4(),
+            version: Uuid::new_v4(),
+            created: now,
+            updated: now,
+
+            from_world_id: Uuid::new_v4(),
+            to_world_id: Uuid::new_v4(),
+            kind: "portal".to_owned(),
+            description: "".to_owned(),
+        }
+    }
+}
+
+impl Seedable for SeedWorldConnection {
+    /// Generate the SQL needed to insert the seeded record into the database
+    ///
+    /// # Returns
+    /// The SQL
+    fn sql(&self) -> &str {
+        "INSERT INTO world_connections(connection_id, version, created, updated, from_world_id, to_world_id, kind, description)
+        VALUES($1, $2, $3, $4, $5, $6, $7, $8)"
+    }
+
+    /// Generate the binds needed to insert the seeded record into the database
+    ///
+    /// # Returns
+    /// The binds
+    fn binds(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![
+            &self.connection_id,
+            &self.version,
+            &self.created,
+            &self.updated,
+            &self.from_world_id,
+            &self.to_world_id,
+            &self.kind,
+            &self.description,
+        ]
+    }
+}
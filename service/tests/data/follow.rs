@@ -0,0 +1,86 @@
+use crate::service::Seedable;
+use chrono::{DateTime, Timelike, Utc};
+use postgres::types::ToSql;
+use uuid::Uuid;
+
+/// A user following another user that can be seeded into the database
+#[derive(Debug, Clone)]
+pub struct SeedUserFollow {
+    /// The ID of the user doing the following
+    pub follower_id: Uuid,
+    /// The ID of the user being followed
+    pub user_id: Uuid,
+    /// When the user was followed
+    pub created: DateTime<Utc>,
+}
+
+impl Default for SeedUserFollow {
+    fn default() -> Self {
+        let now = Utc::now().with_nanosecond(0).unwrap();
+
+        Self {
+            follower_id: Uuid::new_v4(),
+            user_id: Uuid::new_v4(),
+            created: now,
+        }
+    }
+}
+
+impl Seedable for SeedUserFollow {
+    /// Generate the SQL needed to insert the seeded record into the database
+    ///
+    /// # Returns
+    /// The SQL
+    fn sql(&self) -> &str {
+        "INSERT INTO user_follows(follower_id, user_id, created) VALUES($1, $2, $3)"
+    }
+
+    /// Generate the binds needed to insert the seeded record into the database
+    ///
+    /// # Returns
+    /// The binds
+    fn binds(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![&self.follower_id, &self.user_id, &self.created]
+    }
+}
+
+/// A user following a world that can be seeded into the database
+#[derive(Debug, Clone)]
+pub struct SeedWorldFollow {
+    /// The ID of the user doing the following
+    pub follower_id: Uuid,
+    /// The ID of the world being followed
+    pub world_id: Uuid,
+    /// When the world was followed
+    pub created: DateTime<Utc>,
+}
+
+impl Default for SeedWorldFollow {
+    fn default() -> Self {
+        let now = Utc::now().with_nanosecond(0).unwrap();
+
+        Self {
+            follower_id: Uuid::new_v4(),
+            world_id: Uuid::new_v4(),
+            created: now,
+        }
+    }
+}
+
+impl Seedable for SeedWorldFollow {
+    /// Generate the SQL needed to insert the seeded record into the database
+    ///
+    /// # Returns
+    /// The SQL
+    fn sql(&self) -> &str {
+        "INSERT INTO world_follows(follower_id, world_id, created) VALUES($1, $2, $3)"
+    }
+
+    /// Generate the binds needed to insert the seeded record into the database
+    ///
+    /// # Returns
+    /// The binds
+    fn binds(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![&self.follower_id, &self.world_id, &self.created]
+    }
+}
@@ -0,0 +1,83 @@
+use crate::service::Seedable;
+use chrono::{DateTime, Timelike, Utc};
+use postgres::types::ToSql;
+use uuid::Uuid;
+
+/// The SHA-256 hash of the token "abc", for seeding invitations that can then be accepted
+pub const ABC_TOKEN_HASH: &str = "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad";
+
+/// An invitation that can be seeded into the database
+#[derive(Debug, Clone)]
+pub struct SeedInvitation {
+    /// The ID of the invitation
+    pub invitation_id: Uuid,
+    /// The version of the invitation
+    pub version: Uuid,
+    /// When the invitation was created
+    pub created: DateTime<Utc>,
+    /// When the invitation was last updated
+    pub updated: DateTime<Utc>,
+
+    /// The ID of the world that the invitation is for
+    pub world_id: Uuid,
+    /// The hash of the token used to accept the invitation
+    pub token_hash: String,
+    /// The role that users accepting the invitation are given
+    pub role: String,
+    /// When the invitation expires
+    pub expires: Option<DateTime<Utc>>,
+    /// The number of times the invitation can be accepted
+    pub max_uses: Option<i32>,
+    /// The number of times the invitation has been accepted
+    pub uses: i32,
+}
+
+impl Default for SeedInvitation {
+    fn default() -> Self {
+        let now = Utc::now().with_nanosecond(0).unwrap();
+
+        Self {
+            invitation_id: Uuid::new_v4(),
+            version: Uuid::new_v4(),
+            created: now,
+            updated: now,
+
+            world_id: Uuid::new_v4(),
+            token_hash: format!("token_hash_{}", Uuid::new_v4()),
+            role: "viewer".to_owned(),
+            expires: None,
+            max_uses: None,
+            uses: 0,
+        }
+    }
+}
+
+impl Seedable for SeedInvitation {
+    /// Generate the SQL needed to insert the seeded record into the database
+    ///
+    /// # Returns
+    /// The SQL
+    fn sql(&self) -> &str {
+        "INSERT INTO invitations(invitation_id, version, created, updated, world_id, token_hash, role, expires, max_uses, uses)
+        VALUES($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)"
+    }
+
+    /// Generate the binds needed to insert the seeded record into the database
+    ///
+    /// # Returns
+    /// The binds
+    fn binds(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![
+            &self.invitation_id,
+            &self.version,
+            &self.created,
+            &self.updated,
+            &self.world_id,
+            &self.token_hash,
+            &self.role,
+            &self.expires,
+            &self.max_uses,
+            &self.uses,
+        ]
+    }
+}
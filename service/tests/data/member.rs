@@ -0,0 +1,48 @@
+use crate::service::Seedable;
+use chrono::{DateTime, Timelike, Utc};
+use postgres::types::ToSql;
+use uuid::Uuid;
+
+/// A membership of a world by a user that can be seeded into the database
+#[derive(Debug, Clone)]
+pub struct SeedWorldMember {
+    /// The ID of the world
+    pub world_id: Uuid,
+    /// The ID of the user that is a member of the world
+    pub user_id: Uuid,
+    /// When the user became a member
+    pub created: DateTime<Utc>,
+    /// The role that the user has in the world
+    pub role: String,
+}
+
+impl Default for SeedWorldMember {
+    fn default() -> Self {
+        let now = Utc::now().with_nanosecond(0).unwrap();
+
+        Self {
+            world_id: Uuid::new_v4(),
+            user_id: Uuid::new_v4(),
+            created: now,
+            role: "editor".to_owned(),
+        }
+    }
+}
+
+impl Seedable for SeedWorldMember {
+    /// Generate the SQL needed to insert the seeded record into the database
+    ///
+    /// # Returns
+    /// The SQL
+    fn sql(&self) -> &str {
+        "INSERT INTO world_members(world_id, user_id, created, role) VALUES($1, $2, $3, $4)"
+    }
+
+    /// Generate the binds needed to insert the seeded record into the database
+    ///
+    /// # Returns
+    /// The binds
+    fn binds(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![&self.world_id, &self.user_id, &self.created, &self.role]
+    }
+}
@@ -1,5 +1,37 @@
+mod activity;
+mod attachment;
+mod campaign;
+mod character;
+mod comment;
+mod connection;
+mod follow;
+mod invitation;
+mod member;
+mod notification;
+mod report;
+mod star;
+mod table;
 mod user;
+mod user_quota;
+mod webhook;
 mod world;
+mod world_template;
 
+pub use activity::*;
+pub use attachment::*;
+pub use campaign::*;
+pub use character::*;
+pub use comment::*;
+pub use connection::*;
+pub use follow::*;
+pub use invitation::*;
+pub use member::*;
+pub use notification::*;
+pub use report::*;
+pub use star::*;
+pub use table::*;
 pub use user::*;
+pub use user_quota::*;
+pub use webhook::*;
 pub use world::*;
+pub use world_template::*;
@@ -0,0 +1,109 @@
+use crate::service::Seedable;
+use chrono::{DateTime, Timelike, Utc};
+use postgres::types::ToSql;
+use uuid::Uuid;
+
+/// A notification that can be seeded into the database
+#[derive(Debug, Clone)]
+pub struct SeedNotification {
+    /// The ID of the notification
+    pub notification_id: Uuid,
+    /// When the notification was created
+    pub created: DateTime<Utc>,
+    /// The ID of the user that was notified
+    pub user_id: Uuid,
+    /// The ID of the user that caused the notification
+    pub actor_id: Uuid,
+    /// The kind of notification
+    pub kind: String,
+    /// The ID of the world the notification relates to
+    pub world_id: Uuid,
+    /// The target of the notification, if any
+    pub target: Option<String>,
+    /// When the notification was read, if it has been
+    pub read: Option<DateTime<Utc>>,
+}
+
+impl Default for SeedNotification {
+    fn default() -> Self {
+        let now = Utc::now().with_nanosecond(0).unwrap();
+
+        Self {
+            notification_id: Uuid::new_v4(),
+            created: now,
+            user_id: Uuid::new_v4(),
+            actor_id: Uuid::new_v4(),
+            kind: "star".to_owned(),
+            world_id: Uuid::new_v4(),
+            target: None,
+            read: None,
+        }
+    }
+}
+
+impl Seedable for SeedNotification {
+    /// Generate the SQL needed to insert the seeded record into the database
+    ///
+    /// # Returns
+    /// The SQL
+    fn sql(&self) -> &str {
+        "INSERT INTO notifications(notification_id, created, user_id, actor_id, kind, world_id, target, read)
+        VALUES($1, $2, $3, $4, $5, $6, $7, $8)"
+    }
+
+    /// Generate the binds needed to insert the seeded record into the database
+    ///
+    /// # Returns
+    /// The binds
+    fn binds(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![
+            &self.notification_id,
+            &self.created,
+            &self.user_id,
+            &self.actor_id,
+            &self.kind,
+            &self.world_id,
+            &self.target,
+            &self.read,
+        ]
+    }
+}
+
+/// A notification preference that can be seeded into the database
+#[derive(Debug, Clone)]
+pub struct SeedNotificationPreference {
+    /// The ID of the user the preference is for
+    pub user_id: Uuid,
+    /// The kind of notification
+    pub kind: String,
+    /// Whether the kind of notification is enabled
+    pub enabled: bool,
+}
+
+impl Default for SeedNotificationPreference {
+    fn default() -> Self {
+        Self {
+            user_id: Uuid::new_v4(),
+            kind: "star".to_owned(),
+            enabled: false,
+        }
+    }
+}
+
+impl Seedable for SeedNotificationPreference {
+    /// Generate the SQL needed to insert the seeded record into the database
+    ///
+    /// # Returns
+    /// The SQL
+    fn sql(&self) -> &str {
+        "INSERT INTO notification_preferences(user_id, kind, enabled) VALUES($1, $2, $3)"
+    }
+
+    /// Generate the binds needed to insert the seeded record into the database
+    ///
+    /// # Returns
+    /// The binds
+    fn binds(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![&self.user_id, &self.kind, &self.enabled]
+    }
+}
@@ -0,0 +1,76 @@
+use crate::service::Seedable;
+use chrono::{DateTime, Timelike, Utc};
+use postgres::types::ToSql;
+use uuid::Uuid;
+
+/// A report of some content that can be seeded into the database
+#[derive(Debug, Clone)]
+pub struct SeedReport {
+    /// The ID of the report
+    pub report_id: Uuid,
+    /// The version of the report
+    pub version: Uuid,
+    /// When the report was created
+    pub created: DateTime<Utc>,
+    /// When the report was last updated
+    pub updated: DateTime<Utc>,
+
+    /// The ID of the user that made the report
+    pub reporter_id: Uuid,
+    /// The kind of content that was reported - `world`, `comment` or `user`
+    pub target_type: String,
+    /// The ID of the content that was reported
+    pub target_id: Uuid,
+    /// The reason for the report
+    pub reason: String,
+    /// The status of the report
+    pub status: String,
+}
+
+impl Default for SeedReport {
+    fn default() -> Self {
+        let now = Utc::now().with_nanosecond(0).unwrap();
+
+        Self {
+            report_id: Uuid::new_v4(),
+            version: Uuid::new_v4(),
+            created: now,
+            updated: now,
+
+            reporter_id: Uuid::new_v4(),
+            target_type: "world".to_owned(),
+            target_id: Uuid::new_v4(),
+            reason: format!("Reason {}", Uuid::new_v4()),
+            status: "open".to_owned(),
+        }
+    }
+}
+
+impl Seedable for SeedReport {
+    /// Generate the SQL needed to insert the seeded record into the database
+    ///
+    /// # Returns
+    /// The SQL
+    fn sql(&self) -> &str {
+        "INSERT INTO reports(report_id, version, created, updated, reporter_id, target_type, target_id, reason, status)
+        VALUES($1, $2, $3, $4, $5, $6, $7, $8, $9)"
+    }
+
+    /// Generate the binds needed to insert the seeded record into the database
+    ///
+    /// # Returns
+    /// The binds
+    fn binds(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![
+            &self.report_id,
+            &self.version,
+            &self.created,
+            &self.updated,
+            &self.reporter_id,
+            &self.target_type,
+            &self.target_id,
+            &self.reason,
+            &self.status,
+        ]
+    }
+}
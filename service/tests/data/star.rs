@@ -0,0 +1,45 @@
+use crate::service::Seedable;
+use chrono::{DateTime, Timelike, Utc};
+use postgres::types::ToSql;
+use uuid::Uuid;
+
+/// A star given to a world by a user that can be seeded into the database
+#[derive(Debug, Clone)]
+pub struct SeedStar {
+    /// The ID of the world that was starred
+    pub world_id: Uuid,
+    /// The ID of the user that starred the world
+    pub user_id: Uuid,
+    /// When the world was starred
+    pub created: DateTime<Utc>,
+}
+
+impl Default for SeedStar {
+    fn default() -> Self {
+        let now = Utc::now().with_nanosecond(0).unwrap();
+
+        Self {
+            world_id: Uuid::new_v4(),
+            user_id: Uuid::new_v4(),
+            created: now,
+        }
+    }
+}
+
+impl Seedable for SeedStar {
+    /// Generate the SQL needed to insert the seeded record into the database
+    ///
+    /// # Returns
+    /// The SQL
+    fn sql(&self) -> &str {
+        "INSERT INTO world_stars(world_id, user_id, created) VALUES($1, $2, $3)"
+    }
+
+    /// Generate the binds needed to insert the seeded record into the database
+    ///
+    /// # Returns
+    /// The binds
+    fn binds(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![&self.world_id, &self.user_id, &self.created]
+    }
+}
@@ -0,0 +1,85 @@
+use crate::service::Seedable;
+use chrono::{DateTime, Timelike, Utc};
+use postgres::types::ToSql;
+use serde_json::{json, Value};
+use uuid::Uuid;
+
+/// A random table that can be seeded into the database
+#[derive(Debug, Clone)]
+pub struct SeedTable {
+    /// The ID of the table
+    pub table_id: Uuid,
+    /// The version of the table
+    pub version: Uuid,
+    /// When the table was created
+    pub created: DateTime<Utc>,
+    /// When the table was last updated
+    pub updated: DateTime<Utc>,
+
+    /// The ID of the world that the table belongs to
+    pub world_id: Uuid,
+    /// The name of the table
+    pub name: String,
+    /// The description of the table
+    pub description: String,
+    /// The URL Slug of the table
+    pub url_slug: String,
+    /// The entries in the table, as an array of objects with `weight` and `text` fields
+    pub entries: Value,
+    /// The publication status of the table
+    pub status: String,
+    /// When the table is scheduled to be published, if it is scheduled
+    pub publish_at: Option<DateTime<Utc>>,
+}
+
+impl Default for SeedTable {
+    fn default() -> Self {
+        let now = Utc::now().with_nanosecond(0).unwrap();
+
+        Self {
+            table_id: Uuid::new_v4(),
+            version: Uuid::new_v4(),
+            created: now,
+            updated: now,
+
+            world_id: Uuid::new_v4(),
+            name: format!("name_{}", Uuid::new_v4()),
+            description: format!("Description {}", Uuid::new_v4()),
+            url_slug: format!("url_slug_{}", Uuid::new_v4()),
+            entries: json!([]),
+            status: "published".to_owned(),
+            publish_at: None,
+        }
+    }
+}
+
+impl Seedable for SeedTable {
+    /// Generate the SQL needed to insert the seeded record into the database
+    ///
+    /// # Returns
+    /// The SQL
+    fn sql(&self) -> &str {
+        "INSERT INTO random_tables(table_id, version, created, updated, world_id, name, description, url_slug, entries, status, publish_at)
+        VALUES($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)"
+    }
+
+    /// Generate the binds needed to insert the seeded record into the database
+    ///
+    /// # Returns
+    /// The binds
+    fn binds(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![
+            &self.table_id,
+            &self.version,
+            &self.created,
+            &self.updated,
+            &self.world_id,
+            &self.name,
+            &self.description,
+            &self.url_slug,
+            &self.entries,
+            &self.status,
+            &self.publish_at,
+        ]
+    }
+}
@@ -26,6 +26,10 @@ pub struct SeedUser {
     pub avatar_url: Option<String>,
     /// The users hashed password
     pub password: String,
+    /// Whether the user is an administrator
+    pub admin: bool,
+    /// Whether the user has been suspended
+    pub suspended: bool,
 }
 
 pub fn hash_password<S>(input: S) -> String
@@ -54,6 +58,8 @@ impl Default for SeedUser {
             email_address: format!("{}@example.com", Uuid::new_v4()),
             avatar_url: None,
             password: hash_password("password"),
+            admin: false,
+            suspended: false,
         }
     }
 }
@@ -64,8 +70,8 @@ impl Seedable for SeedUser {
     /// # Returns
     /// The SQL
     fn sql(&self) -> &str {
-        "INSERT INTO users(user_id, version, created, updated, username, display_name, email_address, avatar_url, password)
-        VALUES($1, $2, $3, $4, $5, $6, $7, $8, $9)"
+        "INSERT INTO users(user_id, version, created, updated, username, display_name, email_address, avatar_url, password, admin, suspended)
+        VALUES($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)"
     }
 
     /// Generate the binds needed to insert the seeded record into the database
@@ -83,6 +89,8 @@ impl Seedable for SeedUser {
             &self.email_address,
             &self.avatar_url,
             &self.password,
+            &self.admin,
+            &self.suspended,
         ]
     }
 }
@@ -0,0 +1,42 @@
+use crate::service::Seedable;
+use postgres::types::ToSql;
+use uuid::Uuid;
+
+/// A quota that overrides the default for a single user that can be seeded into the database
+#[derive(Debug, Clone)]
+pub struct SeedUserQuota {
+    /// The ID of the user that the quota applies to
+    pub user_id: Uuid,
+    /// The resource that the quota is for
+    pub resource: String,
+    /// The quota of the resource
+    pub quota: i64,
+}
+
+impl Default for SeedUserQuota {
+    fn default() -> Self {
+        Self {
+            user_id: Uuid::new_v4(),
+            resource: "worlds".to_owned(),
+            quota: 1,
+        }
+    }
+}
+
+impl Seedable for SeedUserQuota {
+    /// Generate the SQL needed to insert the seeded record into the database
+    ///
+    /// # Returns
+    /// The SQL
+    fn sql(&self) -> &str {
+        "INSERT INTO user_quotas(user_id, resource, quota) VALUES($1, $2, $3)"
+    }
+
+    /// Generate the binds needed to insert the seeded record into the database
+    ///
+    /// # Returns
+    /// The binds
+    fn binds(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![&self.user_id, &self.resource, &self.quota]
+    }
+}
@@ -0,0 +1,180 @@
+use crate::service::Seedable;
+use chrono::{DateTime, Timelike, Utc};
+use postgres::types::ToSql;
+use uuid::Uuid;
+
+/// A webhook that can be seeded into the database
+#[derive(Debug, Clone)]
+pub struct SeedWebhook {
+    /// The ID of the webhook
+    pub webhook_id: Uuid,
+    /// The version of the webhook
+    pub version: Uuid,
+    /// When the webhook was created
+    pub created: DateTime<Utc>,
+    /// When the webhook was last updated
+    pub updated: DateTime<Utc>,
+
+    /// The ID of the world that the webhook is for
+    pub world_id: Uuid,
+    /// The URL to deliver events to
+    pub url: String,
+    /// The secret used to sign payloads
+    pub secret: String,
+}
+
+impl Default for SeedWebhook {
+    fn default() -> Self {
+        let now = Utc::now().with_nanosecond(0).unwrap();
+
+        Self {
+            webhook_id: Uuid::new_v4(),
+            version: Uuid::new_v4(),
+            created: now,
+            updated: now,
+
+            world_id: Uuid::new_v4(),
+            url: "http://127.0.0.1:1/".to_owned(),
+            secret: "secret".to_owned(),
+        }
+    }
+}
+
+impl Seedable for SeedWebhook {
+    /// Generate the SQL needed to insert the seeded record into the database
+    ///
+    /// # Returns
+    /// The SQL
+    fn sql(&self) -> &str {
+        "INSERT INTO webhooks(webhook_id, version, created, updated, world_id, url, secret)
+        VALUES($1, $2, $3, $4, $5, $6, $7)"
+    }
+
+    /// Generate the binds needed to insert the seeded record into the database
+    ///
+    /// # Returns
+    /// The binds
+    fn binds(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![
+            &self.webhook_id,
+            &self.version,
+            &self.created,
+            &self.updated,
+            &self.world_id,
+            &self.url,
+            &self.secret,
+        ]
+    }
+}
+
+/// A webhook delivery that can be seeded into the database
+#[derive(Debug, Clone)]
+pub struct SeedDelivery {
+    /// The ID of the delivery
+    pub delivery_id: Uuid,
+    /// When the delivery was created
+    pub created: DateTime<Utc>,
+    /// The ID of the webhook that the delivery was made to
+    pub webhook_id: Uuid,
+    /// The name of the event that was delivered
+    pub event: String,
+    /// The payload that was delivered
+    pub payload: String,
+    /// The status of the delivery
+    pub status: String,
+    /// When the next attempt at the delivery is due, if it is pending
+    pub next_attempt: Option<DateTime<Utc>>,
+}
+
+impl Default for SeedDelivery {
+    fn default() -> Self {
+        let now = Utc::now().with_nanosecond(0).unwrap();
+
+        Self {
+            delivery_id: Uuid::new_v4(),
+            created: now,
+            webhook_id: Uuid::new_v4(),
+            event: "table.created".to_owned(),
+            payload: r#"{"event":"table.created"}"#.to_owned(),
+            status: "succeeded".to_owned(),
+            next_attempt: None,
+        }
+    }
+}
+
+impl Seedable for SeedDelivery {
+    /// Generate the SQL needed to insert the seeded record into the database
+    ///
+    /// # Returns
+    /// The SQL
+    fn sql(&self) -> &str {
+        "INSERT INTO webhook_deliveries(delivery_id, created, webhook_id, event, payload, status, next_attempt)
+        VALUES($1, $2, $3, $4, $5, $6, $7)"
+    }
+
+    /// Generate the binds needed to insert the seeded record into the database
+    ///
+    /// # Returns
+    /// The binds
+    fn binds(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![
+            &self.delivery_id,
+            &self.created,
+            &self.webhook_id,
+            &self.event,
+            &self.payload,
+            &self.status,
+            &self.next_attempt,
+        ]
+    }
+}
+
+/// An attempt at a webhook delivery that can be seeded into the database
+#[derive(Debug, Clone)]
+pub struct SeedDeliveryAttempt {
+    /// The ID of the delivery that was attempted
+    pub delivery_id: Uuid,
+    /// When the attempt was made
+    pub attempted: DateTime<Utc>,
+    /// The status code the receiver responded with, if any
+    pub response_status: Option<i32>,
+    /// The error that occurred, if any
+    pub error: Option<String>,
+}
+
+impl Default for SeedDeliveryAttempt {
+    fn default() -> Self {
+        let now = Utc::now().with_nanosecond(0).unwrap();
+
+        Self {
+            delivery_id: Uuid::new_v4(),
+            attempted: now,
+            response_status: Some(200),
+            error: None,
+        }
+    }
+}
+
+impl Seedable for SeedDeliveryAttempt {
+    /// Generate the SQL needed to insert the seeded record into the database
+    ///
+    /// # Returns
+    /// The SQL
+    fn sql(&self) -> &str {
+        "INSERT INTO webhook_delivery_attempts(delivery_id, attempted, response_status, error)
+        VALUES($1, $2, $3, $4)"
+    }
+
+    /// Generate the binds needed to insert the seeded record into the database
+    ///
+    /// # Returns
+    /// The binds
+    fn binds(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![
+            &self.delivery_id,
+            &self.attempted,
+            &self.response_status,
+            &self.error,
+        ]
+    }
+}
@@ -1,6 +1,7 @@
 use crate::service::Seedable;
 use chrono::{DateTime, Timelike, Utc};
 use postgres::types::ToSql;
+use serde_json::{json, Value};
 use uuid::Uuid;
 
 /// A world that can be seeded into the database
@@ -23,6 +24,17 @@ pub struct SeedWorld {
     pub url_slug: String,
     /// The User ID of the owner of the world
     pub owner: Uuid,
+    /// The publication status of the world
+    pub status: String,
+    /// When the world is scheduled to be published, if it is scheduled
+    pub publish_at: Option<DateTime<Utc>>,
+    /// The default language of the world
+    pub language: String,
+    /// The translations of the world, as an object keyed by language of objects with `name` and
+    /// `description` fields
+    pub translations: Value,
+    /// Whether the world has been hidden by a moderator
+    pub hidden: bool,
 }
 
 impl Default for SeedWorld {
@@ -39,6 +51,11 @@ impl Default for SeedWorld {
             description: format!("Description {}", Uuid::new_v4()),
             url_slug: format!("url_slug_{}", Uuid::new_v4()),
             owner: Uuid::new_v4(),
+            status: "published".to_owned(),
+            publish_at: None,
+            language: "en".to_owned(),
+            translations: json!({}),
+            hidden: false,
         }
     }
 }
@@ -49,8 +66,8 @@ impl Seedable for SeedWorld {
     /// # Returns
     /// The SQL
     fn sql(&self) -> &str {
-        "INSERT INTO worlds(world_id, version, created, updated, name, description, url_slug, owner_id)
-        VALUES($1, $2, $3, $4, $5, $6, $7, $8)"
+        "INSERT INTO worlds(world_id, version, created, updated, name, description, url_slug, owner_id, status, publish_at, language, translations, hidden)
+        VALUES($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)"
     }
 
     /// Generate the binds needed to insert the seeded record into the database
@@ -67,6 +84,11 @@ impl Seedable for SeedWorld {
             &self.description,
             &self.url_slug,
             &self.owner,
+            &self.status,
+            &self.publish_at,
+            &self.language,
+            &self.translations,
+            &self.hidden,
         ]
     }
 }
@@ -0,0 +1,76 @@
+use crate::service::Seedable;
+use chrono::{DateTime, Timelike, Utc};
+use postgres::types::ToSql;
+use serde_json::{json, Value};
+use uuid::Uuid;
+
+/// A published world template that can be seeded into the database
+#[derive(Debug, Clone)]
+pub struct SeedWorldTemplate {
+    /// The ID of the template
+    pub template_id: Uuid,
+    /// The version of the template
+    pub version: Uuid,
+    /// When the template was created
+    pub created: DateTime<Utc>,
+    /// When the template was last updated
+    pub updated: DateTime<Utc>,
+
+    /// The User ID of the user that published the template
+    pub owner: Uuid,
+    /// The name of the template
+    pub name: String,
+    /// The description of the template
+    pub description: String,
+    /// The content of the template, as an object with `tables` and `campaigns` fields
+    pub content: Value,
+}
+
+impl Default for SeedWorldTemplate {
+    fn default() -> Self {
+        let now = Utc::now().with_nanosecond(0).unwrap();
+
+        Self {
+            template_id: Uuid::new_v4(),
+            version: Uuid::new_v4(),
+            created: now,
+            updated: now,
+
+            owner: Uuid::new_v4(),
+            name: format!("name_{}", Uuid::new_v4()),
+            description: format!("Description {}", Uuid::new_v4()),
+            content: json!({
+                "tables": [],
+                "campaigns": []
+            }),
+        }
+    }
+}
+
+impl Seedable for SeedWorldTemplate {
+    /// Generate the SQL needed to insert the seeded record into the database
+    ///
+    /// # Returns
+    /// The SQL
+    fn sql(&self) -> &str {
+        "INSERT INTO world_templates(template_id, version, created, updated, owner_id, name, description, content)
+        VALUES($1, $2, $3, $4, $5, $6, $7, $8)"
+    }
+
+    /// Generate the binds needed to insert the seeded record into the database
+    ///
+    /// # Returns
+    /// The binds
+    fn binds(&self) -> Vec<&(dyn ToSql + Sync)> {
+        vec![
+            &self.template_id,
+            &self.version,
+            &self.created,
+            &self.updated,
+            &self.owner,
+            &self.name,
+            &self.description,
+            &self.content,
+        ]
+    }
+}
@@ -1,5 +1,7 @@
+mod receiver;
 mod seed;
 mod service;
 
+pub use receiver::*;
 pub use seed::*;
 pub use service::*;
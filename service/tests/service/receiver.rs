@@ -0,0 +1,133 @@
+use std::{
+    collections::HashMap,
+    io::{BufRead, BufReader, Read, Write},
+    net::TcpListener,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+/// A request that was received by the test receiver
+#[derive(Debug, Clone)]
+pub struct ReceivedRequest {
+    /// The request line, e.g. `POST / HTTP/1.1`
+    pub request_line: String,
+    /// The headers of the request, with lower-cased names
+    pub headers: HashMap<String, String>,
+    /// The body of the request
+    pub body: String,
+}
+
+/// A simple HTTP server that records every request made to it, for testing outgoing HTTP calls.
+///
+/// Each request is answered with the next status from the list provided, repeating the last one
+/// once the list is exhausted
+pub struct TestReceiver {
+    /// The port that the receiver is listening on
+    port: u16,
+    /// The requests that have been received so far
+    requests: Arc<Mutex<Vec<ReceivedRequest>>>,
+}
+
+impl TestReceiver {
+    /// Start a new receiver listening on a random local port
+    ///
+    /// # Parameters
+    /// - `statuses` - The statuses to respond with, in order
+    ///
+    /// # Returns
+    /// The receiver
+    pub fn start(statuses: Vec<u16>) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let requests = Arc::new(Mutex::new(vec![]));
+
+        let received = requests.clone();
+        std::thread::spawn(move || {
+            for (index, stream) in listener.incoming().enumerate() {
+                let mut stream = match stream {
+                    Ok(stream) => stream,
+                    Err(_) => break,
+                };
+
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut request_line = String::new();
+                reader.read_line(&mut request_line).unwrap();
+
+                let mut headers = HashMap::new();
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    let line = line.trim_end();
+                    if line.is_empty() {
+                        break;
+                    }
+                    if let Some(split) = line.find(':') {
+                        headers.insert(
+                            line[..split].trim().to_lowercase(),
+                            line[split + 1..].trim().to_owned(),
+                        );
+                    }
+                }
+
+                let length: usize = headers
+                    .get("content-length")
+                    .and_then(|length| length.parse().ok())
+                    .unwrap_or(0);
+                let mut body = vec![0; length];
+                reader.read_exact(&mut body).unwrap();
+
+                received.lock().unwrap().push(ReceivedRequest {
+                    request_line: request_line.trim_end().to_owned(),
+                    headers,
+                    body: String::from_utf8(body).unwrap(),
+                });
+
+                let status = statuses
+                    .get(index)
+                    .or_else(|| statuses.last())
+                    .cloned()
+                    .unwrap_or(200);
+                write!(
+                    stream,
+                    "HTTP/1.1 {} Test\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+                    status
+                )
+                .unwrap();
+            }
+        });
+
+        Self { port, requests }
+    }
+
+    /// The URL that the receiver can be reached on
+    pub fn url(&self) -> String {
+        format!("http://127.0.0.1:{}/", self.port)
+    }
+
+    /// Wait until at least the given number of requests have been received, failing the test if
+    /// that doesn't happen in time
+    ///
+    /// # Parameters
+    /// - `count` - The number of requests to wait for
+    /// - `timeout` - How long to wait
+    ///
+    /// # Returns
+    /// The requests that have been received
+    pub fn wait_for(&self, count: usize, timeout: Duration) -> Vec<ReceivedRequest> {
+        let start = Instant::now();
+        loop {
+            let requests = self.requests.lock().unwrap().clone();
+            if requests.len() >= count {
+                return requests;
+            }
+            if start.elapsed() > timeout {
+                panic!(
+                    "Expected {} requests but only received {}",
+                    count,
+                    requests.len()
+                );
+            }
+            std::thread::sleep(Duration::from_millis(50));
+        }
+    }
+}
@@ -1,8 +1,8 @@
 use super::seed::Seedable;
-use multiverse_lib::{Service, Settings, TestDatabase};
+use multiverse_lib::{QuotaLimits, Service, Settings, TestDatabase};
 use r2d2::{Pool, PooledConnection};
 use r2d2_postgres::PostgresConnectionManager;
-use std::str::FromStr;
+use std::{path::PathBuf, str::FromStr};
 
 /// Wrapper around the service that we are testing, allowing us to interact with it as needed.
 ///
@@ -16,6 +16,8 @@ pub struct TestService {
     pool: Pool<PostgresConnectionManager<postgres::tls::NoTls>>,
     /// The service under test
     service: Service,
+    /// The directory that uploaded files are stored in
+    blob_storage_path: PathBuf,
 }
 
 impl TestService {
@@ -40,13 +42,30 @@ impl TestService {
             .unwrap();
 
         // Actually build the service to test
-        let settings = Settings { database_url };
+        let blob_storage_path =
+            std::env::temp_dir().join(format!("multiverse-blobs-{}", uuid::Uuid::new_v4()));
+        let settings = Settings {
+            database_url,
+            blob_storage_path: blob_storage_path.clone(),
+            max_attachment_size: 64 * 1024,
+            quotas: QuotaLimits {
+                worlds: 100,
+                tables: 100,
+                campaigns: 100,
+                characters: 100,
+                attachment_bytes: 128 * 1024,
+            },
+            allow_external_avatars: true,
+            allow_loopback_webhooks: true,
+            max_event_streams: 2,
+        };
         let service = multiverse_lib::Service::new(settings);
 
         TestService {
             _database: database,
             pool,
             service,
+            blob_storage_path,
         }
     }
 
@@ -89,4 +108,26 @@ impl TestService {
 
         tracing::debug!(rows = ?updates, "Inserted seed data into database");
     }
+
+    /// Insert a blob into the blob store
+    ///
+    /// # Parameters
+    /// - `key` - The key of the blob
+    /// - `data` - The data of the blob
+    pub fn seed_blob(&self, key: &str, data: &[u8]) {
+        let path = key
+            .split('/')
+            .fold(self.blob_storage_path.clone(), |path, segment| {
+                path.join(segment)
+            });
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, data).unwrap();
+    }
+}
+
+impl Drop for TestService {
+    /// Remove any files that were uploaded during the test
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.blob_storage_path);
+    }
 }
@@ -0,0 +1,267 @@
+use crate::{
+    data::{hash_password, SeedActivity, SeedUser, SeedUserFollow, SeedWorld, SeedWorldFollow},
+    tests::run_test,
+};
+use chrono::Duration;
+use rocket::http::Status;
+use serde_json::json;
+use std::cell::RefCell;
+
+#[test]
+fn test_feed_unauthenticated() {
+    run_test()
+        .get("/feed")
+        .has_status(Status::Forbidden)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/unauthorized",
+            "title": "An invalid access token was provided",
+            "status": 403
+        }));
+}
+
+#[test]
+fn test_feed_invalid_cursor() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+
+    run_test()
+        .seed(&user)
+        .authenticate("testuser", "password")
+        .get("/feed?cursor=rubbish")
+        .has_status(Status::UnprocessableEntity)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/validation_error",
+            "title": "A validation error occurred",
+            "status": 422,
+            "fields": {
+              "cursor": {
+                "type": "tag:multiverse,2020:activity/problems/validation_error/invalid_cursor",
+                "title": "The feed cursor was malformed"
+              }
+            }
+        }));
+}
+
+#[test]
+fn test_feed_following_nothing() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let activity = SeedActivity {
+        world_id: world.world_id,
+        user_id: user.user_id,
+        ..SeedActivity::default()
+    };
+
+    run_test()
+        .seed_many(&[&user, &world, &activity])
+        .authenticate("testuser", "password")
+        .get("/feed")
+        .has_status(Status::Ok)
+        .has_header("Content-Type", "application/json")
+        .has_json_body(json!({
+          "entries": [],
+          "next": null
+        }));
+}
+
+#[test]
+fn test_feed_pages() {
+    let follower = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let followed_user = SeedUser::default();
+    let other_user = SeedUser::default();
+    let followed_world = SeedWorld {
+        world_id: uuid::Uuid::parse_str("00000000-0000-0000-0002-000000000001").unwrap(),
+        owner: other_user.user_id,
+        ..SeedWorld::default()
+    };
+    let other_world = SeedWorld {
+        world_id: uuid::Uuid::parse_str("00000000-0000-0000-0002-000000000002").unwrap(),
+        owner: other_user.user_id,
+        ..SeedWorld::default()
+    };
+    let follow_user = SeedUserFollow {
+        follower_id: follower.user_id,
+        user_id: followed_user.user_id,
+        ..SeedUserFollow::default()
+    };
+    let follow_world = SeedWorldFollow {
+        follower_id: follower.user_id,
+        world_id: followed_world.world_id,
+        ..SeedWorldFollow::default()
+    };
+
+    let now = SeedActivity::default().created;
+    let by_followed_user = SeedActivity {
+        world_id: other_world.world_id,
+        user_id: followed_user.user_id,
+        event: "member_joined".to_owned(),
+        created: now - Duration::minutes(1),
+        ..SeedActivity::default()
+    };
+    let in_followed_world = SeedActivity {
+        world_id: followed_world.world_id,
+        user_id: other_user.user_id,
+        event: "table_created".to_owned(),
+        created: now - Duration::minutes(2),
+        ..SeedActivity::default()
+    };
+    let world_created = SeedActivity {
+        world_id: followed_world.world_id,
+        user_id: other_user.user_id,
+        event: "world_created".to_owned(),
+        created: now - Duration::minutes(3),
+        ..SeedActivity::default()
+    };
+    let unfollowed = SeedActivity {
+        world_id: other_world.world_id,
+        user_id: other_user.user_id,
+        event: "world_created".to_owned(),
+        created: now - Duration::minutes(4),
+        ..SeedActivity::default()
+    };
+    let newer = SeedActivity {
+        world_id: followed_world.world_id,
+        user_id: other_user.user_id,
+        event: "campaign_created".to_owned(),
+        created: now,
+        ..SeedActivity::default()
+    };
+
+    let next = RefCell::new(String::new());
+
+    let harness = run_test()
+        .seed_many(&[
+            &follower,
+            &followed_user,
+            &other_user,
+            &followed_world,
+            &other_world,
+            &follow_user,
+            &follow_world,
+            &by_followed_user,
+            &in_followed_world,
+            &world_created,
+            &unfollowed,
+        ])
+        .authenticate("testuser", "password")
+        .get("/feed?count=2")
+        .has_status(Status::Ok)
+        .has_header("Content-Type", "application/json")
+        .has_header_regex("Link", r#"</feed\?cursor=[0-9a-f]+&count=2>; rel="next""#)
+        .assert_json_body(|body| {
+            assert_eq!(body["entries"].as_array().unwrap().len(), 2);
+            assert_eq!(body["entries"][0]["event"], json!("member_joined"));
+            assert_eq!(
+                body["entries"][0]["world"],
+                json!("/worlds/00000000-0000-0000-0002-000000000002")
+            );
+            assert_eq!(body["entries"][1]["event"], json!("table_created"));
+
+            next.replace(body["next"].as_str().unwrap().to_owned());
+        });
+
+    // Activity recorded after the first page was fetched must not shift the second page
+    harness
+        .seed(&newer)
+        .get(format!("/feed?cursor={}&count=2", next.borrow()))
+        .has_status(Status::Ok)
+        .has_header("Content-Type", "application/json")
+        .assert_json_body(|body| {
+            assert_eq!(body["entries"].as_array().unwrap().len(), 1);
+            assert_eq!(body["entries"][0]["event"], json!("world_created"));
+            assert_eq!(
+                body["entries"][0]["world"],
+                json!("/worlds/00000000-0000-0000-0002-000000000001")
+            );
+            assert_eq!(body["next"], json!(null));
+        });
+}
+
+#[test]
+fn test_feed_huge_count() {
+    let follower = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+
+    run_test()
+        .seed(&follower)
+        .authenticate("testuser", "password")
+        .get("/feed?count=18446744073709551615")
+        .has_status(Status::Ok)
+        .assert_json_body(|body| {
+            assert_eq!(body["entries"], json!([]));
+            assert_eq!(body["next"], json!(null));
+        });
+}
+
+#[test]
+fn test_feed_excludes_draft_worlds() {
+    let follower = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let followed_user = SeedUser::default();
+    let published_world = SeedWorld {
+        owner: followed_user.user_id,
+        ..SeedWorld::default()
+    };
+    let draft_world = SeedWorld {
+        owner: followed_user.user_id,
+        status: "draft".to_owned(),
+        ..SeedWorld::default()
+    };
+    let follow_user = SeedUserFollow {
+        follower_id: follower.user_id,
+        user_id: followed_user.user_id,
+        ..SeedUserFollow::default()
+    };
+    let public_activity = SeedActivity {
+        world_id: published_world.world_id,
+        user_id: followed_user.user_id,
+        event: "world_created".to_owned(),
+        ..SeedActivity::default()
+    };
+    let private_activity = SeedActivity {
+        world_id: draft_world.world_id,
+        user_id: followed_user.user_id,
+        event: "table_created".to_owned(),
+        ..SeedActivity::default()
+    };
+
+    run_test()
+        .seed_many(&[
+            &follower,
+            &followed_user,
+            &published_world,
+            &draft_world,
+            &follow_user,
+            &public_activity,
+            &private_activity,
+        ])
+        .authenticate("testuser", "password")
+        .get("/feed")
+        .has_status(Status::Ok)
+        .assert_json_body(|body| {
+            assert_eq!(body["entries"].as_array().unwrap().len(), 1);
+            assert_eq!(body["entries"][0]["event"], json!("world_created"));
+        });
+}
@@ -0,0 +1,124 @@
+use crate::{
+    data::{hash_password, SeedUser, SeedUserFollow},
+    tests::run_test,
+};
+use rocket::http::Status;
+use serde_json::json;
+
+#[test]
+fn test_follow_unauthenticated() {
+    let user = SeedUser::default();
+    let url = format!("/users/{}/follow", user.user_id);
+
+    run_test()
+        .seed(&user)
+        .put(url, json!({}))
+        .has_status(Status::Forbidden)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/unauthorized",
+            "title": "An invalid access token was provided",
+            "status": 403
+        }));
+}
+
+#[test]
+fn test_follow_unknown_user() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+
+    run_test()
+        .seed(&user)
+        .authenticate("testuser", "password")
+        .put(
+            "/users/d6c124d5-1060-48ef-aeb8-e591a8261c2b/follow",
+            json!({}),
+        )
+        .has_status(Status::NotFound)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:users/problems/unknown_user_id",
+            "title": "The requested user ID was unknown",
+            "status": 404
+        }));
+}
+
+#[test]
+fn test_follow_self() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let url = format!("/users/{}/follow", user.user_id);
+
+    run_test()
+        .seed(&user)
+        .authenticate("testuser", "password")
+        .put(url, json!({}))
+        .has_status(Status::UnprocessableEntity)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:activity/problems/following_self",
+            "title": "Users can not follow themselves",
+            "status": 422
+        }));
+}
+
+#[test]
+fn test_follow_success() {
+    let follower = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let user = SeedUser::default();
+    let url = format!("/users/{}/follow", user.user_id);
+
+    run_test()
+        .seed(&follower)
+        .seed(&user)
+        .authenticate("testuser", "password")
+        .put(url.clone(), json!({}))
+        .has_status(Status::NoContent)
+        .put(url, json!({}))
+        .has_status(Status::NoContent)
+        .assert_database(|mut tx| {
+            let rows = tx
+                .query(
+                    "SELECT * FROM user_follows WHERE follower_id = $1 AND user_id = $2",
+                    &[&follower.user_id, &user.user_id],
+                )
+                .unwrap();
+            assert_eq!(rows.len(), 1);
+        });
+}
+
+#[test]
+fn test_unfollow_success() {
+    let follower = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let user = SeedUser::default();
+    let follow = SeedUserFollow {
+        follower_id: follower.user_id,
+        user_id: user.user_id,
+        ..SeedUserFollow::default()
+    };
+    let url = format!("/users/{}/follow", user.user_id);
+
+    run_test()
+        .seed_many(&[&follower, &user, &follow])
+        .authenticate("testuser", "password")
+        .delete(url)
+        .has_status(Status::NoContent)
+        .assert_database(|mut tx| {
+            let rows = tx.query("SELECT * FROM user_follows", &[]).unwrap();
+            assert_eq!(rows.len(), 0);
+        });
+}
@@ -0,0 +1,117 @@
+use crate::{
+    data::{hash_password, SeedUser, SeedWorld, SeedWorldFollow},
+    tests::run_test,
+};
+use rocket::http::Status;
+use serde_json::json;
+
+#[test]
+fn test_follow_unauthenticated() {
+    let world_owner = SeedUser::default();
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let url = format!("/worlds/{}/follow", world.world_id);
+
+    run_test()
+        .seed(&world_owner)
+        .seed(&world)
+        .put(url, json!({}))
+        .has_status(Status::Forbidden)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/unauthorized",
+            "title": "An invalid access token was provided",
+            "status": 403
+        }));
+}
+
+#[test]
+fn test_follow_unknown_world() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+
+    run_test()
+        .seed(&user)
+        .authenticate("testuser", "password")
+        .put(
+            "/worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b/follow",
+            json!({}),
+        )
+        .has_status(Status::NotFound)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:worlds/problems/unknown_world_id",
+            "title": "The requested world ID was unknown",
+            "status": 404
+        }));
+}
+
+#[test]
+fn test_follow_success() {
+    let world_owner = SeedUser::default();
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let url = format!("/worlds/{}/follow", world.world_id);
+
+    run_test()
+        .seed(&world_owner)
+        .seed(&user)
+        .seed(&world)
+        .authenticate("testuser", "password")
+        .put(url.clone(), json!({}))
+        .has_status(Status::NoContent)
+        .put(url, json!({}))
+        .has_status(Status::NoContent)
+        .assert_database(|mut tx| {
+            let rows = tx
+                .query(
+                    "SELECT * FROM world_follows WHERE follower_id = $1 AND world_id = $2",
+                    &[&user.user_id, &world.world_id],
+                )
+                .unwrap();
+            assert_eq!(rows.len(), 1);
+        });
+}
+
+#[test]
+fn test_unfollow_success() {
+    let world_owner = SeedUser::default();
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let follow = SeedWorldFollow {
+        follower_id: user.user_id,
+        world_id: world.world_id,
+        ..SeedWorldFollow::default()
+    };
+    let url = format!("/worlds/{}/follow", world.world_id);
+
+    run_test()
+        .seed(&world_owner)
+        .seed_many(&[&user, &world, &follow])
+        .authenticate("testuser", "password")
+        .delete(url)
+        .has_status(Status::NoContent)
+        .assert_database(|mut tx| {
+            let rows = tx.query("SELECT * FROM world_follows", &[]).unwrap();
+            assert_eq!(rows.len(), 0);
+        });
+}
@@ -0,0 +1,5 @@
+mod feed;
+mod follow_user;
+mod follow_world;
+mod user_activity;
+mod world_activity;
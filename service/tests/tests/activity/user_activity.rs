@@ -0,0 +1,152 @@
+use crate::{
+    data::{hash_password, SeedActivity, SeedUser, SeedWorld},
+    tests::run_test,
+};
+use rocket::http::Status;
+use serde_json::json;
+
+#[test]
+fn test_user_activity_unknown_user() {
+    run_test()
+        .get("/users/d6c124d5-1060-48ef-aeb8-e591a8261c2b/activity")
+        .has_status(Status::NotFound)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:users/problems/unknown_user_id",
+            "title": "The requested user ID was unknown",
+            "status": 404
+        }));
+}
+
+#[test]
+fn test_user_activity_none() {
+    let user = SeedUser::default();
+    let url = format!("/users/{}/activity", user.user_id);
+
+    run_test()
+        .seed(&user)
+        .get(url)
+        .has_status(Status::Ok)
+        .has_header("Content-Type", "application/json")
+        .has_json_body(json!({
+          "entries": [],
+          "pagination": {
+            "offset": 0,
+            "count": 10,
+            "total": 0
+          }
+        }));
+}
+
+#[test]
+fn test_user_activity_only_own() {
+    let user = SeedUser {
+        user_id: uuid::Uuid::parse_str("00000000-0000-0000-0001-000000000001").unwrap(),
+        ..SeedUser::default()
+    };
+    let other = SeedUser::default();
+    let world = SeedWorld {
+        world_id: uuid::Uuid::parse_str("00000000-0000-0000-0002-000000000001").unwrap(),
+        owner: other.user_id,
+        ..SeedWorld::default()
+    };
+    let joined = SeedActivity {
+        world_id: world.world_id,
+        user_id: user.user_id,
+        event: "member_joined".to_owned(),
+        ..SeedActivity::default()
+    };
+    let created = SeedActivity {
+        world_id: world.world_id,
+        user_id: other.user_id,
+        event: "world_created".to_owned(),
+        ..SeedActivity::default()
+    };
+
+    run_test()
+        .seed_many(&[&user, &other, &world, &joined, &created])
+        .get("/users/00000000-0000-0000-0001-000000000001/activity")
+        .has_status(Status::Ok)
+        .has_header("Content-Type", "application/json")
+        .assert_json_body(|body| {
+            assert_eq!(body["pagination"]["total"], json!(1));
+            assert_eq!(body["entries"][0]["event"], json!("member_joined"));
+            assert_eq!(
+                body["entries"][0]["world"],
+                json!("/worlds/00000000-0000-0000-0002-000000000001")
+            );
+        });
+}
+
+#[test]
+fn test_user_activity_excludes_draft_worlds() {
+    let user = SeedUser::default();
+    let published = SeedWorld {
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let draft = SeedWorld {
+        owner: user.user_id,
+        status: "draft".to_owned(),
+        ..SeedWorld::default()
+    };
+    let public_activity = SeedActivity {
+        world_id: published.world_id,
+        user_id: user.user_id,
+        event: "world_created".to_owned(),
+        ..SeedActivity::default()
+    };
+    let private_activity = SeedActivity {
+        world_id: draft.world_id,
+        user_id: user.user_id,
+        event: "table_created".to_owned(),
+        ..SeedActivity::default()
+    };
+    let url = format!("/users/{}/activity", user.user_id);
+
+    run_test()
+        .seed_many(&[
+            &user,
+            &published,
+            &draft,
+            &public_activity,
+            &private_activity,
+        ])
+        .get(url)
+        .has_status(Status::Ok)
+        .assert_json_body(|body| {
+            assert_eq!(body["pagination"]["total"], json!(1));
+            assert_eq!(body["entries"][0]["event"], json!("world_created"));
+        });
+}
+
+#[test]
+fn test_user_activity_includes_own_draft_worlds() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let draft = SeedWorld {
+        owner: user.user_id,
+        status: "draft".to_owned(),
+        ..SeedWorld::default()
+    };
+    let private_activity = SeedActivity {
+        world_id: draft.world_id,
+        user_id: user.user_id,
+        event: "table_created".to_owned(),
+        ..SeedActivity::default()
+    };
+    let url = format!("/users/{}/activity", user.user_id);
+
+    run_test()
+        .seed_many(&[&user, &draft, &private_activity])
+        .authenticate("testuser", "password")
+        .get(url)
+        .has_status(Status::Ok)
+        .assert_json_body(|body| {
+            assert_eq!(body["pagination"]["total"], json!(1));
+            assert_eq!(body["entries"][0]["event"], json!("table_created"));
+        });
+}
@@ -0,0 +1,175 @@
+use crate::{
+    data::{hash_password, SeedActivity, SeedUser, SeedWorld, SeedWorldMember},
+    tests::run_test,
+};
+use chrono::Duration;
+use rocket::http::Status;
+use serde_json::json;
+
+#[test]
+fn test_world_activity_unknown_world() {
+    run_test()
+        .get("/worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b/activity")
+        .has_status(Status::NotFound)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:worlds/problems/unknown_world_id",
+            "title": "The requested world ID was unknown",
+            "status": 404
+        }));
+}
+
+#[test]
+fn test_world_activity_none() {
+    let user = SeedUser::default();
+    let world = SeedWorld {
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let url = format!("/worlds/{}/activity", world.world_id);
+
+    run_test()
+        .seed(&user)
+        .seed(&world)
+        .get(url)
+        .has_status(Status::Ok)
+        .has_header("Content-Type", "application/json")
+        .has_json_body(json!({
+          "entries": [],
+          "pagination": {
+            "offset": 0,
+            "count": 10,
+            "total": 0
+          }
+        }));
+}
+
+#[test]
+fn test_world_activity_newest_first() {
+    let user = SeedUser {
+        user_id: uuid::Uuid::parse_str("00000000-0000-0000-0001-000000000001").unwrap(),
+        display_name: "Test User".to_owned(),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        world_id: uuid::Uuid::parse_str("00000000-0000-0000-0002-000000000001").unwrap(),
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let other_world = SeedWorld {
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let created = SeedActivity {
+        world_id: world.world_id,
+        user_id: user.user_id,
+        event: "world_created".to_owned(),
+        created: SeedActivity::default().created - Duration::minutes(5),
+        ..SeedActivity::default()
+    };
+    let table = SeedActivity {
+        world_id: world.world_id,
+        user_id: user.user_id,
+        event: "table_created".to_owned(),
+        target: Some("/worlds/00000000-0000-0000-0002-000000000001/tables/names".to_owned()),
+        ..SeedActivity::default()
+    };
+    let other = SeedActivity {
+        world_id: other_world.world_id,
+        user_id: user.user_id,
+        ..SeedActivity::default()
+    };
+
+    run_test()
+        .seed_many(&[&user, &world, &other_world, &created, &table, &other])
+        .get("/worlds/00000000-0000-0000-0002-000000000001/activity")
+        .has_status(Status::Ok)
+        .has_header("Content-Type", "application/json")
+        .has_header_regex(
+            "Link",
+            r#"</worlds/00000000-0000-0000-0002-000000000001>; rel="related"; anchor="\#/entries/0""#,
+        )
+        .has_header_regex(
+            "Link",
+            r#"</users/00000000-0000-0000-0001-000000000001>; rel="author"; title="Test User"; anchor="\#/entries/1""#,
+        )
+        .assert_json_body(|body| {
+            assert_eq!(body["pagination"]["total"], json!(2));
+            assert_eq!(body["entries"][0]["event"], json!("table_created"));
+            assert_eq!(
+                body["entries"][0]["target"],
+                json!("/worlds/00000000-0000-0000-0002-000000000001/tables/names")
+            );
+            assert_eq!(body["entries"][1]["event"], json!("world_created"));
+            assert_eq!(body["entries"][1]["target"], json!(null));
+        });
+}
+
+#[test]
+fn test_create_world_records_activity() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+
+    run_test()
+        .seed(&user)
+        .authenticate("testuser", "password")
+        .post(
+            "/worlds",
+            json!({
+                "name": "Test World",
+                "description": "This is a test world",
+                "url_slug": "test-world"
+            }),
+        )
+        .has_status(Status::Ok)
+        .assert_database(|mut tx| {
+            let rows = tx
+                .query("SELECT user_id, event, target FROM activity", &[])
+                .unwrap();
+            assert_eq!(rows.len(), 1);
+            assert_eq!(rows[0].get::<_, uuid::Uuid>(0), user.user_id);
+            assert_eq!(rows[0].get::<_, String>(1), "world_created");
+            assert_eq!(rows[0].get::<_, Option<String>>(2), None);
+        });
+}
+
+#[test]
+fn test_world_activity_draft_world_viewer() {
+    let owner = SeedUser::default();
+    let viewer = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: owner.user_id,
+        status: "draft".to_owned(),
+        ..SeedWorld::default()
+    };
+    let member = SeedWorldMember {
+        world_id: world.world_id,
+        user_id: viewer.user_id,
+        role: "viewer".to_owned(),
+        ..SeedWorldMember::default()
+    };
+    let activity = SeedActivity {
+        user_id: owner.user_id,
+        world_id: world.world_id,
+        event: "world_created".to_owned(),
+        ..SeedActivity::default()
+    };
+    let url = format!("/worlds/{}/activity", world.world_id);
+
+    run_test()
+        .seed_many(&[&owner, &viewer, &world, &member, &activity])
+        .authenticate("testuser", "password")
+        .get(url)
+        .has_status(Status::Ok)
+        .assert_json_body(|body| {
+            assert_eq!(body["pagination"]["total"], json!(1));
+            assert_eq!(body["entries"][0]["event"], json!("world_created"));
+        });
+}
@@ -0,0 +1,357 @@
+use crate::{
+    data::{hash_password, SeedAttachment, SeedUser, SeedWorld, SeedWorldMember},
+    tests::{multipart, run_test, MULTIPART},
+};
+use image::{DynamicImage, ImageOutputFormat};
+use rocket::http::Status;
+use serde_json::json;
+
+#[test]
+fn test_create_unauthenticated() {
+    let world_owner = SeedUser::default();
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let url = format!("/worlds/{}/attachments", world.world_id);
+
+    run_test()
+        .seed_many(&[&world_owner, &world])
+        .post_raw(url, MULTIPART, multipart("file", "notes.txt", b"Notes"))
+        .has_status(Status::Forbidden)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/unauthorized",
+            "title": "An invalid access token was provided",
+            "status": 403
+        }));
+}
+
+#[test]
+fn test_create_not_editor() {
+    let world_owner = SeedUser::default();
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let url = format!("/worlds/{}/attachments", world.world_id);
+
+    run_test()
+        .seed_many(&[&world_owner, &user, &world])
+        .authenticate("testuser", "password")
+        .post_raw(url, MULTIPART, multipart("file", "notes.txt", b"Notes"))
+        .has_status(Status::Forbidden)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/unauthorized",
+            "title": "An invalid access token was provided",
+            "status": 403
+        }));
+}
+
+#[test]
+fn test_create_unknown_world() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+
+    run_test()
+        .seed(&user)
+        .authenticate("testuser", "password")
+        .post_raw(
+            "/worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b/attachments",
+            MULTIPART,
+            multipart("file", "notes.txt", b"Notes"),
+        )
+        .has_status(Status::NotFound)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:worlds/problems/unknown_world_id",
+            "title": "The requested world ID was unknown",
+            "status": 404
+        }));
+}
+
+#[test]
+fn test_create_not_multipart() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let url = format!("/worlds/{}/attachments", world.world_id);
+
+    run_test()
+        .seed_many(&[&user, &world])
+        .authenticate("testuser", "password")
+        .post_raw(url, "text/plain", "Notes")
+        .has_status(Status::UnsupportedMediaType)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/invalid_upload",
+            "title": "The upload was not valid multipart/form-data",
+            "status": 415
+        }));
+}
+
+#[test]
+fn test_create_missing_file() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let url = format!("/worlds/{}/attachments", world.world_id);
+
+    run_test()
+        .seed_many(&[&user, &world])
+        .authenticate("testuser", "password")
+        .post_raw(url, MULTIPART, multipart("other", "notes.txt", b"Notes"))
+        .has_status(Status::UnprocessableEntity)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/missing_file",
+            "title": "The upload did not contain a file",
+            "status": 422
+        }));
+}
+
+#[test]
+fn test_create_unsupported_type() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let url = format!("/worlds/{}/attachments", world.world_id);
+
+    run_test()
+        .seed_many(&[&user, &world])
+        .authenticate("testuser", "password")
+        .post_raw(
+            url,
+            MULTIPART,
+            multipart("file", "picture.png", b"MZ\x90\0\x03\0\0\0"),
+        )
+        .has_status(Status::UnsupportedMediaType)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:attachments/problems/unsupported_content_type",
+            "title": "The uploaded file was not a supported type",
+            "status": 415
+        }));
+}
+
+#[test]
+fn test_create_too_large() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let url = format!("/worlds/{}/attachments", world.world_id);
+
+    run_test()
+        .seed_many(&[&user, &world])
+        .authenticate("testuser", "password")
+        .post_raw(
+            url,
+            MULTIPART,
+            multipart("file", "notes.txt", &[b'a'; 64 * 1024 + 1]),
+        )
+        .has_status(Status::PayloadTooLarge)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/upload_too_large",
+            "title": "The uploaded file was too large",
+            "status": 413,
+            "max_size": 65536
+        }));
+}
+
+#[test]
+fn test_create_quota_exceeded() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let other_world = SeedWorld {
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let existing = SeedAttachment {
+        world_id: other_world.world_id,
+        owner_id: user.user_id,
+        size: 100 * 1024,
+        ..SeedAttachment::default()
+    };
+    let url = format!("/worlds/{}/attachments", world.world_id);
+
+    run_test()
+        .seed_many(&[&user, &world, &other_world, &existing])
+        .authenticate("testuser", "password")
+        .post_raw(
+            url,
+            MULTIPART,
+            multipart("file", "notes.txt", &[b'a'; 30 * 1024]),
+        )
+        .has_status(Status::PayloadTooLarge)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:attachments/problems/quota_exceeded",
+            "title": "The upload quota has been exceeded",
+            "status": 413,
+            "used": 102400,
+            "quota": 131072
+        }));
+}
+
+#[test]
+fn test_create_text_success() {
+    let world_owner = SeedUser::default();
+    let user = SeedUser {
+        user_id: uuid::Uuid::parse_str("00000000-0000-0000-0001-000000000001").unwrap(),
+        username: "testuser".to_owned(),
+        display_name: "Test User".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        world_id: uuid::Uuid::parse_str("d6c124d5-1060-48ef-aeb8-e591a8261c2b").unwrap(),
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let member = SeedWorldMember {
+        world_id: world.world_id,
+        user_id: user.user_id,
+        role: "editor".to_owned(),
+        ..SeedWorldMember::default()
+    };
+
+    run_test()
+        .seed_many(&[&world_owner, &user, &world, &member])
+        .authenticate("testuser", "password")
+        .post_raw(
+            "/worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b/attachments",
+            MULTIPART,
+            multipart("file", "C:\\Notes\\dragons.txt", b"Here be dragons"),
+        )
+        .has_status(Status::Ok)
+        .has_header("Content-Type", "application/json")
+        .has_header_regex(
+            "Link",
+            r#"</worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b/attachments/[0-9a-f-]+>; rel="self""#,
+        )
+        .has_header_regex(
+            "Link",
+            r#"</users/00000000-0000-0000-0001-000000000001>; rel="author"; title="Test User""#,
+        )
+        .has_header_regex(
+            "Link",
+            r#"</worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b>; rel="related""#,
+        )
+        .has_header_regex(
+            "Link",
+            r#"</worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b/attachments/[0-9a-f-]+/content>; rel="enclosure""#,
+        )
+        .assert_response(|response| {
+            assert!(!response.headers["Link"].contains("rel=\"preview\""));
+        })
+        .assert_json_body(|body| {
+            assert_eq!(body["filename"], json!("dragons.txt"));
+            assert_eq!(body["content_type"], json!("text/plain; charset=utf-8"));
+            assert_eq!(body["size"], json!(15));
+        })
+        .assert_database(|mut tx| {
+            let row = tx
+                .query_one(
+                    "SELECT * FROM attachments WHERE world_id = 'd6c124d5-1060-48ef-aeb8-e591a8261c2b'",
+                    &[],
+                )
+                .unwrap();
+            let owner: uuid::Uuid = row.get("owner_id");
+            let hash: String = row.get("hash");
+            let has_thumbnail: bool = row.get("has_thumbnail");
+            assert_eq!(owner, user.user_id);
+            assert_eq!(
+                hash,
+                "f902c6b5aeaef13665d4fa3c7053bb17908a4f0f8051bb9dcfecdb17c76a7d38"
+            );
+            assert!(!has_thumbnail);
+        });
+}
+
+#[test]
+fn test_create_image_success() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        world_id: uuid::Uuid::parse_str("d6c124d5-1060-48ef-aeb8-e591a8261c2b").unwrap(),
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+
+    let mut image = vec![];
+    DynamicImage::new_rgb8(600, 300)
+        .write_to(&mut image, ImageOutputFormat::Png)
+        .unwrap();
+
+    run_test()
+        .seed_many(&[&user, &world])
+        .authenticate("testuser", "password")
+        .post_raw(
+            "/worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b/attachments",
+            MULTIPART,
+            multipart("file", "map.png", &image),
+        )
+        .has_status(Status::Ok)
+        .has_header_regex(
+            "Link",
+            r#"</worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b/attachments/[0-9a-f-]+/thumbnail>; rel="preview""#,
+        )
+        .assert_json_body(|body| {
+            assert_eq!(body["filename"], json!("map.png"));
+            assert_eq!(body["content_type"], json!("image/png"));
+        })
+        .assert_database(|mut tx| {
+            let has_thumbnail: bool = tx
+                .query_one(
+                    "SELECT has_thumbnail FROM attachments WHERE world_id = 'd6c124d5-1060-48ef-aeb8-e591a8261c2b'",
+                    &[],
+                )
+                .map(|row| row.get("has_thumbnail"))
+                .unwrap();
+            assert!(has_thumbnail);
+        });
+}
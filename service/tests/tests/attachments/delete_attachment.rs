@@ -0,0 +1,150 @@
+use crate::{
+    data::{hash_password, SeedAttachment, SeedUser, SeedWorld},
+    tests::run_test,
+};
+use rocket::http::Status;
+use serde_json::json;
+
+#[test]
+fn test_delete_unknown_attachment() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let url = format!(
+        "/worlds/{}/attachments/d6c124d5-1060-48ef-aeb8-e591a8261c2b",
+        world.world_id
+    );
+
+    run_test()
+        .seed_many(&[&user, &world])
+        .authenticate("testuser", "password")
+        .delete(url)
+        .has_status(Status::NotFound)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:attachments/problems/unknown_attachment",
+            "title": "The requested attachment was unknown",
+            "status": 404
+        }));
+}
+
+#[test]
+fn test_delete_other_user() {
+    let world_owner = SeedUser::default();
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let uploader = SeedUser::default();
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let attachment = SeedAttachment {
+        world_id: world.world_id,
+        owner_id: uploader.user_id,
+        ..SeedAttachment::default()
+    };
+    let url = format!(
+        "/worlds/{}/attachments/{}",
+        world.world_id, attachment.attachment_id
+    );
+
+    run_test()
+        .seed_many(&[&world_owner, &user, &uploader, &world, &attachment])
+        .authenticate("testuser", "password")
+        .delete(url)
+        .has_status(Status::Forbidden)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/unauthorized",
+            "title": "An invalid access token was provided",
+            "status": 403
+        }));
+}
+
+#[test]
+fn test_delete_by_uploader() {
+    let world_owner = SeedUser::default();
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let attachment = SeedAttachment {
+        world_id: world.world_id,
+        owner_id: user.user_id,
+        ..SeedAttachment::default()
+    }
+    .with_content(b"Here be dragons");
+    let url = format!(
+        "/worlds/{}/attachments/{}",
+        world.world_id, attachment.attachment_id
+    );
+
+    run_test()
+        .seed_many(&[&world_owner, &user, &world, &attachment])
+        .seed_blob(
+            format!("attachments/{}", attachment.attachment_id),
+            b"Here be dragons",
+        )
+        .authenticate("testuser", "password")
+        .delete(url.clone())
+        .has_status(Status::NoContent)
+        .assert_database(|mut tx| {
+            let count: i64 = tx
+                .query_one("SELECT COUNT(*) FROM attachments", &[])
+                .unwrap()
+                .get(0);
+            assert_eq!(count, 0);
+        })
+        .get(format!("{}/content", url))
+        .has_status(Status::NotFound);
+}
+
+#[test]
+fn test_delete_by_world_owner() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let uploader = SeedUser::default();
+    let world = SeedWorld {
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let attachment = SeedAttachment {
+        world_id: world.world_id,
+        owner_id: uploader.user_id,
+        ..SeedAttachment::default()
+    };
+    let url = format!(
+        "/worlds/{}/attachments/{}",
+        world.world_id, attachment.attachment_id
+    );
+
+    run_test()
+        .seed_many(&[&user, &uploader, &world, &attachment])
+        .authenticate("testuser", "password")
+        .delete(url)
+        .has_status(Status::NoContent)
+        .assert_database(|mut tx| {
+            let count: i64 = tx
+                .query_one("SELECT COUNT(*) FROM attachments", &[])
+                .unwrap()
+                .get(0);
+            assert_eq!(count, 0);
+        });
+}
@@ -0,0 +1,111 @@
+use crate::{
+    data::{SeedAttachment, SeedUser, SeedWorld},
+    tests::run_test,
+};
+use rocket::http::Status;
+use serde_json::json;
+
+#[test]
+fn test_get_unknown_attachment() {
+    let world_owner = SeedUser::default();
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let url = format!(
+        "/worlds/{}/attachments/d6c124d5-1060-48ef-aeb8-e591a8261c2b",
+        world.world_id
+    );
+
+    run_test()
+        .seed_many(&[&world_owner, &world])
+        .get(url)
+        .has_status(Status::NotFound)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:attachments/problems/unknown_attachment",
+            "title": "The requested attachment was unknown",
+            "status": 404
+        }));
+}
+
+#[test]
+fn test_get_attachment_on_other_world() {
+    let world_owner = SeedUser::default();
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let other_world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let attachment = SeedAttachment {
+        world_id: other_world.world_id,
+        owner_id: world_owner.user_id,
+        ..SeedAttachment::default()
+    };
+    let url = format!(
+        "/worlds/{}/attachments/{}",
+        world.world_id, attachment.attachment_id
+    );
+
+    run_test()
+        .seed_many(&[&world_owner, &world, &other_world, &attachment])
+        .get(url)
+        .has_status(Status::NotFound)
+        .has_header("Content-Type", "application/problem+json");
+}
+
+#[test]
+fn test_get_attachment() {
+    let world_owner = SeedUser {
+        display_name: "Test User".to_owned(),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let attachment = SeedAttachment {
+        world_id: world.world_id,
+        owner_id: world_owner.user_id,
+        filename: "map.png".to_owned(),
+        content_type: "image/png".to_owned(),
+        has_thumbnail: true,
+        ..SeedAttachment::default()
+    }
+    .with_content(b"Not really a PNG");
+    let url = format!(
+        "/worlds/{}/attachments/{}",
+        world.world_id, attachment.attachment_id
+    );
+
+    run_test()
+        .seed_many(&[&world_owner, &world, &attachment])
+        .get(url.clone())
+        .has_status(Status::Ok)
+        .has_header("Content-Type", "application/json")
+        .has_header("ETag", format!("\"{}\"", attachment.version))
+        .has_header_regex("Link", format!(r#"<{}>; rel="self""#, url))
+        .has_header_regex(
+            "Link",
+            format!(
+                r#"</users/{}>; rel="author"; title="Test User""#,
+                world_owner.user_id
+            ),
+        )
+        .has_header_regex(
+            "Link",
+            format!(r#"</worlds/{}>; rel="related""#, world.world_id),
+        )
+        .has_header_regex("Link", format!(r#"<{}/content>; rel="enclosure""#, url))
+        .has_header_regex("Link", format!(r#"<{}/thumbnail>; rel="preview""#, url))
+        .has_json_body(json!({
+            "filename": "map.png",
+            "content_type": "image/png",
+            "size": 16,
+            "created": attachment.created,
+            "updated": attachment.updated
+        }));
+}
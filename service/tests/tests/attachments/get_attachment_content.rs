@@ -0,0 +1,127 @@
+use crate::{
+    data::{SeedAttachment, SeedUser, SeedWorld},
+    tests::run_test,
+};
+use rocket::http::Status;
+
+/// The contents of the attachments that are seeded
+const CONTENT: &[u8] = b"Here be dragons";
+
+/// The SHA-256 hash of `CONTENT`
+const HASH: &str = "f902c6b5aeaef13665d4fa3c7053bb17908a4f0f8051bb9dcfecdb17c76a7d38";
+
+/// Build the seed data for an attachment on a world, along with the URL of its contents
+fn seed() -> (SeedUser, SeedWorld, SeedAttachment, String) {
+    let world_owner = SeedUser::default();
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let attachment = SeedAttachment {
+        world_id: world.world_id,
+        owner_id: world_owner.user_id,
+        filename: "dragons.txt".to_owned(),
+        ..SeedAttachment::default()
+    }
+    .with_content(CONTENT);
+    let url = format!(
+        "/worlds/{}/attachments/{}/content",
+        world.world_id, attachment.attachment_id
+    );
+
+    (world_owner, world, attachment, url)
+}
+
+#[test]
+fn test_get_content() {
+    let (world_owner, world, attachment, url) = seed();
+
+    run_test()
+        .seed_many(&[&world_owner, &world, &attachment])
+        .seed_blob(format!("attachments/{}", attachment.attachment_id), CONTENT)
+        .get(url)
+        .has_status(Status::Ok)
+        .has_header("Content-Type", "text/plain; charset=utf-8")
+        .has_header("ETag", format!("\"{}\"", HASH))
+        .has_header("Accept-Ranges", "bytes")
+        .has_header("Content-Disposition", "inline; filename=\"dragons.txt\"")
+        .assert_binary_body(|body| assert_eq!(body, CONTENT));
+}
+
+#[test]
+fn test_get_content_not_modified() {
+    let (world_owner, world, attachment, url) = seed();
+
+    run_test()
+        .seed_many(&[&world_owner, &world, &attachment])
+        .seed_blob(format!("attachments/{}", attachment.attachment_id), CONTENT)
+        .get_with_header(url, "If-None-Match", format!("\"{}\"", HASH))
+        .has_status(Status::NotModified)
+        .has_header("ETag", format!("\"{}\"", HASH))
+        .assert_binary_body(|body| assert!(body.is_empty()));
+}
+
+#[test]
+fn test_get_content_range() {
+    let (world_owner, world, attachment, url) = seed();
+
+    run_test()
+        .seed_many(&[&world_owner, &world, &attachment])
+        .seed_blob(format!("attachments/{}", attachment.attachment_id), CONTENT)
+        .get_with_header(url, "Range", "bytes=8-")
+        .has_status(Status::PartialContent)
+        .has_header("Content-Range", "bytes 8-14/15")
+        .assert_binary_body(|body| assert_eq!(body, b"dragons"));
+}
+
+#[test]
+fn test_get_content_unsatisfiable_range() {
+    let (world_owner, world, attachment, url) = seed();
+
+    run_test()
+        .seed_many(&[&world_owner, &world, &attachment])
+        .seed_blob(format!("attachments/{}", attachment.attachment_id), CONTENT)
+        .get_with_header(url, "Range", "bytes=100-200")
+        .has_status(Status::RangeNotSatisfiable)
+        .has_header("Content-Range", "bytes */15");
+}
+
+#[test]
+fn test_get_missing_thumbnail() {
+    let (world_owner, world, attachment, _) = seed();
+    let url = format!(
+        "/worlds/{}/attachments/{}/thumbnail",
+        world.world_id, attachment.attachment_id
+    );
+
+    run_test()
+        .seed_many(&[&world_owner, &world, &attachment])
+        .get(url)
+        .has_status(Status::NotFound)
+        .has_header("Content-Type", "application/problem+json");
+}
+
+#[test]
+fn test_get_thumbnail() {
+    let (world_owner, world, attachment, _) = seed();
+    let attachment = SeedAttachment {
+        has_thumbnail: true,
+        ..attachment
+    };
+    let url = format!(
+        "/worlds/{}/attachments/{}/thumbnail",
+        world.world_id, attachment.attachment_id
+    );
+
+    run_test()
+        .seed_many(&[&world_owner, &world, &attachment])
+        .seed_blob(
+            format!("attachments/{}-thumbnail", attachment.attachment_id),
+            b"Thumbnail",
+        )
+        .get(url)
+        .has_status(Status::Ok)
+        .has_header("Content-Type", "image/png")
+        .has_header("ETag", format!("\"{}-thumbnail\"", HASH))
+        .assert_binary_body(|body| assert_eq!(body, b"Thumbnail"));
+}
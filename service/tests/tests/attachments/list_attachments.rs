@@ -0,0 +1,93 @@
+use crate::{
+    data::{SeedAttachment, SeedUser, SeedWorld},
+    tests::run_test,
+};
+use chrono::{Duration, Timelike, Utc};
+use rocket::http::Status;
+use serde_json::json;
+
+#[test]
+fn test_list_unknown_world() {
+    run_test()
+        .get("/worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b/attachments")
+        .has_status(Status::NotFound)
+        .has_header("Content-Type", "application/problem+json");
+}
+
+#[test]
+fn test_list_no_attachments() {
+    let world_owner = SeedUser::default();
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let url = format!("/worlds/{}/attachments", world.world_id);
+
+    run_test()
+        .seed_many(&[&world_owner, &world])
+        .get(url)
+        .has_status(Status::Ok)
+        .has_header("Content-Type", "application/json")
+        .has_json_body(json!({
+            "entries": [],
+            "pagination": {
+                "offset": 0,
+                "count": 10,
+                "total": 0
+            }
+        }));
+}
+
+#[test]
+fn test_list_attachments() {
+    let world_owner = SeedUser {
+        display_name: "Test User".to_owned(),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let now = Utc::now().with_nanosecond(0).unwrap();
+    let older = SeedAttachment {
+        world_id: world.world_id,
+        owner_id: world_owner.user_id,
+        filename: "older.txt".to_owned(),
+        created: now - Duration::hours(1),
+        updated: now - Duration::hours(1),
+        ..SeedAttachment::default()
+    };
+    let newer = SeedAttachment {
+        world_id: world.world_id,
+        owner_id: world_owner.user_id,
+        filename: "newer.txt".to_owned(),
+        created: now,
+        updated: now,
+        ..SeedAttachment::default()
+    };
+    let url = format!("/worlds/{}/attachments", world.world_id);
+
+    run_test()
+        .seed_many(&[&world_owner, &world, &older, &newer])
+        .get(url)
+        .has_status(Status::Ok)
+        .has_header_regex(
+            "Link",
+            format!(
+                r#"</worlds/{}/attachments/{}>; rel="item"; anchor="\#/entries/0""#,
+                world.world_id, newer.attachment_id
+            ),
+        )
+        .has_header_regex(
+            "Link",
+            format!(
+                r#"</users/{}>; rel="author"; title="Test User"; anchor="\#/entries/1""#,
+                world_owner.user_id
+            ),
+        )
+        .assert_json_body(|body| {
+            assert_eq!(body["pagination"]["total"], json!(2));
+            assert_eq!(body["entries"][0]["filename"], json!("newer.txt"));
+            assert_eq!(body["entries"][1]["filename"], json!("older.txt"));
+        });
+}
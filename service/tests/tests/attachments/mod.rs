@@ -0,0 +1,5 @@
+mod create_attachment;
+mod delete_attachment;
+mod get_attachment;
+mod get_attachment_content;
+mod list_attachments;
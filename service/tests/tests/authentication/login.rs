@@ -63,6 +63,31 @@ fn test_login_invalid_password() {
         }));
 }
 
+#[test]
+fn test_login_suspended_user() {
+    run_test()
+        .seed(&SeedUser {
+            username: "testuser".to_owned(),
+            password: hash_password("password"),
+            suspended: true,
+            ..SeedUser::default()
+        })
+        .post(
+            "/login",
+            json!({
+                "username": "testuser",
+                "password": "password"
+            }),
+        )
+        .has_status(Status::Unauthorized)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:users/problems/authentication_error",
+            "title": "Invalid Username or Password",
+            "status": 401
+        }));
+}
+
 #[test]
 fn test_login_missing_password() {
     run_test()
@@ -0,0 +1,253 @@
+use crate::{
+    data::{SeedCampaign, SeedCharacter, SeedTable, SeedUser, SeedWorld},
+    tests::run_test,
+};
+use rocket::http::Status;
+use serde_json::json;
+
+#[test]
+fn test_autocomplete_unknown_world() {
+    run_test()
+        .get("/worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b/autocomplete?q=tav")
+        .has_status(Status::NotFound)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:worlds/problems/unknown_world_id",
+            "title": "The requested world ID was unknown",
+            "status": 404
+        }));
+}
+
+#[test]
+fn test_autocomplete_invalid() {
+    let world_owner = SeedUser::default();
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let url = format!(
+        "/worlds/{}/autocomplete?q=%20&types=table,dragon",
+        world.world_id
+    );
+
+    run_test()
+        .seed(&world_owner)
+        .seed(&world)
+        .get(url)
+        .has_status(Status::UnprocessableEntity)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/validation_error",
+            "title": "A validation error occurred",
+            "status": 422,
+            "fields": {
+              "q": {
+                "type": "tag:multiverse,2020:problems/validation_error/missing",
+                "title": "The required field was missing"
+              },
+              "types": {
+                "type": "tag:multiverse,2020:autocomplete/problems/validation_error/unknown_type",
+                "title": "The requested entity type was unknown"
+              }
+            }
+        }));
+}
+
+#[test]
+fn test_autocomplete_no_matches() {
+    let world_owner = SeedUser::default();
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let table = SeedTable {
+        world_id: world.world_id,
+        name: "Tavern Names".to_owned(),
+        ..SeedTable::default()
+    };
+    let url = format!("/worlds/{}/autocomplete?q=dragon", world.world_id);
+
+    run_test()
+        .seed(&world_owner)
+        .seed_many(&[&world, &table])
+        .get(url)
+        .has_status(Status::Ok)
+        .has_header("Content-Type", "application/json")
+        .has_json_body(json!({
+            "entries": []
+        }));
+}
+
+#[test]
+fn test_autocomplete_ranked() {
+    let world_owner = SeedUser::default();
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let other_world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let prefix_table = SeedTable {
+        world_id: world.world_id,
+        name: "Tavern Names".to_owned(),
+        url_slug: "tavern-names".to_owned(),
+        ..SeedTable::default()
+    };
+    let similar_campaign = SeedCampaign {
+        world_id: world.world_id,
+        name: "The Taverns of Waterdeep".to_owned(),
+        ..SeedCampaign::default()
+    };
+    let unrelated_table = SeedTable {
+        world_id: world.world_id,
+        name: "Dragon Hoards".to_owned(),
+        url_slug: "dragon-hoards".to_owned(),
+        ..SeedTable::default()
+    };
+    let other_world_table = SeedTable {
+        world_id: other_world.world_id,
+        name: "Tavern Keepers".to_owned(),
+        url_slug: "tavern-keepers".to_owned(),
+        ..SeedTable::default()
+    };
+    let url = format!("/worlds/{}/autocomplete?q=tavern", world.world_id);
+
+    run_test()
+        .seed(&world_owner)
+        .seed_many(&[
+            &world,
+            &other_world,
+            &prefix_table,
+            &similar_campaign,
+            &unrelated_table,
+            &other_world_table,
+        ])
+        .get(url)
+        .has_status(Status::Ok)
+        .has_header("Content-Type", "application/json")
+        .has_header_regex(
+            "Link",
+            format!(
+                r#"</worlds/{}/tables/tavern-names>; rel="item"; title="Tavern Names"; anchor="\#/entries/0""#,
+                world.world_id
+            ),
+        )
+        .has_header_regex(
+            "Link",
+            format!(
+                r#"</campaigns/{}>; rel="item"; title="The Taverns of Waterdeep"; anchor="\#/entries/1""#,
+                similar_campaign.campaign_id
+            ),
+        )
+        .has_json_body(json!({
+            "entries": [
+              { "type": "table", "name": "Tavern Names" },
+              { "type": "campaign", "name": "The Taverns of Waterdeep" }
+            ]
+        }));
+}
+
+#[test]
+fn test_autocomplete_filter_types() {
+    let world_owner = SeedUser::default();
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let table = SeedTable {
+        world_id: world.world_id,
+        name: "Tavern Names".to_owned(),
+        ..SeedTable::default()
+    };
+    let campaign = SeedCampaign {
+        world_id: world.world_id,
+        name: "Tavern Brawls".to_owned(),
+        ..SeedCampaign::default()
+    };
+    let url = format!(
+        "/worlds/{}/autocomplete?q=tav&types=campaign",
+        world.world_id
+    );
+
+    run_test()
+        .seed(&world_owner)
+        .seed_many(&[&world, &table, &campaign])
+        .get(url)
+        .has_status(Status::Ok)
+        .has_json_body(json!({
+            "entries": [
+              { "type": "campaign", "name": "Tavern Brawls" }
+            ]
+        }));
+}
+
+#[test]
+fn test_autocomplete_characters() {
+    let world_owner = SeedUser::default();
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let character = SeedCharacter {
+        world_id: world.world_id,
+        name: "Tavern Keeper".to_owned(),
+        ..SeedCharacter::default()
+    };
+    let url = format!(
+        "/worlds/{}/autocomplete?q=tav&types=character",
+        world.world_id
+    );
+
+    run_test()
+        .seed(&world_owner)
+        .seed_many(&[&world, &character])
+        .get(url)
+        .has_status(Status::Ok)
+        .has_header_regex(
+            "Link",
+            format!(
+                r#"</characters/{}>; rel="item"; title="Tavern Keeper"; anchor="\#/entries/0""#,
+                character.character_id
+            ),
+        )
+        .has_json_body(json!({
+            "entries": [
+              { "type": "character", "name": "Tavern Keeper" }
+            ]
+        }));
+}
+
+#[test]
+fn test_autocomplete_count() {
+    let world_owner = SeedUser::default();
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let first = SeedTable {
+        world_id: world.world_id,
+        name: "Tavern Names".to_owned(),
+        url_slug: "tavern-names".to_owned(),
+        ..SeedTable::default()
+    };
+    let second = SeedTable {
+        world_id: world.world_id,
+        name: "Tavern Owners".to_owned(),
+        url_slug: "tavern-owners".to_owned(),
+        ..SeedTable::default()
+    };
+    let url = format!("/worlds/{}/autocomplete?q=tavern&count=1", world.world_id);
+
+    run_test()
+        .seed(&world_owner)
+        .seed_many(&[&world, &first, &second])
+        .get(url)
+        .has_status(Status::Ok)
+        .has_json_body(json!({
+            "entries": [
+              { "type": "table", "name": "Tavern Names" }
+            ]
+        }));
+}
@@ -0,0 +1,128 @@
+use crate::{
+    data::{hash_password, SeedUser, SeedWorld},
+    tests::run_test,
+};
+use rocket::http::Status;
+use serde_json::json;
+
+#[test]
+fn test_create_unauthenticated() {
+    let world_owner = SeedUser::default();
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let url = format!("/worlds/{}/campaigns", world.world_id);
+
+    run_test()
+        .seed(&world_owner)
+        .seed(&world)
+        .post(url, json!({}))
+        .has_status(Status::Forbidden)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/unauthorized",
+            "title": "An invalid access token was provided",
+            "status": 403
+        }));
+}
+
+#[test]
+fn test_create_invalid() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let url = format!("/worlds/{}/campaigns", world.world_id);
+
+    run_test()
+        .seed(&user)
+        .seed(&world)
+        .authenticate("testuser", "password")
+        .post(
+            url,
+            json!({
+              "players": [
+                "not-a-user",
+                "/users/d6c124d5-1060-48ef-aeb8-e591a8261c2b"
+              ]
+            }),
+        )
+        .has_status(Status::UnprocessableEntity)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/validation_error",
+            "title": "A validation error occurred",
+            "status": 422,
+            "fields": {
+              "name": {
+                "type": "tag:multiverse,2020:problems/validation_error/missing",
+                "title": "The required field was missing"
+              },
+              "players/0": {
+                "type": "tag:multiverse,2020:campaigns/problems/validation_error/unknown_user",
+                "title": "The user was unknown"
+              },
+              "players/1": {
+                "type": "tag:multiverse,2020:campaigns/problems/validation_error/unknown_user",
+                "title": "The user was unknown"
+              }
+            }
+        }));
+}
+
+#[test]
+fn test_create_success() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let player = SeedUser {
+        user_id: uuid::Uuid::parse_str("7da4cb77-8839-4805-b93a-f4c536c8bc85").unwrap(),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        world_id: uuid::Uuid::parse_str("d6c124d5-1060-48ef-aeb8-e591a8261c2b").unwrap(),
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+
+    run_test()
+        .seed(&user)
+        .seed(&player)
+        .seed(&world)
+        .authenticate("testuser", "password")
+        .post(
+            "/worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b/campaigns",
+            json!({
+              "name": "Curse of the Crimson Throne",
+              "description": "Our Tuesday night game",
+              "players": [
+                "7da4cb77-8839-4805-b93a-f4c536c8bc85",
+                "/users/7da4cb77-8839-4805-b93a-f4c536c8bc85"
+              ]
+            }),
+        )
+        .has_status(Status::Ok)
+        .has_header("Content-Type", "application/json")
+        .has_header_regex(
+            "Link",
+            r#"</campaigns/[0-9a-f]{8}\b-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-\b[0-9a-f]{12}>; rel="self""#,
+        )
+        .has_header_regex(
+            "Link",
+            r#"</worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b>; rel="related""#,
+        )
+        .has_json_body(json!({
+          "name": "Curse of the Crimson Throne",
+          "description": "Our Tuesday night game",
+          "status": "published",
+          "players": ["/users/7da4cb77-8839-4805-b93a-f4c536c8bc85"]
+        }));
+}
@@ -0,0 +1,140 @@
+use crate::{
+    data::{hash_password, SeedCampaign, SeedUser, SeedWorld},
+    tests::run_test,
+};
+use rocket::http::Status;
+use serde_json::json;
+
+#[test]
+fn test_create_unknown_campaign() {
+    run_test()
+        .post(
+            "/campaigns/5a5e3cbd-5a8e-4f0f-a8b4-7d1a61e0a6a4/sessions",
+            json!({}),
+        )
+        .has_status(Status::NotFound)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:campaigns/problems/unknown_campaign",
+            "title": "The requested campaign was unknown",
+            "status": 404
+        }));
+}
+
+#[test]
+fn test_create_as_player() {
+    let owner = SeedUser::default();
+    let player = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: owner.user_id,
+        ..SeedWorld::default()
+    };
+    let campaign = SeedCampaign {
+        world_id: world.world_id,
+        players: vec![player.user_id],
+        ..SeedCampaign::default()
+    };
+    let url = format!("/campaigns/{}/sessions", campaign.campaign_id);
+
+    run_test()
+        .seed_many(&[&owner, &player, &world, &campaign])
+        .authenticate("testuser", "password")
+        .post(url, json!({ "date": "2020-07-01" }))
+        .has_status(Status::Forbidden)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/unauthorized",
+            "title": "An invalid access token was provided",
+            "status": 403
+        }));
+}
+
+#[test]
+fn test_create_invalid() {
+    let owner = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: owner.user_id,
+        ..SeedWorld::default()
+    };
+    let campaign = SeedCampaign {
+        world_id: world.world_id,
+        ..SeedCampaign::default()
+    };
+    let url = format!("/campaigns/{}/sessions", campaign.campaign_id);
+
+    run_test()
+        .seed_many(&[&owner, &world, &campaign])
+        .authenticate("testuser", "password")
+        .post(url, json!({ "date": "1st July", "entities": [" "] }))
+        .has_status(Status::UnprocessableEntity)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/validation_error",
+            "title": "A validation error occurred",
+            "status": 422,
+            "fields": {
+              "date": {
+                "type": "tag:multiverse,2020:campaigns/problems/validation_error/invalid_date",
+                "title": "The date must be in the format YYYY-MM-DD"
+              },
+              "entities/0": {
+                "type": "tag:multiverse,2020:problems/validation_error/missing",
+                "title": "The required field was missing"
+              }
+            }
+        }));
+}
+
+#[test]
+fn test_create_success() {
+    let owner = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: owner.user_id,
+        ..SeedWorld::default()
+    };
+    let campaign = SeedCampaign {
+        campaign_id: uuid::Uuid::parse_str("5a5e3cbd-5a8e-4f0f-a8b4-7d1a61e0a6a4").unwrap(),
+        world_id: world.world_id,
+        ..SeedCampaign::default()
+    };
+
+    run_test()
+        .seed_many(&[&owner, &world, &campaign])
+        .authenticate("testuser", "password")
+        .post(
+            "/campaigns/5a5e3cbd-5a8e-4f0f-a8b4-7d1a61e0a6a4/sessions",
+            json!({
+              "date": "2020-07-01",
+              "summary": "The party arrived in *Korvosa*.",
+              "entities": ["/characters/queen-ileosa"]
+            }),
+        )
+        .has_status(Status::Ok)
+        .has_header("Content-Type", "application/json")
+        .has_header_regex(
+            "Link",
+            r#"</sessions/[0-9a-f]{8}\b-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-\b[0-9a-f]{12}>; rel="self""#,
+        )
+        .has_header_regex(
+            "Link",
+            r#"</campaigns/5a5e3cbd-5a8e-4f0f-a8b4-7d1a61e0a6a4>; rel="related""#,
+        )
+        .has_json_body(json!({
+          "date": "2020-07-01",
+          "summary": "The party arrived in *Korvosa*.",
+          "entities": ["/characters/queen-ileosa"],
+          "notes": []
+        }));
+}
@@ -0,0 +1,109 @@
+use crate::{
+    data::{SeedCampaign, SeedUser, SeedWorld},
+    tests::run_test,
+};
+use rocket::http::Status;
+use serde_json::json;
+
+#[test]
+fn test_get_unknown_campaign() {
+    run_test()
+        .get("/campaigns/d6c124d5-1060-48ef-aeb8-e591a8261c2b")
+        .has_status(Status::NotFound)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:campaigns/problems/unknown_campaign",
+            "title": "The requested campaign was unknown",
+            "status": 404
+        }));
+}
+
+#[test]
+fn test_get_known_campaign() {
+    let world_owner = SeedUser::default();
+    let player = SeedUser {
+        user_id: uuid::Uuid::parse_str("7da4cb77-8839-4805-b93a-f4c536c8bc85").unwrap(),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        world_id: uuid::Uuid::parse_str("d6c124d5-1060-48ef-aeb8-e591a8261c2b").unwrap(),
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let campaign = SeedCampaign {
+        campaign_id: uuid::Uuid::parse_str("5a5e3cbd-5a8e-4f0f-a8b4-7d1a61e0a6a4").unwrap(),
+        world_id: world.world_id,
+        name: "Curse of the Crimson Throne".to_owned(),
+        description: "Our Tuesday night game".to_owned(),
+        players: vec![player.user_id],
+        ..SeedCampaign::default()
+    };
+
+    run_test()
+        .seed(&world_owner)
+        .seed_many(&[&player, &world, &campaign])
+        .get("/campaigns/5a5e3cbd-5a8e-4f0f-a8b4-7d1a61e0a6a4")
+        .has_status(Status::Ok)
+        .has_header("Content-Type", "application/json")
+        .has_header("ETag", format!("\"{}\"", campaign.version))
+        .has_header_regex(
+            "Link",
+            r#"</campaigns/5a5e3cbd-5a8e-4f0f-a8b4-7d1a61e0a6a4>; rel="self""#,
+        )
+        .has_header_regex(
+            "Link",
+            r#"</worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b>; rel="related""#,
+        )
+        .has_json_body(json!({
+          "name": "Curse of the Crimson Throne",
+          "description": "Our Tuesday night game",
+          "status": "published",
+          "players": ["/users/7da4cb77-8839-4805-b93a-f4c536c8bc85"]
+        }));
+}
+
+#[test]
+fn test_get_draft_campaign_anonymous() {
+    let world_owner = SeedUser::default();
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let campaign = SeedCampaign {
+        campaign_id: uuid::Uuid::parse_str("5a5e3cbd-5a8e-4f0f-a8b4-7d1a61e0a6a4").unwrap(),
+        world_id: world.world_id,
+        status: "draft".to_owned(),
+        ..SeedCampaign::default()
+    };
+
+    run_test()
+        .seed_many(&[&world_owner, &world, &campaign])
+        .get("/campaigns/5a5e3cbd-5a8e-4f0f-a8b4-7d1a61e0a6a4")
+        .has_status(Status::NotFound)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:campaigns/problems/unknown_campaign",
+            "title": "The requested campaign was unknown",
+            "status": 404
+        }));
+}
+
+#[test]
+fn test_get_campaign_in_draft_world() {
+    let world_owner = SeedUser::default();
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        status: "draft".to_owned(),
+        ..SeedWorld::default()
+    };
+    let campaign = SeedCampaign {
+        campaign_id: uuid::Uuid::parse_str("5a5e3cbd-5a8e-4f0f-a8b4-7d1a61e0a6a4").unwrap(),
+        world_id: world.world_id,
+        ..SeedCampaign::default()
+    };
+
+    run_test()
+        .seed_many(&[&world_owner, &world, &campaign])
+        .get("/campaigns/5a5e3cbd-5a8e-4f0f-a8b4-7d1a61e0a6a4")
+        .has_status(Status::NotFound);
+}
@@ -0,0 +1,64 @@
+use crate::{
+    data::{SeedCampaign, SeedSession, SeedUser, SeedWorld},
+    tests::run_test,
+};
+use chrono::NaiveDate;
+use rocket::http::Status;
+use serde_json::json;
+
+#[test]
+fn test_get_unknown_session() {
+    run_test()
+        .get("/sessions/0f5cd0f1-6c71-4d6a-8a3a-2b9b3c6a5e1d")
+        .has_status(Status::NotFound)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:campaigns/problems/unknown_session",
+            "title": "The requested session was unknown",
+            "status": 404
+        }));
+}
+
+#[test]
+fn test_get_known_session() {
+    let world_owner = SeedUser::default();
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let campaign = SeedCampaign {
+        campaign_id: uuid::Uuid::parse_str("5a5e3cbd-5a8e-4f0f-a8b4-7d1a61e0a6a4").unwrap(),
+        world_id: world.world_id,
+        ..SeedCampaign::default()
+    };
+    let session = SeedSession {
+        session_id: uuid::Uuid::parse_str("0f5cd0f1-6c71-4d6a-8a3a-2b9b3c6a5e1d").unwrap(),
+        campaign_id: campaign.campaign_id,
+        session_date: NaiveDate::from_ymd(2020, 7, 1),
+        summary: "The party arrived in *Korvosa*.".to_owned(),
+        entities: vec!["/characters/queen-ileosa".to_owned()],
+        ..SeedSession::default()
+    };
+
+    run_test()
+        .seed(&world_owner)
+        .seed_many(&[&world, &campaign, &session])
+        .get("/sessions/0f5cd0f1-6c71-4d6a-8a3a-2b9b3c6a5e1d")
+        .has_status(Status::Ok)
+        .has_header("Content-Type", "application/json")
+        .has_header("ETag", format!("\"{}\"", session.version))
+        .has_header_regex(
+            "Link",
+            r#"</sessions/0f5cd0f1-6c71-4d6a-8a3a-2b9b3c6a5e1d>; rel="self""#,
+        )
+        .has_header_regex(
+            "Link",
+            r#"</campaigns/5a5e3cbd-5a8e-4f0f-a8b4-7d1a61e0a6a4>; rel="related""#,
+        )
+        .has_json_body(json!({
+          "date": "2020-07-01",
+          "summary": "The party arrived in *Korvosa*.",
+          "entities": ["/characters/queen-ileosa"],
+          "notes": []
+        }));
+}
@@ -0,0 +1,7 @@
+mod create_campaign;
+mod create_session;
+mod get_campaign;
+mod get_session;
+mod save_session_notes;
+mod search_sessions;
+mod update_campaign_status;
@@ -0,0 +1,135 @@
+use crate::{
+    data::{hash_password, SeedCampaign, SeedSession, SeedUser, SeedWorld},
+    tests::run_test,
+};
+use rocket::http::Status;
+use serde_json::json;
+
+#[test]
+fn test_save_not_a_player() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let campaign = SeedCampaign {
+        world_id: world.world_id,
+        ..SeedCampaign::default()
+    };
+    let session = SeedSession {
+        campaign_id: campaign.campaign_id,
+        ..SeedSession::default()
+    };
+    let url = format!("/sessions/{}/notes", session.session_id);
+
+    run_test()
+        .seed_many(&[&user, &world, &campaign, &session])
+        .authenticate("testuser", "password")
+        .put(url, json!({ "notes": "We won!" }))
+        .has_status(Status::Forbidden)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/unauthorized",
+            "title": "An invalid access token was provided",
+            "status": 403
+        }));
+}
+
+#[test]
+fn test_save_missing_notes() {
+    let world_owner = SeedUser::default();
+    let player = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let campaign = SeedCampaign {
+        world_id: world.world_id,
+        players: vec![player.user_id],
+        ..SeedCampaign::default()
+    };
+    let session = SeedSession {
+        campaign_id: campaign.campaign_id,
+        ..SeedSession::default()
+    };
+    let url = format!("/sessions/{}/notes", session.session_id);
+
+    run_test()
+        .seed(&world_owner)
+        .seed_many(&[&player, &world, &campaign, &session])
+        .authenticate("testuser", "password")
+        .put(url, json!({ "notes": "  " }))
+        .has_status(Status::UnprocessableEntity)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/validation_error",
+            "title": "A validation error occurred",
+            "status": 422,
+            "fields": {
+              "notes": {
+                "type": "tag:multiverse,2020:problems/validation_error/missing",
+                "title": "The required field was missing"
+              }
+            }
+        }));
+}
+
+#[test]
+fn test_save_success() {
+    let world_owner = SeedUser::default();
+    let player = SeedUser {
+        user_id: uuid::Uuid::parse_str("7da4cb77-8839-4805-b93a-f4c536c8bc85").unwrap(),
+        display_name: "Test User".to_owned(),
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let campaign = SeedCampaign {
+        world_id: world.world_id,
+        players: vec![player.user_id],
+        ..SeedCampaign::default()
+    };
+    let session = SeedSession {
+        campaign_id: campaign.campaign_id,
+        ..SeedSession::default()
+    };
+    let url = format!("/sessions/{}/notes", session.session_id);
+
+    run_test()
+        .seed(&world_owner)
+        .seed_many(&[&player, &world, &campaign, &session])
+        .authenticate("testuser", "password")
+        .put(url.clone(), json!({ "notes": "First draft" }))
+        .has_status(Status::Ok)
+        .put(url, json!({ "notes": "We *finally* caught Gaedren" }))
+        .has_status(Status::Ok)
+        .has_header("Content-Type", "application/json")
+        .has_header_regex(
+            "Link",
+            r#"</users/7da4cb77-8839-4805-b93a-f4c536c8bc85>; rel="author"; title="Test User"; anchor="\#/notes/0""#,
+        )
+        .assert_json_body(|body| {
+            let notes = body.pointer("/notes").and_then(|v| v.as_array()).unwrap();
+            assert_eq!(notes.len(), 1);
+            assert_eq!(
+                notes[0].pointer("/author"),
+                Some(&json!("/users/7da4cb77-8839-4805-b93a-f4c536c8bc85"))
+            );
+            assert_eq!(
+                notes[0].pointer("/notes"),
+                Some(&json!("We *finally* caught Gaedren"))
+            );
+        });
+}
@@ -0,0 +1,145 @@
+use crate::{
+    data::{SeedCampaign, SeedSession, SeedUser, SeedWorld},
+    tests::run_test,
+};
+use chrono::NaiveDate;
+use rocket::http::Status;
+use serde_json::json;
+
+#[test]
+fn test_search_no_sessions() {
+    run_test()
+        .get("/sessions")
+        .has_status(Status::Ok)
+        .has_header("Content-Type", "application/json")
+        .has_json_body(json!({
+          "entries": [],
+          "pagination": {
+            "offset": 0,
+            "count": 10,
+            "total": 0
+          }
+        }));
+}
+
+#[test]
+fn test_search_by_entity() {
+    let world_owner = SeedUser::default();
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let campaign = SeedCampaign {
+        world_id: world.world_id,
+        ..SeedCampaign::default()
+    };
+    let first = SeedSession {
+        session_id: uuid::Uuid::parse_str("00000000-0000-0000-0004-000000000001").unwrap(),
+        campaign_id: campaign.campaign_id,
+        session_date: NaiveDate::from_ymd(2020, 7, 1),
+        summary: "First".to_owned(),
+        entities: vec!["/characters/queen-ileosa".to_owned()],
+        ..SeedSession::default()
+    };
+    let second = SeedSession {
+        campaign_id: campaign.campaign_id,
+        session_date: NaiveDate::from_ymd(2020, 7, 8),
+        summary: "Second".to_owned(),
+        entities: vec!["/characters/gaedren-lamm".to_owned()],
+        ..SeedSession::default()
+    };
+    let third = SeedSession {
+        session_id: uuid::Uuid::parse_str("00000000-0000-0000-0004-000000000003").unwrap(),
+        campaign_id: campaign.campaign_id,
+        session_date: NaiveDate::from_ymd(2020, 7, 15),
+        summary: "Third".to_owned(),
+        entities: vec![
+            "/characters/gaedren-lamm".to_owned(),
+            "/characters/queen-ileosa".to_owned(),
+        ],
+        ..SeedSession::default()
+    };
+
+    run_test()
+        .seed(&world_owner)
+        .seed_many(&[&world, &campaign, &first, &second, &third])
+        .get("/sessions?entity=/characters/queen-ileosa")
+        .has_status(Status::Ok)
+        .has_header("Content-Type", "application/json")
+        .has_header_regex(
+            "Link",
+            r#"</sessions/00000000-0000-0000-0004-000000000003>; rel="item"; anchor="\#/entries/0""#,
+        )
+        .has_header_regex(
+            "Link",
+            r#"</sessions/00000000-0000-0000-0004-000000000001>; rel="item"; anchor="\#/entries/1""#,
+        )
+        .has_json_body(json!({
+          "entries": [
+            {
+              "date": "2020-07-15",
+              "summary": "Third",
+              "entities": ["/characters/gaedren-lamm", "/characters/queen-ileosa"]
+            },
+            {
+              "date": "2020-07-01",
+              "summary": "First",
+              "entities": ["/characters/queen-ileosa"]
+            }
+          ],
+          "pagination": {
+            "offset": 0,
+            "count": 10,
+            "total": 2
+          }
+        }));
+}
+
+#[test]
+fn test_search_by_campaign() {
+    let world_owner = SeedUser::default();
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let campaign = SeedCampaign {
+        world_id: world.world_id,
+        ..SeedCampaign::default()
+    };
+    let other_campaign = SeedCampaign {
+        world_id: world.world_id,
+        ..SeedCampaign::default()
+    };
+    let session = SeedSession {
+        campaign_id: campaign.campaign_id,
+        session_date: NaiveDate::from_ymd(2020, 7, 1),
+        summary: "Ours".to_owned(),
+        ..SeedSession::default()
+    };
+    let other_session = SeedSession {
+        campaign_id: other_campaign.campaign_id,
+        ..SeedSession::default()
+    };
+    let url = format!("/sessions?campaign=/campaigns/{}", campaign.campaign_id);
+
+    run_test()
+        .seed(&world_owner)
+        .seed_many(&[&world, &campaign, &other_campaign, &session, &other_session])
+        .get(url)
+        .has_status(Status::Ok)
+        .has_header("Content-Type", "application/json")
+        .has_json_body(json!({
+          "entries": [
+            {
+              "date": "2020-07-01",
+              "summary": "Ours",
+              "entities": []
+            }
+          ],
+          "pagination": {
+            "offset": 0,
+            "count": 10,
+            "total": 1
+          }
+        }));
+}
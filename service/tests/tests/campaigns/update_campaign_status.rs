@@ -0,0 +1,93 @@
+use crate::{
+    data::{hash_password, SeedCampaign, SeedUser, SeedWorld},
+    tests::run_test,
+};
+use rocket::http::Status;
+use serde_json::json;
+
+#[test]
+fn test_update_status_unknown_campaign() {
+    run_test()
+        .put(
+            "/campaigns/5a5e3cbd-5a8e-4f0f-a8b4-7d1a61e0a6a4/status",
+            json!({ "status": "draft" }),
+        )
+        .has_status(Status::NotFound)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:campaigns/problems/unknown_campaign",
+            "title": "The requested campaign was unknown",
+            "status": 404
+        }));
+}
+
+#[test]
+fn test_update_status_not_owner() {
+    let world_owner = SeedUser::default();
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let campaign = SeedCampaign {
+        campaign_id: uuid::Uuid::parse_str("5a5e3cbd-5a8e-4f0f-a8b4-7d1a61e0a6a4").unwrap(),
+        world_id: world.world_id,
+        ..SeedCampaign::default()
+    };
+
+    run_test()
+        .seed_many(&[&world_owner, &user, &world, &campaign])
+        .authenticate("testuser", "password")
+        .put(
+            "/campaigns/5a5e3cbd-5a8e-4f0f-a8b4-7d1a61e0a6a4/status",
+            json!({ "status": "draft" }),
+        )
+        .has_status(Status::Forbidden);
+}
+
+#[test]
+fn test_update_status_success() {
+    let world_owner = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let campaign = SeedCampaign {
+        campaign_id: uuid::Uuid::parse_str("5a5e3cbd-5a8e-4f0f-a8b4-7d1a61e0a6a4").unwrap(),
+        world_id: world.world_id,
+        name: "Curse of the Crimson Throne".to_owned(),
+        description: "Our Tuesday night game".to_owned(),
+        ..SeedCampaign::default()
+    };
+
+    run_test()
+        .seed_many(&[&world_owner, &world, &campaign])
+        .authenticate("testuser", "password")
+        .put(
+            "/campaigns/5a5e3cbd-5a8e-4f0f-a8b4-7d1a61e0a6a4/status",
+            json!({ "status": "draft" }),
+        )
+        .has_status(Status::Ok)
+        .has_header("Content-Type", "application/json")
+        .has_json_body(json!({
+          "name": "Curse of the Crimson Throne",
+          "description": "Our Tuesday night game",
+          "status": "draft",
+          "players": []
+        }))
+        .assert_database(|mut tx| {
+            let status: String = tx
+                .query_one("SELECT status FROM campaigns", &[])
+                .unwrap()
+                .get(0);
+            assert_eq!(status, "draft");
+        });
+}
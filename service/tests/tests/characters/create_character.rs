@@ -0,0 +1,222 @@
+use crate::{
+    data::{hash_password, SeedCharacter, SeedUser, SeedUserQuota, SeedWorld, SeedWorldMember},
+    tests::run_test,
+};
+use rocket::http::Status;
+use serde_json::json;
+
+#[test]
+fn test_create_unauthenticated() {
+    let world_owner = SeedUser::default();
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let url = format!("/worlds/{}/characters", world.world_id);
+
+    run_test()
+        .seed_many(&[&world_owner, &world])
+        .post(url, json!({ "name": "Valeros" }))
+        .has_status(Status::Forbidden)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/unauthorized",
+            "title": "An invalid access token was provided",
+            "status": 403
+        }));
+}
+
+#[test]
+fn test_create_as_viewer() {
+    let world_owner = SeedUser::default();
+    let viewer = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let member = SeedWorldMember {
+        world_id: world.world_id,
+        user_id: viewer.user_id,
+        role: "viewer".to_owned(),
+        ..SeedWorldMember::default()
+    };
+    let url = format!("/worlds/{}/characters", world.world_id);
+
+    run_test()
+        .seed_many(&[&world_owner, &viewer, &world, &member])
+        .authenticate("testuser", "password")
+        .post(url, json!({ "name": "Valeros" }))
+        .has_status(Status::Forbidden);
+}
+
+#[test]
+fn test_create_invalid_request() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let url = format!("/worlds/{}/characters", world.world_id);
+
+    run_test()
+        .seed_many(&[&user, &world])
+        .authenticate("testuser", "password")
+        .post(url, json!({ "controlled_by": "not-a-user" }))
+        .has_status(Status::UnprocessableEntity)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/validation_error",
+            "title": "A validation error occurred",
+            "status": 422,
+            "fields": {
+              "name": {
+                "type": "tag:multiverse,2020:problems/validation_error/missing",
+                "title": "The required field was missing"
+              },
+              "controlled_by": {
+                "type": "tag:multiverse,2020:characters/problems/validation_error/unknown_user",
+                "title": "The user was unknown"
+              }
+            }
+        }));
+}
+
+#[test]
+fn test_create_unknown_controller() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let url = format!("/worlds/{}/characters", world.world_id);
+
+    run_test()
+        .seed_many(&[&user, &world])
+        .authenticate("testuser", "password")
+        .post(
+            url,
+            json!({
+              "name": "Valeros",
+              "controlled_by": "/users/7da4cb77-8839-4805-b93a-f4c536c8bc85"
+            }),
+        )
+        .has_status(Status::UnprocessableEntity)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/validation_error",
+            "title": "A validation error occurred",
+            "status": 422,
+            "fields": {
+              "controlled_by": {
+                "type": "tag:multiverse,2020:characters/problems/validation_error/unknown_user",
+                "title": "The user was unknown"
+              }
+            }
+        }));
+}
+
+#[test]
+fn test_create_as_editor() {
+    let world_owner = SeedUser::default();
+    let editor = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let player = SeedUser {
+        user_id: uuid::Uuid::parse_str("7da4cb77-8839-4805-b93a-f4c536c8bc85").unwrap(),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        world_id: uuid::Uuid::parse_str("d6c124d5-1060-48ef-aeb8-e591a8261c2b").unwrap(),
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let member = SeedWorldMember {
+        world_id: world.world_id,
+        user_id: editor.user_id,
+        role: "editor".to_owned(),
+        ..SeedWorldMember::default()
+    };
+
+    run_test()
+        .seed_many(&[&world_owner, &editor, &player, &world, &member])
+        .authenticate("testuser", "password")
+        .post(
+            "/worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b/characters",
+            json!({
+              "name": "Valeros",
+              "description": "A human fighter",
+              "controlled_by": "7da4cb77-8839-4805-b93a-f4c536c8bc85"
+            }),
+        )
+        .has_status(Status::Ok)
+        .has_header("Content-Type", "application/json")
+        .has_header_regex("Link", r#"</characters/[0-9a-f-]+>; rel="self""#)
+        .has_header_regex(
+            "Link",
+            r#"</worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b>; rel="related""#,
+        )
+        .has_json_body(json!({
+          "name": "Valeros",
+          "description": "A human fighter",
+          "controlled_by": "/users/7da4cb77-8839-4805-b93a-f4c536c8bc85",
+          "status": "published"
+        }))
+        .assert_database(|mut tx| {
+            let rows = tx.query("SELECT event, target FROM activity", &[]).unwrap();
+            assert_eq!(rows.len(), 1);
+            assert_eq!(rows[0].get::<_, String>(0), "character_created");
+            assert!(rows[0].get::<_, String>(1).starts_with("/characters/"));
+        });
+}
+
+#[test]
+fn test_create_quota_exceeded() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let character = SeedCharacter {
+        world_id: world.world_id,
+        ..SeedCharacter::default()
+    };
+    let quota = SeedUserQuota {
+        user_id: user.user_id,
+        resource: "characters".to_owned(),
+        quota: 1,
+    };
+    let url = format!("/worlds/{}/characters", world.world_id);
+
+    run_test()
+        .seed_many(&[&user, &world, &character, &quota])
+        .authenticate("testuser", "password")
+        .post(url, json!({ "name": "Valeros" }))
+        .has_status(Status::Forbidden)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/quota_exceeded",
+            "title": "The quota has been exceeded",
+            "status": 403,
+            "resource": "characters",
+            "used": 1,
+            "limit": 1
+        }));
+}
@@ -0,0 +1,57 @@
+use crate::{
+    data::{hash_password, SeedCharacter, SeedUser, SeedWorld},
+    tests::run_test,
+};
+use rocket::http::Status;
+
+#[test]
+fn test_delete_as_controller() {
+    let world_owner = SeedUser::default();
+    let player = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let character = SeedCharacter {
+        world_id: world.world_id,
+        controlled_by: Some(player.user_id),
+        ..SeedCharacter::default()
+    };
+    let url = format!("/characters/{}", character.character_id);
+
+    run_test()
+        .seed_many(&[&world_owner, &player, &world, &character])
+        .authenticate("testuser", "password")
+        .delete(url)
+        .has_status(Status::Forbidden);
+}
+
+#[test]
+fn test_delete_as_owner() {
+    let world_owner = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let character = SeedCharacter {
+        world_id: world.world_id,
+        ..SeedCharacter::default()
+    };
+    let url = format!("/characters/{}", character.character_id);
+
+    run_test()
+        .seed_many(&[&world_owner, &world, &character])
+        .authenticate("testuser", "password")
+        .delete(url.clone())
+        .has_status(Status::NoContent)
+        .get(url)
+        .has_status(Status::NotFound);
+}
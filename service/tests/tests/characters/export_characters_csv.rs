@@ -0,0 +1,110 @@
+use crate::{
+    data::{SeedCharacter, SeedUser, SeedWorld},
+    tests::run_test,
+};
+use rocket::http::Status;
+use serde_json::json;
+
+#[test]
+fn test_export_unknown_world() {
+    run_test()
+        .get("/worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b/characters.csv")
+        .has_status(Status::NotFound)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:worlds/problems/unknown_world_id",
+            "title": "The requested world ID was unknown",
+            "status": 404
+        }));
+}
+
+#[test]
+fn test_export_characters() {
+    let world_owner = SeedUser::default();
+    let player = SeedUser {
+        user_id: uuid::Uuid::parse_str("7da4cb77-8839-4805-b93a-f4c536c8bc85").unwrap(),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        url_slug: "golarion".to_owned(),
+        ..SeedWorld::default()
+    };
+    let amiri = SeedCharacter {
+        world_id: world.world_id,
+        name: "Amiri".to_owned(),
+        description: "A barbarian".to_owned(),
+        controlled_by: Some(player.user_id),
+        ..SeedCharacter::default()
+    };
+    let valeros = SeedCharacter {
+        world_id: world.world_id,
+        name: "Valeros".to_owned(),
+        description: "A fighter".to_owned(),
+        ..SeedCharacter::default()
+    };
+    let draft = SeedCharacter {
+        world_id: world.world_id,
+        status: "draft".to_owned(),
+        ..SeedCharacter::default()
+    };
+    let url = format!("/worlds/{}/characters.csv?sort=-name", world.world_id);
+
+    run_test()
+        .seed_many(&[&world_owner, &player, &world, &amiri, &valeros, &draft])
+        .get(url)
+        .has_status(Status::Ok)
+        .has_header("Content-Type", "text/csv; charset=utf-8")
+        .has_header(
+            "Content-Disposition",
+            "attachment; filename=\"golarion-characters.csv\"",
+        )
+        .assert_response(|response| {
+            assert_eq!(
+                response.body,
+                "name,description,controlled_by,status,publish_at\n\
+                 Valeros,A fighter,,published,\n\
+                 Amiri,A barbarian,7da4cb77-8839-4805-b93a-f4c536c8bc85,published,\n"
+            );
+        });
+}
+
+#[test]
+fn test_export_controlled_by() {
+    let world_owner = SeedUser::default();
+    let player = SeedUser::default();
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let amiri = SeedCharacter {
+        world_id: world.world_id,
+        name: "Amiri".to_owned(),
+        description: "A barbarian".to_owned(),
+        controlled_by: Some(player.user_id),
+        ..SeedCharacter::default()
+    };
+    let valeros = SeedCharacter {
+        world_id: world.world_id,
+        ..SeedCharacter::default()
+    };
+    let url = format!(
+        "/worlds/{}/characters.csv?controlled_by={}",
+        world.world_id, player.user_id
+    );
+
+    run_test()
+        .seed_many(&[&world_owner, &player, &world, &amiri, &valeros])
+        .get(url)
+        .has_status(Status::Ok)
+        .assert_response(|response| {
+            assert_eq!(
+                response.body,
+                format!(
+                    "name,description,controlled_by,status,publish_at\n\
+                     Amiri,A barbarian,{},published,\n",
+                    player.user_id
+                )
+            );
+        });
+}
@@ -0,0 +1,161 @@
+use crate::{
+    data::{hash_password, SeedCharacter, SeedUser, SeedWorld},
+    tests::run_test,
+};
+use rocket::http::Status;
+use serde_json::json;
+
+#[test]
+fn test_get_unknown_character() {
+    run_test()
+        .get("/characters/d6c124d5-1060-48ef-aeb8-e591a8261c2b")
+        .has_status(Status::NotFound)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:characters/problems/unknown_character",
+            "title": "The requested character was unknown",
+            "status": 404
+        }));
+}
+
+#[test]
+fn test_get_known_character() {
+    let world_owner = SeedUser::default();
+    let world = SeedWorld {
+        world_id: uuid::Uuid::parse_str("d6c124d5-1060-48ef-aeb8-e591a8261c2b").unwrap(),
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let character = SeedCharacter {
+        character_id: uuid::Uuid::parse_str("5a5e3cbd-5a8e-4f0f-a8b4-7d1a61e0a6a4").unwrap(),
+        world_id: world.world_id,
+        name: "Valeros".to_owned(),
+        description: "A human fighter".to_owned(),
+        ..SeedCharacter::default()
+    };
+
+    run_test()
+        .seed_many(&[&world_owner, &world, &character])
+        .get("/characters/5a5e3cbd-5a8e-4f0f-a8b4-7d1a61e0a6a4")
+        .has_status(Status::Ok)
+        .has_header("Content-Type", "application/json")
+        .has_header("ETag", format!("\"{}\"", character.version))
+        .has_header_regex(
+            "Link",
+            r#"</characters/5a5e3cbd-5a8e-4f0f-a8b4-7d1a61e0a6a4>; rel="self""#,
+        )
+        .has_header_regex(
+            "Link",
+            r#"</worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b>; rel="related""#,
+        )
+        .has_json_body(json!({
+          "name": "Valeros",
+          "description": "A human fighter",
+          "controlled_by": null,
+          "status": "published"
+        }));
+}
+
+#[test]
+fn test_get_character_in_draft_world() {
+    let world_owner = SeedUser::default();
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        status: "draft".to_owned(),
+        ..SeedWorld::default()
+    };
+    let character = SeedCharacter {
+        character_id: uuid::Uuid::parse_str("5a5e3cbd-5a8e-4f0f-a8b4-7d1a61e0a6a4").unwrap(),
+        world_id: world.world_id,
+        ..SeedCharacter::default()
+    };
+
+    run_test()
+        .seed_many(&[&world_owner, &world, &character])
+        .get("/characters/5a5e3cbd-5a8e-4f0f-a8b4-7d1a61e0a6a4")
+        .has_status(Status::NotFound);
+}
+
+#[test]
+fn test_get_controlled_character_in_draft_world() {
+    let world_owner = SeedUser::default();
+    let player = SeedUser {
+        user_id: uuid::Uuid::parse_str("7da4cb77-8839-4805-b93a-f4c536c8bc85").unwrap(),
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        status: "draft".to_owned(),
+        ..SeedWorld::default()
+    };
+    let character = SeedCharacter {
+        character_id: uuid::Uuid::parse_str("5a5e3cbd-5a8e-4f0f-a8b4-7d1a61e0a6a4").unwrap(),
+        world_id: world.world_id,
+        name: "Valeros".to_owned(),
+        controlled_by: Some(player.user_id),
+        ..SeedCharacter::default()
+    };
+
+    run_test()
+        .seed_many(&[&world_owner, &player, &world, &character])
+        .authenticate("testuser", "password")
+        .get("/characters/5a5e3cbd-5a8e-4f0f-a8b4-7d1a61e0a6a4")
+        .has_status(Status::Ok)
+        .assert_json_body(|body| {
+            assert_eq!(body["name"], json!("Valeros"));
+            assert_eq!(
+                body["controlled_by"],
+                json!("/users/7da4cb77-8839-4805-b93a-f4c536c8bc85")
+            );
+        });
+}
+
+#[test]
+fn test_get_draft_character() {
+    let world_owner = SeedUser::default();
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let character = SeedCharacter {
+        character_id: uuid::Uuid::parse_str("5a5e3cbd-5a8e-4f0f-a8b4-7d1a61e0a6a4").unwrap(),
+        world_id: world.world_id,
+        status: "draft".to_owned(),
+        ..SeedCharacter::default()
+    };
+
+    run_test()
+        .seed_many(&[&world_owner, &world, &character])
+        .get("/characters/5a5e3cbd-5a8e-4f0f-a8b4-7d1a61e0a6a4")
+        .has_status(Status::NotFound);
+}
+
+#[test]
+fn test_get_draft_character_as_owner() {
+    let world_owner = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let character = SeedCharacter {
+        character_id: uuid::Uuid::parse_str("5a5e3cbd-5a8e-4f0f-a8b4-7d1a61e0a6a4").unwrap(),
+        world_id: world.world_id,
+        status: "draft".to_owned(),
+        ..SeedCharacter::default()
+    };
+
+    run_test()
+        .seed_many(&[&world_owner, &world, &character])
+        .authenticate("testuser", "password")
+        .get("/characters/5a5e3cbd-5a8e-4f0f-a8b4-7d1a61e0a6a4")
+        .has_status(Status::Ok)
+        .assert_json_body(|body| {
+            assert_eq!(body["status"], json!("draft"));
+        });
+}
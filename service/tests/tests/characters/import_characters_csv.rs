@@ -0,0 +1,222 @@
+use crate::{
+    data::{hash_password, SeedUser, SeedUserQuota, SeedWorld},
+    tests::run_test,
+};
+use rocket::http::Status;
+use serde_json::json;
+
+const VALID_CSV: &str = "name,description,controlled_by,status,publish_at
+Valeros,A fighter,,,
+Seoni,A sorcerer,,draft,
+";
+
+#[test]
+fn test_import_unauthenticated() {
+    let world_owner = SeedUser::default();
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let url = format!("/worlds/{}/characters.csv", world.world_id);
+
+    run_test()
+        .seed_many(&[&world_owner, &world])
+        .post_raw(url, "text/csv", VALID_CSV)
+        .has_status(Status::Forbidden)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/unauthorized",
+            "title": "An invalid access token was provided",
+            "status": 403
+        }));
+}
+
+#[test]
+fn test_import_dry_run() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let url = format!("/worlds/{}/characters.csv?dry_run=true", world.world_id);
+
+    run_test()
+        .seed_many(&[&user, &world])
+        .authenticate("testuser", "password")
+        .post_raw(
+            url,
+            "text/csv",
+            format!(
+                "{}{}",
+                VALID_CSV, ",Nameless,,,\nAmiri,,7da4cb77-8839-4805-b93a-f4c536c8bc85,,\n"
+            ),
+        )
+        .has_status(Status::Ok)
+        .has_header("Content-Type", "application/json")
+        .has_json_body(json!({
+            "dry_run": true,
+            "valid": false,
+            "rows": [
+                { "line": 2, "status": "valid" },
+                { "line": 3, "status": "valid" },
+                {
+                    "line": 4,
+                    "status": "invalid",
+                    "fields": {
+                        "name": {
+                            "type": "tag:multiverse,2020:problems/validation_error/missing",
+                            "title": "The required field was missing"
+                        }
+                    }
+                },
+                {
+                    "line": 5,
+                    "status": "invalid",
+                    "fields": {
+                        "controlled_by": {
+                            "type": "tag:multiverse,2020:characters/problems/validation_error/unknown_user",
+                            "title": "The user was unknown"
+                        }
+                    }
+                }
+            ]
+        }))
+        .assert_database(|mut tx| {
+            let count: i64 = tx
+                .query_one("SELECT COUNT(*) FROM characters", &[])
+                .unwrap()
+                .get(0);
+            assert_eq!(count, 0);
+        });
+}
+
+#[test]
+fn test_import_invalid_rows() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let url = format!("/worlds/{}/characters.csv", world.world_id);
+
+    run_test()
+        .seed_many(&[&user, &world])
+        .authenticate("testuser", "password")
+        .post_raw(
+            url,
+            "text/csv",
+            format!("{}{}", VALID_CSV, ",Nameless,,,\n"),
+        )
+        .has_status(Status::UnprocessableEntity)
+        .has_header("Content-Type", "application/problem+json")
+        .assert_json_body(|body| {
+            assert_eq!(
+                body["type"],
+                json!("tag:multiverse,2020:problems/invalid_rows")
+            );
+            assert_eq!(body["rows"][0]["status"], json!("valid"));
+            assert_eq!(body["rows"][1]["status"], json!("valid"));
+            assert_eq!(body["rows"][2]["status"], json!("invalid"));
+        })
+        .assert_database(|mut tx| {
+            let count: i64 = tx
+                .query_one("SELECT COUNT(*) FROM characters", &[])
+                .unwrap()
+                .get(0);
+            assert_eq!(count, 0);
+        });
+}
+
+#[test]
+fn test_import_success() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let url = format!("/worlds/{}/characters.csv", world.world_id);
+
+    run_test()
+        .seed_many(&[&user, &world])
+        .authenticate("testuser", "password")
+        .post_raw(url, "text/csv", VALID_CSV)
+        .has_status(Status::Ok)
+        .has_header("Content-Type", "application/json")
+        .assert_json_body(|body| {
+            assert_eq!(body["dry_run"], json!(false));
+            assert_eq!(body["valid"], json!(true));
+            assert_eq!(body["rows"][0]["line"], json!(2));
+            assert_eq!(body["rows"][0]["status"], json!("created"));
+            assert!(body["rows"][0]["href"]
+                .as_str()
+                .unwrap()
+                .starts_with("/characters/"));
+            assert_eq!(body["rows"][1]["line"], json!(3));
+            assert_eq!(body["rows"][1]["status"], json!("created"));
+        })
+        .assert_database(|mut tx| {
+            let names: Vec<String> = tx
+                .query(
+                    "SELECT name FROM characters WHERE world_id = $1 ORDER BY name",
+                    &[&world.world_id],
+                )
+                .unwrap()
+                .iter()
+                .map(|row| row.get(0))
+                .collect();
+            assert_eq!(names, vec!["Seoni", "Valeros"]);
+        });
+}
+
+#[test]
+fn test_import_quota_exceeded() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let quota = SeedUserQuota {
+        user_id: user.user_id,
+        resource: "characters".to_owned(),
+        quota: 1,
+    };
+    let url = format!("/worlds/{}/characters.csv", world.world_id);
+
+    run_test()
+        .seed_many(&[&user, &world, &quota])
+        .authenticate("testuser", "password")
+        .post_raw(url, "text/csv", VALID_CSV)
+        .has_status(Status::Forbidden)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/quota_exceeded",
+            "title": "The quota has been exceeded",
+            "status": 403,
+            "resource": "characters",
+            "used": 0,
+            "limit": 1
+        }))
+        .assert_database(|mut tx| {
+            let count: i64 = tx
+                .query_one("SELECT COUNT(*) FROM characters", &[])
+                .unwrap()
+                .get(0);
+            assert_eq!(count, 0);
+        });
+}
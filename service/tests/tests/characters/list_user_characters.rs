@@ -0,0 +1,114 @@
+use crate::{
+    data::{hash_password, SeedCharacter, SeedUser, SeedWorld},
+    tests::run_test,
+};
+use rocket::http::Status;
+use serde_json::json;
+
+#[test]
+fn test_list_unknown_user() {
+    run_test()
+        .get("/users/d6c124d5-1060-48ef-aeb8-e591a8261c2b/characters")
+        .has_status(Status::NotFound);
+}
+
+#[test]
+fn test_list_across_worlds() {
+    let world_owner = SeedUser::default();
+    let player = SeedUser {
+        user_id: uuid::Uuid::parse_str("7da4cb77-8839-4805-b93a-f4c536c8bc85").unwrap(),
+        ..SeedUser::default()
+    };
+    let world1 = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let world2 = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let draft_world = SeedWorld {
+        owner: world_owner.user_id,
+        status: "draft".to_owned(),
+        ..SeedWorld::default()
+    };
+    let character1 = SeedCharacter {
+        character_id: uuid::Uuid::parse_str("5a5e3cbd-5a8e-4f0f-a8b4-7d1a61e0a6a4").unwrap(),
+        world_id: world1.world_id,
+        name: "Amiri".to_owned(),
+        controlled_by: Some(player.user_id),
+        ..SeedCharacter::default()
+    };
+    let character2 = SeedCharacter {
+        world_id: world2.world_id,
+        name: "Valeros".to_owned(),
+        controlled_by: Some(player.user_id),
+        ..SeedCharacter::default()
+    };
+    let hidden = SeedCharacter {
+        world_id: draft_world.world_id,
+        name: "Kyra".to_owned(),
+        controlled_by: Some(player.user_id),
+        ..SeedCharacter::default()
+    };
+    let uncontrolled = SeedCharacter {
+        world_id: world1.world_id,
+        ..SeedCharacter::default()
+    };
+
+    run_test()
+        .seed_many(&[
+            &world_owner,
+            &player,
+            &world1,
+            &world2,
+            &draft_world,
+            &character1,
+            &character2,
+            &hidden,
+            &uncontrolled,
+        ])
+        .get("/users/7da4cb77-8839-4805-b93a-f4c536c8bc85/characters")
+        .has_status(Status::Ok)
+        .has_header_regex(
+            "Link",
+            r#"</characters/5a5e3cbd-5a8e-4f0f-a8b4-7d1a61e0a6a4>; rel="item"; anchor="\#/entries/0""#,
+        )
+        .assert_json_body(|body| {
+            assert_eq!(body["pagination"]["total"], json!(2));
+            assert_eq!(body["entries"][0]["name"], json!("Amiri"));
+            assert_eq!(body["entries"][1]["name"], json!("Valeros"));
+        });
+}
+
+#[test]
+fn test_list_own_characters_in_draft_world() {
+    let world_owner = SeedUser::default();
+    let player = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let draft_world = SeedWorld {
+        owner: world_owner.user_id,
+        status: "draft".to_owned(),
+        ..SeedWorld::default()
+    };
+    let character = SeedCharacter {
+        world_id: draft_world.world_id,
+        name: "Kyra".to_owned(),
+        controlled_by: Some(player.user_id),
+        ..SeedCharacter::default()
+    };
+    let url = format!("/users/{}/characters", player.user_id);
+
+    run_test()
+        .seed_many(&[&world_owner, &player, &draft_world, &character])
+        .authenticate("testuser", "password")
+        .get(url)
+        .has_status(Status::Ok)
+        .assert_json_body(|body| {
+            assert_eq!(body["pagination"]["total"], json!(1));
+            assert_eq!(body["entries"][0]["name"], json!("Kyra"));
+        });
+}
@@ -0,0 +1,8 @@
+mod create_character;
+mod delete_character;
+mod export_characters_csv;
+mod get_character;
+mod import_characters_csv;
+mod list_user_characters;
+mod search_characters;
+mod update_character;
@@ -0,0 +1,166 @@
+use crate::{
+    data::{hash_password, SeedCharacter, SeedUser, SeedWorld},
+    tests::run_test,
+};
+use rocket::http::Status;
+use serde_json::json;
+
+#[test]
+fn test_search_unknown_world() {
+    run_test()
+        .get("/worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b/characters")
+        .has_status(Status::NotFound)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:worlds/problems/unknown_world_id",
+            "title": "The requested world ID was unknown",
+            "status": 404
+        }));
+}
+
+#[test]
+fn test_search_published() {
+    let world_owner = SeedUser::default();
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let amiri = SeedCharacter {
+        world_id: world.world_id,
+        name: "Amiri".to_owned(),
+        ..SeedCharacter::default()
+    };
+    let valeros = SeedCharacter {
+        character_id: uuid::Uuid::parse_str("5a5e3cbd-5a8e-4f0f-a8b4-7d1a61e0a6a4").unwrap(),
+        world_id: world.world_id,
+        name: "Valeros".to_owned(),
+        ..SeedCharacter::default()
+    };
+    let draft = SeedCharacter {
+        world_id: world.world_id,
+        name: "Kyra".to_owned(),
+        status: "draft".to_owned(),
+        ..SeedCharacter::default()
+    };
+    let other_world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let elsewhere = SeedCharacter {
+        world_id: other_world.world_id,
+        name: "Seoni".to_owned(),
+        ..SeedCharacter::default()
+    };
+    let url = format!("/worlds/{}/characters?sort=-name", world.world_id);
+
+    run_test()
+        .seed_many(&[
+            &world_owner,
+            &world,
+            &other_world,
+            &amiri,
+            &valeros,
+            &draft,
+            &elsewhere,
+        ])
+        .get(url)
+        .has_status(Status::Ok)
+        .has_header_regex(
+            "Link",
+            r#"</characters/5a5e3cbd-5a8e-4f0f-a8b4-7d1a61e0a6a4>; rel="item"; anchor="\#/entries/0""#,
+        )
+        .assert_json_body(|body| {
+            assert_eq!(body["pagination"]["total"], json!(2));
+            assert_eq!(body["entries"][0]["name"], json!("Valeros"));
+            assert_eq!(body["entries"][1]["name"], json!("Amiri"));
+        });
+}
+
+#[test]
+fn test_search_controlled_by() {
+    let world_owner = SeedUser::default();
+    let player = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let controlled = SeedCharacter {
+        world_id: world.world_id,
+        name: "Amiri".to_owned(),
+        controlled_by: Some(player.user_id),
+        ..SeedCharacter::default()
+    };
+    let controlled_draft = SeedCharacter {
+        world_id: world.world_id,
+        name: "Kyra".to_owned(),
+        controlled_by: Some(player.user_id),
+        status: "draft".to_owned(),
+        ..SeedCharacter::default()
+    };
+    let uncontrolled = SeedCharacter {
+        world_id: world.world_id,
+        name: "Valeros".to_owned(),
+        ..SeedCharacter::default()
+    };
+    let url = format!(
+        "/worlds/{}/characters?controlled_by=/users/{}",
+        world.world_id, player.user_id
+    );
+
+    run_test()
+        .seed_many(&[
+            &world_owner,
+            &player,
+            &world,
+            &controlled,
+            &controlled_draft,
+            &uncontrolled,
+        ])
+        .get(url.clone())
+        .has_status(Status::Ok)
+        .assert_json_body(|body| {
+            assert_eq!(body["pagination"]["total"], json!(1));
+            assert_eq!(body["entries"][0]["name"], json!("Amiri"));
+        })
+        .authenticate("testuser", "password")
+        .get(url)
+        .has_status(Status::Ok)
+        .assert_json_body(|body| {
+            assert_eq!(body["pagination"]["total"], json!(2));
+            assert_eq!(body["entries"][0]["name"], json!("Amiri"));
+            assert_eq!(body["entries"][1]["name"], json!("Kyra"));
+        });
+}
+
+#[test]
+fn test_search_as_owner_includes_drafts() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let draft = SeedCharacter {
+        world_id: world.world_id,
+        status: "draft".to_owned(),
+        ..SeedCharacter::default()
+    };
+    let url = format!("/worlds/{}/characters", world.world_id);
+
+    run_test()
+        .seed_many(&[&user, &world, &draft])
+        .authenticate("testuser", "password")
+        .get(url)
+        .has_status(Status::Ok)
+        .assert_json_body(|body| {
+            assert_eq!(body["pagination"]["total"], json!(1));
+            assert_eq!(body["entries"][0]["status"], json!("draft"));
+        });
+}
@@ -0,0 +1,203 @@
+use crate::{
+    data::{hash_password, SeedCharacter, SeedUser, SeedWorld},
+    tests::run_test,
+};
+use rocket::http::Status;
+use serde_json::json;
+
+#[test]
+fn test_update_unauthenticated() {
+    let world_owner = SeedUser::default();
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let character = SeedCharacter {
+        world_id: world.world_id,
+        ..SeedCharacter::default()
+    };
+    let url = format!("/characters/{}", character.character_id);
+
+    run_test()
+        .seed_many(&[&world_owner, &world, &character])
+        .put(url, json!({ "name": "Valeros" }))
+        .has_status(Status::Forbidden)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/unauthorized",
+            "title": "An invalid access token was provided",
+            "status": 403
+        }));
+}
+
+#[test]
+fn test_update_other_users_character() {
+    let world_owner = SeedUser::default();
+    let player = SeedUser::default();
+    let other = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let character = SeedCharacter {
+        world_id: world.world_id,
+        controlled_by: Some(player.user_id),
+        ..SeedCharacter::default()
+    };
+    let url = format!("/characters/{}", character.character_id);
+
+    run_test()
+        .seed_many(&[&world_owner, &player, &other, &world, &character])
+        .authenticate("testuser", "password")
+        .put(url, json!({ "name": "Valeros" }))
+        .has_status(Status::Forbidden);
+}
+
+#[test]
+fn test_update_as_controller() {
+    let world_owner = SeedUser::default();
+    let player = SeedUser {
+        user_id: uuid::Uuid::parse_str("7da4cb77-8839-4805-b93a-f4c536c8bc85").unwrap(),
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let character = SeedCharacter {
+        world_id: world.world_id,
+        name: "Valeros".to_owned(),
+        controlled_by: Some(player.user_id),
+        ..SeedCharacter::default()
+    };
+    let url = format!("/characters/{}", character.character_id);
+
+    run_test()
+        .seed_many(&[&world_owner, &player, &world, &character])
+        .authenticate("testuser", "password")
+        .put(
+            url,
+            json!({
+              "name": "Valeros the Bold",
+              "description": "A human fighter",
+              "controlled_by": "/users/7da4cb77-8839-4805-b93a-f4c536c8bc85"
+            }),
+        )
+        .has_status(Status::Ok)
+        .has_json_body(json!({
+          "name": "Valeros the Bold",
+          "description": "A human fighter",
+          "controlled_by": "/users/7da4cb77-8839-4805-b93a-f4c536c8bc85",
+          "status": "published"
+        }));
+}
+
+#[test]
+fn test_update_controller_as_controller() {
+    let world_owner = SeedUser::default();
+    let player = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let character = SeedCharacter {
+        world_id: world.world_id,
+        controlled_by: Some(player.user_id),
+        ..SeedCharacter::default()
+    };
+    let url = format!("/characters/{}", character.character_id);
+
+    run_test()
+        .seed_many(&[&world_owner, &player, &world, &character])
+        .authenticate("testuser", "password")
+        .put(url, json!({ "name": "Valeros" }))
+        .has_status(Status::Forbidden);
+}
+
+#[test]
+fn test_publish_as_controller() {
+    let world_owner = SeedUser::default();
+    let player = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let character = SeedCharacter {
+        world_id: world.world_id,
+        controlled_by: Some(player.user_id),
+        status: "draft".to_owned(),
+        ..SeedCharacter::default()
+    };
+    let url = format!("/characters/{}", character.character_id);
+    let controlled_by = format!("/users/{}", player.user_id);
+
+    run_test()
+        .seed_many(&[&world_owner, &player, &world, &character])
+        .authenticate("testuser", "password")
+        .put(
+            url,
+            json!({
+              "name": "Valeros",
+              "controlled_by": controlled_by,
+              "status": "published"
+            }),
+        )
+        .has_status(Status::Forbidden);
+}
+
+#[test]
+fn test_update_controller_as_owner() {
+    let world_owner = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let player = SeedUser::default();
+    let new_player = SeedUser {
+        user_id: uuid::Uuid::parse_str("7da4cb77-8839-4805-b93a-f4c536c8bc85").unwrap(),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let character = SeedCharacter {
+        world_id: world.world_id,
+        name: "Valeros".to_owned(),
+        controlled_by: Some(player.user_id),
+        ..SeedCharacter::default()
+    };
+    let url = format!("/characters/{}", character.character_id);
+
+    run_test()
+        .seed_many(&[&world_owner, &player, &new_player, &world, &character])
+        .authenticate("testuser", "password")
+        .put(
+            url,
+            json!({
+              "name": "Valeros",
+              "controlled_by": "/users/7da4cb77-8839-4805-b93a-f4c536c8bc85"
+            }),
+        )
+        .has_status(Status::Ok)
+        .assert_json_body(|body| {
+            assert_eq!(
+                body["controlled_by"],
+                json!("/users/7da4cb77-8839-4805-b93a-f4c536c8bc85")
+            );
+        });
+}
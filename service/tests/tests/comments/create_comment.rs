@@ -0,0 +1,226 @@
+use crate::{
+    data::{hash_password, SeedComment, SeedUser, SeedWorld},
+    tests::run_test,
+};
+use rocket::http::Status;
+use serde_json::json;
+
+#[test]
+fn test_create_unauthenticated() {
+    let world_owner = SeedUser::default();
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let url = format!("/worlds/{}/comments", world.world_id);
+
+    run_test()
+        .seed(&world_owner)
+        .seed(&world)
+        .post(url, json!({ "body": "Hello" }))
+        .has_status(Status::Forbidden)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/unauthorized",
+            "title": "An invalid access token was provided",
+            "status": 403
+        }));
+}
+
+#[test]
+fn test_create_unknown_world() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+
+    run_test()
+        .seed(&user)
+        .authenticate("testuser", "password")
+        .post(
+            "/worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b/comments",
+            json!({ "body": "Hello" }),
+        )
+        .has_status(Status::NotFound)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:worlds/problems/unknown_world_id",
+            "title": "The requested world ID was unknown",
+            "status": 404
+        }));
+}
+
+#[test]
+fn test_create_invalid() {
+    let world_owner = SeedUser::default();
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let url = format!("/worlds/{}/comments", world.world_id);
+
+    run_test()
+        .seed(&world_owner)
+        .seed(&user)
+        .seed(&world)
+        .authenticate("testuser", "password")
+        .post(
+            url,
+            json!({ "parent": "/comments/d6c124d5-1060-48ef-aeb8-e591a8261c2b" }),
+        )
+        .has_status(Status::UnprocessableEntity)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/validation_error",
+            "title": "A validation error occurred",
+            "status": 422,
+            "fields": {
+              "body": {
+                "type": "tag:multiverse,2020:problems/validation_error/missing",
+                "title": "The required field was missing"
+              },
+              "parent": {
+                "type": "tag:multiverse,2020:comments/problems/validation_error/unknown_parent",
+                "title": "The parent comment was unknown"
+              }
+            }
+        }));
+}
+
+#[test]
+fn test_create_reply_to_different_entity() {
+    let world_owner = SeedUser::default();
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let parent = SeedComment {
+        world_id: world.world_id,
+        author_id: user.user_id,
+        entity: Some("dragons".to_owned()),
+        ..SeedComment::default()
+    };
+    let url = format!("/worlds/{}/comments", world.world_id);
+
+    run_test()
+        .seed(&world_owner)
+        .seed(&user)
+        .seed(&world)
+        .seed(&parent)
+        .authenticate("testuser", "password")
+        .post(
+            url,
+            json!({
+              "body": "Hello",
+              "entity": "castles",
+              "parent": format!("/comments/{}", parent.comment_id)
+            }),
+        )
+        .has_status(Status::UnprocessableEntity)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/validation_error",
+            "title": "A validation error occurred",
+            "status": 422,
+            "fields": {
+              "parent": {
+                "type": "tag:multiverse,2020:comments/problems/validation_error/unknown_parent",
+                "title": "The parent comment was unknown"
+              }
+            }
+        }));
+}
+
+#[test]
+fn test_create_success() {
+    let world_owner = SeedUser::default();
+    let user = SeedUser {
+        user_id: uuid::Uuid::parse_str("00000000-0000-0000-0001-000000000001").unwrap(),
+        username: "testuser".to_owned(),
+        display_name: "Test User".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        world_id: uuid::Uuid::parse_str("d6c124d5-1060-48ef-aeb8-e591a8261c2b").unwrap(),
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+
+    run_test()
+        .seed(&world_owner)
+        .seed(&user)
+        .seed(&world)
+        .authenticate("testuser", "password")
+        .post(
+            "/worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b/comments",
+            json!({
+              "body": "I *love* this world <script>alert('Hi')</script>",
+              "entity": "dragons"
+            }),
+        )
+        .has_status(Status::Ok)
+        .has_header("Content-Type", "application/json")
+        .has_header_regex("Link", r#"</comments/[0-9a-f-]+>; rel="self""#)
+        .has_header_regex(
+            "Link",
+            r#"</users/00000000-0000-0000-0001-000000000001>; rel="author"; title="Test User""#,
+        )
+        .has_header_regex(
+            "Link",
+            r#"</worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b>; rel="related""#,
+        )
+        .assert_json_body(|body| {
+            assert_eq!(
+                body["body"],
+                json!("I *love* this world <script>alert('Hi')</script>")
+            );
+            assert_eq!(body["html"], json!("<p>I <em>love</em> this world </p>\n"));
+            assert_eq!(body["entity"], json!("dragons"));
+            assert_eq!(body["parent"], json!(null));
+        });
+}
+
+#[test]
+fn test_create_reply() {
+    let world_owner = SeedUser::default();
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let parent = SeedComment {
+        world_id: world.world_id,
+        author_id: user.user_id,
+        ..SeedComment::default()
+    };
+    let url = format!("/worlds/{}/comments", world.world_id);
+    let parent_link = format!("/comments/{}", parent.comment_id);
+
+    run_test()
+        .seed(&world_owner)
+        .seed(&user)
+        .seed(&world)
+        .seed(&parent)
+        .authenticate("testuser", "password")
+        .post(url, json!({ "body": "Agreed", "parent": parent_link }))
+        .has_status(Status::Ok)
+        .assert_json_body(|body| {
+            assert_eq!(body["parent"], json!(parent_link));
+        });
+}
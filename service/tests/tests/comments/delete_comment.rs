@@ -0,0 +1,127 @@
+use crate::{
+    data::{hash_password, SeedComment, SeedUser, SeedWorld},
+    tests::run_test,
+};
+use rocket::http::Status;
+use serde_json::json;
+
+#[test]
+fn test_delete_unknown_comment() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+
+    run_test()
+        .seed(&user)
+        .authenticate("testuser", "password")
+        .delete("/comments/d6c124d5-1060-48ef-aeb8-e591a8261c2b")
+        .has_status(Status::NotFound)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:comments/problems/unknown_comment",
+            "title": "The requested comment was unknown",
+            "status": 404
+        }));
+}
+
+#[test]
+fn test_delete_other_user() {
+    let world_owner = SeedUser::default();
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let author = SeedUser::default();
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let comment = SeedComment {
+        world_id: world.world_id,
+        author_id: author.user_id,
+        ..SeedComment::default()
+    };
+    let url = format!("/comments/{}", comment.comment_id);
+
+    run_test()
+        .seed(&world_owner)
+        .seed_many(&[&user, &author, &world, &comment])
+        .authenticate("testuser", "password")
+        .delete(url)
+        .has_status(Status::Forbidden)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/unauthorized",
+            "title": "An invalid access token was provided",
+            "status": 403
+        }));
+}
+
+#[test]
+fn test_delete_by_author() {
+    let world_owner = SeedUser::default();
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let comment = SeedComment {
+        world_id: world.world_id,
+        author_id: user.user_id,
+        ..SeedComment::default()
+    };
+    let reply = SeedComment {
+        world_id: world.world_id,
+        author_id: user.user_id,
+        parent_id: Some(comment.comment_id),
+        ..SeedComment::default()
+    };
+    let url = format!("/comments/{}", comment.comment_id);
+
+    run_test()
+        .seed(&world_owner)
+        .seed_many(&[&user, &world, &comment, &reply])
+        .authenticate("testuser", "password")
+        .delete(url)
+        .has_status(Status::NoContent)
+        .assert_database(|mut tx| {
+            let count: i64 = tx
+                .query_one("SELECT COUNT(*) FROM comments", &[])
+                .unwrap()
+                .get(0);
+            assert_eq!(count, 0);
+        });
+}
+
+#[test]
+fn test_delete_by_world_owner() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let author = SeedUser::default();
+    let world = SeedWorld {
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let comment = SeedComment {
+        world_id: world.world_id,
+        author_id: author.user_id,
+        ..SeedComment::default()
+    };
+    let url = format!("/comments/{}", comment.comment_id);
+
+    run_test()
+        .seed_many(&[&user, &author, &world, &comment])
+        .authenticate("testuser", "password")
+        .delete(url)
+        .has_status(Status::NoContent);
+}
@@ -0,0 +1,133 @@
+use crate::{
+    data::{hash_password, SeedComment, SeedUser, SeedWorld},
+    tests::run_test,
+};
+use rocket::http::Status;
+use serde_json::json;
+
+#[test]
+fn test_get_unknown_comment() {
+    run_test()
+        .get("/comments/d6c124d5-1060-48ef-aeb8-e591a8261c2b")
+        .has_status(Status::NotFound)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:comments/problems/unknown_comment",
+            "title": "The requested comment was unknown",
+            "status": 404
+        }));
+}
+
+#[test]
+fn test_get_known_comment() {
+    let world_owner = SeedUser::default();
+    let user = SeedUser {
+        user_id: uuid::Uuid::parse_str("00000000-0000-0000-0001-000000000001").unwrap(),
+        display_name: "Test User".to_owned(),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let comment = SeedComment {
+        comment_id: uuid::Uuid::parse_str("d6c124d5-1060-48ef-aeb8-e591a8261c2b").unwrap(),
+        world_id: world.world_id,
+        author_id: user.user_id,
+        body: "[Home](javascript:alert('Hi'))".to_owned(),
+        ..SeedComment::default()
+    };
+
+    run_test()
+        .seed(&world_owner)
+        .seed(&user)
+        .seed(&world)
+        .seed(&comment)
+        .get("/comments/d6c124d5-1060-48ef-aeb8-e591a8261c2b")
+        .has_status(Status::Ok)
+        .has_header("Content-Type", "application/json")
+        .has_header_regex(
+            "Link",
+            r#"</comments/d6c124d5-1060-48ef-aeb8-e591a8261c2b>; rel="self""#,
+        )
+        .has_header_regex(
+            "Link",
+            r#"</users/00000000-0000-0000-0001-000000000001>; rel="author"; title="Test User""#,
+        )
+        .assert_json_body(|body| {
+            assert_eq!(body["body"], json!("[Home](javascript:alert('Hi'))"));
+            assert_eq!(
+                body["html"],
+                json!("<p><a rel=\"noopener noreferrer\">Home</a></p>\n")
+            );
+        });
+}
+
+#[test]
+fn test_get_comment_in_draft_world() {
+    let world_owner = SeedUser::default();
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        status: "draft".to_owned(),
+        ..SeedWorld::default()
+    };
+    let comment = SeedComment {
+        world_id: world.world_id,
+        author_id: world_owner.user_id,
+        ..SeedComment::default()
+    };
+    let url = format!("/comments/{}", comment.comment_id);
+
+    run_test()
+        .seed_many(&[&world_owner, &user, &world, &comment])
+        .get(url.clone())
+        .has_status(Status::NotFound)
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:comments/problems/unknown_comment",
+            "title": "The requested comment was unknown",
+            "status": 404
+        }))
+        .authenticate("testuser", "password")
+        .get(url)
+        .has_status(Status::NotFound)
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:comments/problems/unknown_comment",
+            "title": "The requested comment was unknown",
+            "status": 404
+        }));
+}
+
+#[test]
+fn test_get_comment_in_draft_world_as_owner() {
+    let world_owner = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        status: "draft".to_owned(),
+        ..SeedWorld::default()
+    };
+    let comment = SeedComment {
+        world_id: world.world_id,
+        author_id: world_owner.user_id,
+        body: "Not published yet".to_owned(),
+        ..SeedComment::default()
+    };
+    let url = format!("/comments/{}", comment.comment_id);
+
+    run_test()
+        .seed_many(&[&world_owner, &world, &comment])
+        .authenticate("testuser", "password")
+        .get(url)
+        .has_status(Status::Ok)
+        .assert_json_body(|body| {
+            assert_eq!(body["body"], json!("Not published yet"));
+        });
+}
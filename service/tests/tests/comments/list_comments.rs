@@ -0,0 +1,178 @@
+use crate::{
+    data::{SeedComment, SeedUser, SeedWorld},
+    tests::run_test,
+};
+use chrono::{DateTime, Utc};
+use rocket::http::Status;
+use serde_json::json;
+
+#[test]
+fn test_list_unknown_world() {
+    run_test()
+        .get("/worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b/comments")
+        .has_status(Status::NotFound)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:worlds/problems/unknown_world_id",
+            "title": "The requested world ID was unknown",
+            "status": 404
+        }));
+}
+
+#[test]
+fn test_list_no_comments() {
+    let world_owner = SeedUser::default();
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let url = format!("/worlds/{}/comments", world.world_id);
+
+    run_test()
+        .seed(&world_owner)
+        .seed(&world)
+        .get(url)
+        .has_status(Status::Ok)
+        .has_header("Content-Type", "application/json")
+        .has_json_body(json!({
+            "entries": [],
+            "pagination": {
+                "offset": 0,
+                "count": 10,
+                "total": 0
+            }
+        }));
+}
+
+#[test]
+fn test_list_world_comments() {
+    let world_owner = SeedUser::default();
+    let user = SeedUser {
+        user_id: uuid::Uuid::parse_str("00000000-0000-0000-0001-000000000001").unwrap(),
+        display_name: "Test User".to_owned(),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let first = SeedComment {
+        comment_id: uuid::Uuid::parse_str("00000000-0000-0000-0003-000000000001").unwrap(),
+        created: "2020-01-01T12:00:00Z".parse::<DateTime<Utc>>().unwrap(),
+        world_id: world.world_id,
+        author_id: user.user_id,
+        body: "First".to_owned(),
+        ..SeedComment::default()
+    };
+    let second = SeedComment {
+        comment_id: uuid::Uuid::parse_str("00000000-0000-0000-0003-000000000002").unwrap(),
+        created: "2020-01-02T12:00:00Z".parse::<DateTime<Utc>>().unwrap(),
+        world_id: world.world_id,
+        author_id: user.user_id,
+        body: "Second".to_owned(),
+        ..SeedComment::default()
+    };
+    let reply = SeedComment {
+        world_id: world.world_id,
+        author_id: user.user_id,
+        parent_id: Some(first.comment_id),
+        ..SeedComment::default()
+    };
+    let on_entity = SeedComment {
+        world_id: world.world_id,
+        author_id: user.user_id,
+        entity: Some("dragons".to_owned()),
+        ..SeedComment::default()
+    };
+    let url = format!("/worlds/{}/comments", world.world_id);
+
+    run_test()
+        .seed(&world_owner)
+        .seed_many(&[&user, &world, &second, &first, &reply, &on_entity])
+        .get(url)
+        .has_status(Status::Ok)
+        .has_header("Content-Type", "application/json")
+        .has_header_regex(
+            "Link",
+            r#"</comments/00000000-0000-0000-0003-000000000001>; rel="item"; anchor="\#/entries/0""#,
+        )
+        .has_header_regex(
+            "Link",
+            r#"</comments/00000000-0000-0000-0003-000000000002>; rel="item"; anchor="\#/entries/1""#,
+        )
+        .has_header_regex(
+            "Link",
+            r#"</users/00000000-0000-0000-0001-000000000001>; rel="author"; title="Test User"; anchor="\#/entries/1""#,
+        )
+        .assert_json_body(|body| {
+            assert_eq!(body["entries"][0]["body"], json!("First"));
+            assert_eq!(body["entries"][1]["body"], json!("Second"));
+            assert_eq!(body["pagination"]["total"], json!(2));
+        });
+}
+
+#[test]
+fn test_list_excludes_hidden_comments() {
+    let world_owner = SeedUser::default();
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let visible = SeedComment {
+        world_id: world.world_id,
+        author_id: world_owner.user_id,
+        body: "Visible".to_owned(),
+        ..SeedComment::default()
+    };
+    let hidden = SeedComment {
+        world_id: world.world_id,
+        author_id: world_owner.user_id,
+        body: "Hidden".to_owned(),
+        hidden: true,
+        ..SeedComment::default()
+    };
+    let url = format!("/worlds/{}/comments", world.world_id);
+
+    run_test()
+        .seed_many(&[&world_owner, &world, &visible, &hidden])
+        .get(url)
+        .has_status(Status::Ok)
+        .assert_json_body(|body| {
+            assert_eq!(body["entries"][0]["body"], json!("Visible"));
+            assert_eq!(body["pagination"]["total"], json!(1));
+        });
+}
+
+#[test]
+fn test_list_entity_comments() {
+    let world_owner = SeedUser::default();
+    let user = SeedUser::default();
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let on_world = SeedComment {
+        world_id: world.world_id,
+        author_id: user.user_id,
+        ..SeedComment::default()
+    };
+    let on_entity = SeedComment {
+        world_id: world.world_id,
+        author_id: user.user_id,
+        entity: Some("dragons".to_owned()),
+        body: "Dragons!".to_owned(),
+        ..SeedComment::default()
+    };
+    let url = format!("/worlds/{}/comments?entity=dragons", world.world_id);
+
+    run_test()
+        .seed(&world_owner)
+        .seed_many(&[&user, &world, &on_world, &on_entity])
+        .get(url)
+        .has_status(Status::Ok)
+        .assert_json_body(|body| {
+            assert_eq!(body["entries"][0]["body"], json!("Dragons!"));
+            assert_eq!(body["entries"][0]["entity"], json!("dragons"));
+            assert_eq!(body["pagination"]["total"], json!(1));
+        });
+}
@@ -0,0 +1,99 @@
+use crate::{
+    data::{hash_password, SeedComment, SeedUser, SeedWorld},
+    tests::run_test,
+};
+use rocket::http::Status;
+use serde_json::json;
+
+#[test]
+fn test_list_replies_unknown_comment() {
+    run_test()
+        .get("/comments/d6c124d5-1060-48ef-aeb8-e591a8261c2b/replies")
+        .has_status(Status::NotFound)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:comments/problems/unknown_comment",
+            "title": "The requested comment was unknown",
+            "status": 404
+        }));
+}
+
+#[test]
+fn test_list_replies() {
+    let world_owner = SeedUser::default();
+    let user = SeedUser::default();
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let parent = SeedComment {
+        world_id: world.world_id,
+        author_id: user.user_id,
+        ..SeedComment::default()
+    };
+    let reply = SeedComment {
+        world_id: world.world_id,
+        author_id: user.user_id,
+        parent_id: Some(parent.comment_id),
+        body: "A reply".to_owned(),
+        ..SeedComment::default()
+    };
+    let nested = SeedComment {
+        world_id: world.world_id,
+        author_id: user.user_id,
+        parent_id: Some(reply.comment_id),
+        ..SeedComment::default()
+    };
+    let url = format!("/comments/{}/replies", parent.comment_id);
+    let parent_link = format!("/comments/{}", parent.comment_id);
+
+    run_test()
+        .seed(&world_owner)
+        .seed_many(&[&user, &world, &parent, &reply, &nested])
+        .get(url)
+        .has_status(Status::Ok)
+        .has_header("Content-Type", "application/json")
+        .assert_json_body(|body| {
+            assert_eq!(body["entries"][0]["body"], json!("A reply"));
+            assert_eq!(body["entries"][0]["parent"], json!(parent_link));
+            assert_eq!(body["pagination"]["total"], json!(1));
+        });
+}
+
+#[test]
+fn test_list_replies_in_draft_world() {
+    let world_owner = SeedUser::default();
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        status: "draft".to_owned(),
+        ..SeedWorld::default()
+    };
+    let parent = SeedComment {
+        world_id: world.world_id,
+        author_id: world_owner.user_id,
+        ..SeedComment::default()
+    };
+    let reply = SeedComment {
+        world_id: world.world_id,
+        author_id: world_owner.user_id,
+        parent_id: Some(parent.comment_id),
+        ..SeedComment::default()
+    };
+    let url = format!("/comments/{}/replies", parent.comment_id);
+
+    run_test()
+        .seed_many(&[&world_owner, &user, &world, &parent, &reply])
+        .authenticate("testuser", "password")
+        .get(url)
+        .has_status(Status::NotFound)
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:comments/problems/unknown_comment",
+            "title": "The requested comment was unknown",
+            "status": 404
+        }));
+}
@@ -0,0 +1,6 @@
+mod create_comment;
+mod delete_comment;
+mod get_comment;
+mod list_comments;
+mod list_replies;
+mod update_comment;
@@ -0,0 +1,109 @@
+use crate::{
+    data::{hash_password, SeedComment, SeedUser, SeedWorld},
+    tests::run_test,
+};
+use rocket::http::Status;
+use serde_json::json;
+
+#[test]
+fn test_update_not_author() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let author = SeedUser::default();
+    let world = SeedWorld {
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let comment = SeedComment {
+        world_id: world.world_id,
+        author_id: author.user_id,
+        ..SeedComment::default()
+    };
+    let url = format!("/comments/{}", comment.comment_id);
+
+    run_test()
+        .seed_many(&[&user, &author, &world, &comment])
+        .authenticate("testuser", "password")
+        .put(url, json!({ "body": "Changed" }))
+        .has_status(Status::Forbidden)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/unauthorized",
+            "title": "An invalid access token was provided",
+            "status": 403
+        }));
+}
+
+#[test]
+fn test_update_blank_body() {
+    let world_owner = SeedUser::default();
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let comment = SeedComment {
+        world_id: world.world_id,
+        author_id: user.user_id,
+        ..SeedComment::default()
+    };
+    let url = format!("/comments/{}", comment.comment_id);
+
+    run_test()
+        .seed(&world_owner)
+        .seed_many(&[&user, &world, &comment])
+        .authenticate("testuser", "password")
+        .put(url, json!({ "body": "  " }))
+        .has_status(Status::UnprocessableEntity)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/validation_error",
+            "title": "A validation error occurred",
+            "status": 422,
+            "fields": {
+              "body": {
+                "type": "tag:multiverse,2020:problems/validation_error/missing",
+                "title": "The required field was missing"
+              }
+            }
+        }));
+}
+
+#[test]
+fn test_update_success() {
+    let world_owner = SeedUser::default();
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let comment = SeedComment {
+        world_id: world.world_id,
+        author_id: user.user_id,
+        ..SeedComment::default()
+    };
+    let url = format!("/comments/{}", comment.comment_id);
+
+    run_test()
+        .seed(&world_owner)
+        .seed_many(&[&user, &world, &comment])
+        .authenticate("testuser", "password")
+        .put(url, json!({ "body": "**Changed**" }))
+        .has_status(Status::Ok)
+        .has_header("Content-Type", "application/json")
+        .assert_json_body(|body| {
+            assert_eq!(body["body"], json!("**Changed**"));
+            assert_eq!(body["html"], json!("<p><strong>Changed</strong></p>\n"));
+        });
+}
@@ -0,0 +1,2 @@
+mod publish_changes;
+mod world_events;
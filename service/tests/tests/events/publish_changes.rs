@@ -0,0 +1,131 @@
+use crate::{
+    data::{hash_password, SeedComment, SeedTable, SeedUser, SeedWorld},
+    tests::run_test,
+};
+use rocket::http::Status;
+use serde_json::json;
+use uuid::Uuid;
+
+#[test]
+fn test_create_table_publishes_change() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let url = format!("/worlds/{}/tables", world.world_id);
+    let world_id = world.world_id;
+
+    run_test()
+        .seed(&user)
+        .seed(&world)
+        .authenticate("testuser", "password")
+        .post(
+            url,
+            json!({
+              "name": "Tavern Names",
+              "entries": [
+                { "text": "The Prancing Pony" }
+              ]
+            }),
+        )
+        .has_status(Status::Ok)
+        .assert_database(|mut tx| {
+            let rows = tx
+                .query(
+                    "SELECT e.world_id, e.entity_type, e.entity_id, e.version, t.table_id, t.version
+                    FROM world_events e LEFT JOIN random_tables t ON e.entity_id = t.table_id",
+                    &[],
+                )
+                .unwrap();
+            assert_eq!(rows.len(), 1);
+
+            let row = &rows[0];
+            assert_eq!(row.get::<_, Uuid>(0), world_id);
+            assert_eq!(row.get::<_, String>(1), "table");
+            assert_eq!(row.get::<_, Uuid>(2), row.get::<_, Uuid>(4));
+            assert_eq!(row.get::<_, Option<Uuid>>(3), row.get::<_, Option<Uuid>>(5));
+        });
+}
+
+#[test]
+fn test_delete_table_publishes_change() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let table = SeedTable {
+        world_id: world.world_id,
+        url_slug: "tavern-names".to_owned(),
+        ..SeedTable::default()
+    };
+    let url = format!("/worlds/{}/tables/tavern-names", world.world_id);
+    let table_id = table.table_id;
+
+    run_test()
+        .seed_many(&[&user, &world, &table])
+        .authenticate("testuser", "password")
+        .delete(url)
+        .has_status(Status::NoContent)
+        .assert_database(|mut tx| {
+            let row = tx
+                .query_one(
+                    "SELECT entity_type, entity_id, version FROM world_events",
+                    &[],
+                )
+                .unwrap();
+            assert_eq!(row.get::<_, String>(0), "table");
+            assert_eq!(row.get::<_, Uuid>(1), table_id);
+            assert_eq!(row.get::<_, Option<Uuid>>(2), None);
+        });
+}
+
+#[test]
+fn test_delete_comment_publishes_change() {
+    let world_owner = SeedUser::default();
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let comment = SeedComment {
+        world_id: world.world_id,
+        author_id: user.user_id,
+        ..SeedComment::default()
+    };
+    let url = format!("/comments/{}", comment.comment_id);
+    let comment_id = comment.comment_id;
+    let world_id = world.world_id;
+
+    run_test()
+        .seed(&world_owner)
+        .seed_many(&[&user, &world, &comment])
+        .authenticate("testuser", "password")
+        .delete(url)
+        .has_status(Status::NoContent)
+        .assert_database(|mut tx| {
+            let row = tx
+                .query_one(
+                    "SELECT world_id, entity_type, entity_id, version FROM world_events",
+                    &[],
+                )
+                .unwrap();
+            assert_eq!(row.get::<_, Uuid>(0), world_id);
+            assert_eq!(row.get::<_, String>(1), "comment");
+            assert_eq!(row.get::<_, Uuid>(2), comment_id);
+            assert_eq!(row.get::<_, Option<Uuid>>(3), None);
+        });
+}
@@ -0,0 +1,54 @@
+use crate::{
+    data::{SeedUser, SeedWorld},
+    tests::run_test,
+};
+use rocket::http::Status;
+use serde_json::{json, Value};
+
+#[test]
+fn test_events_unknown_world() {
+    run_test()
+        .get("/worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b/events")
+        .has_status(Status::NotFound)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:worlds/problems/unknown_world_id",
+            "title": "The requested world ID was unknown",
+            "status": 404
+        }));
+}
+
+#[test]
+fn test_events_too_many_streams() {
+    let user = SeedUser::default();
+    let world = SeedWorld {
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let url = format!("/worlds/{}/events", world.world_id);
+
+    let harness = run_test().seed_many(&[&user, &world]);
+
+    // The test service allows two streams to be open at once
+    let first = harness.open_stream(url.clone());
+    assert_eq!(first.status(), Status::Ok);
+    let second = harness.open_stream(url.clone());
+    assert_eq!(second.status(), Status::Ok);
+
+    let mut third = harness.open_stream(url.clone());
+    assert_eq!(third.status(), Status::ServiceUnavailable);
+    let body: Value = serde_json::from_str(&third.body_string().unwrap()).unwrap();
+    assert_eq!(
+        body,
+        json!({
+            "type": "tag:multiverse,2020:events/problems/too_many_streams",
+            "title": "Too many event streams are already open",
+            "status": 503
+        })
+    );
+
+    // Closing a stream makes room for another
+    drop(first);
+    let fourth = harness.open_stream(url);
+    assert_eq!(fourth.status(), Status::Ok);
+}
@@ -0,0 +1,206 @@
+use crate::{
+    data::{SeedAttachment, SeedCampaign, SeedCharacter, SeedTable, SeedUser, SeedWorld},
+    tests::run_test,
+};
+use rocket::http::Status;
+use serde_json::json;
+use std::io::{Cursor, Read};
+use zip::{CompressionMethod, ZipArchive};
+
+/// Read a single file out of a Zip archive
+fn read_file(archive: &[u8], name: &str) -> String {
+    let mut zip = ZipArchive::new(Cursor::new(archive.to_vec())).unwrap();
+    let mut contents = String::new();
+    zip.by_name(name)
+        .unwrap()
+        .read_to_string(&mut contents)
+        .unwrap();
+    contents
+}
+
+/// Extract every value of the given attribute from some XML
+fn attribute_values<'a>(xml: &'a str, attribute: &str) -> Vec<&'a str> {
+    let prefix = format!("{}=\"", attribute);
+    xml.match_indices(&prefix)
+        .map(|(index, _)| {
+            let value = &xml[index + prefix.len()..];
+            &value[..value.find('"').unwrap()]
+        })
+        .collect()
+}
+
+#[test]
+fn test_export_unknown_world() {
+    run_test()
+        .get("/worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b/export/epub")
+        .has_status(Status::NotFound)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:worlds/problems/unknown_world_id",
+            "title": "The requested world ID was unknown",
+            "status": 404
+        }));
+}
+
+#[test]
+fn test_export_unknown_section() {
+    let owner = SeedUser::default();
+    let world = SeedWorld {
+        world_id: uuid::Uuid::parse_str("d6c124d5-1060-48ef-aeb8-e591a8261c2b").unwrap(),
+        owner: owner.user_id,
+        ..SeedWorld::default()
+    };
+
+    run_test()
+        .seed_many(&[&owner, &world])
+        .get("/worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b/export/epub?contents=overview,maps")
+        .has_status(Status::UnprocessableEntity)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/validation_error",
+            "title": "A validation error occurred",
+            "status": 422,
+            "fields": {
+              "contents": {
+                "type": "tag:multiverse,2020:exports/problems/validation_error/unknown_section",
+                "title": "The requested section was unknown"
+              }
+            }
+        }));
+}
+
+#[test]
+fn test_export_epub() {
+    let owner = SeedUser::default();
+    let world = SeedWorld {
+        world_id: uuid::Uuid::parse_str("d6c124d5-1060-48ef-aeb8-e591a8261c2b").unwrap(),
+        name: "Test World".to_owned(),
+        url_slug: "test-world".to_owned(),
+        owner: owner.user_id,
+        ..SeedWorld::default()
+    };
+    let table = SeedTable {
+        world_id: world.world_id,
+        name: "Tavern Names".to_owned(),
+        url_slug: "tavern-names".to_owned(),
+        ..SeedTable::default()
+    };
+    let campaign = SeedCampaign {
+        campaign_id: uuid::Uuid::parse_str("5a5e3cbd-5a8e-4f0f-a8b4-7d1a61e0a6a4").unwrap(),
+        world_id: world.world_id,
+        name: "Curse of the Crimson Throne".to_owned(),
+        ..SeedCampaign::default()
+    };
+    let character = SeedCharacter {
+        world_id: world.world_id,
+        name: "Volo".to_owned(),
+        ..SeedCharacter::default()
+    };
+    let image = SeedAttachment {
+        attachment_id: uuid::Uuid::parse_str("8f1e0c3a-7b6d-4e2f-9a5c-1d2e3f4a5b6c").unwrap(),
+        world_id: world.world_id,
+        owner_id: owner.user_id,
+        filename: "map.png".to_owned(),
+        content_type: "image/png".to_owned(),
+        ..SeedAttachment::default()
+    }
+    .with_content(b"Map");
+    let document = SeedAttachment {
+        attachment_id: uuid::Uuid::parse_str("0c4b7a1e-3d2f-4e5a-8b9c-6d7e8f9a0b1c").unwrap(),
+        world_id: world.world_id,
+        owner_id: owner.user_id,
+        filename: "notes.txt".to_owned(),
+        ..SeedAttachment::default()
+    }
+    .with_content(b"Notes");
+
+    run_test()
+        .seed_many(&[
+            &owner, &world, &table, &campaign, &character, &image, &document,
+        ])
+        .seed_blob("attachments/8f1e0c3a-7b6d-4e2f-9a5c-1d2e3f4a5b6c", b"Map")
+        .seed_blob("attachments/0c4b7a1e-3d2f-4e5a-8b9c-6d7e8f9a0b1c", b"Notes")
+        .get("/worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b/export/epub")
+        .has_status(Status::Ok)
+        .has_header("Content-Type", "application/epub+zip")
+        .has_header(
+            "Content-Disposition",
+            "attachment; filename=\"test-world.epub\"",
+        )
+        .assert_binary_body(|body| {
+            let mut zip = ZipArchive::new(Cursor::new(body.to_vec())).unwrap();
+            {
+                let mut mimetype = zip.by_index(0).unwrap();
+                assert_eq!(mimetype.name(), "mimetype");
+                assert_eq!(mimetype.compression(), CompressionMethod::Stored);
+                let mut contents = String::new();
+                mimetype.read_to_string(&mut contents).unwrap();
+                assert_eq!(contents, "application/epub+zip");
+            }
+
+            let container = read_file(body, "META-INF/container.xml");
+            assert!(container.contains("full-path=\"OEBPS/content.opf\""));
+
+            let package = read_file(body, "OEBPS/content.opf");
+            assert!(package.contains("<dc:title>Test World</dc:title>"));
+            for href in attribute_values(&package, "href") {
+                assert!(
+                    zip.by_name(&format!("OEBPS/{}", href)).is_ok(),
+                    "Missing manifest file {}",
+                    href
+                );
+            }
+
+            assert!(package.contains(
+                "<item id=\"attachment-8f1e0c3a-7b6d-4e2f-9a5c-1d2e3f4a5b6c\" href=\"attachments/8f1e0c3a-7b6d-4e2f-9a5c-1d2e3f4a5b6c.png\" media-type=\"image/png\"/>"
+            ));
+            assert!(!zip
+                .file_names()
+                .any(|name| name.contains("0c4b7a1e-3d2f-4e5a-8b9c-6d7e8f9a0b1c")));
+
+            let nav = read_file(body, "OEBPS/nav.xhtml");
+            assert!(nav.contains("<li><a href=\"table-tavern-names.xhtml\">Tavern Names</a></li>"));
+            assert!(nav.contains("<li><span>Characters</span>"));
+        });
+}
+
+#[test]
+fn test_export_epub_sections() {
+    let owner = SeedUser::default();
+    let world = SeedWorld {
+        world_id: uuid::Uuid::parse_str("d6c124d5-1060-48ef-aeb8-e591a8261c2b").unwrap(),
+        owner: owner.user_id,
+        ..SeedWorld::default()
+    };
+    let table = SeedTable {
+        world_id: world.world_id,
+        url_slug: "tavern-names".to_owned(),
+        ..SeedTable::default()
+    };
+    let campaign = SeedCampaign {
+        campaign_id: uuid::Uuid::parse_str("5a5e3cbd-5a8e-4f0f-a8b4-7d1a61e0a6a4").unwrap(),
+        world_id: world.world_id,
+        ..SeedCampaign::default()
+    };
+
+    run_test()
+        .seed_many(&[&owner, &world, &table, &campaign])
+        .get("/worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b/export/epub?contents=campaigns,overview")
+        .has_status(Status::Ok)
+        .assert_binary_body(|body| {
+            let package = read_file(body, "OEBPS/content.opf");
+            assert_eq!(
+                attribute_values(&package, "idref"),
+                vec![
+                    "cover",
+                    "campaign-5a5e3cbd-5a8e-4f0f-a8b4-7d1a61e0a6a4",
+                    "overview"
+                ]
+            );
+
+            let zip = ZipArchive::new(Cursor::new(body.to_vec())).unwrap();
+            assert!(!zip
+                .file_names()
+                .any(|name| name == "OEBPS/table-tavern-names.xhtml"));
+        });
+}
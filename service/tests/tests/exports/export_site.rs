@@ -0,0 +1,208 @@
+use crate::{
+    data::{
+        SeedAttachment, SeedCampaign, SeedCharacter, SeedSession, SeedTable, SeedUser, SeedWorld,
+    },
+    tests::run_test,
+};
+use chrono::NaiveDate;
+use rocket::http::Status;
+use serde_json::json;
+use std::io::{Cursor, Read};
+use zip::ZipArchive;
+
+/// Read a single file out of a Zip archive
+fn read_file(archive: &[u8], name: &str) -> String {
+    let mut zip = ZipArchive::new(Cursor::new(archive.to_vec())).unwrap();
+    let mut contents = String::new();
+    zip.by_name(name)
+        .unwrap()
+        .read_to_string(&mut contents)
+        .unwrap();
+    contents
+}
+
+#[test]
+fn test_export_unknown_world() {
+    run_test()
+        .get("/worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b/export/site")
+        .has_status(Status::NotFound)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:worlds/problems/unknown_world_id",
+            "title": "The requested world ID was unknown",
+            "status": 404
+        }));
+}
+
+#[test]
+fn test_export_draft_world() {
+    let owner = SeedUser::default();
+    let world = SeedWorld {
+        world_id: uuid::Uuid::parse_str("d6c124d5-1060-48ef-aeb8-e591a8261c2b").unwrap(),
+        owner: owner.user_id,
+        status: "draft".to_owned(),
+        ..SeedWorld::default()
+    };
+
+    run_test()
+        .seed_many(&[&owner, &world])
+        .get("/worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b/export/site")
+        .has_status(Status::NotFound);
+}
+
+#[test]
+fn test_export_site() {
+    let owner = SeedUser::default();
+    let world = SeedWorld {
+        world_id: uuid::Uuid::parse_str("d6c124d5-1060-48ef-aeb8-e591a8261c2b").unwrap(),
+        name: "Test World".to_owned(),
+        description: "Start with [[Tavern Names]]".to_owned(),
+        url_slug: "test-world".to_owned(),
+        owner: owner.user_id,
+        ..SeedWorld::default()
+    };
+    let table = SeedTable {
+        world_id: world.world_id,
+        name: "Tavern Names".to_owned(),
+        description: "Part of [[Test World]]".to_owned(),
+        url_slug: "tavern-names".to_owned(),
+        entries: json!([
+          { "weight": 2, "text": "The Prancing Pony" }
+        ]),
+        ..SeedTable::default()
+    };
+    let draft_table = SeedTable {
+        world_id: world.world_id,
+        url_slug: "secret-table".to_owned(),
+        status: "draft".to_owned(),
+        ..SeedTable::default()
+    };
+    let campaign = SeedCampaign {
+        campaign_id: uuid::Uuid::parse_str("5a5e3cbd-5a8e-4f0f-a8b4-7d1a61e0a6a4").unwrap(),
+        world_id: world.world_id,
+        name: "Curse of the Crimson Throne".to_owned(),
+        ..SeedCampaign::default()
+    };
+    let session = SeedSession {
+        campaign_id: campaign.campaign_id,
+        session_date: NaiveDate::from_ymd(2020, 7, 1),
+        summary: "We visited [[tavern-names|an inn]]".to_owned(),
+        ..SeedSession::default()
+    };
+    let character = SeedCharacter {
+        character_id: uuid::Uuid::parse_str("2b9a6e4e-0f52-4c1c-9d0a-6c1f8e3d4a21").unwrap(),
+        world_id: world.world_id,
+        name: "Volo".to_owned(),
+        description: "![[map.png]]".to_owned(),
+        ..SeedCharacter::default()
+    };
+    let draft_character = SeedCharacter {
+        world_id: world.world_id,
+        status: "draft".to_owned(),
+        ..SeedCharacter::default()
+    };
+    let attachment = SeedAttachment {
+        attachment_id: uuid::Uuid::parse_str("8f1e0c3a-7b6d-4e2f-9a5c-1d2e3f4a5b6c").unwrap(),
+        world_id: world.world_id,
+        owner_id: owner.user_id,
+        filename: "map.png".to_owned(),
+        content_type: "image/png".to_owned(),
+        ..SeedAttachment::default()
+    }
+    .with_content(b"Map");
+
+    run_test()
+        .seed_many(&[
+            &owner,
+            &world,
+            &table,
+            &draft_table,
+            &campaign,
+            &session,
+            &character,
+            &draft_character,
+            &attachment,
+        ])
+        .seed_blob(
+            "attachments/8f1e0c3a-7b6d-4e2f-9a5c-1d2e3f4a5b6c",
+            b"Map",
+        )
+        .get("/worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b/export/site")
+        .has_status(Status::Ok)
+        .has_header("Content-Type", "application/zip")
+        .has_header(
+            "Content-Disposition",
+            "attachment; filename=\"test-world.zip\"",
+        )
+        .assert_binary_body(|body| {
+            let zip = ZipArchive::new(Cursor::new(body.to_vec())).unwrap();
+            let mut names: Vec<&str> = zip.file_names().collect();
+            names.sort();
+            assert_eq!(
+                names,
+                vec![
+                    "attachments/8f1e0c3a-7b6d-4e2f-9a5c-1d2e3f4a5b6c.png",
+                    "campaigns/5a5e3cbd-5a8e-4f0f-a8b4-7d1a61e0a6a4.html",
+                    "characters/2b9a6e4e-0f52-4c1c-9d0a-6c1f8e3d4a21.html",
+                    "index.html",
+                    "style.css",
+                    "tables/tavern-names.html",
+                ]
+            );
+
+            let index = read_file(body, "index.html");
+            assert!(index.contains("<h1>Test World</h1>"));
+            assert!(index.contains(
+                "<a href=\"tables/tavern-names.html\" rel=\"noopener noreferrer\">Tavern Names</a>"
+            ));
+            assert!(index.contains(
+                "<li><a href=\"campaigns/5a5e3cbd-5a8e-4f0f-a8b4-7d1a61e0a6a4.html\">Curse of the Crimson Throne</a></li>"
+            ));
+
+            let table = read_file(body, "tables/tavern-names.html");
+            assert!(table.contains("<tr><td>2</td><td>The Prancing Pony</td></tr>"));
+            assert!(table
+                .contains("<a href=\"../index.html\" rel=\"noopener noreferrer\">Test World</a>"));
+
+            let campaign = read_file(body, "campaigns/5a5e3cbd-5a8e-4f0f-a8b4-7d1a61e0a6a4.html");
+            assert!(campaign.contains("<h3>2020-07-01</h3>"));
+            assert!(campaign.contains(
+                "<a href=\"../tables/tavern-names.html\" rel=\"noopener noreferrer\">an inn</a>"
+            ));
+
+            let character = read_file(body, "characters/2b9a6e4e-0f52-4c1c-9d0a-6c1f8e3d4a21.html");
+            assert!(character.contains("<h1>Volo</h1>"));
+            assert!(character.contains(
+                "<img src=\"../attachments/8f1e0c3a-7b6d-4e2f-9a5c-1d2e3f4a5b6c.png\" alt=\"map.png\">"
+            ));
+
+            assert_eq!(
+                read_file(body, "attachments/8f1e0c3a-7b6d-4e2f-9a5c-1d2e3f4a5b6c.png"),
+                "Map"
+            );
+        });
+}
+
+#[test]
+fn test_export_site_is_deterministic() {
+    let owner = SeedUser::default();
+    let world = SeedWorld {
+        world_id: uuid::Uuid::parse_str("d6c124d5-1060-48ef-aeb8-e591a8261c2b").unwrap(),
+        owner: owner.user_id,
+        ..SeedWorld::default()
+    };
+    let table = SeedTable {
+        world_id: world.world_id,
+        ..SeedTable::default()
+    };
+
+    let mut first = vec![];
+    run_test()
+        .seed_many(&[&owner, &world, &table])
+        .get("/worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b/export/site")
+        .has_status(Status::Ok)
+        .assert_binary_body(|body| first = body.to_vec())
+        .get("/worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b/export/site")
+        .has_status(Status::Ok)
+        .assert_binary_body(|body| assert_eq!(body, &first[..]));
+}
@@ -0,0 +1,2 @@
+mod export_epub;
+mod export_site;
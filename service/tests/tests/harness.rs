@@ -28,6 +28,8 @@ pub struct Response {
     pub headers: HashMap<String, String>,
     /// The body
     pub body: String,
+    /// The raw bytes of the body, for responses that aren't text
+    pub body_bytes: Vec<u8>,
 }
 
 impl<'r> From<LocalResponse<'r>> for Response {
@@ -40,12 +42,14 @@ impl<'r> From<LocalResponse<'r>> for Response {
 
         let status = response.status();
 
-        let body = response.body_string().unwrap_or_else(|| "".to_owned());
+        let body_bytes = response.body_bytes().unwrap_or_default();
+        let body = String::from_utf8(body_bytes.clone()).unwrap_or_default();
 
         Self {
             status,
             headers,
             body,
+            body_bytes,
         }
     }
 }
@@ -63,6 +67,33 @@ pub fn run_test() -> TestHarness {
     }
 }
 
+/// The boundary between the parts of the multipart bodies built by `multipart`
+const MULTIPART_BOUNDARY: &str = "X-MULTIVERSE-BOUNDARY";
+
+/// The content type of the multipart bodies built by `multipart`
+pub const MULTIPART: &str = "multipart/form-data; boundary=X-MULTIVERSE-BOUNDARY";
+
+/// Build a `multipart/form-data` body containing a single file, for uploading with `MULTIPART` as
+/// the content type
+///
+/// # Parameters
+/// - `field` - The name of the form field to put the file in
+/// - `filename` - The name of the file
+/// - `content` - The contents of the file
+///
+/// # Returns
+/// The body
+pub fn multipart(field: &str, filename: &str, content: &[u8]) -> Vec<u8> {
+    let mut body = format!(
+        "--{}\r\nContent-Disposition: form-data; name=\"{}\"; filename=\"{}\"\r\nContent-Type: application/octet-stream\r\n\r\n",
+        MULTIPART_BOUNDARY, field, filename
+    )
+    .into_bytes();
+    body.extend_from_slice(content);
+    body.extend_from_slice(format!("\r\n--{}--\r\n", MULTIPART_BOUNDARY).as_bytes());
+    body
+}
+
 impl TestHarness {
     /// Add some seed data into the database being used for this test
     ///
@@ -94,6 +125,23 @@ impl TestHarness {
         self
     }
 
+    /// Add a blob into the blob store being used for this test
+    ///
+    /// # Parameters
+    /// - `key` - The key of the blob
+    /// - `data` - The data of the blob
+    ///
+    /// # Returns
+    /// Self, for chaining
+    pub fn seed_blob<K>(self, key: K, data: &[u8]) -> Self
+    where
+        K: Into<String>,
+    {
+        self.service.seed_blob(&key.into(), data);
+
+        self
+    }
+
     /// Authenticate the requests as the given username and password
     ///
     /// # Parameters
@@ -157,6 +205,56 @@ impl TestHarness {
         }
     }
 
+    /// Make a GET request to the service without reading the response body, for responses that
+    /// stream for as long as they are held open
+    ///
+    /// # Parameters
+    /// - `url` - The URL to make the request to
+    ///
+    /// # Returns
+    /// The response, which stays open until it is dropped
+    pub fn open_stream<S>(&self, url: S) -> LocalResponse
+    where
+        S: Into<String>,
+    {
+        let mut request = self.client.get(url.into());
+        if let Some(token) = &self.authentication_token {
+            request = request.header(Header::new("Authorization", format!("Bearer {}", token)));
+        }
+        request.dispatch()
+    }
+
+    /// Make a GET request to the service with an extra request header
+    ///
+    /// # Parameters
+    /// - `url` - The URL to make the request to
+    /// - `header` - The name of the header to send
+    /// - `value` - The value of the header to send
+    ///
+    /// # Returns
+    /// Self, for chaining
+    pub fn get_with_header<S, H, V>(self, url: S, header: H, value: V) -> Self
+    where
+        S: Into<String>,
+        H: Into<String>,
+        V: Into<String>,
+    {
+        let client = self.client;
+        let mut request = client
+            .get(url.into())
+            .header(Header::new(header.into(), value.into()));
+        if let Some(token) = &self.authentication_token {
+            request = request.header(Header::new("Authorization", format!("Bearer {}", token)));
+        }
+        let response = request.dispatch().into();
+
+        Self {
+            client,
+            last_response: Some(response),
+            ..self
+        }
+    }
+
     /// Make a POST request to the service
     ///
     /// # Parameters
@@ -186,6 +284,38 @@ impl TestHarness {
         }
     }
 
+    /// Make a POST request to the service with a body that isn't JSON
+    ///
+    /// # Parameters
+    /// - `url` - The URL to make the request to
+    /// - `content_type` - The content type of the body
+    /// - `body` - The raw bytes of the body to make the request with
+    ///
+    /// # Returns
+    /// Self, for chaining
+    pub fn post_raw<S, C, B>(self, url: S, content_type: C, body: B) -> Self
+    where
+        S: Into<String>,
+        C: Into<String>,
+        B: AsRef<[u8]>,
+    {
+        let client = self.client;
+        let mut request = client
+            .post(url.into())
+            .header(Header::new("Content-Type", content_type.into()))
+            .body(body);
+        if let Some(token) = &self.authentication_token {
+            request = request.header(Header::new("Authorization", format!("Bearer {}", token)));
+        }
+        let response = request.dispatch().into();
+
+        Self {
+            client,
+            last_response: Some(response),
+            ..self
+        }
+    }
+
     /// Make a PATCH request to the service
     ///
     /// # Parameters
@@ -215,6 +345,92 @@ impl TestHarness {
         }
     }
 
+    /// Make a PUT request to the service
+    ///
+    /// # Parameters
+    /// - `url` - The URL to make the request to
+    /// - `body` - The JSON Body to make the request with
+    ///
+    /// # Returns
+    /// Self, for chaining
+    pub fn put<S, B>(self, url: S, body: B) -> Self
+    where
+        S: Into<String>,
+        B: Into<Value>,
+    {
+        let client = self.client;
+        let mut request = client
+            .put(url.into())
+            .body(serde_json::to_string(&body.into()).unwrap());
+        if let Some(token) = &self.authentication_token {
+            request = request.header(Header::new("Authorization", format!("Bearer {}", token)));
+        }
+        let response = request.dispatch().into();
+
+        Self {
+            client,
+            last_response: Some(response),
+            ..self
+        }
+    }
+
+    /// Make a PUT request to the service with a body that isn't JSON
+    ///
+    /// # Parameters
+    /// - `url` - The URL to make the request to
+    /// - `content_type` - The content type of the body
+    /// - `body` - The raw bytes of the body to make the request with
+    ///
+    /// # Returns
+    /// Self, for chaining
+    pub fn put_raw<S, C, B>(self, url: S, content_type: C, body: B) -> Self
+    where
+        S: Into<String>,
+        C: Into<String>,
+        B: AsRef<[u8]>,
+    {
+        let client = self.client;
+        let mut request = client
+            .put(url.into())
+            .header(Header::new("Content-Type", content_type.into()))
+            .body(body);
+        if let Some(token) = &self.authentication_token {
+            request = request.header(Header::new("Authorization", format!("Bearer {}", token)));
+        }
+        let response = request.dispatch().into();
+
+        Self {
+            client,
+            last_response: Some(response),
+            ..self
+        }
+    }
+
+    /// Make a DELETE request to the service
+    ///
+    /// # Parameters
+    /// - `url` - The URL to make the request to
+    ///
+    /// # Returns
+    /// Self, for chaining
+    pub fn delete<S>(self, url: S) -> Self
+    where
+        S: Into<String>,
+    {
+        let client = self.client;
+        let mut request = client.delete(url.into());
+        if let Some(token) = &self.authentication_token {
+            request = request.header(Header::new("Authorization", format!("Bearer {}", token)));
+        }
+        let response = request.dispatch().into();
+
+        Self {
+            client,
+            last_response: Some(response),
+            ..self
+        }
+    }
+
     /// Assert that we have a response and that the response has the expected status code
     ///
     /// # Parameters
@@ -334,6 +550,22 @@ impl TestHarness {
         })
     }
 
+    /// Assert some details about the body, treating it as raw bytes
+    ///
+    /// # Parameters
+    /// - `f` - The function to assert the body with
+    ///
+    /// # Returns
+    /// Self, for chaining
+    pub fn assert_binary_body<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&[u8]),
+    {
+        self.assert_response(|response| {
+            f(&response.body_bytes);
+        })
+    }
+
     pub fn assert_database<F>(self, f: F) -> Self
     where
         F: FnOnce(postgres::Transaction),
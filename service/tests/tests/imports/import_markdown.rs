@@ -0,0 +1,245 @@
+use crate::{
+    data::{hash_password, SeedTable, SeedUser, SeedWorld},
+    tests::run_test,
+};
+use rocket::http::Status;
+use serde_json::{json, Value};
+use std::io::{Cursor, Write};
+use zip::{write::FileOptions, ZipWriter};
+
+/// Build a Zip archive containing the given files
+fn vault(files: &[(&str, &str)]) -> Vec<u8> {
+    let mut zip = ZipWriter::new(Cursor::new(vec![]));
+    for (path, contents) in files {
+        zip.start_file(*path, FileOptions::default()).unwrap();
+        zip.write_all(contents.as_bytes()).unwrap();
+    }
+    zip.finish().unwrap().into_inner()
+}
+
+#[test]
+fn test_import_unauthenticated() {
+    let world_owner = SeedUser::default();
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let url = format!("/worlds/{}/import/markdown", world.world_id);
+
+    run_test()
+        .seed_many(&[&world_owner, &world])
+        .post_raw(url, "application/zip", vault(&[]))
+        .has_status(Status::Forbidden)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/unauthorized",
+            "title": "An invalid access token was provided",
+            "status": 403
+        }));
+}
+
+#[test]
+fn test_import_invalid_archive() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let url = format!("/worlds/{}/import/markdown", world.world_id);
+
+    run_test()
+        .seed_many(&[&user, &world])
+        .authenticate("testuser", "password")
+        .post_raw(url, "application/zip", "Not a zip file")
+        .has_status(Status::UnprocessableEntity)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:imports/problems/invalid_archive",
+            "title": "The uploaded archive could not be read",
+            "status": 422
+        }));
+}
+
+#[test]
+fn test_import_archive_too_large_once_decompressed() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let url = format!("/worlds/{}/import/markdown", world.world_id);
+
+    // Each note compresses to almost nothing, but together they decompress to more than can be read
+    let note = "a".repeat(1024 * 1024);
+    let names: Vec<String> = (0..25).map(|i| format!("Note {}.md", i)).collect();
+    let files: Vec<(&str, &str)> = names
+        .iter()
+        .map(|name| (name.as_str(), note.as_str()))
+        .collect();
+
+    run_test()
+        .seed_many(&[&user, &world])
+        .authenticate("testuser", "password")
+        .post_raw(url, "application/zip", vault(&files))
+        .has_status(Status::UnprocessableEntity)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/validation_error",
+            "title": "A validation error occurred",
+            "status": 422,
+            "fields": {
+              "archive": {
+                "type": "tag:multiverse,2020:imports/problems/validation_error/too_large",
+                "title": "The uploaded archive was too large once decompressed"
+              }
+            }
+        }));
+}
+
+#[test]
+fn test_import_vault() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        world_id: uuid::Uuid::parse_str("d6c124d5-1060-48ef-aeb8-e591a8261c2b").unwrap(),
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let existing = SeedTable {
+        world_id: world.world_id,
+        name: "Weather".to_owned(),
+        url_slug: "weather".to_owned(),
+        ..SeedTable::default()
+    };
+
+    let archive = vault(&[
+        (
+            "Tables/Tavern Names.md",
+            "---\nstatus: published\ncolour: red\n---\nNames for taverns\n\n- The Prancing Pony\n- The Green Dragon\n",
+        ),
+        (
+            "Campaigns/Dragon Heist.md",
+            "We drank at [[tavern names|the inn]] with [[Volo]] and checked the [[weather]]",
+        ),
+        ("Characters/Volo.md", "A famous author"),
+        ("map.png", "PNG"),
+        (".obsidian/workspace.json", "{}"),
+    ]);
+
+    run_test()
+        .seed_many(&[&user, &world, &existing])
+        .authenticate("testuser", "password")
+        .post_raw(
+            "/worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b/import/markdown",
+            "application/zip",
+            archive,
+        )
+        .has_status(Status::Ok)
+        .has_header("Content-Type", "application/json")
+        .has_header_regex(
+            "Link",
+            r#"</worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b/tables/tavern-names>; rel="item"; anchor="\#/imported/1""#,
+        )
+        .has_json_body(json!({
+          "imported": [
+            { "path": "Campaigns/Dragon Heist.md", "kind": "campaign", "name": "Dragon Heist" },
+            { "path": "Tables/Tavern Names.md", "kind": "table", "name": "Tavern Names" }
+          ],
+          "unmapped": [
+            {
+              "path": "map.png",
+              "type": "tag:multiverse,2020:imports/problems/unsupported_file",
+              "title": "The file was not a Markdown note"
+            },
+            {
+              "path": "Characters/Volo.md",
+              "type": "tag:multiverse,2020:imports/problems/unknown_category",
+              "title": "The folder of the note did not match any kind of content"
+            }
+          ],
+          "unmapped_fields": [
+            { "path": "Tables/Tavern Names.md", "field": "colour" }
+          ],
+          "unresolved_links": [
+            { "path": "Campaigns/Dragon Heist.md", "link": "Volo" }
+          ]
+        }))
+        .assert_database(|mut tx| {
+            let campaign = tx
+                .query_one("SELECT description, status FROM campaigns", &[])
+                .unwrap();
+            assert_eq!(
+                campaign.get::<_, String>(0),
+                "We drank at [[Tavern Names|the inn]] with [[Volo]] and checked the [[Weather|weather]]"
+            );
+            assert_eq!(campaign.get::<_, String>(1), "draft");
+
+            let table = tx
+                .query_one(
+                    "SELECT description, entries, status FROM random_tables WHERE url_slug = 'tavern-names'",
+                    &[],
+                )
+                .unwrap();
+            assert_eq!(table.get::<_, String>(0), "Names for taverns");
+            assert_eq!(
+                table.get::<_, Value>(1),
+                json!([
+                  { "weight": 1, "text": "The Prancing Pony" },
+                  { "weight": 1, "text": "The Green Dragon" }
+                ])
+            );
+            assert_eq!(table.get::<_, String>(2), "published");
+        });
+}
+
+#[test]
+fn test_import_duplicate_url_slug() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        world_id: uuid::Uuid::parse_str("d6c124d5-1060-48ef-aeb8-e591a8261c2b").unwrap(),
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let existing = SeedTable {
+        world_id: world.world_id,
+        url_slug: "tavern-names".to_owned(),
+        ..SeedTable::default()
+    };
+
+    run_test()
+        .seed_many(&[&user, &world, &existing])
+        .authenticate("testuser", "password")
+        .post_raw(
+            "/worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b/import/markdown",
+            "application/zip",
+            vault(&[("Tables/Tavern Names.md", "- The Prancing Pony")]),
+        )
+        .has_status(Status::Ok)
+        .has_json_body(json!({
+          "imported": [],
+          "unmapped": [
+            {
+              "path": "Tables/Tavern Names.md",
+              "type": "tag:multiverse,2020:imports/problems/duplicate_url_slug",
+              "title": "The URL Slug was already present for this world"
+            }
+          ],
+          "unmapped_fields": [],
+          "unresolved_links": []
+        }));
+}
@@ -0,0 +1 @@
+mod import_markdown;
@@ -0,0 +1,246 @@
+use crate::{
+    data::{hash_password, SeedInvitation, SeedUser, SeedWorld, ABC_TOKEN_HASH},
+    tests::run_test,
+};
+use chrono::{Duration, Utc};
+use rocket::http::Status;
+use serde_json::json;
+
+#[test]
+fn test_accept_unauthenticated() {
+    let world_owner = SeedUser::default();
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let invitation = SeedInvitation {
+        world_id: world.world_id,
+        token_hash: ABC_TOKEN_HASH.to_owned(),
+        ..SeedInvitation::default()
+    };
+
+    run_test()
+        .seed(&world_owner)
+        .seed(&world)
+        .seed(&invitation)
+        .post("/invitations/abc/accept", json!({}))
+        .has_status(Status::Forbidden)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/unauthorized",
+            "title": "An invalid access token was provided",
+            "status": 403
+        }));
+}
+
+#[test]
+fn test_accept_unknown_invitation() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+
+    run_test()
+        .seed(&user)
+        .authenticate("testuser", "password")
+        .post("/invitations/abc/accept", json!({}))
+        .has_status(Status::NotFound)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:invitations/problems/unknown_invitation",
+            "title": "The requested invitation was unknown",
+            "status": 404
+        }));
+}
+
+#[test]
+fn test_accept_expired_invitation() {
+    let world_owner = SeedUser::default();
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let invitation = SeedInvitation {
+        world_id: world.world_id,
+        token_hash: ABC_TOKEN_HASH.to_owned(),
+        expires: Some(Utc::now() - Duration::hours(1)),
+        ..SeedInvitation::default()
+    };
+
+    run_test()
+        .seed(&world_owner)
+        .seed(&user)
+        .seed(&world)
+        .seed(&invitation)
+        .authenticate("testuser", "password")
+        .post("/invitations/abc/accept", json!({}))
+        .has_status(Status::Gone)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:invitations/problems/expired_invitation",
+            "title": "The requested invitation has expired",
+            "status": 410
+        }));
+}
+
+#[test]
+fn test_accept_used_up_invitation() {
+    let world_owner = SeedUser::default();
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let invitation = SeedInvitation {
+        world_id: world.world_id,
+        token_hash: ABC_TOKEN_HASH.to_owned(),
+        max_uses: Some(2),
+        uses: 2,
+        ..SeedInvitation::default()
+    };
+
+    run_test()
+        .seed(&world_owner)
+        .seed(&user)
+        .seed(&world)
+        .seed(&invitation)
+        .authenticate("testuser", "password")
+        .post("/invitations/abc/accept", json!({}))
+        .has_status(Status::Gone)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:invitations/problems/expired_invitation",
+            "title": "The requested invitation has expired",
+            "status": 410
+        }));
+}
+
+#[test]
+fn test_accept_own_world() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let invitation = SeedInvitation {
+        world_id: world.world_id,
+        token_hash: ABC_TOKEN_HASH.to_owned(),
+        ..SeedInvitation::default()
+    };
+
+    run_test()
+        .seed(&user)
+        .seed(&world)
+        .seed(&invitation)
+        .authenticate("testuser", "password")
+        .post("/invitations/abc/accept", json!({}))
+        .has_status(Status::UnprocessableEntity)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:invitations/problems/already_member",
+            "title": "The user is already a member of the world",
+            "status": 422
+        }));
+}
+
+#[test]
+fn test_accept_success() {
+    let world_owner = SeedUser::default();
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        world_id: uuid::Uuid::parse_str("d6c124d5-1060-48ef-aeb8-e591a8261c2b").unwrap(),
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let invitation = SeedInvitation {
+        world_id: world.world_id,
+        token_hash: ABC_TOKEN_HASH.to_owned(),
+        role: "editor".to_owned(),
+        max_uses: Some(2),
+        ..SeedInvitation::default()
+    };
+
+    run_test()
+        .seed(&world_owner)
+        .seed(&user)
+        .seed(&world)
+        .seed(&invitation)
+        .authenticate("testuser", "password")
+        .post("/invitations/abc/accept", json!({}))
+        .has_status(Status::Ok)
+        .has_header("Content-Type", "application/json")
+        .has_header(
+            "Link",
+            r#"</worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b>; rel="related""#,
+        )
+        .has_json_body(json!({
+            "world": "/worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b",
+            "role": "editor"
+        }))
+        .assert_database(|mut tx| {
+            let role: String = tx
+                .query_one("SELECT role FROM world_members", &[])
+                .unwrap()
+                .get(0);
+            assert_eq!(role, "editor");
+
+            let uses: i32 = tx
+                .query_one("SELECT uses FROM invitations", &[])
+                .unwrap()
+                .get(0);
+            assert_eq!(uses, 1);
+        });
+}
+
+#[test]
+fn test_accept_twice() {
+    let world_owner = SeedUser::default();
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let invitation = SeedInvitation {
+        world_id: world.world_id,
+        token_hash: ABC_TOKEN_HASH.to_owned(),
+        ..SeedInvitation::default()
+    };
+
+    run_test()
+        .seed(&world_owner)
+        .seed(&user)
+        .seed(&world)
+        .seed(&invitation)
+        .authenticate("testuser", "password")
+        .post("/invitations/abc/accept", json!({}))
+        .has_status(Status::Ok)
+        .post("/invitations/abc/accept", json!({}))
+        .has_status(Status::UnprocessableEntity)
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:invitations/problems/already_member",
+            "title": "The user is already a member of the world",
+            "status": 422
+        }));
+}
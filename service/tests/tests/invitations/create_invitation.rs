@@ -0,0 +1,189 @@
+use crate::{
+    data::{hash_password, SeedUser, SeedWorld},
+    tests::run_test,
+};
+use rocket::http::Status;
+use serde_json::json;
+
+#[test]
+fn test_create_unauthenticated() {
+    let world_owner = SeedUser::default();
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let url = format!("/worlds/{}/invitations", world.world_id);
+
+    run_test()
+        .seed(&world_owner)
+        .seed(&world)
+        .post(url, json!({ "role": "viewer" }))
+        .has_status(Status::Forbidden)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/unauthorized",
+            "title": "An invalid access token was provided",
+            "status": 403
+        }));
+}
+
+#[test]
+fn test_create_wrong_user() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let owner = SeedUser::default();
+    let world = SeedWorld {
+        owner: owner.user_id,
+        ..SeedWorld::default()
+    };
+    let url = format!("/worlds/{}/invitations", world.world_id);
+
+    run_test()
+        .seed(&user)
+        .seed(&owner)
+        .seed(&world)
+        .authenticate("testuser", "password")
+        .post(url, json!({ "role": "viewer" }))
+        .has_status(Status::Forbidden)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/unauthorized",
+            "title": "An invalid access token was provided",
+            "status": 403
+        }));
+}
+
+#[test]
+fn test_create_empty_body() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let url = format!("/worlds/{}/invitations", world.world_id);
+
+    run_test()
+        .seed(&user)
+        .seed(&world)
+        .authenticate("testuser", "password")
+        .post(url, json!({}))
+        .has_status(Status::UnprocessableEntity)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/validation_error",
+            "title": "A validation error occurred",
+            "status": 422,
+            "fields": {
+              "role": {
+                "type": "tag:multiverse,2020:problems/validation_error/missing",
+                "title": "The required field was missing"
+              }
+            }
+        }));
+}
+
+#[test]
+fn test_create_invalid_values() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let url = format!("/worlds/{}/invitations", world.world_id);
+
+    run_test()
+        .seed(&user)
+        .seed(&world)
+        .authenticate("testuser", "password")
+        .post(
+            url,
+            json!({
+              "role": "owner",
+              "expires": "2000-01-01T00:00:00Z",
+              "max_uses": 0
+            }),
+        )
+        .has_status(Status::UnprocessableEntity)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/validation_error",
+            "title": "A validation error occurred",
+            "status": 422,
+            "fields": {
+              "role": {
+                "type": "tag:multiverse,2020:invitations/problems/validation_error/invalid_role",
+                "title": "The role was not a known role"
+              },
+              "expires": {
+                "type": "tag:multiverse,2020:invitations/problems/validation_error/invalid_expiry",
+                "title": "The expiry must be a timestamp in the future"
+              },
+              "max_uses": {
+                "type": "tag:multiverse,2020:invitations/problems/validation_error/invalid_max_uses",
+                "title": "The maximum number of uses must be a positive number"
+              }
+            }
+        }));
+}
+
+#[test]
+fn test_create_success() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        world_id: uuid::Uuid::parse_str("d6c124d5-1060-48ef-aeb8-e591a8261c2b").unwrap(),
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+
+    run_test()
+        .seed(&user)
+        .seed(&world)
+        .authenticate("testuser", "password")
+        .post(
+            "/worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b/invitations",
+            json!({
+              "role": "editor",
+              "expires": "2100-01-01T00:00:00Z",
+              "max_uses": 5
+            }),
+        )
+        .has_status(Status::Ok)
+        .has_header("Content-Type", "application/json")
+        .has_header_regex(
+            "Link",
+            r#"</worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b/invitations/[0-9a-f-]+>; rel="self""#,
+        )
+        .has_header_regex(
+            "Link",
+            r#"</worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b>; rel="related""#,
+        )
+        .assert_json_body(|body| {
+            assert_eq!(body["role"], json!("editor"));
+            assert_eq!(body["expires"], json!("2100-01-01T00:00:00Z"));
+            assert_eq!(body["max_uses"], json!(5));
+            assert_eq!(body["uses"], json!(0));
+            assert_eq!(body["token"].as_str().unwrap().len(), 64);
+        })
+        .assert_database(|mut tx| {
+            let count: i64 = tx
+                .query_one("SELECT COUNT(*) FROM invitations", &[])
+                .unwrap()
+                .get(0);
+            assert_eq!(count, 1);
+        });
+}
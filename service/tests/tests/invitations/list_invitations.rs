@@ -0,0 +1,108 @@
+use crate::{
+    data::{hash_password, SeedInvitation, SeedUser, SeedWorld},
+    tests::run_test,
+};
+use rocket::http::Status;
+use serde_json::json;
+
+#[test]
+fn test_list_unauthenticated() {
+    let world_owner = SeedUser::default();
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let url = format!("/worlds/{}/invitations", world.world_id);
+
+    run_test()
+        .seed(&world_owner)
+        .seed(&world)
+        .get(url)
+        .has_status(Status::Forbidden)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/unauthorized",
+            "title": "An invalid access token was provided",
+            "status": 403
+        }));
+}
+
+#[test]
+fn test_list_no_invitations() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let url = format!("/worlds/{}/invitations", world.world_id);
+
+    run_test()
+        .seed(&user)
+        .seed(&world)
+        .authenticate("testuser", "password")
+        .get(url)
+        .has_status(Status::Ok)
+        .has_header("Content-Type", "application/json")
+        .has_json_body(json!({
+            "entries": [],
+            "pagination": {
+                "offset": 0,
+                "count": 0,
+                "total": 0
+            }
+        }));
+}
+
+#[test]
+fn test_list_invitations() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        world_id: uuid::Uuid::parse_str("d6c124d5-1060-48ef-aeb8-e591a8261c2b").unwrap(),
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let invitation = SeedInvitation {
+        invitation_id: uuid::Uuid::parse_str("4b5e2b6d-97b3-4b55-9a70-b8d9e62db1b8").unwrap(),
+        world_id: world.world_id,
+        role: "editor".to_owned(),
+        max_uses: Some(3),
+        uses: 1,
+        ..SeedInvitation::default()
+    };
+
+    run_test()
+        .seed(&user)
+        .seed(&world)
+        .seed(&invitation)
+        .authenticate("testuser", "password")
+        .get("/worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b/invitations")
+        .has_status(Status::Ok)
+        .has_header("Content-Type", "application/json")
+        .has_header_regex(
+            "Link",
+            r#"</worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b/invitations/4b5e2b6d-97b3-4b55-9a70-b8d9e62db1b8>; rel="item"; anchor="\#/entries/0""#,
+        )
+        .has_json_body(json!({
+            "entries": [
+                {
+                    "role": "editor",
+                    "expires": null,
+                    "max_uses": 3,
+                    "uses": 1
+                }
+            ],
+            "pagination": {
+                "offset": 0,
+                "count": 1,
+                "total": 1
+            }
+        }));
+}
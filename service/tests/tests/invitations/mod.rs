@@ -0,0 +1,4 @@
+mod accept_invitation;
+mod create_invitation;
+mod list_invitations;
+mod revoke_invitation;
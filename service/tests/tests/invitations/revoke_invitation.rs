@@ -0,0 +1,102 @@
+use crate::{
+    data::{hash_password, SeedInvitation, SeedUser, SeedWorld},
+    tests::run_test,
+};
+use rocket::http::Status;
+use serde_json::json;
+
+#[test]
+fn test_revoke_unauthenticated() {
+    let world_owner = SeedUser::default();
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let invitation = SeedInvitation {
+        world_id: world.world_id,
+        ..SeedInvitation::default()
+    };
+    let url = format!(
+        "/worlds/{}/invitations/{}",
+        world.world_id, invitation.invitation_id
+    );
+
+    run_test()
+        .seed(&world_owner)
+        .seed(&world)
+        .seed(&invitation)
+        .delete(url)
+        .has_status(Status::Forbidden)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/unauthorized",
+            "title": "An invalid access token was provided",
+            "status": 403
+        }));
+}
+
+#[test]
+fn test_revoke_unknown_invitation() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let url = format!(
+        "/worlds/{}/invitations/4b5e2b6d-97b3-4b55-9a70-b8d9e62db1b8",
+        world.world_id
+    );
+
+    run_test()
+        .seed(&user)
+        .seed(&world)
+        .authenticate("testuser", "password")
+        .delete(url)
+        .has_status(Status::NotFound)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:invitations/problems/unknown_invitation",
+            "title": "The requested invitation was unknown",
+            "status": 404
+        }));
+}
+
+#[test]
+fn test_revoke_success() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let invitation = SeedInvitation {
+        world_id: world.world_id,
+        ..SeedInvitation::default()
+    };
+    let url = format!(
+        "/worlds/{}/invitations/{}",
+        world.world_id, invitation.invitation_id
+    );
+
+    run_test()
+        .seed(&user)
+        .seed(&world)
+        .seed(&invitation)
+        .authenticate("testuser", "password")
+        .delete(url)
+        .has_status(Status::NoContent)
+        .assert_database(|mut tx| {
+            let count: i64 = tx
+                .query_one("SELECT COUNT(*) FROM invitations", &[])
+                .unwrap()
+                .get(0);
+            assert_eq!(count, 0);
+        });
+}
@@ -1,7 +1,23 @@
+mod activity;
+mod attachments;
 mod authentication;
+mod autocomplete;
+mod campaigns;
+mod characters;
+mod comments;
+mod events;
+mod exports;
 mod harness;
 mod health;
+mod imports;
+mod invitations;
+mod moderation;
+mod notifications;
+mod quotas;
+mod tables;
+mod templates;
 mod users;
+mod webhooks;
 mod worlds;
 
 pub use harness::*;
@@ -0,0 +1,215 @@
+use crate::{
+    data::{hash_password, SeedComment, SeedUser, SeedWorld},
+    tests::run_test,
+};
+use rocket::http::Status;
+use serde_json::json;
+
+#[test]
+fn test_report_unauthenticated() {
+    run_test()
+        .post("/reports", json!({}))
+        .has_status(Status::Forbidden)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/unauthorized",
+            "title": "An invalid access token was provided",
+            "status": 403
+        }));
+}
+
+#[test]
+fn test_report_empty_body() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+
+    run_test()
+        .seed(&user)
+        .authenticate("testuser", "password")
+        .post("/reports", json!({}))
+        .has_status(Status::UnprocessableEntity)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/validation_error",
+            "title": "A validation error occurred",
+            "status": 422,
+            "fields": {
+              "reason": {
+                "type": "tag:multiverse,2020:problems/validation_error/missing",
+                "title": "The required field was missing"
+              },
+              "target": {
+                "type": "tag:multiverse,2020:problems/validation_error/missing",
+                "title": "The required field was missing"
+              }
+            }
+        }));
+}
+
+#[test]
+fn test_report_invalid_target() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+
+    run_test()
+        .seed(&user)
+        .authenticate("testuser", "password")
+        .post(
+            "/reports",
+            json!({
+              "target": "/tables/tavern-names",
+              "reason": "Spam"
+            }),
+        )
+        .has_status(Status::UnprocessableEntity)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/validation_error",
+            "title": "A validation error occurred",
+            "status": 422,
+            "fields": {
+              "target": {
+                "type": "tag:multiverse,2020:moderation/problems/validation_error/invalid_target",
+                "title": "The target must be a link to a world, comment or user"
+              }
+            }
+        }));
+}
+
+#[test]
+fn test_report_unknown_target() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+
+    run_test()
+        .seed(&user)
+        .authenticate("testuser", "password")
+        .post(
+            "/reports",
+            json!({
+              "target": format!("/worlds/{}", uuid::Uuid::new_v4()),
+              "reason": "Spam"
+            }),
+        )
+        .has_status(Status::UnprocessableEntity)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/validation_error",
+            "title": "A validation error occurred",
+            "status": 422,
+            "fields": {
+              "target": {
+                "type": "tag:multiverse,2020:moderation/problems/validation_error/unknown_target",
+                "title": "The target was unknown"
+              }
+            }
+        }));
+}
+
+#[test]
+fn test_report_world() {
+    let user = SeedUser {
+        display_name: "Test User".to_owned(),
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let owner = SeedUser::default();
+    let world = SeedWorld {
+        owner: owner.user_id,
+        ..SeedWorld::default()
+    };
+
+    run_test()
+        .seed_many(&[&user, &owner, &world])
+        .authenticate("testuser", "password")
+        .post(
+            "/reports",
+            json!({
+              "target": format!("/worlds/{}", world.world_id),
+              "reason": "Offensive content"
+            }),
+        )
+        .has_status(Status::Ok)
+        .has_header("Content-Type", "application/json")
+        .has_header("Cache-Control", "private, no-cache")
+        .has_header_regex(
+            "Link",
+            r#"</reports/[0-9a-f]{8}\b-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-\b[0-9a-f]{12}>; rel="self""#,
+        )
+        .has_header_regex(
+            "Link",
+            format!(
+                r#"</users/{}>; rel="author"; title="Test User""#,
+                user.user_id
+            ),
+        )
+        .has_header_regex(
+            "Link",
+            format!(r#"</worlds/{}>; rel="related""#, world.world_id),
+        )
+        .assert_json_body(|body| {
+            assert_eq!(body["target"], json!(format!("/worlds/{}", world.world_id)));
+            assert_eq!(body["reason"], json!("Offensive content"));
+            assert_eq!(body["status"], json!("open"));
+        })
+        .assert_database(|mut tx| {
+            let rows = tx
+                .query(
+                    "SELECT reporter_id, target_type, target_id, status FROM reports",
+                    &[],
+                )
+                .unwrap();
+            assert_eq!(rows.len(), 1);
+            assert_eq!(rows[0].get::<_, uuid::Uuid>(0), user.user_id);
+            assert_eq!(rows[0].get::<_, String>(1), "world");
+            assert_eq!(rows[0].get::<_, uuid::Uuid>(2), world.world_id);
+            assert_eq!(rows[0].get::<_, String>(3), "open");
+        });
+}
+
+#[test]
+fn test_report_comment() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let comment = SeedComment {
+        world_id: world.world_id,
+        author_id: user.user_id,
+        ..SeedComment::default()
+    };
+
+    run_test()
+        .seed_many(&[&user, &world, &comment])
+        .authenticate("testuser", "password")
+        .post(
+            "/reports",
+            json!({
+              "target": format!("/comments/{}", comment.comment_id),
+              "reason": "Spam"
+            }),
+        )
+        .has_status(Status::Ok)
+        .assert_json_body(|body| {
+            assert_eq!(
+                body["target"],
+                json!(format!("/comments/{}", comment.comment_id))
+            );
+            assert_eq!(body["status"], json!("open"));
+        });
+}
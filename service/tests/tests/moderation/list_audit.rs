@@ -0,0 +1,112 @@
+use crate::{
+    data::{hash_password, SeedReport, SeedUser, SeedWorld},
+    tests::run_test,
+};
+use rocket::http::Status;
+use serde_json::json;
+use uritemplate::UriTemplate;
+
+#[test]
+fn test_audit_not_moderator() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+
+    run_test()
+        .seed(&user)
+        .authenticate("testuser", "password")
+        .get("/moderation/audit")
+        .has_status(Status::Forbidden)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/unauthorized",
+            "title": "An invalid access token was provided",
+            "status": 403
+        }));
+}
+
+#[test]
+fn test_audit_suspended_moderator() {
+    let admin = SeedUser {
+        username: "admin".to_owned(),
+        password: hash_password("password"),
+        admin: true,
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: admin.user_id,
+        ..SeedWorld::default()
+    };
+    let report = SeedReport {
+        reporter_id: admin.user_id,
+        target_id: world.world_id,
+        ..SeedReport::default()
+    };
+
+    let url = UriTemplate::new("/reports/{id}/actions")
+        .set("id", report.report_id.to_string())
+        .build();
+
+    // Suspending the owner of the world suspends the moderator themselves, after which their existing
+    // token is no longer accepted
+    run_test()
+        .seed_many(&[&admin, &world, &report])
+        .authenticate("admin", "password")
+        .post(url, json!({ "action": "suspend" }))
+        .has_status(Status::Ok)
+        .get("/moderation/audit")
+        .has_status(Status::Forbidden);
+}
+
+#[test]
+fn test_audit_records_actions() {
+    let admin = SeedUser {
+        display_name: "Moderator".to_owned(),
+        username: "admin".to_owned(),
+        password: hash_password("password"),
+        admin: true,
+        ..SeedUser::default()
+    };
+    let world = SeedWorld::default();
+    let report = SeedReport {
+        reporter_id: admin.user_id,
+        target_id: world.world_id,
+        ..SeedReport::default()
+    };
+
+    let url = UriTemplate::new("/reports/{id}/actions")
+        .set("id", report.report_id.to_string())
+        .build();
+
+    run_test()
+        .seed_many(&[&admin, &world, &report])
+        .authenticate("admin", "password")
+        .post(url, json!({ "action": "hide", "note": "Offensive" }))
+        .has_status(Status::Ok)
+        .get("/moderation/audit")
+        .has_status(Status::Ok)
+        .has_header("Content-Type", "application/json")
+        .has_header("Cache-Control", "private, no-cache")
+        .has_header_regex(
+            "Link",
+            format!(
+                r#"</users/{}>; rel="author"; title="Moderator"; anchor="\#/entries/0""#,
+                admin.user_id
+            ),
+        )
+        .assert_json_body(|body| {
+            assert_eq!(body["pagination"]["total"], json!(1));
+            assert_eq!(body["entries"][0]["action"], json!("hide"));
+            assert_eq!(
+                body["entries"][0]["target"],
+                json!(format!("/worlds/{}", world.world_id))
+            );
+            assert_eq!(
+                body["entries"][0]["report"],
+                json!(format!("/reports/{}", report.report_id))
+            );
+            assert_eq!(body["entries"][0]["note"], json!("Offensive"));
+        });
+}
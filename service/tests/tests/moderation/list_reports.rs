@@ -0,0 +1,176 @@
+use crate::{
+    data::{hash_password, SeedReport, SeedUser, SeedWorld},
+    tests::run_test,
+};
+use chrono::{DateTime, Utc};
+use rocket::http::Status;
+use serde_json::json;
+
+#[test]
+fn test_list_not_moderator() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+
+    run_test()
+        .seed(&user)
+        .authenticate("testuser", "password")
+        .get("/reports")
+        .has_status(Status::Forbidden)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/unauthorized",
+            "title": "An invalid access token was provided",
+            "status": 403
+        }));
+}
+
+#[test]
+fn test_list_invalid_status() {
+    let admin = SeedUser {
+        username: "admin".to_owned(),
+        password: hash_password("password"),
+        admin: true,
+        ..SeedUser::default()
+    };
+
+    run_test()
+        .seed(&admin)
+        .authenticate("admin", "password")
+        .get("/reports?status=unknown")
+        .has_status(Status::UnprocessableEntity)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/validation_error",
+            "title": "A validation error occurred",
+            "status": 422,
+            "fields": {
+              "status": {
+                "type": "tag:multiverse,2020:moderation/problems/validation_error/invalid_status",
+                "title": "The status was not a known status"
+              }
+            }
+        }));
+}
+
+#[test]
+fn test_list_open_reports() {
+    let admin = SeedUser {
+        username: "admin".to_owned(),
+        password: hash_password("password"),
+        admin: true,
+        ..SeedUser::default()
+    };
+    let reporter = SeedUser {
+        display_name: "Reporter".to_owned(),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: reporter.user_id,
+        ..SeedWorld::default()
+    };
+    let older = SeedReport {
+        reporter_id: reporter.user_id,
+        target_id: world.world_id,
+        reason: "Older".to_owned(),
+        created: "2020-06-01T12:00:00Z".parse::<DateTime<Utc>>().unwrap(),
+        updated: "2020-06-01T12:00:00Z".parse::<DateTime<Utc>>().unwrap(),
+        ..SeedReport::default()
+    };
+    let newer = SeedReport {
+        reporter_id: reporter.user_id,
+        target_type: "user".to_owned(),
+        target_id: reporter.user_id,
+        reason: "Newer".to_owned(),
+        created: "2020-06-02T12:00:00Z".parse::<DateTime<Utc>>().unwrap(),
+        updated: "2020-06-02T12:00:00Z".parse::<DateTime<Utc>>().unwrap(),
+        ..SeedReport::default()
+    };
+    let dismissed = SeedReport {
+        reporter_id: reporter.user_id,
+        target_id: world.world_id,
+        status: "dismissed".to_owned(),
+        ..SeedReport::default()
+    };
+
+    run_test()
+        .seed_many(&[&admin, &reporter, &world, &older, &newer, &dismissed])
+        .authenticate("admin", "password")
+        .get("/reports")
+        .has_status(Status::Ok)
+        .has_header("Content-Type", "application/json")
+        .has_header("Cache-Control", "private, no-cache")
+        .has_header_regex(
+            "Link",
+            format!(
+                r#"</reports/{}>; rel="item"; anchor="\#/entries/0""#,
+                older.report_id
+            ),
+        )
+        .has_header_regex(
+            "Link",
+            format!(
+                r#"</users/{}>; rel="author"; title="Reporter"; anchor="\#/entries/1""#,
+                reporter.user_id
+            ),
+        )
+        .has_json_body(json!({
+            "entries": [
+                {
+                    "target": format!("/worlds/{}", world.world_id),
+                    "reason": "Older",
+                    "status": "open",
+                    "created": "2020-06-01T12:00:00Z",
+                    "updated": "2020-06-01T12:00:00Z"
+                },
+                {
+                    "target": format!("/users/{}", reporter.user_id),
+                    "reason": "Newer",
+                    "status": "open",
+                    "created": "2020-06-02T12:00:00Z",
+                    "updated": "2020-06-02T12:00:00Z"
+                }
+            ],
+            "pagination": {
+                "offset": 0,
+                "count": 10,
+                "total": 2
+            }
+        }));
+}
+
+#[test]
+fn test_list_dismissed_reports() {
+    let admin = SeedUser {
+        username: "admin".to_owned(),
+        password: hash_password("password"),
+        admin: true,
+        ..SeedUser::default()
+    };
+    let world = SeedWorld::default();
+    let open = SeedReport {
+        reporter_id: admin.user_id,
+        target_id: world.world_id,
+        ..SeedReport::default()
+    };
+    let dismissed = SeedReport {
+        reporter_id: admin.user_id,
+        target_id: world.world_id,
+        reason: "Dismissed".to_owned(),
+        status: "dismissed".to_owned(),
+        ..SeedReport::default()
+    };
+
+    run_test()
+        .seed_many(&[&admin, &world, &open, &dismissed])
+        .authenticate("admin", "password")
+        .get("/reports?status=dismissed")
+        .has_status(Status::Ok)
+        .assert_json_body(|body| {
+            assert_eq!(body["pagination"]["total"], json!(1));
+            assert_eq!(body["entries"][0]["reason"], json!("Dismissed"));
+            assert_eq!(body["entries"][0]["status"], json!("dismissed"));
+        });
+}
@@ -0,0 +1,4 @@
+mod create_report;
+mod list_audit;
+mod list_reports;
+mod moderate_report;
@@ -0,0 +1,446 @@
+use crate::{
+    data::{hash_password, SeedComment, SeedReport, SeedUser, SeedWorld},
+    tests::run_test,
+};
+use rocket::http::Status;
+use serde_json::json;
+use uritemplate::UriTemplate;
+
+#[test]
+fn test_moderate_not_moderator() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld::default();
+    let report = SeedReport {
+        reporter_id: user.user_id,
+        target_id: world.world_id,
+        ..SeedReport::default()
+    };
+
+    let url = UriTemplate::new("/reports/{id}/actions")
+        .set("id", report.report_id.to_string())
+        .build();
+
+    run_test()
+        .seed_many(&[&user, &world, &report])
+        .authenticate("testuser", "password")
+        .post(url, json!({ "action": "dismiss" }))
+        .has_status(Status::Forbidden)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/unauthorized",
+            "title": "An invalid access token was provided",
+            "status": 403
+        }));
+}
+
+#[test]
+fn test_moderate_unknown_report() {
+    let admin = SeedUser {
+        username: "admin".to_owned(),
+        password: hash_password("password"),
+        admin: true,
+        ..SeedUser::default()
+    };
+
+    let url = UriTemplate::new("/reports/{id}/actions")
+        .set("id", uuid::Uuid::new_v4().to_string())
+        .build();
+
+    run_test()
+        .seed(&admin)
+        .authenticate("admin", "password")
+        .post(url, json!({ "action": "dismiss" }))
+        .has_status(Status::NotFound)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:moderation/problems/unknown_report",
+            "title": "The requested report was unknown",
+            "status": 404
+        }));
+}
+
+#[test]
+fn test_moderate_invalid_action() {
+    let admin = SeedUser {
+        username: "admin".to_owned(),
+        password: hash_password("password"),
+        admin: true,
+        ..SeedUser::default()
+    };
+    let world = SeedWorld::default();
+    let report = SeedReport {
+        reporter_id: admin.user_id,
+        target_id: world.world_id,
+        ..SeedReport::default()
+    };
+
+    let url = UriTemplate::new("/reports/{id}/actions")
+        .set("id", report.report_id.to_string())
+        .build();
+
+    run_test()
+        .seed_many(&[&admin, &world, &report])
+        .authenticate("admin", "password")
+        .post(url, json!({ "action": "delete" }))
+        .has_status(Status::UnprocessableEntity)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/validation_error",
+            "title": "A validation error occurred",
+            "status": 422,
+            "fields": {
+              "action": {
+                "type": "tag:multiverse,2020:moderation/problems/validation_error/invalid_action",
+                "title": "The action was not a known action"
+              }
+            }
+        }));
+}
+
+#[test]
+fn test_moderate_dismiss() {
+    let admin = SeedUser {
+        username: "admin".to_owned(),
+        password: hash_password("password"),
+        admin: true,
+        ..SeedUser::default()
+    };
+    let world = SeedWorld::default();
+    let report = SeedReport {
+        reporter_id: admin.user_id,
+        target_id: world.world_id,
+        ..SeedReport::default()
+    };
+
+    let url = UriTemplate::new("/reports/{id}/actions")
+        .set("id", report.report_id.to_string())
+        .build();
+
+    run_test()
+        .seed_many(&[&admin, &world, &report])
+        .authenticate("admin", "password")
+        .post(url, json!({ "action": "dismiss", "note": "Not a problem" }))
+        .has_status(Status::Ok)
+        .has_header("Content-Type", "application/json")
+        .assert_json_body(|body| {
+            assert_eq!(body["status"], json!("dismissed"));
+        })
+        .assert_database(|mut tx| {
+            let hidden: bool = tx
+                .query_one(
+                    "SELECT hidden FROM worlds WHERE world_id = $1",
+                    &[&world.world_id],
+                )
+                .unwrap()
+                .get(0);
+            assert_eq!(hidden, false);
+
+            let rows = tx
+                .query(
+                    "SELECT moderator_id, report_id, action, target_type, target_id, note FROM moderation_actions",
+                    &[],
+                )
+                .unwrap();
+            assert_eq!(rows.len(), 1);
+            assert_eq!(rows[0].get::<_, uuid::Uuid>(0), admin.user_id);
+            assert_eq!(rows[0].get::<_, uuid::Uuid>(1), report.report_id);
+            assert_eq!(rows[0].get::<_, String>(2), "dismiss");
+            assert_eq!(rows[0].get::<_, String>(3), "world");
+            assert_eq!(rows[0].get::<_, uuid::Uuid>(4), world.world_id);
+            assert_eq!(rows[0].get::<_, String>(5), "Not a problem");
+        });
+}
+
+#[test]
+fn test_moderate_already_resolved() {
+    let admin = SeedUser {
+        username: "admin".to_owned(),
+        password: hash_password("password"),
+        admin: true,
+        ..SeedUser::default()
+    };
+    let world = SeedWorld::default();
+    let report = SeedReport {
+        reporter_id: admin.user_id,
+        target_id: world.world_id,
+        status: "dismissed".to_owned(),
+        ..SeedReport::default()
+    };
+
+    let url = UriTemplate::new("/reports/{id}/actions")
+        .set("id", report.report_id.to_string())
+        .build();
+
+    run_test()
+        .seed_many(&[&admin, &world, &report])
+        .authenticate("admin", "password")
+        .post(url, json!({ "action": "hide" }))
+        .has_status(Status::Conflict)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:moderation/problems/already_resolved",
+            "title": "The report has already been resolved",
+            "status": 409
+        }))
+        .assert_database(|mut tx| {
+            let hidden: bool = tx
+                .query_one(
+                    "SELECT hidden FROM worlds WHERE world_id = $1",
+                    &[&world.world_id],
+                )
+                .unwrap()
+                .get(0);
+            assert_eq!(hidden, false);
+        });
+}
+
+#[test]
+fn test_moderate_hide_world() {
+    let admin = SeedUser {
+        username: "admin".to_owned(),
+        password: hash_password("password"),
+        admin: true,
+        ..SeedUser::default()
+    };
+    let world = SeedWorld::default();
+    let report = SeedReport {
+        reporter_id: admin.user_id,
+        target_id: world.world_id,
+        ..SeedReport::default()
+    };
+
+    let url = UriTemplate::new("/reports/{id}/actions")
+        .set("id", report.report_id.to_string())
+        .build();
+
+    run_test()
+        .seed_many(&[&admin, &world, &report])
+        .authenticate("admin", "password")
+        .post(url, json!({ "action": "hide" }))
+        .has_status(Status::Ok)
+        .assert_json_body(|body| {
+            assert_eq!(body["status"], json!("actioned"));
+        })
+        .assert_database(|mut tx| {
+            let hidden: bool = tx
+                .query_one(
+                    "SELECT hidden FROM worlds WHERE world_id = $1",
+                    &[&world.world_id],
+                )
+                .unwrap()
+                .get(0);
+            assert_eq!(hidden, true);
+        });
+}
+
+#[test]
+fn test_moderate_hide_comment() {
+    let admin = SeedUser {
+        username: "admin".to_owned(),
+        password: hash_password("password"),
+        admin: true,
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: admin.user_id,
+        ..SeedWorld::default()
+    };
+    let comment = SeedComment {
+        world_id: world.world_id,
+        author_id: admin.user_id,
+        ..SeedComment::default()
+    };
+    let report = SeedReport {
+        reporter_id: admin.user_id,
+        target_type: "comment".to_owned(),
+        target_id: comment.comment_id,
+        ..SeedReport::default()
+    };
+
+    let url = UriTemplate::new("/reports/{id}/actions")
+        .set("id", report.report_id.to_string())
+        .build();
+
+    run_test()
+        .seed_many(&[&admin, &world, &comment, &report])
+        .authenticate("admin", "password")
+        .post(url, json!({ "action": "hide" }))
+        .has_status(Status::Ok)
+        .assert_database(|mut tx| {
+            let hidden: bool = tx
+                .query_one(
+                    "SELECT hidden FROM comments WHERE comment_id = $1",
+                    &[&comment.comment_id],
+                )
+                .unwrap()
+                .get(0);
+            assert_eq!(hidden, true);
+        });
+}
+
+#[test]
+fn test_moderate_hide_comment_removes_it() {
+    let admin = SeedUser {
+        username: "admin".to_owned(),
+        password: hash_password("password"),
+        admin: true,
+        ..SeedUser::default()
+    };
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld::default();
+    let parent = SeedComment {
+        world_id: world.world_id,
+        author_id: user.user_id,
+        ..SeedComment::default()
+    };
+    let comment = SeedComment {
+        world_id: world.world_id,
+        author_id: user.user_id,
+        parent_id: Some(parent.comment_id),
+        ..SeedComment::default()
+    };
+    let report = SeedReport {
+        reporter_id: admin.user_id,
+        target_type: "comment".to_owned(),
+        target_id: comment.comment_id,
+        ..SeedReport::default()
+    };
+
+    let url = UriTemplate::new("/reports/{id}/actions")
+        .set("id", report.report_id.to_string())
+        .build();
+    let comment_url = format!("/comments/{}", comment.comment_id);
+    let replies_url = format!("/comments/{}/replies", parent.comment_id);
+
+    run_test()
+        .seed_many(&[&admin, &user, &world, &parent, &comment, &report])
+        .authenticate("testuser", "password")
+        .get(comment_url.clone())
+        .has_status(Status::Ok)
+        .get(replies_url.clone())
+        .has_status(Status::Ok)
+        .assert_json_body(|body| {
+            assert_eq!(body["pagination"]["total"], json!(1));
+        })
+        .authenticate("admin", "password")
+        .post(url, json!({ "action": "hide" }))
+        .has_status(Status::Ok)
+        // Moderators can still see the hidden comment
+        .get(comment_url.clone())
+        .has_status(Status::Ok)
+        .authenticate("testuser", "password")
+        .get(comment_url.clone())
+        .has_status(Status::NotFound)
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:comments/problems/unknown_comment",
+            "title": "The requested comment was unknown",
+            "status": 404
+        }))
+        .get(format!("{}/replies", comment_url))
+        .has_status(Status::NotFound)
+        .get(replies_url)
+        .has_status(Status::Ok)
+        .assert_json_body(|body| {
+            assert_eq!(body["entries"], json!([]));
+            assert_eq!(body["pagination"]["total"], json!(0));
+        });
+}
+
+#[test]
+fn test_moderate_hide_user() {
+    let admin = SeedUser {
+        username: "admin".to_owned(),
+        password: hash_password("password"),
+        admin: true,
+        ..SeedUser::default()
+    };
+    let user = SeedUser::default();
+    let report = SeedReport {
+        reporter_id: admin.user_id,
+        target_type: "user".to_owned(),
+        target_id: user.user_id,
+        ..SeedReport::default()
+    };
+
+    let url = UriTemplate::new("/reports/{id}/actions")
+        .set("id", report.report_id.to_string())
+        .build();
+
+    run_test()
+        .seed_many(&[&admin, &user, &report])
+        .authenticate("admin", "password")
+        .post(url, json!({ "action": "hide" }))
+        .has_status(Status::UnprocessableEntity)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/validation_error",
+            "title": "A validation error occurred",
+            "status": 422,
+            "fields": {
+              "action": {
+                "type": "tag:multiverse,2020:moderation/problems/validation_error/unsupported_action",
+                "title": "The action can not be taken against the reported content"
+              }
+            }
+        }));
+}
+
+#[test]
+fn test_moderate_suspend_world_owner() {
+    let admin = SeedUser {
+        username: "admin".to_owned(),
+        password: hash_password("password"),
+        admin: true,
+        ..SeedUser::default()
+    };
+    let owner = SeedUser::default();
+    let world = SeedWorld {
+        owner: owner.user_id,
+        ..SeedWorld::default()
+    };
+    let report = SeedReport {
+        reporter_id: admin.user_id,
+        target_id: world.world_id,
+        ..SeedReport::default()
+    };
+
+    let url = UriTemplate::new("/reports/{id}/actions")
+        .set("id", report.report_id.to_string())
+        .build();
+
+    run_test()
+        .seed_many(&[&admin, &owner, &world, &report])
+        .authenticate("admin", "password")
+        .post(url, json!({ "action": "suspend", "note": "Repeated spam" }))
+        .has_status(Status::Ok)
+        .assert_json_body(|body| {
+            assert_eq!(body["status"], json!("actioned"));
+        })
+        .assert_database(|mut tx| {
+            let suspended: bool = tx
+                .query_one(
+                    "SELECT suspended FROM users WHERE user_id = $1",
+                    &[&owner.user_id],
+                )
+                .unwrap()
+                .get(0);
+            assert_eq!(suspended, true);
+
+            let suspended: bool = tx
+                .query_one(
+                    "SELECT suspended FROM users WHERE user_id = $1",
+                    &[&admin.user_id],
+                )
+                .unwrap()
+                .get(0);
+            assert_eq!(suspended, false);
+        });
+}
@@ -0,0 +1,127 @@
+use crate::{
+    data::{hash_password, SeedNotification, SeedUser, SeedWorld},
+    tests::run_test,
+};
+use chrono::Duration;
+use rocket::http::Status;
+use serde_json::json;
+
+#[test]
+fn test_list_unauthenticated() {
+    run_test()
+        .get("/notifications")
+        .has_status(Status::Forbidden)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/unauthorized",
+            "title": "An invalid access token was provided",
+            "status": 403
+        }));
+}
+
+#[test]
+fn test_list_none() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+
+    run_test()
+        .seed(&user)
+        .authenticate("testuser", "password")
+        .get("/notifications")
+        .has_status(Status::Ok)
+        .has_header("Content-Type", "application/json")
+        .has_json_body(json!({
+          "entries": [],
+          "pagination": {
+            "offset": 0,
+            "count": 10,
+            "total": 0
+          }
+        }));
+}
+
+#[test]
+fn test_list_own_notifications() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let actor = SeedUser {
+        user_id: uuid::Uuid::parse_str("00000000-0000-0000-0001-000000000002").unwrap(),
+        display_name: "Other User".to_owned(),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        world_id: uuid::Uuid::parse_str("00000000-0000-0000-0002-000000000001").unwrap(),
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let read = SeedNotification {
+        user_id: user.user_id,
+        actor_id: actor.user_id,
+        world_id: world.world_id,
+        kind: "star".to_owned(),
+        created: SeedNotification::default().created - Duration::minutes(5),
+        read: Some(SeedNotification::default().created),
+        ..SeedNotification::default()
+    };
+    let unread = SeedNotification {
+        notification_id: uuid::Uuid::parse_str("00000000-0000-0000-0003-000000000001").unwrap(),
+        user_id: user.user_id,
+        actor_id: actor.user_id,
+        world_id: world.world_id,
+        kind: "comment".to_owned(),
+        target: Some("/comments/00000000-0000-0000-0004-000000000001".to_owned()),
+        ..SeedNotification::default()
+    };
+    let someone_elses = SeedNotification {
+        user_id: actor.user_id,
+        actor_id: user.user_id,
+        world_id: world.world_id,
+        ..SeedNotification::default()
+    };
+
+    run_test()
+        .seed_many(&[&user, &actor, &world, &read, &unread, &someone_elses])
+        .authenticate("testuser", "password")
+        .get("/notifications")
+        .has_status(Status::Ok)
+        .has_header("Content-Type", "application/json")
+        .has_header_regex(
+            "Link",
+            r#"</notifications/00000000-0000-0000-0003-000000000001>; rel="self"; anchor="\#/entries/0""#,
+        )
+        .has_header_regex(
+            "Link",
+            r#"</users/00000000-0000-0000-0001-000000000002>; rel="author"; title="Other User"; anchor="\#/entries/0""#,
+        )
+        .assert_json_body(|body| {
+            assert_eq!(body["pagination"]["total"], json!(2));
+            assert_eq!(body["entries"][0]["kind"], json!("comment"));
+            assert_eq!(
+                body["entries"][0]["actor"],
+                json!("/users/00000000-0000-0000-0001-000000000002")
+            );
+            assert_eq!(
+                body["entries"][0]["world"],
+                json!("/worlds/00000000-0000-0000-0002-000000000001")
+            );
+            assert_eq!(
+                body["entries"][0]["target"],
+                json!("/comments/00000000-0000-0000-0004-000000000001")
+            );
+            assert_eq!(body["entries"][0]["read"], json!(null));
+            assert_eq!(body["entries"][1]["kind"], json!("star"));
+            assert!(body["entries"][1]["read"].is_string());
+        })
+        .get("/notifications?unread=true")
+        .has_status(Status::Ok)
+        .assert_json_body(|body| {
+            assert_eq!(body["pagination"]["total"], json!(1));
+            assert_eq!(body["entries"][0]["kind"], json!("comment"));
+        });
+}
@@ -0,0 +1,4 @@
+mod list_notifications;
+mod preferences;
+mod read_notification;
+mod send_notifications;
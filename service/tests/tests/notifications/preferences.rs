@@ -0,0 +1,146 @@
+use crate::{
+    data::{hash_password, SeedNotificationPreference, SeedUser},
+    tests::run_test,
+};
+use rocket::http::Status;
+use serde_json::json;
+
+#[test]
+fn test_get_preferences_unauthenticated() {
+    run_test()
+        .get("/notifications/preferences")
+        .has_status(Status::Forbidden)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/unauthorized",
+            "title": "An invalid access token was provided",
+            "status": 403
+        }));
+}
+
+#[test]
+fn test_get_default_preferences() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+
+    run_test()
+        .seed(&user)
+        .authenticate("testuser", "password")
+        .get("/notifications/preferences")
+        .has_status(Status::Ok)
+        .has_header("Content-Type", "application/json")
+        .has_json_body(json!({
+          "comment": true,
+          "member_joined": true,
+          "reply": true,
+          "star": true
+        }));
+}
+
+#[test]
+fn test_get_saved_preferences() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let preference = SeedNotificationPreference {
+        user_id: user.user_id,
+        kind: "star".to_owned(),
+        enabled: false,
+    };
+
+    run_test()
+        .seed(&user)
+        .seed(&preference)
+        .authenticate("testuser", "password")
+        .get("/notifications/preferences")
+        .has_status(Status::Ok)
+        .has_json_body(json!({
+          "comment": true,
+          "member_joined": true,
+          "reply": true,
+          "star": false
+        }));
+}
+
+#[test]
+fn test_update_invalid_preferences() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+
+    run_test()
+        .seed(&user)
+        .authenticate("testuser", "password")
+        .put(
+            "/notifications/preferences",
+            json!({
+              "poke": false,
+              "star": "no"
+            }),
+        )
+        .has_status(Status::UnprocessableEntity)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/validation_error",
+            "title": "A validation error occurred",
+            "status": 422,
+            "fields": {
+              "poke": {
+                "type": "tag:multiverse,2020:notifications/problems/validation_error/unknown_kind",
+                "title": "The notification kind was unknown"
+              },
+              "star": {
+                "type": "tag:multiverse,2020:notifications/problems/validation_error/invalid_preference",
+                "title": "The preference must be true or false"
+              }
+            }
+        }));
+}
+
+#[test]
+fn test_update_preferences() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let preference = SeedNotificationPreference {
+        user_id: user.user_id,
+        kind: "star".to_owned(),
+        enabled: false,
+    };
+
+    run_test()
+        .seed(&user)
+        .seed(&preference)
+        .authenticate("testuser", "password")
+        .put(
+            "/notifications/preferences",
+            json!({
+              "comment": false,
+              "star": true
+            }),
+        )
+        .has_status(Status::Ok)
+        .has_json_body(json!({
+          "comment": false,
+          "member_joined": true,
+          "reply": true,
+          "star": true
+        }))
+        .get("/notifications/preferences")
+        .has_status(Status::Ok)
+        .has_json_body(json!({
+          "comment": false,
+          "member_joined": true,
+          "reply": true,
+          "star": true
+        }));
+}
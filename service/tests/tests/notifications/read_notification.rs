@@ -0,0 +1,170 @@
+use crate::{
+    data::{hash_password, SeedNotification, SeedUser, SeedWorld},
+    tests::run_test,
+};
+use rocket::http::Status;
+use serde_json::json;
+
+#[test]
+fn test_read_unauthenticated() {
+    run_test()
+        .post(
+            "/notifications/d6c124d5-1060-48ef-aeb8-e591a8261c2b/read",
+            json!({}),
+        )
+        .has_status(Status::Forbidden)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/unauthorized",
+            "title": "An invalid access token was provided",
+            "status": 403
+        }));
+}
+
+#[test]
+fn test_read_unknown_notification() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+
+    run_test()
+        .seed(&user)
+        .authenticate("testuser", "password")
+        .post(
+            "/notifications/d6c124d5-1060-48ef-aeb8-e591a8261c2b/read",
+            json!({}),
+        )
+        .has_status(Status::NotFound)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:notifications/problems/unknown_notification",
+            "title": "The requested notification was unknown",
+            "status": 404
+        }));
+}
+
+#[test]
+fn test_read_someone_elses_notification() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let other = SeedUser::default();
+    let world = SeedWorld {
+        owner: other.user_id,
+        ..SeedWorld::default()
+    };
+    let notification = SeedNotification {
+        user_id: other.user_id,
+        actor_id: user.user_id,
+        world_id: world.world_id,
+        ..SeedNotification::default()
+    };
+    let url = format!("/notifications/{}/read", notification.notification_id);
+
+    run_test()
+        .seed_many(&[&user, &other, &world, &notification])
+        .authenticate("testuser", "password")
+        .post(url, json!({}))
+        .has_status(Status::NotFound)
+        .assert_database(|mut tx| {
+            let rows = tx
+                .query("SELECT * FROM notifications WHERE read IS NULL", &[])
+                .unwrap();
+            assert_eq!(rows.len(), 1);
+        });
+}
+
+#[test]
+fn test_read_success() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let other = SeedUser::default();
+    let world = SeedWorld {
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let notification = SeedNotification {
+        user_id: user.user_id,
+        actor_id: other.user_id,
+        world_id: world.world_id,
+        ..SeedNotification::default()
+    };
+    let still_unread = SeedNotification {
+        user_id: user.user_id,
+        actor_id: other.user_id,
+        world_id: world.world_id,
+        ..SeedNotification::default()
+    };
+    let url = format!("/notifications/{}/read", notification.notification_id);
+
+    run_test()
+        .seed_many(&[&user, &other, &world, &notification, &still_unread])
+        .authenticate("testuser", "password")
+        .post(url, json!({}))
+        .has_status(Status::NoContent)
+        .assert_database(|mut tx| {
+            let rows = tx
+                .query(
+                    "SELECT notification_id FROM notifications WHERE read IS NULL",
+                    &[],
+                )
+                .unwrap();
+            assert_eq!(rows.len(), 1);
+            assert_eq!(
+                rows[0].get::<_, uuid::Uuid>(0),
+                still_unread.notification_id
+            );
+        });
+}
+
+#[test]
+fn test_read_all() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let other = SeedUser::default();
+    let world = SeedWorld {
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let first = SeedNotification {
+        user_id: user.user_id,
+        actor_id: other.user_id,
+        world_id: world.world_id,
+        ..SeedNotification::default()
+    };
+    let second = SeedNotification {
+        user_id: user.user_id,
+        actor_id: other.user_id,
+        world_id: world.world_id,
+        ..SeedNotification::default()
+    };
+    let someone_elses = SeedNotification {
+        user_id: other.user_id,
+        actor_id: user.user_id,
+        world_id: world.world_id,
+        ..SeedNotification::default()
+    };
+
+    run_test()
+        .seed_many(&[&user, &other, &world, &first, &second, &someone_elses])
+        .authenticate("testuser", "password")
+        .post("/notifications/read-all", json!({}))
+        .has_status(Status::NoContent)
+        .assert_database(|mut tx| {
+            let rows = tx
+                .query("SELECT user_id FROM notifications WHERE read IS NULL", &[])
+                .unwrap();
+            assert_eq!(rows.len(), 1);
+            assert_eq!(rows[0].get::<_, uuid::Uuid>(0), other.user_id);
+        });
+}
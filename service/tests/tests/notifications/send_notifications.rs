@@ -0,0 +1,180 @@
+use crate::{
+    data::{
+        hash_password, SeedComment, SeedInvitation, SeedNotificationPreference, SeedUser,
+        SeedWorld, ABC_TOKEN_HASH,
+    },
+    tests::run_test,
+};
+use rocket::http::Status;
+use serde_json::json;
+
+#[test]
+fn test_star_notifies_owner_once() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let owner = SeedUser::default();
+    let world = SeedWorld {
+        owner: owner.user_id,
+        ..SeedWorld::default()
+    };
+    let url = format!("/worlds/{}/star", world.world_id);
+
+    run_test()
+        .seed_many(&[&user, &owner, &world])
+        .authenticate("testuser", "password")
+        .put(url.clone(), json!({}))
+        .has_status(Status::NoContent)
+        .put(url, json!({}))
+        .has_status(Status::NoContent)
+        .assert_database(|mut tx| {
+            let rows = tx
+                .query(
+                    "SELECT user_id, actor_id, kind, world_id, target FROM notifications",
+                    &[],
+                )
+                .unwrap();
+            assert_eq!(rows.len(), 1);
+            assert_eq!(rows[0].get::<_, uuid::Uuid>(0), owner.user_id);
+            assert_eq!(rows[0].get::<_, uuid::Uuid>(1), user.user_id);
+            assert_eq!(rows[0].get::<_, String>(2), "star");
+            assert_eq!(rows[0].get::<_, uuid::Uuid>(3), world.world_id);
+            assert_eq!(rows[0].get::<_, Option<String>>(4), None);
+        });
+}
+
+#[test]
+fn test_star_own_world_does_not_notify() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let url = format!("/worlds/{}/star", world.world_id);
+
+    run_test()
+        .seed_many(&[&user, &world])
+        .authenticate("testuser", "password")
+        .put(url, json!({}))
+        .has_status(Status::NoContent)
+        .assert_database(|mut tx| {
+            let rows = tx.query("SELECT * FROM notifications", &[]).unwrap();
+            assert_eq!(rows.len(), 0);
+        });
+}
+
+#[test]
+fn test_disabled_preference_does_not_notify() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let owner = SeedUser::default();
+    let world = SeedWorld {
+        owner: owner.user_id,
+        ..SeedWorld::default()
+    };
+    let preference = SeedNotificationPreference {
+        user_id: owner.user_id,
+        kind: "star".to_owned(),
+        enabled: false,
+    };
+    let url = format!("/worlds/{}/star", world.world_id);
+
+    run_test()
+        .seed_many(&[&user, &owner, &world, &preference])
+        .authenticate("testuser", "password")
+        .put(url, json!({}))
+        .has_status(Status::NoContent)
+        .assert_database(|mut tx| {
+            let rows = tx.query("SELECT * FROM notifications", &[]).unwrap();
+            assert_eq!(rows.len(), 0);
+        });
+}
+
+#[test]
+fn test_reply_notifies_owner_and_parent_author() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let owner = SeedUser::default();
+    let parent_author = SeedUser::default();
+    let world = SeedWorld {
+        owner: owner.user_id,
+        ..SeedWorld::default()
+    };
+    let parent = SeedComment {
+        world_id: world.world_id,
+        author_id: parent_author.user_id,
+        ..SeedComment::default()
+    };
+    let url = format!("/worlds/{}/comments", world.world_id);
+
+    run_test()
+        .seed_many(&[&user, &owner, &parent_author, &world, &parent])
+        .authenticate("testuser", "password")
+        .post(
+            url,
+            json!({
+              "body": "Hello",
+              "parent": format!("/comments/{}", parent.comment_id)
+            }),
+        )
+        .has_status(Status::Ok)
+        .assert_database(|mut tx| {
+            let rows = tx
+                .query(
+                    "SELECT user_id, kind FROM notifications WHERE actor_id = $1 ORDER BY kind",
+                    &[&user.user_id],
+                )
+                .unwrap();
+            assert_eq!(rows.len(), 2);
+            assert_eq!(rows[0].get::<_, uuid::Uuid>(0), owner.user_id);
+            assert_eq!(rows[0].get::<_, String>(1), "comment");
+            assert_eq!(rows[1].get::<_, uuid::Uuid>(0), parent_author.user_id);
+            assert_eq!(rows[1].get::<_, String>(1), "reply");
+        });
+}
+
+#[test]
+fn test_accept_invitation_notifies_owner() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let owner = SeedUser::default();
+    let world = SeedWorld {
+        owner: owner.user_id,
+        ..SeedWorld::default()
+    };
+    let invitation = SeedInvitation {
+        world_id: world.world_id,
+        token_hash: ABC_TOKEN_HASH.to_owned(),
+        ..SeedInvitation::default()
+    };
+
+    run_test()
+        .seed_many(&[&user, &owner, &world, &invitation])
+        .authenticate("testuser", "password")
+        .post("/invitations/abc/accept", json!({}))
+        .has_status(Status::Ok)
+        .assert_database(|mut tx| {
+            let rows = tx
+                .query("SELECT user_id, actor_id, kind FROM notifications", &[])
+                .unwrap();
+            assert_eq!(rows.len(), 1);
+            assert_eq!(rows[0].get::<_, uuid::Uuid>(0), owner.user_id);
+            assert_eq!(rows[0].get::<_, uuid::Uuid>(1), user.user_id);
+            assert_eq!(rows[0].get::<_, String>(2), "member_joined");
+        });
+}
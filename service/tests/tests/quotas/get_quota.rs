@@ -0,0 +1,127 @@
+use crate::{
+    data::{hash_password, SeedAttachment, SeedTable, SeedUser, SeedUserQuota, SeedWorld},
+    tests::run_test,
+};
+use rocket::http::Status;
+use serde_json::json;
+use uritemplate::UriTemplate;
+
+#[test]
+fn test_get_quota_unauthenticated() {
+    let user = SeedUser::default();
+
+    let url = UriTemplate::new("/users/{id}/quota")
+        .set("id", user.user_id.to_string())
+        .build();
+
+    run_test()
+        .seed(&user)
+        .get(url)
+        .has_status(Status::Forbidden)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/unauthorized",
+            "title": "An invalid access token was provided",
+            "status": 403
+        }));
+}
+
+#[test]
+fn test_get_quota_other_user() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let other_user = SeedUser::default();
+
+    let url = UriTemplate::new("/users/{id}/quota")
+        .set("id", other_user.user_id.to_string())
+        .build();
+
+    run_test()
+        .seed_many(&[&user, &other_user])
+        .authenticate("testuser", "password")
+        .get(url)
+        .has_status(Status::Forbidden)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/unauthorized",
+            "title": "An invalid access token was provided",
+            "status": 403
+        }));
+}
+
+#[test]
+fn test_get_quota_defaults() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+
+    let url = UriTemplate::new("/users/{id}/quota")
+        .set("id", user.user_id.to_string())
+        .build();
+
+    run_test()
+        .seed(&user)
+        .authenticate("testuser", "password")
+        .get(url)
+        .has_status(Status::Ok)
+        .has_header("Content-Type", "application/json")
+        .has_header("Cache-Control", "private, no-cache")
+        .has_json_body(json!({
+            "worlds": { "used": 0, "limit": 100 },
+            "tables": { "used": 0, "limit": 100 },
+            "campaigns": { "used": 0, "limit": 100 },
+            "characters": { "used": 0, "limit": 100 },
+            "attachment_bytes": { "used": 0, "limit": 131072 }
+        }));
+}
+
+#[test]
+fn test_get_quota_used_with_override() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let table = SeedTable {
+        world_id: world.world_id,
+        ..SeedTable::default()
+    };
+    let attachment = SeedAttachment {
+        world_id: world.world_id,
+        owner_id: user.user_id,
+        size: 1024,
+        ..SeedAttachment::default()
+    };
+    let quota = SeedUserQuota {
+        user_id: user.user_id,
+        resource: "tables".to_owned(),
+        quota: 5,
+    };
+
+    let url = UriTemplate::new("/users/{id}/quota")
+        .set("id", user.user_id.to_string())
+        .build();
+
+    run_test()
+        .seed_many(&[&user, &world, &table, &attachment, &quota])
+        .authenticate("testuser", "password")
+        .get(url)
+        .has_status(Status::Ok)
+        .has_header("Content-Type", "application/json")
+        .has_json_body(json!({
+            "worlds": { "used": 1, "limit": 100 },
+            "tables": { "used": 1, "limit": 5 },
+            "campaigns": { "used": 0, "limit": 100 },
+            "characters": { "used": 0, "limit": 100 },
+            "attachment_bytes": { "used": 1024, "limit": 131072 }
+        }));
+}
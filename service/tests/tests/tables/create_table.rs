@@ -0,0 +1,415 @@
+use crate::{
+    data::{hash_password, SeedTable, SeedUser, SeedUserQuota, SeedWorld, SeedWorldMember},
+    tests::run_test,
+};
+use rocket::http::Status;
+use serde_json::json;
+
+#[test]
+fn test_create_unauthenticated() {
+    let world_owner = SeedUser::default();
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let url = format!("/worlds/{}/tables", world.world_id);
+
+    run_test()
+        .seed(&world_owner)
+        .seed(&world)
+        .post(url, json!({}))
+        .has_status(Status::Forbidden)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/unauthorized",
+            "title": "An invalid access token was provided",
+            "status": 403
+        }));
+}
+
+#[test]
+fn test_create_wrong_user() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let owner = SeedUser::default();
+    let world = SeedWorld {
+        owner: owner.user_id,
+        ..SeedWorld::default()
+    };
+    let url = format!("/worlds/{}/tables", world.world_id);
+
+    run_test()
+        .seed(&user)
+        .seed(&owner)
+        .seed(&world)
+        .authenticate("testuser", "password")
+        .post(url, json!({}))
+        .has_status(Status::Forbidden)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/unauthorized",
+            "title": "An invalid access token was provided",
+            "status": 403
+        }));
+}
+
+#[test]
+fn test_create_unknown_world() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+
+    run_test()
+        .seed(&user)
+        .authenticate("testuser", "password")
+        .post(
+            "/worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b/tables",
+            json!({}),
+        )
+        .has_status(Status::NotFound)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:worlds/problems/unknown_world_id",
+            "title": "The requested world ID was unknown",
+            "status": 404
+        }));
+}
+
+#[test]
+fn test_create_empty_body() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let url = format!("/worlds/{}/tables", world.world_id);
+
+    run_test()
+        .seed(&user)
+        .seed(&world)
+        .authenticate("testuser", "password")
+        .post(url, json!({}))
+        .has_status(Status::UnprocessableEntity)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/validation_error",
+            "title": "A validation error occurred",
+            "status": 422,
+            "fields": {
+              "name": {
+                "type": "tag:multiverse,2020:problems/validation_error/missing",
+                "title": "The required field was missing"
+              },
+              "url_slug": {
+                "type": "tag:multiverse,2020:problems/validation_error/missing",
+                "title": "The required field was missing"
+              }
+            }
+        }));
+}
+
+#[test]
+fn test_create_invalid_entries() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let url = format!("/worlds/{}/tables", world.world_id);
+
+    run_test()
+        .seed(&user)
+        .seed(&world)
+        .authenticate("testuser", "password")
+        .post(
+            url,
+            json!({
+              "name": "Treasure",
+              "entries": [
+                { "weight": 0, "text": "Nothing" },
+                { "text": "{3d6" },
+                { "weight": 2 }
+              ]
+            }),
+        )
+        .has_status(Status::UnprocessableEntity)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/validation_error",
+            "title": "A validation error occurred",
+            "status": 422,
+            "fields": {
+              "entries/0/weight": {
+                "type": "tag:multiverse,2020:tables/problems/validation_error/invalid_weight",
+                "title": "The weight must be a positive number"
+              },
+              "entries/1/text": {
+                "type": "tag:multiverse,2020:tables/problems/validation_error/invalid_expression",
+                "title": "The text contained an invalid expression"
+              },
+              "entries/2/text": {
+                "type": "tag:multiverse,2020:problems/validation_error/missing",
+                "title": "The required field was missing"
+              }
+            }
+        }));
+}
+
+#[test]
+fn test_create_weight_too_large() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let url = format!("/worlds/{}/tables", world.world_id);
+
+    run_test()
+        .seed(&user)
+        .seed(&world)
+        .authenticate("testuser", "password")
+        .post(
+            url,
+            json!({
+              "name": "Treasure",
+              "entries": [
+                { "weight": 4294967295u64, "text": "Everything" },
+                { "weight": 1, "text": "Nothing" }
+              ]
+            }),
+        )
+        .has_status(Status::UnprocessableEntity)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/validation_error",
+            "title": "A validation error occurred",
+            "status": 422,
+            "fields": {
+              "entries/0/weight": {
+                "type": "tag:multiverse,2020:tables/problems/validation_error/weight_too_large",
+                "title": "The weight was too large"
+              }
+            }
+        }));
+}
+
+#[test]
+fn test_create_success() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        world_id: uuid::Uuid::parse_str("d6c124d5-1060-48ef-aeb8-e591a8261c2b").unwrap(),
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+
+    run_test()
+        .seed(&user)
+        .seed(&world)
+        .authenticate("testuser", "password")
+        .post(
+            "/worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b/tables",
+            json!({
+              "name": "Tavern Names",
+              "description": "Names for taverns",
+              "entries": [
+                { "weight": 2, "text": "The Prancing Pony" },
+                { "text": "The {1d3} Broomsticks" }
+              ]
+            }),
+        )
+        .has_status(Status::Ok)
+        .has_header("Content-Type", "application/json")
+        .has_header_regex(
+            "Link",
+            r#"</worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b/tables/tavern-names>; rel="self""#,
+        )
+        .has_header_regex(
+            "Link",
+            r#"</worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b>; rel="related""#,
+        )
+        .has_json_body(json!({
+          "name": "Tavern Names",
+          "description": "Names for taverns",
+          "status": "published",
+          "url_slug": "tavern-names",
+          "entries": [
+            { "weight": 2, "text": "The Prancing Pony" },
+            { "weight": 1, "text": "The {1d3} Broomsticks" }
+          ]
+        }));
+}
+
+#[test]
+fn test_create_as_editor() {
+    let world_owner = SeedUser::default();
+    let editor = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let member = SeedWorldMember {
+        world_id: world.world_id,
+        user_id: editor.user_id,
+        role: "editor".to_owned(),
+        ..SeedWorldMember::default()
+    };
+    let url = format!("/worlds/{}/tables", world.world_id);
+
+    run_test()
+        .seed_many(&[&world_owner, &editor, &world, &member])
+        .authenticate("testuser", "password")
+        .post(
+            url,
+            json!({
+              "name": "Tavern Names",
+              "entries": [{ "text": "The Prancing Pony" }]
+            }),
+        )
+        .has_status(Status::Ok)
+        .assert_json_body(|body| {
+            assert_eq!(body["url_slug"], json!("tavern-names"));
+        });
+}
+
+#[test]
+fn test_create_as_viewer() {
+    let world_owner = SeedUser::default();
+    let viewer = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let member = SeedWorldMember {
+        world_id: world.world_id,
+        user_id: viewer.user_id,
+        role: "viewer".to_owned(),
+        ..SeedWorldMember::default()
+    };
+    let url = format!("/worlds/{}/tables", world.world_id);
+
+    run_test()
+        .seed_many(&[&world_owner, &viewer, &world, &member])
+        .authenticate("testuser", "password")
+        .post(
+            url,
+            json!({
+              "name": "Tavern Names",
+              "entries": [{ "text": "The Prancing Pony" }]
+            }),
+        )
+        .has_status(Status::Forbidden);
+}
+
+#[test]
+fn test_create_duplicate_url_slug() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let table = SeedTable {
+        world_id: world.world_id,
+        url_slug: "tavern-names".to_owned(),
+        ..SeedTable::default()
+    };
+    let url = format!("/worlds/{}/tables", world.world_id);
+
+    run_test()
+        .seed(&user)
+        .seed(&world)
+        .seed(&table)
+        .authenticate("testuser", "password")
+        .post(
+            url,
+            json!({
+              "name": "Tavern Names",
+              "entries": []
+            }),
+        )
+        .has_status(Status::UnprocessableEntity)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:tables/problems/duplicate_url_slug",
+            "title": "The URL Slug was already present for this world",
+            "status": 422
+        }));
+}
+
+#[test]
+fn test_create_quota_exceeded() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let table = SeedTable {
+        world_id: world.world_id,
+        ..SeedTable::default()
+    };
+    let quota = SeedUserQuota {
+        user_id: user.user_id,
+        resource: "tables".to_owned(),
+        quota: 1,
+    };
+    let url = format!("/worlds/{}/tables", world.world_id);
+
+    run_test()
+        .seed_many(&[&user, &world, &table, &quota])
+        .authenticate("testuser", "password")
+        .post(
+            url,
+            json!({
+              "name": "Tavern Names",
+              "entries": [
+                { "text": "The Prancing Pony" }
+              ]
+            }),
+        )
+        .has_status(Status::Forbidden)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/quota_exceeded",
+            "title": "The quota has been exceeded",
+            "status": 403,
+            "resource": "tables",
+            "used": 1,
+            "limit": 1
+        }));
+}
@@ -0,0 +1,95 @@
+use crate::{
+    data::{hash_password, SeedTable, SeedUser, SeedWorld},
+    tests::run_test,
+};
+use rocket::http::Status;
+use serde_json::json;
+
+#[test]
+fn test_delete_unauthenticated() {
+    let world_owner = SeedUser::default();
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let table = SeedTable {
+        world_id: world.world_id,
+        url_slug: "tavern-names".to_owned(),
+        ..SeedTable::default()
+    };
+    let url = format!("/worlds/{}/tables/tavern-names", world.world_id);
+
+    run_test()
+        .seed(&world_owner)
+        .seed(&world)
+        .seed(&table)
+        .delete(url)
+        .has_status(Status::Forbidden)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/unauthorized",
+            "title": "An invalid access token was provided",
+            "status": 403
+        }));
+}
+
+#[test]
+fn test_delete_unknown_table() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let url = format!("/worlds/{}/tables/tavern-names", world.world_id);
+
+    run_test()
+        .seed(&user)
+        .seed(&world)
+        .authenticate("testuser", "password")
+        .delete(url)
+        .has_status(Status::NotFound)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:tables/problems/unknown_table",
+            "title": "The requested table was unknown",
+            "status": 404
+        }));
+}
+
+#[test]
+fn test_delete_success() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let table = SeedTable {
+        world_id: world.world_id,
+        url_slug: "tavern-names".to_owned(),
+        ..SeedTable::default()
+    };
+    let url = format!("/worlds/{}/tables/tavern-names", world.world_id);
+
+    run_test()
+        .seed(&user)
+        .seed(&world)
+        .seed(&table)
+        .authenticate("testuser", "password")
+        .delete(url)
+        .has_status(Status::NoContent)
+        .assert_database(|mut tx| {
+            let count: i64 = tx
+                .query_one("SELECT COUNT(*) FROM random_tables", &[])
+                .unwrap()
+                .get(0);
+            assert_eq!(count, 0);
+        });
+}
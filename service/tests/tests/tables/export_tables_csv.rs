@@ -0,0 +1,64 @@
+use crate::{
+    data::{SeedTable, SeedUser, SeedWorld},
+    tests::run_test,
+};
+use rocket::http::Status;
+use serde_json::json;
+
+#[test]
+fn test_export_unknown_world() {
+    run_test()
+        .get("/worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b/tables.csv")
+        .has_status(Status::NotFound)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:worlds/problems/unknown_world_id",
+            "title": "The requested world ID was unknown",
+            "status": 404
+        }));
+}
+
+#[test]
+fn test_export_tables() {
+    let world_owner = SeedUser::default();
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        url_slug: "middle-earth".to_owned(),
+        ..SeedWorld::default()
+    };
+    let published = SeedTable {
+        world_id: world.world_id,
+        name: "Tavern Names".to_owned(),
+        description: "Names for taverns".to_owned(),
+        url_slug: "tavern-names".to_owned(),
+        entries: json!([
+            { "weight": 2, "text": "The Prancing Pony" },
+            { "weight": 1, "text": "The Green Dragon" }
+        ]),
+        ..SeedTable::default()
+    };
+    let draft = SeedTable {
+        world_id: world.world_id,
+        url_slug: "weather".to_owned(),
+        status: "draft".to_owned(),
+        ..SeedTable::default()
+    };
+    let url = format!("/worlds/{}/tables.csv", world.world_id);
+
+    run_test()
+        .seed_many(&[&world_owner, &world, &published, &draft])
+        .get(url)
+        .has_status(Status::Ok)
+        .has_header("Content-Type", "text/csv; charset=utf-8")
+        .has_header(
+            "Content-Disposition",
+            "attachment; filename=\"middle-earth-tables.csv\"",
+        )
+        .assert_response(|response| {
+            assert_eq!(
+                response.body,
+                "name,url_slug,description,status,publish_at,entries\n\
+                 Tavern Names,tavern-names,Names for taverns,published,,\"2: The Prancing Pony\n1: The Green Dragon\"\n"
+            );
+        });
+}
@@ -0,0 +1,176 @@
+use crate::{
+    data::{hash_password, SeedTable, SeedUser, SeedWorld},
+    tests::run_test,
+};
+use rocket::http::Status;
+use serde_json::json;
+
+#[test]
+fn test_get_unknown_table() {
+    let world_owner = SeedUser::default();
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let url = format!("/worlds/{}/tables/unknown", world.world_id);
+
+    run_test()
+        .seed(&world_owner)
+        .seed(&world)
+        .get(url)
+        .has_status(Status::NotFound)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:tables/problems/unknown_table",
+            "title": "The requested table was unknown",
+            "status": 404
+        }));
+}
+
+#[test]
+fn test_get_table_from_other_world() {
+    let world_owner = SeedUser::default();
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let other_world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let table = SeedTable {
+        world_id: other_world.world_id,
+        url_slug: "tavern-names".to_owned(),
+        ..SeedTable::default()
+    };
+    let url = format!("/worlds/{}/tables/tavern-names", world.world_id);
+
+    run_test()
+        .seed(&world_owner)
+        .seed(&world)
+        .seed(&other_world)
+        .seed(&table)
+        .get(url)
+        .has_status(Status::NotFound);
+}
+
+#[test]
+fn test_get_known_table() {
+    let world_owner = SeedUser::default();
+    let world = SeedWorld {
+        world_id: uuid::Uuid::parse_str("d6c124d5-1060-48ef-aeb8-e591a8261c2b").unwrap(),
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let table = SeedTable {
+        world_id: world.world_id,
+        name: "Tavern Names".to_owned(),
+        description: "Names for taverns".to_owned(),
+        url_slug: "tavern-names".to_owned(),
+        entries: json!([
+          { "weight": 2, "text": "The Prancing Pony" },
+          { "weight": 1, "text": "The {1d3} Broomsticks" }
+        ]),
+        ..SeedTable::default()
+    };
+
+    run_test()
+        .seed(&world_owner)
+        .seed(&world)
+        .seed(&table)
+        .get("/worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b/tables/tavern-names")
+        .has_status(Status::Ok)
+        .has_header("Content-Type", "application/json")
+        .has_header("ETag", format!("\"{}\"", table.version))
+        .has_header_regex(
+            "Link",
+            r#"</worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b/tables/tavern-names>; rel="self""#,
+        )
+        .has_json_body(json!({
+          "name": "Tavern Names",
+          "description": "Names for taverns",
+          "status": "published",
+          "url_slug": "tavern-names",
+          "entries": [
+            { "weight": 2, "text": "The Prancing Pony" },
+            { "weight": 1, "text": "The {1d3} Broomsticks" }
+          ]
+        }));
+}
+
+#[test]
+fn test_get_draft_table_anonymous() {
+    let world_owner = SeedUser::default();
+    let world = SeedWorld {
+        world_id: uuid::Uuid::parse_str("d6c124d5-1060-48ef-aeb8-e591a8261c2b").unwrap(),
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let table = SeedTable {
+        world_id: world.world_id,
+        url_slug: "tavern-names".to_owned(),
+        status: "draft".to_owned(),
+        ..SeedTable::default()
+    };
+
+    run_test()
+        .seed_many(&[&world_owner, &world, &table])
+        .get("/worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b/tables/tavern-names")
+        .has_status(Status::NotFound)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:tables/problems/unknown_table",
+            "title": "The requested table was unknown",
+            "status": 404
+        }));
+}
+
+#[test]
+fn test_get_draft_table_owner() {
+    let world_owner = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        world_id: uuid::Uuid::parse_str("d6c124d5-1060-48ef-aeb8-e591a8261c2b").unwrap(),
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let table = SeedTable {
+        world_id: world.world_id,
+        url_slug: "tavern-names".to_owned(),
+        status: "draft".to_owned(),
+        ..SeedTable::default()
+    };
+
+    run_test()
+        .seed_many(&[&world_owner, &world, &table])
+        .authenticate("testuser", "password")
+        .get("/worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b/tables/tavern-names")
+        .has_status(Status::Ok)
+        .assert_json_body(|body| {
+            assert_eq!(body["status"], json!("draft"));
+        });
+}
+
+#[test]
+fn test_get_table_in_draft_world() {
+    let world_owner = SeedUser::default();
+    let world = SeedWorld {
+        world_id: uuid::Uuid::parse_str("d6c124d5-1060-48ef-aeb8-e591a8261c2b").unwrap(),
+        owner: world_owner.user_id,
+        status: "draft".to_owned(),
+        ..SeedWorld::default()
+    };
+    let table = SeedTable {
+        world_id: world.world_id,
+        url_slug: "tavern-names".to_owned(),
+        ..SeedTable::default()
+    };
+
+    run_test()
+        .seed_many(&[&world_owner, &world, &table])
+        .get("/worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b/tables/tavern-names")
+        .has_status(Status::NotFound);
+}
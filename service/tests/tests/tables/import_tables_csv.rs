@@ -0,0 +1,229 @@
+use crate::{
+    data::{hash_password, SeedTable, SeedUser, SeedUserQuota, SeedWorld},
+    tests::run_test,
+};
+use rocket::http::Status;
+use serde_json::json;
+
+const VALID_CSV: &str = "name,description,entries
+Tavern Names,Names for taverns,\"2: The Prancing Pony
+The Green Dragon\"
+Weather,,\"Sunny
+Raining\"
+";
+
+#[test]
+fn test_import_unauthenticated() {
+    let world_owner = SeedUser::default();
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let url = format!("/worlds/{}/tables.csv", world.world_id);
+
+    run_test()
+        .seed_many(&[&world_owner, &world])
+        .post_raw(url, "text/csv", VALID_CSV)
+        .has_status(Status::Forbidden)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/unauthorized",
+            "title": "An invalid access token was provided",
+            "status": 403
+        }));
+}
+
+#[test]
+fn test_import_dry_run() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let table = SeedTable {
+        world_id: world.world_id,
+        url_slug: "weather".to_owned(),
+        ..SeedTable::default()
+    };
+    let url = format!("/worlds/{}/tables.csv?dry_run=true", world.world_id);
+
+    run_test()
+        .seed_many(&[&user, &world, &table])
+        .authenticate("testuser", "password")
+        .post_raw(url, "text/csv", format!("{}{}", VALID_CSV, ",,Nothing\n"))
+        .has_status(Status::Ok)
+        .has_header("Content-Type", "application/json")
+        .has_json_body(json!({
+            "dry_run": true,
+            "valid": false,
+            "rows": [
+                { "line": 2, "status": "valid" },
+                {
+                    "line": 4,
+                    "status": "invalid",
+                    "fields": {
+                        "url_slug": {
+                            "type": "tag:multiverse,2020:tables/problems/validation_error/duplicate_url_slug",
+                            "title": "The URL Slug was already present for this world"
+                        }
+                    }
+                },
+                {
+                    "line": 6,
+                    "status": "invalid",
+                    "fields": {
+                        "name": {
+                            "type": "tag:multiverse,2020:problems/validation_error/missing",
+                            "title": "The required field was missing"
+                        },
+                        "url_slug": {
+                            "type": "tag:multiverse,2020:problems/validation_error/missing",
+                            "title": "The required field was missing"
+                        }
+                    }
+                }
+            ]
+        }))
+        .assert_database(|mut tx| {
+            let count: i64 = tx
+                .query_one("SELECT COUNT(*) FROM random_tables", &[])
+                .unwrap()
+                .get(0);
+            assert_eq!(count, 1);
+        });
+}
+
+#[test]
+fn test_import_invalid_rows() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let url = format!("/worlds/{}/tables.csv", world.world_id);
+
+    run_test()
+        .seed_many(&[&user, &world])
+        .authenticate("testuser", "password")
+        .post_raw(url, "text/csv", format!("{}{}", VALID_CSV, ",,Nothing\n"))
+        .has_status(Status::UnprocessableEntity)
+        .has_header("Content-Type", "application/problem+json")
+        .assert_json_body(|body| {
+            assert_eq!(
+                body["type"],
+                json!("tag:multiverse,2020:problems/invalid_rows")
+            );
+            assert_eq!(body["rows"][0]["status"], json!("valid"));
+            assert_eq!(body["rows"][1]["status"], json!("valid"));
+            assert_eq!(body["rows"][2]["status"], json!("invalid"));
+        })
+        .assert_database(|mut tx| {
+            let count: i64 = tx
+                .query_one("SELECT COUNT(*) FROM random_tables", &[])
+                .unwrap()
+                .get(0);
+            assert_eq!(count, 0);
+        });
+}
+
+#[test]
+fn test_import_success() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        world_id: uuid::Uuid::parse_str("d6c124d5-1060-48ef-aeb8-e591a8261c2b").unwrap(),
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+
+    run_test()
+        .seed_many(&[&user, &world])
+        .authenticate("testuser", "password")
+        .post_raw(
+            "/worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b/tables.csv",
+            "text/csv",
+            VALID_CSV,
+        )
+        .has_status(Status::Ok)
+        .has_header("Content-Type", "application/json")
+        .has_json_body(json!({
+            "dry_run": false,
+            "valid": true,
+            "rows": [
+                {
+                    "line": 2,
+                    "status": "created",
+                    "href": "/worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b/tables/tavern-names"
+                },
+                {
+                    "line": 4,
+                    "status": "created",
+                    "href": "/worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b/tables/weather"
+                }
+            ]
+        }))
+        .get("/worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b/tables/tavern-names")
+        .has_status(Status::Ok)
+        .has_json_body(json!({
+          "name": "Tavern Names",
+          "description": "Names for taverns",
+          "status": "published",
+          "url_slug": "tavern-names",
+          "entries": [
+            { "weight": 2, "text": "The Prancing Pony" },
+            { "weight": 1, "text": "The Green Dragon" }
+          ]
+        }));
+}
+
+#[test]
+fn test_import_quota_exceeded() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let quota = SeedUserQuota {
+        user_id: user.user_id,
+        resource: "tables".to_owned(),
+        quota: 1,
+    };
+    let url = format!("/worlds/{}/tables.csv", world.world_id);
+
+    run_test()
+        .seed_many(&[&user, &world, &quota])
+        .authenticate("testuser", "password")
+        .post_raw(url, "text/csv", VALID_CSV)
+        .has_status(Status::Forbidden)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/quota_exceeded",
+            "title": "The quota has been exceeded",
+            "status": 403,
+            "resource": "tables",
+            "used": 0,
+            "limit": 1
+        }))
+        .assert_database(|mut tx| {
+            let count: i64 = tx
+                .query_one("SELECT COUNT(*) FROM random_tables", &[])
+                .unwrap()
+                .get(0);
+            assert_eq!(count, 0);
+        });
+}
@@ -0,0 +1,133 @@
+use crate::{
+    data::{SeedTable, SeedUser, SeedWorld},
+    tests::run_test,
+};
+use rocket::http::Status;
+use serde_json::json;
+
+#[test]
+fn test_list_unknown_world() {
+    run_test()
+        .get("/worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b/tables")
+        .has_status(Status::NotFound)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:worlds/problems/unknown_world_id",
+            "title": "The requested world ID was unknown",
+            "status": 404
+        }));
+}
+
+#[test]
+fn test_list_no_tables() {
+    let world_owner = SeedUser::default();
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let url = format!("/worlds/{}/tables", world.world_id);
+
+    run_test()
+        .seed(&world_owner)
+        .seed(&world)
+        .get(url)
+        .has_status(Status::Ok)
+        .has_header("Content-Type", "application/json")
+        .has_json_body(json!({
+          "entries": [],
+          "pagination": {
+            "offset": 0,
+            "count": 10,
+            "total": 0
+          }
+        }));
+}
+
+#[test]
+fn test_list_tables() {
+    let world_owner = SeedUser::default();
+    let world = SeedWorld {
+        world_id: uuid::Uuid::parse_str("d6c124d5-1060-48ef-aeb8-e591a8261c2b").unwrap(),
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let other_world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let first = SeedTable {
+        world_id: world.world_id,
+        name: "Weather".to_owned(),
+        description: "".to_owned(),
+        url_slug: "weather".to_owned(),
+        ..SeedTable::default()
+    };
+    let second = SeedTable {
+        world_id: world.world_id,
+        name: "Tavern Names".to_owned(),
+        description: "".to_owned(),
+        url_slug: "tavern-names".to_owned(),
+        ..SeedTable::default()
+    };
+    let other = SeedTable {
+        world_id: other_world.world_id,
+        ..SeedTable::default()
+    };
+
+    run_test()
+        .seed(&world_owner)
+        .seed_many(&[&world, &other_world, &first, &second, &other])
+        .get("/worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b/tables?count=1")
+        .has_status(Status::Ok)
+        .has_header("Content-Type", "application/json")
+        .has_header_regex(
+            "Link",
+            r#"</worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b/tables/tavern-names>; rel="item"; anchor="\#/entries/0""#,
+        )
+        .has_json_body(json!({
+          "entries": [
+            {
+              "name": "Tavern Names",
+              "description": "",
+              "status": "published",
+              "url_slug": "tavern-names",
+              "entries": []
+            }
+          ],
+          "pagination": {
+            "offset": 0,
+            "count": 1,
+            "total": 2
+          }
+        }));
+}
+
+#[test]
+fn test_list_tables_excludes_drafts() {
+    let world_owner = SeedUser::default();
+    let world = SeedWorld {
+        world_id: uuid::Uuid::parse_str("d6c124d5-1060-48ef-aeb8-e591a8261c2b").unwrap(),
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let published = SeedTable {
+        world_id: world.world_id,
+        url_slug: "weather".to_owned(),
+        ..SeedTable::default()
+    };
+    let draft = SeedTable {
+        world_id: world.world_id,
+        url_slug: "tavern-names".to_owned(),
+        status: "draft".to_owned(),
+        ..SeedTable::default()
+    };
+
+    run_test()
+        .seed_many(&[&world_owner, &world, &published, &draft])
+        .get("/worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b/tables")
+        .has_status(Status::Ok)
+        .assert_json_body(|body| {
+            assert_eq!(body["pagination"]["total"], json!(1));
+            assert_eq!(body["entries"][0]["url_slug"], json!("weather"));
+        });
+}
@@ -0,0 +1,8 @@
+mod create_table;
+mod delete_table;
+mod export_tables_csv;
+mod get_table;
+mod import_tables_csv;
+mod list_tables;
+mod roll_table;
+mod update_table;
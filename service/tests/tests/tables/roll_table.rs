@@ -0,0 +1,255 @@
+use crate::{
+    data::{SeedTable, SeedUser, SeedWorld},
+    tests::run_test,
+};
+use rocket::http::Status;
+use serde_json::{json, Value};
+
+#[test]
+fn test_roll_unknown_table() {
+    let world_owner = SeedUser::default();
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let url = format!("/worlds/{}/tables/unknown/roll", world.world_id);
+
+    run_test()
+        .seed(&world_owner)
+        .seed(&world)
+        .post(url, json!({}))
+        .has_status(Status::NotFound)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:tables/problems/unknown_table",
+            "title": "The requested table was unknown",
+            "status": 404
+        }));
+}
+
+#[test]
+fn test_roll_empty_table() {
+    let world_owner = SeedUser::default();
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let table = SeedTable {
+        world_id: world.world_id,
+        url_slug: "empty".to_owned(),
+        ..SeedTable::default()
+    };
+    let url = format!("/worlds/{}/tables/empty/roll", world.world_id);
+
+    run_test()
+        .seed(&world_owner)
+        .seed(&world)
+        .seed(&table)
+        .post(url, json!({}))
+        .has_status(Status::UnprocessableEntity)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:tables/problems/empty_table",
+            "title": "A table referenced by the roll had no entries",
+            "status": 422,
+            "table": "empty"
+        }));
+}
+
+#[test]
+fn test_roll_unknown_nested_table() {
+    let world_owner = SeedUser::default();
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let table = SeedTable {
+        world_id: world.world_id,
+        url_slug: "encounters".to_owned(),
+        entries: json!([{ "weight": 1, "text": "A {table:monsters}" }]),
+        ..SeedTable::default()
+    };
+    let url = format!("/worlds/{}/tables/encounters/roll", world.world_id);
+
+    run_test()
+        .seed(&world_owner)
+        .seed(&world)
+        .seed(&table)
+        .post(url, json!({}))
+        .has_status(Status::UnprocessableEntity)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:tables/problems/unknown_referenced_table",
+            "title": "A table referenced by the roll was unknown",
+            "status": 422,
+            "table": "monsters"
+        }));
+}
+
+#[test]
+fn test_roll_recursive_table() {
+    let world_owner = SeedUser::default();
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let table = SeedTable {
+        world_id: world.world_id,
+        url_slug: "forever".to_owned(),
+        entries: json!([{ "weight": 1, "text": "And {table:forever}" }]),
+        ..SeedTable::default()
+    };
+    let url = format!("/worlds/{}/tables/forever/roll", world.world_id);
+
+    run_test()
+        .seed(&world_owner)
+        .seed(&world)
+        .seed(&table)
+        .post(url, json!({}))
+        .has_status(Status::UnprocessableEntity)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:tables/problems/recursion_limit",
+            "title": "The tables referenced by the roll were nested too deeply",
+            "status": 422
+        }));
+}
+
+#[test]
+fn test_roll_too_many_tables() {
+    let world_owner = SeedUser::default();
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let wide = SeedTable {
+        world_id: world.world_id,
+        url_slug: "wide".to_owned(),
+        entries: json!([{ "weight": 1, "text": "{table:leaf} ".repeat(101) }]),
+        ..SeedTable::default()
+    };
+    let leaf = SeedTable {
+        world_id: world.world_id,
+        url_slug: "leaf".to_owned(),
+        entries: json!([{ "weight": 1, "text": "Leaf" }]),
+        ..SeedTable::default()
+    };
+    let url = format!("/worlds/{}/tables/wide/roll", world.world_id);
+
+    run_test()
+        .seed(&world_owner)
+        .seed_many(&[&world, &wide, &leaf])
+        .post(url, json!({}))
+        .has_status(Status::UnprocessableEntity)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:tables/problems/roll_limit",
+            "title": "The roll referenced too many tables",
+            "status": 422
+        }));
+}
+
+#[test]
+fn test_roll_nested_tables() {
+    let world_owner = SeedUser::default();
+    let world = SeedWorld {
+        world_id: uuid::Uuid::parse_str("d6c124d5-1060-48ef-aeb8-e591a8261c2b").unwrap(),
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let encounters = SeedTable {
+        world_id: world.world_id,
+        name: "Encounters".to_owned(),
+        url_slug: "encounters".to_owned(),
+        entries: json!([{ "weight": 1, "text": "{2d1+1} {table:monsters}" }]),
+        ..SeedTable::default()
+    };
+    let monsters = SeedTable {
+        world_id: world.world_id,
+        name: "Monsters".to_owned(),
+        url_slug: "monsters".to_owned(),
+        entries: json!([{ "weight": 5, "text": "goblins" }]),
+        ..SeedTable::default()
+    };
+
+    run_test()
+        .seed(&world_owner)
+        .seed_many(&[&world, &encounters, &monsters])
+        .post(
+            "/worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b/tables/encounters/roll?seed=1234",
+            json!({}),
+        )
+        .has_status(Status::Ok)
+        .has_header("Content-Type", "application/json")
+        .has_header_regex(
+            "Link",
+            r#"</worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b/tables/encounters>; rel="related""#,
+        )
+        .assert_json_body(|body| {
+            assert_eq!(body.pointer("/seed"), Some(&json!(1234)));
+            assert_eq!(body.pointer("/result"), Some(&json!("3 goblins")));
+            assert_eq!(body.pointer("/trace/type"), Some(&json!("table")));
+            assert_eq!(body.pointer("/trace/table"), Some(&json!("encounters")));
+            assert_eq!(body.pointer("/trace/roll"), Some(&json!(1)));
+            assert_eq!(body.pointer("/trace/entry"), Some(&json!(0)));
+            assert_eq!(
+                body.pointer("/trace/steps/0"),
+                Some(&json!({
+                  "type": "dice",
+                  "expression": "2d1+1",
+                  "terms": [
+                    { "term": "2d1", "rolls": [1, 1], "kept": [1, 1], "value": 2 },
+                    { "term": "1", "rolls": [], "kept": [], "value": 1 }
+                  ],
+                  "total": 3
+                }))
+            );
+            assert_eq!(body.pointer("/trace/steps/1/type"), Some(&json!("table")));
+            assert_eq!(
+                body.pointer("/trace/steps/1/table"),
+                Some(&json!("monsters"))
+            );
+            assert_eq!(body.pointer("/trace/steps/1/total_weight"), Some(&json!(5)));
+            assert_eq!(
+                body.pointer("/trace/steps/1/result"),
+                Some(&json!("goblins"))
+            );
+        });
+}
+
+#[test]
+fn test_roll_same_seed_is_reproducible() {
+    let world_owner = SeedUser::default();
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let table = SeedTable {
+        world_id: world.world_id,
+        url_slug: "treasure".to_owned(),
+        entries: json!([
+          { "weight": 3, "text": "{3d6+10} gold pieces" },
+          { "weight": 2, "text": "{4d6kh3} gems" },
+          { "weight": 1, "text": "A magic sword" }
+        ]),
+        ..SeedTable::default()
+    };
+    let url = format!("/worlds/{}/tables/treasure/roll?seed=98765", world.world_id);
+
+    let mut first: Option<Value> = None;
+    let mut second: Option<Value> = None;
+
+    run_test()
+        .seed(&world_owner)
+        .seed(&world)
+        .seed(&table)
+        .post(url.clone(), json!({}))
+        .has_status(Status::Ok)
+        .assert_json_body(|body| first = Some(body.clone()))
+        .post(url, json!({}))
+        .has_status(Status::Ok)
+        .assert_json_body(|body| second = Some(body.clone()));
+
+    assert!(first.is_some());
+    assert_eq!(first, second);
+}
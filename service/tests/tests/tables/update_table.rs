@@ -0,0 +1,117 @@
+use crate::{
+    data::{hash_password, SeedTable, SeedUser, SeedWorld},
+    tests::run_test,
+};
+use rocket::http::Status;
+use serde_json::json;
+
+#[test]
+fn test_update_unauthenticated() {
+    let world_owner = SeedUser::default();
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let table = SeedTable {
+        world_id: world.world_id,
+        url_slug: "tavern-names".to_owned(),
+        ..SeedTable::default()
+    };
+    let url = format!("/worlds/{}/tables/tavern-names", world.world_id);
+
+    run_test()
+        .seed(&world_owner)
+        .seed(&world)
+        .seed(&table)
+        .put(url, json!({}))
+        .has_status(Status::Forbidden)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/unauthorized",
+            "title": "An invalid access token was provided",
+            "status": 403
+        }));
+}
+
+#[test]
+fn test_update_unknown_table() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let url = format!("/worlds/{}/tables/tavern-names", world.world_id);
+
+    run_test()
+        .seed(&user)
+        .seed(&world)
+        .authenticate("testuser", "password")
+        .put(url, json!({ "name": "Tavern Names" }))
+        .has_status(Status::NotFound)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:tables/problems/unknown_table",
+            "title": "The requested table was unknown",
+            "status": 404
+        }));
+}
+
+#[test]
+fn test_update_success() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        world_id: uuid::Uuid::parse_str("d6c124d5-1060-48ef-aeb8-e591a8261c2b").unwrap(),
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let table = SeedTable {
+        world_id: world.world_id,
+        url_slug: "tavern-names".to_owned(),
+        ..SeedTable::default()
+    };
+
+    run_test()
+        .seed(&user)
+        .seed(&world)
+        .seed(&table)
+        .authenticate("testuser", "password")
+        .put(
+            "/worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b/tables/tavern-names",
+            json!({
+              "name": "Inn Names",
+              "entries": [
+                { "weight": 3, "text": "The Green Dragon" }
+              ]
+            }),
+        )
+        .has_status(Status::Ok)
+        .has_header("Content-Type", "application/json")
+        .has_header_regex(
+            "Link",
+            r#"</worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b/tables/inn-names>; rel="self""#,
+        )
+        .has_json_body(json!({
+          "name": "Inn Names",
+          "description": "",
+          "status": "published",
+          "url_slug": "inn-names",
+          "entries": [
+            { "weight": 3, "text": "The Green Dragon" }
+          ]
+        }))
+        .assert_database(|mut tx| {
+            let count: i64 = tx
+                .query_one("SELECT COUNT(*) FROM random_tables", &[])
+                .unwrap()
+                .get(0);
+            assert_eq!(count, 1);
+        });
+}
@@ -0,0 +1,115 @@
+use crate::{
+    data::{hash_password, SeedUser, SeedWorldTemplate},
+    tests::run_test,
+};
+use rocket::http::Status;
+use serde_json::json;
+use uritemplate::UriTemplate;
+
+#[test]
+fn test_delete_unauthenticated() {
+    let user = SeedUser::default();
+    let template = SeedWorldTemplate {
+        owner: user.user_id,
+        ..SeedWorldTemplate::default()
+    };
+
+    let url = UriTemplate::new("/templates/{id}")
+        .set("id", template.template_id.to_string())
+        .build();
+
+    run_test()
+        .seed_many(&[&user, &template])
+        .delete(url)
+        .has_status(Status::Forbidden)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/unauthorized",
+            "title": "An invalid access token was provided",
+            "status": 403
+        }));
+}
+
+#[test]
+fn test_delete_other_users_template() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let other_user = SeedUser::default();
+    let template = SeedWorldTemplate {
+        owner: other_user.user_id,
+        ..SeedWorldTemplate::default()
+    };
+
+    let url = UriTemplate::new("/templates/{id}")
+        .set("id", template.template_id.to_string())
+        .build();
+
+    run_test()
+        .seed_many(&[&user, &other_user, &template])
+        .authenticate("testuser", "password")
+        .delete(url)
+        .has_status(Status::Forbidden)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/unauthorized",
+            "title": "An invalid access token was provided",
+            "status": 403
+        }));
+}
+
+#[test]
+fn test_delete_bundled_template() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+
+    run_test()
+        .seed(&user)
+        .authenticate("testuser", "password")
+        .delete("/templates/fantasy")
+        .has_status(Status::NotFound)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:templates/problems/unknown_template",
+            "title": "The requested template was unknown",
+            "status": 404
+        }));
+}
+
+#[test]
+fn test_delete_success() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let template = SeedWorldTemplate {
+        owner: user.user_id,
+        ..SeedWorldTemplate::default()
+    };
+
+    let url = UriTemplate::new("/templates/{id}")
+        .set("id", template.template_id.to_string())
+        .build();
+
+    run_test()
+        .seed_many(&[&user, &template])
+        .authenticate("testuser", "password")
+        .delete(url)
+        .has_status(Status::NoContent)
+        .assert_database(|mut database| {
+            let count: i64 = database
+                .query_one(
+                    "SELECT COUNT(*) FROM world_templates WHERE template_id = $1",
+                    &[&template.template_id],
+                )
+                .unwrap()
+                .get(0);
+            assert_eq!(count, 0);
+        });
+}
@@ -0,0 +1,125 @@
+use crate::{
+    data::{SeedUser, SeedWorldTemplate},
+    tests::run_test,
+};
+use rocket::http::Status;
+use serde_json::json;
+use uritemplate::UriTemplate;
+
+#[test]
+fn test_get_unknown_template() {
+    run_test()
+        .get("/templates/unknown")
+        .has_status(Status::NotFound)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:templates/problems/unknown_template",
+            "title": "The requested template was unknown",
+            "status": 404
+        }));
+}
+
+#[test]
+fn test_get_unknown_published_template() {
+    let url = UriTemplate::new("/templates/{id}")
+        .set("id", uuid::Uuid::new_v4().to_string())
+        .build();
+
+    run_test()
+        .get(url)
+        .has_status(Status::NotFound)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:templates/problems/unknown_template",
+            "title": "The requested template was unknown",
+            "status": 404
+        }));
+}
+
+#[test]
+fn test_get_bundled_template() {
+    run_test()
+        .get("/templates/fantasy")
+        .has_status(Status::Ok)
+        .has_header("Content-Type", "application/json")
+        .has_header("Link", r#"</templates/fantasy>; rel="self""#)
+        .assert_json_body(|body| {
+            assert_eq!(body["name"], json!("Fantasy"));
+            assert_eq!(body["bundled"], json!(true));
+            assert_eq!(body["content"]["tables"][0]["name"], json!("Tavern Names"));
+            assert_eq!(
+                body["content"]["tables"][0]["entries"][0],
+                json!({ "weight": 2, "text": "The Prancing Pony" })
+            );
+            assert_eq!(
+                body["content"]["tables"][0]["entries"][1],
+                json!({ "weight": 1, "text": "The {1d3} Broomsticks" })
+            );
+        });
+}
+
+#[test]
+fn test_get_published_template() {
+    let user = SeedUser {
+        display_name: "Test User".to_owned(),
+        ..SeedUser::default()
+    };
+    let template = SeedWorldTemplate {
+        owner: user.user_id,
+        name: "My Template".to_owned(),
+        description: "My published template".to_owned(),
+        content: json!({
+            "tables": [
+                {
+                    "name": "Names",
+                    "description": "Some names",
+                    "url_slug": "names",
+                    "entries": [{ "weight": 1, "text": "Bob" }]
+                }
+            ],
+            "campaigns": [
+                {
+                    "name": "My Campaign",
+                    "description": "",
+                    "sessions": [
+                        {
+                            "date": "2020-06-01",
+                            "summary": "We met Bob",
+                            "entities": ["/worlds/{world}/tables/names"]
+                        }
+                    ]
+                }
+            ]
+        }),
+        ..SeedWorldTemplate::default()
+    };
+
+    let url = UriTemplate::new("/templates/{id}")
+        .set("id", template.template_id.to_string())
+        .build();
+
+    run_test()
+        .seed_many(&[&user, &template])
+        .get(url)
+        .has_status(Status::Ok)
+        .has_header("Content-Type", "application/json")
+        .has_header_regex(
+            "Link",
+            format!(r#"</templates/{}>; rel="self""#, template.template_id),
+        )
+        .has_header_regex(
+            "Link",
+            format!(
+                r#"</users/{}>; rel="author"; title="Test User""#,
+                user.user_id
+            ),
+        )
+        .has_json_body(json!({
+            "name": "My Template",
+            "description": "My published template",
+            "bundled": false,
+            "tables": 1,
+            "campaigns": 1,
+            "content": template.content
+        }));
+}
@@ -0,0 +1,82 @@
+use crate::{
+    data::{SeedUser, SeedWorldTemplate},
+    tests::run_test,
+};
+use rocket::http::Status;
+use serde_json::json;
+
+#[test]
+fn test_list_bundled_templates() {
+    run_test()
+        .get("/templates")
+        .has_status(Status::Ok)
+        .has_header("Content-Type", "application/json")
+        .has_header_regex("Link", r#"</templates/fantasy>; rel="item"; anchor="\#/entries/0""#)
+        .has_header_regex(
+            "Link",
+            r#"</templates/science-fiction>; rel="item"; anchor="\#/entries/1""#,
+        )
+        .has_json_body(json!({
+            "entries": [
+                {
+                    "name": "Fantasy",
+                    "description": "A classic fantasy setting of taverns, dungeons and dragons, ready for a first adventure.",
+                    "bundled": true,
+                    "tables": 2,
+                    "campaigns": 1
+                },
+                {
+                    "name": "Science Fiction",
+                    "description": "A setting among the stars, with starships, space stations and strange new worlds.",
+                    "bundled": true,
+                    "tables": 2,
+                    "campaigns": 1
+                }
+            ]
+        }));
+}
+
+#[test]
+fn test_list_published_templates() {
+    let user = SeedUser {
+        display_name: "Test User".to_owned(),
+        ..SeedUser::default()
+    };
+    let template = SeedWorldTemplate {
+        owner: user.user_id,
+        name: "My Template".to_owned(),
+        description: "My published template".to_owned(),
+        content: json!({
+            "tables": [
+                {
+                    "name": "Names",
+                    "description": "",
+                    "url_slug": "names",
+                    "entries": [{ "weight": 1, "text": "Bob" }]
+                }
+            ],
+            "campaigns": []
+        }),
+        ..SeedWorldTemplate::default()
+    };
+
+    run_test()
+        .seed_many(&[&user, &template])
+        .get("/templates")
+        .has_status(Status::Ok)
+        .has_header("Content-Type", "application/json")
+        .has_header_regex(
+            "Link",
+            format!(
+                r#"</templates/{}>; rel="item"; anchor="\#/entries/2""#,
+                template.template_id
+            ),
+        )
+        .has_header_regex(
+            "Link",
+            format!(
+                r#"</users/{}>; rel="author"; title="Test User"; anchor="\#/entries/2""#,
+                user.user_id
+            ),
+        );
+}
@@ -0,0 +1,4 @@
+mod delete_template;
+mod get_template;
+mod list_templates;
+mod publish_template;
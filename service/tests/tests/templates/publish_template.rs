@@ -0,0 +1,222 @@
+use crate::{
+    data::{hash_password, SeedCampaign, SeedSession, SeedTable, SeedUser, SeedWorld},
+    tests::run_test,
+};
+use rocket::http::Status;
+use serde_json::json;
+
+#[test]
+fn test_publish_unauthenticated() {
+    run_test()
+        .post("/templates", json!({}))
+        .has_status(Status::Forbidden)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/unauthorized",
+            "title": "An invalid access token was provided",
+            "status": 403
+        }));
+}
+
+#[test]
+fn test_publish_empty_body() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+
+    run_test()
+        .seed(&user)
+        .authenticate("testuser", "password")
+        .post("/templates", json!({}))
+        .has_status(Status::UnprocessableEntity)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/validation_error",
+            "title": "A validation error occurred",
+            "status": 422,
+            "fields": {
+              "name": {
+                "type": "tag:multiverse,2020:problems/validation_error/missing",
+                "title": "The required field was missing"
+              },
+              "world": {
+                "type": "tag:multiverse,2020:problems/validation_error/missing",
+                "title": "The required field was missing"
+              }
+            }
+        }));
+}
+
+#[test]
+fn test_publish_unknown_world() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+
+    run_test()
+        .seed(&user)
+        .authenticate("testuser", "password")
+        .post(
+            "/templates",
+            json!({
+              "name": "My Template",
+              "world": format!("/worlds/{}", uuid::Uuid::new_v4())
+            }),
+        )
+        .has_status(Status::NotFound)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:worlds/problems/unknown_world_id",
+            "title": "The requested world ID was unknown",
+            "status": 404
+        }));
+}
+
+#[test]
+fn test_publish_other_users_world() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld::default();
+
+    run_test()
+        .seed_many(&[&user, &world])
+        .authenticate("testuser", "password")
+        .post(
+            "/templates",
+            json!({
+              "name": "My Template",
+              "world": format!("/worlds/{}", world.world_id)
+            }),
+        )
+        .has_status(Status::Forbidden)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/unauthorized",
+            "title": "An invalid access token was provided",
+            "status": 403
+        }));
+}
+
+#[test]
+fn test_publish_success() {
+    let user = SeedUser {
+        display_name: "Test User".to_owned(),
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let table = SeedTable {
+        world_id: world.world_id,
+        name: "Tavern Names".to_owned(),
+        description: "Names of taverns".to_owned(),
+        url_slug: "tavern-names".to_owned(),
+        entries: json!([
+          { "weight": 2, "text": "The Prancing Pony" },
+          { "weight": 1, "text": "The Green Dragon" }
+        ]),
+        ..SeedTable::default()
+    };
+    let draft_table = SeedTable {
+        world_id: world.world_id,
+        status: "draft".to_owned(),
+        ..SeedTable::default()
+    };
+    let campaign = SeedCampaign {
+        world_id: world.world_id,
+        name: "The Taverns of Waterdeep".to_owned(),
+        description: "".to_owned(),
+        ..SeedCampaign::default()
+    };
+    let session = SeedSession {
+        campaign_id: campaign.campaign_id,
+        session_date: chrono::NaiveDate::from_ymd(2020, 6, 1),
+        summary: "We went to the pub".to_owned(),
+        entities: vec![
+            format!("/worlds/{}/tables/tavern-names", world.world_id),
+            "/users/elsewhere".to_owned(),
+        ],
+        ..SeedSession::default()
+    };
+
+    run_test()
+        .seed_many(&[&user, &world, &table, &draft_table, &campaign, &session])
+        .authenticate("testuser", "password")
+        .post(
+            "/templates",
+            json!({
+              "name": "My Template",
+              "description": "My published template",
+              "world": format!("/worlds/{}", world.world_id)
+            }),
+        )
+        .has_status(Status::Ok)
+        .has_header("Content-Type", "application/json")
+        .has_header_regex(
+            "Link",
+            r#"</templates/[0-9a-f]{8}\b-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-\b[0-9a-f]{12}>; rel="self""#,
+        )
+        .has_header_regex(
+            "Link",
+            format!(
+                r#"</users/{}>; rel="author"; title="Test User""#,
+                user.user_id
+            ),
+        )
+        .has_json_body(json!({
+            "name": "My Template",
+            "description": "My published template",
+            "bundled": false,
+            "tables": 1,
+            "campaigns": 1,
+            "content": {
+                "tables": [
+                    {
+                        "name": "Tavern Names",
+                        "description": "Names of taverns",
+                        "url_slug": "tavern-names",
+                        "entries": [
+                            { "weight": 2, "text": "The Prancing Pony" },
+                            { "weight": 1, "text": "The Green Dragon" }
+                        ]
+                    }
+                ],
+                "campaigns": [
+                    {
+                        "name": "The Taverns of Waterdeep",
+                        "description": "",
+                        "sessions": [
+                            {
+                                "date": "2020-06-01",
+                                "summary": "We went to the pub",
+                                "entities": [
+                                    "/worlds/{world}/tables/tavern-names",
+                                    "/users/elsewhere"
+                                ]
+                            }
+                        ]
+                    }
+                ]
+            }
+        }))
+        .assert_database(|mut database| {
+            let count: i64 = database
+                .query_one(
+                    "SELECT COUNT(*) FROM world_templates WHERE owner_id = $1",
+                    &[&user.user_id],
+                )
+                .unwrap()
+                .get(0);
+            assert_eq!(count, 1);
+        });
+}
@@ -0,0 +1,100 @@
+use crate::{
+    data::{hash_password, SeedUser},
+    tests::run_test,
+};
+use galvanic_assert::{assert_that, matchers::*};
+use rocket::http::Status;
+use serde_json::json;
+use uritemplate::UriTemplate;
+use uuid::Uuid;
+
+#[test]
+fn test_delete_avatar_wrong_user() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+
+    let url = UriTemplate::new("/users/{id}/avatar")
+        .set("id", Uuid::new_v4().to_string())
+        .build();
+
+    run_test()
+        .seed(&user)
+        .authenticate("testuser", "password")
+        .delete(url)
+        .has_status(Status::Forbidden)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/unauthorized",
+            "title": "An invalid access token was provided",
+            "status": 403
+        }));
+}
+
+#[test]
+fn test_delete_uploaded_avatar() {
+    let user_id = Uuid::new_v4();
+    let url = UriTemplate::new("/users/{id}/avatar")
+        .set("id", user_id.to_string())
+        .build();
+    let user = SeedUser {
+        user_id,
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        email_address: "testuser@example.com".to_owned(),
+        display_name: "Test User".to_owned(),
+        avatar_url: Some(url.clone()),
+        ..SeedUser::default()
+    };
+
+    run_test()
+        .seed(&user.clone())
+        .seed_blob(format!("avatars/{}/128", user_id), b"Avatar")
+        .authenticate("testuser", "password")
+        .delete(url.clone())
+        .has_status(Status::Ok)
+        .has_header("Content-Type", "application/json")
+        .has_json_body(json!({
+            "username": "testuser",
+            "display_name": "Test User",
+            "email_address": "testuser@example.com"
+        }))
+        .assert_database(|mut conn| {
+            let user_row = conn
+                .query_one("SELECT * FROM users WHERE user_id = $1", &[&user.user_id])
+                .unwrap();
+
+            assert_that!(&user_row.get("avatar_url"), eq(Option::<&str>::None));
+        })
+        .get(url)
+        .has_status(Status::NotFound);
+}
+
+#[test]
+fn test_delete_external_avatar() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        avatar_url: Some("http://example.com/avatar".to_owned()),
+        ..SeedUser::default()
+    };
+
+    let url = UriTemplate::new("/users/{id}/avatar")
+        .set("id", user.user_id.to_string())
+        .build();
+
+    run_test()
+        .seed(&user.clone())
+        .authenticate("testuser", "password")
+        .delete(url)
+        .has_status(Status::Ok)
+        .assert_database(|mut conn| {
+            let user_row = conn
+                .query_one("SELECT * FROM users WHERE user_id = $1", &[&user.user_id])
+                .unwrap();
+
+            assert_that!(&user_row.get("avatar_url"), eq(Option::<&str>::None));
+        });
+}
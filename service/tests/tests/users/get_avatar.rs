@@ -0,0 +1,49 @@
+use crate::{data::SeedUser, tests::run_test};
+use rocket::http::Status;
+use serde_json::json;
+use uritemplate::UriTemplate;
+
+#[test]
+fn test_get_unknown_avatar() {
+    let user = SeedUser::default();
+
+    let url = UriTemplate::new("/users/{id}/avatar")
+        .set("id", user.user_id.to_string())
+        .build();
+
+    run_test()
+        .seed(&user)
+        .get(url)
+        .has_status(Status::NotFound)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:users/problems/unknown_avatar",
+            "title": "The requested avatar was unknown",
+            "status": 404
+        }));
+}
+
+#[test]
+fn test_get_avatar_nearest_size() {
+    let user = SeedUser::default();
+
+    let url = UriTemplate::new("/users/{id}/avatar")
+        .set("id", user.user_id.to_string())
+        .build();
+
+    run_test()
+        .seed(&user)
+        .seed_blob(format!("avatars/{}/64", user.user_id), b"Small")
+        .seed_blob(format!("avatars/{}/128", user.user_id), b"Medium")
+        .seed_blob(format!("avatars/{}/256", user.user_id), b"Large")
+        .get(format!("{}?size=100", url))
+        .has_status(Status::Ok)
+        .has_header("Content-Type", "image/png")
+        .has_header(
+            "ETag",
+            "\"8e588cd187741f1cd76f5fab77b7208782a8c21d764ce7d7a4cf3ac4e0968873\"",
+        )
+        .assert_binary_body(|body| assert_eq!(body, b"Medium"))
+        .get(format!("{}?size=1000", url))
+        .assert_binary_body(|body| assert_eq!(body, b"Large"));
+}
@@ -1,3 +1,6 @@
+mod delete_avatar;
+mod get_avatar;
 mod get_user;
 mod lookup_username;
 mod patch_user;
+mod put_avatar;
@@ -0,0 +1,169 @@
+use crate::{
+    data::{hash_password, SeedUser},
+    tests::{multipart, run_test, MULTIPART},
+};
+use galvanic_assert::{assert_that, matchers::*};
+use image::{DynamicImage, GenericImageView, ImageOutputFormat};
+use rocket::http::Status;
+use serde_json::json;
+use uritemplate::UriTemplate;
+use uuid::Uuid;
+
+#[test]
+fn test_put_avatar_unauthenticated() {
+    let url = UriTemplate::new("/users/{id}/avatar")
+        .set("id", Uuid::new_v4().to_string())
+        .build();
+
+    run_test()
+        .put_raw(url, MULTIPART, multipart("file", "avatar.png", b""))
+        .has_status(Status::Forbidden)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/unauthorized",
+            "title": "An invalid access token was provided",
+            "status": 403
+        }));
+}
+
+#[test]
+fn test_put_avatar_wrong_user() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+
+    let url = UriTemplate::new("/users/{id}/avatar")
+        .set("id", Uuid::new_v4().to_string())
+        .build();
+
+    run_test()
+        .seed(&user)
+        .authenticate("testuser", "password")
+        .put_raw(url, MULTIPART, multipart("file", "avatar.png", b""))
+        .has_status(Status::Forbidden)
+        .has_header("Content-Type", "application/problem+json");
+}
+
+#[test]
+fn test_put_avatar_not_image() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+
+    let url = UriTemplate::new("/users/{id}/avatar")
+        .set("id", user.user_id.to_string())
+        .build();
+
+    run_test()
+        .seed(&user)
+        .authenticate("testuser", "password")
+        .put_raw(
+            url,
+            MULTIPART,
+            multipart("file", "avatar.png", b"Not an image"),
+        )
+        .has_status(Status::UnprocessableEntity)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:users/problems/invalid_avatar",
+            "title": "The avatar was not a valid image",
+            "status": 422
+        }));
+}
+
+#[test]
+fn test_put_avatar_too_large() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+
+    let url = UriTemplate::new("/users/{id}/avatar")
+        .set("id", user.user_id.to_string())
+        .build();
+
+    // A GIF header claiming to be 65535x65535 pixels, with no image data
+    let header = b"GIF89a\xff\xff\xff\xff\x00\x00\x00\x3b";
+
+    run_test()
+        .seed(&user)
+        .authenticate("testuser", "password")
+        .put_raw(url, MULTIPART, multipart("file", "avatar.gif", header))
+        .has_status(Status::UnprocessableEntity)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "status": 422,
+            "type": "tag:multiverse,2020:problems/validation_error",
+            "title": "A validation error occurred",
+            "fields": {
+                "file": {
+                    "title": "The avatar image was too large",
+                    "type": "tag:multiverse,2020:users/problems/validation_error/avatar_too_large"
+                }
+            }
+        }));
+}
+
+#[test]
+fn test_put_avatar_success() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        email_address: "testuser@example.com".to_owned(),
+        display_name: "Test User".to_owned(),
+        avatar_url: Some("http://example.com/avatar".to_owned()),
+        ..SeedUser::default()
+    };
+
+    let url = UriTemplate::new("/users/{id}/avatar")
+        .set("id", user.user_id.to_string())
+        .build();
+
+    let mut image = vec![];
+    DynamicImage::new_rgb8(600, 300)
+        .write_to(&mut image, ImageOutputFormat::Jpeg(90))
+        .unwrap();
+
+    run_test()
+        .seed(&user.clone())
+        .authenticate("testuser", "password")
+        .put_raw(
+            url.clone(),
+            MULTIPART,
+            multipart("file", "avatar.jpg", &image),
+        )
+        .has_status(Status::Ok)
+        .has_header("Content-Type", "application/json")
+        .has_json_body(json!({
+            "username": "testuser",
+            "display_name": "Test User",
+            "email_address": "testuser@example.com",
+            "avatar_url": url
+        }))
+        .assert_database(|mut conn| {
+            let user_row = conn
+                .query_one("SELECT * FROM users WHERE user_id = $1", &[&user.user_id])
+                .unwrap();
+
+            assert_that!(&user_row.get("avatar_url"), eq(Some(url.as_str())));
+            assert_that!(&user_row.get("version"), not(eq(user.version)));
+        })
+        .get(format!("{}?size=64", url))
+        .has_status(Status::Ok)
+        .has_header("Content-Type", "image/png")
+        .assert_binary_body(|body| {
+            let avatar = image::load_from_memory(body).unwrap();
+            assert_that!(&avatar.dimensions(), eq((64, 64)));
+        })
+        .get(url)
+        .has_status(Status::Ok)
+        .assert_binary_body(|body| {
+            let avatar = image::load_from_memory(body).unwrap();
+            assert_that!(&avatar.dimensions(), eq((128, 128)));
+        });
+}
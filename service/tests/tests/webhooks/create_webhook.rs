@@ -0,0 +1,220 @@
+use crate::{
+    data::{hash_password, SeedUser, SeedWorld},
+    tests::run_test,
+};
+use rocket::http::Status;
+use serde_json::json;
+
+#[test]
+fn test_create_unauthenticated() {
+    let world_owner = SeedUser::default();
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let url = format!("/worlds/{}/webhooks", world.world_id);
+
+    run_test()
+        .seed(&world_owner)
+        .seed(&world)
+        .post(
+            url,
+            json!({ "url": "https://example.com/hook", "secret": "s3cr3t" }),
+        )
+        .has_status(Status::Forbidden)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/unauthorized",
+            "title": "An invalid access token was provided",
+            "status": 403
+        }));
+}
+
+#[test]
+fn test_create_not_owner() {
+    let world_owner = SeedUser::default();
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let url = format!("/worlds/{}/webhooks", world.world_id);
+
+    run_test()
+        .seed(&world_owner)
+        .seed(&user)
+        .seed(&world)
+        .authenticate("testuser", "password")
+        .post(
+            url,
+            json!({ "url": "https://example.com/hook", "secret": "s3cr3t" }),
+        )
+        .has_status(Status::Forbidden)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/unauthorized",
+            "title": "An invalid access token was provided",
+            "status": 403
+        }));
+}
+
+#[test]
+fn test_create_missing_fields() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let url = format!("/worlds/{}/webhooks", world.world_id);
+
+    run_test()
+        .seed(&user)
+        .seed(&world)
+        .authenticate("testuser", "password")
+        .post(url, json!({}))
+        .has_status(Status::UnprocessableEntity)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/validation_error",
+            "title": "A validation error occurred",
+            "status": 422,
+            "fields": {
+              "secret": {
+                "type": "tag:multiverse,2020:problems/validation_error/missing",
+                "title": "The required field was missing"
+              },
+              "url": {
+                "type": "tag:multiverse,2020:problems/validation_error/missing",
+                "title": "The required field was missing"
+              }
+            }
+        }));
+}
+
+#[test]
+fn test_create_invalid_url() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let url = format!("/worlds/{}/webhooks", world.world_id);
+
+    run_test()
+        .seed(&user)
+        .seed(&world)
+        .authenticate("testuser", "password")
+        .post(
+            url,
+            json!({ "url": "ftp://example.com/hook", "secret": "s3cr3t" }),
+        )
+        .has_status(Status::UnprocessableEntity)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/validation_error",
+            "title": "A validation error occurred",
+            "status": 422,
+            "fields": {
+              "url": {
+                "type": "tag:multiverse,2020:webhooks/problems/validation_error/invalid_url",
+                "title": "The URL must be an absolute HTTP or HTTPS URL"
+              }
+            }
+        }));
+}
+
+#[test]
+fn test_create_private_url() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let url = format!("/worlds/{}/webhooks", world.world_id);
+
+    for target in &[
+        "http://169.254.169.254/latest/meta-data/",
+        "http://10.0.0.1/hook",
+        "http://192.168.1.1:8080/hook",
+        "http://[fd00::1]/hook",
+    ] {
+        run_test()
+            .seed(&user)
+            .seed(&world)
+            .authenticate("testuser", "password")
+            .post(url.clone(), json!({ "url": target, "secret": "s3cr3t" }))
+            .has_status(Status::UnprocessableEntity)
+            .has_header("Content-Type", "application/problem+json")
+            .has_json_body(json!({
+                "type": "tag:multiverse,2020:problems/validation_error",
+                "title": "A validation error occurred",
+                "status": 422,
+                "fields": {
+                  "url": {
+                    "type": "tag:multiverse,2020:webhooks/problems/validation_error/forbidden_url",
+                    "title": "The URL must not target a private or internal address"
+                  }
+                }
+            }));
+    }
+}
+
+#[test]
+fn test_create_success() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        world_id: uuid::Uuid::parse_str("d6c124d5-1060-48ef-aeb8-e591a8261c2b").unwrap(),
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+
+    run_test()
+        .seed(&user)
+        .seed(&world)
+        .authenticate("testuser", "password")
+        .post(
+            "/worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b/webhooks",
+            json!({ "url": "https://example.com/hook", "secret": "s3cr3t" }),
+        )
+        .has_status(Status::Ok)
+        .has_header("Content-Type", "application/json")
+        .has_header_regex(
+            "Link",
+            r#"</worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b/webhooks/[0-9a-f-]+>; rel="self""#,
+        )
+        .has_header_regex(
+            "Link",
+            r#"</worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b>; rel="related""#,
+        )
+        .assert_json_body(|body| {
+            assert_eq!(body["url"], json!("https://example.com/hook"));
+            assert!(body.get("secret").is_none());
+        })
+        .assert_database(|mut tx| {
+            let secret: String = tx
+                .query_one("SELECT secret FROM webhooks", &[])
+                .unwrap()
+                .get(0);
+            assert_eq!(secret, "s3cr3t");
+        });
+}
@@ -0,0 +1,126 @@
+use crate::{
+    data::{hash_password, SeedUser, SeedWebhook, SeedWorld},
+    tests::run_test,
+};
+use rocket::http::Status;
+use serde_json::json;
+
+#[test]
+fn test_delete_unauthenticated() {
+    let world_owner = SeedUser::default();
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let webhook = SeedWebhook {
+        world_id: world.world_id,
+        ..SeedWebhook::default()
+    };
+    let url = format!("/worlds/{}/webhooks/{}", world.world_id, webhook.webhook_id);
+
+    run_test()
+        .seed(&world_owner)
+        .seed(&world)
+        .seed(&webhook)
+        .delete(url)
+        .has_status(Status::Forbidden)
+        .has_header("Content-Type", "application/problem+json");
+}
+
+#[test]
+fn test_delete_unknown_webhook() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let url = format!(
+        "/worlds/{}/webhooks/d6c124d5-1060-48ef-aeb8-e591a8261c2b",
+        world.world_id
+    );
+
+    run_test()
+        .seed(&user)
+        .seed(&world)
+        .authenticate("testuser", "password")
+        .delete(url)
+        .has_status(Status::NotFound)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:webhooks/problems/unknown_webhook",
+            "title": "The requested webhook was unknown",
+            "status": 404
+        }));
+}
+
+#[test]
+fn test_delete_webhook_for_other_world() {
+    let world_owner = SeedUser::default();
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let other_world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let webhook = SeedWebhook {
+        world_id: other_world.world_id,
+        ..SeedWebhook::default()
+    };
+    let url = format!("/worlds/{}/webhooks/{}", world.world_id, webhook.webhook_id);
+
+    run_test()
+        .seed(&world_owner)
+        .seed_many(&[&user, &world, &other_world, &webhook])
+        .authenticate("testuser", "password")
+        .delete(url)
+        .has_status(Status::NotFound)
+        .assert_database(|mut tx| {
+            let count: i64 = tx
+                .query_one("SELECT COUNT(*) FROM webhooks", &[])
+                .unwrap()
+                .get(0);
+            assert_eq!(count, 1);
+        });
+}
+
+#[test]
+fn test_delete_success() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let webhook = SeedWebhook {
+        world_id: world.world_id,
+        ..SeedWebhook::default()
+    };
+    let url = format!("/worlds/{}/webhooks/{}", world.world_id, webhook.webhook_id);
+
+    run_test()
+        .seed_many(&[&user, &world, &webhook])
+        .authenticate("testuser", "password")
+        .delete(url)
+        .has_status(Status::NoContent)
+        .assert_database(|mut tx| {
+            let count: i64 = tx
+                .query_one("SELECT COUNT(*) FROM webhooks", &[])
+                .unwrap()
+                .get(0);
+            assert_eq!(count, 0);
+        });
+}
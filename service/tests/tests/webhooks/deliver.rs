@@ -0,0 +1,366 @@
+use crate::{
+    data::{hash_password, SeedCharacter, SeedDelivery, SeedUser, SeedWebhook, SeedWorld},
+    service::TestReceiver,
+    tests::run_test,
+};
+use hmac::{Hmac, Mac, NewMac};
+use rocket::http::Status;
+use serde_json::{json, Value};
+use sha2::Sha256;
+use std::time::{Duration, Instant};
+
+/// Wait for the single delivery in the database to reach the given status
+fn wait_for_status(mut tx: postgres::Transaction, expected: &str) {
+    let start = Instant::now();
+    loop {
+        let status: String = tx
+            .query_one("SELECT status FROM webhook_deliveries", &[])
+            .unwrap()
+            .get(0);
+        if status == expected {
+            return;
+        }
+        if start.elapsed() > Duration::from_secs(10) {
+            panic!("Delivery had status {} instead of {}", status, expected);
+        }
+        std::thread::sleep(Duration::from_millis(50));
+    }
+}
+
+#[test]
+fn test_deliver_table_created() {
+    let receiver = TestReceiver::start(vec![200]);
+
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        world_id: uuid::Uuid::parse_str("d6c124d5-1060-48ef-aeb8-e591a8261c2b").unwrap(),
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let webhook = SeedWebhook {
+        world_id: world.world_id,
+        url: receiver.url(),
+        secret: "s3cr3t".to_owned(),
+        ..SeedWebhook::default()
+    };
+
+    let harness = run_test()
+        .seed_many(&[&user, &world, &webhook])
+        .authenticate("testuser", "password")
+        .post(
+            "/worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b/tables",
+            json!({
+              "name": "Tavern Names",
+              "entries": [
+                { "text": "The Prancing Pony" }
+              ]
+            }),
+        )
+        .has_status(Status::Ok);
+
+    let requests = receiver.wait_for(1, Duration::from_secs(10));
+    let request = &requests[0];
+
+    assert_eq!(request.request_line, "POST / HTTP/1.1");
+    assert_eq!(
+        request.headers.get("content-type"),
+        Some(&"application/json".to_owned())
+    );
+    assert_eq!(
+        request.headers.get("x-multiverse-event"),
+        Some(&"table.created".to_owned())
+    );
+    assert!(request.headers.contains_key("x-multiverse-delivery"));
+
+    let mut mac = Hmac::<Sha256>::new_varkey(b"s3cr3t").unwrap();
+    mac.update(request.body.as_bytes());
+    let expected_signature = format!("sha256={}", hex::encode(mac.finalize().into_bytes()));
+    assert_eq!(
+        request.headers.get("x-multiverse-signature"),
+        Some(&expected_signature)
+    );
+
+    let payload: Value = serde_json::from_str(&request.body).unwrap();
+    assert_eq!(payload["event"], json!("table.created"));
+    assert_eq!(
+        payload["world"],
+        json!("/worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b")
+    );
+    assert_eq!(
+        payload["target"],
+        json!("/worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b/tables/tavern-names")
+    );
+
+    harness.assert_database(|tx| wait_for_status(tx, "succeeded"));
+}
+
+#[test]
+fn test_deliver_retries_after_failure() {
+    let receiver = TestReceiver::start(vec![500, 200]);
+
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let webhook = SeedWebhook {
+        world_id: world.world_id,
+        url: receiver.url(),
+        ..SeedWebhook::default()
+    };
+    let url = format!("/worlds/{}/tables", world.world_id);
+
+    let harness = run_test()
+        .seed_many(&[&user, &world, &webhook])
+        .authenticate("testuser", "password")
+        .post(
+            url,
+            json!({
+              "name": "Tavern Names",
+              "entries": [
+                { "text": "The Prancing Pony" }
+              ]
+            }),
+        )
+        .has_status(Status::Ok);
+
+    let requests = receiver.wait_for(2, Duration::from_secs(10));
+    assert_eq!(requests[0].body, requests[1].body);
+    assert_eq!(
+        requests[0].headers.get("x-multiverse-delivery"),
+        requests[1].headers.get("x-multiverse-delivery")
+    );
+
+    harness.assert_database(|mut tx| {
+        let attempts: Vec<Option<i32>> = tx
+            .query(
+                "SELECT response_status FROM webhook_delivery_attempts ORDER BY attempted ASC",
+                &[],
+            )
+            .unwrap()
+            .iter()
+            .map(|row| row.get(0))
+            .collect();
+        assert_eq!(attempts[0], Some(500));
+
+        wait_for_status(tx, "succeeded");
+    });
+}
+
+#[test]
+fn test_deliver_world_updated() {
+    let receiver = TestReceiver::start(vec![200]);
+
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        world_id: uuid::Uuid::parse_str("d6c124d5-1060-48ef-aeb8-e591a8261c2b").unwrap(),
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let webhook = SeedWebhook {
+        world_id: world.world_id,
+        url: receiver.url(),
+        ..SeedWebhook::default()
+    };
+
+    let harness = run_test()
+        .seed_many(&[&user, &world, &webhook])
+        .authenticate("testuser", "password")
+        .put(
+            "/worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b/status",
+            json!({ "status": "draft" }),
+        )
+        .has_status(Status::Ok);
+
+    let requests = receiver.wait_for(1, Duration::from_secs(10));
+    let request = &requests[0];
+
+    assert_eq!(
+        request.headers.get("x-multiverse-event"),
+        Some(&"world.updated".to_owned())
+    );
+
+    let payload: Value = serde_json::from_str(&request.body).unwrap();
+    assert_eq!(payload["event"], json!("world.updated"));
+    assert_eq!(
+        payload["world"],
+        json!("/worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b")
+    );
+    assert_eq!(
+        payload["target"],
+        json!("/worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b")
+    );
+
+    harness.assert_database(|tx| wait_for_status(tx, "succeeded"));
+}
+
+#[test]
+fn test_deliver_world_translation_saved() {
+    let receiver = TestReceiver::start(vec![200]);
+
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let webhook = SeedWebhook {
+        world_id: world.world_id,
+        url: receiver.url(),
+        ..SeedWebhook::default()
+    };
+    let url = format!("/worlds/{}/translations/fr", world.world_id);
+
+    let harness = run_test()
+        .seed_many(&[&user, &world, &webhook])
+        .authenticate("testuser", "password")
+        .put(url, json!({ "name": "Le Monde" }))
+        .has_status(Status::Ok);
+
+    let requests = receiver.wait_for(1, Duration::from_secs(10));
+    assert_eq!(
+        requests[0].headers.get("x-multiverse-event"),
+        Some(&"world.updated".to_owned())
+    );
+
+    harness.assert_database(|tx| wait_for_status(tx, "succeeded"));
+}
+
+#[test]
+fn test_deliver_character_deleted() {
+    let receiver = TestReceiver::start(vec![200]);
+
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let character = SeedCharacter {
+        character_id: uuid::Uuid::parse_str("5a5e3cbd-5a8e-4f0f-a8b4-7d1a61e0a6a4").unwrap(),
+        world_id: world.world_id,
+        ..SeedCharacter::default()
+    };
+    let webhook = SeedWebhook {
+        world_id: world.world_id,
+        url: receiver.url(),
+        ..SeedWebhook::default()
+    };
+
+    let harness = run_test()
+        .seed_many(&[&user, &world, &character, &webhook])
+        .authenticate("testuser", "password")
+        .delete("/characters/5a5e3cbd-5a8e-4f0f-a8b4-7d1a61e0a6a4")
+        .has_status(Status::NoContent);
+
+    let requests = receiver.wait_for(1, Duration::from_secs(10));
+    assert_eq!(
+        requests[0].headers.get("x-multiverse-event"),
+        Some(&"character.deleted".to_owned())
+    );
+
+    let payload: Value = serde_json::from_str(&requests[0].body).unwrap();
+    assert_eq!(
+        payload["target"],
+        json!("/characters/5a5e3cbd-5a8e-4f0f-a8b4-7d1a61e0a6a4")
+    );
+
+    harness.assert_database(|tx| wait_for_status(tx, "succeeded"));
+}
+
+#[test]
+fn test_deliver_pending_from_previous_run() {
+    let receiver = TestReceiver::start(vec![200]);
+
+    let user = SeedUser::default();
+    let world = SeedWorld {
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let webhook = SeedWebhook {
+        world_id: world.world_id,
+        url: receiver.url(),
+        ..SeedWebhook::default()
+    };
+    // A delivery that was still waiting to be retried when the service was last stopped
+    let delivery = SeedDelivery {
+        webhook_id: webhook.webhook_id,
+        status: "pending".to_owned(),
+        next_attempt: Some(chrono::Utc::now()),
+        ..SeedDelivery::default()
+    };
+
+    let harness = run_test().seed_many(&[&user, &world, &webhook, &delivery]);
+
+    let requests = receiver.wait_for(1, Duration::from_secs(10));
+    assert_eq!(requests[0].body, delivery.payload);
+    assert_eq!(
+        requests[0].headers.get("x-multiverse-delivery"),
+        Some(&delivery.delivery_id.to_string())
+    );
+
+    harness.assert_database(|tx| wait_for_status(tx, "succeeded"));
+}
+
+#[test]
+fn test_deliver_refuses_private_address() {
+    let user = SeedUser::default();
+    let world = SeedWorld {
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    // Registered before addresses were checked, so it was never validated
+    let webhook = SeedWebhook {
+        world_id: world.world_id,
+        url: "http://10.255.255.1/hook".to_owned(),
+        ..SeedWebhook::default()
+    };
+    let delivery = SeedDelivery {
+        webhook_id: webhook.webhook_id,
+        status: "pending".to_owned(),
+        next_attempt: Some(chrono::Utc::now()),
+        ..SeedDelivery::default()
+    };
+
+    run_test()
+        .seed_many(&[&user, &world, &webhook, &delivery])
+        .assert_database(|mut tx| {
+            let start = Instant::now();
+            let error: String = loop {
+                let row = tx
+                    .query_opt("SELECT error FROM webhook_delivery_attempts", &[])
+                    .unwrap();
+                if let Some(row) = row {
+                    break row.get(0);
+                }
+                if start.elapsed() > Duration::from_secs(10) {
+                    panic!("No delivery attempt was made");
+                }
+                std::thread::sleep(Duration::from_millis(50));
+            };
+
+            assert!(
+                error.contains("private or internal address"),
+                "Unexpected error: {}",
+                error
+            );
+        });
+}
@@ -0,0 +1,141 @@
+use crate::{
+    data::{hash_password, SeedDelivery, SeedDeliveryAttempt, SeedUser, SeedWebhook, SeedWorld},
+    tests::run_test,
+};
+use chrono::{DateTime, Utc};
+use rocket::http::Status;
+use serde_json::json;
+
+#[test]
+fn test_list_unknown_webhook() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let url = format!(
+        "/worlds/{}/webhooks/d6c124d5-1060-48ef-aeb8-e591a8261c2b/deliveries",
+        world.world_id
+    );
+
+    run_test()
+        .seed(&user)
+        .seed(&world)
+        .authenticate("testuser", "password")
+        .get(url)
+        .has_status(Status::NotFound)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:webhooks/problems/unknown_webhook",
+            "title": "The requested webhook was unknown",
+            "status": 404
+        }));
+}
+
+#[test]
+fn test_list_deliveries() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let webhook = SeedWebhook {
+        world_id: world.world_id,
+        ..SeedWebhook::default()
+    };
+    let older = SeedDelivery {
+        webhook_id: webhook.webhook_id,
+        created: "2020-01-01T12:00:00Z".parse::<DateTime<Utc>>().unwrap(),
+        event: "table.created".to_owned(),
+        payload: r#"{"event":"table.created"}"#.to_owned(),
+        status: "failed".to_owned(),
+        ..SeedDelivery::default()
+    };
+    let older_attempt = SeedDeliveryAttempt {
+        delivery_id: older.delivery_id,
+        attempted: "2020-01-01T12:00:01Z".parse::<DateTime<Utc>>().unwrap(),
+        response_status: Some(500),
+        error: Some("Receiver responded with 500".to_owned()),
+    };
+    let newer = SeedDelivery {
+        webhook_id: webhook.webhook_id,
+        created: "2020-01-02T12:00:00Z".parse::<DateTime<Utc>>().unwrap(),
+        event: "table.deleted".to_owned(),
+        payload: r#"{"event":"table.deleted"}"#.to_owned(),
+        status: "succeeded".to_owned(),
+        ..SeedDelivery::default()
+    };
+    let newer_attempt = SeedDeliveryAttempt {
+        delivery_id: newer.delivery_id,
+        attempted: "2020-01-02T12:00:01Z".parse::<DateTime<Utc>>().unwrap(),
+        response_status: Some(200),
+        error: None,
+    };
+    let url = format!(
+        "/worlds/{}/webhooks/{}/deliveries",
+        world.world_id, webhook.webhook_id
+    );
+    let link = format!(
+        r#"</worlds/{}/webhooks/{}/deliveries/{}>; rel="item"; anchor="\#/entries/0""#,
+        world.world_id, webhook.webhook_id, newer.delivery_id
+    );
+
+    run_test()
+        .seed_many(&[
+            &user,
+            &world,
+            &webhook,
+            &older,
+            &older_attempt,
+            &newer,
+            &newer_attempt,
+        ])
+        .authenticate("testuser", "password")
+        .get(url)
+        .has_status(Status::Ok)
+        .has_header("Content-Type", "application/json")
+        .has_header_regex("Link", link)
+        .has_json_body(json!({
+            "entries": [
+              {
+                "event": "table.deleted",
+                "status": "succeeded",
+                "created": "2020-01-02T12:00:00Z",
+                "payload": { "event": "table.deleted" },
+                "attempts": [
+                  {
+                    "attempted": "2020-01-02T12:00:01Z",
+                    "response_status": 200,
+                    "error": null
+                  }
+                ]
+              },
+              {
+                "event": "table.created",
+                "status": "failed",
+                "created": "2020-01-01T12:00:00Z",
+                "payload": { "event": "table.created" },
+                "attempts": [
+                  {
+                    "attempted": "2020-01-01T12:00:01Z",
+                    "response_status": 500,
+                    "error": "Receiver responded with 500"
+                  }
+                ]
+              }
+            ],
+            "pagination": {
+              "offset": 0,
+              "count": 2,
+              "total": 2
+            }
+        }));
+}
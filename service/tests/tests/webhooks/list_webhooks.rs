@@ -0,0 +1,120 @@
+use crate::{
+    data::{hash_password, SeedUser, SeedWebhook, SeedWorld},
+    tests::run_test,
+};
+use chrono::{DateTime, Utc};
+use rocket::http::Status;
+use serde_json::json;
+
+#[test]
+fn test_list_not_owner() {
+    let world_owner = SeedUser::default();
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let webhook = SeedWebhook {
+        world_id: world.world_id,
+        ..SeedWebhook::default()
+    };
+    let url = format!("/worlds/{}/webhooks", world.world_id);
+
+    run_test()
+        .seed(&world_owner)
+        .seed(&user)
+        .seed(&world)
+        .seed(&webhook)
+        .authenticate("testuser", "password")
+        .get(url)
+        .has_status(Status::Forbidden)
+        .has_header("Content-Type", "application/problem+json");
+}
+
+#[test]
+fn test_list_no_webhooks() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let url = format!("/worlds/{}/webhooks", world.world_id);
+
+    run_test()
+        .seed(&user)
+        .seed(&world)
+        .authenticate("testuser", "password")
+        .get(url)
+        .has_status(Status::Ok)
+        .has_header("Content-Type", "application/json")
+        .has_json_body(json!({
+            "entries": [],
+            "pagination": {
+              "offset": 0,
+              "count": 0,
+              "total": 0
+            }
+        }));
+}
+
+#[test]
+fn test_list_webhooks() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let other_world = SeedWorld {
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let webhook = SeedWebhook {
+        world_id: world.world_id,
+        url: "https://example.com/hook".to_owned(),
+        secret: "s3cr3t".to_owned(),
+        created: "2020-01-01T12:00:00Z".parse::<DateTime<Utc>>().unwrap(),
+        ..SeedWebhook::default()
+    };
+    let other_webhook = SeedWebhook {
+        world_id: other_world.world_id,
+        ..SeedWebhook::default()
+    };
+    let url = format!("/worlds/{}/webhooks", world.world_id);
+    let link = format!(
+        r#"</worlds/{}/webhooks/{}>; rel="item"; anchor="\#/entries/0""#,
+        world.world_id, webhook.webhook_id
+    );
+
+    run_test()
+        .seed_many(&[&user, &world, &other_world, &webhook, &other_webhook])
+        .authenticate("testuser", "password")
+        .get(url)
+        .has_status(Status::Ok)
+        .has_header("Content-Type", "application/json")
+        .has_header_regex("Link", link)
+        .has_json_body(json!({
+            "entries": [
+              {
+                "url": "https://example.com/hook",
+                "created": "2020-01-01T12:00:00Z"
+              }
+            ],
+            "pagination": {
+              "offset": 0,
+              "count": 1,
+              "total": 1
+            }
+        }));
+}
@@ -0,0 +1,6 @@
+mod create_webhook;
+mod delete_webhook;
+mod deliver;
+mod list_deliveries;
+mod list_webhooks;
+mod redeliver;
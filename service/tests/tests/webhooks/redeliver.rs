@@ -0,0 +1,98 @@
+use crate::{
+    data::{hash_password, SeedDelivery, SeedUser, SeedWebhook, SeedWorld},
+    service::TestReceiver,
+    tests::run_test,
+};
+use rocket::http::Status;
+use serde_json::json;
+use std::time::Duration;
+
+#[test]
+fn test_redeliver_unknown_delivery() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let webhook = SeedWebhook {
+        world_id: world.world_id,
+        ..SeedWebhook::default()
+    };
+    let url = format!(
+        "/worlds/{}/webhooks/{}/deliveries/d6c124d5-1060-48ef-aeb8-e591a8261c2b/redeliver",
+        world.world_id, webhook.webhook_id
+    );
+
+    run_test()
+        .seed_many(&[&user, &world, &webhook])
+        .authenticate("testuser", "password")
+        .post(url, json!({}))
+        .has_status(Status::NotFound)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:webhooks/problems/unknown_delivery",
+            "title": "The requested delivery was unknown",
+            "status": 404
+        }));
+}
+
+#[test]
+fn test_redeliver_success() {
+    let receiver = TestReceiver::start(vec![200]);
+
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let webhook = SeedWebhook {
+        world_id: world.world_id,
+        url: receiver.url(),
+        ..SeedWebhook::default()
+    };
+    let delivery = SeedDelivery {
+        webhook_id: webhook.webhook_id,
+        event: "table.created".to_owned(),
+        payload: r#"{"event":"table.created"}"#.to_owned(),
+        status: "failed".to_owned(),
+        ..SeedDelivery::default()
+    };
+    let url = format!(
+        "/worlds/{}/webhooks/{}/deliveries/{}/redeliver",
+        world.world_id, webhook.webhook_id, delivery.delivery_id
+    );
+
+    run_test()
+        .seed_many(&[&user, &world, &webhook, &delivery])
+        .authenticate("testuser", "password")
+        .post(url, json!({}))
+        .has_status(Status::Accepted)
+        .has_header_regex(
+            "Link",
+            format!(
+                r#"</worlds/{}/webhooks/{}>; rel="related""#,
+                world.world_id, webhook.webhook_id
+            ),
+        )
+        .assert_json_body(|body| {
+            assert_eq!(body["event"], json!("table.created"));
+            assert_eq!(body["payload"], json!({ "event": "table.created" }));
+            assert_eq!(body["status"], json!("pending"));
+            assert_eq!(body["attempts"], json!([]));
+        });
+
+    let requests = receiver.wait_for(1, Duration::from_secs(10));
+    assert_eq!(requests[0].body, r#"{"event":"table.created"}"#);
+    assert_eq!(
+        requests[0].headers.get("x-multiverse-event"),
+        Some(&"table.created".to_owned())
+    );
+}
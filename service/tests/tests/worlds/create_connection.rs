@@ -0,0 +1,290 @@
+use crate::{
+    data::{hash_password, SeedUser, SeedWorld, SeedWorldConnection},
+    tests::run_test,
+};
+use rocket::http::Status;
+use serde_json::json;
+
+#[test]
+fn test_create_unauthenticated() {
+    let owner = SeedUser::default();
+    let world = SeedWorld {
+        owner: owner.user_id,
+        ..SeedWorld::default()
+    };
+    let url = format!("/worlds/{}/connections", world.world_id);
+
+    run_test()
+        .seed(&owner)
+        .seed(&world)
+        .post(url, json!({}))
+        .has_status(Status::Forbidden)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/unauthorized",
+            "title": "An invalid access token was provided",
+            "status": 403
+        }));
+}
+
+#[test]
+fn test_create_wrong_user() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let owner = SeedUser::default();
+    let world = SeedWorld {
+        owner: owner.user_id,
+        ..SeedWorld::default()
+    };
+    let other = SeedWorld {
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let url = format!("/worlds/{}/connections", world.world_id);
+
+    run_test()
+        .seed(&user)
+        .seed(&owner)
+        .seed(&world)
+        .seed(&other)
+        .authenticate("testuser", "password")
+        .post(
+            url,
+            json!({
+              "world": format!("/worlds/{}", other.world_id),
+              "kind": "portal"
+            }),
+        )
+        .has_status(Status::Forbidden)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/unauthorized",
+            "title": "An invalid access token was provided",
+            "status": 403
+        }));
+}
+
+#[test]
+fn test_create_empty_body() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let url = format!("/worlds/{}/connections", world.world_id);
+
+    run_test()
+        .seed(&user)
+        .seed(&world)
+        .authenticate("testuser", "password")
+        .post(url, json!({}))
+        .has_status(Status::UnprocessableEntity)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/validation_error",
+            "title": "A validation error occurred",
+            "status": 422,
+            "fields": {
+              "kind": {
+                "type": "tag:multiverse,2020:problems/validation_error/missing",
+                "title": "The required field was missing"
+              },
+              "world": {
+                "type": "tag:multiverse,2020:problems/validation_error/missing",
+                "title": "The required field was missing"
+              }
+            }
+        }));
+}
+
+#[test]
+fn test_create_invalid() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let url = format!("/worlds/{}/connections", world.world_id);
+
+    run_test()
+        .seed(&user)
+        .seed(&world)
+        .authenticate("testuser", "password")
+        .post(
+            url,
+            json!({
+              "world": format!("/worlds/{}", world.world_id),
+              "kind": "wormhole"
+            }),
+        )
+        .has_status(Status::UnprocessableEntity)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/validation_error",
+            "title": "A validation error occurred",
+            "status": 422,
+            "fields": {
+              "kind": {
+                "type": "tag:multiverse,2020:worlds/problems/validation_error/unknown_connection_kind",
+                "title": "The kind of connection was unknown"
+              },
+              "world": {
+                "type": "tag:multiverse,2020:worlds/problems/validation_error/same_world",
+                "title": "A world can not be connected to itself"
+              }
+            }
+        }));
+}
+
+#[test]
+fn test_create_unknown_target_world() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let url = format!("/worlds/{}/connections", world.world_id);
+
+    run_test()
+        .seed(&user)
+        .seed(&world)
+        .authenticate("testuser", "password")
+        .post(
+            url,
+            json!({
+              "world": "/worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b",
+              "kind": "portal"
+            }),
+        )
+        .has_status(Status::UnprocessableEntity)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/validation_error",
+            "title": "A validation error occurred",
+            "status": 422,
+            "fields": {
+              "world": {
+                "type": "tag:multiverse,2020:worlds/problems/validation_error/unknown_world",
+                "title": "The world was unknown"
+              }
+            }
+        }));
+}
+
+#[test]
+fn test_create_duplicate() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let other = SeedWorld {
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let connection = SeedWorldConnection {
+        from_world_id: world.world_id,
+        to_world_id: other.world_id,
+        ..SeedWorldConnection::default()
+    };
+    let url = format!("/worlds/{}/connections", world.world_id);
+
+    run_test()
+        .seed(&user)
+        .seed(&world)
+        .seed(&other)
+        .seed(&connection)
+        .authenticate("testuser", "password")
+        .post(
+            url,
+            json!({
+              "world": format!("/worlds/{}", other.world_id),
+              "kind": "sequel"
+            }),
+        )
+        .has_status(Status::UnprocessableEntity)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:worlds/problems/duplicate_connection",
+            "title": "The worlds are already connected",
+            "status": 422
+        }));
+}
+
+#[test]
+fn test_create_success() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        world_id: uuid::Uuid::parse_str("d6c124d5-1060-48ef-aeb8-e591a8261c2b").unwrap(),
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let other = SeedWorld {
+        world_id: uuid::Uuid::parse_str("7da4cb77-8839-4805-b93a-f4c536c8bc85").unwrap(),
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+
+    run_test()
+        .seed(&user)
+        .seed(&world)
+        .seed(&other)
+        .authenticate("testuser", "password")
+        .post(
+            "/worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b/connections",
+            json!({
+              "world": "/worlds/7da4cb77-8839-4805-b93a-f4c536c8bc85",
+              "kind": "parallel_universe",
+              "description": "Through the looking glass"
+            }),
+        )
+        .has_status(Status::Ok)
+        .has_header("Content-Type", "application/json")
+        .has_header_regex(
+            "Link",
+            r#"</worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b/connections/[0-9a-f-]+>; rel="self""#,
+        )
+        .has_header_regex(
+            "Link",
+            r#"</worlds/7da4cb77-8839-4805-b93a-f4c536c8bc85>; rel="related""#,
+        )
+        .assert_json_body(|body| {
+            assert_eq!(body["from"], "/worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b");
+            assert_eq!(body["to"], "/worlds/7da4cb77-8839-4805-b93a-f4c536c8bc85");
+            assert_eq!(body["kind"], "parallel_universe");
+            assert_eq!(body["description"], "Through the looking glass");
+        })
+        .assert_database(|mut tx| {
+            let count: i64 = tx
+                .query_one(
+                    "SELECT COUNT(*) FROM world_connections WHERE from_world_id = $1 AND to_world_id = $2",
+                    &[&world.world_id, &other.world_id],
+                )
+                .unwrap()
+                .get(0);
+            assert_eq!(count, 1);
+        });
+}
@@ -1,5 +1,5 @@
 use crate::{
-    data::{hash_password, SeedUser, SeedWorld},
+    data::{hash_password, SeedUser, SeedUserQuota, SeedWorld},
     tests::run_test,
 };
 use rocket::http::Status;
@@ -83,7 +83,9 @@ fn test_create_success() {
         .has_json_body(json!({
           "name": "Test World",
           "description": "This is a test world",
-          "url_slug": "test-world"
+          "status": "published",
+          "url_slug": "test-world",
+          "stars": 0
         }));
 }
 
@@ -153,6 +155,170 @@ fn test_create_minimal() {
         .has_json_body(json!({
           "name": "Test World",
           "description": "",
-          "url_slug": "test-world"
+          "status": "published",
+          "url_slug": "test-world",
+          "stars": 0
         }));
 }
+
+#[test]
+fn test_create_quota_exceeded() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let quota = SeedUserQuota {
+        user_id: user.user_id,
+        resource: "worlds".to_owned(),
+        quota: 1,
+    };
+
+    run_test()
+        .seed_many(&[&user, &world, &quota])
+        .authenticate("testuser", "password")
+        .post(
+            "/worlds",
+            json!({
+              "name": "Test World",
+              "url_slug": "test-world"
+            }),
+        )
+        .has_status(Status::Forbidden)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/quota_exceeded",
+            "title": "The quota has been exceeded",
+            "status": 403,
+            "resource": "worlds",
+            "used": 1,
+            "limit": 1
+        }));
+}
+
+#[test]
+fn test_create_unknown_template() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+
+    run_test()
+        .seed(&user)
+        .authenticate("testuser", "password")
+        .post(
+            "/worlds",
+            json!({
+              "name": "Test World",
+              "url_slug": "test-world",
+              "template": "unknown"
+            }),
+        )
+        .has_status(Status::UnprocessableEntity)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/validation_error",
+            "title": "A validation error occurred",
+            "status": 422,
+            "fields": {
+              "template": {
+                "type": "tag:multiverse,2020:templates/problems/validation_error/unknown_template",
+                "title": "The template was unknown"
+              }
+            }
+        }));
+}
+
+#[test]
+fn test_create_from_template() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+
+    run_test()
+        .seed(&user)
+        .authenticate("testuser", "password")
+        .post(
+            "/worlds",
+            json!({
+              "name": "Test World",
+              "url_slug": "test-world",
+              "template": "fantasy"
+            }),
+        )
+        .has_status(Status::Ok)
+        .has_header("Content-Type", "application/json")
+        .assert_database(|mut tx| {
+            let rows = tx
+                .query(
+                    "SELECT t.url_slug FROM random_tables t JOIN worlds w ON t.world_id = w.world_id WHERE w.owner_id = $1 ORDER BY t.url_slug",
+                    &[&user.user_id],
+                )
+                .unwrap();
+            assert_eq!(rows.len(), 2);
+            assert_eq!(rows[0].get::<_, String>(0), "random-encounters");
+            assert_eq!(rows[1].get::<_, String>(0), "tavern-names");
+
+            let rows = tx
+                .query(
+                    "SELECT c.name FROM campaigns c JOIN worlds w ON c.world_id = w.world_id WHERE w.owner_id = $1",
+                    &[&user.user_id],
+                )
+                .unwrap();
+            assert_eq!(rows.len(), 1);
+            assert_eq!(rows[0].get::<_, String>(0), "The Lost Mine");
+        });
+}
+
+#[test]
+fn test_create_from_template_quota_exceeded() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let quota = SeedUserQuota {
+        user_id: user.user_id,
+        resource: "tables".to_owned(),
+        quota: 1,
+    };
+
+    run_test()
+        .seed_many(&[&user, &quota])
+        .authenticate("testuser", "password")
+        .post(
+            "/worlds",
+            json!({
+              "name": "Test World",
+              "url_slug": "test-world",
+              "template": "fantasy"
+            }),
+        )
+        .has_status(Status::Forbidden)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/quota_exceeded",
+            "title": "The quota has been exceeded",
+            "status": 403,
+            "resource": "tables",
+            "used": 0,
+            "limit": 1
+        }))
+        .assert_database(|mut tx| {
+            let count: i64 = tx
+                .query_one(
+                    "SELECT COUNT(*) FROM worlds WHERE owner_id = $1",
+                    &[&user.user_id],
+                )
+                .unwrap()
+                .get(0);
+            assert_eq!(count, 0);
+        });
+}
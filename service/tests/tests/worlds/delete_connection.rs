@@ -0,0 +1,149 @@
+use crate::{
+    data::{hash_password, SeedUser, SeedWorld, SeedWorldConnection},
+    tests::run_test,
+};
+use rocket::http::Status;
+use serde_json::json;
+
+#[test]
+fn test_delete_unknown_connection() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let url = format!(
+        "/worlds/{}/connections/d6c124d5-1060-48ef-aeb8-e591a8261c2b",
+        world.world_id
+    );
+
+    run_test()
+        .seed(&user)
+        .seed(&world)
+        .authenticate("testuser", "password")
+        .delete(url)
+        .has_status(Status::NotFound)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:worlds/problems/unknown_connection",
+            "title": "The requested connection was unknown",
+            "status": 404
+        }));
+}
+
+#[test]
+fn test_delete_connection_on_other_world() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let first = SeedWorld {
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let second = SeedWorld {
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let connection = SeedWorldConnection {
+        from_world_id: first.world_id,
+        to_world_id: second.world_id,
+        ..SeedWorldConnection::default()
+    };
+    let url = format!(
+        "/worlds/{}/connections/{}",
+        world.world_id, connection.connection_id
+    );
+
+    run_test()
+        .seed(&user)
+        .seed_many(&[&world, &first, &second])
+        .seed(&connection)
+        .authenticate("testuser", "password")
+        .delete(url)
+        .has_status(Status::NotFound);
+}
+
+#[test]
+fn test_delete_from_source_world() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let other = SeedWorld {
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let connection = SeedWorldConnection {
+        from_world_id: world.world_id,
+        to_world_id: other.world_id,
+        ..SeedWorldConnection::default()
+    };
+    let url = format!(
+        "/worlds/{}/connections/{}",
+        world.world_id, connection.connection_id
+    );
+
+    run_test()
+        .seed(&user)
+        .seed_many(&[&world, &other])
+        .seed(&connection)
+        .authenticate("testuser", "password")
+        .delete(url)
+        .has_status(Status::NoContent)
+        .assert_database(|mut tx| {
+            let count: i64 = tx
+                .query_one("SELECT COUNT(*) FROM world_connections", &[])
+                .unwrap()
+                .get(0);
+            assert_eq!(count, 0);
+        });
+}
+
+#[test]
+fn test_delete_from_target_world() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let other = SeedWorld {
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let connection = SeedWorldConnection {
+        from_world_id: other.world_id,
+        to_world_id: world.world_id,
+        ..SeedWorldConnection::default()
+    };
+    let url = format!(
+        "/worlds/{}/connections/{}",
+        world.world_id, connection.connection_id
+    );
+
+    run_test()
+        .seed(&user)
+        .seed_many(&[&world, &other])
+        .seed(&connection)
+        .authenticate("testuser", "password")
+        .delete(url)
+        .has_status(Status::NoContent);
+}
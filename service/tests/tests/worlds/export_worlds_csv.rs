@@ -0,0 +1,49 @@
+use crate::{
+    data::{SeedUser, SeedWorld},
+    tests::run_test,
+};
+use rocket::http::Status;
+
+#[test]
+fn test_export_no_worlds() {
+    run_test()
+        .get("/worlds.csv")
+        .has_status(Status::Ok)
+        .has_header("Content-Type", "text/csv; charset=utf-8")
+        .has_header("Content-Disposition", "attachment; filename=\"worlds.csv\"")
+        .assert_response(|response| {
+            assert_eq!(
+                response.body,
+                "id,name,url_slug,description,owner,status,publish_at,created,updated\n"
+            );
+        });
+}
+
+#[test]
+fn test_export_filtered_worlds() {
+    let world_owner = SeedUser::default();
+    let first = SeedWorld {
+        owner: world_owner.user_id,
+        name: "Middle-earth".to_owned(),
+        url_slug: "middle-earth".to_owned(),
+        ..SeedWorld::default()
+    };
+    let second = SeedWorld {
+        owner: world_owner.user_id,
+        url_slug: "discworld".to_owned(),
+        ..SeedWorld::default()
+    };
+
+    run_test()
+        .seed_many(&[&world_owner, &first, &second])
+        .get("/worlds.csv?url_slug=middle-earth")
+        .has_status(Status::Ok)
+        .assert_response(|response| {
+            let lines: Vec<&str> = response.body.lines().collect();
+            assert_eq!(lines.len(), 2);
+            assert!(lines[1].starts_with(&format!(
+                "{},Middle-earth,middle-earth,{},{},published,,",
+                first.world_id, first.description, world_owner.user_id
+            )));
+        });
+}
@@ -1,7 +1,8 @@
 use crate::{
-    data::{SeedUser, SeedWorld},
+    data::{hash_password, SeedUser, SeedWorld, SeedWorldConnection, SeedWorldMember},
     tests::run_test,
 };
+use chrono::{Duration, Timelike, Utc};
 use rocket::http::Status;
 use serde_json::json;
 
@@ -51,6 +52,223 @@ fn test_get_known_world() {
         .has_json_body(json!({
           "name": "Test World",
           "description": "This is a test world",
-          "url_slug": "test-world"
+          "status": "published",
+          "url_slug": "test-world",
+          "stars": 0
         }));
 }
+
+#[test]
+fn test_get_connected_world() {
+    let owner = SeedUser::default();
+    let world = SeedWorld {
+        world_id: uuid::Uuid::parse_str("d6c124d5-1060-48ef-aeb8-e591a8261c2b").unwrap(),
+        owner: owner.user_id,
+        ..SeedWorld::default()
+    };
+    let target = SeedWorld {
+        world_id: uuid::Uuid::parse_str("7da4cb77-8839-4805-b93a-f4c536c8bc85").unwrap(),
+        owner: owner.user_id,
+        ..SeedWorld::default()
+    };
+    let source = SeedWorld {
+        world_id: uuid::Uuid::parse_str("00000000-0000-0000-0002-000000000001").unwrap(),
+        owner: owner.user_id,
+        ..SeedWorld::default()
+    };
+    let outgoing = SeedWorldConnection {
+        from_world_id: world.world_id,
+        to_world_id: target.world_id,
+        kind: "portal".to_owned(),
+        ..SeedWorldConnection::default()
+    };
+    let incoming = SeedWorldConnection {
+        from_world_id: source.world_id,
+        to_world_id: world.world_id,
+        kind: "sequel".to_owned(),
+        ..SeedWorldConnection::default()
+    };
+
+    run_test()
+        .seed(&owner)
+        .seed_many(&[&world, &target, &source])
+        .seed_many(&[&outgoing, &incoming])
+        .get("/worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b")
+        .has_status(Status::Ok)
+        .has_header_regex(
+            "Link",
+            r#"</worlds/7da4cb77-8839-4805-b93a-f4c536c8bc85>; rel="related"; title="portal""#,
+        )
+        .has_header_regex(
+            "Link",
+            r#"</worlds/00000000-0000-0000-0002-000000000001>; rel="related"; title="sequel""#,
+        );
+}
+
+#[test]
+fn test_get_draft_world_anonymous() {
+    let owner = SeedUser::default();
+    let world = SeedWorld {
+        world_id: uuid::Uuid::parse_str("d6c124d5-1060-48ef-aeb8-e591a8261c2b").unwrap(),
+        owner: owner.user_id,
+        status: "draft".to_owned(),
+        ..SeedWorld::default()
+    };
+
+    run_test()
+        .seed_many(&[&owner, &world])
+        .get("/worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b")
+        .has_status(Status::NotFound)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:worlds/problems/unknown_world_id",
+            "title": "The requested world ID was unknown",
+            "status": 404
+        }));
+}
+
+#[test]
+fn test_get_draft_world_other_user() {
+    let owner = SeedUser::default();
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        world_id: uuid::Uuid::parse_str("d6c124d5-1060-48ef-aeb8-e591a8261c2b").unwrap(),
+        owner: owner.user_id,
+        status: "draft".to_owned(),
+        ..SeedWorld::default()
+    };
+
+    run_test()
+        .seed_many(&[&owner, &user, &world])
+        .authenticate("testuser", "password")
+        .get("/worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b")
+        .has_status(Status::NotFound);
+}
+
+#[test]
+fn test_get_draft_world_owner() {
+    let owner = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        world_id: uuid::Uuid::parse_str("d6c124d5-1060-48ef-aeb8-e591a8261c2b").unwrap(),
+        name: "Test World".to_owned(),
+        description: "This is a test world".to_owned(),
+        url_slug: "test-world".to_owned(),
+        owner: owner.user_id,
+        status: "draft".to_owned(),
+        ..SeedWorld::default()
+    };
+
+    run_test()
+        .seed_many(&[&owner, &world])
+        .authenticate("testuser", "password")
+        .get("/worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b")
+        .has_status(Status::Ok)
+        .has_json_body(json!({
+          "name": "Test World",
+          "description": "This is a test world",
+          "status": "draft",
+          "url_slug": "test-world",
+          "stars": 0
+        }));
+}
+
+#[test]
+fn test_get_draft_world_editor() {
+    let owner = SeedUser::default();
+    let editor = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        world_id: uuid::Uuid::parse_str("d6c124d5-1060-48ef-aeb8-e591a8261c2b").unwrap(),
+        owner: owner.user_id,
+        status: "draft".to_owned(),
+        ..SeedWorld::default()
+    };
+    let member = SeedWorldMember {
+        world_id: world.world_id,
+        user_id: editor.user_id,
+        ..SeedWorldMember::default()
+    };
+
+    run_test()
+        .seed_many(&[&owner, &editor, &world, &member])
+        .authenticate("testuser", "password")
+        .get("/worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b")
+        .has_status(Status::Ok);
+}
+
+#[test]
+fn test_get_draft_world_viewer() {
+    let owner = SeedUser::default();
+    let viewer = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        world_id: uuid::Uuid::parse_str("d6c124d5-1060-48ef-aeb8-e591a8261c2b").unwrap(),
+        owner: owner.user_id,
+        status: "draft".to_owned(),
+        ..SeedWorld::default()
+    };
+    let member = SeedWorldMember {
+        world_id: world.world_id,
+        user_id: viewer.user_id,
+        role: "viewer".to_owned(),
+        ..SeedWorldMember::default()
+    };
+
+    run_test()
+        .seed_many(&[&owner, &viewer, &world, &member])
+        .authenticate("testuser", "password")
+        .get("/worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b")
+        .has_status(Status::Ok);
+}
+
+#[test]
+fn test_get_scheduled_world_in_past() {
+    let owner = SeedUser::default();
+    let world = SeedWorld {
+        world_id: uuid::Uuid::parse_str("d6c124d5-1060-48ef-aeb8-e591a8261c2b").unwrap(),
+        owner: owner.user_id,
+        status: "scheduled".to_owned(),
+        publish_at: Some(Utc::now().with_nanosecond(0).unwrap() - Duration::hours(1)),
+        ..SeedWorld::default()
+    };
+
+    run_test()
+        .seed_many(&[&owner, &world])
+        .get("/worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b")
+        .has_status(Status::Ok)
+        .assert_json_body(|body| {
+            assert_eq!(body["status"], json!("published"));
+            assert_eq!(body["publish_at"], json!(world.publish_at));
+        });
+}
+
+#[test]
+fn test_get_scheduled_world_in_future() {
+    let owner = SeedUser::default();
+    let world = SeedWorld {
+        world_id: uuid::Uuid::parse_str("d6c124d5-1060-48ef-aeb8-e591a8261c2b").unwrap(),
+        owner: owner.user_id,
+        status: "scheduled".to_owned(),
+        publish_at: Some(Utc::now().with_nanosecond(0).unwrap() + Duration::hours(1)),
+        ..SeedWorld::default()
+    };
+
+    run_test()
+        .seed_many(&[&owner, &world])
+        .get("/worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b")
+        .has_status(Status::NotFound);
+}
@@ -0,0 +1,294 @@
+use crate::{
+    data::{
+        hash_password, SeedActivity, SeedAttachment, SeedCampaign, SeedCharacter, SeedComment,
+        SeedSession, SeedTable, SeedUser, SeedWorld,
+    },
+    tests::run_test,
+};
+use chrono::{DateTime, Utc};
+use rocket::http::Status;
+use serde_json::json;
+
+#[test]
+fn test_get_stats_unknown_world() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+
+    run_test()
+        .seed(&user)
+        .authenticate("testuser", "password")
+        .get("/worlds/7da4cb77-8839-4805-b93a-f4c536c8bc85/stats")
+        .has_status(Status::NotFound)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:worlds/problems/unknown_world_id",
+            "title": "The requested world ID was unknown",
+            "status": 404
+        }));
+}
+
+#[test]
+fn test_get_stats_not_owner() {
+    let world_owner = SeedUser::default();
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let url = format!("/worlds/{}/stats", world.world_id);
+
+    run_test()
+        .seed_many(&[&world_owner, &user, &world])
+        .authenticate("testuser", "password")
+        .get(url)
+        .has_status(Status::Forbidden)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/unauthorized",
+            "title": "An invalid access token was provided",
+            "status": 403
+        }));
+}
+
+#[test]
+fn test_get_stats_empty_world() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        world_id: uuid::Uuid::parse_str("7da4cb77-8839-4805-b93a-f4c536c8bc85").unwrap(),
+        owner: user.user_id,
+        updated: "2020-02-01T12:00:00Z".parse::<DateTime<Utc>>().unwrap(),
+        ..SeedWorld::default()
+    };
+
+    run_test()
+        .seed_many(&[&user, &world])
+        .authenticate("testuser", "password")
+        .get("/worlds/7da4cb77-8839-4805-b93a-f4c536c8bc85/stats")
+        .has_status(Status::Ok)
+        .has_header("Content-Type", "application/json")
+        .has_header("ETag", format!("\"{}\"", world.version))
+        .has_header_regex(
+            "Link",
+            r#"</worlds/7da4cb77-8839-4805-b93a-f4c536c8bc85/stats>; rel="self""#,
+        )
+        .has_header_regex(
+            "Link",
+            r#"</worlds/7da4cb77-8839-4805-b93a-f4c536c8bc85>; rel="related""#,
+        )
+        .has_json_body(json!({
+            "counts": {
+                "tables": 0,
+                "characters": 0,
+                "campaigns": 0,
+                "sessions": 0,
+                "comments": 0,
+                "attachments": 0,
+                "connections": 0
+            },
+            "words": 0,
+            "broken_links": 0,
+            "most_linked": [],
+            "last_activity": "2020-02-01T12:00:00Z"
+        }));
+}
+
+#[test]
+fn test_get_stats_populated_world() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        world_id: uuid::Uuid::parse_str("7da4cb77-8839-4805-b93a-f4c536c8bc85").unwrap(),
+        owner: user.user_id,
+        updated: "2020-02-01T12:00:00Z".parse::<DateTime<Utc>>().unwrap(),
+        ..SeedWorld::default()
+    };
+    let table = SeedTable {
+        world_id: world.world_id,
+        url_slug: "tavern-names".to_owned(),
+        ..SeedTable::default()
+    };
+    let character = SeedCharacter {
+        character_id: uuid::Uuid::parse_str("3b4f8c1e-5d2a-4e6b-9c7d-1a2b3c4d5e6f").unwrap(),
+        world_id: world.world_id,
+        ..SeedCharacter::default()
+    };
+    let campaign = SeedCampaign {
+        world_id: world.world_id,
+        ..SeedCampaign::default()
+    };
+    let other_world = SeedWorld {
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+    let other_campaign = SeedCampaign {
+        campaign_id: uuid::Uuid::parse_str("9e8d7c6b-5a4f-4e3d-8c2b-1a0f9e8d7c6b").unwrap(),
+        world_id: other_world.world_id,
+        ..SeedCampaign::default()
+    };
+    let session1 = SeedSession {
+        campaign_id: campaign.campaign_id,
+        summary: "The party met the queen".to_owned(),
+        entities: vec![
+            "/characters/3b4f8c1e-5d2a-4e6b-9c7d-1a2b3c4d5e6f".to_owned(),
+            "/worlds/7da4cb77-8839-4805-b93a-f4c536c8bc85/tables/tavern-names".to_owned(),
+            "/worlds/7da4cb77-8839-4805-b93a-f4c536c8bc85/tables/missing".to_owned(),
+        ],
+        ..SeedSession::default()
+    };
+    let session2 = SeedSession {
+        campaign_id: campaign.campaign_id,
+        summary: "  ".to_owned(),
+        entities: vec![
+            "/characters/3b4f8c1e-5d2a-4e6b-9c7d-1a2b3c4d5e6f".to_owned(),
+            "/characters/queen-ileosa".to_owned(),
+            "/campaigns/9e8d7c6b-5a4f-4e3d-8c2b-1a0f9e8d7c6b".to_owned(),
+        ],
+        ..SeedSession::default()
+    };
+    let comment = SeedComment {
+        world_id: world.world_id,
+        author_id: user.user_id,
+        ..SeedComment::default()
+    };
+    let attachment = SeedAttachment {
+        world_id: world.world_id,
+        owner_id: user.user_id,
+        ..SeedAttachment::default()
+    };
+    let activity = SeedActivity {
+        world_id: world.world_id,
+        user_id: user.user_id,
+        created: "2020-03-01T12:00:00Z".parse::<DateTime<Utc>>().unwrap(),
+        ..SeedActivity::default()
+    };
+
+    run_test()
+        .seed_many(&[
+            &user,
+            &world,
+            &table,
+            &character,
+            &campaign,
+            &other_world,
+            &other_campaign,
+            &session1,
+            &session2,
+            &comment,
+            &attachment,
+            &activity,
+        ])
+        .authenticate("testuser", "password")
+        .get("/worlds/7da4cb77-8839-4805-b93a-f4c536c8bc85/stats")
+        .has_status(Status::Ok)
+        .has_header("Content-Type", "application/json")
+        .has_json_body(json!({
+            "counts": {
+                "tables": 1,
+                "characters": 1,
+                "campaigns": 1,
+                "sessions": 2,
+                "comments": 1,
+                "attachments": 1,
+                "connections": 0
+            },
+            "words": 5,
+            "broken_links": 3,
+            "most_linked": [
+                { "entity": "/characters/3b4f8c1e-5d2a-4e6b-9c7d-1a2b3c4d5e6f", "links": 2 },
+                { "entity": "/campaigns/9e8d7c6b-5a4f-4e3d-8c2b-1a0f9e8d7c6b", "links": 1 },
+                { "entity": "/characters/queen-ileosa", "links": 1 },
+                { "entity": "/worlds/7da4cb77-8839-4805-b93a-f4c536c8bc85/tables/missing", "links": 1 },
+                { "entity": "/worlds/7da4cb77-8839-4805-b93a-f4c536c8bc85/tables/tavern-names", "links": 1 }
+            ],
+            "last_activity": "2020-03-01T12:00:00Z"
+        }));
+}
+
+#[test]
+fn test_get_stats_after_creating_table() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        world_id: uuid::Uuid::parse_str("7da4cb77-8839-4805-b93a-f4c536c8bc85").unwrap(),
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+
+    run_test()
+        .seed_many(&[&user, &world])
+        .authenticate("testuser", "password")
+        .get("/worlds/7da4cb77-8839-4805-b93a-f4c536c8bc85/stats")
+        .has_status(Status::Ok)
+        .assert_json_body(|body| {
+            assert_eq!(body["counts"]["tables"], json!(0));
+        })
+        .post(
+            "/worlds/7da4cb77-8839-4805-b93a-f4c536c8bc85/tables",
+            json!({
+              "name": "Tavern Names",
+              "description": "Names for taverns",
+              "entries": [
+                { "text": "The Prancing Pony" }
+              ]
+            }),
+        )
+        .has_status(Status::Ok)
+        .get("/worlds/7da4cb77-8839-4805-b93a-f4c536c8bc85/stats")
+        .has_status(Status::Ok)
+        .assert_json_body(|body| {
+            assert_eq!(body["counts"]["tables"], json!(1));
+        });
+}
+
+#[test]
+fn test_get_stats_after_creating_character() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        world_id: uuid::Uuid::parse_str("7da4cb77-8839-4805-b93a-f4c536c8bc85").unwrap(),
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+
+    run_test()
+        .seed_many(&[&user, &world])
+        .authenticate("testuser", "password")
+        .get("/worlds/7da4cb77-8839-4805-b93a-f4c536c8bc85/stats")
+        .has_status(Status::Ok)
+        .assert_json_body(|body| {
+            assert_eq!(body["counts"]["characters"], json!(0));
+        })
+        .post(
+            "/worlds/7da4cb77-8839-4805-b93a-f4c536c8bc85/characters",
+            json!({
+              "name": "Amiri",
+              "description": "A barbarian"
+            }),
+        )
+        .has_status(Status::Ok)
+        .get("/worlds/7da4cb77-8839-4805-b93a-f4c536c8bc85/stats")
+        .has_status(Status::Ok)
+        .assert_json_body(|body| {
+            assert_eq!(body["counts"]["characters"], json!(1));
+        });
+}
@@ -0,0 +1,171 @@
+use crate::{
+    data::{SeedUser, SeedWorld, SeedWorldConnection},
+    tests::run_test,
+};
+use rocket::http::Status;
+use serde_json::json;
+
+#[test]
+fn test_list_unknown_world() {
+    run_test()
+        .get("/worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b/connections")
+        .has_status(Status::NotFound)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:worlds/problems/unknown_world_id",
+            "title": "The requested world ID was unknown",
+            "status": 404
+        }));
+}
+
+#[test]
+fn test_list_no_connections() {
+    let owner = SeedUser::default();
+    let world = SeedWorld {
+        world_id: uuid::Uuid::parse_str("d6c124d5-1060-48ef-aeb8-e591a8261c2b").unwrap(),
+        owner: owner.user_id,
+        name: "Test World".to_owned(),
+        description: "This is a test world".to_owned(),
+        url_slug: "test-world".to_owned(),
+        ..SeedWorld::default()
+    };
+
+    run_test()
+        .seed(&owner)
+        .seed(&world)
+        .get("/worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b/connections")
+        .has_status(Status::Ok)
+        .has_header("Content-Type", "application/json")
+        .has_header_regex(
+            "Link",
+            r#"</worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b>; rel="item"; title="Test World"; anchor="\#/worlds/0""#,
+        )
+        .has_json_body(json!({
+            "worlds": [
+              {
+                "name": "Test World",
+                "description": "This is a test world",
+                "status": "published",
+                "url_slug": "test-world",
+                "stars": 0,
+                "depth": 0
+              }
+            ],
+            "connections": []
+        }));
+}
+
+/// Build a chain of worlds, A -> B <- C -> D, all owned by the same user
+fn seed_chain() -> (SeedUser, Vec<SeedWorld>, Vec<SeedWorldConnection>) {
+    let owner = SeedUser::default();
+    let worlds: Vec<SeedWorld> = ["A", "B", "C", "D"]
+        .iter()
+        .map(|name| SeedWorld {
+            owner: owner.user_id,
+            name: format!("World {}", name),
+            ..SeedWorld::default()
+        })
+        .collect();
+
+    let connections = vec![
+        SeedWorldConnection {
+            from_world_id: worlds[0].world_id,
+            to_world_id: worlds[1].world_id,
+            kind: "portal".to_owned(),
+            ..SeedWorldConnection::default()
+        },
+        SeedWorldConnection {
+            from_world_id: worlds[2].world_id,
+            to_world_id: worlds[1].world_id,
+            kind: "sequel".to_owned(),
+            ..SeedWorldConnection::default()
+        },
+        SeedWorldConnection {
+            from_world_id: worlds[2].world_id,
+            to_world_id: worlds[3].world_id,
+            kind: "shared_setting".to_owned(),
+            ..SeedWorldConnection::default()
+        },
+    ];
+
+    (owner, worlds, connections)
+}
+
+#[test]
+fn test_list_default_depth() {
+    let (owner, worlds, connections) = seed_chain();
+    let url = format!("/worlds/{}/connections", worlds[1].world_id);
+
+    run_test()
+        .seed(&owner)
+        .seed_many(&[&worlds[0], &worlds[1], &worlds[2], &worlds[3]])
+        .seed_many(&[&connections[0], &connections[1], &connections[2]])
+        .get(url)
+        .has_status(Status::Ok)
+        .has_header("Content-Type", "application/json")
+        .assert_json_body(|body| {
+            let names: Vec<_> = body["worlds"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|world| (world["name"].clone(), world["depth"].clone()))
+                .collect();
+            assert_eq!(
+                names,
+                vec![
+                    (json!("World B"), json!(0)),
+                    (json!("World A"), json!(1)),
+                    (json!("World C"), json!(1)),
+                ]
+            );
+
+            let kinds: Vec<_> = body["connections"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|connection| connection["kind"].clone())
+                .collect();
+            assert_eq!(kinds.len(), 2);
+            assert!(kinds.contains(&json!("portal")));
+            assert!(kinds.contains(&json!("sequel")));
+        });
+}
+
+#[test]
+fn test_list_deeper() {
+    let (owner, worlds, connections) = seed_chain();
+    let url = format!("/worlds/{}/connections?depth=2", worlds[1].world_id);
+
+    run_test()
+        .seed(&owner)
+        .seed_many(&[&worlds[0], &worlds[1], &worlds[2], &worlds[3]])
+        .seed_many(&[&connections[0], &connections[1], &connections[2]])
+        .get(url)
+        .has_status(Status::Ok)
+        .has_header("Content-Type", "application/json")
+        .has_header_regex(
+            "Link",
+            format!(
+                r#"</worlds/{}>; rel="item"; title="World D"; anchor="\#/worlds/3""#,
+                worlds[3].world_id
+            ),
+        )
+        .assert_json_body(|body| {
+            let names: Vec<_> = body["worlds"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|world| (world["name"].clone(), world["depth"].clone()))
+                .collect();
+            assert_eq!(
+                names,
+                vec![
+                    (json!("World B"), json!(0)),
+                    (json!("World A"), json!(1)),
+                    (json!("World C"), json!(1)),
+                    (json!("World D"), json!(2)),
+                ]
+            );
+            assert_eq!(body["connections"].as_array().unwrap().len(), 3);
+        });
+}
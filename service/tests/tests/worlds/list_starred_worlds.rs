@@ -0,0 +1,118 @@
+use crate::{
+    data::{SeedStar, SeedUser, SeedWorld},
+    tests::run_test,
+};
+use rocket::http::Status;
+use serde_json::json;
+
+#[test]
+fn test_list_starred_unknown_user() {
+    run_test()
+        .get("/users/d6c124d5-1060-48ef-aeb8-e591a8261c2b/starred")
+        .has_status(Status::NotFound)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:users/problems/unknown_user_id",
+            "title": "The requested user ID was unknown",
+            "status": 404
+        }));
+}
+
+#[test]
+fn test_list_starred_none() {
+    let world_owner = SeedUser::default();
+    let user = SeedUser::default();
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let url = format!("/users/{}/starred", user.user_id);
+
+    run_test()
+        .seed(&world_owner)
+        .seed(&user)
+        .seed(&world)
+        .get(url)
+        .has_status(Status::Ok)
+        .has_header("Content-Type", "application/json")
+        .has_json_body(json!({
+          "entries": [],
+          "pagination": {
+            "offset": 0,
+            "count": 10,
+            "total": 0
+          }
+        }));
+}
+
+#[test]
+fn test_list_starred_worlds() {
+    let world_owner = SeedUser::default();
+    let user = SeedUser {
+        user_id: uuid::Uuid::parse_str("00000000-0000-0000-0001-000000000001").unwrap(),
+        ..SeedUser::default()
+    };
+    let other = SeedUser::default();
+    let starred = SeedWorld {
+        world_id: uuid::Uuid::parse_str("00000000-0000-0000-0002-000000000001").unwrap(),
+        name: "Starred World".to_owned(),
+        description: "This is a test world".to_owned(),
+        url_slug: "starred-world".to_owned(),
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let unstarred = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let star = SeedStar {
+        world_id: starred.world_id,
+        user_id: user.user_id,
+        ..SeedStar::default()
+    };
+    let other_star = SeedStar {
+        world_id: starred.world_id,
+        user_id: other.user_id,
+        ..SeedStar::default()
+    };
+    let other_unstarred = SeedStar {
+        world_id: unstarred.world_id,
+        user_id: other.user_id,
+        ..SeedStar::default()
+    };
+
+    run_test()
+        .seed(&world_owner)
+        .seed_many(&[
+            &user,
+            &other,
+            &starred,
+            &unstarred,
+            &star,
+            &other_star,
+            &other_unstarred,
+        ])
+        .get("/users/00000000-0000-0000-0001-000000000001/starred")
+        .has_status(Status::Ok)
+        .has_header("Content-Type", "application/json")
+        .has_header_regex(
+            "Link",
+            r#"</worlds/00000000-0000-0000-0002-000000000001>; rel="item"; anchor="\#/entries/0""#,
+        )
+        .has_json_body(json!({
+          "entries": [
+            {
+              "name": "Starred World",
+              "description": "This is a test world",
+              "status": "published",
+              "url_slug": "starred-world",
+              "stars": 2
+            }
+          ],
+          "pagination": {
+            "offset": 0,
+            "count": 10,
+            "total": 1
+          }
+        }));
+}
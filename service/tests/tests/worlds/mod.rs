@@ -1,3 +1,13 @@
+mod create_connection;
 mod create_world;
+mod delete_connection;
+mod export_worlds_csv;
 mod get_world;
+mod get_world_stats;
+mod list_connections;
+mod list_starred_worlds;
 mod search_worlds;
+mod star_world;
+mod unstar_world;
+mod update_world_status;
+mod world_translations;
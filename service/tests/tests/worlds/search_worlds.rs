@@ -1,5 +1,5 @@
 use crate::{
-    data::{SeedUser, SeedWorld},
+    data::{hash_password, SeedStar, SeedUser, SeedWorld},
     tests::run_test,
 };
 use chrono::{DateTime, Utc};
@@ -119,7 +119,9 @@ fn test_list_one_world() {
             {
               "name": "First World",
               "description": "This is a test world",
-              "url_slug": "first-world"
+              "status": "published",
+              "url_slug": "first-world",
+              "stars": 0
             }
           ],
           "pagination": {
@@ -153,7 +155,9 @@ fn test_list_first_of_three_worlds() {
             {
               "name": "First World",
               "description": "This is a test world",
-              "url_slug": "first-world"
+              "status": "published",
+              "url_slug": "first-world",
+              "stars": 0
             }
           ],
           "pagination": {
@@ -187,7 +191,9 @@ fn test_list_second_of_three_worlds() {
             {
               "name": "Second World",
               "description": "This is a test world",
-              "url_slug": "second-world"
+              "status": "published",
+              "url_slug": "second-world",
+              "stars": 0
             }
           ],
           "pagination": {
@@ -253,7 +259,9 @@ fn test_list_one_world_matching_owner() {
             {
               "name": "First World",
               "description": "This is a test world",
-              "url_slug": "first-world"
+              "status": "published",
+              "url_slug": "first-world",
+              "stars": 0
             }
           ],
           "pagination": {
@@ -281,7 +289,9 @@ fn test_list_one_world_matching_url_slug() {
             {
               "name": "First World",
               "description": "This is a test world",
-              "url_slug": "first-world"
+              "status": "published",
+              "url_slug": "first-world",
+              "stars": 0
             }
           ],
           "pagination": {
@@ -309,7 +319,9 @@ fn test_list_one_world_matching_url_slug_and_owner() {
             {
               "name": "First World",
               "description": "This is a test world",
-              "url_slug": "first-world"
+              "status": "published",
+              "url_slug": "first-world",
+              "stars": 0
             }
           ],
           "pagination": {
@@ -369,17 +381,23 @@ macro_rules! test_list_many_worlds {
         worlds.insert("world1", json!({
           "name": data.world1.name,
           "description": data.world1.description,
-          "url_slug": data.world1.url_slug
+          "status": "published",
+          "url_slug": data.world1.url_slug,
+          "stars": 0
         }));
         worlds.insert("world2", json!({
           "name": data.world2.name,
           "description": data.world2.description,
-          "url_slug": data.world2.url_slug
+          "status": "published",
+          "url_slug": data.world2.url_slug,
+          "stars": 0
         }));
         worlds.insert("world3", json!({
           "name": data.world3.name,
           "description": data.world3.description,
-          "url_slug": data.world3.url_slug
+          "status": "published",
+          "url_slug": data.world3.url_slug,
+          "stars": 0
         }));
 
         let mut user_names = HashMap::new();
@@ -459,3 +477,179 @@ test_list_many_worlds! {
   test_list_many_worlds_owner_descending: "/worlds?sort=-owner" -> (world3,world1,world2),
   test_list_many_worlds_owner_default: "/worlds?sort=owner" -> (world1,world2,world3),
 }
+
+#[test]
+fn test_list_many_worlds_stars() {
+    let data = TestData::default();
+    let stars = vec![
+        SeedStar {
+            world_id: data.world3.world_id,
+            user_id: data.user1.user_id,
+            ..SeedStar::default()
+        },
+        SeedStar {
+            world_id: data.world3.world_id,
+            user_id: data.user2.user_id,
+            ..SeedStar::default()
+        },
+        SeedStar {
+            world_id: data.world2.world_id,
+            user_id: data.user1.user_id,
+            ..SeedStar::default()
+        },
+    ];
+
+    run_test()
+        .seed_many(&[
+            &data.user1,
+            &data.user2,
+            &data.world1,
+            &data.world2,
+            &data.world3,
+            &stars[0],
+            &stars[1],
+            &stars[2],
+        ])
+        .get("/worlds?sort=stars")
+        .has_status(Status::Ok)
+        .has_header("Content-Type", "application/json")
+        .has_header_regex(
+            "Link",
+            format!(
+                r#"</worlds/{}>; rel="item"; anchor="\#/entries/0""#,
+                data.world3.world_id
+            ),
+        )
+        .has_header_regex(
+            "Link",
+            format!(
+                r#"</worlds/{}>; rel="item"; anchor="\#/entries/1""#,
+                data.world2.world_id
+            ),
+        )
+        .has_header_regex(
+            "Link",
+            format!(
+                r#"</worlds/{}>; rel="item"; anchor="\#/entries/2""#,
+                data.world1.world_id
+            ),
+        )
+        .assert_json_body(|body| {
+            assert_eq!(body["entries"][0]["stars"], json!(2));
+            assert_eq!(body["entries"][1]["stars"], json!(1));
+            assert_eq!(body["entries"][2]["stars"], json!(0));
+        });
+}
+
+#[test]
+fn test_list_excludes_draft_worlds() {
+    let data = TestData::default();
+    let draft = SeedWorld {
+        owner: data.user1.user_id,
+        status: "draft".to_owned(),
+        ..data.world2.clone()
+    };
+
+    run_test()
+        .seed_many(&[&data.user1, &data.world1, &draft])
+        .get("/worlds")
+        .has_status(Status::Ok)
+        .assert_json_body(|body| {
+            assert_eq!(body["pagination"]["total"], json!(1));
+            assert_eq!(body["entries"][0]["name"], json!("First World"));
+        });
+}
+
+#[test]
+fn test_list_draft_worlds_as_owner() {
+    let data = TestData::default();
+    let owner = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..data.user1.clone()
+    };
+    let draft = SeedWorld {
+        status: "draft".to_owned(),
+        ..data.world2.clone()
+    };
+    let other_draft = SeedWorld {
+        status: "draft".to_owned(),
+        ..data.world3.clone()
+    };
+
+    run_test()
+        .seed_many(&[&owner, &data.user2, &data.world1, &draft, &other_draft])
+        .authenticate("testuser", "password")
+        .get("/worlds?status=draft")
+        .has_status(Status::Ok)
+        .has_header_regex(
+            "Link",
+            r#"</worlds/00000000-0000-0000-0002-000000000002>; rel="item"; anchor="\#/entries/0""#,
+        )
+        .assert_json_body(|body| {
+            assert_eq!(body["pagination"]["total"], json!(1));
+            assert_eq!(body["entries"][0]["status"], json!("draft"));
+        });
+}
+
+#[test]
+fn test_list_unknown_status() {
+    run_test()
+        .get("/worlds?status=archived")
+        .has_status(Status::UnprocessableEntity)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/validation_error",
+            "title": "A validation error occurred",
+            "status": 422,
+            "fields": {
+              "status": {
+                "type": "tag:multiverse,2020:problems/validation_error/unknown_status",
+                "title": "The publication status was unknown"
+              }
+            }
+        }));
+}
+
+#[test]
+fn test_list_excludes_hidden_worlds() {
+    let data = TestData::default();
+    let hidden = SeedWorld {
+        owner: data.user1.user_id,
+        hidden: true,
+        ..data.world2.clone()
+    };
+
+    run_test()
+        .seed_many(&[&data.user1, &data.world1, &hidden])
+        .get("/worlds")
+        .has_status(Status::Ok)
+        .assert_json_body(|body| {
+            assert_eq!(body["pagination"]["total"], json!(1));
+            assert_eq!(body["entries"][0]["name"], json!("First World"));
+        });
+}
+
+#[test]
+fn test_list_hidden_worlds_as_owner() {
+    let data = TestData::default();
+    let owner = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..data.user1.clone()
+    };
+    let hidden = SeedWorld {
+        owner: owner.user_id,
+        hidden: true,
+        ..data.world2.clone()
+    };
+
+    run_test()
+        .seed_many(&[&owner, &data.world1, &hidden])
+        .authenticate("testuser", "password")
+        .get("/worlds")
+        .has_status(Status::Ok)
+        .assert_json_body(|body| {
+            assert_eq!(body["pagination"]["total"], json!(2));
+        });
+}
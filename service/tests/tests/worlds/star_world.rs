@@ -0,0 +1,116 @@
+use crate::{
+    data::{hash_password, SeedStar, SeedUser, SeedWorld},
+    tests::run_test,
+};
+use rocket::http::Status;
+use serde_json::json;
+
+#[test]
+fn test_star_unauthenticated() {
+    let world_owner = SeedUser::default();
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let url = format!("/worlds/{}/star", world.world_id);
+
+    run_test()
+        .seed(&world_owner)
+        .seed(&world)
+        .put(url, json!({}))
+        .has_status(Status::Forbidden)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/unauthorized",
+            "title": "An invalid access token was provided",
+            "status": 403
+        }));
+}
+
+#[test]
+fn test_star_unknown_world() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+
+    run_test()
+        .seed(&user)
+        .authenticate("testuser", "password")
+        .put(
+            "/worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b/star",
+            json!({}),
+        )
+        .has_status(Status::NotFound)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:worlds/problems/unknown_world_id",
+            "title": "The requested world ID was unknown",
+            "status": 404
+        }));
+}
+
+#[test]
+fn test_star_success() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let owner = SeedUser::default();
+    let world = SeedWorld {
+        owner: owner.user_id,
+        ..SeedWorld::default()
+    };
+    let url = format!("/worlds/{}/star", world.world_id);
+
+    run_test()
+        .seed(&user)
+        .seed(&owner)
+        .seed(&world)
+        .authenticate("testuser", "password")
+        .put(url.clone(), json!({}))
+        .has_status(Status::NoContent)
+        .get(format!("/worlds/{}", world.world_id))
+        .has_status(Status::Ok)
+        .assert_json_body(|body| {
+            assert_eq!(body["stars"], json!(1));
+        });
+}
+
+#[test]
+fn test_star_already_starred() {
+    let world_owner = SeedUser::default();
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let star = SeedStar {
+        world_id: world.world_id,
+        user_id: user.user_id,
+        ..SeedStar::default()
+    };
+    let url = format!("/worlds/{}/star", world.world_id);
+
+    run_test()
+        .seed(&world_owner)
+        .seed(&user)
+        .seed(&world)
+        .seed(&star)
+        .authenticate("testuser", "password")
+        .put(url, json!({}))
+        .has_status(Status::NoContent)
+        .assert_database(|mut tx| {
+            let count: i64 = tx
+                .query_one("SELECT COUNT(*) FROM world_stars", &[])
+                .unwrap()
+                .get(0);
+            assert_eq!(count, 1);
+        });
+}
@@ -0,0 +1,95 @@
+use crate::{
+    data::{hash_password, SeedStar, SeedUser, SeedWorld},
+    tests::run_test,
+};
+use rocket::http::Status;
+use serde_json::json;
+
+#[test]
+fn test_unstar_unauthenticated() {
+    let world_owner = SeedUser::default();
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let url = format!("/worlds/{}/star", world.world_id);
+
+    run_test()
+        .seed(&world_owner)
+        .seed(&world)
+        .delete(url)
+        .has_status(Status::Forbidden)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/unauthorized",
+            "title": "An invalid access token was provided",
+            "status": 403
+        }));
+}
+
+#[test]
+fn test_unstar_not_starred() {
+    let world_owner = SeedUser::default();
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let url = format!("/worlds/{}/star", world.world_id);
+
+    run_test()
+        .seed(&world_owner)
+        .seed(&user)
+        .seed(&world)
+        .authenticate("testuser", "password")
+        .delete(url)
+        .has_status(Status::NoContent);
+}
+
+#[test]
+fn test_unstar_success() {
+    let world_owner = SeedUser::default();
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let other = SeedUser::default();
+    let world = SeedWorld {
+        owner: world_owner.user_id,
+        ..SeedWorld::default()
+    };
+    let star = SeedStar {
+        world_id: world.world_id,
+        user_id: user.user_id,
+        ..SeedStar::default()
+    };
+    let other_star = SeedStar {
+        world_id: world.world_id,
+        user_id: other.user_id,
+        ..SeedStar::default()
+    };
+    let url = format!("/worlds/{}/star", world.world_id);
+
+    run_test()
+        .seed(&world_owner)
+        .seed(&user)
+        .seed(&other)
+        .seed(&world)
+        .seed(&star)
+        .seed(&other_star)
+        .authenticate("testuser", "password")
+        .delete(url)
+        .has_status(Status::NoContent)
+        .assert_database(|mut tx| {
+            let count: i64 = tx
+                .query_one("SELECT COUNT(*) FROM world_stars", &[])
+                .unwrap()
+                .get(0);
+            assert_eq!(count, 1);
+        });
+}
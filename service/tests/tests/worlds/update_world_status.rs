@@ -0,0 +1,200 @@
+use crate::{
+    data::{hash_password, SeedUser, SeedWorld},
+    tests::run_test,
+};
+use rocket::http::Status;
+use serde_json::json;
+
+#[test]
+fn test_update_status_unauthenticated() {
+    let owner = SeedUser::default();
+    let world = SeedWorld {
+        world_id: uuid::Uuid::parse_str("d6c124d5-1060-48ef-aeb8-e591a8261c2b").unwrap(),
+        owner: owner.user_id,
+        ..SeedWorld::default()
+    };
+
+    run_test()
+        .seed_many(&[&owner, &world])
+        .put(
+            "/worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b/status",
+            json!({ "status": "draft" }),
+        )
+        .has_status(Status::Forbidden);
+}
+
+#[test]
+fn test_update_status_not_owner() {
+    let owner = SeedUser::default();
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        world_id: uuid::Uuid::parse_str("d6c124d5-1060-48ef-aeb8-e591a8261c2b").unwrap(),
+        owner: owner.user_id,
+        ..SeedWorld::default()
+    };
+
+    run_test()
+        .seed_many(&[&owner, &user, &world])
+        .authenticate("testuser", "password")
+        .put(
+            "/worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b/status",
+            json!({ "status": "draft" }),
+        )
+        .has_status(Status::Forbidden);
+}
+
+#[test]
+fn test_update_status_invalid() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        world_id: uuid::Uuid::parse_str("d6c124d5-1060-48ef-aeb8-e591a8261c2b").unwrap(),
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+
+    run_test()
+        .seed_many(&[&user, &world])
+        .authenticate("testuser", "password")
+        .put(
+            "/worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b/status",
+            json!({ "status": "scheduled" }),
+        )
+        .has_status(Status::UnprocessableEntity)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/validation_error",
+            "title": "A validation error occurred",
+            "status": 422,
+            "fields": {
+              "publish_at": {
+                "type": "tag:multiverse,2020:problems/validation_error/missing",
+                "title": "The required field was missing"
+              }
+            }
+        }));
+}
+
+#[test]
+fn test_update_status_unknown_status() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        world_id: uuid::Uuid::parse_str("d6c124d5-1060-48ef-aeb8-e591a8261c2b").unwrap(),
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+
+    run_test()
+        .seed_many(&[&user, &world])
+        .authenticate("testuser", "password")
+        .put(
+            "/worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b/status",
+            json!({ "status": "archived" }),
+        )
+        .has_status(Status::UnprocessableEntity)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/validation_error",
+            "title": "A validation error occurred",
+            "status": 422,
+            "fields": {
+              "status": {
+                "type": "tag:multiverse,2020:problems/validation_error/unknown_status",
+                "title": "The publication status was unknown"
+              }
+            }
+        }));
+}
+
+#[test]
+fn test_update_status_to_draft() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        world_id: uuid::Uuid::parse_str("d6c124d5-1060-48ef-aeb8-e591a8261c2b").unwrap(),
+        name: "Test World".to_owned(),
+        description: "This is a test world".to_owned(),
+        url_slug: "test-world".to_owned(),
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+
+    run_test()
+        .seed_many(&[&user, &world])
+        .authenticate("testuser", "password")
+        .put(
+            "/worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b/status",
+            json!({ "status": "draft" }),
+        )
+        .has_status(Status::Ok)
+        .has_header("Content-Type", "application/json")
+        .has_json_body(json!({
+          "name": "Test World",
+          "description": "This is a test world",
+          "status": "draft",
+          "url_slug": "test-world",
+          "stars": 0
+        }))
+        .assert_database(|mut tx| {
+            let status: String = tx
+                .query_one("SELECT status FROM worlds", &[])
+                .unwrap()
+                .get(0);
+            assert_eq!(status, "draft");
+
+            let event: String = tx
+                .query_one("SELECT event FROM activity", &[])
+                .unwrap()
+                .get(0);
+            assert_eq!(event, "world_updated");
+        });
+}
+
+#[test]
+fn test_update_status_to_scheduled() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = SeedWorld {
+        world_id: uuid::Uuid::parse_str("d6c124d5-1060-48ef-aeb8-e591a8261c2b").unwrap(),
+        name: "Test World".to_owned(),
+        description: "This is a test world".to_owned(),
+        url_slug: "test-world".to_owned(),
+        owner: user.user_id,
+        ..SeedWorld::default()
+    };
+
+    run_test()
+        .seed_many(&[&user, &world])
+        .authenticate("testuser", "password")
+        .put(
+            "/worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b/status",
+            json!({ "status": "scheduled", "publish_at": "2999-01-01T12:00:00Z" }),
+        )
+        .has_status(Status::Ok)
+        .has_header("Content-Type", "application/json")
+        .has_json_body(json!({
+          "name": "Test World",
+          "description": "This is a test world",
+          "status": "scheduled",
+          "publish_at": "2999-01-01T12:00:00Z",
+          "url_slug": "test-world",
+          "stars": 0
+        }));
+}
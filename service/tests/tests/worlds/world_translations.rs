@@ -0,0 +1,333 @@
+use crate::{
+    data::{hash_password, SeedUser, SeedWorld},
+    tests::run_test,
+};
+use rocket::http::Status;
+use serde_json::json;
+
+fn translated_world(owner: &SeedUser) -> SeedWorld {
+    SeedWorld {
+        world_id: uuid::Uuid::parse_str("d6c124d5-1060-48ef-aeb8-e591a8261c2b").unwrap(),
+        owner: owner.user_id,
+        name: "The Dark Forest".to_owned(),
+        description: "A forest full of danger".to_owned(),
+        url_slug: "dark-forest".to_owned(),
+        translations: json!({
+            "de": {
+                "name": "Der dunkle Wald",
+                "description": "Ein Wald voller Gefahren"
+            }
+        }),
+        ..SeedWorld::default()
+    }
+}
+
+#[test]
+fn test_get_default_language() {
+    let owner = SeedUser::default();
+    let world = translated_world(&owner);
+
+    run_test()
+        .seed_many(&[&owner, &world])
+        .get("/worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b")
+        .has_status(Status::Ok)
+        .has_header("Content-Language", "en")
+        .has_header("Vary", "Accept-Language")
+        .has_header_regex(
+            "Link",
+            r#"</worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b\?lang=de>; rel="alternate"; hreflang="de""#,
+        )
+        .has_json_body(json!({
+          "name": "The Dark Forest",
+          "description": "A forest full of danger",
+          "status": "published",
+          "url_slug": "dark-forest",
+          "stars": 0
+        }));
+}
+
+#[test]
+fn test_get_accepted_language() {
+    let owner = SeedUser::default();
+    let world = translated_world(&owner);
+
+    run_test()
+        .seed_many(&[&owner, &world])
+        .get_with_header(
+            "/worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b",
+            "Accept-Language",
+            "de-CH, en;q=0.5",
+        )
+        .has_status(Status::Ok)
+        .has_header("Content-Language", "de")
+        .has_header("Vary", "Accept-Language")
+        .has_header_regex(
+            "Link",
+            r#"</worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b\?lang=en>; rel="alternate"; hreflang="en""#,
+        )
+        .has_json_body(json!({
+          "name": "Der dunkle Wald",
+          "description": "Ein Wald voller Gefahren",
+          "status": "published",
+          "url_slug": "dark-forest",
+          "stars": 0
+        }));
+}
+
+#[test]
+fn test_get_unavailable_language() {
+    let owner = SeedUser::default();
+    let world = translated_world(&owner);
+
+    run_test()
+        .seed_many(&[&owner, &world])
+        .get_with_header(
+            "/worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b",
+            "Accept-Language",
+            "fr",
+        )
+        .has_status(Status::Ok)
+        .has_header("Content-Language", "en")
+        .assert_json_body(|body| {
+            assert_eq!(body["name"], json!("The Dark Forest"));
+        });
+}
+
+#[test]
+fn test_get_alternate_link() {
+    let owner = SeedUser::default();
+    let world = translated_world(&owner);
+
+    run_test()
+        .seed_many(&[&owner, &world])
+        .get_with_header(
+            "/worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b?lang=de",
+            "Accept-Language",
+            "en",
+        )
+        .has_status(Status::Ok)
+        .has_header("Content-Language", "de")
+        .assert_json_body(|body| {
+            assert_eq!(body["name"], json!("Der dunkle Wald"));
+        });
+}
+
+#[test]
+fn test_search_accepted_language() {
+    let owner = SeedUser::default();
+    let world = translated_world(&owner);
+
+    run_test()
+        .seed_many(&[&owner, &world])
+        .get_with_header("/worlds", "Accept-Language", "de")
+        .has_status(Status::Ok)
+        .has_header("Vary", "Accept-Language")
+        .assert_json_body(|body| {
+            assert_eq!(body["entries"][0]["name"], json!("Der dunkle Wald"));
+        });
+}
+
+#[test]
+fn test_create_with_translations() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+
+    run_test()
+        .seed(&user)
+        .authenticate("testuser", "password")
+        .post(
+            "/worlds",
+            json!({
+                "name": "Der dunkle Wald",
+                "url_slug": "dark-forest",
+                "language": "de",
+                "translations": {
+                    "en-gb": {
+                        "name": "The Dark Forest",
+                        "description": "A forest full of danger"
+                    }
+                }
+            }),
+        )
+        .has_status(Status::Ok)
+        .has_header("Content-Language", "de")
+        .has_header_regex(
+            "Link",
+            r#"\?lang=en-GB>; rel="alternate"; hreflang="en-GB""#,
+        )
+        .assert_json_body(|body| {
+            assert_eq!(body["name"], json!("Der dunkle Wald"));
+        })
+        .assert_database(|mut tx| {
+            let row = tx
+                .query_one("SELECT language, translations FROM worlds", &[])
+                .unwrap();
+            let language: String = row.get(0);
+            let translations: serde_json::Value = row.get(1);
+            assert_eq!(language, "de");
+            assert_eq!(
+                translations,
+                json!({
+                    "en-GB": {
+                        "name": "The Dark Forest",
+                        "description": "A forest full of danger"
+                    }
+                })
+            );
+        });
+}
+
+#[test]
+fn test_create_invalid_translations() {
+    let user = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+
+    run_test()
+        .seed(&user)
+        .authenticate("testuser", "password")
+        .post(
+            "/worlds",
+            json!({
+                "name": "The Dark Forest",
+                "translations": {
+                    "en": { "name": "The Dark Forest" },
+                    "de": { "description": "Ein Wald voller Gefahren" },
+                    "not a language": { "name": "Unknown" }
+                }
+            }),
+        )
+        .has_status(Status::UnprocessableEntity)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/validation_error",
+            "title": "A validation error occurred",
+            "status": 422,
+            "fields": {
+              "translations/en": {
+                "type": "tag:multiverse,2020:worlds/problems/validation_error/default_language_translation",
+                "title": "The world can not be translated into its default language"
+              },
+              "translations/de/name": {
+                "type": "tag:multiverse,2020:problems/validation_error/missing",
+                "title": "The required field was missing"
+              },
+              "translations/not a language": {
+                "type": "tag:multiverse,2020:problems/validation_error/invalid_language",
+                "title": "The language tag was invalid"
+              }
+            }
+        }));
+}
+
+#[test]
+fn test_save_translation_unauthenticated() {
+    let owner = SeedUser::default();
+    let world = translated_world(&owner);
+
+    run_test()
+        .seed_many(&[&owner, &world])
+        .put(
+            "/worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b/translations/fr",
+            json!({ "name": "La Forêt Sombre" }),
+        )
+        .has_status(Status::Forbidden)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:problems/unauthorized",
+            "title": "An invalid access token was provided",
+            "status": 403
+        }));
+}
+
+#[test]
+fn test_save_translation_default_language() {
+    let owner = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = translated_world(&owner);
+
+    run_test()
+        .seed_many(&[&owner, &world])
+        .authenticate("testuser", "password")
+        .put(
+            "/worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b/translations/EN",
+            json!({ "name": "The Forest" }),
+        )
+        .has_status(Status::UnprocessableEntity)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:worlds/problems/default_language_translation",
+            "title": "The world can not be translated into its default language",
+            "status": 422
+        }));
+}
+
+#[test]
+fn test_save_translation_success() {
+    let owner = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = translated_world(&owner);
+
+    run_test()
+        .seed_many(&[&owner, &world])
+        .authenticate("testuser", "password")
+        .put(
+            "/worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b/translations/fr",
+            json!({ "name": "La Forêt Sombre" }),
+        )
+        .has_status(Status::Ok)
+        .has_header_regex("Link", r#"\?lang=fr>; rel="alternate"; hreflang="fr""#)
+        .has_header_regex("Link", r#"\?lang=de>; rel="alternate"; hreflang="de""#)
+        .get_with_header(
+            "/worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b",
+            "Accept-Language",
+            "fr-FR",
+        )
+        .has_status(Status::Ok)
+        .has_header("Content-Language", "fr")
+        .assert_json_body(|body| {
+            assert_eq!(body["name"], json!("La Forêt Sombre"));
+            assert_eq!(body["description"], json!(""));
+        });
+}
+
+#[test]
+fn test_delete_translation() {
+    let owner = SeedUser {
+        username: "testuser".to_owned(),
+        password: hash_password("password"),
+        ..SeedUser::default()
+    };
+    let world = translated_world(&owner);
+
+    run_test()
+        .seed_many(&[&owner, &world])
+        .authenticate("testuser", "password")
+        .delete("/worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b/translations/de")
+        .has_status(Status::NoContent)
+        .get_with_header(
+            "/worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b",
+            "Accept-Language",
+            "de",
+        )
+        .has_header("Content-Language", "en")
+        .delete("/worlds/d6c124d5-1060-48ef-aeb8-e591a8261c2b/translations/de")
+        .has_status(Status::NotFound)
+        .has_header("Content-Type", "application/problem+json")
+        .has_json_body(json!({
+            "type": "tag:multiverse,2020:worlds/problems/unknown_translation",
+            "title": "The requested translation was unknown",
+            "status": 404
+        }));
+}